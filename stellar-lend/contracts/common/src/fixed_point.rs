@@ -0,0 +1,72 @@
+//! Shared fixed-point and integer math helpers.
+//!
+//! Everything here is pure integer arithmetic so results are deterministic
+//! across contracts and never panic on overflow.
+
+/// Fixed-point scale used for ratios (1.0 = 10^18).
+pub const WAD: i128 = 1_000_000_000_000_000_000;
+
+/// Floor of the square root of `value`.
+///
+/// Uses Newton's method; converges in O(log n) iterations.
+pub fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value;
+    let mut y = x / 2 + x % 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+/// Quadratic voting weight of raw voting power: `floor(sqrt(power))`.
+///
+/// Non-positive power has no weight.
+pub fn quadratic_weight(power: i128) -> i128 {
+    if power <= 0 {
+        return 0;
+    }
+    isqrt(power as u128) as i128
+}
+
+/// `a * b / denominator` rounded down, or `None` on overflow or a zero denominator.
+pub fn mul_div_floor(a: i128, b: i128, denominator: i128) -> Option<i128> {
+    if denominator == 0 {
+        return None;
+    }
+    a.checked_mul(b)?.checked_div(denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isqrt_exact_and_floor() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(1_000_000), 1_000);
+        assert_eq!(isqrt(u128::MAX), 18_446_744_073_709_551_615);
+    }
+
+    #[test]
+    fn quadratic_weight_ignores_non_positive() {
+        assert_eq!(quadratic_weight(-4), 0);
+        assert_eq!(quadratic_weight(0), 0);
+        assert_eq!(quadratic_weight(10_000), 100);
+        assert_eq!(quadratic_weight(i128::MAX), 13_043_817_825_332_782_212);
+    }
+
+    #[test]
+    fn mul_div_floor_handles_edges() {
+        assert_eq!(mul_div_floor(7, 3, 2), Some(10));
+        assert_eq!(mul_div_floor(1, 1, 0), None);
+        assert_eq!(mul_div_floor(i128::MAX, 2, 1), None);
+        assert_eq!(mul_div_floor(WAD, 5, WAD), Some(5));
+    }
+}
@@ -4,10 +4,13 @@
 //!
 //! The common crate exists to keep cross-crate data models and safety-sensitive helper logic in
 //! one place so downstream contracts do not silently drift in storage or authorization behavior.
-//! At the moment the shared surface is the upgrade-management module.
+//! The shared surface is the upgrade-management module and the fixed-point math helpers.
 
+pub mod fixed_point;
 pub mod upgrade;
 
+pub use fixed_point::{isqrt, mul_div_floor, quadratic_weight, WAD};
+
 pub use upgrade::{
     UpgradeError, UpgradeManager, UpgradeProposal, UpgradeStage, UpgradeStatus,
     INITIAL_CONTRACT_VERSION, MAX_UPGRADE_APPROVERS,
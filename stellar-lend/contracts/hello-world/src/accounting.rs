@@ -0,0 +1,273 @@
+//! # Accounting Module
+//!
+//! Reconciles the contract's actual token balances against recorded
+//! liabilities so operators can spot accounting drift or balance skew from
+//! direct transfers.
+//!
+//! ## Expected Balance
+//! For an asset, the contract should hold:
+//!
+//! ```text
+//! expected = deposits - borrows + reserves + escrowed - strategy_allocated
+//! ```
+//!
+//! where `deposits` and `borrows` are the asset's totals in the per-asset
+//! position ledger, `reserves` covers every protocol-held bucket (the reserve
+//! balance, legacy protocol reserve, insurance fund and protocol-owned
+//! liquidity) and `escrowed` covers tokens held for users outside the lending
+//! market: governance vote stakes, keeper bonds, savings balances, unclaimed
+//! claim-campaign funding, claimable supply interest and referral rewards.
+//! `discrepancy = actual - expected`; a positive value is surplus (e.g. a
+//! direct transfer), a negative one means recorded liabilities are not backed.
+//!
+//! ## Surplus Sweep
+//! A treasurer can call [`sweep_surplus`] to move a positive discrepancy
+//! (stray transfers, rounding dust) into the asset's reserve balance so it is
+//! captured by the protocol instead of sitting untracked. Every recorded
+//! liability above is excluded, but supply interest that borrowers have paid
+//! and suppliers have not yet settled is only tracked per user, so it still
+//! shows up as surplus; the treasurer should sweep only once the discrepancy
+//! is understood.
+//!
+//! ## Token Rescue
+//! Tokens sent to the contract by mistake can be returned with
+//! [`rescue_tokens`]. It is only reachable through an executed governance
+//! proposal and refuses any asset the protocol holds on behalf of users: market
+//! assets, the native asset, the governance vote token, the keeper bond token,
+//! and any token with open savings balances, posted keeper bonds or unclaimed
+//! campaign funding.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, token::TokenClient, Address, Env, Symbol};
+
+use crate::deposit::DepositDataKey;
+use crate::reserve::ReserveDataKey;
+
+/// Errors that can occur during accounting operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AccountingError {
+    /// Asset address is invalid or the native asset is not configured
+    InvalidAsset = 1,
+    /// Arithmetic overflow occurred
+    Overflow = 2,
+    /// Balance does not exceed recorded liabilities
+    NoSurplus = 3,
+    /// Amount must be greater than zero
+    InvalidAmount = 4,
+    /// Asset is used by the protocol and cannot be rescued
+    ProtocolAsset = 5,
+    /// Caller lacks the required role
+    Unauthorized = 6,
+}
+
+/// Result of reconciling an asset's balance against recorded liabilities
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reconciliation {
+    /// Token balance held by the contract
+    pub actual_balance: i128,
+    /// Recorded deposits
+    pub total_deposits: i128,
+    /// Recorded borrows
+    pub total_borrows: i128,
+    /// Reserves, insurance fund and protocol-owned liquidity
+    pub reserves: i128,
+    /// Vote stakes, keeper bonds, savings, unclaimed campaign funding,
+    /// claimable supply interest and referral rewards
+    pub escrowed: i128,
+    /// Principal deployed to a yield strategy
+    pub strategy_allocated: i128,
+    /// Balance the contract should hold
+    pub expected_balance: i128,
+    /// `actual_balance - expected_balance`
+    pub discrepancy: i128,
+    /// Whether the discrepancy is zero
+    pub balanced: bool,
+}
+
+/// Resolve the token contract for an asset (`None` is the native asset)
+pub fn asset_address(env: &Env, asset: &Option<Address>) -> Result<Address, AccountingError> {
+    match asset {
+        Some(addr) if addr == &env.current_contract_address() => Err(AccountingError::InvalidAsset),
+        Some(addr) => Ok(addr.clone()),
+        None => env
+            .storage()
+            .persistent()
+            .get::<DepositDataKey, Address>(&DepositDataKey::NativeAssetAddress)
+            .ok_or(AccountingError::InvalidAsset),
+    }
+}
+
+/// Protocol-held balance of an asset across all reserve buckets
+pub fn total_reserves(env: &Env, asset: Option<Address>) -> Result<i128, AccountingError> {
+    let legacy_reserve: i128 = env
+        .storage()
+        .persistent()
+        .get(&DepositDataKey::ProtocolReserve(asset.clone()))
+        .unwrap_or(0);
+
+    crate::reserve::get_reserve_balance(env, asset.clone())
+        .checked_add(legacy_reserve)
+        .and_then(|v| v.checked_add(crate::reserve::get_insurance_fund_balance(env, asset.clone())))
+        .and_then(|v| v.checked_add(crate::reserve::get_protocol_owned_liquidity(env, asset)))
+        .ok_or(AccountingError::Overflow)
+}
+
+/// Tokens of an asset held for users outside the lending market
+pub fn total_escrowed(
+    env: &Env,
+    asset: &Option<Address>,
+    asset_addr: &Address,
+) -> Result<i128, AccountingError> {
+    let stakes = match crate::governance::get_config(env) {
+        Some(config) if &config.vote_token == asset_addr => {
+            crate::governance::get_total_staked(env)
+        }
+        _ => 0,
+    };
+    let claimable_interest = crate::supply_interest::get_total_claimable(env, asset.clone());
+    let referral_rewards = crate::referral::get_total_referral_rewards(env, asset.clone());
+
+    stakes
+        .checked_add(crate::keeper::get_total_bonded(env, asset_addr))
+        .and_then(|v| v.checked_add(crate::savings::get_savings_total(env, asset_addr)))
+        .and_then(|v| v.checked_add(crate::claims::get_outstanding(env, asset_addr)))
+        .and_then(|v| v.checked_add(claimable_interest))
+        .and_then(|v| v.checked_add(referral_rewards))
+        .ok_or(AccountingError::Overflow)
+}
+
+/// Compare an asset's actual balance with recorded liabilities
+///
+/// # Errors
+/// * `AccountingError::InvalidAsset` - If the asset cannot be resolved
+/// * `AccountingError::Overflow` - If arithmetic overflow occurs
+pub fn reconcile(env: &Env, asset: Option<Address>) -> Result<Reconciliation, AccountingError> {
+    let asset_addr = asset_address(env, &asset)?;
+    let actual_balance = TokenClient::new(env, &asset_addr).balance(&env.current_contract_address());
+
+    let totals = crate::asset_positions::get_asset_totals(env, asset.clone());
+    let total_deposits = totals.collateral;
+    let total_borrows = totals.debt;
+    let escrowed = total_escrowed(env, &asset, &asset_addr)?;
+    let reserves = total_reserves(env, asset)?;
+    let strategy_allocated = crate::strategy::get_allocated(env, &asset_addr);
+
+    let expected_balance = total_deposits
+        .checked_sub(total_borrows)
+        .and_then(|v| v.checked_add(reserves))
+        .and_then(|v| v.checked_add(escrowed))
+        .and_then(|v| v.checked_sub(strategy_allocated))
+        .ok_or(AccountingError::Overflow)?;
+    let discrepancy = actual_balance
+        .checked_sub(expected_balance)
+        .ok_or(AccountingError::Overflow)?;
+
+    Ok(Reconciliation {
+        actual_balance,
+        total_deposits,
+        total_borrows,
+        reserves,
+        escrowed,
+        strategy_allocated,
+        expected_balance,
+        discrepancy,
+        balanced: discrepancy == 0,
+    })
+}
+
+/// Move token balance exceeding recorded liabilities into reserves
+///
+/// Returns the amount swept.
+///
+/// # Errors
+/// * `AccountingError::Unauthorized` - If `caller` is not a treasurer or the admin
+/// * `AccountingError::InvalidAsset` - If the asset cannot be resolved
+/// * `AccountingError::NoSurplus` - If there is nothing to sweep
+/// * `AccountingError::Overflow` - If arithmetic overflow occurs
+#[allow(deprecated)]
+pub fn sweep_surplus(
+    env: &Env,
+    caller: Address,
+    asset: Option<Address>,
+) -> Result<i128, AccountingError> {
+    caller.require_auth();
+    crate::admin::require_role(env, &caller, crate::admin::Role::Treasurer)
+        .map_err(|_| AccountingError::Unauthorized)?;
+
+    let report = reconcile(env, asset.clone())?;
+    if report.discrepancy <= 0 {
+        return Err(AccountingError::NoSurplus);
+    }
+    let surplus = report.discrepancy;
+
+    let balance_key = ReserveDataKey::ReserveBalance(asset.clone());
+    let new_balance = crate::reserve::get_reserve_balance(env, asset.clone())
+        .checked_add(surplus)
+        .ok_or(AccountingError::Overflow)?;
+    env.storage().persistent().set(&balance_key, &new_balance);
+
+    let topics = (Symbol::new(env, "surplus_swept"),);
+    env.events().publish(topics, (asset, surplus, new_balance));
+
+    Ok(surplus)
+}
+
+/// Whether the protocol holds `asset` on behalf of users
+pub fn is_protocol_asset(env: &Env, asset: &Address) -> bool {
+    let some_asset = Some(asset.clone());
+    if crate::cross_asset::get_asset_config_by_address(env, some_asset).is_ok() {
+        return true;
+    }
+    if env
+        .storage()
+        .persistent()
+        .has(&DepositDataKey::AssetParams(asset.clone()))
+    {
+        return true;
+    }
+    if asset_address(env, &None).ok().as_ref() == Some(asset) {
+        return true;
+    }
+    if crate::governance::get_config(env).map(|c| c.vote_token).as_ref() == Some(asset) {
+        return true;
+    }
+    if crate::keeper::get_config(env).map(|c| c.bond_token).as_ref() == Some(asset) {
+        return true;
+    }
+    // Escrow that outlives the configuration it was created under
+    crate::savings::get_savings_total(env, asset) > 0
+        || crate::keeper::get_total_bonded(env, asset) > 0
+        || crate::claims::get_outstanding(env, asset) > 0
+}
+
+/// Return tokens mistakenly sent to the contract
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `AccountingError::InvalidAmount` - If `amount <= 0`
+/// * `AccountingError::ProtocolAsset` - If the protocol holds `asset` for users
+#[allow(deprecated)]
+pub fn rescue_tokens(
+    env: &Env,
+    asset: Address,
+    amount: i128,
+    recipient: Address,
+) -> Result<(), AccountingError> {
+    if amount <= 0 {
+        return Err(AccountingError::InvalidAmount);
+    }
+    if asset == env.current_contract_address() || is_protocol_asset(env, &asset) {
+        return Err(AccountingError::ProtocolAsset);
+    }
+
+    TokenClient::new(env, &asset).transfer(&env.current_contract_address(), &recipient, &amount);
+
+    let topics = (Symbol::new(env, "tokens_rescued"), asset);
+    env.events().publish(topics, (recipient, amount));
+
+    Ok(())
+}
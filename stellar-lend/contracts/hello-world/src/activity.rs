@@ -0,0 +1,110 @@
+//! # Activity History Module
+//!
+//! Per-user activity history stored in fixed-size pages.
+//!
+//! A single log of every user's activity grows with usage until it no longer
+//! fits in one ledger entry. Each user's history is therefore split into
+//! pages of [`ACTIVITY_PAGE_SIZE`] entries, each its own entry keyed by
+//! `(user, page)`. Pages are numbered from `0` in the order they were
+//! written; [`get_activity_index`] tells which are still stored and
+//! [`get_activity`] reads one of them, oldest entry first.
+//!
+//! Only the latest [`MAX_ACTIVITY_PAGES`] pages of a user are kept. Opening a
+//! new page beyond that archives the oldest one: it is removed from storage
+//! and its entries are published in an `activity_page_archived` event, so
+//! indexers keep the full history.
+//!
+//! The protocol-wide feed under `DepositDataKey::ActivityLog` only keeps the
+//! most recent [`RECENT_ACTIVITY_SIZE`] entries.
+//!
+//! ## Security
+//! - Only the flow modules record activity; there is no external setter.
+//! - Storage per user is bounded by `ACTIVITY_PAGE_SIZE × MAX_ACTIVITY_PAGES`
+//!   entries.
+
+#![allow(unused)]
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::deposit::Activity;
+
+/// Entries per activity page
+pub const ACTIVITY_PAGE_SIZE: u32 = 20;
+
+/// Pages kept per user before the oldest is archived
+pub const MAX_ACTIVITY_PAGES: u32 = 25;
+
+/// Entries kept in the protocol-wide recent activity feed
+pub const RECENT_ACTIVITY_SIZE: u32 = 100;
+
+/// Storage keys for activity history
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum ActivityDataKey {
+    /// Page of a user's activity, oldest entry first: Vec<Activity>
+    Page(Address, u32),
+    /// Stored page range of a user: ActivityIndex
+    Index(Address),
+}
+
+/// Range of a user's stored activity pages
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ActivityIndex {
+    /// Oldest page still stored
+    pub first_page: u32,
+    /// Page currently being written
+    pub last_page: u32,
+    /// Entries recorded over the user's lifetime, archived ones included
+    pub total_entries: u64,
+}
+
+/// Append `activity` to its user's history, archiving the oldest page when
+/// more than [`MAX_ACTIVITY_PAGES`] would be stored
+#[allow(deprecated)]
+pub fn record(env: &Env, activity: Activity) {
+    let user = activity.user.clone();
+    let mut index = get_activity_index(env, &user);
+    let mut page = get_activity(env, &user, index.last_page);
+    if page.len() >= ACTIVITY_PAGE_SIZE {
+        index.last_page += 1;
+        page = Vec::new(env);
+    }
+    page.push_back(activity);
+    env.storage()
+        .persistent()
+        .set(&ActivityDataKey::Page(user.clone(), index.last_page), &page);
+    index.total_entries = index.total_entries.saturating_add(1);
+
+    if index.last_page - index.first_page >= MAX_ACTIVITY_PAGES {
+        let key = ActivityDataKey::Page(user.clone(), index.first_page);
+        let archived = get_activity(env, &user, index.first_page);
+        env.storage().persistent().remove(&key);
+
+        let topics = (Symbol::new(env, "activity_page_archived"), user.clone());
+        env.events().publish(topics, (index.first_page, archived));
+        index.first_page += 1;
+    }
+
+    env.storage()
+        .persistent()
+        .set(&ActivityDataKey::Index(user), &index);
+}
+
+/// Page `page` of `user`'s activity, oldest entry first
+///
+/// Empty for pages not yet written or already archived.
+pub fn get_activity(env: &Env, user: &Address, page: u32) -> Vec<Activity> {
+    env.storage()
+        .persistent()
+        .get(&ActivityDataKey::Page(user.clone(), page))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Stored page range of `user`'s activity
+pub fn get_activity_index(env: &Env, user: &Address) -> ActivityIndex {
+    env.storage()
+        .persistent()
+        .get(&ActivityDataKey::Index(user.clone()))
+        .unwrap_or_default()
+}
@@ -6,7 +6,15 @@
 //! ## Features
 //! - **Super Admin**: A single address with ultimate authority over the protocol.
 //! - **Roles**: Optional multi-admin functionality via specific roles (e.g., "oracle_admin").
+//!   The protocol's admin-gated functions each require one of the operational
+//!   [`Role`]s; the super admin implicitly holds all of them.
+//! - **Renouncement of roles**: any holder can give up a role with [`renounce_role`].
 //! - **Events**: Emits events for critical admin actions (admin changes, role grants/revocations).
+//! - **Renouncement**: [`renounce_admin`] permanently hands the super-admin role, and
+//!   governance's own admin, to the contract itself. Admin-only calls are then only
+//!   reachable through executed governance proposals, which act as the contract
+//!   address, and the admin can never be changed again. Roles granted before the
+//!   renouncement are frozen; only roles granted afterwards (by governance) count.
 
 use soroban_sdk::{contracterror, contracttype, Address, Env, IntoVal, Symbol, Val, Vec};
 
@@ -21,6 +29,8 @@ pub enum AdminError {
     InvalidParameter = 2,
     /// Admin has already been set
     AdminAlreadySet = 3,
+    /// Admin was renounced to governance and can no longer change
+    AdminRenounced = 4,
 }
 
 /// Storage keys for Admin and Roles
@@ -30,8 +40,44 @@ pub enum AdminError {
 pub enum AdminDataKey {
     /// The super admin address
     Admin,
-    /// Specific role assigned to an address: Role(RoleName, Address) -> bool
+    /// Specific role assigned to an address: Role(RoleName, Address) -> u32
+    /// (the role epoch it was granted in)
     Role(Symbol, Address),
+    /// Whether the admin was renounced to governance: bool
+    Renounced,
+    /// Current role epoch; grants from earlier epochs no longer count: u32
+    RoleEpoch,
+}
+
+/// Operational roles the super admin can delegate
+///
+/// Stored and granted under [`Role::name`], so [`grant_role`] and
+/// [`revoke_role`] manage them like any other role.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// Risk parameters, oracles, rate models and feature configuration
+    RiskAdmin,
+    /// Pause switches, emergency pause and circuit breakers
+    PauseGuardian,
+    /// Reserves, treasury address and reward funding
+    Treasurer,
+    /// Contract upgrades
+    Upgrader,
+}
+
+impl Role {
+    /// Name the role is granted under
+    pub fn name(&self, env: &Env) -> Symbol {
+        Symbol::new(
+            env,
+            match self {
+                Role::RiskAdmin => "risk_admin",
+                Role::PauseGuardian => "pause_guardian",
+                Role::Treasurer => "treasurer",
+                Role::Upgrader => "upgrader",
+            },
+        )
+    }
 }
 
 /// Check if the super admin is set
@@ -51,6 +97,9 @@ pub fn get_admin(env: &Env) -> Option<Address> {
 /// * `new_admin` - The new admin address
 /// * `caller` - The caller address (must be the current admin if one exists)
 pub fn set_admin(env: &Env, new_admin: Address, caller: Option<Address>) -> Result<(), AdminError> {
+    if is_admin_renounced(env) {
+        return Err(AdminError::AdminRenounced);
+    }
     if let Some(current_admin) = get_admin(env) {
         if let Some(ref c) = caller {
             if *c != current_admin {
@@ -80,6 +129,51 @@ pub fn set_admin(env: &Env, new_admin: Address, caller: Option<Address>) -> Resu
     Ok(())
 }
 
+/// Whether the super admin was renounced to governance
+pub fn is_admin_renounced(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&AdminDataKey::Renounced)
+        .unwrap_or(false)
+}
+
+/// Permanently hand the super-admin role to governance (admin only)
+///
+/// The contract address becomes the admin, and governance's admin, so
+/// admin-only operations can only be performed by executed governance
+/// proposals. Every role granted so far is frozen. This cannot be undone.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The caller address (must be the current admin)
+pub fn renounce_admin(env: &Env, caller: Address) -> Result<(), AdminError> {
+    caller.require_auth();
+    require_admin(env, &caller)?;
+    if is_admin_renounced(env) {
+        return Err(AdminError::AdminRenounced);
+    }
+
+    let governance = env.current_contract_address();
+    env.storage()
+        .persistent()
+        .set(&AdminDataKey::Admin, &governance);
+    env.storage()
+        .persistent()
+        .set(&AdminDataKey::Renounced, &true);
+    crate::governance::renounce_governance_admin(env);
+
+    // Start a new role epoch, freezing every role granted by the old admin
+    env.storage()
+        .persistent()
+        .set(&AdminDataKey::RoleEpoch, &(role_epoch(env) + 1));
+
+    // Emit event
+    let topics = (Symbol::new(env, "admin_renounced"), caller);
+    env.events().publish(topics, governance);
+
+    Ok(())
+}
+
 /// Require that the caller is the super admin
 pub fn require_admin(env: &Env, caller: &Address) -> Result<(), AdminError> {
     let admin = get_admin(env).ok_or(AdminError::Unauthorized)?;
@@ -99,7 +193,7 @@ pub fn grant_role(
     require_admin(env, &caller)?;
 
     let key = AdminDataKey::Role(role.clone(), account.clone());
-    env.storage().persistent().set(&key, &true);
+    env.storage().persistent().set(&key, &role_epoch(env));
 
     // Emit event
     let topics = (
@@ -143,11 +237,23 @@ pub fn revoke_role(
     Ok(())
 }
 
-/// Check if an address has a specific role
+/// Current role epoch (starts at 0, advanced when the admin is renounced)
+fn role_epoch(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&AdminDataKey::RoleEpoch)
+        .unwrap_or(0)
+}
+
+/// Check if an address has a specific role granted in the current role epoch
 #[allow(dead_code)]
 pub fn has_role(env: &Env, role: Symbol, account: Address) -> bool {
     let key = AdminDataKey::Role(role, account);
-    env.storage().persistent().get(&key).unwrap_or(false)
+    env.storage()
+        .persistent()
+        .get::<_, u32>(&key)
+        .map(|epoch| epoch == role_epoch(env))
+        .unwrap_or(false)
 }
 
 /// Require that the caller is either the super admin or has the required role
@@ -167,3 +273,28 @@ pub fn require_role_or_admin(
 
     Err(AdminError::Unauthorized)
 }
+
+/// Require that the caller holds `role` or is the super admin
+pub fn require_role(env: &Env, caller: &Address, role: Role) -> Result<(), AdminError> {
+    require_role_or_admin(env, caller, role.name(env))
+}
+
+/// Give up a role held by `account`
+///
+/// # Errors
+/// * `AdminError::InvalidParameter` - If `account` does not hold the role
+pub fn renounce_role(env: &Env, account: Address, role: Symbol) -> Result<(), AdminError> {
+    account.require_auth();
+    if !has_role(env, role.clone(), account.clone()) {
+        return Err(AdminError::InvalidParameter);
+    }
+
+    let key = AdminDataKey::Role(role.clone(), account.clone());
+    env.storage().persistent().remove(&key);
+
+    // Emit event
+    let topics = (Symbol::new(env, "role_renounced"), account, role);
+    env.events().publish(topics, ());
+
+    Ok(())
+}
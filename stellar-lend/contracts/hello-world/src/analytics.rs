@@ -3,7 +3,8 @@
 //! Provides protocol-wide and per-user analytics, reporting, and activity tracking.
 //!
 //! This module aggregates data from the deposit, borrow, and repay modules to produce:
-//! - **Protocol metrics**: TVL, utilization, average borrow rate, total users/transactions
+//! - **Protocol metrics**: TVL (split into user funds and protocol-owned liquidity),
+//!   utilization, average borrow rate, total users/transactions
 //! - **User metrics**: collateral, debt, health factor, risk level, activity score
 //! - **Activity feed**: bounded log of recent protocol operations (max 10,000 entries)
 //!
@@ -11,6 +12,26 @@
 //! `health_factor = (collateral * 10000) / debt`
 //!
 //! A health factor below 10,000 (1.0x) indicates an undercollateralized position.
+//! [`get_account_health_batch`] reports health for up to
+//! [`MAX_HEALTH_BATCH_SIZE`] accounts at once, for liquidation bots and monitors.
+//! [`get_position_summary`] reports one account's health together with how
+//! much more it can borrow or withdraw.
+//!
+//! Every operation that changes a position emits `health_factor_changed` with
+//! the account's new health at oracle prices. Keepers can call the
+//! permissionless [`poke`] to accrue an idle account's interest and re-emit its
+//! health, so at-risk accounts show up in the event stream without polling.
+//!
+//! ## Dashboard Queries
+//! Read-only views expose the raw [`DepositProtocolAnalytics`] totals
+//! ([`get_protocol_totals`]), per-asset utilization from the per-asset
+//! ledger ([`get_asset_utilization`]), a per-asset market summary with
+//! current rates and reserves ([`get_market_data`]) and the number of
+//! borrowers at each
+//! risk level ([`get_health_distribution`]). The distribution is kept up to
+//! date by the same hook that emits `health_factor_changed`, so it reflects
+//! each borrower's health as of their last operation or `poke`; accounts
+//! whose health could not be priced keep their previous level.
 //!
 //! ## Risk Levels
 //! | Health Factor | Risk Level |
@@ -20,6 +41,17 @@
 //! | ≥ 1.10        | 3          |
 //! | ≥ 1.05        | 4          |
 //! | < 1.05        | 5 (Critical) |
+//!
+//! ## Verbosity
+//! Governance sets an [`AnalyticsVerbosity`] level that the deposit, borrow,
+//! repay, withdraw and liquidation flows consult before writing telemetry:
+//! - `Full` (default): per-user analytics, activity log and analytics events
+//! - `AggregatesOnly`: analytics events only; per-user analytics and the
+//!   activity log are skipped
+//! - `Off`: no telemetry writes or analytics events
+//!
+//! Protocol-wide totals are maintained at every level because the interest
+//! rate model derives utilization from them.
 
 #![allow(unused)]
 use soroban_sdk::{contracterror, contracttype, Address, Env, Map, Symbol, Vec};
@@ -63,6 +95,27 @@ pub enum AnalyticsDataKey {
     /// Cumulative count of all protocol transactions
     /// Value type: u64
     TotalTransactions,
+    /// Telemetry level consulted by the flow modules (instance storage)
+    /// Value type: AnalyticsVerbosity
+    Verbosity,
+    /// Risk level a borrower was last counted at, absent without debt
+    /// Value type: u32
+    BorrowerRiskLevel(Address),
+    /// Number of borrowers at each risk level
+    /// Value type: HealthDistribution
+    HealthDistribution,
+}
+
+/// How much telemetry the flow modules record.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AnalyticsVerbosity {
+    /// Per-user analytics, activity log and analytics events
+    Full,
+    /// Analytics events only
+    AggregatesOnly,
+    /// No telemetry
+    Off,
 }
 
 /// Snapshot of protocol-wide metrics.
@@ -71,6 +124,15 @@ pub enum AnalyticsDataKey {
 pub struct ProtocolMetrics {
     /// Total value locked across all deposited collateral
     pub total_value_locked: i128,
+    /// Value of user-supplied collateral, each asset valued at its oracle
+    /// price
+    pub user_value_locked: i128,
+    /// Value of liquidity seeded by the protocol from reserves, each asset
+    /// valued at its oracle price
+    pub protocol_owned_liquidity: i128,
+    /// Withdrawable protocol reserves summed over native XLM and every
+    /// deposited asset
+    pub total_reserves: i128,
     /// Cumulative deposit volume
     pub total_deposits: i128,
     /// Cumulative borrow volume
@@ -137,6 +199,8 @@ pub struct ActivityEntry {
 pub struct ProtocolReport {
     /// Current protocol metrics
     pub metrics: ProtocolMetrics,
+    /// Cumulative origination fees and their insurance/treasury split
+    pub fees: crate::origination_fee::FeeMetrics,
     /// Report generation timestamp
     pub timestamp: u64,
 }
@@ -157,9 +221,150 @@ pub struct UserReport {
     pub timestamp: u64,
 }
 
+/// Utilization of one asset, as returned by [`get_asset_utilization`]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssetUtilization {
+    /// Asset address (None for native XLM)
+    pub asset: Option<Address>,
+    /// Collateral supplied in the asset
+    pub total_supply: i128,
+    /// Principal borrowed in the asset
+    pub total_borrows: i128,
+    /// Utilization in basis points (borrows / supply * 10000)
+    pub utilization_rate: i128,
+}
+
+/// Market summary of one asset, as returned by [`get_market_data`]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarketData {
+    /// Asset address (None for native XLM)
+    pub asset: Option<Address>,
+    /// Collateral supplied in the asset
+    pub total_supplied: i128,
+    /// Principal borrowed in the asset
+    pub total_borrowed: i128,
+    /// Utilization the rates are evaluated at, in basis points
+    pub utilization_rate: i128,
+    /// Current borrow APR in basis points
+    pub borrow_rate: i128,
+    /// Current supply APR in basis points
+    pub supply_rate: i128,
+    /// Withdrawable protocol reserves held in the asset
+    pub reserves: i128,
+}
+
+/// Number of borrowers at each risk level (see [`calculate_user_risk_level`])
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HealthDistribution {
+    /// Risk level 1: health factor ≥ 1.50
+    pub low: u32,
+    /// Risk level 2: health factor ≥ 1.20
+    pub moderate: u32,
+    /// Risk level 3: health factor ≥ 1.10
+    pub elevated: u32,
+    /// Risk level 4: health factor ≥ 1.05
+    pub high: u32,
+    /// Risk level 5: health factor < 1.05
+    pub critical: u32,
+}
+
+impl HealthDistribution {
+    /// Total number of borrowers counted
+    pub fn total(&self) -> u32 {
+        self.low + self.moderate + self.elevated + self.high + self.critical
+    }
+
+    fn count_mut(&mut self, risk_level: u32) -> &mut u32 {
+        match risk_level {
+            1 => &mut self.low,
+            2 => &mut self.moderate,
+            3 => &mut self.elevated,
+            4 => &mut self.high,
+            _ => &mut self.critical,
+        }
+    }
+}
+
+/// Health snapshot of one account, as returned by [`get_account_health_batch`]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountHealth {
+    /// Account address
+    pub user: Address,
+    /// Collateral value at oracle prices
+    pub collateral_value: i128,
+    /// Debt value at oracle prices, including interest accrued up to now
+    pub debt_value: i128,
+    /// Health factor in basis points (`i128::MAX` with no debt)
+    pub health_factor: i128,
+    /// Whether the account can currently be liquidated
+    pub is_liquidatable: bool,
+}
+
+/// One account's position at oracle prices, as returned by [`get_position_summary`]
+///
+/// Values are in the oracle's quote unit; assets without a price source count
+/// one-to-one.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionSummary {
+    /// Account address
+    pub user: Address,
+    /// Collateral value
+    pub collateral_value: i128,
+    /// Debt value, including interest accrued up to now
+    pub debt_value: i128,
+    /// Value of the interest included in `debt_value`
+    pub accrued_interest: i128,
+    /// Health factor in basis points (`i128::MAX` with no debt)
+    pub health_factor: i128,
+    /// Value that can still be borrowed in native XLM
+    pub max_borrowable: i128,
+    /// Value of native XLM collateral that can be withdrawn
+    pub max_withdrawable: i128,
+}
+
 const BASIS_POINTS: i128 = 10_000;
 const MAX_ACTIVITY_LOG_SIZE: u32 = 10_000;
 
+/// Maximum number of accounts in one [`get_account_health_batch`] call
+pub const MAX_HEALTH_BATCH_SIZE: u32 = 50;
+
+/// Set the telemetry verbosity level.
+///
+/// Only reachable through an executed governance proposal. Stored in instance
+/// storage so the flows read it without an extra persistent entry.
+#[allow(deprecated)]
+pub fn set_verbosity(env: &Env, level: AnalyticsVerbosity) {
+    env.storage()
+        .instance()
+        .set(&AnalyticsDataKey::Verbosity, &level);
+
+    let topics = (Symbol::new(env, "analytics_verbosity_updated"),);
+    env.events().publish(topics, level);
+}
+
+/// Current telemetry verbosity level (default `Full`).
+pub fn get_verbosity(env: &Env) -> AnalyticsVerbosity {
+    env.storage()
+        .instance()
+        .get(&AnalyticsDataKey::Verbosity)
+        .unwrap_or(AnalyticsVerbosity::Full)
+}
+
+/// Whether per-user analytics and the activity log should be written.
+pub fn tracks_user_activity(env: &Env) -> bool {
+    get_verbosity(env) == AnalyticsVerbosity::Full
+}
+
+/// Whether analytics events should be emitted.
+pub fn emits_analytics_events(env: &Env) -> bool {
+    get_verbosity(env) != AnalyticsVerbosity::Off
+}
+
 /// Get the total value locked (TVL) in the protocol.
 ///
 /// Reads the cumulative TVL from protocol analytics storage.
@@ -272,8 +477,34 @@ pub fn update_protocol_metrics(env: &Env) -> Result<ProtocolMetrics, AnalyticsEr
         .get::<AnalyticsDataKey, u64>(&AnalyticsDataKey::TotalTransactions)
         .unwrap_or(0);
 
+    // Both halves are valued per asset at oracle prices; subtracting POL from
+    // the raw TVL counter would mix units of differently priced assets
+    let protocol_owned_liquidity = crate::reserve::get_protocol_owned_liquidity_value(env)
+        .map_err(|_| AnalyticsError::DataNotFound)?;
+    let mut user_value_locked: i128 = 0;
+    for asset in crate::asset_positions::get_supported_assets(env).iter() {
+        let collateral = crate::asset_positions::get_asset_totals(env, asset.clone()).collateral;
+        let value = crate::oracle::ratio_value(env, asset.as_ref(), collateral)
+            .map_err(|_| AnalyticsError::DataNotFound)?;
+        user_value_locked = user_value_locked
+            .checked_add(value)
+            .ok_or(AnalyticsError::Overflow)?;
+    }
+
+    let mut total_reserves = crate::reserve::get_reserves(env, None);
+    for asset in crate::asset_positions::get_supported_assets(env).iter() {
+        if asset.is_some() {
+            total_reserves = total_reserves
+                .checked_add(crate::reserve::get_reserves(env, asset))
+                .ok_or(AnalyticsError::Overflow)?;
+        }
+    }
+
     let metrics = ProtocolMetrics {
         total_value_locked: tvl,
+        user_value_locked,
+        protocol_owned_liquidity,
+        total_reserves,
         total_deposits: protocol_analytics.total_deposits,
         total_borrows: protocol_analytics.total_borrows,
         utilization_rate: utilization,
@@ -354,6 +585,341 @@ pub fn calculate_health_factor(env: &Env, user: &Address) -> Result<i128, Analyt
     Ok(health_factor)
 }
 
+/// Compute the health of several accounts in one call.
+///
+/// Debt includes interest accrued since the last accrual, and liquidatability
+/// applies the liquidation hysteresis band, so the results match what a
+/// liquidation would see. Accounts without a position are reported with zero
+/// values and infinite health.
+///
+/// # Errors
+/// Returns `AnalyticsError::InvalidParameter` if more than
+/// `MAX_HEALTH_BATCH_SIZE` accounts are requested, or
+/// `AnalyticsError::DataNotFound` if a priced asset held by one of the
+/// accounts has no fresh price.
+pub fn get_account_health_batch(
+    env: &Env,
+    users: Vec<Address>,
+) -> Result<Vec<AccountHealth>, AnalyticsError> {
+    if users.len() > MAX_HEALTH_BATCH_SIZE {
+        return Err(AnalyticsError::InvalidParameter);
+    }
+
+    let mut results = Vec::new(env);
+    for user in users.iter() {
+        results.push_back(get_account_health(env, &user)?);
+    }
+
+    Ok(results)
+}
+
+/// Compute the health of one account, as in [`get_account_health_batch`].
+///
+/// # Errors
+/// Returns `AnalyticsError::DataNotFound` if a priced asset held by the
+/// account has no fresh price.
+pub fn get_account_health(env: &Env, user: &Address) -> Result<AccountHealth, AnalyticsError> {
+    let value = account_value(env, user)?;
+    let collateral_value = value.collateral;
+    let debt_value = value
+        .debt
+        .checked_add(value.interest)
+        .ok_or(AnalyticsError::Overflow)?;
+    let health_factor = health_factor_of(collateral_value, debt_value)?;
+    let is_liquidatable =
+        crate::risk_params::can_liquidate_position(env, user, collateral_value, debt_value)
+            .unwrap_or(false);
+
+    Ok(AccountHealth {
+        user: user.clone(),
+        collateral_value,
+        debt_value,
+        health_factor,
+        is_liquidatable,
+    })
+}
+
+/// Emit `health_factor_changed` with `user`'s current health.
+///
+/// Called after every operation that changes a position, and records the
+/// account's liquidation flag so the hysteresis band remembers the last
+/// evaluation. Nothing is emitted while a priced asset held by the account
+/// has no fresh price, so a stale feed never blocks the operation itself.
+pub fn emit_health_factor_changed(env: &Env, user: &Address, operation: Symbol) {
+    let Ok(health) = get_account_health(env, user) else {
+        return;
+    };
+    crate::risk_params::set_liquidation_flag(env, user, health.is_liquidatable);
+    track_borrower_health(env, &health);
+    crate::events::emit_health_factor_changed(
+        env,
+        crate::events::HealthFactorChangedEvent {
+            user: user.clone(),
+            operation,
+            collateral_value: health.collateral_value,
+            debt_value: health.debt_value,
+            health_factor: health.health_factor,
+            is_liquidatable: health.is_liquidatable,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+/// Move the account into the distribution bucket of its current health,
+/// or out of the distribution once it has no debt.
+fn track_borrower_health(env: &Env, health: &AccountHealth) {
+    let level_key = AnalyticsDataKey::BorrowerRiskLevel(health.user.clone());
+    let previous: Option<u32> = env.storage().persistent().get(&level_key);
+    let current = if health.debt_value > 0 {
+        Some(calculate_user_risk_level(health.health_factor) as u32)
+    } else {
+        None
+    };
+    if previous == current {
+        return;
+    }
+
+    let mut distribution = get_health_distribution(env);
+    if let Some(level) = previous {
+        let count = distribution.count_mut(level);
+        *count = count.saturating_sub(1);
+    }
+    match current {
+        Some(level) => {
+            *distribution.count_mut(level) += 1;
+            env.storage().persistent().set(&level_key, &level);
+        }
+        None => env.storage().persistent().remove(&level_key),
+    }
+    env.storage()
+        .persistent()
+        .set(&AnalyticsDataKey::HealthDistribution, &distribution);
+}
+
+/// Number of borrowers at each risk level, as of their last health update.
+pub fn get_health_distribution(env: &Env) -> HealthDistribution {
+    env.storage()
+        .persistent()
+        .get(&AnalyticsDataKey::HealthDistribution)
+        .unwrap_or_default()
+}
+
+/// Number of accounts with outstanding debt.
+pub fn get_total_borrowers(env: &Env) -> u32 {
+    get_health_distribution(env).total()
+}
+
+/// Raw protocol-wide deposit, borrow and TVL totals.
+pub fn get_protocol_totals(env: &Env) -> DepositProtocolAnalytics {
+    env.storage()
+        .persistent()
+        .get::<DepositDataKey, DepositProtocolAnalytics>(&DepositDataKey::ProtocolAnalytics)
+        .unwrap_or(DepositProtocolAnalytics {
+            total_deposits: 0,
+            total_borrows: 0,
+            total_value_locked: 0,
+        })
+}
+
+/// Supply, borrows and utilization of one asset.
+///
+/// Amounts come from the per-asset ledger, so they exclude interest and
+/// collateral recorded before that ledger existed. Utilization is 0 with no
+/// supply.
+pub fn get_asset_utilization(env: &Env, asset: Option<Address>) -> AssetUtilization {
+    let totals = crate::asset_positions::get_asset_totals(env, asset.clone());
+    let utilization_rate = if totals.collateral > 0 {
+        totals.debt.saturating_mul(BASIS_POINTS) / totals.collateral
+    } else {
+        0
+    };
+
+    AssetUtilization {
+        asset,
+        total_supply: totals.collateral,
+        total_borrows: totals.debt,
+        utilization_rate,
+    }
+}
+
+/// Supply, borrows, rates and reserves of one asset in a single read.
+///
+/// Amounts are those of [`get_asset_utilization`]. `utilization_rate` is the
+/// figure the interest rate model evaluates the asset's rates at, which is
+/// the protocol-wide utilization for assets pooled in the shared market.
+/// Rates are 0 while no interest rate config is set.
+pub fn get_market_data(env: &Env, asset: Option<Address>) -> MarketData {
+    let totals = crate::asset_positions::get_asset_totals(env, asset.clone());
+    MarketData {
+        asset: asset.clone(),
+        total_supplied: totals.collateral,
+        total_borrowed: totals.debt,
+        utilization_rate: crate::interest_rate::calculate_asset_utilization(env, asset.clone())
+            .unwrap_or(0),
+        borrow_rate: crate::interest_rate::calculate_asset_borrow_rate(env, asset.clone())
+            .unwrap_or(0),
+        supply_rate: crate::interest_rate::calculate_asset_supply_rate(env, asset.clone())
+            .unwrap_or(0),
+        reserves: crate::reserve::get_reserves(env, asset),
+    }
+}
+
+/// [`get_asset_utilization`] for every asset that has held a position.
+pub fn get_all_asset_utilization(env: &Env) -> Vec<AssetUtilization> {
+    let mut result = Vec::new(env);
+    for asset in crate::asset_positions::get_supported_assets(env).iter() {
+        result.push_back(get_asset_utilization(env, asset));
+    }
+    result
+}
+
+/// Accrue `user`'s interest and re-emit their health.
+///
+/// Permissionless keeper hook: liquidation bots can refresh an idle account's
+/// debt and get its health into the event stream without waiting for the
+/// user to act.
+///
+/// # Errors
+/// Returns `AnalyticsError::Overflow` if interest accrual overflows, or
+/// `AnalyticsError::DataNotFound` if a priced asset held by the account has
+/// no fresh price.
+pub fn poke(env: &Env, user: Address) -> Result<AccountHealth, AnalyticsError> {
+    let key = DepositDataKey::Position(user.clone());
+    if let Some(mut position) = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Position>(&key)
+    {
+        crate::borrow::accrue_interest(env, &user, &mut position)
+            .map_err(|_| AnalyticsError::Overflow)?;
+        env.storage().persistent().set(&key, &position);
+    }
+
+    let health = get_account_health(env, &user)?;
+    crate::ttl::bump_position(env, &user);
+    emit_health_factor_changed(env, &user, Symbol::new(env, "poke"));
+    Ok(health)
+}
+
+/// Summarize one account's position for frontends and liquidation bots.
+///
+/// Collateral, debt and health are computed as in
+/// [`get_account_health_batch`]. `max_borrowable` and `max_withdrawable`
+/// apply the minimum collateral ratio and the native XLM collateral factor,
+/// adjusted by the account's credit history;
+/// collateral still inside its holding period cannot be borrowed against.
+/// An account without a position is reported with zero values.
+///
+/// # Errors
+/// Returns `AnalyticsError::DataNotFound` if a priced asset held by the
+/// account has no fresh price.
+pub fn get_position_summary(env: &Env, user: &Address) -> Result<PositionSummary, AnalyticsError> {
+    let value = account_value(env, user)?;
+    let debt_value = value
+        .debt
+        .checked_add(value.interest)
+        .ok_or(AnalyticsError::Overflow)?;
+    let health_factor = health_factor_of(value.collateral, debt_value)?;
+
+    let min_ratio = crate::emode::min_collateral_ratio_for(env, user, None);
+    let collateral_factor = crate::risk_params::effective_collateral_factor(env, None, BASIS_POINTS);
+    let collateral_factor =
+        crate::credit_history::adjusted_collateral_factor(env, user, collateral_factor);
+
+    // Borrowing power: borrowable collateral * factor / min ratio, less current debt
+    let collateral = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, i128>(&DepositDataKey::CollateralBalance(user.clone()))
+        .unwrap_or(0);
+    let borrowable = crate::flash_guard::borrowable_collateral(env, user, collateral);
+    let borrowable_value = if borrowable < collateral {
+        value
+            .collateral
+            .checked_mul(borrowable)
+            .and_then(|v| v.checked_div(collateral))
+            .ok_or(AnalyticsError::Overflow)?
+    } else {
+        value.collateral
+    };
+    let max_debt = borrowable_value
+        .checked_mul(collateral_factor)
+        .map(|v| v / BASIS_POINTS)
+        .and_then(|v| v.checked_mul(BASIS_POINTS))
+        .and_then(|v| v.checked_div(min_ratio))
+        .ok_or(AnalyticsError::Overflow)?;
+    let max_borrowable = max_debt.saturating_sub(debt_value).max(0);
+
+    // Withdrawable: collateral above what the debt needs at the min ratio
+    let required = if debt_value == 0 {
+        0
+    } else if collateral_factor <= 0 {
+        value.collateral
+    } else {
+        debt_value
+            .checked_mul(min_ratio)
+            .ok_or(AnalyticsError::Overflow)?
+            .checked_add(collateral_factor - 1)
+            .ok_or(AnalyticsError::Overflow)?
+            / collateral_factor
+    };
+    let max_withdrawable = value.collateral.saturating_sub(required).max(0);
+
+    Ok(PositionSummary {
+        user: user.clone(),
+        collateral_value: value.collateral,
+        debt_value,
+        accrued_interest: value.interest,
+        health_factor,
+        max_borrowable,
+        max_withdrawable,
+    })
+}
+
+/// Position of `user` at oracle prices, with interest accrued up to now
+fn account_value(
+    env: &Env,
+    user: &Address,
+) -> Result<crate::asset_positions::PositionValue, AnalyticsError> {
+    let Ok(position) = get_user_position_summary(env, user) else {
+        return Ok(crate::asset_positions::PositionValue::default());
+    };
+
+    let pending = crate::borrow::calculate_accrued_interest(
+        env,
+        user,
+        position.debt,
+        position.last_accrual_time,
+    )
+    .unwrap_or(0);
+    let interest = position
+        .borrow_interest
+        .checked_add(pending)
+        .ok_or(AnalyticsError::Overflow)?;
+    crate::asset_positions::position_value(
+        env,
+        user,
+        position.collateral,
+        position.debt,
+        interest,
+    )
+    .map_err(|_| AnalyticsError::DataNotFound)
+}
+
+/// Health factor in basis points, `i128::MAX` with no debt
+pub(crate) fn health_factor_of(
+    collateral_value: i128,
+    debt_value: i128,
+) -> Result<i128, AnalyticsError> {
+    if debt_value == 0 {
+        return Ok(i128::MAX);
+    }
+    Ok(collateral_value
+        .checked_mul(BASIS_POINTS)
+        .ok_or(AnalyticsError::Overflow)?
+        / debt_value)
+}
+
 /// Map a health factor to a risk level (1–5).
 ///
 /// | Health Factor | Risk Level |
@@ -645,6 +1211,7 @@ pub fn generate_protocol_report(env: &Env) -> Result<ProtocolReport, AnalyticsEr
 
     let report = ProtocolReport {
         metrics,
+        fees: crate::origination_fee::get_fee_metrics(env),
         timestamp: env.ledger().timestamp(),
     };
 
@@ -0,0 +1,419 @@
+//! # Per-Asset Position Module
+//!
+//! The unified [`Position`](crate::deposit::Position) sums every asset a user
+//! has deposited or borrowed, so a USDC deposit and an XLM deposit look the
+//! same. This module keeps a `(user, asset)` ledger next to it:
+//!
+//! - `collateral` — collateral deposited in the asset and not yet withdrawn
+//!   or seized.
+//! - `debt` — principal borrowed in the asset and not yet repaid. Interest
+//!   stays in the unified position, which carries a single accrual clock.
+//!
+//! Deposits, withdrawals, borrows, repayments, liquidations, migrations and
+//! reserve-funded debt forgiveness all update the ledger. Every asset that has
+//! ever held a position is added to the registry returned by
+//! [`get_supported_assets`], and each user's open assets are listed by
+//! [`get_user_assets`]. [`open_position_count`] tracks how many users hold a
+//! position in each asset and [`get_asset_totals`] sums their balances.
+//! Every change is checkpointed with [`crate::rewards`] first, so liquidity
+//! mining rewards accrue on the balance held until then.
+//!
+//! ## Scope
+//! The ledger attributes balances to assets; it is not the source of truth
+//! for accounting. `Position(user)` and `CollateralBalance(user)` still hold
+//! the authoritative totals and the interest, and the ledger is reconciled
+//! against them (see below). Moving deposits, withdrawals, borrows and
+//! repayments onto `(user, asset)`-scoped storage with the totals derived from
+//! it is a separate storage migration, not done here.
+//!
+//! ## Unattributed Balances
+//! Collateral recorded before this ledger existed has no asset. A withdrawal
+//! may draw on the asset's own collateral plus that unattributed remainder
+//! (`unified collateral − Σ per-asset collateral`), so existing positions stay
+//! withdrawable while new deposits can only be withdrawn in the asset they
+//! were made in. [`crate::migrations::migrate`] books the remainder on the
+//! native asset.
+//!
+//! ## Valuation
+//! [`position_value`] prices each asset's balances through the oracle so
+//! health checks compare collateral and debt across assets by value rather
+//! than by raw amount.
+//!
+//! ## Collateral Toggle
+//! A user can exclude a deposited asset from collateral with
+//! [`set_use_as_collateral`], e.g. to keep it out of reach of liquidators.
+//! Excluded collateral is valued at zero, cannot be seized and can be
+//! withdrawn without a ratio check. Disabling is checked like withdrawing the
+//! asset's whole balance, so it cannot push the position below the minimum
+//! collateral ratio. The flag persists until the user changes it.
+//!
+//! ## Security
+//! - Only the flow modules write the ledger; there is no external setter.
+//! - Reductions from repayments, seizures and forgiveness saturate at zero so
+//!   the ledger can never block a flow that the unified position allows.
+
+#![allow(unused)]
+use crate::oracle::{ratio_value, OracleError};
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+/// Errors that can occur while updating per-asset positions
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AssetPositionError {
+    /// Withdrawal exceeds the collateral held in the asset
+    InsufficientCollateral = 1,
+    /// Arithmetic overflow occurred
+    Overflow = 2,
+    /// User holds no collateral in the asset
+    NoCollateral = 3,
+    /// Disabling the asset would breach the minimum collateral ratio
+    InsufficientCollateralRatio = 4,
+    /// A priced asset has no fresh price
+    PriceNotAvailable = 5,
+}
+
+/// Storage keys for per-asset position data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum AssetPositionDataKey {
+    /// Position of a user in one asset: AssetPosition
+    Position(Address, Option<Address>),
+    /// Assets a user has an open position in: Vec<Option<Address>>
+    UserAssets(Address),
+    /// Every asset that has held a position: Vec<Option<Address>>
+    SupportedAssets,
+    /// Asset a user has excluded from collateral: CollateralDisabled(user, asset) -> bool
+    CollateralDisabled(Address, Option<Address>),
+    /// Number of users with an open position in an asset: u32
+    OpenPositions(Option<Address>),
+    /// Sum of all users' positions in an asset: AssetPosition
+    Totals(Option<Address>),
+}
+
+/// A user's collateral and principal debt in one asset
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AssetPosition {
+    pub collateral: i128,
+    pub debt: i128,
+}
+
+/// Position of `user` in `asset`
+pub fn get_asset_position(env: &Env, user: &Address, asset: Option<Address>) -> AssetPosition {
+    env.storage()
+        .persistent()
+        .get(&AssetPositionDataKey::Position(user.clone(), asset))
+        .unwrap_or_default()
+}
+
+/// Assets `user` has an open position in
+pub fn get_user_assets(env: &Env, user: &Address) -> Vec<Option<Address>> {
+    env.storage()
+        .persistent()
+        .get(&AssetPositionDataKey::UserAssets(user.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Every asset that has held a position
+pub fn get_supported_assets(env: &Env) -> Vec<Option<Address>> {
+    env.storage()
+        .persistent()
+        .get(&AssetPositionDataKey::SupportedAssets)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Number of users with collateral or debt recorded in `asset`
+pub fn open_position_count(env: &Env, asset: Option<Address>) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&AssetPositionDataKey::OpenPositions(asset))
+        .unwrap_or(0)
+}
+
+/// Collateral and principal debt of all users in `asset`
+pub fn get_asset_totals(env: &Env, asset: Option<Address>) -> AssetPosition {
+    env.storage()
+        .persistent()
+        .get(&AssetPositionDataKey::Totals(asset))
+        .unwrap_or_default()
+}
+
+/// Collateral in the unified position that no asset accounts for
+pub fn unattributed_collateral(env: &Env, user: &Address, unified_collateral: i128) -> i128 {
+    let attributed = get_user_assets(env, user).iter().fold(0i128, |sum, asset| {
+        sum.saturating_add(get_asset_position(env, user, asset).collateral)
+    });
+    unified_collateral.saturating_sub(attributed).max(0)
+}
+
+/// Whether `user`'s deposits of `asset` count as collateral (default true)
+pub fn is_collateral_enabled(env: &Env, user: &Address, asset: &Option<Address>) -> bool {
+    !env.storage()
+        .persistent()
+        .has(&AssetPositionDataKey::CollateralDisabled(
+            user.clone(),
+            asset.clone(),
+        ))
+}
+
+/// Collateral `user` has excluded from collateral across all assets
+pub fn disabled_collateral(env: &Env, user: &Address) -> i128 {
+    get_user_assets(env, user)
+        .iter()
+        .filter(|asset| !is_collateral_enabled(env, user, asset))
+        .fold(0i128, |sum, asset| {
+            sum.saturating_add(get_asset_position(env, user, asset).collateral)
+        })
+}
+
+/// Include or exclude `user`'s deposits of `asset` from collateral
+///
+/// # Errors
+/// * `AssetPositionError::NoCollateral` - If disabling an asset the user holds
+///   no collateral in
+/// * `AssetPositionError::InsufficientCollateralRatio` - If disabling would
+///   breach the minimum collateral ratio
+/// * `AssetPositionError::PriceNotAvailable` - If a priced asset has no fresh price
+#[allow(deprecated)]
+pub fn set_use_as_collateral(
+    env: &Env,
+    user: Address,
+    asset: Option<Address>,
+    enabled: bool,
+) -> Result<(), AssetPositionError> {
+    user.require_auth();
+
+    let key = AssetPositionDataKey::CollateralDisabled(user.clone(), asset.clone());
+    if enabled {
+        env.storage().persistent().remove(&key);
+    } else if is_collateral_enabled(env, &user, &asset) {
+        let collateral = get_asset_position(env, &user, asset.clone()).collateral;
+        if collateral <= 0 {
+            return Err(AssetPositionError::NoCollateral);
+        }
+        crate::withdraw::validate_collateral_ratio_after_withdraw(
+            env,
+            &user,
+            collateral,
+            asset.as_ref(),
+        )
+        .map_err(|err| match err {
+            crate::withdraw::WithdrawError::PriceNotAvailable => {
+                AssetPositionError::PriceNotAvailable
+            }
+            _ => AssetPositionError::InsufficientCollateralRatio,
+        })?;
+        env.storage().persistent().set(&key, &true);
+    }
+
+    crate::analytics::emit_health_factor_changed(env, &user, Symbol::new(env, "use_as_collateral"));
+    let topics = (Symbol::new(env, "use_as_collateral_updated"), user);
+    env.events().publish(topics, (asset, enabled));
+
+    Ok(())
+}
+
+/// A user's unified balances valued at oracle prices
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PositionValue {
+    pub collateral: i128,
+    pub debt: i128,
+    pub interest: i128,
+}
+
+/// Value `user`'s unified balances for collateral ratio math
+///
+/// Each asset's collateral and principal is valued with
+/// [`crate::oracle::ratio_value`]; unattributed balances are valued 1:1.
+/// Collateral the user has excluded from collateral is valued at zero.
+/// `interest` has no asset of its own and is valued at the principal's
+/// average price.
+///
+/// # Errors
+/// * Any error from [`crate::oracle::ratio_value`]
+pub fn position_value(
+    env: &Env,
+    user: &Address,
+    collateral: i128,
+    debt: i128,
+    interest: i128,
+) -> Result<PositionValue, OracleError> {
+    let mut value = PositionValue::default();
+    let mut attributed = AssetPosition::default();
+    for asset in get_user_assets(env, user).iter() {
+        let position = get_asset_position(env, user, asset.clone());
+        if is_collateral_enabled(env, user, &asset) {
+            value.collateral = value
+                .collateral
+                .checked_add(ratio_value(env, asset.as_ref(), position.collateral)?)
+                .ok_or(OracleError::Overflow)?;
+        }
+        value.debt = value
+            .debt
+            .checked_add(ratio_value(env, asset.as_ref(), position.debt)?)
+            .ok_or(OracleError::Overflow)?;
+        attributed.collateral = attributed.collateral.saturating_add(position.collateral);
+        attributed.debt = attributed.debt.saturating_add(position.debt);
+    }
+
+    value.collateral = value
+        .collateral
+        .checked_add(collateral.saturating_sub(attributed.collateral).max(0))
+        .ok_or(OracleError::Overflow)?;
+    value.debt = value
+        .debt
+        .checked_add(debt.saturating_sub(attributed.debt).max(0))
+        .ok_or(OracleError::Overflow)?;
+    value.interest = if debt > 0 {
+        interest
+            .checked_mul(value.debt)
+            .and_then(|v| v.checked_div(debt))
+            .ok_or(OracleError::Overflow)?
+    } else {
+        interest
+    };
+
+    Ok(value)
+}
+
+/// Record a deposit of `amount` of `asset`
+pub fn record_deposit(
+    env: &Env,
+    user: &Address,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<(), AssetPositionError> {
+    let mut position = get_asset_position(env, user, asset.clone());
+    position.collateral = position
+        .collateral
+        .checked_add(amount)
+        .ok_or(AssetPositionError::Overflow)?;
+    save(env, user, asset, &position);
+    Ok(())
+}
+
+/// Record a withdrawal of `amount` of `asset`
+///
+/// # Errors
+/// * `AssetPositionError::InsufficientCollateral` - If `amount` exceeds the
+///   asset's collateral plus the unattributed remainder of `unified_collateral`
+pub fn record_withdrawal(
+    env: &Env,
+    user: &Address,
+    asset: Option<Address>,
+    amount: i128,
+    unified_collateral: i128,
+) -> Result<(), AssetPositionError> {
+    let mut position = get_asset_position(env, user, asset.clone());
+    let available =
+        position
+            .collateral
+            .saturating_add(unattributed_collateral(env, user, unified_collateral));
+    if amount > available {
+        return Err(AssetPositionError::InsufficientCollateral);
+    }
+
+    position.collateral = position.collateral.saturating_sub(amount).max(0);
+    save(env, user, asset, &position);
+    Ok(())
+}
+
+/// Record a borrow of `amount` of `asset`
+pub fn record_borrow(
+    env: &Env,
+    user: &Address,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<(), AssetPositionError> {
+    let mut position = get_asset_position(env, user, asset.clone());
+    position.debt = position
+        .debt
+        .checked_add(amount)
+        .ok_or(AssetPositionError::Overflow)?;
+    save(env, user, asset, &position);
+    Ok(())
+}
+
+/// Record `principal` of `asset` debt repaid, forgiven or liquidated
+pub fn record_repayment(env: &Env, user: &Address, asset: Option<Address>, principal: i128) {
+    if principal <= 0 {
+        return;
+    }
+    let mut position = get_asset_position(env, user, asset.clone());
+    position.debt = position.debt.saturating_sub(principal).max(0);
+    save(env, user, asset, &position);
+}
+
+/// Record `amount` of `asset` collateral seized in a liquidation
+pub fn record_seizure(env: &Env, user: &Address, asset: Option<Address>, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let mut position = get_asset_position(env, user, asset.clone());
+    position.collateral = position.collateral.saturating_sub(amount).max(0);
+    save(env, user, asset, &position);
+}
+
+fn save(env: &Env, user: &Address, asset: Option<Address>, position: &AssetPosition) {
+    let previous = get_asset_position(env, user, asset.clone());
+    let totals = get_asset_totals(env, asset.clone());
+    crate::rewards::checkpoint(env, user, asset.clone(), &previous, &totals);
+    let totals = AssetPosition {
+        collateral: (totals.collateral - previous.collateral + position.collateral).max(0),
+        debt: (totals.debt - previous.debt + position.debt).max(0),
+    };
+    env.storage()
+        .persistent()
+        .set(&AssetPositionDataKey::Totals(asset.clone()), &totals);
+
+    let key = AssetPositionDataKey::Position(user.clone(), asset.clone());
+    let mut assets = get_user_assets(env, user);
+    let index = assets.first_index_of(&asset);
+
+    if position.collateral == 0 && position.debt == 0 {
+        env.storage().persistent().remove(&key);
+        if let Some(index) = index {
+            assets.remove(index);
+            set_user_assets(env, user, &assets);
+            let open = open_position_count(env, asset.clone()).saturating_sub(1);
+            set_open_position_count(env, asset, open);
+        }
+        return;
+    }
+
+    env.storage().persistent().set(&key, position);
+    if index.is_none() {
+        assets.push_back(asset.clone());
+        set_user_assets(env, user, &assets);
+        let open = open_position_count(env, asset.clone()).saturating_add(1);
+        set_open_position_count(env, asset.clone(), open);
+
+        let mut supported = get_supported_assets(env);
+        if !supported.contains(&asset) {
+            supported.push_back(asset);
+            env.storage()
+                .persistent()
+                .set(&AssetPositionDataKey::SupportedAssets, &supported);
+        }
+    }
+}
+
+fn set_user_assets(env: &Env, user: &Address, assets: &Vec<Option<Address>>) {
+    let key = AssetPositionDataKey::UserAssets(user.clone());
+    if assets.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, assets);
+    }
+}
+
+fn set_open_position_count(env: &Env, asset: Option<Address>, count: u32) {
+    let key = AssetPositionDataKey::OpenPositions(asset);
+    if count == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &count);
+    }
+}
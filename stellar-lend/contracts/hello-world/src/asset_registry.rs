@@ -0,0 +1,379 @@
+//! # Asset Registry Module
+//!
+//! Markets added through a governance vote.
+//!
+//! A `ProposalType::ListAsset` proposal carries an asset's full configuration
+//! in one [`AssetListing`]: its deposit parameters (collateral factor, deposit
+//! cap, borrow fee), its oracle configuration and, optionally, its own
+//! interest rate curve. Executing the proposal applies each part through the
+//! owning module and records the asset in the [`AssetRegistry`], so a market
+//! goes live in a single atomic step instead of several separately voted
+//! parameter changes.
+//!
+//! [`get_listed_assets`] pages through the registry in listing order.
+//!
+//! ## Delisting
+//! A `ProposalType::DelistAsset` proposal starts an asset's sunset:
+//! 1. The asset is frozen (see [`crate::risk_management::set_asset_frozen`]),
+//!    so it takes no new deposits or borrows while repayments, withdrawals
+//!    and liquidations continue.
+//! 2. Its collateral factor ramps linearly from the configured value to zero
+//!    over the proposal's ramp duration ([`sunset_collateral_factor`]), giving
+//!    borrowers time to move to other collateral.
+//! 3. Once no user holds a position in the asset, anyone may call
+//!    [`finalize_delisting`] to remove it from the registry.
+//!
+//! [`get_sunset_status`] reports the progress. The asset stays frozen after
+//! removal; relisting it requires lifting the freeze as well.
+//!
+//! ## Security
+//! - Assets are only listed and delisted through an executed governance
+//!   proposal; there is no admin shortcut.
+//! - The whole listing is validated when the proposal is created and again
+//!   when it executes; nothing is written unless every part is valid.
+//! - An asset can be listed once; later changes go through the existing
+//!   per-parameter proposals.
+//! - Finalizing is permissionless but only succeeds once no open position is
+//!   recorded in the asset.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::deposit::AssetParams;
+use crate::interest_rate::AssetRateModel;
+use crate::oracle::AssetOracleConfig;
+
+/// Errors that can occur when listing assets
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AssetRegistryError {
+    /// The asset is already listed
+    AlreadyListed = 1,
+    /// The asset address is not a valid market
+    InvalidAsset = 2,
+    /// A part of the listing configuration is out of range
+    InvalidConfig = 3,
+    /// The asset is not listed
+    NotListed = 4,
+    /// The asset is already being delisted
+    AlreadyDelisting = 5,
+    /// The asset is not being delisted
+    NotDelisting = 6,
+    /// Users still hold positions in the asset
+    PositionsOpen = 7,
+}
+
+/// Storage keys for asset registry data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum AssetRegistryDataKey {
+    /// Every listed asset in listing order: AssetRegistry
+    Registry,
+    /// Configuration an asset was listed with: ListedAsset
+    Listing(Address),
+    /// Delisting schedule of an asset being sunset: Sunset
+    Sunset(Address),
+}
+
+/// Full configuration of a new market
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssetListing {
+    /// Collateral factor, deposit cap and borrow fee
+    pub params: AssetParams,
+    /// Price feed, decimals, heartbeat and deviation limit
+    pub oracle: AssetOracleConfig,
+    /// Own interest rate curve (`None` = the pool curve)
+    pub rate_model: Option<AssetRateModel>,
+}
+
+/// Listed assets in listing order
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssetRegistry {
+    pub assets: Vec<Address>,
+}
+
+/// Registry entry of a listed asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListedAsset {
+    /// Configuration applied at listing (later updates are not reflected)
+    pub listing: AssetListing,
+    /// Timestamp the listing was executed
+    pub listed_at: u64,
+}
+
+/// Delisting schedule of an asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sunset {
+    /// Timestamp the delisting proposal executed
+    pub started_at: u64,
+    /// Seconds over which the collateral factor ramps down to zero
+    pub ramp_duration: u64,
+}
+
+/// Progress of an asset's delisting
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SunsetStatus {
+    /// Timestamp the delisting started
+    pub started_at: u64,
+    /// Timestamp the collateral factor reaches zero
+    pub ramp_ends_at: u64,
+    /// Share of the configured collateral factor still applied (bps)
+    pub collateral_factor_scale_bps: i128,
+    /// Users still holding a position in the asset
+    pub open_positions: u32,
+    /// Whether [`finalize_delisting`] would succeed now
+    pub can_finalize: bool,
+}
+
+/// Basis points scale (100% = 10_000)
+const BASIS_POINTS_SCALE: i128 = 10_000;
+
+/// Check a listing without applying it
+///
+/// # Errors
+/// * `AssetRegistryError::InvalidAsset` - If the asset is this contract
+/// * `AssetRegistryError::AlreadyListed` - If the asset is already listed
+/// * `AssetRegistryError::InvalidConfig` - If the parameters, oracle
+///   configuration or rate curve are out of range
+pub fn validate_listing(
+    env: &Env,
+    asset: &Address,
+    listing: &AssetListing,
+) -> Result<(), AssetRegistryError> {
+    if *asset == env.current_contract_address() {
+        return Err(AssetRegistryError::InvalidAsset);
+    }
+    if is_listed(env, asset) {
+        return Err(AssetRegistryError::AlreadyListed);
+    }
+
+    let rate_model_valid = match listing.rate_model {
+        Some(ref model) => crate::interest_rate::validate_asset_rate_model(model).is_ok(),
+        None => true,
+    };
+    if crate::deposit::validate_asset_params(&listing.params).is_err()
+        || crate::oracle::validate_asset_oracle_config(env, &listing.oracle).is_err()
+        || !rate_model_valid
+    {
+        return Err(AssetRegistryError::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// List a new market with its full configuration
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * Same as [`validate_listing`]
+#[allow(deprecated)]
+pub fn list_asset(
+    env: &Env,
+    asset: Address,
+    listing: AssetListing,
+) -> Result<(), AssetRegistryError> {
+    validate_listing(env, &asset, &listing)?;
+
+    crate::deposit::set_asset_params(env, asset.clone(), listing.params.clone())
+        .map_err(|_| AssetRegistryError::InvalidConfig)?;
+    crate::oracle::apply_asset_oracle_config(env, asset.clone(), listing.oracle.clone())
+        .map_err(|_| AssetRegistryError::InvalidConfig)?;
+    if listing.rate_model.is_some() {
+        crate::interest_rate::set_asset_rate_model(
+            env,
+            Some(asset.clone()),
+            listing.rate_model.clone(),
+        )
+        .map_err(|_| AssetRegistryError::InvalidConfig)?;
+    }
+
+    let mut registry = get_registry(env);
+    registry.assets.push_back(asset.clone());
+    env.storage()
+        .persistent()
+        .set(&AssetRegistryDataKey::Registry, &registry);
+
+    let listed_at = env.ledger().timestamp();
+    env.storage().persistent().set(
+        &AssetRegistryDataKey::Listing(asset.clone()),
+        &ListedAsset {
+            listing: listing.clone(),
+            listed_at,
+        },
+    );
+
+    let topics = (Symbol::new(env, "asset_listed"), asset);
+    env.events().publish(topics, (listing, listed_at));
+
+    Ok(())
+}
+
+/// Whether `asset` has been listed
+pub fn is_listed(env: &Env, asset: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&AssetRegistryDataKey::Listing(asset.clone()))
+}
+
+/// Registry entry of `asset`, if listed
+pub fn get_listing(env: &Env, asset: &Address) -> Option<ListedAsset> {
+    env.storage()
+        .persistent()
+        .get(&AssetRegistryDataKey::Listing(asset.clone()))
+}
+
+/// Number of listed assets
+pub fn listed_asset_count(env: &Env) -> u32 {
+    get_registry(env).assets.len()
+}
+
+/// Listed assets in listing order, skipping `offset` and returning at most `limit`
+pub fn get_listed_assets(env: &Env, offset: u32, limit: u32) -> Vec<Address> {
+    let assets = get_registry(env).assets;
+    let mut result = Vec::new(env);
+    let end = offset.saturating_add(limit).min(assets.len());
+    for i in offset..end {
+        if let Some(asset) = assets.get(i) {
+            result.push_back(asset);
+        }
+    }
+    result
+}
+
+/// Check that `asset` can start delisting
+///
+/// # Errors
+/// * `AssetRegistryError::NotListed` - If the asset is not listed
+/// * `AssetRegistryError::AlreadyDelisting` - If a delisting is in progress
+pub fn validate_delisting(env: &Env, asset: &Address) -> Result<(), AssetRegistryError> {
+    if !is_listed(env, asset) {
+        return Err(AssetRegistryError::NotListed);
+    }
+    if get_sunset(env, asset).is_some() {
+        return Err(AssetRegistryError::AlreadyDelisting);
+    }
+    Ok(())
+}
+
+/// Start delisting `asset`: freeze it and ramp its collateral factor to zero
+/// over `ramp_duration` seconds
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * Same as [`validate_delisting`]
+#[allow(deprecated)]
+pub fn start_delisting(
+    env: &Env,
+    asset: Address,
+    ramp_duration: u64,
+) -> Result<(), AssetRegistryError> {
+    validate_delisting(env, &asset)?;
+
+    crate::risk_management::store_asset_frozen(env, Some(asset.clone()), true);
+    let sunset = Sunset {
+        started_at: env.ledger().timestamp(),
+        ramp_duration,
+    };
+    env.storage()
+        .persistent()
+        .set(&AssetRegistryDataKey::Sunset(asset.clone()), &sunset);
+
+    let topics = (Symbol::new(env, "asset_delisting_started"), asset);
+    env.events().publish(topics, sunset);
+
+    Ok(())
+}
+
+/// Remove a delisted asset from the registry once no positions remain
+///
+/// # Errors
+/// * `AssetRegistryError::NotDelisting` - If no delisting is in progress
+/// * `AssetRegistryError::PositionsOpen` - If users still hold positions in the asset
+#[allow(deprecated)]
+pub fn finalize_delisting(env: &Env, asset: Address) -> Result<(), AssetRegistryError> {
+    if get_sunset(env, &asset).is_none() {
+        return Err(AssetRegistryError::NotDelisting);
+    }
+    if crate::asset_positions::open_position_count(env, Some(asset.clone())) > 0 {
+        return Err(AssetRegistryError::PositionsOpen);
+    }
+
+    let mut registry = get_registry(env);
+    if let Some(index) = registry.assets.first_index_of(&asset) {
+        registry.assets.remove(index);
+        env.storage()
+            .persistent()
+            .set(&AssetRegistryDataKey::Registry, &registry);
+    }
+    env.storage()
+        .persistent()
+        .remove(&AssetRegistryDataKey::Listing(asset.clone()));
+    env.storage()
+        .persistent()
+        .remove(&AssetRegistryDataKey::Sunset(asset.clone()));
+
+    let topics = (Symbol::new(env, "asset_delisted"), asset);
+    env.events().publish(topics, env.ledger().timestamp());
+
+    Ok(())
+}
+
+/// Delisting schedule of `asset`, if one is in progress
+pub fn get_sunset(env: &Env, asset: &Address) -> Option<Sunset> {
+    env.storage()
+        .persistent()
+        .get(&AssetRegistryDataKey::Sunset(asset.clone()))
+}
+
+/// Delisting progress of `asset`, if one is in progress
+pub fn get_sunset_status(env: &Env, asset: &Address) -> Option<SunsetStatus> {
+    let sunset = get_sunset(env, asset)?;
+    let open_positions = crate::asset_positions::open_position_count(env, Some(asset.clone()));
+    Some(SunsetStatus {
+        started_at: sunset.started_at,
+        ramp_ends_at: sunset.started_at.saturating_add(sunset.ramp_duration),
+        collateral_factor_scale_bps: ramp_scale_bps(env, &sunset),
+        open_positions,
+        can_finalize: open_positions == 0,
+    })
+}
+
+/// Scale `collateral_factor` down by `asset`'s delisting ramp
+///
+/// Returns `collateral_factor` unchanged when no delisting is in progress.
+pub fn sunset_collateral_factor(env: &Env, asset: &Address, collateral_factor: i128) -> i128 {
+    match get_sunset(env, asset) {
+        Some(sunset) => collateral_factor
+            .checked_mul(ramp_scale_bps(env, &sunset))
+            .map(|v| v / BASIS_POINTS_SCALE)
+            .unwrap_or(0),
+        None => collateral_factor,
+    }
+}
+
+/// Share of the collateral factor left at the current point of the ramp (bps)
+fn ramp_scale_bps(env: &Env, sunset: &Sunset) -> i128 {
+    let elapsed = env.ledger().timestamp().saturating_sub(sunset.started_at);
+    if elapsed >= sunset.ramp_duration {
+        return 0;
+    }
+    let remaining = (sunset.ramp_duration - elapsed) as i128;
+    remaining * BASIS_POINTS_SCALE / sunset.ramp_duration as i128
+}
+
+fn get_registry(env: &Env) -> AssetRegistry {
+    env.storage()
+        .persistent()
+        .get(&AssetRegistryDataKey::Registry)
+        .unwrap_or_else(|| AssetRegistry {
+            assets: Vec::new(env),
+        })
+}
@@ -12,20 +12,80 @@
 //! rate from the `interest_rate` module. The rate is based on protocol utilization
 //! following a kink-based piecewise linear model.
 //!
+//! ## Origination Fee
+//! Each borrow pays an origination fee out of the amount received (see
+//! [`crate::origination_fee`]); the full amount is added to debt. The fee is
+//! split between the insurance fund, the borrower's referrer (see
+//! [`crate::referral`]) and the treasury reserve, and
+//! [`borrow_asset_with_receipt`] returns the breakdown.
+//!
 //! ## Invariants
 //! - A user must have collateral deposited before borrowing.
 //! - The collateral ratio must remain at or above the minimum after the borrow.
 //! - Borrow amount must not exceed the maximum borrowable given current collateral.
+//! - Debt in the borrowed asset must reach the asset's minimum debt, if any
+//!   (see [`crate::dust`]).
+//! - The borrowed amount must fit in the asset's pool liquidity as recorded by
+//!   the per-asset ledger, supply less borrows (see
+//!   [`crate::strategy::get_pool_liquidity`]); reserves and escrow held in the
+//!   same token are never lent out. It is transferred to the borrower, native
+//!   XLM through the native asset contract once configured.
+//!
+//! ## Valuation
+//! Collateral, debt and the borrow itself are compared by oracle value (see
+//! [`crate::oracle::ratio_value`]), so borrowing one asset against another
+//! accounts for their prices.
 
 #![allow(unused)]
-use soroban_sdk::{contracterror, Address, Env, IntoVal, Map, Symbol, Val, Vec};
+use soroban_sdk::{contracterror, contracttype, Address, Env, IntoVal, Map, Symbol, Val, Vec};
 
 use crate::deposit::{
     add_activity_log, emit_analytics_updated_event, emit_position_updated_event,
     emit_user_activity_tracked_event, update_protocol_analytics, update_user_analytics, Activity,
     AssetParams, DepositDataKey, Position, ProtocolAnalytics, UserAnalytics,
 };
-use crate::events::{emit_borrow, BorrowEvent};
+use crate::errors::ProtocolError;
+use crate::events::{
+    emit_borrow, emit_borrow_v1, BorrowEvent, BorrowEventV1, EVENT_SCHEMA_VERSION,
+};
+
+/// Read-only quote for a prospective borrow
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BorrowPreview {
+    /// Requested borrow amount (added to debt)
+    pub amount: i128,
+    /// Origination fee after any staker discount
+    pub fee_amount: i128,
+    /// Amount the borrower would receive
+    pub receive_amount: i128,
+    /// Discount applied to the origination fee, staker or loyalty tier (basis points)
+    pub fee_discount_bps: i128,
+    /// Current borrow rate of the borrowed asset (basis points)
+    pub borrow_rate_bps: i128,
+    /// Total debt (principal + interest) after the borrow
+    pub total_debt_after: i128,
+    /// Remaining borrow capacity before this borrow
+    pub max_borrowable: i128,
+}
+
+/// Outcome of an executed borrow
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BorrowReceipt {
+    /// Total debt (principal + interest) after the borrow
+    pub total_debt: i128,
+    /// Amount sent to the recipient (borrowed amount minus the origination fee)
+    pub amount_received: i128,
+    /// Origination fee after any staker discount
+    pub origination_fee: i128,
+    /// Part of the fee routed to the insurance fund
+    pub insurance_share: i128,
+    /// Part of the fee set aside for the borrower's referrer
+    pub referral_share: i128,
+    /// Part of the fee credited to the treasury reserve
+    pub treasury_share: i128,
+}
 
 /// Errors that can occur during borrow operations
 #[contracterror]
@@ -50,6 +110,22 @@ pub enum BorrowError {
     MaxBorrowExceeded = 8,
     /// Asset is not enabled for borrowing
     AssetNotEnabled = 9,
+    /// User deposited in the current ledger
+    SameLedgerDeposit = 10,
+    /// Volume circuit breaker is tripped for the asset
+    CircuitBreakerTripped = 11,
+    /// User exceeded the per-user borrow rate limit
+    RateLimited = 12,
+    /// Asset is frozen (wind-down freeze or depeg breaker)
+    AssetFrozen = 13,
+    /// A priced asset in the position has no fresh oracle price
+    PriceNotAvailable = 14,
+    /// Delegated borrow exceeds the credit allowance
+    InsufficientCreditAllowance = 15,
+    /// Borrow would leave debt in the asset below its minimum size
+    DustPosition = 16,
+    /// The pool does not hold enough of the asset to pay out the borrow
+    InsufficientLiquidity = 17,
 }
 
 /// Minimum collateral ratio (in basis points, e.g., 15000 = 150%)
@@ -57,57 +133,50 @@ pub enum BorrowError {
 // Minimum collateral ratio is now managed by the risk_params module
 // const MIN_COLLATERAL_RATIO_BPS: i128 = 15000; // 150% (Legacy)
 
-/// Annual interest rate in basis points (e.g., 500 = 5% per year)
-/// This is a simple constant rate model - in production, this would be more sophisticated
-// Interest rate is now calculated dynamically based on utilization
-// See interest_rate module for details
-/// Calculate interest accrued since last accrual time
-/// Uses simple interest: interest = principal * rate * time
-/// Calculate accrued interest using dynamic interest rate
-/// Uses the current borrow rate based on protocol utilization
-fn calculate_accrued_interest(
+/// Interest accrued on `principal` since `last_accrual_time`
+///
+/// Follows each borrowed asset's borrow index, net of any staker spread
+/// discount; see [`crate::interest_rate::pending_position_interest`].
+pub(crate) fn calculate_accrued_interest(
     env: &Env,
+    user: &Address,
     principal: i128,
     last_accrual_time: u64,
-    current_time: u64,
 ) -> Result<i128, BorrowError> {
     if principal == 0 {
         return Ok(0);
     }
-
-    if current_time <= last_accrual_time {
-        return Ok(0);
-    }
-
-    // Get current borrow rate (in basis points)
-    let rate_bps =
-        crate::interest_rate::calculate_borrow_rate(env).map_err(|_| BorrowError::Overflow)?;
-
-    // Calculate interest using the dynamic rate
-    crate::interest_rate::calculate_accrued_interest(
-        principal,
-        last_accrual_time,
-        current_time,
-        rate_bps,
-    )
-    .map_err(|_| BorrowError::Overflow)
+    crate::interest_rate::pending_position_interest(env, user, principal, last_accrual_time)
+        .map_err(|_| BorrowError::Overflow)
 }
 
 /// Accrue interest on a position
 /// Updates the position's borrow_interest and last_accrual_time
-fn accrue_interest(env: &Env, position: &mut Position) -> Result<(), BorrowError> {
+pub(crate) fn accrue_interest(
+    env: &Env,
+    user: &Address,
+    position: &mut Position,
+) -> Result<(), BorrowError> {
     let current_time = env.ledger().timestamp();
 
+    // Moves the position's borrow index snapshots forward, or drops them once repaid
+    let new_interest = crate::interest_rate::accrue_position_interest(
+        env,
+        user,
+        position.debt,
+        position.last_accrual_time,
+    )
+    .map_err(|_| BorrowError::Overflow)?;
+
     if position.debt == 0 {
         position.borrow_interest = 0;
         position.last_accrual_time = current_time;
+        // Starts the capitalization clock for the borrow that follows
+        crate::capitalization::capitalize(env, user, position)
+            .map_err(|_| BorrowError::Overflow)?;
         return Ok(());
     }
 
-    // Calculate new interest accrued using dynamic rate
-    let new_interest =
-        calculate_accrued_interest(env, position.debt, position.last_accrual_time, current_time)?;
-
     // Add to existing interest
     position.borrow_interest = position
         .borrow_interest
@@ -117,13 +186,15 @@ fn accrue_interest(env: &Env, position: &mut Position) -> Result<(), BorrowError
     // Update last accrual time
     position.last_accrual_time = current_time;
 
+    crate::capitalization::capitalize(env, user, position).map_err(|_| BorrowError::Overflow)?;
+
     Ok(())
 }
 
 /// Calculate collateral ratio
 /// Returns (collateral_value * collateral_factor) / (debt + interest) in basis points
 /// Returns None if debt is zero (infinite ratio)
-fn calculate_collateral_ratio(
+pub(crate) fn calculate_collateral_ratio(
     collateral: i128,
     debt: i128,
     interest: i128,
@@ -182,12 +253,42 @@ fn calculate_max_borrowable(
     }
 }
 
-/// Validate that borrow would maintain minimum collateral ratio
+/// Borrowable collateral, principal and interest valued at oracle prices
+///
+/// Collateral still inside its holding period is removed pro rata.
+fn valued_position(
+    env: &Env,
+    user: &Address,
+    position: &Position,
+    collateral: i128,
+) -> Result<crate::asset_positions::PositionValue, BorrowError> {
+    let mut value = crate::asset_positions::position_value(
+        env,
+        user,
+        collateral,
+        position.debt,
+        position.borrow_interest,
+    )
+    .map_err(|_| BorrowError::PriceNotAvailable)?;
+
+    let borrowable = crate::flash_guard::borrowable_collateral(env, user, collateral);
+    if borrowable < collateral {
+        value.collateral = value
+            .collateral
+            .checked_mul(borrowable)
+            .and_then(|v| v.checked_div(collateral))
+            .ok_or(BorrowError::Overflow)?;
+    }
+    Ok(value)
+}
+
+/// Validate that a borrow worth `borrow_value` would maintain `min_ratio`
 fn validate_collateral_ratio_after_borrow(
     env: &Env,
     user: &Address,
-    borrow_amount: i128,
+    borrow_value: i128,
     collateral_factor: i128,
+    min_ratio: i128,
 ) -> Result<(), BorrowError> {
     // Get user position
     let position_key = DepositDataKey::Position(user.clone());
@@ -208,21 +309,21 @@ fn validate_collateral_ratio_after_borrow(
     if current_collateral == 0 {
         return Err(BorrowError::InsufficientCollateral);
     }
+    let value = valued_position(env, user, &position, current_collateral)?;
 
     // Calculate new debt after borrow
-    let new_debt = position
+    let new_debt = value
         .debt
-        .checked_add(borrow_amount)
+        .checked_add(borrow_value)
         .ok_or(BorrowError::Overflow)?;
 
     // Calculate new collateral ratio
     if let Some(new_ratio) = calculate_collateral_ratio(
-        current_collateral,
+        value.collateral,
         new_debt,
-        position.borrow_interest,
+        value.interest,
         collateral_factor,
     ) {
-        let min_ratio = crate::risk_params::get_min_collateral_ratio(env).unwrap_or(15000);
         if new_ratio < min_ratio {
             return Err(BorrowError::InsufficientCollateralRatio);
         }
@@ -236,12 +337,46 @@ fn validate_collateral_ratio_after_borrow(
 }
 
 /// Borrow assets from the protocol
+///
+/// Returns the total debt after the borrow; see [`borrow_asset_with_receipt`]
+/// for the origination fee breakdown. The amount received is transferred from
+/// the pool to `user`, native XLM through its Stellar Asset Contract.
+///
+/// # Errors
+/// * `BorrowError::InsufficientLiquidity` - If `amount` exceeds the pool's
+///   lendable liquidity, supply less borrows, or the pool cannot pay it out
+///   even after recalling strategy principal
 pub fn borrow_asset(
     env: &Env,
     user: Address,
     asset: Option<Address>,
     amount: i128,
 ) -> Result<i128, BorrowError> {
+    borrow_to(env, user.clone(), user, asset, amount).map(|receipt| receipt.total_debt)
+}
+
+/// Borrow assets from the protocol, returning the amount received and the
+/// origination fee with its insurance/treasury split
+pub fn borrow_asset_with_receipt(
+    env: &Env,
+    user: Address,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<BorrowReceipt, BorrowError> {
+    borrow_to(env, user.clone(), user, asset, amount)
+}
+
+/// Borrow against `user`'s position and send the proceeds to `recipient`
+///
+/// The debt is always booked on `user`; `recipient` differs only for
+/// delegated borrows (see the credit_delegation module).
+pub(crate) fn borrow_to(
+    env: &Env,
+    user: Address,
+    recipient: Address,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<BorrowReceipt, BorrowError> {
     // Validate amount
     if amount <= 0 {
         return Err(BorrowError::InvalidAmount);
@@ -251,7 +386,12 @@ pub fn borrow_asset(
     let _guard =
         crate::reentrancy::ReentrancyGuard::new(env).map_err(|_| BorrowError::Reentrancy)?;
 
-    // Check if borrows are paused
+    // Check emergency pause and the borrow switch
+    if crate::hot_config::load(env).is_paused(env, crate::risk_management::PauseOperation::Borrow) {
+        return Err(BorrowError::BorrowPaused);
+    }
+
+    // Legacy pause switches
     let pause_switches_key = DepositDataKey::PauseSwitches;
     if let Some(pause_map) = env
         .storage()
@@ -265,9 +405,40 @@ pub fn borrow_asset(
         }
     }
 
+    // Per-asset borrow switch
+    if crate::risk_management::is_asset_paused(
+        env,
+        asset.clone(),
+        crate::risk_management::PauseOperation::Borrow,
+    ) {
+        return Err(BorrowError::BorrowPaused);
+    }
+
+    // Reject borrowing against collateral deposited in this same ledger
+    crate::flash_guard::require_not_same_ledger(env, &user)
+        .map_err(|_| BorrowError::SameLedgerDeposit)?;
+
+    // Track per-ledger volume; rejected while the breaker is tripped
+    crate::circuit_breaker::record_volume(env, asset.clone(), amount)
+        .map_err(|_| BorrowError::CircuitBreakerTripped)?;
+
+    // Per-user rate limit
+    crate::throttle::check_and_record(env, &user, crate::throttle::ThrottledOperation::Borrow)
+        .map_err(|_| BorrowError::RateLimited)?;
+
     // Get current timestamp
     let timestamp = env.ledger().timestamp();
 
+    // Checkpoint the asset indexes and settle supply interest before utilization changes
+    crate::interest_rate::accrue_asset(env, asset.clone()).map_err(|_| BorrowError::Overflow)?;
+    crate::supply_interest::settle_supply_interest(env, &user, asset.clone())
+        .map_err(|_| BorrowError::Overflow)?;
+
+    // Reject assets frozen for wind-down or by the depeg breaker
+    if crate::risk_management::is_asset_frozen(env, asset.clone()) {
+        return Err(BorrowError::AssetFrozen);
+    }
+
     // Validate asset if provided
     if let Some(ref asset_addr) = asset {
         // Validate asset address - ensure it's not the contract itself
@@ -303,7 +474,7 @@ pub fn borrow_asset(
         });
 
     // Accrue interest on existing debt before borrowing
-    accrue_interest(env, &mut position)?;
+    accrue_interest(env, &user, &mut position)?;
 
     // Get current collateral balance
     let collateral_key = DepositDataKey::CollateralBalance(user.clone());
@@ -333,42 +504,50 @@ pub fn borrow_asset(
     } else {
         10000
     };
+    let collateral_factor =
+        crate::risk_params::effective_collateral_factor(env, asset.as_ref(), collateral_factor);
+    let collateral_factor =
+        crate::credit_history::adjusted_collateral_factor(env, &user, collateral_factor);
 
-    // Get borrow fee bps if provided
-    let borrow_fee_bps = if let Some(asset_addr) = asset.as_ref() {
-        let asset_params_key = DepositDataKey::AssetParams(asset_addr.clone());
-        if let Some(params) = env
-            .storage()
-            .persistent()
-            .get::<DepositDataKey, AssetParams>(&asset_params_key)
-        {
-            params.borrow_fee_bps
-        } else {
-            0
-        }
-    } else {
-        0
-    };
+    // Origination fee: the asset's own borrow fee, or the protocol rate
+    let borrow_fee_bps =
+        crate::origination_fee::fee_bps_for(env, asset_params(env, asset.as_ref()).as_ref());
 
-    // Get minimum collateral ratio from risk params
-    let min_ratio = crate::risk_params::get_min_collateral_ratio(env).unwrap_or(15000);
+    // Get minimum collateral ratio from risk params, or the user's e-mode category
+    let min_ratio = crate::emode::min_collateral_ratio_for(env, &user, Some(&asset));
 
-    // Calculate maximum borrowable amount
+    // Value the position and the borrow at oracle prices; collateral still
+    // inside its holding period does not count yet
+    let value = valued_position(env, &user, &position, current_collateral)?;
+    let amount_value = crate::oracle::ratio_value(env, asset.as_ref(), amount)
+        .map_err(|_| BorrowError::PriceNotAvailable)?;
+
+    // Calculate maximum borrowable value
     let max_borrowable = calculate_max_borrowable(
-        current_collateral,
-        position.debt,
-        position.borrow_interest,
+        value.collateral,
+        value.debt,
+        value.interest,
         collateral_factor,
         min_ratio,
     )?;
 
-    // Check if borrow amount exceeds maximum
-    if amount > max_borrowable {
+    // Check if borrow value exceeds maximum
+    if amount_value > max_borrowable {
         return Err(BorrowError::MaxBorrowExceeded);
     }
 
     // Validate collateral ratio after borrow
-    validate_collateral_ratio_after_borrow(env, &user, amount, collateral_factor)?;
+    validate_collateral_ratio_after_borrow(env, &user, amount_value, collateral_factor, min_ratio)?;
+
+    // Reject borrows that leave a debt too small to liquidate profitably
+    let asset_debt = crate::asset_positions::get_asset_position(env, &user, asset.clone())
+        .debt
+        .checked_add(amount)
+        .ok_or(BorrowError::Overflow)?;
+    let min_debt = crate::dust::get_min_position_size(env, asset.clone()).min_debt;
+    if crate::dust::is_dust(asset_debt, min_debt) {
+        return Err(BorrowError::DustPosition);
+    }
 
     // Calculate new debt
     let new_debt = position
@@ -376,12 +555,17 @@ pub fn borrow_asset(
         .checked_add(amount)
         .ok_or(BorrowError::Overflow)?;
 
-    // Calculate borrow fee
+    // Calculate borrow fee (discounted for governance stakers or loyalty tiers)
     let fee_amount = amount
         .checked_mul(borrow_fee_bps)
         .ok_or(BorrowError::Overflow)?
         .checked_div(10000)
         .ok_or(BorrowError::Overflow)?;
+    let fee_amount = crate::fee_discount::apply_discount(
+        fee_amount,
+        crate::fee_discount::borrow_fee_discount_bps(env, &user),
+    )
+    .ok_or(BorrowError::Overflow)?;
 
     // Amount user actually receives
     let receive_amount = amount
@@ -392,44 +576,74 @@ pub fn borrow_asset(
         return Err(BorrowError::InvalidAmount);
     }
 
+    // Only supplied liquidity not already lent out can be borrowed; reserves
+    // and escrow held in the same token are not part of it
+    let token = asset
+        .clone()
+        .or_else(|| crate::deposit::get_native_asset_address(env));
+    if let Some(ref token_addr) = token {
+        if crate::strategy::get_pool_liquidity(env, token_addr) < amount {
+            return Err(BorrowError::InsufficientLiquidity);
+        }
+    }
+
     // Update position
     position.debt = new_debt;
     position.last_accrual_time = timestamp;
     env.storage().persistent().set(&position_key, &position);
-
-    // Handle asset transfer - contract sends tokens to user
-    if let Some(ref asset_addr) = asset {
-        // Skip actual token transfers in unit tests to avoid Storage error with non-existent contracts
-        #[cfg(not(test))]
-        {
-            let token_client = soroban_sdk::token::Client::new(env, asset_addr);
-
-            // Check contract balance
-            let contract_balance = token_client.balance(&env.current_contract_address());
-            if contract_balance < amount {
-                return Err(BorrowError::InsufficientCollateral);
-            }
-
-            token_client.transfer(&env.current_contract_address(), &user, &receive_amount);
+    crate::asset_positions::record_borrow(env, &user, asset.clone(), amount)
+        .map_err(|_| BorrowError::Overflow)?;
+    crate::interest_rate::snapshot_position_index(env, &user, asset.clone())
+        .map_err(|_| BorrowError::Overflow)?;
+    crate::credit_history::record_borrow(env, &user);
+
+    // Handle asset transfer - contract sends tokens to user; native XLM goes
+    // through its Stellar Asset Contract
+    if let Some(token_addr) = token {
+        let token_client = soroban_sdk::token::Client::new(env, &token_addr);
+
+        // Recall strategy principal if idle liquidity falls short
+        crate::strategy::ensure_liquidity(env, &token_addr, amount)
+            .map_err(|_| BorrowError::InsufficientLiquidity)?;
+
+        // Check contract balance
+        let contract_balance = token_client.balance(&env.current_contract_address());
+        if contract_balance < amount {
+            return Err(BorrowError::InsufficientLiquidity);
         }
 
-        // Credit fee to protocol reserve
-        if fee_amount > 0 {
-            let reserve_key = DepositDataKey::ProtocolReserve(asset.clone());
-            let current_reserve = env
-                .storage()
-                .persistent()
-                .get::<DepositDataKey, i128>(&reserve_key)
-                .unwrap_or(0);
-            env.storage().persistent().set(
-                &reserve_key,
-                &(current_reserve
-                    .checked_add(fee_amount)
-                    .ok_or(BorrowError::Overflow)?),
-            );
-        }
+        token_client.transfer(&env.current_contract_address(), &recipient, &receive_amount);
     }
 
+    // Credit fee to the insurance fund share, the referrer's reward, then the treasury reserve
+    let after_insurance = crate::insurance::route_fee_share(env, asset.clone(), fee_amount)
+        .map_err(|_| BorrowError::Overflow)?;
+    let referral_share =
+        crate::referral::record_borrow(env, &user, asset.clone(), amount, after_insurance);
+    let treasury_share = after_insurance - referral_share;
+    if fee_amount > 0 {
+        let reserve_key = DepositDataKey::ProtocolReserve(asset.clone());
+        let current_reserve = env
+            .storage()
+            .persistent()
+            .get::<DepositDataKey, i128>(&reserve_key)
+            .unwrap_or(0);
+        env.storage().persistent().set(
+            &reserve_key,
+            &(current_reserve
+                .checked_add(treasury_share)
+                .ok_or(BorrowError::Overflow)?),
+        );
+        crate::revenue::record(
+            env,
+            asset.clone(),
+            crate::revenue::RevenueSource::Fee,
+            fee_amount,
+        );
+    }
+    let insurance_share = fee_amount - after_insurance;
+    crate::origination_fee::record(env, fee_amount, insurance_share, referral_share);
+
     // Update user analytics
     update_user_analytics_borrow(env, &user, amount, timestamp)?;
 
@@ -460,18 +674,298 @@ pub fn borrow_asset(
             timestamp,
         },
     );
+    emit_borrow_v1(
+        env,
+        BorrowEventV1 {
+            schema_version: EVENT_SCHEMA_VERSION,
+            user: user.clone(),
+            asset: asset.clone(),
+            amount,
+            origination_fee: fee_amount,
+            collateral_after: position.collateral,
+            total_debt_after: position.debt.saturating_add(position.borrow_interest),
+            timestamp,
+        },
+    );
 
     // Emit position updated event
     emit_position_updated_event(env, &user, &position, Symbol::new(env, "borrow"), timestamp);
+    crate::ttl::bump_position(env, &user);
+    crate::analytics::emit_health_factor_changed(env, &user, Symbol::new(env, "borrow"));
     emit_analytics_updated_event(env, &user, "borrow", amount, timestamp);
     emit_user_activity_tracked_event(env, &user, Symbol::new(env, "borrow"), amount, timestamp);
 
-    // Return total debt
     let total_debt = position
         .debt
         .checked_add(position.borrow_interest)
         .ok_or(BorrowError::Overflow)?;
-    Ok(total_debt)
+    Ok(BorrowReceipt {
+        total_debt,
+        amount_received: receive_amount,
+        origination_fee: fee_amount,
+        insurance_share,
+        referral_share,
+        treasury_share,
+    })
+}
+
+/// Deposit collateral and borrow against it in one call
+///
+/// Runs [`crate::deposit::deposit_collateral`] then [`borrow_asset`]. Deposits
+/// never check the collateral ratio, so the position is checked once, after
+/// the borrow, against the combined result. Either step failing reverts both.
+///
+/// The same-ledger guard still applies: while it is enabled the borrow is
+/// rejected with `SameLedgerDeposit`, exactly as two separate calls would be.
+///
+/// # Returns
+/// Total debt (principal + interest) after the borrow
+///
+/// # Errors
+/// Any error of the deposit or the borrow, converted to [`ProtocolError`]
+pub fn deposit_and_borrow(
+    env: &Env,
+    user: Address,
+    collateral_asset: Option<Address>,
+    collateral_amount: i128,
+    debt_asset: Option<Address>,
+    borrow_amount: i128,
+) -> Result<i128, ProtocolError> {
+    if collateral_amount <= 0 || borrow_amount <= 0 {
+        return Err(ProtocolError::InvalidAmount);
+    }
+
+    crate::deposit::deposit_collateral(env, user.clone(), collateral_asset, collateral_amount)?;
+    Ok(borrow_asset(env, user, debt_asset, borrow_amount)?)
+}
+
+/// Quote a borrow without mutating state
+///
+/// Mirrors the fee, discount, accrual, and capacity logic of [`borrow_asset`]
+/// so frontends can display exactly what a borrow would cost.
+///
+/// # Errors
+/// * `BorrowError::InvalidAmount` - If amount <= 0 or the fee consumes the amount
+/// * `BorrowError::InsufficientCollateral` - If the user has no collateral
+/// * `BorrowError::PriceNotAvailable` - If a priced asset has no fresh price
+/// * `BorrowError::Overflow` - If arithmetic overflow occurs
+pub fn preview_borrow(
+    env: &Env,
+    user: Address,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<BorrowPreview, BorrowError> {
+    if amount <= 0 {
+        return Err(BorrowError::InvalidAmount);
+    }
+
+    let position = pending_position(env, &user)?;
+
+    let current_collateral = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, i128>(&DepositDataKey::CollateralBalance(user.clone()))
+        .unwrap_or(0);
+    if current_collateral == 0 {
+        return Err(BorrowError::InsufficientCollateral);
+    }
+    let value = valued_position(env, &user, &position, current_collateral)?;
+
+    let params = asset_params(env, asset.as_ref());
+    let collateral_factor = borrow_collateral_factor(env, &user, asset.as_ref(), params.as_ref());
+    let borrow_fee_bps = crate::origination_fee::fee_bps_for(env, params.as_ref());
+
+    let min_ratio = crate::emode::min_collateral_ratio_for(env, &user, Some(&asset));
+    let max_borrowable = calculate_max_borrowable(
+        value.collateral,
+        value.debt,
+        value.interest,
+        collateral_factor,
+        min_ratio,
+    )?;
+    let max_borrowable = crate::oracle::ratio_amount(env, asset.as_ref(), max_borrowable)
+        .map_err(|_| BorrowError::PriceNotAvailable)?;
+
+    let fee_discount_bps = crate::fee_discount::borrow_fee_discount_bps(env, &user);
+    let fee_amount = amount
+        .checked_mul(borrow_fee_bps)
+        .ok_or(BorrowError::Overflow)?
+        .checked_div(10000)
+        .ok_or(BorrowError::Overflow)?;
+    let fee_amount = crate::fee_discount::apply_discount(fee_amount, fee_discount_bps)
+        .ok_or(BorrowError::Overflow)?;
+    let receive_amount = amount
+        .checked_sub(fee_amount)
+        .ok_or(BorrowError::Overflow)?;
+    if receive_amount <= 0 {
+        return Err(BorrowError::InvalidAmount);
+    }
+
+    let borrow_rate_bps = crate::interest_rate::calculate_asset_borrow_rate(env, asset.clone())
+        .map_err(|_| BorrowError::Overflow)?;
+    let borrow_rate_bps =
+        crate::fee_discount::discounted_borrow_rate(env, &user, borrow_rate_bps);
+
+    let total_debt_after = position
+        .debt
+        .checked_add(position.borrow_interest)
+        .and_then(|v| v.checked_add(amount))
+        .ok_or(BorrowError::Overflow)?;
+
+    Ok(BorrowPreview {
+        amount,
+        fee_amount,
+        receive_amount,
+        fee_discount_bps,
+        borrow_rate_bps,
+        total_debt_after,
+        max_borrowable,
+    })
+}
+
+/// Largest amount of `asset` `user` could borrow right now
+///
+/// Applies the same limits as [`borrow_asset`]: collateral at oracle prices
+/// net of collateral still inside its holding period, the collateral factor,
+/// the minimum collateral ratio, existing debt with interest accrued up to
+/// now, the pool's lendable liquidity and the asset's minimum debt. Returns
+/// `0` whenever the borrow would be rejected outright: borrows paused, the
+/// asset frozen, disabled or behind a tripped circuit breaker, or deposited
+/// in this same ledger.
+///
+/// The result is the amount added to debt; the origination fee comes out of it.
+///
+/// # Errors
+/// * `BorrowError::PriceNotAvailable` - If a priced asset has no fresh price
+/// * `BorrowError::Overflow` - If arithmetic overflow occurs
+pub fn get_max_borrowable(
+    env: &Env,
+    user: Address,
+    asset: Option<Address>,
+) -> Result<i128, BorrowError> {
+    if is_borrow_paused(env, asset.clone())
+        || crate::risk_management::is_asset_frozen(env, asset.clone())
+        || crate::circuit_breaker::is_tripped(env, asset.clone())
+        || crate::flash_guard::require_not_same_ledger(env, &user).is_err()
+    {
+        return Ok(0);
+    }
+
+    let params = asset_params(env, asset.as_ref());
+    if let Some(ref asset_addr) = asset {
+        if asset_addr == &env.current_contract_address()
+            || params.as_ref().is_some_and(|p| !p.deposit_enabled)
+        {
+            return Ok(0);
+        }
+    }
+
+    let current_collateral = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, i128>(&DepositDataKey::CollateralBalance(user.clone()))
+        .unwrap_or(0);
+    if current_collateral == 0 {
+        return Ok(0);
+    }
+
+    let position = pending_position(env, &user)?;
+    let value = valued_position(env, &user, &position, current_collateral)?;
+    let collateral_factor = borrow_collateral_factor(env, &user, asset.as_ref(), params.as_ref());
+    let min_ratio = crate::emode::min_collateral_ratio_for(env, &user, Some(&asset));
+    let max_value = calculate_max_borrowable(
+        value.collateral,
+        value.debt,
+        value.interest,
+        collateral_factor,
+        min_ratio,
+    )?;
+    let max_borrowable = crate::oracle::ratio_amount(env, asset.as_ref(), max_value)
+        .map_err(|_| BorrowError::PriceNotAvailable)?;
+
+    let max_borrowable = match asset
+        .clone()
+        .or_else(|| crate::deposit::get_native_asset_address(env))
+    {
+        Some(token_addr) => {
+            max_borrowable.min(crate::strategy::get_pool_liquidity(env, &token_addr))
+        }
+        None => max_borrowable,
+    };
+
+    // A borrow that cannot reach the asset's minimum debt is not possible at all
+    let asset_debt = crate::asset_positions::get_asset_position(env, &user, asset.clone()).debt;
+    let min_debt = crate::dust::get_min_position_size(env, asset).min_debt;
+    if crate::dust::is_dust(asset_debt.saturating_add(max_borrowable), min_debt) {
+        return Ok(0);
+    }
+
+    Ok(max_borrowable.max(0))
+}
+
+/// `user`'s stored position with interest accrued up to now, without
+/// persisting anything
+fn pending_position(env: &Env, user: &Address) -> Result<Position, BorrowError> {
+    let timestamp = env.ledger().timestamp();
+    let mut position = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Position>(&DepositDataKey::Position(user.clone()))
+        .unwrap_or(Position {
+            collateral: 0,
+            debt: 0,
+            borrow_interest: 0,
+            last_accrual_time: timestamp,
+        });
+    // Pending interest only: accruing would move the stored index snapshots
+    position.borrow_interest =
+        calculate_accrued_interest(env, user, position.debt, position.last_accrual_time)?
+            .checked_add(position.borrow_interest)
+            .ok_or(BorrowError::Overflow)?;
+    Ok(position)
+}
+
+/// Stored parameters of `asset`, if any
+fn asset_params(env: &Env, asset: Option<&Address>) -> Option<AssetParams> {
+    asset.and_then(|asset_addr| {
+        env.storage()
+            .persistent()
+            .get::<DepositDataKey, AssetParams>(&DepositDataKey::AssetParams(asset_addr.clone()))
+    })
+}
+
+/// Collateral factor applied when `user` borrows `asset`
+fn borrow_collateral_factor(
+    env: &Env,
+    user: &Address,
+    asset: Option<&Address>,
+    params: Option<&AssetParams>,
+) -> i128 {
+    let collateral_factor = crate::risk_params::effective_collateral_factor(
+        env,
+        asset,
+        params.map(|p| p.collateral_factor).unwrap_or(10000),
+    );
+    crate::credit_history::adjusted_collateral_factor(env, user, collateral_factor)
+}
+
+/// Whether borrows of `asset` are paused by the emergency pause, the borrow
+/// switch, the asset's own switch or the legacy pause switches
+fn is_borrow_paused(env: &Env, asset: Option<Address>) -> bool {
+    if crate::hot_config::load(env).is_paused(env, crate::risk_management::PauseOperation::Borrow)
+        || crate::risk_management::is_asset_paused(
+            env,
+            asset,
+            crate::risk_management::PauseOperation::Borrow,
+        )
+    {
+        return true;
+    }
+    env.storage()
+        .persistent()
+        .get::<DepositDataKey, Map<Symbol, bool>>(&DepositDataKey::PauseSwitches)
+        .and_then(|pause_map| pause_map.get(Symbol::new(env, "pause_borrow")))
+        .unwrap_or(false)
 }
 
 /// Update user analytics after borrow
@@ -481,6 +975,9 @@ fn update_user_analytics_borrow(
     amount: i128,
     timestamp: u64,
 ) -> Result<(), BorrowError> {
+    if !crate::analytics::tracks_user_activity(env) {
+        return Ok(());
+    }
     let analytics_key = DepositDataKey::UserAnalytics(user.clone());
     #[allow(clippy::unnecessary_lazy_evaluations)]
     let mut analytics = env
@@ -524,13 +1021,17 @@ fn update_user_analytics_borrow(
 
     analytics.transaction_count = analytics.transaction_count.saturating_add(1);
     analytics.last_activity = timestamp;
+    analytics.loyalty_tier = crate::loyalty::tier_for(env, &analytics, timestamp);
 
     env.storage().persistent().set(&analytics_key, &analytics);
     Ok(())
 }
 
 /// Update protocol analytics after borrow
-fn update_protocol_analytics_borrow(env: &Env, amount: i128) -> Result<(), BorrowError> {
+pub(crate) fn update_protocol_analytics_borrow(
+    env: &Env,
+    amount: i128,
+) -> Result<(), BorrowError> {
     let analytics_key = DepositDataKey::ProtocolAnalytics;
     let mut analytics = env
         .storage()
@@ -0,0 +1,136 @@
+//! # Interest Capitalization Module
+//!
+//! Periodically rolls a borrower's accrued `borrow_interest` into
+//! `Position.debt`, so interest starts accruing on interest and long-lived
+//! positions compound.
+//!
+//! With an interval of `N` seconds configured, every interest accrual checks
+//! whether at least `N` seconds have passed since the position was last
+//! capitalized; if so, the outstanding interest moves into principal. The
+//! clock starts when a debt-free position is accrued (i.e. as a new borrow
+//! begins); positions already open when the interval is configured start
+//! their clock at their next accrual.
+//!
+//! Capitalization only runs after accrual and before any repayment logic, so
+//! repayments still settle `borrow_interest` first and principal second.
+//!
+//! ## Security
+//! - The interval is admin-only, disabled (`0`) by default and bounded below
+//!   by [`MIN_CAPITALIZATION_INTERVAL`].
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::deposit::Position;
+
+/// Shortest allowed capitalization interval (1 hour)
+pub const MIN_CAPITALIZATION_INTERVAL: u64 = 3_600;
+
+/// Errors that can occur during interest capitalization
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CapitalizationError {
+    /// Caller is not authorized
+    Unauthorized = 1,
+    /// A parameter is out of range
+    InvalidParameter = 2,
+    /// Arithmetic overflow occurred
+    Overflow = 3,
+}
+
+/// Storage keys for capitalization data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum CapitalizationDataKey {
+    /// Seconds between capitalizations (0 = disabled): u64
+    Interval,
+    /// Timestamp of a borrower's last capitalization: u64
+    LastCapitalized(Address),
+}
+
+/// Set the capitalization interval (admin only); `0` disables it
+///
+/// # Errors
+/// * `CapitalizationError::Unauthorized` - If caller is not admin
+/// * `CapitalizationError::InvalidParameter` - If a non-zero interval is
+///   below `MIN_CAPITALIZATION_INTERVAL`
+#[allow(deprecated)]
+pub fn set_interval(env: &Env, caller: Address, seconds: u64) -> Result<(), CapitalizationError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| CapitalizationError::Unauthorized)?;
+
+    if seconds != 0 && seconds < MIN_CAPITALIZATION_INTERVAL {
+        return Err(CapitalizationError::InvalidParameter);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&CapitalizationDataKey::Interval, &seconds);
+
+    let topics = (Symbol::new(env, "capitalization_interval_updated"), caller);
+    env.events().publish(topics, seconds);
+
+    Ok(())
+}
+
+/// Seconds between capitalizations (default 0 = disabled)
+pub fn get_interval(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&CapitalizationDataKey::Interval)
+        .unwrap_or(0)
+}
+
+/// Timestamp of a borrower's last capitalization, if the clock has started
+pub fn get_last_capitalized(env: &Env, user: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&CapitalizationDataKey::LastCapitalized(user.clone()))
+}
+
+/// Roll accrued interest into principal if the interval has passed
+///
+/// Called right after interest accrual; the caller persists `position`.
+/// Returns the amount capitalized.
+///
+/// # Errors
+/// * `CapitalizationError::Overflow` - If arithmetic overflow occurs
+#[allow(deprecated)]
+pub fn capitalize(
+    env: &Env,
+    user: &Address,
+    position: &mut Position,
+) -> Result<i128, CapitalizationError> {
+    let interval = get_interval(env);
+    if interval == 0 {
+        return Ok(0);
+    }
+
+    let key = CapitalizationDataKey::LastCapitalized(user.clone());
+    let now = env.ledger().timestamp();
+    let last = match get_last_capitalized(env, user) {
+        Some(last) if position.debt > 0 || position.borrow_interest > 0 => last,
+        _ => {
+            env.storage().persistent().set(&key, &now);
+            return Ok(0);
+        }
+    };
+    if now.saturating_sub(last) < interval || position.borrow_interest <= 0 {
+        return Ok(0);
+    }
+
+    let amount = position.borrow_interest;
+    position.debt = position
+        .debt
+        .checked_add(amount)
+        .ok_or(CapitalizationError::Overflow)?;
+    position.borrow_interest = 0;
+    env.storage().persistent().set(&key, &now);
+
+    let topics = (Symbol::new(env, "interest_capitalized"), user.clone());
+    env.events().publish(topics, (amount, position.debt));
+
+    Ok(amount)
+}
@@ -0,0 +1,250 @@
+//! # Circuit Breaker Module
+//!
+//! Per-asset volume circuit breaker for borrows and withdrawals.
+//!
+//! Every borrow and withdrawal adds to the asset's volume for the current
+//! ledger. When a new ledger starts, the previous ledger's volume is folded
+//! into an exponentially weighted average of "normal" per-ledger volume
+//! (the first active ledger seeds the average).
+//!
+//! If the current ledger's volume exceeds
+//! `max(average × trip_multiple_bps / 10_000, min_volume)`, the breaker trips:
+//! borrows and withdrawals of the asset are rejected for `cooldown_ledgers`
+//! ledgers and a `circuit_breaker_tripped` alert event is emitted.
+//!
+//! Failing calls revert their storage writes, so the call that pushes volume
+//! over the threshold completes and trips the breaker for the calls after it.
+//!
+//! ## Security
+//! - Configuration is admin-only; assets without a configuration are never
+//!   throttled.
+//! - The admin or an executed governance proposal can clear a tripped breaker
+//!   before its cooldown ends.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+/// Basis points scale (100% = 10_000)
+pub const BASIS_POINTS_SCALE: i128 = 10_000;
+
+/// Weight of the latest ledger's volume in the average (10%)
+pub const VOLUME_EWMA_WEIGHT_BPS: i128 = 1_000;
+
+/// Errors that can occur during circuit breaker operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CircuitBreakerError {
+    /// Caller is not authorized
+    Unauthorized = 1,
+    /// A parameter is out of range
+    InvalidParameter = 2,
+    /// Breaker is tripped for the asset
+    Tripped = 3,
+    /// Arithmetic overflow occurred
+    Overflow = 4,
+}
+
+/// Storage keys for circuit breaker data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum CircuitBreakerDataKey {
+    /// Breaker configuration per asset: CircuitBreakerConfig
+    Config(Option<Address>),
+    /// Volume tracking per asset: VolumeState
+    Volume(Option<Address>),
+    /// Ledger sequence until which the asset is paused: u32
+    TrippedUntil(Option<Address>),
+}
+
+/// Circuit breaker configuration for an asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CircuitBreakerConfig {
+    /// Multiple of average per-ledger volume that trips the breaker (basis points, e.g. 30_000 = 3x)
+    pub trip_multiple_bps: i128,
+    /// Volume below which the breaker never trips
+    pub min_volume: i128,
+    /// Ledgers the asset stays paused after a trip
+    pub cooldown_ledgers: u32,
+}
+
+/// Per-ledger volume tracking for an asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VolumeState {
+    /// Ledger the `volume` belongs to
+    pub ledger: u32,
+    /// Borrow + withdrawal volume in `ledger`
+    pub volume: i128,
+    /// Average per-ledger volume of previous active ledgers
+    pub average: i128,
+}
+
+/// Configure the circuit breaker for an asset (admin only)
+///
+/// # Errors
+/// * `CircuitBreakerError::Unauthorized` - If caller is not admin
+/// * `CircuitBreakerError::InvalidParameter` - If the multiple is at most 1x,
+///   `min_volume` is negative or the cooldown is zero
+#[allow(deprecated)]
+pub fn set_config(
+    env: &Env,
+    caller: Address,
+    asset: Option<Address>,
+    config: CircuitBreakerConfig,
+) -> Result<(), CircuitBreakerError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| CircuitBreakerError::Unauthorized)?;
+
+    if config.trip_multiple_bps <= BASIS_POINTS_SCALE
+        || config.min_volume < 0
+        || config.cooldown_ledgers == 0
+    {
+        return Err(CircuitBreakerError::InvalidParameter);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&CircuitBreakerDataKey::Config(asset.clone()), &config);
+
+    let topics = (Symbol::new(env, "circuit_breaker_updated"), caller);
+    env.events().publish(
+        topics,
+        (
+            asset,
+            config.trip_multiple_bps,
+            config.min_volume,
+            config.cooldown_ledgers,
+        ),
+    );
+
+    Ok(())
+}
+
+/// Get the circuit breaker configuration for an asset
+pub fn get_config(env: &Env, asset: Option<Address>) -> Option<CircuitBreakerConfig> {
+    env.storage()
+        .persistent()
+        .get(&CircuitBreakerDataKey::Config(asset))
+}
+
+/// Get the volume tracking state for an asset
+pub fn get_volume_state(env: &Env, asset: Option<Address>) -> Option<VolumeState> {
+    env.storage()
+        .persistent()
+        .get(&CircuitBreakerDataKey::Volume(asset))
+}
+
+/// Whether the breaker is currently tripped for an asset
+pub fn is_tripped(env: &Env, asset: Option<Address>) -> bool {
+    let until: u32 = env
+        .storage()
+        .persistent()
+        .get(&CircuitBreakerDataKey::TrippedUntil(asset))
+        .unwrap_or(0);
+    env.ledger().sequence() < until
+}
+
+/// Record borrow or withdrawal volume for an asset, tripping the breaker if
+/// the ledger's volume is abnormal
+///
+/// # Errors
+/// * `CircuitBreakerError::Tripped` - If the breaker is already tripped
+/// * `CircuitBreakerError::Overflow` - If arithmetic overflow occurs
+#[allow(deprecated)]
+pub fn record_volume(
+    env: &Env,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<(), CircuitBreakerError> {
+    let config = match get_config(env, asset.clone()) {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+    if is_tripped(env, asset.clone()) {
+        return Err(CircuitBreakerError::Tripped);
+    }
+
+    let sequence = env.ledger().sequence();
+    let mut state = get_volume_state(env, asset.clone()).unwrap_or(VolumeState {
+        ledger: sequence,
+        volume: 0,
+        average: 0,
+    });
+
+    // Fold the previous active ledger into the average; the first one seeds it
+    if state.ledger != sequence {
+        state.average = if state.average == 0 {
+            state.volume
+        } else {
+            state
+                .volume
+                .checked_mul(VOLUME_EWMA_WEIGHT_BPS)
+                .and_then(|v| {
+                    v.checked_add(
+                        state
+                            .average
+                            .checked_mul(BASIS_POINTS_SCALE - VOLUME_EWMA_WEIGHT_BPS)?,
+                    )
+                })
+                .ok_or(CircuitBreakerError::Overflow)?
+                / BASIS_POINTS_SCALE
+        };
+        state.ledger = sequence;
+        state.volume = 0;
+    }
+
+    state.volume = state
+        .volume
+        .checked_add(amount.max(0))
+        .ok_or(CircuitBreakerError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&CircuitBreakerDataKey::Volume(asset.clone()), &state);
+
+    let threshold = state
+        .average
+        .checked_mul(config.trip_multiple_bps)
+        .ok_or(CircuitBreakerError::Overflow)?
+        / BASIS_POINTS_SCALE;
+    let threshold = threshold.max(config.min_volume);
+
+    if state.volume > threshold {
+        let until = sequence.saturating_add(config.cooldown_ledgers);
+        env.storage()
+            .persistent()
+            .set(&CircuitBreakerDataKey::TrippedUntil(asset.clone()), &until);
+
+        let topics = (Symbol::new(env, "circuit_breaker_tripped"),);
+        env.events()
+            .publish(topics, (asset, state.volume, state.average, until));
+    }
+
+    Ok(())
+}
+
+/// Clear a tripped breaker (admin only)
+///
+/// # Errors
+/// * `CircuitBreakerError::Unauthorized` - If caller is not admin
+pub fn reset(env: &Env, caller: Address, asset: Option<Address>) -> Result<(), CircuitBreakerError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::PauseGuardian)
+        .map_err(|_| CircuitBreakerError::Unauthorized)?;
+    clear(env, asset);
+    Ok(())
+}
+
+/// Clear a tripped breaker without an authorization check
+///
+/// Used by governance execution.
+#[allow(deprecated)]
+pub fn clear(env: &Env, asset: Option<Address>) {
+    env.storage()
+        .persistent()
+        .remove(&CircuitBreakerDataKey::TrippedUntil(asset.clone()));
+
+    let topics = (Symbol::new(env, "circuit_breaker_cleared"),);
+    env.events().publish(topics, asset);
+}
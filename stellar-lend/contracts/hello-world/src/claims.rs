@@ -0,0 +1,252 @@
+//! # Claims Module
+//!
+//! Merkle-proof claims for retroactive airdrops and off-chain-computed reward
+//! campaigns.
+//!
+//! Governance posts a campaign consisting of a token, a funding total and the
+//! Merkle root of `(address, amount)` entitlements. Users claim their amount by
+//! presenting a proof, so no per-user balance has to be written on-chain until
+//! it is claimed.
+//!
+//! ## Tree Format
+//! - `leaf = sha256(xdr(address) || amount as 16 big-endian bytes)`
+//! - Internal nodes hash the two children in ascending order:
+//!   `node = sha256(min(a, b) || max(a, b))`, so proofs carry no direction bits.
+//!
+//! ## Security
+//! - Campaigns can only be created through an executed governance proposal.
+//! - Each address can claim at most once per campaign.
+//! - Total claims can never exceed the campaign's funded total.
+//! - Unclaimed funding is tracked per token, so reconciliation treats it as
+//!   held for claimants rather than as surplus.
+
+#![allow(unused)]
+use soroban_sdk::{
+    contracterror, contracttype, token::TokenClient, xdr::ToXdr, Address, Bytes, BytesN, Env,
+    Symbol, Vec,
+};
+
+/// Maximum proof length (supports trees of up to 2^32 entitlements)
+pub const MAX_PROOF_LENGTH: u32 = 32;
+
+/// Errors that can occur during claim operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ClaimsError {
+    /// Campaign does not exist
+    CampaignNotFound = 1,
+    /// Address has already claimed from the campaign
+    AlreadyClaimed = 2,
+    /// Proof does not match the campaign root
+    InvalidProof = 3,
+    /// A parameter is out of range
+    InvalidParameter = 4,
+    /// Claim would exceed the campaign's funded total
+    CampaignExhausted = 5,
+    /// Pool and strategy together cannot cover the transfer
+    InsufficientLiquidity = 6,
+}
+
+/// Storage keys for claims data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum ClaimsDataKey {
+    /// Campaign by id: ClaimCampaign
+    Campaign(u64),
+    /// Next campaign id: u64
+    NextCampaignId,
+    /// Whether an address has claimed from a campaign: bool
+    Claimed(u64, Address),
+    /// Funding not yet claimed across all campaigns per token: i128
+    Outstanding(Address),
+}
+
+/// A Merkle-root claim campaign
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClaimCampaign {
+    pub id: u64,
+    /// Token paid out by the campaign
+    pub token: Address,
+    /// Merkle root of `(address, amount)` entitlements
+    pub merkle_root: BytesN<32>,
+    /// Total amount funded for the campaign
+    pub total_amount: i128,
+    /// Amount claimed so far
+    pub claimed_amount: i128,
+    pub created_at: u64,
+}
+
+/// Create a claim campaign
+///
+/// Only reachable through an executed governance proposal. The contract must
+/// hold at least `total_amount` of `token` for claims to succeed.
+///
+/// # Returns
+/// The new campaign id
+///
+/// # Errors
+/// * `ClaimsError::InvalidParameter` - If `total_amount <= 0`
+#[allow(deprecated)]
+pub fn create_campaign(
+    env: &Env,
+    token: Address,
+    merkle_root: BytesN<32>,
+    total_amount: i128,
+) -> Result<u64, ClaimsError> {
+    if total_amount <= 0 {
+        return Err(ClaimsError::InvalidParameter);
+    }
+
+    let id: u64 = env
+        .storage()
+        .persistent()
+        .get(&ClaimsDataKey::NextCampaignId)
+        .unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&ClaimsDataKey::NextCampaignId, &(id + 1));
+
+    let campaign = ClaimCampaign {
+        id,
+        token,
+        merkle_root,
+        total_amount,
+        claimed_amount: 0,
+        created_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&ClaimsDataKey::Campaign(id), &campaign);
+    let outstanding = get_outstanding(env, &campaign.token).saturating_add(total_amount);
+    env.storage().persistent().set(
+        &ClaimsDataKey::Outstanding(campaign.token.clone()),
+        &outstanding,
+    );
+
+    let topics = (Symbol::new(env, "claim_campaign_created"), id);
+    env.events()
+        .publish(topics, (campaign.token, campaign.merkle_root, total_amount));
+
+    Ok(id)
+}
+
+/// Claim an entitlement from a campaign
+///
+/// # Arguments
+/// * `campaign_id` - Campaign to claim from
+/// * `user` - Entitled address (must authorize)
+/// * `amount` - Entitled amount, as committed in the tree
+/// * `proof` - Sibling hashes from the leaf up to the root
+///
+/// # Errors
+/// * `ClaimsError::CampaignNotFound` - If the campaign does not exist
+/// * `ClaimsError::AlreadyClaimed` - If `user` already claimed
+/// * `ClaimsError::InvalidParameter` - If `amount <= 0` or the proof is too long
+/// * `ClaimsError::InvalidProof` - If the proof does not match the root
+/// * `ClaimsError::CampaignExhausted` - If the claim exceeds the funded total
+/// * `ClaimsError::InsufficientLiquidity` - If pool and strategy cannot cover the claim
+#[allow(deprecated)]
+pub fn claim(
+    env: &Env,
+    campaign_id: u64,
+    user: Address,
+    amount: i128,
+    proof: Vec<BytesN<32>>,
+) -> Result<i128, ClaimsError> {
+    user.require_auth();
+
+    if amount <= 0 || proof.len() > MAX_PROOF_LENGTH {
+        return Err(ClaimsError::InvalidParameter);
+    }
+
+    let campaign_key = ClaimsDataKey::Campaign(campaign_id);
+    let mut campaign: ClaimCampaign = env
+        .storage()
+        .persistent()
+        .get(&campaign_key)
+        .ok_or(ClaimsError::CampaignNotFound)?;
+
+    let claimed_key = ClaimsDataKey::Claimed(campaign_id, user.clone());
+    if env.storage().persistent().has(&claimed_key) {
+        return Err(ClaimsError::AlreadyClaimed);
+    }
+
+    let mut node = leaf_hash(env, &user, amount);
+    for sibling in proof.iter() {
+        node = hash_pair(env, &node, &sibling);
+    }
+    if node != campaign.merkle_root {
+        return Err(ClaimsError::InvalidProof);
+    }
+
+    let claimed_amount = campaign
+        .claimed_amount
+        .checked_add(amount)
+        .filter(|total| *total <= campaign.total_amount)
+        .ok_or(ClaimsError::CampaignExhausted)?;
+
+    // Effects before the token transfer
+    campaign.claimed_amount = claimed_amount;
+    env.storage().persistent().set(&campaign_key, &campaign);
+    env.storage().persistent().set(&claimed_key, &true);
+    let outstanding = get_outstanding(env, &campaign.token)
+        .saturating_sub(amount)
+        .max(0);
+    env.storage().persistent().set(
+        &ClaimsDataKey::Outstanding(campaign.token.clone()),
+        &outstanding,
+    );
+
+    crate::strategy::ensure_liquidity(env, &campaign.token, amount)
+        .map_err(|_| ClaimsError::InsufficientLiquidity)?;
+    TokenClient::new(env, &campaign.token).transfer(
+        &env.current_contract_address(),
+        &user,
+        &amount,
+    );
+
+    let topics = (Symbol::new(env, "claimed"), campaign_id, user);
+    env.events().publish(topics, amount);
+
+    Ok(amount)
+}
+
+/// Get a campaign by id
+pub fn get_campaign(env: &Env, campaign_id: u64) -> Option<ClaimCampaign> {
+    env.storage()
+        .persistent()
+        .get(&ClaimsDataKey::Campaign(campaign_id))
+}
+
+/// Campaign funding in `token` that has not been claimed yet
+pub fn get_outstanding(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ClaimsDataKey::Outstanding(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Whether an address has claimed from a campaign
+pub fn has_claimed(env: &Env, campaign_id: u64, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&ClaimsDataKey::Claimed(campaign_id, user.clone()))
+}
+
+/// Hash of a single `(address, amount)` entitlement
+pub fn leaf_hash(env: &Env, user: &Address, amount: i128) -> BytesN<32> {
+    let mut data = user.clone().to_xdr(env);
+    data.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    env.crypto().sha256(&data).to_bytes()
+}
+
+/// Hash of two sibling nodes, in ascending order
+pub fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from_array(env, &first.to_array());
+    data.append(&Bytes::from_array(env, &second.to_array()));
+    env.crypto().sha256(&data).to_bytes()
+}
@@ -3,7 +3,7 @@
 //! Provides key-value configuration storage for the lending protocol.
 //! Allows the admin to set, get, backup, and restore configuration parameters.
 
-use crate::risk_management::require_admin;
+use crate::admin::{require_role, Role};
 use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Val, Vec};
 
 /// Errors that can occur during configuration operations
@@ -35,7 +35,7 @@ pub enum ConfigDataKey {
 /// # Returns
 /// Returns Ok(()) on success
 pub fn config_set(env: &Env, caller: Address, key: Symbol, value: Val) -> Result<(), ConfigError> {
-    require_admin(env, &caller).map_err(|_| ConfigError::Unauthorized)?;
+    require_role(env, &caller, Role::RiskAdmin).map_err(|_| ConfigError::Unauthorized)?;
 
     let storage_key = ConfigDataKey::ConfigKey(key);
     env.storage().persistent().set(&storage_key, &value);
@@ -74,7 +74,7 @@ pub fn config_backup(
     caller: Address,
     keys: Vec<Symbol>,
 ) -> Result<Vec<(Symbol, Val)>, ConfigError> {
-    require_admin(env, &caller).map_err(|_| ConfigError::Unauthorized)?;
+    require_role(env, &caller, Role::RiskAdmin).map_err(|_| ConfigError::Unauthorized)?;
 
     let mut backup = Vec::new(env);
     for key in keys.iter() {
@@ -100,7 +100,7 @@ pub fn config_restore(
     caller: Address,
     backup: Vec<(Symbol, Val)>,
 ) -> Result<(), ConfigError> {
-    require_admin(env, &caller).map_err(|_| ConfigError::Unauthorized)?;
+    require_role(env, &caller, Role::RiskAdmin).map_err(|_| ConfigError::Unauthorized)?;
 
     for (key, value) in backup.iter() {
         let storage_key = ConfigDataKey::ConfigKey(key);
@@ -0,0 +1,147 @@
+//! # Credit Delegation Module
+//!
+//! Lets a depositor hand part of their borrowing power to another address.
+//!
+//! The delegator approves a delegatee for up to `amount` of an asset with
+//! [`approve_credit`]. The delegatee then calls [`borrow_on_behalf`]: the
+//! borrowed tokens go to the delegatee, while the debt is booked on the
+//! delegator's position and checked against the delegator's collateral like
+//! any other borrow. Each delegated borrow consumes allowance; repaying does
+//! not restore it.
+//!
+//! ## Security
+//! - Only the delegator can set an allowance, and setting one replaces the
+//!   previous value; approving `0` revokes it.
+//! - The delegator stays liable for the debt, including interest and
+//!   liquidation, whoever received the tokens.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::borrow::BorrowError;
+
+/// Errors that can occur when approving credit
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CreditDelegationError {
+    /// Allowance must not be negative
+    InvalidAmount = 1,
+    /// Delegator and delegatee are the same address
+    SelfDelegation = 2,
+}
+
+/// Storage keys for credit delegation data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum CreditDelegationDataKey {
+    /// Remaining allowance: Allowance(delegator, delegatee, asset) -> i128
+    Allowance(Address, Address, Option<Address>),
+}
+
+/// Allow `delegatee` to borrow up to `amount` of `asset` against
+/// `delegator`'s collateral
+///
+/// Replaces any existing allowance for the pair and asset.
+///
+/// # Errors
+/// * `CreditDelegationError::InvalidAmount` - If amount < 0
+/// * `CreditDelegationError::SelfDelegation` - If delegator == delegatee
+#[allow(deprecated)]
+pub fn approve_credit(
+    env: &Env,
+    delegator: Address,
+    delegatee: Address,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<(), CreditDelegationError> {
+    delegator.require_auth();
+
+    if amount < 0 {
+        return Err(CreditDelegationError::InvalidAmount);
+    }
+    if delegator == delegatee {
+        return Err(CreditDelegationError::SelfDelegation);
+    }
+
+    let key =
+        CreditDelegationDataKey::Allowance(delegator.clone(), delegatee.clone(), asset.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &amount);
+    }
+
+    let topics = (Symbol::new(env, "credit_approved"), delegator, delegatee);
+    env.events().publish(topics, (asset, amount));
+
+    Ok(())
+}
+
+/// Remaining amount of `asset` `delegatee` may borrow against `delegator`
+pub fn get_credit_allowance(
+    env: &Env,
+    delegator: Address,
+    delegatee: Address,
+    asset: Option<Address>,
+) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&CreditDelegationDataKey::Allowance(
+            delegator, delegatee, asset,
+        ))
+        .unwrap_or(0)
+}
+
+/// Borrow against `delegator`'s position and receive the tokens as `delegatee`
+///
+/// # Returns
+/// The delegator's total debt after the borrow
+///
+/// # Errors
+/// * `BorrowError::InvalidAmount` - If amount <= 0
+/// * `BorrowError::InsufficientCreditAllowance` - If amount exceeds the allowance
+/// * Any error of the borrow itself, checked against the delegator's position
+#[allow(deprecated)]
+pub fn borrow_on_behalf(
+    env: &Env,
+    delegatee: Address,
+    delegator: Address,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<i128, BorrowError> {
+    delegatee.require_auth();
+
+    if amount <= 0 {
+        return Err(BorrowError::InvalidAmount);
+    }
+
+    let allowance = get_credit_allowance(env, delegator.clone(), delegatee.clone(), asset.clone());
+    if amount > allowance {
+        return Err(BorrowError::InsufficientCreditAllowance);
+    }
+
+    let key =
+        CreditDelegationDataKey::Allowance(delegator.clone(), delegatee.clone(), asset.clone());
+    let remaining = allowance - amount;
+    if remaining == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &remaining);
+    }
+
+    let total_debt = crate::borrow::borrow_to(
+        env,
+        delegator.clone(),
+        delegatee.clone(),
+        asset.clone(),
+        amount,
+    )?
+    .total_debt;
+
+    let topics = (Symbol::new(env, "credit_borrowed"), delegator, delegatee);
+    env.events().publish(topics, (asset, amount, remaining));
+
+    Ok(total_debt)
+}
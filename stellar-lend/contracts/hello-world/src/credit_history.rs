@@ -0,0 +1,250 @@
+//! # Credit History Module
+//!
+//! Tracks each borrower's repayment record and, when governance enables it,
+//! nudges their collateral factor up or down in borrow and withdraw checks.
+//!
+//! ## Record
+//! - `on_time_repays` — repayments that cleared all accrued borrow interest,
+//!   came at least `min_loan_duration` after the borrow they repay and were
+//!   worth at least `min_repay_value`; at most one counts per borrow
+//! - `liquidations` — liquidations suffered as a borrower
+//! - `first_borrow_at` — timestamp of the account's first borrow
+//!
+//! ## Adjustment
+//! With [`CreditHistoryConfig::enabled`], the collateral factor used for a
+//! borrow, a withdrawal and the borrow and withdraw limits in position views
+//! is scaled by `(10_000 + adjustment_bps) / 10_000`, where:
+//! - a never-liquidated account at least `min_account_age` old with at least
+//!   `min_on_time_repays` on-time repayments gets `+bonus_bps`;
+//! - an account with liquidations gets `-penalty_per_liquidation_bps` per
+//!   liquidation, capped at `max_penalty_bps`.
+//!
+//! The adjusted factor never exceeds 100%. Liquidation checks value
+//! collateral without a collateral factor, so a position opened with the
+//! bonus still satisfies the liquidation threshold.
+//!
+//! ## Security
+//! - Configuration is only changed through executed governance proposals.
+//! - The bonus is capped at [`MAX_CREDIT_BONUS_BPS`] so history can only ever
+//!   loosen limits slightly.
+//! - A repayment only counts once per borrow and only after the minimum loan
+//!   duration, so history cannot be farmed with rapid or dust-sized
+//!   borrow/repay loops.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+/// Basis points scale (100% = 10_000)
+pub const BASIS_POINTS_SCALE: i128 = 10_000;
+
+/// Maximum relative collateral factor bonus (5%)
+pub const MAX_CREDIT_BONUS_BPS: i128 = 500;
+
+/// Errors that can occur during credit history operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CreditHistoryError {
+    /// A parameter is out of range
+    InvalidParameter = 1,
+}
+
+/// Storage keys for credit history data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum CreditHistoryDataKey {
+    /// Credit record per user: CreditRecord
+    Record(Address),
+    /// History-based adjustment configuration: CreditHistoryConfig
+    Config,
+}
+
+/// A borrower's repayment record
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct CreditRecord {
+    pub on_time_repays: u32,
+    pub liquidations: u32,
+    /// Timestamp of the first borrow (0 if the account never borrowed)
+    pub first_borrow_at: u64,
+    /// Timestamp of the borrow a repayment can next count against
+    /// (0 once a repayment has counted)
+    pub open_borrow_at: u64,
+}
+
+/// History-based collateral factor adjustment configuration
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreditHistoryConfig {
+    pub enabled: bool,
+    /// On-time repayments required for the bonus
+    pub min_on_time_repays: u32,
+    /// Seconds since first borrow required for the bonus
+    pub min_account_age: u64,
+    /// Seconds a borrow must stay open before its repayment counts
+    pub min_loan_duration: u64,
+    /// Minimum value of a repayment that counts (oracle-priced)
+    pub min_repay_value: i128,
+    /// Relative collateral factor bonus for good accounts (basis points)
+    pub bonus_bps: i128,
+    /// Relative collateral factor penalty per liquidation (basis points)
+    pub penalty_per_liquidation_bps: i128,
+    /// Cap on the total penalty (basis points)
+    pub max_penalty_bps: i128,
+}
+
+/// Set the credit history configuration
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `CreditHistoryError::InvalidParameter` - If the bonus exceeds
+///   `MAX_CREDIT_BONUS_BPS`, a penalty is outside `[0, 10_000]` or the
+///   minimum repayment value is negative
+#[allow(deprecated)]
+pub fn set_config(env: &Env, config: CreditHistoryConfig) -> Result<(), CreditHistoryError> {
+    if !(0..=MAX_CREDIT_BONUS_BPS).contains(&config.bonus_bps)
+        || !(0..=BASIS_POINTS_SCALE).contains(&config.penalty_per_liquidation_bps)
+        || !(0..=BASIS_POINTS_SCALE).contains(&config.max_penalty_bps)
+        || config.min_repay_value < 0
+    {
+        return Err(CreditHistoryError::InvalidParameter);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&CreditHistoryDataKey::Config, &config);
+
+    let topics = (Symbol::new(env, "credit_history_config_updated"),);
+    env.events().publish(
+        topics,
+        (
+            config.enabled,
+            config.bonus_bps,
+            config.penalty_per_liquidation_bps,
+        ),
+    );
+
+    Ok(())
+}
+
+/// Get the credit history configuration
+pub fn get_config(env: &Env) -> Option<CreditHistoryConfig> {
+    env.storage().persistent().get(&CreditHistoryDataKey::Config)
+}
+
+/// Get a user's credit record
+pub fn get_record(env: &Env, user: &Address) -> CreditRecord {
+    env.storage()
+        .persistent()
+        .get(&CreditHistoryDataKey::Record(user.clone()))
+        .unwrap_or_default()
+}
+
+fn set_record(env: &Env, user: &Address, record: &CreditRecord) {
+    env.storage()
+        .persistent()
+        .set(&CreditHistoryDataKey::Record(user.clone()), record);
+}
+
+/// Record a borrow (stamps the account's first borrow time and opens a
+/// borrow for a repayment to count against)
+pub fn record_borrow(env: &Env, user: &Address) {
+    let mut record = get_record(env, user);
+    let now = env.ledger().timestamp().max(1);
+    if record.first_borrow_at != 0 && record.open_borrow_at != 0 {
+        return;
+    }
+    if record.first_borrow_at == 0 {
+        record.first_borrow_at = now;
+    }
+    if record.open_borrow_at == 0 {
+        record.open_borrow_at = now;
+    }
+    set_record(env, user, &record);
+}
+
+/// Record a repayment worth `value`
+///
+/// Only counts when it clears accrued interest, repays a borrow open for at
+/// least `min_loan_duration`, and is worth at least `min_repay_value`.
+/// Counting closes the open borrow, so the next on-time repayment needs a
+/// new borrow.
+pub fn record_repayment(env: &Env, user: &Address, value: i128, on_time: bool) {
+    if !on_time {
+        return;
+    }
+    let mut record = get_record(env, user);
+    if record.open_borrow_at == 0 {
+        return;
+    }
+    let (min_duration, min_value) = get_config(env)
+        .map(|config| (config.min_loan_duration, config.min_repay_value))
+        .unwrap_or((0, 0));
+    let held = env
+        .ledger()
+        .timestamp()
+        .saturating_sub(record.open_borrow_at);
+    if held < min_duration || value < min_value {
+        return;
+    }
+    record.on_time_repays = record.on_time_repays.saturating_add(1);
+    record.open_borrow_at = 0;
+    set_record(env, user, &record);
+}
+
+/// Record a liquidation suffered by `user`
+pub fn record_liquidation(env: &Env, user: &Address) {
+    let mut record = get_record(env, user);
+    record.liquidations = record.liquidations.saturating_add(1);
+    set_record(env, user, &record);
+}
+
+/// Relative collateral factor adjustment for `user` in basis points
+///
+/// Positive for good standing, negative for liquidated accounts, 0 when the
+/// feature is disabled or the account qualifies for neither.
+pub fn get_adjustment_bps(env: &Env, user: &Address) -> i128 {
+    let config = match get_config(env) {
+        Some(config) if config.enabled => config,
+        _ => return 0,
+    };
+    let record = get_record(env, user);
+
+    if record.liquidations > 0 {
+        let penalty = config
+            .penalty_per_liquidation_bps
+            .saturating_mul(record.liquidations as i128)
+            .min(config.max_penalty_bps);
+        return -penalty;
+    }
+
+    let age = env
+        .ledger()
+        .timestamp()
+        .saturating_sub(record.first_borrow_at);
+    if record.first_borrow_at > 0
+        && age >= config.min_account_age
+        && record.on_time_repays >= config.min_on_time_repays
+    {
+        return config.bonus_bps;
+    }
+
+    0
+}
+
+/// Collateral factor for `user` after the history-based adjustment
+///
+/// Never exceeds 100%.
+pub fn adjusted_collateral_factor(env: &Env, user: &Address, collateral_factor: i128) -> i128 {
+    let adjustment_bps = get_adjustment_bps(env, user);
+    if adjustment_bps == 0 {
+        return collateral_factor;
+    }
+    collateral_factor
+        .checked_mul(BASIS_POINTS_SCALE + adjustment_bps)
+        .map(|v| v / BASIS_POINTS_SCALE)
+        .unwrap_or(0)
+        .clamp(0, BASIS_POINTS_SCALE)
+}
@@ -23,6 +23,8 @@
 //!   reserve factor, supply/borrow caps
 //! - Oracle-based price feeds with staleness protection (1-hour window)
 //! - Unified position summary with health factor across all assets
+//! - Per-user list of active assets, so health checks only visit the assets a
+//!   user actually holds or owes, however many markets are listed
 //! - Checked arithmetic throughout — all math uses `checked_*` to prevent overflow
 //!
 //! ## Health Factor
@@ -284,6 +286,9 @@ const TOTAL_BORROWS: Symbol = symbol_short!("borrows");
 /// Storage key for the global list of registered assets: `Vec<AssetKey>`.
 const ASSET_LIST: Symbol = symbol_short!("assets");
 
+/// Storage key prefix for each user's active assets: `(USER_ASSETS, Address) -> Vec<AssetKey>`.
+const USER_ASSETS: Symbol = symbol_short!("usr_asset");
+
 /// Price staleness threshold in seconds (1 hour).
 const PRICE_STALENESS_THRESHOLD: u64 = 3600;
 
@@ -511,6 +516,93 @@ pub fn update_asset_config(
     Ok(())
 }
 
+/// Set an asset's collateral factor without an admin check, returning the
+/// previous value.
+///
+/// Used by the risk engine, whose adjustments are bounded by governance-set
+/// bands before reaching this function.
+///
+/// # Errors
+/// * [`CrossAssetError::AssetNotConfigured`] — asset is not registered.
+/// * [`CrossAssetError::InvalidConfig`] — factor outside `[0, liquidation_threshold]`.
+pub fn apply_collateral_factor(
+    env: &Env,
+    asset: Option<Address>,
+    collateral_factor: i128,
+) -> Result<i128, CrossAssetError> {
+    let asset_key = AssetKey::from_option(asset.clone());
+    let mut config = get_asset_config(env, &asset_key)?;
+
+    require_valid_basis_points(collateral_factor)?;
+    if collateral_factor > config.liquidation_threshold {
+        return Err(CrossAssetError::InvalidConfig);
+    }
+    let previous = config.collateral_factor;
+    config.collateral_factor = collateral_factor;
+
+    let mut configs: Map<AssetKey, AssetConfig> = env
+        .storage()
+        .persistent()
+        .get(&ASSET_CONFIGS)
+        .unwrap_or(Map::new(env));
+    configs.set(asset_key, config.clone());
+    env.storage().persistent().set(&ASSET_CONFIGS, &configs);
+
+    AssetConfigUpdatedEvent {
+        asset,
+        collateral_factor: config.collateral_factor,
+        liquidation_threshold: config.liquidation_threshold,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+
+    Ok(previous)
+}
+
+/// Set an asset's risk-tier parameters without an admin check.
+///
+/// Used by the risk tier module, whose parameters are only changed by
+/// governance or the super admin.
+///
+/// # Errors
+/// * [`CrossAssetError::AssetNotConfigured`] — asset is not registered.
+/// * [`CrossAssetError::InvalidConfig`] — resulting config would be invalid.
+pub fn apply_tier_params(
+    env: &Env,
+    asset: Option<Address>,
+    collateral_factor: i128,
+    liquidation_threshold: i128,
+    max_supply: i128,
+    max_borrow: i128,
+) -> Result<(), CrossAssetError> {
+    let asset_key = AssetKey::from_option(asset.clone());
+    let mut config = get_asset_config(env, &asset_key)?;
+
+    config.collateral_factor = collateral_factor;
+    config.liquidation_threshold = liquidation_threshold;
+    config.max_supply = max_supply;
+    config.max_borrow = max_borrow;
+    require_valid_config(&config)?;
+
+    let mut configs: Map<AssetKey, AssetConfig> = env
+        .storage()
+        .persistent()
+        .get(&ASSET_CONFIGS)
+        .unwrap_or(Map::new(env));
+    configs.set(asset_key, config.clone());
+    env.storage().persistent().set(&ASSET_CONFIGS, &configs);
+
+    AssetConfigUpdatedEvent {
+        asset,
+        collateral_factor: config.collateral_factor,
+        liquidation_threshold: config.liquidation_threshold,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+
+    Ok(())
+}
+
 // ============================================================================
 // Price Updates
 // ============================================================================
@@ -922,10 +1014,12 @@ pub fn get_user_asset_position(env: &Env, user: &Address, asset: Option<Address>
     })
 }
 
-/// Calculate a unified position summary across all registered assets.
+/// Calculate a unified position summary across the user's active assets.
 ///
-/// Iterates over all configured assets, aggregates collateral and debt values
-/// weighted by their respective factors, and computes the health factor.
+/// Iterates over the assets the user holds collateral or debt in (see
+/// [`get_user_active_assets`]), aggregates collateral and debt values weighted
+/// by their respective factors, and computes the health factor. The cost is
+/// independent of the number of registered assets.
 /// Prices older than 1 hour are rejected for any asset with a non-zero position.
 ///
 /// # Arguments
@@ -946,11 +1040,7 @@ pub fn get_user_position_summary(
     env: &Env,
     user: &Address,
 ) -> Result<UserPositionSummary, CrossAssetError> {
-    let asset_list: Vec<AssetKey> = env
-        .storage()
-        .persistent()
-        .get(&ASSET_LIST)
-        .unwrap_or(Vec::new(env));
+    let asset_list = get_user_active_assets(env, user);
 
     let configs: Map<AssetKey, AssetConfig> = env
         .storage()
@@ -1044,6 +1134,18 @@ pub fn get_asset_list(env: &Env) -> Vec<AssetKey> {
         .unwrap_or(Vec::new(env))
 }
 
+/// Return the assets a user currently has collateral or debt in.
+///
+/// Assets are added on the first deposit or borrow and removed once the
+/// position is fully closed. Returns an empty vector for users without
+/// positions. Read-only — no mutation.
+pub fn get_user_active_assets(env: &Env, user: &Address) -> Vec<AssetKey> {
+    env.storage()
+        .persistent()
+        .get(&(USER_ASSETS, user.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
 /// Look up the configuration for a specific asset by address.
 ///
 /// # Arguments
@@ -1094,12 +1196,21 @@ pub fn get_total_borrow_for(env: &Env, asset: Option<Address>) -> i128 {
 // ============================================================================
 
 /// Update user's position for a specific asset in persistent storage.
+///
+/// Also keeps the user's active-asset list in sync: the asset is added when
+/// the position opens and removed once collateral, principal and interest are
+/// all zero.
 fn set_user_asset_position(
     env: &Env,
     user: &Address,
     asset: Option<Address>,
     position: AssetPosition,
 ) {
+    let asset_key = AssetKey::from_option(asset.clone());
+    let is_open = position.collateral != 0
+        || position.debt_principal != 0
+        || position.accrued_interest != 0;
+
     let key = UserAssetKey::new(user.clone(), asset);
     let mut positions: Map<UserAssetKey, AssetPosition> = env
         .storage()
@@ -1109,16 +1220,35 @@ fn set_user_asset_position(
 
     positions.set(key, position);
     env.storage().persistent().set(&USER_POSITIONS, &positions);
+
+    update_user_active_assets(env, user, asset_key, is_open);
 }
 
-/// Quick check whether a user has any outstanding debt across all assets.
+/// Add or remove an asset from the user's active-asset list.
+fn update_user_active_assets(env: &Env, user: &Address, asset_key: AssetKey, is_open: bool) {
+    let mut active = get_user_active_assets(env, user);
+    let index = active.first_index_of(&asset_key);
+
+    match (index, is_open) {
+        (None, true) => active.push_back(asset_key),
+        (Some(i), false) => {
+            active.remove(i);
+        }
+        _ => return,
+    }
+
+    let key = (USER_ASSETS, user.clone());
+    if active.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &active);
+    }
+}
+
+/// Quick check whether a user has any outstanding debt across their active assets.
 /// Used to decide whether stale-price errors are blocking for withdrawals.
 fn user_has_any_debt(env: &Env, user: &Address) -> bool {
-    let asset_list: Vec<AssetKey> = env
-        .storage()
-        .persistent()
-        .get(&ASSET_LIST)
-        .unwrap_or(Vec::new(env));
+    let asset_list = get_user_active_assets(env, user);
 
     for i in 0..asset_list.len() {
         let asset_key = asset_list.get(i).unwrap();
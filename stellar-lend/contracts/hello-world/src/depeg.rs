@@ -0,0 +1,184 @@
+//! # Stablecoin Depeg Circuit Breaker
+//!
+//! Watches primary price updates of assets flagged as stablecoins and freezes
+//! an asset that stays off its peg.
+//!
+//! The peg is 1.0 in the feed's own decimals (`10^decimals`). When an update
+//! deviates from the peg by more than the asset's `band_bps`, the deviation
+//! clock starts; an update back inside the band resets it. If an update
+//! arrives while the asset has been outside the band for at least
+//! `grace_period` seconds, the asset is frozen and a `depeg_alert` event is
+//! emitted. A frozen asset rejects new deposits and borrows; repayments,
+//! withdrawals and liquidations are unaffected so positions can still unwind.
+//!
+//! ## Security
+//! - Flagging, configuration and unfreezing are admin-only.
+//! - A frozen asset stays frozen after the price recovers until the admin
+//!   clears it, so a briefly restored peg cannot be used to re-enter.
+//! - Deviation is only evaluated on price updates, so the freeze takes effect
+//!   on the first update after the grace period.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+/// Basis points scale (100% = 10_000)
+pub const BASIS_POINTS_SCALE: i128 = 10_000;
+
+/// Errors that can occur during depeg monitoring operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DepegError {
+    /// Caller is not authorized
+    Unauthorized = 1,
+    /// A parameter is out of range
+    InvalidParameter = 2,
+}
+
+/// Storage keys for depeg monitoring data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum DepegDataKey {
+    /// Depeg monitoring configuration per stablecoin: DepegConfig
+    Config(Address),
+    /// Deviation tracking per stablecoin: DepegState
+    State(Address),
+}
+
+/// Depeg monitoring configuration for a stablecoin
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepegConfig {
+    /// Allowed deviation from the peg (basis points)
+    pub band_bps: i128,
+    /// Seconds the price may stay outside the band before the asset freezes
+    pub grace_period: u64,
+}
+
+/// Deviation tracking for a stablecoin
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DepegState {
+    /// Timestamp the price first left the band, if it is outside it
+    pub deviating_since: Option<u64>,
+    /// Whether the asset is frozen
+    pub frozen: bool,
+    /// Deviation of the last price update from the peg (basis points)
+    pub last_deviation_bps: i128,
+}
+
+/// Flag an asset as a stablecoin and configure its depeg breaker, or unflag
+/// it with `None` (admin only)
+///
+/// Unflagging does not unfreeze the asset.
+///
+/// # Errors
+/// * `DepegError::Unauthorized` - If caller is not admin
+/// * `DepegError::InvalidParameter` - If the band is outside `(0, 10_000]`
+#[allow(deprecated)]
+pub fn set_depeg_config(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    config: Option<DepegConfig>,
+) -> Result<(), DepegError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| DepegError::Unauthorized)?;
+
+    let key = DepegDataKey::Config(asset.clone());
+    match config {
+        Some(ref config) => {
+            if config.band_bps <= 0 || config.band_bps > BASIS_POINTS_SCALE {
+                return Err(DepegError::InvalidParameter);
+            }
+            env.storage().persistent().set(&key, config);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+
+    let topics = (Symbol::new(env, "depeg_config_updated"), caller);
+    env.events().publish(topics, (asset, config));
+
+    Ok(())
+}
+
+/// Depeg breaker configuration of an asset, if it is flagged as a stablecoin
+pub fn get_depeg_config(env: &Env, asset: &Address) -> Option<DepegConfig> {
+    env.storage()
+        .persistent()
+        .get(&DepegDataKey::Config(asset.clone()))
+}
+
+/// Deviation tracking of an asset
+pub fn get_depeg_state(env: &Env, asset: &Address) -> DepegState {
+    env.storage()
+        .persistent()
+        .get(&DepegDataKey::State(asset.clone()))
+        .unwrap_or_default()
+}
+
+/// Whether an asset is frozen by the depeg breaker
+pub fn is_frozen(env: &Env, asset: &Address) -> bool {
+    get_depeg_state(env, asset).frozen
+}
+
+/// Unfreeze an asset and reset its deviation clock (admin only)
+///
+/// # Errors
+/// * `DepegError::Unauthorized` - If caller is not admin
+#[allow(deprecated)]
+pub fn clear_depeg_freeze(env: &Env, caller: Address, asset: Address) -> Result<(), DepegError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::PauseGuardian)
+        .map_err(|_| DepegError::Unauthorized)?;
+
+    env.storage()
+        .persistent()
+        .remove(&DepegDataKey::State(asset.clone()));
+
+    let topics = (Symbol::new(env, "depeg_freeze_cleared"), caller);
+    env.events().publish(topics, asset);
+
+    Ok(())
+}
+
+/// Evaluate a new primary price for `asset` against its peg
+///
+/// Does nothing for assets that are not flagged as stablecoins.
+#[allow(deprecated)]
+pub fn check_price(env: &Env, asset: &Address, price: i128, decimals: u32) {
+    let config = match get_depeg_config(env, asset) {
+        Some(config) => config,
+        None => return,
+    };
+    let peg = match 10i128.checked_pow(decimals) {
+        Some(peg) => peg,
+        None => return,
+    };
+
+    let deviation_bps = (price - peg)
+        .abs()
+        .checked_mul(BASIS_POINTS_SCALE)
+        .map(|v| v / peg)
+        .unwrap_or(i128::MAX);
+
+    let mut state = get_depeg_state(env, asset);
+    state.last_deviation_bps = deviation_bps;
+
+    if deviation_bps <= config.band_bps {
+        state.deviating_since = None;
+    } else {
+        let now = env.ledger().timestamp();
+        let since = *state.deviating_since.get_or_insert(now);
+        if !state.frozen && now.saturating_sub(since) >= config.grace_period {
+            state.frozen = true;
+
+            let topics = (Symbol::new(env, "depeg_alert"), asset.clone());
+            env.events().publish(topics, (price, deviation_bps, since));
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DepegDataKey::State(asset.clone()), &state);
+}
@@ -9,26 +9,43 @@
 //! - Emitting events for off-chain indexing
 //!
 //! ## Storage Layout
-//! - `CollateralBalance(user)` — per-user collateral amount
-//! - `Position(user)` — per-user position (collateral, debt, interest)
+//! - `CollateralBalance(user)` — per-user collateral amount, summed across assets
+//! - `Position(user)` — per-user position (collateral, debt, interest), summed
+//!   across assets; these totals are authoritative, and
+//!   [`crate::asset_positions`] attributes them to assets
 //! - `AssetParams(asset)` — per-asset deposit parameters
+//! - `UserDepositCap(asset)` — per-account collateral ceiling for deposits of an asset
 //! - `PauseSwitches` — operation pause flags
 //! - `ProtocolAnalytics` — aggregate protocol metrics
 //! - `UserAnalytics(user)` — per-user activity metrics
-//! - `ActivityLog` — bounded activity history (max 1000 entries)
+//! - `ActivityLog` — recent protocol-wide activity (max 100 entries); each
+//!   user's full history is paged by [`crate::activity`]
 //!
 //! ## Invariants
 //! - Deposit amount must be strictly positive.
-//! - Deposits are rejected when the protocol or deposit operation is paused.
+//! - Deposits are rejected when the protocol or deposit operation is paused
+//!   (read from the hot configuration cache).
+//! - A deposit of an asset with a per-account cap may not lift the user's
+//!   collateral balance above that cap (no cap = unlimited).
 //! - Token transfers use `transfer_from`, requiring prior user approval.
+//!
+//! ## Native XLM
+//! Native XLM (`asset = None`) moves through its Stellar Asset Contract like
+//! any other token. `initialize` resolves the network's native asset contract
+//! address into `NativeAssetAddress`; the admin can repoint it with
+//! [`set_native_asset_address`]. Until an address is stored, native deposits
+//! and withdrawals are recorded without moving funds.
 
 #![allow(unused)]
-use soroban_sdk::{contracterror, contracttype, Address, Env, IntoVal, Map, Symbol, Val, Vec};
+use soroban_sdk::{
+    contracterror, contracttype, Address, Bytes, Env, IntoVal, Map, Symbol, Val, Vec,
+};
 
 use crate::events::{
-    emit_analytics_updated, emit_borrower_health_v1, emit_deposit, emit_position_updated,
-    emit_user_activity_tracked, AnalyticsUpdatedEvent, BorrowerHealthEventV1, DepositEvent,
-    PositionUpdatedEvent, UserActivityTrackedEvent,
+    emit_analytics_updated, emit_borrower_health_v1, emit_deposit, emit_deposit_v1,
+    emit_position_updated, emit_user_activity_tracked, AnalyticsUpdatedEvent,
+    BorrowerHealthEventV1, DepositEvent, DepositEventV1, PositionUpdatedEvent,
+    UserActivityTrackedEvent, EVENT_SCHEMA_VERSION,
 };
 
 /// Errors that can occur during deposit operations
@@ -50,6 +67,12 @@ pub enum DepositError {
     Overflow = 6,
     /// Reentrancy detected
     Reentrancy = 7,
+    /// Deposit would exceed the asset's per-account cap
+    DepositCapExceeded = 8,
+    /// Caller is not authorized
+    Unauthorized = 9,
+    /// Asset is frozen (wind-down freeze or depeg breaker)
+    AssetFrozen = 10,
 }
 
 /// Storage keys for deposit-related data
@@ -77,12 +100,15 @@ pub enum DepositDataKey {
     /// Granular per-user analytics metrics
     /// Value type: UserAnalytics
     UserAnalytics(Address),
-    /// Bounded log of recent deposit activities: Vec<Activity>
+    /// Bounded feed of recent activity across users: Vec<Activity>
     ActivityLog,
     /// Protocol reserve per asset: Map<Option<Address>, i128>
     ProtocolReserve(Option<Address>),
     /// Native asset (XLM) contract address
     NativeAssetAddress,
+    /// Per-account collateral ceiling for deposits of an asset
+    /// Value type: i128
+    UserDepositCap(Option<Address>),
 }
 
 /// Asset parameters for collateral
@@ -107,9 +133,10 @@ pub struct Position {
     pub collateral: i128,
     /// Total debt amount
     pub debt: i128,
-    /// Borrow interest accrued
+    /// Borrow interest accrued up to `last_accrual_time`
     pub borrow_interest: i128,
-    /// Last accrual timestamp
+    /// Timestamp of the last accrual; the borrow index snapshots taken then
+    /// are kept by the interest rate module
     pub last_accrual_time: u64,
 }
 
@@ -194,12 +221,15 @@ pub struct ProtocolAnalytics {
 /// * `DepositError::InsufficientBalance` - If user doesn't have enough balance
 /// * `DepositError::DepositPaused` - If deposits are paused
 /// * `DepositError::AssetNotEnabled` - If asset is not enabled for deposits
+/// * `DepositError::DepositCapExceeded` - If the deposit would lift the user's
+///   collateral above the asset's per-account cap
 /// * `DepositError::Overflow` - If calculation overflow occurs
 ///
 /// # Security
 /// * Validates deposit amount > 0
 /// * Checks pause switches
 /// * Validates asset parameters
+/// * Enforces the per-account deposit cap
 /// * Transfers tokens from user to contract
 /// * Updates collateral balances
 /// * Emits events for tracking
@@ -237,13 +267,34 @@ pub fn deposit_collateral(
     }
 
     // Check risk management emergency pause and operation pause
-    // We access the risk management storage directly to check pause status
-    check_risk_management_pause(env)?;
+    if crate::hot_config::load(env).is_paused(env, crate::risk_management::PauseOperation::Deposit)
+    {
+        return Err(DepositError::DepositPaused);
+    }
+
+    // Check the asset's own deposit switch
+    if crate::risk_management::is_asset_paused(
+        env,
+        asset.clone(),
+        crate::risk_management::PauseOperation::Deposit,
+    ) {
+        return Err(DepositError::DepositPaused);
+    }
 
     // Get current timestamp
     let timestamp = env.ledger().timestamp();
 
-    // Handle asset transfer
+    // Checkpoint the asset indexes and settle supply interest before balances change
+    crate::interest_rate::accrue_asset(env, asset.clone()).map_err(|_| DepositError::Overflow)?;
+    crate::supply_interest::settle_supply_interest(env, &user, asset.clone())
+        .map_err(|_| DepositError::Overflow)?;
+
+    // Reject assets frozen for wind-down or by the depeg breaker
+    if crate::risk_management::is_asset_frozen(env, asset.clone()) {
+        return Err(DepositError::AssetFrozen);
+    }
+
+    // Validate token deposits
     if let Some(ref asset_addr) = asset {
         // Validate asset address - ensure it's not the contract itself
         if asset_addr == &env.current_contract_address() {
@@ -266,10 +317,12 @@ pub fn deposit_collateral(
                 return Err(DepositError::InvalidAmount);
             }
         }
+    }
 
-        // Transfer tokens from user to contract using token contract
-        // Use the token contract's transfer_from method
-        let token_client = soroban_sdk::token::Client::new(env, asset_addr);
+    // Transfer tokens from user to contract; native XLM goes through its
+    // Stellar Asset Contract
+    if let Some(token_addr) = asset.clone().or_else(|| get_native_asset_address(env)) {
+        let token_client = soroban_sdk::token::Client::new(env, &token_addr);
 
         // Check user balance
         let user_balance = token_client.balance(&user);
@@ -286,10 +339,6 @@ pub fn deposit_collateral(
             &env.current_contract_address(), // to (this contract)
             &amount,
         );
-    } else {
-        // Native XLM deposit - in Soroban, native assets are handled differently
-        // For now, we'll track it but actual XLM handling depends on Soroban's native asset support
-        // This is a placeholder for native asset handling
     }
 
     // Get or create user position
@@ -319,6 +368,14 @@ pub fn deposit_collateral(
         .checked_add(amount)
         .ok_or(DepositError::Overflow)?;
 
+    if let Some(cap) = get_user_deposit_cap(env, asset.clone()) {
+        let asset_collateral =
+            crate::asset_positions::get_asset_position(env, &user, asset.clone()).collateral;
+        if asset_collateral.saturating_add(amount) > cap {
+            return Err(DepositError::DepositCapExceeded);
+        }
+    }
+
     // Update storage
     env.storage()
         .persistent()
@@ -326,8 +383,12 @@ pub fn deposit_collateral(
 
     // Update position
     position.collateral = new_collateral;
-    position.last_accrual_time = timestamp;
     env.storage().persistent().set(&position_key, &position);
+    crate::asset_positions::record_deposit(env, &user, asset.clone(), amount)
+        .map_err(|_| DepositError::Overflow)?;
+    crate::flash_guard::record_deposit(env, &user);
+    crate::flash_guard::record_maturing_deposit(env, &user, asset.clone(), amount);
+    crate::referral::record_deposit(env, &user, amount);
 
     // Update user analytics
     update_user_analytics(env, &user, amount, timestamp, true)?;
@@ -355,6 +416,18 @@ pub fn deposit_collateral(
             timestamp,
         },
     );
+    emit_deposit_v1(
+        env,
+        DepositEventV1 {
+            schema_version: EVENT_SCHEMA_VERSION,
+            user: user.clone(),
+            asset: asset.clone(),
+            amount,
+            collateral_after: position.collateral,
+            total_debt_after: position.debt.saturating_add(position.borrow_interest),
+            timestamp,
+        },
+    );
 
     // Emit position updated event
     emit_position_updated_event(
@@ -364,6 +437,8 @@ pub fn deposit_collateral(
         Symbol::new(env, "deposit"),
         timestamp,
     );
+    crate::ttl::bump_position(env, &user);
+    crate::analytics::emit_health_factor_changed(env, &user, Symbol::new(env, "deposit"));
 
     // Emit analytics updated event
     emit_analytics_updated_event(env, &user, "deposit", amount, timestamp);
@@ -375,16 +450,15 @@ pub fn deposit_collateral(
 }
 
 /// Set the native asset address (admin only).
-/// Required for deposit/borrow/repay with asset = None. Must be called before using None as asset.
+/// Required for deposit/borrow/repay with asset = None. `initialize` sets it to the
+/// network's native XLM Stellar Asset Contract; this overrides it.
 pub fn set_native_asset_address(
     env: &Env,
     caller: Address,
     native_asset: Address,
 ) -> Result<(), DepositError> {
-    let admin = crate::admin::get_admin(env).ok_or(DepositError::InvalidAsset)?;
-    if caller != admin {
-        return Err(DepositError::InvalidAsset);
-    }
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| DepositError::InvalidAsset)?;
     caller.require_auth();
     if native_asset == env.current_contract_address() {
         return Err(DepositError::InvalidAsset);
@@ -395,6 +469,195 @@ pub fn set_native_asset_address(
     Ok(())
 }
 
+/// Configured native asset (XLM) contract address, if any
+pub fn get_native_asset_address(env: &Env) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get::<DepositDataKey, Address>(&DepositDataKey::NativeAssetAddress)
+}
+
+/// Address of the native XLM Stellar Asset Contract on the current network
+///
+/// The address is derived from the asset alone, so it is known without the
+/// contract having to be deployed first.
+pub fn native_asset_contract_address(env: &Env) -> Address {
+    // XDR encoding of `Asset::Native`
+    let native_asset = Bytes::from_array(env, &[0u8; 4]);
+    env.deployer()
+        .with_stellar_asset(native_asset)
+        .deployed_address()
+}
+
+/// Store the native XLM Stellar Asset Contract as the native asset address,
+/// unless one is already configured
+pub fn init_native_asset_address(env: &Env) {
+    if get_native_asset_address(env).is_none() {
+        env.storage().persistent().set(
+            &DepositDataKey::NativeAssetAddress,
+            &native_asset_contract_address(env),
+        );
+    }
+}
+
+/// Set the per-account deposit cap for an asset (admin only)
+///
+/// A deposit of `asset` is rejected if it would lift the depositor's
+/// collateral in that asset above `cap`. `None` removes the cap.
+///
+/// # Errors
+/// * `DepositError::Unauthorized` - If caller is not admin
+/// * `DepositError::InvalidAmount` - If `cap` is not positive
+#[allow(deprecated)]
+pub fn set_user_deposit_cap(
+    env: &Env,
+    caller: Address,
+    asset: Option<Address>,
+    cap: Option<i128>,
+) -> Result<(), DepositError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| DepositError::Unauthorized)?;
+
+    let key = DepositDataKey::UserDepositCap(asset.clone());
+    match cap {
+        Some(cap) if cap <= 0 => return Err(DepositError::InvalidAmount),
+        Some(cap) => env.storage().persistent().set(&key, &cap),
+        None => env.storage().persistent().remove(&key),
+    }
+
+    let topics = (Symbol::new(env, "user_deposit_cap_updated"), caller);
+    env.events().publish(topics, (asset, cap));
+
+    Ok(())
+}
+
+/// Per-account deposit cap for an asset (`None` = unlimited)
+pub fn get_user_deposit_cap(env: &Env, asset: Option<Address>) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DepositDataKey::UserDepositCap(asset))
+}
+
+/// Largest amount of `asset` `user` could deposit right now
+///
+/// Applies the same limits as [`deposit_collateral`]: the asset's
+/// `max_deposit` and the per-account deposit cap. Returns `0` whenever the
+/// deposit would be rejected outright: deposits paused, or the asset frozen,
+/// disabled or the contract itself. `i128::MAX` means no limit; the user's
+/// token balance is not checked.
+pub fn get_max_depositable(env: &Env, user: &Address, asset: Option<Address>) -> i128 {
+    if is_deposit_paused(env, asset.clone())
+        || crate::risk_management::is_asset_frozen(env, asset.clone())
+    {
+        return 0;
+    }
+
+    let mut limit = i128::MAX;
+    if let Some(ref asset_addr) = asset {
+        if asset_addr == &env.current_contract_address() {
+            return 0;
+        }
+        if let Some(params) = env
+            .storage()
+            .persistent()
+            .get::<DepositDataKey, AssetParams>(&DepositDataKey::AssetParams(asset_addr.clone()))
+        {
+            if !params.deposit_enabled {
+                return 0;
+            }
+            if params.max_deposit > 0 {
+                limit = params.max_deposit;
+            }
+        }
+    }
+
+    if let Some(cap) = get_user_deposit_cap(env, asset.clone()) {
+        let asset_collateral =
+            crate::asset_positions::get_asset_position(env, user, asset).collateral;
+        limit = limit.min(cap.saturating_sub(asset_collateral).max(0));
+    }
+    limit
+}
+
+/// Whether deposits of `asset` are paused by the emergency pause, the
+/// deposit switch, the asset's own switch or the legacy pause switches
+fn is_deposit_paused(env: &Env, asset: Option<Address>) -> bool {
+    if crate::hot_config::load(env).is_paused(env, crate::risk_management::PauseOperation::Deposit)
+        || crate::risk_management::is_asset_paused(
+            env,
+            asset,
+            crate::risk_management::PauseOperation::Deposit,
+        )
+    {
+        return true;
+    }
+    env.storage()
+        .persistent()
+        .get::<DepositDataKey, Map<Symbol, bool>>(&DepositDataKey::PauseSwitches)
+        .and_then(|pause_map| pause_map.get(Symbol::new(env, "pause_deposit")))
+        .unwrap_or(false)
+}
+
+/// Set an asset's deposit parameters
+///
+/// Only reachable through an executed governance proposal. A collateral
+/// factor change ramps in over the configured duration (see
+/// [`crate::param_ramp`]).
+///
+/// # Errors
+/// * `DepositError::InvalidAsset` - If `asset` is this contract
+/// * `DepositError::InvalidAmount` - If the collateral factor or borrow fee is
+///   outside `[0, 10_000]` bps, or `max_deposit` is negative
+#[allow(deprecated)]
+pub fn set_asset_params(
+    env: &Env,
+    asset: Address,
+    params: AssetParams,
+) -> Result<(), DepositError> {
+    if asset == env.current_contract_address() {
+        return Err(DepositError::InvalidAsset);
+    }
+    validate_asset_params(&params)?;
+
+    // Ramp a collateral factor change in over the configured duration
+    if let Some(previous) = get_asset_params(env, asset.clone()) {
+        if previous.collateral_factor != params.collateral_factor {
+            crate::param_ramp::start(
+                env,
+                crate::param_ramp::RampedParam::CollateralFactor(asset.clone()),
+                previous.collateral_factor,
+                params.collateral_factor,
+            );
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DepositDataKey::AssetParams(asset.clone()), &params);
+
+    let topics = (Symbol::new(env, "asset_params_updated"), asset);
+    env.events().publish(topics, params);
+
+    Ok(())
+}
+
+/// Range-check deposit parameters (`max_deposit` of 0 means unlimited)
+pub fn validate_asset_params(params: &AssetParams) -> Result<(), DepositError> {
+    if !(0..=10_000).contains(&params.collateral_factor)
+        || !(0..=10_000).contains(&params.borrow_fee_bps)
+        || params.max_deposit < 0
+    {
+        return Err(DepositError::InvalidAmount);
+    }
+    Ok(())
+}
+
+/// Deposit parameters of an asset, if any were set
+pub fn get_asset_params(env: &Env, asset: Address) -> Option<AssetParams> {
+    env.storage()
+        .persistent()
+        .get(&DepositDataKey::AssetParams(asset))
+}
+
 /// Update user analytics after deposit
 pub fn update_user_analytics(
     env: &Env,
@@ -403,6 +666,9 @@ pub fn update_user_analytics(
     timestamp: u64,
     is_deposit: bool,
 ) -> Result<(), DepositError> {
+    if !crate::analytics::tracks_user_activity(env) {
+        return Ok(());
+    }
     let analytics_key = DepositDataKey::UserAnalytics(user.clone());
     #[allow(clippy::unnecessary_lazy_evaluations)]
     let mut analytics = env
@@ -438,6 +704,7 @@ pub fn update_user_analytics(
 
     analytics.transaction_count = analytics.transaction_count.saturating_add(1);
     analytics.last_activity = timestamp;
+    analytics.loyalty_tier = crate::loyalty::tier_for(env, &analytics, timestamp);
 
     env.storage().persistent().set(&analytics_key, &analytics);
     Ok(())
@@ -475,7 +742,7 @@ pub fn update_protocol_analytics(
     Ok(())
 }
 
-/// Add entry to activity log
+/// Add entry to the user's paged activity history and the recent activity feed
 pub fn add_activity_log(
     env: &Env,
     user: &Address,
@@ -484,6 +751,9 @@ pub fn add_activity_log(
     asset: Option<Address>,
     timestamp: u64,
 ) -> Result<(), DepositError> {
+    if !crate::analytics::tracks_user_activity(env) {
+        return Ok(());
+    }
     let log_key = DepositDataKey::ActivityLog;
     let mut log = env
         .storage()
@@ -500,10 +770,11 @@ pub fn add_activity_log(
         metadata: Map::new(env),
     };
 
+    crate::activity::record(env, activity.clone());
     log.push_back(activity);
 
-    // Keep only last 1000 activities (prevent unbounded growth)
-    if log.len() > 1000 {
+    // Keep only the most recent activities (prevent unbounded growth)
+    while log.len() > crate::activity::RECENT_ACTIVITY_SIZE {
         log.pop_front();
     }
 
@@ -532,7 +803,7 @@ pub fn emit_position_updated_event(
     emit_borrower_health_v1(
         env,
         BorrowerHealthEventV1 {
-            schema_version: 1,
+            schema_version: EVENT_SCHEMA_VERSION,
             user: user.clone(),
             operation,
             collateral: position.collateral,
@@ -603,6 +874,9 @@ pub fn emit_analytics_updated_event(
     timestamp: u64,
 ) {
     use soroban_sdk::String;
+    if !crate::analytics::emits_analytics_events(env) {
+        return;
+    }
     emit_analytics_updated(
         env,
         AnalyticsUpdatedEvent {
@@ -622,6 +896,9 @@ pub fn emit_user_activity_tracked_event(
     amount: i128,
     timestamp: u64,
 ) {
+    if !crate::analytics::emits_analytics_events(env) {
+        return;
+    }
     emit_user_activity_tracked(
         env,
         UserActivityTrackedEvent {
@@ -632,36 +909,3 @@ pub fn emit_user_activity_tracked_event(
         },
     );
 }
-
-#[contracttype]
-enum RiskDataKey {
-    RiskConfig,
-    EmergencyPause,
-}
-
-/// Check risk management pause status
-/// This function checks the risk management system's pause switches
-/// by accessing the storage directly to avoid module dependency issues
-fn check_risk_management_pause(env: &Env) -> Result<(), DepositError> {
-    // Define risk management storage keys locally to avoid dependency
-
-    // Check emergency pause first
-    let emergency_key = RiskDataKey::EmergencyPause;
-    if let Some(emergency_paused) = env
-        .storage()
-        .persistent()
-        .get::<RiskDataKey, bool>(&emergency_key)
-    {
-        if emergency_paused {
-            return Err(DepositError::DepositPaused);
-        }
-    }
-
-    // Check operation-specific pause in risk config
-    // Note: We need to access the RiskConfig struct, but to avoid circular dependencies,
-    // we'll check if the config exists and try to read pause_switches
-    // For now, we'll skip this check and rely on the old pause switch system
-    // The risk management pause switches should be checked at the contract API level
-
-    Ok(())
-}
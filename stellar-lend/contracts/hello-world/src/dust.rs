@@ -0,0 +1,100 @@
+//! # Dust Position Module
+//!
+//! Minimum position sizes that keep positions worth liquidating.
+//!
+//! A position so small that the liquidation bonus does not cover a
+//! liquidator's fees is never liquidated and lingers as bad debt. Each asset
+//! can therefore be given a [`MinPositionSize`]:
+//! - a borrow may not leave the user's debt in the asset above zero but below
+//!   `min_debt`,
+//! - a withdrawal may not leave the user's collateral in the asset above zero
+//!   but below `min_collateral`.
+//!
+//! Closing a balance completely is always allowed, as is anything on assets
+//! without a configuration.
+//!
+//! ## Security
+//! - Sizes are set by the `risk_admin` role or the super admin.
+//! - Debt is measured as principal in the per-asset ledger; accrued interest
+//!   does not count, so interest alone never turns a position into dust.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+/// Errors that can occur when configuring minimum position sizes
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DustError {
+    /// Caller is not authorized
+    Unauthorized = 1,
+    /// A minimum is negative
+    InvalidParameter = 2,
+}
+
+/// Storage keys for dust position data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum DustDataKey {
+    /// Minimum position sizes per asset: MinPositionSize
+    MinPositionSize(Option<Address>),
+}
+
+/// Smallest non-zero balances a user may hold in an asset
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MinPositionSize {
+    /// Smallest non-zero principal debt (`0` = no minimum)
+    pub min_debt: i128,
+    /// Smallest non-zero collateral (`0` = no minimum)
+    pub min_collateral: i128,
+}
+
+/// Set the minimum position sizes for `asset`
+///
+/// Zero for both removes the configuration.
+///
+/// # Errors
+/// * `DustError::Unauthorized` - If caller is not a risk admin or the admin
+/// * `DustError::InvalidParameter` - If a minimum is negative
+#[allow(deprecated)]
+pub fn set_min_position_size(
+    env: &Env,
+    caller: Address,
+    asset: Option<Address>,
+    sizes: MinPositionSize,
+) -> Result<(), DustError> {
+    caller.require_auth();
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| DustError::Unauthorized)?;
+
+    if sizes.min_debt < 0 || sizes.min_collateral < 0 {
+        return Err(DustError::InvalidParameter);
+    }
+
+    let key = DustDataKey::MinPositionSize(asset.clone());
+    if sizes == MinPositionSize::default() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &sizes);
+    }
+
+    let topics = (Symbol::new(env, "min_position_size_updated"), caller);
+    env.events().publish(topics, (asset, sizes));
+
+    Ok(())
+}
+
+/// Minimum position sizes for `asset` (zeros when unset)
+pub fn get_min_position_size(env: &Env, asset: Option<Address>) -> MinPositionSize {
+    env.storage()
+        .persistent()
+        .get(&DustDataKey::MinPositionSize(asset))
+        .unwrap_or_default()
+}
+
+/// Whether `balance` is non-zero but below `minimum`
+pub fn is_dust(balance: i128, minimum: i128) -> bool {
+    balance > 0 && balance < minimum
+}
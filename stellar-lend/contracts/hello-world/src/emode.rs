@@ -0,0 +1,274 @@
+//! # E-Mode Module
+//!
+//! Efficiency mode for correlated assets, e.g. stablecoins or XLM and its
+//! derivatives.
+//!
+//! Governance defines numbered categories, each with its own minimum
+//! collateral ratio and liquidation threshold, and assigns assets to them. A
+//! user opts into one category with [`set_user_emode`]. While every asset the
+//! user holds as collateral or debt belongs to that category, the category's
+//! ratios replace the global risk parameters for borrowing, withdrawing and
+//! liquidation. As soon as the position includes any other asset, the global
+//! parameters apply again.
+//!
+//! Category ratios are usually below the global ones, since correlated prices
+//! move together and a thinner buffer carries the same risk, but never below
+//! 100%.
+//!
+//! ## Security
+//! - Categories and asset assignments only change through executed governance
+//!   proposals.
+//! - Entering or leaving a category is refused if the position would fall
+//!   below the minimum collateral ratio that applies afterwards.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+use crate::deposit::{DepositDataKey, Position};
+
+/// Basis points scale (100% = 10_000)
+pub const BASIS_POINTS_SCALE: i128 = 10_000;
+
+/// Errors that can occur during e-mode operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum EModeError {
+    /// A category ratio is out of range
+    InvalidParameter = 1,
+    /// Category has not been configured
+    CategoryNotFound = 2,
+    /// Switching categories would breach the minimum collateral ratio
+    InsufficientCollateralRatio = 3,
+    /// A priced asset has no fresh price
+    PriceNotAvailable = 4,
+    /// Arithmetic overflow occurred
+    Overflow = 5,
+}
+
+/// Risk ratios shared by the assets of an e-mode category
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EModeCategory {
+    /// Minimum collateral ratio for borrowing and withdrawing (basis points)
+    pub min_collateral_ratio: i128,
+    /// Ratio below which a position can be liquidated (basis points)
+    pub liquidation_threshold: i128,
+}
+
+/// Storage keys for e-mode data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum EModeDataKey {
+    /// Ratios of a category: Category(id) -> EModeCategory
+    Category(u32),
+    /// Category an asset belongs to: AssetCategory(asset) -> u32
+    AssetCategory(Option<Address>),
+    /// Category a user opted into: UserCategory(user) -> u32
+    UserCategory(Address),
+}
+
+/// Check a category's ratios
+///
+/// # Errors
+/// * `EModeError::InvalidParameter` - If the liquidation threshold is below
+///   100% or above the minimum collateral ratio
+pub fn validate_category(config: &EModeCategory) -> Result<(), EModeError> {
+    if config.liquidation_threshold < BASIS_POINTS_SCALE
+        || config.min_collateral_ratio < config.liquidation_threshold
+    {
+        return Err(EModeError::InvalidParameter);
+    }
+    Ok(())
+}
+
+/// Create or update an e-mode category
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `EModeError::InvalidParameter` - If the ratios are invalid
+#[allow(deprecated)]
+pub fn set_category(env: &Env, id: u32, config: EModeCategory) -> Result<(), EModeError> {
+    validate_category(&config)?;
+
+    env.storage()
+        .persistent()
+        .set(&EModeDataKey::Category(id), &config);
+
+    let topics = (Symbol::new(env, "emode_category_updated"), id);
+    env.events().publish(
+        topics,
+        (config.min_collateral_ratio, config.liquidation_threshold),
+    );
+
+    Ok(())
+}
+
+/// Assign an asset to an e-mode category, or remove it with `None`
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `EModeError::CategoryNotFound` - If the category is not configured
+#[allow(deprecated)]
+pub fn set_asset_category(
+    env: &Env,
+    asset: Option<Address>,
+    category: Option<u32>,
+) -> Result<(), EModeError> {
+    let key = EModeDataKey::AssetCategory(asset.clone());
+    match category {
+        Some(id) => {
+            if get_category(env, id).is_none() {
+                return Err(EModeError::CategoryNotFound);
+            }
+            env.storage().persistent().set(&key, &id);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+
+    let topics = (Symbol::new(env, "emode_asset_updated"),);
+    env.events().publish(topics, (asset, category));
+
+    Ok(())
+}
+
+/// Ratios of an e-mode category, if configured
+pub fn get_category(env: &Env, id: u32) -> Option<EModeCategory> {
+    env.storage().persistent().get(&EModeDataKey::Category(id))
+}
+
+/// Category an asset belongs to, if any
+pub fn get_asset_category(env: &Env, asset: Option<Address>) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&EModeDataKey::AssetCategory(asset))
+}
+
+/// Category a user opted into, if any
+pub fn get_user_emode(env: &Env, user: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&EModeDataKey::UserCategory(user.clone()))
+}
+
+/// Opt `user` into an e-mode category, or out of e-mode with `None`
+///
+/// # Errors
+/// * `EModeError::CategoryNotFound` - If the category is not configured
+/// * `EModeError::InsufficientCollateralRatio` - If the position would fall
+///   below the minimum collateral ratio that applies after the switch
+/// * `EModeError::PriceNotAvailable` - If a priced asset has no fresh price
+#[allow(deprecated)]
+pub fn set_user_emode(env: &Env, user: Address, category: Option<u32>) -> Result<(), EModeError> {
+    user.require_auth();
+
+    if let Some(id) = category {
+        if get_category(env, id).is_none() {
+            return Err(EModeError::CategoryNotFound);
+        }
+    }
+
+    let position = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Position>(&DepositDataKey::Position(user.clone()));
+    if let Some(position) = position.filter(|p| p.debt > 0 || p.borrow_interest > 0) {
+        let collateral = env
+            .storage()
+            .persistent()
+            .get::<DepositDataKey, i128>(&DepositDataKey::CollateralBalance(user.clone()))
+            .unwrap_or(0);
+        let value = crate::asset_positions::position_value(
+            env,
+            &user,
+            collateral,
+            position.debt,
+            position.borrow_interest,
+        )
+        .map_err(|_| EModeError::PriceNotAvailable)?;
+        let debt_value = value
+            .debt
+            .checked_add(value.interest)
+            .ok_or(EModeError::Overflow)?;
+        let ratio = value
+            .collateral
+            .checked_mul(BASIS_POINTS_SCALE)
+            .ok_or(EModeError::Overflow)?
+            / debt_value.max(1);
+
+        let min_ratio = resolve(env, &user, category, None)
+            .map(|config| config.min_collateral_ratio)
+            .unwrap_or_else(|| crate::risk_params::get_min_collateral_ratio(env).unwrap_or(15_000));
+        if ratio < min_ratio {
+            return Err(EModeError::InsufficientCollateralRatio);
+        }
+    }
+
+    let key = EModeDataKey::UserCategory(user.clone());
+    match category {
+        Some(id) => env.storage().persistent().set(&key, &id),
+        None => env.storage().persistent().remove(&key),
+    }
+
+    crate::analytics::emit_health_factor_changed(env, &user, Symbol::new(env, "emode"));
+    let topics = (Symbol::new(env, "user_emode_updated"), user);
+    env.events().publish(topics, category);
+
+    Ok(())
+}
+
+/// Category ratios applying to `user`'s position, if e-mode is in effect
+///
+/// `borrowing` is an asset about to be borrowed, which must belong to the
+/// category too.
+pub fn active_category(
+    env: &Env,
+    user: &Address,
+    borrowing: Option<&Option<Address>>,
+) -> Option<EModeCategory> {
+    resolve(env, user, get_user_emode(env, user), borrowing)
+}
+
+/// Minimum collateral ratio applying to `user`: the e-mode category's when
+/// in effect, the global one otherwise
+pub fn min_collateral_ratio_for(
+    env: &Env,
+    user: &Address,
+    borrowing: Option<&Option<Address>>,
+) -> i128 {
+    active_category(env, user, borrowing)
+        .map(|config| config.min_collateral_ratio)
+        .unwrap_or_else(|| crate::risk_params::get_min_collateral_ratio(env).unwrap_or(15_000))
+}
+
+/// E-mode liquidation threshold applying to `user`, if e-mode is in effect
+pub fn liquidation_threshold_for(env: &Env, user: &Address) -> Option<i128> {
+    active_category(env, user, None).map(|config| config.liquidation_threshold)
+}
+
+/// Ratios of `category` if every asset in `user`'s position belongs to it
+fn resolve(
+    env: &Env,
+    user: &Address,
+    category: Option<u32>,
+    borrowing: Option<&Option<Address>>,
+) -> Option<EModeCategory> {
+    let id = category?;
+    let in_category = |asset: Option<Address>| get_asset_category(env, asset) == Some(id);
+
+    if let Some(asset) = borrowing {
+        if !in_category(asset.clone()) {
+            return None;
+        }
+    }
+    if !crate::asset_positions::get_user_assets(env, user)
+        .iter()
+        .all(in_category)
+    {
+        return None;
+    }
+    get_category(env, id)
+}
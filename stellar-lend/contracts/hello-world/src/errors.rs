@@ -1,5 +1,11 @@
 use soroban_sdk::contracterror;
 
+use crate::borrow::BorrowError;
+use crate::deposit::DepositError;
+use crate::oracle::OracleError;
+use crate::repay::RepayError;
+use crate::withdraw::WithdrawError;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum GovernanceError {
@@ -39,4 +45,176 @@ pub enum GovernanceError {
     NotInitialized = 133,
     InvalidProposal = 134,
     InvalidThreshold = 135,
+    EmergencyTrackDisabled = 136,
+    SpendLimitExceeded = 137,
+    InvalidStakeAmount = 138,
+    InsufficientStake = 139,
+    InsufficientLiquidity = 140,
+    SpendLimitNotSet = 141,
+}
+
+/// Protocol-wide error codes shared by the user-facing entrypoints
+///
+/// Returned by the deposit, withdraw, borrow, repay and oracle entrypoints.
+/// Module errors convert into it with `?`, so a client handles one set of
+/// codes no matter which flow failed. Codes start at 200 so they never
+/// collide with a module's own codes.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ProtocolError {
+    /// Caller is not authorized
+    Unauthorized = 200,
+    /// Amount must be greater than zero
+    InvalidAmount = 201,
+    /// Asset address is invalid
+    InvalidAsset = 202,
+    /// Token balance is too low
+    InsufficientBalance = 203,
+    /// Not enough collateral for the operation
+    InsufficientCollateral = 204,
+    /// Operation would leave the position below the required collateral ratio
+    InsufficientCollateralRatio = 205,
+    /// Borrow exceeds the position's borrowing power
+    MaxBorrowExceeded = 206,
+    /// Position has no debt
+    NoDebt = 207,
+    /// Operation is paused
+    OperationPaused = 208,
+    /// Asset is not enabled for the operation
+    AssetNotEnabled = 209,
+    /// Asset is frozen
+    AssetFrozen = 210,
+    /// Deposit cap reached
+    CapExceeded = 211,
+    /// Collateral was deposited in the current ledger
+    SameLedgerDeposit = 212,
+    /// Circuit breaker is tripped for the asset
+    CircuitBreakerTripped = 213,
+    /// Operation exceeds the rate limit
+    RateLimited = 214,
+    /// Reentrancy detected
+    Reentrancy = 215,
+    /// Arithmetic overflow occurred
+    Overflow = 216,
+    /// Price or oracle parameter is out of range
+    InvalidPrice = 217,
+    /// Price is older than the staleness threshold
+    StalePrice = 218,
+    /// Price moved further than the allowed deviation
+    PriceDeviationExceeded = 219,
+    /// Oracle address is invalid
+    InvalidOracle = 220,
+    /// No usable price for the asset
+    PriceNotAvailable = 221,
+    /// Signed price nonce is reused or rewound
+    InvalidNonce = 222,
+    /// Signed price timestamp is in the future
+    InvalidTimestamp = 223,
+    /// Operation would leave a balance below the asset's minimum position size
+    DustPosition = 224,
+    /// Pool does not hold enough of the asset
+    InsufficientLiquidity = 225,
+    /// Token or credit allowance is too low
+    InsufficientAllowance = 226,
+}
+
+impl From<DepositError> for ProtocolError {
+    fn from(err: DepositError) -> Self {
+        match err {
+            DepositError::InvalidAmount => ProtocolError::InvalidAmount,
+            DepositError::InvalidAsset => ProtocolError::InvalidAsset,
+            DepositError::InsufficientBalance => ProtocolError::InsufficientBalance,
+            DepositError::DepositPaused => ProtocolError::OperationPaused,
+            DepositError::AssetNotEnabled => ProtocolError::AssetNotEnabled,
+            DepositError::Overflow => ProtocolError::Overflow,
+            DepositError::Reentrancy => ProtocolError::Reentrancy,
+            DepositError::DepositCapExceeded => ProtocolError::CapExceeded,
+            DepositError::Unauthorized => ProtocolError::Unauthorized,
+            DepositError::AssetFrozen => ProtocolError::AssetFrozen,
+        }
+    }
+}
+
+impl From<WithdrawError> for ProtocolError {
+    fn from(err: WithdrawError) -> Self {
+        match err {
+            WithdrawError::InvalidAmount => ProtocolError::InvalidAmount,
+            WithdrawError::InvalidAsset => ProtocolError::InvalidAsset,
+            WithdrawError::InsufficientCollateral => ProtocolError::InsufficientCollateral,
+            WithdrawError::WithdrawPaused => ProtocolError::OperationPaused,
+            WithdrawError::InsufficientCollateralRatio | WithdrawError::Undercollateralized => {
+                ProtocolError::InsufficientCollateralRatio
+            }
+            WithdrawError::Overflow => ProtocolError::Overflow,
+            WithdrawError::Reentrancy => ProtocolError::Reentrancy,
+            WithdrawError::Unauthorized => ProtocolError::Unauthorized,
+            WithdrawError::SameLedgerDeposit => ProtocolError::SameLedgerDeposit,
+            WithdrawError::CircuitBreakerTripped => ProtocolError::CircuitBreakerTripped,
+            WithdrawError::RateLimited => ProtocolError::RateLimited,
+            WithdrawError::PriceNotAvailable => ProtocolError::PriceNotAvailable,
+            WithdrawError::DustPosition => ProtocolError::DustPosition,
+        }
+    }
+}
+
+impl From<BorrowError> for ProtocolError {
+    fn from(err: BorrowError) -> Self {
+        match err {
+            BorrowError::InvalidAmount => ProtocolError::InvalidAmount,
+            BorrowError::InvalidAsset => ProtocolError::InvalidAsset,
+            BorrowError::InsufficientCollateral => ProtocolError::InsufficientCollateral,
+            BorrowError::BorrowPaused => ProtocolError::OperationPaused,
+            BorrowError::InsufficientCollateralRatio => ProtocolError::InsufficientCollateralRatio,
+            BorrowError::Overflow => ProtocolError::Overflow,
+            BorrowError::Reentrancy => ProtocolError::Reentrancy,
+            BorrowError::MaxBorrowExceeded => ProtocolError::MaxBorrowExceeded,
+            BorrowError::AssetNotEnabled => ProtocolError::AssetNotEnabled,
+            BorrowError::SameLedgerDeposit => ProtocolError::SameLedgerDeposit,
+            BorrowError::CircuitBreakerTripped => ProtocolError::CircuitBreakerTripped,
+            BorrowError::RateLimited => ProtocolError::RateLimited,
+            BorrowError::AssetFrozen => ProtocolError::AssetFrozen,
+            BorrowError::PriceNotAvailable => ProtocolError::PriceNotAvailable,
+            BorrowError::InsufficientCreditAllowance => ProtocolError::InsufficientAllowance,
+            BorrowError::DustPosition => ProtocolError::DustPosition,
+            BorrowError::InsufficientLiquidity => ProtocolError::InsufficientLiquidity,
+        }
+    }
+}
+
+impl From<RepayError> for ProtocolError {
+    fn from(err: RepayError) -> Self {
+        match err {
+            RepayError::InvalidAmount => ProtocolError::InvalidAmount,
+            RepayError::InvalidAsset => ProtocolError::InvalidAsset,
+            RepayError::InsufficientBalance => ProtocolError::InsufficientBalance,
+            RepayError::RepayPaused => ProtocolError::OperationPaused,
+            RepayError::NoDebt => ProtocolError::NoDebt,
+            RepayError::Overflow => ProtocolError::Overflow,
+            RepayError::Reentrancy => ProtocolError::Reentrancy,
+            RepayError::InsufficientCollateral => ProtocolError::InsufficientCollateral,
+            RepayError::PriceNotAvailable => ProtocolError::PriceNotAvailable,
+            RepayError::Liquidatable => ProtocolError::InsufficientCollateralRatio,
+            RepayError::InsufficientAllowance => ProtocolError::InsufficientAllowance,
+        }
+    }
+}
+
+impl From<OracleError> for ProtocolError {
+    fn from(err: OracleError) -> Self {
+        match err {
+            OracleError::InvalidPrice => ProtocolError::InvalidPrice,
+            OracleError::StalePrice => ProtocolError::StalePrice,
+            OracleError::PriceDeviationExceeded => ProtocolError::PriceDeviationExceeded,
+            OracleError::InvalidOracle => ProtocolError::InvalidOracle,
+            OracleError::OraclePaused => ProtocolError::OperationPaused,
+            OracleError::Overflow => ProtocolError::Overflow,
+            OracleError::Unauthorized => ProtocolError::Unauthorized,
+            OracleError::AssetNotSupported | OracleError::FallbackNotConfigured => {
+                ProtocolError::PriceNotAvailable
+            }
+            OracleError::InvalidNonce => ProtocolError::InvalidNonce,
+            OracleError::InvalidTimestamp => ProtocolError::InvalidTimestamp,
+        }
+    }
 }
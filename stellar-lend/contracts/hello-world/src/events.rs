@@ -97,6 +97,88 @@ pub struct LiquidationEventV1 {
     pub timestamp: u64,
 }
 
+/// Schema version carried by every versioned (`…V1`) event payload.
+///
+/// Bumped only when a versioned payload changes shape; indexers can branch
+/// on `schema_version` instead of guessing the layout from the data.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Stable deposit payload for downstream indexers.
+///
+/// Emitted next to the legacy `DepositEvent` with the depositor's position
+/// after the deposit, so indexers do not need a second read.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct DepositEventV1 {
+    pub schema_version: u32,
+    pub user: Address,
+    pub asset: Option<Address>,
+    pub amount: i128,
+    pub collateral_after: i128,
+    pub total_debt_after: i128,
+    pub timestamp: u64,
+}
+
+/// Stable withdrawal payload for downstream indexers.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct WithdrawalEventV1 {
+    pub schema_version: u32,
+    pub user: Address,
+    pub asset: Option<Address>,
+    pub amount: i128,
+    pub collateral_after: i128,
+    pub total_debt_after: i128,
+    pub timestamp: u64,
+}
+
+/// Stable borrow payload for downstream indexers.
+///
+/// `amount` is added to debt; `origination_fee` is the part of it kept by
+/// the protocol.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct BorrowEventV1 {
+    pub schema_version: u32,
+    pub user: Address,
+    pub asset: Option<Address>,
+    pub amount: i128,
+    pub origination_fee: i128,
+    pub collateral_after: i128,
+    pub total_debt_after: i128,
+    pub timestamp: u64,
+}
+
+/// Stable repayment payload for downstream indexers.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RepayEventV1 {
+    pub schema_version: u32,
+    pub user: Address,
+    pub asset: Option<Address>,
+    pub amount: i128,
+    pub interest_paid: i128,
+    pub principal_paid: i128,
+    pub collateral_after: i128,
+    pub total_debt_after: i128,
+    pub timestamp: u64,
+}
+
+/// Stable payload for a change of a scalar protocol parameter.
+///
+/// `param` names the parameter (e.g. `origination_fee_bps`); `asset` is set
+/// for per-asset parameters. Emitted next to the module's own update event.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ParamChangeEventV1 {
+    pub schema_version: u32,
+    pub param: Symbol,
+    pub asset: Option<Address>,
+    pub old_value: i128,
+    pub new_value: i128,
+    pub timestamp: u64,
+}
+
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct FlashLoanInitiatedEvent {
@@ -181,6 +263,22 @@ pub struct BorrowerHealthEventV1 {
     pub timestamp: u64,
 }
 
+/// Oracle-valued account health after a position change or a keeper `poke`.
+///
+/// Lets keepers and liquidation bots track at-risk accounts from the event
+/// stream instead of polling every borrower.
+#[contractevent(topics = ["health_factor_changed"])]
+#[derive(Clone, Debug)]
+pub struct HealthFactorChangedEvent {
+    pub user: Address,
+    pub operation: Symbol,
+    pub collateral_value: i128,
+    pub debt_value: i128,
+    pub health_factor: i128,
+    pub is_liquidatable: bool,
+    pub timestamp: u64,
+}
+
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct AnalyticsUpdatedEvent {
@@ -306,6 +404,24 @@ pub struct VoteCastEvent {
     pub timestamp: u64,
 }
 
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct VotesStakedEvent {
+    pub staker: Address,
+    pub amount: i128,
+    pub staked: i128,
+    pub timestamp: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct VotesUnstakedEvent {
+    pub staker: Address,
+    pub amount: i128,
+    pub staked: i128,
+    pub timestamp: u64,
+}
+
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ProposalQueuedEvent {
@@ -335,6 +451,17 @@ pub struct ProposalFailedEvent {
     pub threshold_met: bool,
 }
 
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ProposalFinalizedEvent {
+    pub proposal_id: u64,
+    pub succeeded: bool,
+    pub for_votes: i128,
+    pub against_votes: i128,
+    pub quorum_reached: bool,
+    pub timestamp: u64,
+}
+
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ProposalCancelledEvent {
@@ -343,6 +470,22 @@ pub struct ProposalCancelledEvent {
     pub timestamp: u64,
 }
 
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ProposalVetoedEvent {
+    pub proposal_id: u64,
+    pub guardian: Address,
+    pub timestamp: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct VetoGuardianSetEvent {
+    pub guardian: Option<Address>,
+    pub set_by: Address,
+    pub timestamp: u64,
+}
+
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ProposalApprovedEvent {
@@ -463,6 +606,41 @@ pub fn emit_liquidation_v1(e: &Env, event: LiquidationEventV1) {
     event.publish(e);
 }
 
+pub fn emit_deposit_v1(e: &Env, event: DepositEventV1) {
+    event.publish(e);
+}
+
+pub fn emit_withdrawal_v1(e: &Env, event: WithdrawalEventV1) {
+    event.publish(e);
+}
+
+pub fn emit_borrow_v1(e: &Env, event: BorrowEventV1) {
+    event.publish(e);
+}
+
+pub fn emit_repay_v1(e: &Env, event: RepayEventV1) {
+    event.publish(e);
+}
+
+/// Emit a [`ParamChangeEventV1`] for `param` moving from `old_value` to `new_value`
+pub fn emit_param_change(
+    e: &Env,
+    param: &str,
+    asset: Option<Address>,
+    old_value: i128,
+    new_value: i128,
+) {
+    ParamChangeEventV1 {
+        schema_version: EVENT_SCHEMA_VERSION,
+        param: Symbol::new(e, param),
+        asset,
+        old_value,
+        new_value,
+        timestamp: e.ledger().timestamp(),
+    }
+    .publish(e);
+}
+
 pub fn emit_flash_loan_initiated(e: &Env, event: FlashLoanInitiatedEvent) {
     event.publish(e);
 }
@@ -495,6 +673,10 @@ pub fn emit_borrower_health_v1(e: &Env, event: BorrowerHealthEventV1) {
     event.publish(e);
 }
 
+pub fn emit_health_factor_changed(e: &Env, event: HealthFactorChangedEvent) {
+    event.publish(e);
+}
+
 pub fn emit_analytics_updated(e: &Env, event: AnalyticsUpdatedEvent) {
     event.publish(e);
 }
@@ -633,3 +815,83 @@ pub fn emit_recovery_approved(e: &Env, event: RecoveryApprovedEvent) {
 pub fn emit_recovery_executed(e: &Env, event: RecoveryExecutedEvent) {
     event.publish(e);
 }
+
+// ============================================================================
+// Rewards Events
+// ============================================================================
+
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct EmissionScheduleSetEvent {
+    pub actor: Address,
+    pub asset: Option<Address>,
+    pub start_time: u64,
+    pub epoch_duration: u64,
+    pub initial_budget: i128,
+    pub decay_bps: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_emission_schedule_set(e: &Env, event: EmissionScheduleSetEvent) {
+    event.publish(e);
+}
+
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct GaugeVoteCastEvent {
+    pub voter: Address,
+    pub asset: Option<Address>,
+    pub period: u64,
+    pub weight_bps: u32,
+    pub vote_weight: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_gauge_vote_cast(e: &Env, event: GaugeVoteCastEvent) {
+    event.publish(e);
+}
+
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct GaugeWeightsFinalizedEvent {
+    pub period: u64,
+    pub total_votes: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_gauge_weights_finalized(e: &Env, event: GaugeWeightsFinalizedEvent) {
+    event.publish(e);
+}
+
+// ============================================================================
+// Risk Engine Events
+// ============================================================================
+
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RiskEngineAdjustmentEvent {
+    pub id: u64,
+    pub asset: Option<Address>,
+    pub parameter: Symbol,
+    pub old_value: i128,
+    pub new_value: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_risk_engine_adjustment(e: &Env, event: RiskEngineAdjustmentEvent) {
+    event.publish(e);
+}
+
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RiskEngineRevertedEvent {
+    pub id: u64,
+    pub asset: Option<Address>,
+    pub parameter: Symbol,
+    pub restored_value: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_risk_engine_reverted(e: &Env, event: RiskEngineRevertedEvent) {
+    event.publish(e);
+}
@@ -0,0 +1,190 @@
+//! # Fee Discount Module
+//!
+//! Rewards long-term governance participants with cheaper borrowing.
+//!
+//! Accounts whose staked governance vote tokens are at or above `min_stake`
+//! receive `discount_bps` off:
+//! - the borrow (origination) fee charged in `borrow_asset`, and
+//! - the protocol spread on their borrow rate during interest accrual.
+//!
+//! Only the protocol's share is discounted — the supply rate paid to lenders
+//! is unchanged, so the discount is funded entirely out of reserves.
+//!
+//! ## Security
+//! - Configuration is admin-only and the discount is capped at
+//!   [`MAX_FEE_DISCOUNT_BPS`].
+//! - The vote token is the one configured in governance; if governance is not
+//!   initialized no discount applies.
+//! - Only stake held over the whole interval counts: the origination fee
+//!   looks at the lowest stake since the previous ledger, and the spread
+//!   rebate at the lowest stake since the position's last accrual (see
+//!   [`crate::governance::get_min_staked_votes_since`]). Wallet balances and
+//!   stake added in the same transaction never qualify.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+/// Basis points scale (100% = 10_000)
+pub const BASIS_POINTS_SCALE: i128 = 10_000;
+
+/// Maximum discount (50%)
+pub const MAX_FEE_DISCOUNT_BPS: i128 = 5_000;
+
+/// Errors that can occur during fee discount operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FeeDiscountError {
+    /// Caller is not authorized
+    Unauthorized = 1,
+    /// A parameter is out of range
+    InvalidParameter = 2,
+}
+
+/// Storage keys for fee discount data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum FeeDiscountDataKey {
+    /// Staker discount configuration: FeeDiscountConfig
+    Config,
+}
+
+/// Staker fee discount configuration
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeDiscountConfig {
+    /// Minimum staked vote tokens required to qualify
+    pub min_stake: i128,
+    /// Discount applied to fees and spreads (basis points)
+    pub discount_bps: i128,
+}
+
+/// Set the staker fee discount configuration (admin only)
+///
+/// # Errors
+/// * `FeeDiscountError::Unauthorized` - If caller is not admin
+/// * `FeeDiscountError::InvalidParameter` - If `min_stake <= 0` or the
+///   discount is outside `[0, MAX_FEE_DISCOUNT_BPS]`
+#[allow(deprecated)]
+pub fn set_fee_discount_config(
+    env: &Env,
+    caller: Address,
+    min_stake: i128,
+    discount_bps: i128,
+) -> Result<(), FeeDiscountError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| FeeDiscountError::Unauthorized)?;
+
+    if min_stake <= 0 || !(0..=MAX_FEE_DISCOUNT_BPS).contains(&discount_bps) {
+        return Err(FeeDiscountError::InvalidParameter);
+    }
+
+    let config = FeeDiscountConfig {
+        min_stake,
+        discount_bps,
+    };
+    env.storage()
+        .persistent()
+        .set(&FeeDiscountDataKey::Config, &config);
+
+    let topics = (Symbol::new(env, "fee_discount_updated"), caller);
+    env.events().publish(topics, (min_stake, discount_bps));
+
+    Ok(())
+}
+
+/// Get the staker fee discount configuration
+pub fn get_fee_discount_config(env: &Env) -> Option<FeeDiscountConfig> {
+    env.storage().persistent().get(&FeeDiscountDataKey::Config)
+}
+
+/// Discount (basis points) that applies to `user` now
+///
+/// Qualifies on stake held since the end of the previous ledger.
+pub fn get_fee_discount_bps(env: &Env, user: &Address) -> i128 {
+    let since = env.ledger().timestamp().saturating_sub(1);
+    get_fee_discount_bps_since(env, user, since)
+}
+
+/// Discount (basis points) earned by `user` over the interval since `since`
+///
+/// Returns `0` when no discount is configured, governance is not initialized,
+/// or the user's stake dropped below `min_stake` at any point after the
+/// ledger at `since`.
+pub fn get_fee_discount_bps_since(env: &Env, user: &Address, since: u64) -> i128 {
+    let config = match get_fee_discount_config(env) {
+        Some(config) if config.discount_bps > 0 => config,
+        _ => return 0,
+    };
+    if crate::governance::get_config(env).is_none() {
+        return 0;
+    }
+
+    let stake = crate::governance::get_min_staked_votes_since(env, user, since);
+    if stake >= config.min_stake {
+        config.discount_bps
+    } else {
+        0
+    }
+}
+
+/// Discount (basis points) on `user`'s origination fees
+///
+/// The larger of the staker discount and the user's loyalty tier discount
+/// (see [`crate::loyalty`]).
+pub fn borrow_fee_discount_bps(env: &Env, user: &Address) -> i128 {
+    get_fee_discount_bps(env, user).max(crate::loyalty::fee_discount_bps(env, user))
+}
+
+/// Apply a basis-point discount to a fee amount
+///
+/// Returns `None` on overflow.
+pub fn apply_discount(amount: i128, discount_bps: i128) -> Option<i128> {
+    if discount_bps <= 0 {
+        return Some(amount);
+    }
+    let discount = amount.checked_mul(discount_bps)?.checked_div(BASIS_POINTS_SCALE)?;
+    amount.checked_sub(discount)
+}
+
+/// Part of the protocol spread `user` is excused from since `since`, in
+/// basis points
+///
+/// `spread_bps * discount_bps / 10_000`, with the discount earned over the
+/// whole interval (see [`get_fee_discount_bps_since`]).
+pub fn spread_discount_bps(env: &Env, user: &Address, since: u64) -> i128 {
+    let discount_bps = get_fee_discount_bps_since(env, user, since);
+    if discount_bps == 0 {
+        return 0;
+    }
+    match crate::interest_rate::get_interest_rate_config(env) {
+        Some(config) => config
+            .spread_bps
+            .checked_mul(discount_bps)
+            .and_then(|v| v.checked_div(BASIS_POINTS_SCALE))
+            .unwrap_or(0),
+        None => 0,
+    }
+}
+
+/// Borrow rate for `user` after discounting the protocol spread
+///
+/// `rate = rate_bps - spread_bps * discount_bps / 10_000`, never below the
+/// configured supply rate floor.
+pub fn discounted_borrow_rate(env: &Env, user: &Address, rate_bps: i128) -> i128 {
+    let since = env.ledger().timestamp().saturating_sub(1);
+    let spread_discount = spread_discount_bps(env, user, since);
+    if spread_discount == 0 {
+        return rate_bps;
+    }
+    let config = match crate::interest_rate::get_interest_rate_config(env) {
+        Some(config) => config,
+        None => return rate_bps,
+    };
+
+    rate_bps
+        .checked_sub(spread_discount)
+        .unwrap_or(rate_bps)
+        .max(config.rate_floor_bps)
+}
@@ -0,0 +1,202 @@
+//! # Flash-Capital Guard Module
+//!
+//! Defenses against collateral that only exists for the duration of a single
+//! transaction, such as deposits funded by a flash loan.
+//!
+//! ## Same-Ledger Guard
+//! Every deposit records the ledger sequence it happened in. While the guard
+//! is enabled, a borrow or withdrawal by the same user in that same ledger is
+//! rejected, so collateral cannot be deposited, borrowed against and pulled
+//! back out atomically.
+//!
+//! ## Minimum Holding Period
+//! Governance can give an asset a minimum holding period. Deposits of that
+//! asset are tracked as *maturing* collateral and do not count toward the
+//! user's borrowing power until the period has passed. Maturing deposits are
+//! merged per user: each new one adds to the amount and pushes the maturity
+//! time out to the latest deadline, which errs on the conservative side.
+//! Maturing collateral can still be withdrawn.
+//!
+//! ## Security
+//! - The same-ledger guard is admin-configurable and disabled by default.
+//! - Holding periods are only set through executed governance proposals and
+//!   capped at [`MAX_HOLDING_PERIOD_SECONDS`].
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+/// Maximum minimum holding period (7 days)
+pub const MAX_HOLDING_PERIOD_SECONDS: u64 = 7 * 86_400;
+
+/// Errors that can occur during flash guard operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FlashGuardError {
+    /// Caller is not authorized
+    Unauthorized = 1,
+    /// User deposited in the current ledger
+    SameLedgerDeposit = 2,
+    /// A parameter is out of range
+    InvalidParameter = 3,
+}
+
+/// Storage keys for flash guard data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum FlashGuardDataKey {
+    /// Whether the same-ledger guard is enabled: bool
+    SameLedgerGuard,
+    /// Ledger sequence of the user's last deposit: u32
+    LastDepositLedger(Address),
+    /// Minimum holding period per asset in seconds: u64
+    MinHoldingPeriod(Option<Address>),
+    /// Collateral not yet counting toward borrowing power: MaturingCollateral
+    MaturingCollateral(Address),
+}
+
+/// Recently deposited collateral still inside its holding period
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaturingCollateral {
+    pub amount: i128,
+    /// Timestamp from which the collateral counts
+    pub matures_at: u64,
+}
+
+/// Enable or disable the same-ledger deposit guard (admin only)
+///
+/// # Errors
+/// * `FlashGuardError::Unauthorized` - If caller is not admin
+#[allow(deprecated)]
+pub fn set_same_ledger_guard(
+    env: &Env,
+    caller: Address,
+    enabled: bool,
+) -> Result<(), FlashGuardError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| FlashGuardError::Unauthorized)?;
+
+    env.storage()
+        .persistent()
+        .set(&FlashGuardDataKey::SameLedgerGuard, &enabled);
+
+    let topics = (Symbol::new(env, "same_ledger_guard_updated"), caller);
+    env.events().publish(topics, enabled);
+
+    Ok(())
+}
+
+/// Whether the same-ledger deposit guard is enabled (default `false`)
+pub fn is_same_ledger_guard_enabled(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&FlashGuardDataKey::SameLedgerGuard)
+        .unwrap_or(false)
+}
+
+/// Record that `user` deposited in the current ledger
+pub fn record_deposit(env: &Env, user: &Address) {
+    env.storage().persistent().set(
+        &FlashGuardDataKey::LastDepositLedger(user.clone()),
+        &env.ledger().sequence(),
+    );
+}
+
+/// Reject the call if the guard is enabled and `user` deposited in this ledger
+///
+/// # Errors
+/// * `FlashGuardError::SameLedgerDeposit` - If `user` deposited in the current ledger
+pub fn require_not_same_ledger(env: &Env, user: &Address) -> Result<(), FlashGuardError> {
+    if !is_same_ledger_guard_enabled(env) {
+        return Ok(());
+    }
+    let last: Option<u32> = env
+        .storage()
+        .persistent()
+        .get(&FlashGuardDataKey::LastDepositLedger(user.clone()));
+    if last == Some(env.ledger().sequence()) {
+        return Err(FlashGuardError::SameLedgerDeposit);
+    }
+    Ok(())
+}
+
+/// Set the minimum holding period for an asset's collateral
+///
+/// Only reachable through an executed governance proposal. `0` disables it.
+///
+/// # Errors
+/// * `FlashGuardError::InvalidParameter` - If the period exceeds
+///   `MAX_HOLDING_PERIOD_SECONDS`
+#[allow(deprecated)]
+pub fn set_min_holding_period(
+    env: &Env,
+    asset: Option<Address>,
+    seconds: u64,
+) -> Result<(), FlashGuardError> {
+    if seconds > MAX_HOLDING_PERIOD_SECONDS {
+        return Err(FlashGuardError::InvalidParameter);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&FlashGuardDataKey::MinHoldingPeriod(asset.clone()), &seconds);
+
+    let topics = (Symbol::new(env, "min_holding_period_updated"),);
+    env.events().publish(topics, (asset, seconds));
+
+    Ok(())
+}
+
+/// Minimum holding period for an asset's collateral (default 0)
+pub fn get_min_holding_period(env: &Env, asset: Option<Address>) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&FlashGuardDataKey::MinHoldingPeriod(asset))
+        .unwrap_or(0)
+}
+
+/// Track a deposit of `asset` as maturing collateral if the asset has a holding period
+pub fn record_maturing_deposit(env: &Env, user: &Address, asset: Option<Address>, amount: i128) {
+    let period = get_min_holding_period(env, asset);
+    if period == 0 || amount <= 0 {
+        return;
+    }
+
+    let now = env.ledger().timestamp();
+    let matures_at = now.saturating_add(period);
+    let key = FlashGuardDataKey::MaturingCollateral(user.clone());
+    let maturing = match env
+        .storage()
+        .persistent()
+        .get::<FlashGuardDataKey, MaturingCollateral>(&key)
+    {
+        Some(existing) if existing.matures_at > now => MaturingCollateral {
+            amount: existing.amount.saturating_add(amount),
+            matures_at: existing.matures_at.max(matures_at),
+        },
+        _ => MaturingCollateral { amount, matures_at },
+    };
+    env.storage().persistent().set(&key, &maturing);
+}
+
+/// Collateral of `user` that does not yet count toward borrowing power
+pub fn get_maturing_collateral(env: &Env, user: &Address) -> i128 {
+    match env
+        .storage()
+        .persistent()
+        .get::<FlashGuardDataKey, MaturingCollateral>(&FlashGuardDataKey::MaturingCollateral(
+            user.clone(),
+        )) {
+        Some(maturing) if maturing.matures_at > env.ledger().timestamp() => maturing.amount,
+        _ => 0,
+    }
+}
+
+/// Collateral that counts toward borrowing power: `collateral` minus maturing deposits
+pub fn borrowable_collateral(env: &Env, user: &Address, collateral: i128) -> i128 {
+    collateral
+        .saturating_sub(get_maturing_collateral(env, user))
+        .max(0)
+}
@@ -188,7 +188,8 @@ fn clear_flash_loan(env: &Env, user: &Address, asset: &Address) {
 /// # Errors
 /// * `FlashLoanError::InvalidAmount` - If amount is zero, negative, or outside limits
 /// * `FlashLoanError::InvalidAsset` - If asset address is invalid
-/// * `FlashLoanError::InsufficientLiquidity` - If contract doesn't have enough liquidity
+/// * `FlashLoanError::InsufficientLiquidity` - If the contract and its strategy
+///   together don't have enough liquidity
 /// * `FlashLoanError::FlashLoanPaused` - If flash loans are paused
 /// * `FlashLoanError::Reentrancy` - If flash loan is already active for this user/asset
 /// * `FlashLoanError::InvalidCallback` - If callback address is invalid
@@ -244,7 +245,10 @@ pub fn execute_flash_loan(
     let fee = calculate_flash_loan_fee(env, amount)?;
     let total_repayment = amount.checked_add(fee).ok_or(FlashLoanError::Overflow)?;
 
-    // Check contract balance
+    // Recall strategy principal if idle liquidity falls short, then check
+    // the contract balance
+    crate::strategy::ensure_liquidity(env, &asset, amount)
+        .map_err(|_| FlashLoanError::InsufficientLiquidity)?;
     let token_client = soroban_sdk::token::Client::new(env, &asset);
     let contract_balance = token_client.balance(&env.current_contract_address());
     if contract_balance < amount {
@@ -350,6 +354,12 @@ pub fn repay_flash_loan(
                 .checked_add(record.fee)
                 .ok_or(FlashLoanError::Overflow)?),
         );
+        crate::revenue::record(
+            env,
+            Some(asset.clone()),
+            crate::revenue::RevenueSource::FlashLoanFee,
+            record.fee,
+        );
     }
 
     // Clear flash loan record
@@ -378,7 +388,8 @@ pub fn repay_flash_loan(
 /// * `fee_bps` - The new fee in basis points
 pub fn set_flash_loan_fee(env: &Env, caller: Address, fee_bps: i128) -> Result<(), FlashLoanError> {
     // Check authorization
-    crate::admin::require_admin(env, &caller).map_err(|_| FlashLoanError::InvalidCallback)?;
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| FlashLoanError::InvalidCallback)?;
 
     // Validate fee (must be between 0 and 10000 basis points)
     if !(0..=10000).contains(&fee_bps) {
@@ -406,7 +417,8 @@ pub fn configure_flash_loan(
     config: FlashLoanConfig,
 ) -> Result<(), FlashLoanError> {
     // Check authorization
-    crate::admin::require_admin(env, &caller).map_err(|_| FlashLoanError::InvalidCallback)?;
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| FlashLoanError::InvalidCallback)?;
 
     // Validate configuration
     if !(0..=10000).contains(&config.fee_bps) {
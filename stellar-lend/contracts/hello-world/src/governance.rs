@@ -9,11 +9,12 @@
 //!
 //! | Role       | Powers |
 //! |------------|--------|
-//! | **Admin**  | Initialize governance, cancel any proposal, manage guardians, set multisig config. |
+//! | **Admin**  | Initialize governance, cancel any proposal that is not queued, manage guardians and the veto guardian, set multisig config and per-action thresholds. |
 //! | **Guardian** | Initiate and approve social recovery (admin key rotation). |
-//! | **Multisig Admin** | Approve proposals for multisig execution. |
-//! | **Proposer** | Any token holder above `proposal_threshold` can create proposals. Can cancel own proposals. |
-//! | **Voter** | Any vote-token holder with non-zero balance can vote once per proposal during the voting window. |
+//! | **Veto Guardian** | Cancel any proposal before execution, including passed and queued ones. |
+//! | **Multisig Admin** | Approve proposals and, once the action category's approval threshold is met, execute emergency actions, spend-limited reserve withdrawals and the multisig's own collateral-ratio proposals without a vote. |
+//! | **Proposer** | Any holder of at least `proposal_threshold` vote tokens, held or staked, can create proposals. Can cancel own proposals until voting ends. |
+//! | **Voter** | Any address with vote tokens staked when a proposal was created can vote once on it during the voting window. |
 //! | **Executor** | Anyone can execute a queued proposal once the timelock elapses (permissionless). |
 //!
 //! ## Security Assumptions
@@ -23,15 +24,53 @@
 //! - All arithmetic uses checked operations to prevent overflow/underflow.
 //! - Reentrancy guard protects `execute_proposal` and `execute_generic_action`.
 //! - State transitions are validated: proposals move through a strict state machine
-//!   (Pending → Active → Queued → Executed) and may be Cancelled, Defeated, or Expired.
+//!   (Pending → Active → [Succeeded] → Queued → Executed) and may be Cancelled,
+//!   Defeated, or Expired.
+//! - Once voting ends, anyone can `finalize_proposal` to settle Succeeded or
+//!   Defeated from the tally; Defeated is terminal.
+//! - An optional governance-set `VoteWeightCap` limits the voting power any
+//!   single address contributes to one proposal to a share of the total
+//!   stake snapshotted before the proposal's creation. There is no vote
+//!   delegation, so the cap applies to each voter's own balance.
+//! - Governance can give each `ProposalCategory` its own voting threshold and
+//!   quorum. Both are snapshotted into the proposal at creation; a custom
+//!   threshold can raise but never lower the category's bar.
+//! - Emergency fast-track proposals (`create_emergency_proposal`) use a short
+//!   voting window and a supermajority threshold, skip the execution delay,
+//!   and cannot execute until enough guardians co-sign. Only emergency actions
+//!   (pauses and circuit-breaker resets) can be fast-tracked.
+//! - Governance caps what the multisig moves out of protocol holdings per
+//!   asset over a rolling 24-hour window (`MultisigSpendLimit`). Assets
+//!   without a limit cannot leave through the multisig at all, and the
+//!   multisig cannot execute a proposal that changes its own spend limit.
+//! - Multisig execution skips the token vote, so it is limited to an explicit
+//!   allowlist: emergency actions (pauses and circuit-breaker resets) and
+//!   `WithdrawReserves` under the spend limit run once approved, and
+//!   `MinCollateralRatio` runs only after the voting window plus the
+//!   execution delay and before the timelock expires. Every other proposal
+//!   type — including debt forgiveness, keeper slashing, reward emissions and
+//!   claim campaigns, as well as risk, oracle and asset parameters,
+//!   governance rules, upgrades and generic calls — needs a passed, queued
+//!   vote.
+//! - With quadratic voting enabled, proposals created afterwards tally
+//!   `floor(sqrt(power))` of each (capped) vote, using the shared
+//!   `stellarlend_common::fixed_point` math.
+//! - Quorum is a share of all vote tokens staked at the proposal's
+//!   snapshot: the combined stake of everyone who voted (for, against or
+//!   abstain, before caps and quadratic weighting) must reach it.
+//! - Voting power is the voter's staked vote-token balance at the end of the
+//!   last ledger before the proposal's creation, read from per-address
+//!   checkpoints. Tokens staked in the creation ledger or later, moved or
+//!   unstaked do not change it, so the same tokens cannot vote twice from
+//!   different addresses and nobody can stake in reaction to a proposal.
 //! - Double-execution is prevented by checking proposal status before and after execution.
 //!
 //! ## Token Transfer Flows
 //!
-//! This module does **not** transfer tokens directly. Voting power is read via
-//! `TokenClient::balance` at vote time (snapshot-less). Proposal execution
-//! delegates to other modules (`risk_params`, `risk_management`, `cross_asset`)
-//! which handle their own token flows.
+//! `stake_votes` and `unstake_votes` move vote tokens between the staker and
+//! this contract. Proposal execution delegates to other modules
+//! (`risk_params`, `risk_management`, `cross_asset`) which handle their own
+//! token flows.
 //!
 //! ## Storage Key Versioning
 //!
@@ -56,7 +95,7 @@
 
 #![allow(unused_variables)]
 
-use soroban_sdk::{token::TokenClient, Address, Env, String, Symbol, Val, Vec};
+use soroban_sdk::{token::TokenClient, Address, Env, Map, String, Symbol, Val, Vec};
 
 use crate::errors::GovernanceError;
 use crate::storage::{GovernanceDataKey, GuardianConfig};
@@ -64,13 +103,16 @@ use crate::storage::{GovernanceDataKey, GuardianConfig};
 use crate::events::{
     GovernanceInitializedEvent, GuardianAddedEvent, GuardianRemovedEvent, ProposalApprovedEvent,
     ProposalCancelledEvent, ProposalCreatedEvent, ProposalExecutedEvent, ProposalFailedEvent,
-    ProposalQueuedEvent, RecoveryApprovedEvent, RecoveryExecutedEvent, RecoveryStartedEvent,
-    VoteCastEvent,
+    ProposalFinalizedEvent, ProposalQueuedEvent, ProposalVetoedEvent, RecoveryApprovedEvent,
+    RecoveryExecutedEvent, RecoveryStartedEvent, VetoGuardianSetEvent, VoteCastEvent,
+    VotesStakedEvent, VotesUnstakedEvent,
 };
 
 use crate::types::{
-    Action, GovernanceConfig, MultisigConfig, Proposal, ProposalOutcome, ProposalStatus,
-    ProposalType, RecoveryRequest, Vote, VoteInfo, VoteType, BASIS_POINTS_SCALE,
+    Action, GovernanceConfig, MultisigConfig, MultisigSpend, Proposal, ProposalOutcome, ProposalStatus,
+    CategoryThreshold, EmergencyTrackConfig, ProposalCategory, ProposalType, RecoveryRequest, Vote,
+    VoteCheckpoint, VoteInfo, VoteType,
+    VoteWeightCap, BASIS_POINTS_SCALE,
     DEFAULT_EXECUTION_DELAY, DEFAULT_QUORUM_BPS, DEFAULT_RECOVERY_PERIOD,
     DEFAULT_TIMELOCK_DURATION, DEFAULT_VOTING_PERIOD, DEFAULT_VOTING_THRESHOLD,
 };
@@ -79,12 +121,18 @@ use crate::types::{
 // Constants
 // ========================================================================
 
+/// Minimum for-vote threshold for emergency fast-track proposals (two thirds).
+const MIN_EMERGENCY_VOTING_THRESHOLD: i128 = 6_667;
+
 /// Maximum number of guardians to prevent unbounded iteration.
 const MAX_GUARDIANS: u32 = 20;
 
 /// Maximum number of multisig admins.
 const MAX_MULTISIG_ADMINS: u32 = 20;
 
+/// Rolling window for the multisig treasury spend limit (24 hours).
+const MULTISIG_SPEND_WINDOW: u64 = 24 * 60 * 60;
+
 /// Maximum voting period (90 days) to prevent proposals that never expire.
 const MAX_VOTING_PERIOD: u64 = 90 * 24 * 60 * 60;
 
@@ -110,7 +158,7 @@ const MAX_TIMELOCK_DURATION: u64 = 30 * 24 * 60 * 60;
 /// * `vote_token` - The token contract used for voting power.
 /// * `voting_period` - Duration of voting window in seconds (default: 7 days).
 /// * `execution_delay` - Delay after queuing before execution is allowed (default: 2 days).
-/// * `quorum_bps` - Quorum as basis points of total voting power (default: 4000 = 40%).
+/// * `quorum_bps` - Quorum as basis points of the total staked vote power (default: 4000 = 40%).
 /// * `proposal_threshold` - Minimum token balance to create a proposal (default: 0).
 /// * `timelock_duration` - Max window for execution after delay elapses (default: 7 days).
 /// * `default_voting_threshold` - For-vote threshold in basis points (default: 5000 = 50%).
@@ -241,17 +289,19 @@ pub fn initialize(
 /// * `proposal_type` - The type/payload of the proposal.
 /// * `description` - Human-readable description.
 /// * `voting_threshold` - Override for the for-vote threshold in basis points.
+///   If the proposal's category has a configured bar, the override can only raise it.
 ///
 /// # Errors
 ///
 /// - `NotInitialized` — governance not yet initialized.
-/// - `InsufficientProposalPower` — proposer token balance below threshold.
+/// - `InsufficientProposalPower` — proposer's held and staked tokens are below threshold.
 /// - `MathOverflow` — proposal ID or timestamp arithmetic overflows.
 /// - `InvalidThreshold` — custom voting threshold exceeds `BASIS_POINTS_SCALE`.
+/// - `InvalidProposal` — a parameter change fails its module's validation.
 ///
 /// # Security
 ///
-/// Proposer must sign. Token balance is checked at creation time.
+/// Proposer must sign. Token holdings are checked at creation time.
 pub fn create_proposal(
     env: &Env,
     proposer: Address,
@@ -275,14 +325,102 @@ pub fn create_proposal(
     }
 
     // ── token threshold check ──
-    if config.proposal_threshold > 0 {
-        let token_client = TokenClient::new(env, &config.vote_token);
-        let balance = token_client.balance(&proposer);
-        if balance < config.proposal_threshold {
-            return Err(GovernanceError::InsufficientProposalPower);
-        }
+    if config.proposal_threshold > 0
+        && vote_token_holdings(env, &config, &proposer)? < config.proposal_threshold
+    {
+        return Err(GovernanceError::InsufficientProposalPower);
+    }
+
+    // ── category voting bar ──
+    let mut threshold = voting_threshold.unwrap_or(config.default_voting_threshold);
+    let mut quorum_bps = config.quorum_bps;
+    if let Some(bar) = get_category_threshold(env, proposal_type.category()) {
+        threshold = threshold.max(bar.voting_threshold);
+        quorum_bps = bar.quorum_bps;
+    }
+
+    store_new_proposal(
+        env,
+        proposer,
+        proposal_type,
+        description,
+        threshold,
+        quorum_bps,
+        config.voting_period,
+        false,
+    )
+}
+
+/// Create an emergency fast-track proposal.
+///
+/// Uses the emergency track's voting period, supermajority threshold and
+/// quorum. Once passed, the proposal can be executed immediately after the
+/// configured number of guardians co-sign it via `approve_emergency_proposal`.
+///
+/// # Errors
+///
+/// - `NotInitialized` — governance not yet initialized.
+/// - `EmergencyTrackDisabled` — the emergency track is not configured.
+/// - `InvalidProposal` — the proposal is not an emergency action (pause or
+///   circuit-breaker reset).
+/// - `InsufficientProposalPower` — proposer token balance below threshold.
+/// - `MathOverflow` — proposal ID or timestamp arithmetic overflows.
+///
+/// # Security
+///
+/// Proposer must sign. The supermajority and guardian co-sign requirements
+/// replace the timelock as the safety margin.
+pub fn create_emergency_proposal(
+    env: &Env,
+    proposer: Address,
+    proposal_type: ProposalType,
+    description: String,
+) -> Result<u64, GovernanceError> {
+    proposer.require_auth();
+
+    let config: GovernanceConfig = env
+        .storage()
+        .instance()
+        .get(&GovernanceDataKey::Config)
+        .ok_or(GovernanceError::NotInitialized)?;
+    let track = get_emergency_track_config(env).ok_or(GovernanceError::EmergencyTrackDisabled)?;
+
+    if proposal_type.category() != ProposalCategory::Emergency {
+        return Err(GovernanceError::InvalidProposal);
     }
 
+    if config.proposal_threshold > 0
+        && vote_token_holdings(env, &config, &proposer)? < config.proposal_threshold
+    {
+        return Err(GovernanceError::InsufficientProposalPower);
+    }
+
+    store_new_proposal(
+        env,
+        proposer,
+        proposal_type,
+        description,
+        track.voting_threshold,
+        track.quorum_bps,
+        track.voting_period,
+        true,
+    )
+}
+
+/// Persist a new proposal and emit its creation event.
+#[allow(clippy::too_many_arguments)]
+fn store_new_proposal(
+    env: &Env,
+    proposer: Address,
+    proposal_type: ProposalType,
+    description: String,
+    threshold: i128,
+    quorum_bps: u32,
+    voting_period: u64,
+    emergency: bool,
+) -> Result<u64, GovernanceError> {
+    validate_proposal_type(env, &proposal_type)?;
+
     let next_id: u64 = env
         .storage()
         .instance()
@@ -293,7 +431,7 @@ pub fn create_proposal(
 
     // ── checked end_time ──
     let end_time = now
-        .checked_add(config.voting_period)
+        .checked_add(voting_period)
         .ok_or(GovernanceError::MathOverflow)?;
 
     let proposal = Proposal {
@@ -305,12 +443,16 @@ pub fn create_proposal(
         start_time: now,
         end_time,
         execution_time: None,
-        voting_threshold: voting_threshold.unwrap_or(config.default_voting_threshold),
+        voting_threshold: threshold,
         for_votes: 0,
         against_votes: 0,
         abstain_votes: 0,
         total_voting_power: 0,
         created_at: now,
+        quadratic: is_quadratic_voting_enabled(env),
+        quorum_bps,
+        emergency,
+        turnout: 0,
     };
 
     env.storage()
@@ -352,9 +494,11 @@ pub fn create_proposal(
 
 /// Cast a vote on an active proposal.
 ///
-/// The voter's token balance at the time of voting determines their voting
-/// power. Each address can vote exactly once per proposal. Voting is only
-/// allowed while the proposal is `Active` and within the voting window.
+/// The voter's staked vote tokens before the proposal's creation ledger
+/// determine their voting power, limited by the vote weight cap if one is set
+/// and square-rooted on quadratic proposals. Each address can vote exactly
+/// once per proposal. Voting is only allowed while the proposal is `Active`,
+/// until the end of the voting window.
 ///
 /// # Arguments
 ///
@@ -369,7 +513,7 @@ pub fn create_proposal(
 /// - `ProposalNotActive` — proposal is not in the Active state.
 /// - `NotInVotingPeriod` — current time is past `end_time`.
 /// - `AlreadyVoted` — voter has already cast a vote.
-/// - `NoVotingPower` — voter's token balance is zero.
+/// - `NoVotingPower` — voter had nothing staked before the proposal was created.
 /// - `MathOverflow` — vote tally would overflow i128.
 ///
 /// # Security
@@ -385,11 +529,9 @@ pub fn vote(
 ) -> Result<(), GovernanceError> {
     voter.require_auth();
 
-    let config: GovernanceConfig = env
-        .storage()
-        .instance()
-        .get(&GovernanceDataKey::Config)
-        .ok_or(GovernanceError::NotInitialized)?;
+    if !env.storage().instance().has(&GovernanceDataKey::Config) {
+        return Err(GovernanceError::NotInitialized);
+    }
 
     let mut proposal: Proposal = env
         .storage()
@@ -420,8 +562,13 @@ pub fn vote(
     }
 
     // ── voting power ──
-    let token_client = TokenClient::new(env, &config.vote_token);
-    let voting_power = token_client.balance(&voter);
+    let staked = get_staked_votes_before(env, &voter, proposal.created_at);
+    let voting_power = capped_voting_power(env, staked, proposal.created_at)?;
+    let voting_power = if proposal.quadratic {
+        stellarlend_common::fixed_point::quadratic_weight(voting_power)
+    } else {
+        voting_power
+    };
 
     if voting_power == 0 {
         return Err(GovernanceError::NoVotingPower);
@@ -452,6 +599,10 @@ pub fn vote(
         .total_voting_power
         .checked_add(voting_power)
         .ok_or(GovernanceError::MathOverflow)?;
+    proposal.turnout = proposal
+        .turnout
+        .checked_add(staked)
+        .ok_or(GovernanceError::MathOverflow)?;
 
     // ── persist ──
     env.storage()
@@ -480,6 +631,451 @@ pub fn vote(
     Ok(())
 }
 
+// ========================================================================
+// Vote Staking
+// ========================================================================
+
+/// Stake vote tokens to gain voting power.
+///
+/// The tokens move into this contract. They count on proposals created
+/// after the current ledger, and toward the proposal threshold right away.
+/// Returns the staker's new stake.
+///
+/// # Errors
+///
+/// - `NotInitialized` — governance not initialized.
+/// - `InvalidStakeAmount` — `amount` is not positive.
+/// - `MathOverflow` — the stake would overflow.
+///
+/// # Security
+///
+/// Staker must sign; the transfer runs under that authorization, so no
+/// allowance is needed.
+pub fn stake_votes(env: &Env, staker: Address, amount: i128) -> Result<i128, GovernanceError> {
+    staker.require_auth();
+
+    if amount <= 0 {
+        return Err(GovernanceError::InvalidStakeAmount);
+    }
+    let config = get_config(env).ok_or(GovernanceError::NotInitialized)?;
+
+    let staked = get_staked_votes(env, &staker)
+        .checked_add(amount)
+        .ok_or(GovernanceError::MathOverflow)?;
+    let total = get_total_staked(env)
+        .checked_add(amount)
+        .ok_or(GovernanceError::MathOverflow)?;
+    TokenClient::new(env, &config.vote_token).transfer(
+        &staker,
+        &env.current_contract_address(),
+        &amount,
+    );
+    write_vote_checkpoint(
+        env,
+        GovernanceDataKey::VoteCheckpoints(staker.clone()),
+        staked,
+    );
+    write_vote_checkpoint(env, GovernanceDataKey::TotalStakeCheckpoints, total);
+
+    VotesStakedEvent {
+        staker,
+        amount,
+        staked,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+
+    Ok(staked)
+}
+
+/// Withdraw staked vote tokens.
+///
+/// Votes already cast keep their weight, and proposals created before the
+/// unstake still see the old stake. Returns the staker's remaining stake.
+///
+/// # Errors
+///
+/// - `NotInitialized` — governance not initialized.
+/// - `InvalidStakeAmount` — `amount` is not positive.
+/// - `InsufficientStake` — `amount` exceeds the staker's stake.
+/// - `InsufficientLiquidity` — pool and strategy cannot cover `amount`.
+pub fn unstake_votes(env: &Env, staker: Address, amount: i128) -> Result<i128, GovernanceError> {
+    staker.require_auth();
+
+    if amount <= 0 {
+        return Err(GovernanceError::InvalidStakeAmount);
+    }
+    let config = get_config(env).ok_or(GovernanceError::NotInitialized)?;
+
+    let staked = get_staked_votes(env, &staker)
+        .checked_sub(amount)
+        .filter(|staked| *staked >= 0)
+        .ok_or(GovernanceError::InsufficientStake)?;
+    let total = get_total_staked(env).saturating_sub(amount).max(0);
+    write_vote_checkpoint(
+        env,
+        GovernanceDataKey::VoteCheckpoints(staker.clone()),
+        staked,
+    );
+    write_vote_checkpoint(env, GovernanceDataKey::TotalStakeCheckpoints, total);
+    crate::strategy::ensure_liquidity(env, &config.vote_token, amount)
+        .map_err(|_| GovernanceError::InsufficientLiquidity)?;
+    TokenClient::new(env, &config.vote_token).transfer(
+        &env.current_contract_address(),
+        &staker,
+        &amount,
+    );
+
+    VotesUnstakedEvent {
+        staker,
+        amount,
+        staked,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+
+    Ok(staked)
+}
+
+/// Current staked vote tokens of `user`.
+pub fn get_staked_votes(env: &Env, user: &Address) -> i128 {
+    get_staked_votes_at(env, user, env.ledger().timestamp())
+}
+
+/// Vote tokens staked across all stakers.
+pub fn get_total_staked(env: &Env) -> i128 {
+    get_total_staked_at(env, env.ledger().timestamp())
+}
+
+/// Vote tokens staked across all stakers at the end of the ledger at `timestamp`.
+pub fn get_total_staked_at(env: &Env, timestamp: u64) -> i128 {
+    staked_at(
+        &read_vote_checkpoints(env, &GovernanceDataKey::TotalStakeCheckpoints),
+        timestamp,
+    )
+}
+
+/// Staked vote tokens of `user` at the end of the ledger at `timestamp`.
+pub fn get_staked_votes_at(env: &Env, user: &Address, timestamp: u64) -> i128 {
+    staked_at(
+        &read_vote_checkpoints(env, &GovernanceDataKey::VoteCheckpoints(user.clone())),
+        timestamp,
+    )
+}
+
+/// Staked vote tokens of `user` at the end of the last ledger before `timestamp`.
+///
+/// Proposals snapshot stake this way at their creation time, so a stake
+/// landing in the creation ledger, after the proposal is public, never counts.
+pub fn get_staked_votes_before(env: &Env, user: &Address, timestamp: u64) -> i128 {
+    match timestamp.checked_sub(1) {
+        Some(last) => get_staked_votes_at(env, user, last),
+        None => 0,
+    }
+}
+
+/// Vote tokens staked across all stakers at the end of the last ledger before
+/// `timestamp`.
+pub fn get_total_staked_before(env: &Env, timestamp: u64) -> i128 {
+    match timestamp.checked_sub(1) {
+        Some(last) => get_total_staked_at(env, last),
+        None => 0,
+    }
+}
+
+/// Stake recorded by the last checkpoint at or before `timestamp`.
+fn staked_at(checkpoints: &Vec<VoteCheckpoint>, timestamp: u64) -> i128 {
+    // Binary search for the last checkpoint at or before `timestamp`
+    let mut low = 0;
+    let mut high = checkpoints.len();
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match checkpoints.get(mid) {
+            Some(checkpoint) if checkpoint.timestamp <= timestamp => low = mid + 1,
+            _ => high = mid,
+        }
+    }
+    if low == 0 {
+        return 0;
+    }
+    checkpoints.get(low - 1).map(|c| c.staked).unwrap_or(0)
+}
+
+/// Lowest stake of `user` from the end of the ledger at `since` until now.
+///
+/// Used where a stake must have been held over a whole interval, so that
+/// staking just before a read and unstaking right after earns nothing.
+pub fn get_min_staked_votes_since(env: &Env, user: &Address, since: u64) -> i128 {
+    let mut min = get_staked_votes_at(env, user, since);
+    let key = GovernanceDataKey::VoteCheckpoints(user.clone());
+    for checkpoint in read_vote_checkpoints(env, &key).iter() {
+        if checkpoint.timestamp > since && checkpoint.staked < min {
+            min = checkpoint.staked;
+        }
+    }
+    min
+}
+
+/// Vote tokens `user` holds in their wallet plus those they have staked.
+fn vote_token_holdings(
+    env: &Env,
+    config: &GovernanceConfig,
+    user: &Address,
+) -> Result<i128, GovernanceError> {
+    TokenClient::new(env, &config.vote_token)
+        .balance(user)
+        .checked_add(get_staked_votes(env, user))
+        .ok_or(GovernanceError::MathOverflow)
+}
+
+fn read_vote_checkpoints(env: &Env, key: &GovernanceDataKey) -> Vec<VoteCheckpoint> {
+    env.storage().persistent().get(key).unwrap_or(Vec::new(env))
+}
+
+/// Record `staked` under `key` as of the current ledger, replacing an entry
+/// from the same ledger.
+fn write_vote_checkpoint(env: &Env, key: GovernanceDataKey, staked: i128) {
+    let now = env.ledger().timestamp();
+    let mut checkpoints = read_vote_checkpoints(env, &key);
+    let checkpoint = VoteCheckpoint {
+        timestamp: now,
+        staked,
+    };
+    match checkpoints.last() {
+        Some(last) if last.timestamp == now => {
+            checkpoints.set(checkpoints.len() - 1, checkpoint);
+        }
+        _ => checkpoints.push_back(checkpoint),
+    }
+    env.storage().persistent().set(&key, &checkpoints);
+}
+
+/// Set or clear the per-voter vote weight cap.
+///
+/// # Errors
+///
+/// - `InvalidThreshold` — `max_vote_bps` outside `(0, 10 000]`.
+///
+/// # Security
+///
+/// No authorization check — only reachable through an executed governance
+/// proposal (`ProposalType::VoteWeightCap`).
+pub fn set_vote_weight_cap(env: &Env, cap: Option<VoteWeightCap>) -> Result<(), GovernanceError> {
+    match cap {
+        Some(cap) => {
+            if cap.max_vote_bps <= 0 || cap.max_vote_bps > BASIS_POINTS_SCALE {
+                return Err(GovernanceError::InvalidThreshold);
+            }
+            env.storage()
+                .instance()
+                .set(&GovernanceDataKey::VoteWeightCap, &cap);
+        }
+        None => env
+            .storage()
+            .instance()
+            .remove(&GovernanceDataKey::VoteWeightCap),
+    }
+    Ok(())
+}
+
+/// Get the per-voter vote weight cap, if set.
+pub fn get_vote_weight_cap(env: &Env) -> Option<VoteWeightCap> {
+    env.storage().instance().get(&GovernanceDataKey::VoteWeightCap)
+}
+
+/// Enable or disable quadratic vote weighting for proposals created afterwards.
+///
+/// # Security
+///
+/// No authorization check — only reachable through an executed governance
+/// proposal (`ProposalType::QuadraticVoting`). Existing proposals keep the
+/// setting they were created with.
+pub fn set_quadratic_voting(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&GovernanceDataKey::QuadraticVoting, &enabled);
+}
+
+/// Whether new proposals use quadratic vote weighting (default `false`).
+pub fn is_quadratic_voting_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&GovernanceDataKey::QuadraticVoting)
+        .unwrap_or(false)
+}
+
+/// Configure or disable the emergency fast-track.
+///
+/// # Errors
+///
+/// - `InvalidVotingPeriod` — zero or above `MAX_VOTING_PERIOD`.
+/// - `InvalidThreshold` — threshold below two thirds or above 10 000.
+/// - `InvalidQuorum` — quorum exceeds 10 000.
+/// - `InvalidGuardianConfig` — no guardian co-signatures required.
+///
+/// # Security
+///
+/// No authorization check — only reachable through an executed governance
+/// proposal (`ProposalType::EmergencyTrackConfig`), which itself can never be
+/// fast-tracked.
+pub fn set_emergency_track_config(
+    env: &Env,
+    track: Option<EmergencyTrackConfig>,
+) -> Result<(), GovernanceError> {
+    match track {
+        Some(track) => {
+            if track.voting_period == 0 || track.voting_period > MAX_VOTING_PERIOD {
+                return Err(GovernanceError::InvalidVotingPeriod);
+            }
+            if track.voting_threshold < MIN_EMERGENCY_VOTING_THRESHOLD
+                || track.voting_threshold > BASIS_POINTS_SCALE
+            {
+                return Err(GovernanceError::InvalidThreshold);
+            }
+            if track.quorum_bps > 10_000 {
+                return Err(GovernanceError::InvalidQuorum);
+            }
+            if track.guardian_approvals == 0 {
+                return Err(GovernanceError::InvalidGuardianConfig);
+            }
+            env.storage()
+                .instance()
+                .set(&GovernanceDataKey::EmergencyTrackConfig, &track);
+        }
+        None => env
+            .storage()
+            .instance()
+            .remove(&GovernanceDataKey::EmergencyTrackConfig),
+    }
+    Ok(())
+}
+
+/// Get the emergency fast-track configuration, if enabled.
+pub fn get_emergency_track_config(env: &Env) -> Option<EmergencyTrackConfig> {
+    env.storage()
+        .instance()
+        .get(&GovernanceDataKey::EmergencyTrackConfig)
+}
+
+/// Co-sign an emergency proposal as a guardian.
+///
+/// # Errors
+///
+/// - `Unauthorized` — approver is not a guardian.
+/// - `ProposalNotFound` — no such proposal.
+/// - `InvalidProposal` — the proposal is not an emergency proposal.
+/// - `AlreadyVoted` — guardian already co-signed.
+///
+/// # Security
+///
+/// Guardian must sign.
+pub fn approve_emergency_proposal(
+    env: &Env,
+    guardian: Address,
+    proposal_id: u64,
+) -> Result<(), GovernanceError> {
+    guardian.require_auth();
+
+    let guardian_config: GuardianConfig = env
+        .storage()
+        .instance()
+        .get(&GovernanceDataKey::GuardianConfig)
+        .ok_or(GovernanceError::Unauthorized)?;
+    if !guardian_config.guardians.contains(&guardian) {
+        return Err(GovernanceError::Unauthorized);
+    }
+
+    let proposal: Proposal = env
+        .storage()
+        .persistent()
+        .get(&GovernanceDataKey::Proposal(proposal_id))
+        .ok_or(GovernanceError::ProposalNotFound)?;
+    if !proposal.emergency {
+        return Err(GovernanceError::InvalidProposal);
+    }
+
+    let approvals_key = GovernanceDataKey::EmergencyApprovals(proposal_id);
+    let mut approvals: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&approvals_key)
+        .unwrap_or_else(|| Vec::new(env));
+    if approvals.contains(&guardian) {
+        return Err(GovernanceError::AlreadyVoted);
+    }
+    approvals.push_back(guardian.clone());
+    env.storage().persistent().set(&approvals_key, &approvals);
+
+    ProposalApprovedEvent {
+        proposal_id,
+        approver: guardian,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+/// Set or clear the voting bar for a proposal category.
+///
+/// # Errors
+///
+/// - `InvalidThreshold` — threshold outside `[0, 10 000]`.
+/// - `InvalidQuorum` — quorum exceeds 10 000.
+///
+/// # Security
+///
+/// No authorization check — only reachable through an executed governance
+/// proposal (`ProposalType::CategoryThreshold`). Existing proposals keep the
+/// bar they were created with.
+pub fn set_category_threshold(
+    env: &Env,
+    category: ProposalCategory,
+    bar: Option<CategoryThreshold>,
+) -> Result<(), GovernanceError> {
+    let key = GovernanceDataKey::CategoryThreshold(category);
+    match bar {
+        Some(bar) => {
+            if bar.voting_threshold < 0 || bar.voting_threshold > BASIS_POINTS_SCALE {
+                return Err(GovernanceError::InvalidThreshold);
+            }
+            if bar.quorum_bps > 10_000 {
+                return Err(GovernanceError::InvalidQuorum);
+            }
+            env.storage().instance().set(&key, &bar);
+        }
+        None => env.storage().instance().remove(&key),
+    }
+    Ok(())
+}
+
+/// Get the voting bar for a proposal category, if configured.
+pub fn get_category_threshold(env: &Env, category: ProposalCategory) -> Option<CategoryThreshold> {
+    env.storage()
+        .instance()
+        .get(&GovernanceDataKey::CategoryThreshold(category))
+}
+
+/// Apply the vote weight cap to a voter's raw voting power.
+///
+/// The cap is a share of the total stake before `created_at`, the same
+/// snapshot the voter's own power and the quorum are measured against.
+fn capped_voting_power(
+    env: &Env,
+    raw_power: i128,
+    created_at: u64,
+) -> Result<i128, GovernanceError> {
+    let cap = match get_vote_weight_cap(env) {
+        Some(cap) => cap,
+        None => return Ok(raw_power),
+    };
+    let max_power = get_total_staked_before(env, created_at)
+        .checked_mul(cap.max_vote_bps)
+        .ok_or(GovernanceError::MathOverflow)?
+        / BASIS_POINTS_SCALE;
+    Ok(raw_power.min(max_power))
+}
+
 // ========================================================================
 // Queue Proposal
 // ========================================================================
@@ -500,7 +1096,7 @@ pub fn vote(
 /// - `NotInitialized` — governance not initialized.
 /// - `ProposalNotFound` — no such proposal.
 /// - `VotingNotEnded` — voting window has not closed yet.
-/// - `InvalidProposalStatus` — proposal is already Executed/Cancelled/Expired/Queued.
+/// - `InvalidProposalStatus` — proposal is already Executed/Cancelled/Expired/Queued/Defeated.
 /// - `ProposalExpired` — too much time passed since voting ended.
 /// - `MathOverflow` — arithmetic overflow computing quorum/threshold.
 ///
@@ -539,7 +1135,8 @@ pub fn queue_proposal(
         ProposalStatus::Executed
         | ProposalStatus::Cancelled
         | ProposalStatus::Expired
-        | ProposalStatus::Queued => {
+        | ProposalStatus::Queued
+        | ProposalStatus::Defeated => {
             return Err(GovernanceError::InvalidProposalStatus);
         }
         _ => {}
@@ -559,43 +1156,22 @@ pub fn queue_proposal(
     }
 
     // ── evaluate votes (checked arithmetic) ──
-    let total_votes = proposal
-        .for_votes
-        .checked_add(proposal.against_votes)
-        .and_then(|s| s.checked_add(proposal.abstain_votes))
-        .ok_or(GovernanceError::MathOverflow)?;
+    let outcome = tally_proposal(env, &config, &proposal)?;
+    let succeeded = outcome.succeeded;
+    let quorum_reached = outcome.quorum_reached;
 
-    let quorum_required = total_votes
-        .checked_mul(config.quorum_bps as i128)
-        .ok_or(GovernanceError::MathOverflow)?
-        / BASIS_POINTS_SCALE;
-    let quorum_reached = total_votes >= quorum_required;
-
-    let threshold_votes = proposal
-        .total_voting_power
-        .checked_mul(proposal.voting_threshold)
-        .ok_or(GovernanceError::MathOverflow)?
-        / BASIS_POINTS_SCALE;
-    let threshold_met = proposal.for_votes >= threshold_votes;
-
-    let succeeded = quorum_reached && threshold_met;
-
-    let outcome = ProposalOutcome {
-        proposal_id,
-        succeeded,
-        for_votes: proposal.for_votes,
-        against_votes: proposal.against_votes,
-        abstain_votes: proposal.abstain_votes,
-        quorum_reached,
-        quorum_required,
-    };
-
-    if succeeded {
-        let execution_time = now
-            .checked_add(config.execution_delay)
-            .ok_or(GovernanceError::MathOverflow)?;
-        proposal.execution_time = Some(execution_time);
-        proposal.status = ProposalStatus::Queued;
+    if succeeded {
+        // Emergency proposals skip the execution delay
+        let delay = if proposal.emergency {
+            0
+        } else {
+            config.execution_delay
+        };
+        let execution_time = now
+            .checked_add(delay)
+            .ok_or(GovernanceError::MathOverflow)?;
+        proposal.execution_time = Some(execution_time);
+        proposal.status = ProposalStatus::Queued;
 
         env.storage()
             .persistent()
@@ -629,6 +1205,111 @@ pub fn queue_proposal(
     Ok(outcome)
 }
 
+/// Evaluate a proposal's votes against quorum and its voting threshold.
+///
+/// Quorum compares the stake of everyone who voted with the total stake at
+/// the proposal's snapshot, so it measures participation of all vote power
+/// rather than of the votes cast.
+fn tally_proposal(
+    env: &Env,
+    config: &GovernanceConfig,
+    proposal: &Proposal,
+) -> Result<ProposalOutcome, GovernanceError> {
+    let quorum_required = get_total_staked_before(env, proposal.created_at)
+        .checked_mul(proposal.quorum_bps as i128)
+        .ok_or(GovernanceError::MathOverflow)?
+        / BASIS_POINTS_SCALE;
+    let quorum_reached = proposal.turnout > 0 && proposal.turnout >= quorum_required;
+
+    let threshold_votes = proposal
+        .total_voting_power
+        .checked_mul(proposal.voting_threshold)
+        .ok_or(GovernanceError::MathOverflow)?
+        / BASIS_POINTS_SCALE;
+    let threshold_met = proposal.for_votes >= threshold_votes;
+
+    Ok(ProposalOutcome {
+        proposal_id: proposal.id,
+        succeeded: quorum_reached && threshold_met,
+        for_votes: proposal.for_votes,
+        against_votes: proposal.against_votes,
+        abstain_votes: proposal.abstain_votes,
+        quorum_reached,
+        quorum_required,
+    })
+}
+
+// ========================================================================
+// Finalize Proposal
+// ========================================================================
+
+/// Settle a proposal's result once its voting period has ended.
+///
+/// Deterministically moves a `Pending`/`Active` proposal to `Succeeded` or
+/// `Defeated` using the same quorum and threshold rules as `queue_proposal`.
+/// A `Succeeded` proposal can then be queued; `Defeated` is terminal.
+///
+/// # Arguments
+///
+/// * `proposal_id` - The proposal to finalize.
+///
+/// # Errors
+///
+/// - `NotInitialized` — governance not initialized.
+/// - `ProposalNotFound` — no such proposal.
+/// - `VotingNotEnded` — voting window has not closed yet.
+/// - `InvalidProposalStatus` — proposal was already finalized, queued or closed.
+/// - `MathOverflow` — arithmetic overflow computing quorum/threshold.
+///
+/// # Security
+///
+/// Permissionless: the result only depends on recorded votes, so there is no
+/// caller input to trust.
+pub fn finalize_proposal(env: &Env, proposal_id: u64) -> Result<ProposalOutcome, GovernanceError> {
+    let config: GovernanceConfig = env
+        .storage()
+        .instance()
+        .get(&GovernanceDataKey::Config)
+        .ok_or(GovernanceError::NotInitialized)?;
+
+    let mut proposal: Proposal = env
+        .storage()
+        .persistent()
+        .get(&GovernanceDataKey::Proposal(proposal_id))
+        .ok_or(GovernanceError::ProposalNotFound)?;
+
+    let now = env.ledger().timestamp();
+    if now <= proposal.end_time {
+        return Err(GovernanceError::VotingNotEnded);
+    }
+    match proposal.status {
+        ProposalStatus::Pending | ProposalStatus::Active => {}
+        _ => return Err(GovernanceError::InvalidProposalStatus),
+    }
+
+    let outcome = tally_proposal(env, &config, &proposal)?;
+    proposal.status = if outcome.succeeded {
+        ProposalStatus::Succeeded
+    } else {
+        ProposalStatus::Defeated
+    };
+    env.storage()
+        .persistent()
+        .set(&GovernanceDataKey::Proposal(proposal_id), &proposal);
+
+    ProposalFinalizedEvent {
+        proposal_id,
+        succeeded: outcome.succeeded,
+        for_votes: outcome.for_votes,
+        against_votes: outcome.against_votes,
+        quorum_reached: outcome.quorum_reached,
+        timestamp: now,
+    }
+    .publish(env);
+
+    Ok(outcome)
+}
+
 // ========================================================================
 // Execute Proposal
 // ========================================================================
@@ -693,6 +1374,20 @@ pub fn execute_proposal(
         return Err(GovernanceError::ExecutionTooEarly);
     }
 
+    // ── emergency proposals need guardian co-signatures ──
+    if proposal.emergency {
+        let track =
+            get_emergency_track_config(env).ok_or(GovernanceError::EmergencyTrackDisabled)?;
+        let approvals: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&GovernanceDataKey::EmergencyApprovals(proposal_id))
+            .unwrap_or_else(|| Vec::new(env));
+        if approvals.len() < track.guardian_approvals {
+            return Err(GovernanceError::InsufficientApprovals);
+        }
+    }
+
     let expiry = execution_time
         .checked_add(config.timelock_duration)
         .ok_or(GovernanceError::MathOverflow)?;
@@ -731,6 +1426,83 @@ pub fn execute_proposal(
     Ok(())
 }
 
+/// Check a proposal's parameters before it is put to a vote.
+///
+/// Runs the target module's own validation without applying anything, so a
+/// proposal that could only fail at execution is rejected at creation.
+/// Values that depend on state at execution time, such as balances, are
+/// still checked when the proposal runs.
+///
+/// # Errors
+///
+/// - `InvalidProposal` — a parameter is out of range for its module.
+fn validate_proposal_type(env: &Env, proposal_type: &ProposalType) -> Result<(), GovernanceError> {
+    let valid = match proposal_type {
+        ProposalType::MinCollateralRatio(val) => {
+            crate::risk_params::check_risk_params_governed(env, Some(*val), None, None, None)
+                .is_ok()
+        }
+        ProposalType::RiskParams(min_cr, liq_threshold, close_factor, liq_incentive) => {
+            crate::risk_params::check_risk_params_governed(
+                env,
+                *min_cr,
+                *liq_threshold,
+                *close_factor,
+                *liq_incentive,
+            )
+            .is_ok()
+        }
+        ProposalType::AssetParams(asset, params) => {
+            *asset != env.current_contract_address()
+                && crate::deposit::validate_asset_params(params).is_ok()
+        }
+        ProposalType::AssetOracleConfig(_, config) => {
+            crate::oracle::validate_asset_oracle_config(env, config).is_ok()
+        }
+        ProposalType::OracleAddresses(asset, primary, fallback) => {
+            crate::oracle::validate_oracle_addresses(env, asset, primary, fallback).is_ok()
+        }
+        ProposalType::InterestRateConfig(base, kink, mult, jump, floor, ceiling, spread) => {
+            crate::interest_rate::check_interest_rate_config(
+                env, *base, *kink, *mult, *jump, *floor, *ceiling, *spread,
+            )
+            .is_ok()
+        }
+        ProposalType::AssetRateModel(_, Some(model)) => {
+            crate::interest_rate::validate_asset_rate_model(model).is_ok()
+        }
+        ProposalType::InsuranceFeeShare(share_bps) => {
+            (0..=crate::insurance::MAX_INSURANCE_FEE_SHARE_BPS).contains(share_bps)
+        }
+        ProposalType::OriginationFee(fee_bps) => {
+            (0..=crate::origination_fee::MAX_ORIGINATION_FEE_BPS).contains(fee_bps)
+        }
+        ProposalType::ReferralShare(share_bps) => {
+            (0..=crate::referral::MAX_REFERRAL_SHARE_BPS).contains(share_bps)
+        }
+        ProposalType::RewardEmission(market, schedule) => {
+            crate::rewards::validate_reward_emission(env, market, schedule.as_ref()).is_ok()
+        }
+        ProposalType::EModeCategory(_, config) => crate::emode::validate_category(config).is_ok(),
+        ProposalType::ParameterRampDuration(duration) => {
+            *duration <= crate::param_ramp::MAX_RAMP_DURATION
+        }
+        ProposalType::ListAsset(asset, listing) => {
+            crate::asset_registry::validate_listing(env, asset, listing).is_ok()
+        }
+        ProposalType::DelistAsset(asset, _) => {
+            crate::asset_registry::validate_delisting(env, asset).is_ok()
+        }
+        _ => true,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(GovernanceError::InvalidProposal)
+    }
+}
+
 /// Dispatch the proposal's action to the appropriate module.
 ///
 /// # Security
@@ -740,11 +1512,11 @@ pub fn execute_proposal(
 fn execute_proposal_type(env: &Env, proposal_type: &ProposalType) -> Result<(), GovernanceError> {
     match proposal_type {
         ProposalType::MinCollateralRatio(val) => {
-            crate::risk_params::set_risk_params(env, Some(*val), None, None, None)
+            crate::risk_params::set_risk_params_governed(env, Some(*val), None, None, None)
                 .map_err(|_| GovernanceError::ExecutionFailed)?;
         }
         ProposalType::RiskParams(min_cr, liq_threshold, close_factor, liq_incentive) => {
-            crate::risk_params::set_risk_params(
+            crate::risk_params::set_risk_params_governed(
                 env,
                 *min_cr,
                 *liq_threshold,
@@ -767,13 +1539,189 @@ fn execute_proposal_type(env: &Env, proposal_type: &ProposalType) -> Result<(),
             .map_err(|_| GovernanceError::ExecutionFailed)?;
         }
         ProposalType::PauseSwitch(op, paused) => {
-            let admin = env.current_contract_address();
-            crate::risk_management::set_pause_switch(env, admin, op.clone(), *paused)
+            crate::risk_management::set_pause_switch_governed(env, *op, *paused)
                 .map_err(|_| GovernanceError::ExecutionFailed)?;
         }
         ProposalType::EmergencyPause(paused) => {
-            let admin = env.current_contract_address();
-            crate::risk_management::set_emergency_pause(env, admin, *paused)
+            crate::risk_management::set_emergency_pause_governed(env, *paused);
+        }
+        ProposalType::SeedProtocolLiquidity(asset, amount) => {
+            crate::reserve::seed_protocol_liquidity(env, asset.clone(), *amount)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::LiquidationInsuranceShare(share_bps) => {
+            crate::reserve::set_liquidation_insurance_share(env, *share_bps)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::RiskEngineSettings(enabled, cooldown_seconds) => {
+            crate::risk_engine::set_settings(env, *enabled, *cooldown_seconds);
+        }
+        ProposalType::RiskEngineRateBand(band) => {
+            crate::risk_engine::set_rate_band(env, band.clone())
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::RiskEngineCollateralBand(asset, band) => {
+            crate::risk_engine::set_collateral_band(env, asset.clone(), band.clone())
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::RevertRiskAdjustment(id) => {
+            crate::risk_engine::revert_adjustment(env, *id)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::CreateClaimCampaign(token, merkle_root, total_amount) => {
+            crate::claims::create_campaign(env, token.clone(), merkle_root.clone(), *total_amount)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::RewardEmission(market, schedule) => {
+            crate::rewards::set_reward_emission(env, market.clone(), schedule.clone())
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::AssetOracleConfig(asset, config) => {
+            crate::oracle::apply_asset_oracle_config(env, asset.clone(), config.clone())
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::SetStrategy(asset, strategy, max_allocation_bps) => {
+            crate::strategy::set_strategy(env, asset.clone(), strategy.clone(), *max_allocation_bps)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::StrategyDeposit(asset, amount) => {
+            crate::strategy::strategy_deposit(env, asset.clone(), *amount)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::StrategyWithdraw(asset, amount) => {
+            crate::strategy::strategy_withdraw(env, asset.clone(), *amount)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::CreditHistoryConfig(config) => {
+            crate::credit_history::set_config(env, config.clone())
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::ParameterChangeLimit(limit_bps) => {
+            crate::risk_params::set_max_parameter_change_bps(env, *limit_bps)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::ParameterRampDuration(duration) => {
+            crate::param_ramp::set_ramp_duration(env, *duration)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::RiskTierParams(tier, params) => {
+            crate::risk_tier::set_tier_params(env, *tier, params.clone())
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::KeeperConfig(config) => {
+            crate::keeper::set_config(env, config.clone())
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::SlashKeeper(keeper, amount) => {
+            crate::keeper::slash_keeper(env, keeper.clone(), *amount)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::MinHoldingPeriod(asset, seconds) => {
+            crate::flash_guard::set_min_holding_period(env, asset.clone(), *seconds)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::ResetCircuitBreaker(asset) => {
+            crate::circuit_breaker::clear(env, asset.clone());
+        }
+        ProposalType::AnalyticsVerbosity(level) => {
+            crate::analytics::set_verbosity(env, *level);
+        }
+        ProposalType::LiquidationHysteresis(buffer_bps) => {
+            crate::risk_params::set_liquidation_hysteresis_bps(env, *buffer_bps)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::SavingsRate(asset, rate_bps) => {
+            crate::savings::set_savings_rate(env, asset.clone(), *rate_bps)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::AssetRateModel(asset, model) => {
+            crate::interest_rate::set_asset_rate_model(env, asset.clone(), model.clone())
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::WithdrawReserves(to, asset, amount) => {
+            crate::reserve::release_reserves(env, to.clone(), asset.clone(), *amount)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::AssetParams(asset, params) => {
+            crate::deposit::set_asset_params(env, asset.clone(), params.clone())
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::OracleAddresses(asset, primary, fallback) => {
+            crate::oracle::apply_oracle_addresses(
+                env,
+                asset.clone(),
+                primary.clone(),
+                fallback.clone(),
+            )
+            .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::InterestRateConfig(base, kink, mult, jump, floor, ceiling, spread) => {
+            crate::interest_rate::set_interest_rate_config_governed(
+                env, *base, *kink, *mult, *jump, *floor, *ceiling, *spread,
+            )
+            .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::InsuranceFeeShare(share_bps) => {
+            crate::insurance::set_insurance_fee_share(env, *share_bps)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::OriginationFee(fee_bps) => {
+            crate::origination_fee::set_origination_fee_bps(env, *fee_bps)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::ReferralShare(share_bps) => {
+            crate::referral::set_referral_share_bps(env, *share_bps)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::EModeCategory(id, config) => {
+            crate::emode::set_category(env, *id, config.clone())
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::EModeAsset(asset, category) => {
+            crate::emode::set_asset_category(env, asset.clone(), *category)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::LiquidatorWhitelist(enabled) => {
+            crate::liquidate::set_liquidator_whitelist_enabled(env, *enabled);
+        }
+        ProposalType::Liquidator(liquidator, true) => {
+            crate::liquidate::add_liquidator(env, liquidator.clone());
+        }
+        ProposalType::Liquidator(liquidator, false) => {
+            crate::liquidate::remove_liquidator(env, liquidator.clone());
+        }
+        ProposalType::ListAsset(asset, listing) => {
+            crate::asset_registry::list_asset(env, asset.clone(), listing.clone())
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::DelistAsset(asset, ramp_duration) => {
+            crate::asset_registry::start_delisting(env, asset.clone(), *ramp_duration)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::UpgradeContract(new_wasm_hash) => {
+            crate::upgrade::upgrade(env, new_wasm_hash.clone());
+        }
+        ProposalType::VoteWeightCap(cap) => {
+            set_vote_weight_cap(env, cap.clone())?;
+        }
+        ProposalType::QuadraticVoting(enabled) => {
+            set_quadratic_voting(env, *enabled);
+        }
+        ProposalType::CategoryThreshold(category, bar) => {
+            set_category_threshold(env, *category, bar.clone())?;
+        }
+        ProposalType::EmergencyTrackConfig(track) => {
+            set_emergency_track_config(env, track.clone())?;
+        }
+        ProposalType::MultisigSpendLimit(asset, limit) => {
+            set_multisig_spend_limit(env, asset.clone(), *limit)?;
+        }
+        ProposalType::RescueTokens(asset, amount, recipient) => {
+            crate::accounting::rescue_tokens(env, asset.clone(), *amount, recipient.clone())
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        ProposalType::ForgiveDebt(borrower, asset, amount) => {
+            crate::reserve::forgive_debt(env, borrower.clone(), asset.clone(), *amount)
                 .map_err(|_| GovernanceError::ExecutionFailed)?;
         }
         ProposalType::GenericAction(action) => {
@@ -799,27 +1747,34 @@ fn execute_generic_action(env: &Env, action: &Action) -> Result<(), GovernanceEr
 // Cancel Proposal
 // ========================================================================
 
-/// Cancel a proposal. Only the proposer or admin can cancel.
+/// Cancel a proposal.
+///
+/// - The proposer can cancel their own proposal until voting ends, unless it
+///   has already passed.
+/// - The admin can cancel any proposal that is not yet queued.
+/// - The veto guardian can cancel any proposal before execution, including
+///   passed and queued ones; this also emits a `ProposalVetoedEvent`.
 ///
-/// Proposals that are already `Executed` or `Queued` cannot be cancelled
-/// (queued proposals have passed governance and are awaiting execution).
+/// Executed, cancelled, defeated and expired proposals cannot be cancelled.
 ///
 /// # Arguments
 ///
-/// * `caller` - The address cancelling (must be proposer or admin).
+/// * `caller` - The address cancelling (proposer, admin or veto guardian).
 /// * `proposal_id` - The proposal to cancel.
 ///
 /// # Errors
 ///
 /// - `NotInitialized` — governance not initialized.
 /// - `ProposalNotFound` — no such proposal.
-/// - `Unauthorized` — caller is neither proposer nor admin.
-/// - `InvalidProposalStatus` — proposal is Executed or Queued.
+/// - `Unauthorized` — caller is not the proposer, admin or veto guardian.
+/// - `NotInVotingPeriod` — the proposer cancels after voting ended.
+/// - `InvalidProposalStatus` — the proposal is terminal, or past the point
+///   the caller's role may cancel it.
 ///
 /// # Security
 ///
-/// Admin can cancel any non-terminal proposal. Proposer can only cancel
-/// their own. Already-executed proposals cannot be rolled back.
+/// Already-executed proposals cannot be rolled back. Only the veto guardian
+/// can stop a queued proposal.
 pub fn cancel_proposal(
     env: &Env,
     caller: Address,
@@ -839,25 +1794,89 @@ pub fn cancel_proposal(
         .get(&GovernanceDataKey::Proposal(proposal_id))
         .ok_or(GovernanceError::ProposalNotFound)?;
 
-    if caller != proposal.proposer && caller != admin {
+    let now = env.ledger().timestamp();
+    let vetoed = get_veto_guardian(env).as_ref() == Some(&caller);
+    if !vetoed && caller != admin && caller != proposal.proposer {
         return Err(GovernanceError::Unauthorized);
     }
 
     match proposal.status {
-        ProposalStatus::Executed | ProposalStatus::Queued => {
-            return Err(GovernanceError::InvalidProposalStatus);
+        ProposalStatus::Executed
+        | ProposalStatus::Cancelled
+        | ProposalStatus::Defeated
+        | ProposalStatus::Expired => return Err(GovernanceError::InvalidProposalStatus),
+        ProposalStatus::Queued if !vetoed => return Err(GovernanceError::InvalidProposalStatus),
+        ProposalStatus::Succeeded if !vetoed && caller != admin => {
+            return Err(GovernanceError::InvalidProposalStatus)
         }
         _ => {}
     }
+    if !vetoed && caller != admin && now > proposal.end_time {
+        return Err(GovernanceError::NotInVotingPeriod);
+    }
 
     proposal.status = ProposalStatus::Cancelled;
     env.storage()
         .persistent()
         .set(&GovernanceDataKey::Proposal(proposal_id), &proposal);
 
+    if vetoed {
+        ProposalVetoedEvent {
+            proposal_id,
+            guardian: caller.clone(),
+            timestamp: now,
+        }
+        .publish(env);
+    }
     ProposalCancelledEvent {
         proposal_id,
         caller,
+        timestamp: now,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+/// Set or clear the veto guardian (admin-only).
+///
+/// The veto guardian can cancel any proposal before it executes, as a last
+/// line of defence against a malicious proposal that won its vote.
+///
+/// # Errors
+///
+/// - `NotInitialized` — governance not initialized.
+/// - `Unauthorized` — caller is not admin.
+pub fn set_veto_guardian(
+    env: &Env,
+    caller: Address,
+    guardian: Option<Address>,
+) -> Result<(), GovernanceError> {
+    caller.require_auth();
+
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&GovernanceDataKey::Admin)
+        .ok_or(GovernanceError::NotInitialized)?;
+    if caller != admin {
+        return Err(GovernanceError::Unauthorized);
+    }
+
+    match &guardian {
+        Some(guardian) => env
+            .storage()
+            .instance()
+            .set(&GovernanceDataKey::VetoGuardian, guardian),
+        None => env
+            .storage()
+            .instance()
+            .remove(&GovernanceDataKey::VetoGuardian),
+    }
+
+    VetoGuardianSetEvent {
+        guardian,
+        set_by: caller,
         timestamp: env.ledger().timestamp(),
     }
     .publish(env);
@@ -865,6 +1884,13 @@ pub fn cancel_proposal(
     Ok(())
 }
 
+/// Get the veto guardian, if one is set.
+pub fn get_veto_guardian(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&GovernanceDataKey::VetoGuardian)
+}
+
 // ========================================================================
 // Multisig Operations
 // ========================================================================
@@ -974,10 +2000,303 @@ pub fn set_multisig_config(
         return Err(GovernanceError::InvalidMultisigConfig);
     }
 
+    // Per-action thresholds must stay reachable with the new signer set
+    for (_, action_threshold) in get_multisig_action_thresholds(env).iter() {
+        if action_threshold > admins.len() {
+            return Err(GovernanceError::InvalidMultisigConfig);
+        }
+    }
+
     let config = MultisigConfig { admins, threshold };
     env.storage()
-        .instance()
-        .set(&GovernanceDataKey::MultisigConfig, &config);
+        .instance()
+        .set(&GovernanceDataKey::MultisigConfig, &config);
+
+    Ok(())
+}
+
+/// Set or clear the multisig approval threshold for an action category
+/// (admin-only).
+///
+/// Categories without their own threshold use the multisig config's
+/// threshold, e.g. 2-of-5 by default but 4-of-5 for `Treasury` actions.
+///
+/// # Errors
+///
+/// - `NotInitialized` — governance or multisig not initialized.
+/// - `Unauthorized` — caller is not the governance admin.
+/// - `InvalidMultisigConfig` — threshold is zero or exceeds the signer count.
+///
+/// # Security
+///
+/// Only the governance admin can modify multisig thresholds.
+pub fn set_multisig_action_threshold(
+    env: &Env,
+    caller: Address,
+    category: ProposalCategory,
+    threshold: Option<u32>,
+) -> Result<(), GovernanceError> {
+    caller.require_auth();
+
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&GovernanceDataKey::Admin)
+        .ok_or(GovernanceError::NotInitialized)?;
+
+    if caller != admin {
+        return Err(GovernanceError::Unauthorized);
+    }
+
+    let mut thresholds = get_multisig_action_thresholds(env);
+    match threshold {
+        Some(threshold) => {
+            let config = get_multisig_config(env).ok_or(GovernanceError::NotInitialized)?;
+            if threshold == 0 || threshold > config.admins.len() {
+                return Err(GovernanceError::InvalidMultisigConfig);
+            }
+            thresholds.set(category, threshold);
+        }
+        None => {
+            thresholds.remove(category);
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&GovernanceDataKey::MultisigActionThresholds, &thresholds);
+
+    Ok(())
+}
+
+/// All per-action multisig thresholds that override the default threshold.
+pub fn get_multisig_action_thresholds(env: &Env) -> Map<ProposalCategory, u32> {
+    env.storage()
+        .instance()
+        .get(&GovernanceDataKey::MultisigActionThresholds)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Approvals required to execute an action of `category` through the
+/// multisig (the category threshold, else the multisig config threshold).
+pub fn get_multisig_action_threshold(env: &Env, category: ProposalCategory) -> u32 {
+    get_multisig_action_thresholds(env)
+        .get(category)
+        .unwrap_or_else(|| get_multisig_threshold(env))
+}
+
+/// Execute a proposal through the multisig once its action category's
+/// approval threshold is met.
+///
+/// Only emergency actions, `WithdrawReserves` within the spend limit, and
+/// `MinCollateralRatio` once the voting window and execution delay have
+/// passed, can be executed this way.
+///
+/// # Errors
+///
+/// - `NotInitialized` — multisig not configured.
+/// - `Unauthorized` — executor is not a multisig admin.
+/// - `ProposalNotFound` — no such proposal.
+/// - `AlreadyExecuted` — proposal was already executed.
+/// - `InvalidProposalStatus` — proposal was cancelled, defeated or expired.
+/// - `InvalidProposal` — the proposal type is not multisig-executable.
+/// - `ProposalNotReady` — a `MinCollateralRatio` proposal's voting window and
+///   execution delay have not yet passed.
+/// - `ProposalExpired` — a `MinCollateralRatio` proposal's timelock elapsed.
+/// - `InsufficientApprovals` — fewer approvals than the category threshold.
+/// - `SpendLimitNotSet` — the proposal moves an asset that has no multisig
+///   spend limit.
+/// - `SpendLimitExceeded` — the treasury outflow would exceed the asset's
+///   rolling 24-hour multisig spend limit.
+///
+/// # Security
+///
+/// Executor must sign. The proposal is marked executed before dispatch and
+/// rolled back if the action fails.
+pub fn execute_multisig_proposal(
+    env: &Env,
+    executor: Address,
+    proposal_id: u64,
+) -> Result<(), GovernanceError> {
+    executor.require_auth();
+
+    let multisig_config = get_multisig_config(env).ok_or(GovernanceError::NotInitialized)?;
+    if !multisig_config.admins.contains(&executor) {
+        return Err(GovernanceError::Unauthorized);
+    }
+
+    let mut proposal: Proposal = env
+        .storage()
+        .persistent()
+        .get(&GovernanceDataKey::Proposal(proposal_id))
+        .ok_or(GovernanceError::ProposalNotFound)?;
+
+    match proposal.status {
+        ProposalStatus::Executed => return Err(GovernanceError::AlreadyExecuted),
+        ProposalStatus::Cancelled | ProposalStatus::Defeated | ProposalStatus::Expired => {
+            return Err(GovernanceError::InvalidProposalStatus)
+        }
+        _ => {}
+    }
+    // Everything outside the allowlist needs a full governance vote
+    match &proposal.proposal_type {
+        ProposalType::MinCollateralRatio(..) => {
+            let config: GovernanceConfig = env
+                .storage()
+                .instance()
+                .get(&GovernanceDataKey::Config)
+                .ok_or(GovernanceError::NotInitialized)?;
+            let ready_at = proposal
+                .end_time
+                .checked_add(config.execution_delay)
+                .ok_or(GovernanceError::MathOverflow)?;
+            let expires_at = ready_at
+                .checked_add(config.timelock_duration)
+                .ok_or(GovernanceError::MathOverflow)?;
+            let now = env.ledger().timestamp();
+            if now < ready_at {
+                return Err(GovernanceError::ProposalNotReady);
+            }
+            if now > expires_at {
+                return Err(GovernanceError::ProposalExpired);
+            }
+        }
+        ProposalType::WithdrawReserves(..) => {}
+        other => {
+            if other.category() != ProposalCategory::Emergency {
+                return Err(GovernanceError::InvalidProposal);
+            }
+        }
+    }
+
+    let approvals = get_proposal_approvals(env, proposal_id).unwrap_or_else(|| Vec::new(env));
+    let required = get_multisig_action_threshold(env, proposal.proposal_type.category());
+    if approvals.len() < required {
+        return Err(GovernanceError::InsufficientApprovals);
+    }
+
+    if let Some((asset, amount)) = proposal.proposal_type.treasury_outflow() {
+        record_multisig_spend(env, asset, amount)?;
+    }
+
+    // ── mark executed BEFORE dispatching (CEI pattern) ──
+    let previous_status = proposal.status.clone();
+    proposal.status = ProposalStatus::Executed;
+    env.storage()
+        .persistent()
+        .set(&GovernanceDataKey::Proposal(proposal_id), &proposal);
+
+    if let Err(err) = execute_proposal_type(env, &proposal.proposal_type) {
+        proposal.status = previous_status;
+        env.storage()
+            .persistent()
+            .set(&GovernanceDataKey::Proposal(proposal_id), &proposal);
+        return Err(err);
+    }
+
+    ProposalExecutedEvent {
+        proposal_id,
+        executor,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+/// Set or clear the multisig's rolling 24-hour spend limit for an asset.
+///
+/// Without a limit, the multisig cannot move the asset at all.
+///
+/// # Errors
+///
+/// - `InvalidThreshold` — negative limit.
+///
+/// # Security
+///
+/// No authorization check — only reachable through a proposal executed by
+/// governance vote (`ProposalType::MultisigSpendLimit`); the multisig path
+/// refuses to execute it.
+pub fn set_multisig_spend_limit(
+    env: &Env,
+    asset: Option<Address>,
+    limit: Option<i128>,
+) -> Result<(), GovernanceError> {
+    let key = GovernanceDataKey::MultisigSpendLimit(asset);
+    match limit {
+        Some(limit) => {
+            if limit < 0 {
+                return Err(GovernanceError::InvalidThreshold);
+            }
+            env.storage().persistent().set(&key, &limit);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+    Ok(())
+}
+
+/// Get the multisig's rolling 24-hour spend limit for an asset, if set.
+pub fn get_multisig_spend_limit(env: &Env, asset: Option<Address>) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&GovernanceDataKey::MultisigSpendLimit(asset))
+}
+
+/// Multisig spends of an asset within the last 24 hours, oldest first.
+fn recent_multisig_spends(env: &Env, asset: &Option<Address>) -> Vec<MultisigSpend> {
+    let now = env.ledger().timestamp();
+    let log: Vec<MultisigSpend> = env
+        .storage()
+        .persistent()
+        .get(&GovernanceDataKey::MultisigSpendLog(asset.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+    let mut recent = Vec::new(env);
+    for spend in log.iter() {
+        if now.saturating_sub(spend.timestamp) < MULTISIG_SPEND_WINDOW {
+            recent.push_back(spend);
+        }
+    }
+    recent
+}
+
+/// Total the multisig moved of an asset within the last 24 hours.
+pub fn get_multisig_spent(env: &Env, asset: Option<Address>) -> i128 {
+    recent_multisig_spends(env, &asset)
+        .iter()
+        .fold(0i128, |total, spend| total.saturating_add(spend.amount))
+}
+
+/// Record a multisig treasury outflow, enforcing the asset's spend limit.
+///
+/// Fails closed: an asset without a spend limit cannot leave through the
+/// multisig at all.
+fn record_multisig_spend(
+    env: &Env,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<(), GovernanceError> {
+    let limit =
+        get_multisig_spend_limit(env, asset.clone()).ok_or(GovernanceError::SpendLimitNotSet)?;
+
+    let mut recent = recent_multisig_spends(env, &asset);
+    let mut spent: i128 = 0;
+    for spend in recent.iter() {
+        spent = spent
+            .checked_add(spend.amount)
+            .ok_or(GovernanceError::MathOverflow)?;
+    }
+    // Saturates so an unbounded outflow reads as over the limit
+    let total = spent.saturating_add(amount.max(0));
+    if total > limit {
+        return Err(GovernanceError::SpendLimitExceeded);
+    }
+
+    recent.push_back(MultisigSpend {
+        amount: amount.max(0),
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage()
+        .persistent()
+        .set(&GovernanceDataKey::MultisigSpendLog(asset), &recent);
 
     Ok(())
 }
@@ -1455,6 +2774,19 @@ pub fn get_vote(env: &Env, proposal_id: u64, voter: Address) -> Option<VoteInfo>
         .get(&GovernanceDataKey::Vote(proposal_id, voter))
 }
 
+/// Hand the governance admin to the contract itself, if governance is
+/// initialized.
+///
+/// Called when the protocol admin is renounced, so admin-only governance
+/// calls are then only reachable through executed proposals.
+pub fn renounce_governance_admin(env: &Env) {
+    if env.storage().instance().has(&GovernanceDataKey::Admin) {
+        env.storage()
+            .instance()
+            .set(&GovernanceDataKey::Admin, &env.current_contract_address());
+    }
+}
+
 /// Get the governance configuration, or `None` if not initialized.
 pub fn get_config(env: &Env) -> Option<GovernanceConfig> {
     env.storage().instance().get(&GovernanceDataKey::Config)
@@ -1562,14 +2894,6 @@ pub fn set_multisig_threshold(
     crate::multisig::set_ms_threshold(env, caller, threshold)
 }
 
-pub fn execute_multisig_proposal(
-    env: &Env,
-    executor: Address,
-    proposal_id: u64,
-) -> Result<(), GovernanceError> {
-    crate::multisig::ms_execute(env, executor, proposal_id)
-}
-
 pub fn propose_set_min_collateral_ratio(
     env: &Env,
     proposer: Address,
@@ -1590,12 +2914,15 @@ mod tests {
     //! - Initialization (happy path, double-init, invalid params)
     //! - Proposal creation (happy path, insufficient power, invalid threshold)
     //! - Voting (happy path, double vote, after deadline, zero power, overflow)
+    //! - Vote staking (snapshot before creation, moved tokens, creation ledger, unstake)
     //! - Queue (happy path, defeated, expired, already queued)
+    //! - Finalize (succeeded, defeated, before deadline, already finalized)
     //! - Execution (happy path, double execution, too early, expired)
-    //! - Cancellation (by proposer, by admin, unauthorized, already executed/queued)
-    //! - Multisig (approve, double approve, unauthorized, config)
+    //! - Cancellation (by proposer, by admin, veto guardian, unauthorized, already executed/queued)
+    //! - Multisig (approve, double approve, unauthorized, config, per-action thresholds)
     //! - Guardian (add, remove, duplicate, threshold, max count)
     //! - Recovery (start, approve, execute, expired, duplicate, no recovery)
+    //! - Parameter dispatch (asset params, interest curve, oracles, creation-time validation)
 
     use super::*;
     use soroban_sdk::testutils::{Address as _, Ledger as _};
@@ -1644,6 +2971,20 @@ mod tests {
         sac.mint(to, &amount);
     }
 
+    /// Mint vote tokens to `to`, stake them and close the ledger, so the
+    /// stake counts for proposals created afterwards
+    fn stake(
+        env: &Env,
+        client: &HelloContractClient,
+        token: &Address,
+        to: &Address,
+        amount: i128,
+    ) {
+        mint(env, token, to, amount);
+        client.gov_stake(to, &amount);
+        env.ledger().with_mut(|li| li.timestamp += 1);
+    }
+
     // ────────────────────────────────────────────────────────────────────
     // Initialization
     // ────────────────────────────────────────────────────────────────────
@@ -1730,7 +3071,7 @@ mod tests {
     fn test_create_proposal_happy_path() {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &proposer, 1_000);
 
         let id = client.gov_create_proposal(
             &proposer,
@@ -1750,7 +3091,7 @@ mod tests {
     fn test_create_proposal_insufficient_power() {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
-        mint(&env, &token, &proposer, 50); // below 100 threshold
+        stake(&env, &client, &token, &proposer, 50); // below 100 threshold
 
         let result = client.try_gov_create_proposal(
             &proposer,
@@ -1765,7 +3106,7 @@ mod tests {
     fn test_create_proposal_invalid_threshold() {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &proposer, 1_000);
 
         let result = client.try_gov_create_proposal(
             &proposer,
@@ -1786,9 +3127,9 @@ mod tests {
         let proposer = Address::generate(&env);
         let voter1 = Address::generate(&env);
         let voter2 = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
-        mint(&env, &token, &voter1, 500);
-        mint(&env, &token, &voter2, 300);
+        stake(&env, &client, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &voter1, 500);
+        stake(&env, &client, &token, &voter2, 300);
 
         let id = client.gov_create_proposal(
             &proposer,
@@ -1815,8 +3156,8 @@ mod tests {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
         let voter = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
-        mint(&env, &token, &voter, 500);
+        stake(&env, &client, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &voter, 500);
 
         let id = client.gov_create_proposal(
             &proposer,
@@ -1838,8 +3179,8 @@ mod tests {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
         let voter = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
-        mint(&env, &token, &voter, 500);
+        stake(&env, &client, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &voter, 500);
 
         let id = client.gov_create_proposal(
             &proposer,
@@ -1861,7 +3202,7 @@ mod tests {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
         let voter = Address::generate(&env); // no tokens minted
-        mint(&env, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &proposer, 1_000);
 
         let id = client.gov_create_proposal(
             &proposer,
@@ -1881,112 +3222,584 @@ mod tests {
     fn test_vote_nonexistent_proposal() {
         let (env, admin, token, client) = setup();
         let voter = Address::generate(&env);
-        mint(&env, &token, &voter, 500);
+        stake(&env, &client, &token, &voter, 500);
 
         let result = client.try_gov_vote(&voter, &999, &VoteType::For);
         assert!(result.is_err());
     }
 
-    // ────────────────────────────────────────────────────────────────────
-    // Queue
-    // ────────────────────────────────────────────────────────────────────
-
     #[test]
-    fn test_queue_defeated() {
+    fn test_voting_power_snapshot_at_creation() {
+        let (env, admin, token, client) = setup();
+        let proposer = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let other = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &voter, 500);
+
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+
+        // Staked after the snapshot: does not count
+        let t = env.ledger().timestamp();
+        env.ledger().set_timestamp(t + 1);
+        stake(&env, &client, &token, &voter, 500);
+        client.gov_vote(&voter, &id, &VoteType::For);
+        assert_eq!(client.gov_get_vote(&id, &voter).unwrap().voting_power, 500);
+
+        // The same tokens moved to another address carry no power
+        client.gov_unstake(&voter, &1_000);
+        TokenClient::new(&env, &token).transfer(&voter, &other, &1_000);
+        client.gov_stake(&other, &1_000);
+        assert_eq!(
+            client.try_gov_vote(&other, &id, &VoteType::For),
+            Err(Ok(GovernanceError::NoVotingPower))
+        );
+
+        assert_eq!(client.gov_get_staked_votes(&voter, &Some(t)), 500);
+        assert_eq!(client.gov_get_staked_votes(&voter, &None), 0);
+        assert_eq!(client.gov_get_staked_votes(&other, &None), 1_000);
+    }
+
+    #[test]
+    fn test_stake_in_creation_ledger_not_counted() {
+        let (env, admin, token, client) = setup();
+        let proposer = Address::generate(&env);
+        let late = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 1_000);
+
+        // Staked in the same ledger as the proposal, e.g. by front-running it
+        mint(&env, &token, &late, 5_000);
+        client.gov_stake(&late, &5_000);
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+
+        assert_eq!(
+            client.try_gov_vote(&late, &id, &VoteType::For),
+            Err(Ok(GovernanceError::NoVotingPower))
+        );
+        client.gov_vote(&proposer, &id, &VoteType::For);
+        assert_eq!(
+            client.gov_get_vote(&id, &proposer).unwrap().voting_power,
+            1_000
+        );
+    }
+
+    #[test]
+    fn test_stake_and_unstake() {
+        let (env, admin, token, client) = setup();
+        let staker = Address::generate(&env);
+        mint(&env, &token, &staker, 1_000);
+
+        assert_eq!(
+            client.try_gov_stake(&staker, &0),
+            Err(Ok(GovernanceError::InvalidStakeAmount))
+        );
+        assert_eq!(client.gov_stake(&staker, &600), 600);
+        assert_eq!(
+            client.try_gov_unstake(&staker, &700),
+            Err(Ok(GovernanceError::InsufficientStake))
+        );
+        assert_eq!(client.gov_unstake(&staker, &200), 400);
+        assert_eq!(TokenClient::new(&env, &token).balance(&staker), 600);
+
+        // Held and staked tokens both count toward the proposal threshold
+        client.gov_create_proposal(
+            &staker,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+    }
+
+    // ────────────────────────────────────────────────────────────────────
+    // Queue
+    // ────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_queue_defeated() {
+        let (env, admin, token, client) = setup();
+        let proposer = Address::generate(&env);
+        let voter = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &voter, 500);
+
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+
+        let t = env.ledger().timestamp();
+        env.ledger().set_timestamp(t + 1);
+        // Vote against
+        client.gov_vote(&voter, &id, &VoteType::Against);
+
+        // Advance past voting period
+        env.ledger().set_timestamp(t + 260_000);
+
+        let outcome = client.gov_queue_proposal(&admin, &id);
+        assert!(!outcome.succeeded);
+
+        let p = client.gov_get_proposal(&id).unwrap();
+        assert!(matches!(p.status, ProposalStatus::Defeated));
+    }
+
+    #[test]
+    fn test_quorum_measured_against_total_stake() {
+        let (env, admin, token, client) = setup();
+        let proposer = Address::generate(&env);
+        let holder = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 100);
+        stake(&env, &client, &token, &holder, 10_000);
+
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+        let t = env.ledger().timestamp();
+        env.ledger().set_timestamp(t + 1);
+        // Unanimous, but only 100 of 10_100 staked votes took part
+        client.gov_vote(&proposer, &id, &VoteType::For);
+
+        env.ledger().set_timestamp(t + 260_000);
+        let outcome = client.gov_queue_proposal(&admin, &id);
+        assert!(!outcome.quorum_reached);
+        assert_eq!(outcome.quorum_required, 404);
+        assert!(!outcome.succeeded);
+        let p = client.gov_get_proposal(&id).unwrap();
+        assert!(matches!(p.status, ProposalStatus::Defeated));
+    }
+
+    #[test]
+    fn test_queue_voting_not_ended() {
+        let (env, admin, token, client) = setup();
+        let proposer = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 1_000);
+
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+
+        // Don't advance time past voting window
+        let result = client.try_gov_queue_proposal(&admin, &id);
+        assert!(result.is_err());
+    }
+
+    // ────────────────────────────────────────────────────────────────────
+    // Finalize
+    // ────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_finalize_succeeded_then_queue() {
+        let (env, admin, token, client) = setup();
+        let proposer = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 1_000);
+
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+        let t = env.ledger().timestamp();
+        env.ledger().set_timestamp(t + 1);
+        client.gov_vote(&proposer, &id, &VoteType::For);
+
+        assert_eq!(
+            client.try_gov_finalize_proposal(&id),
+            Err(Ok(GovernanceError::VotingNotEnded))
+        );
+
+        env.ledger().set_timestamp(t + 260_000);
+        let outcome = client.gov_finalize_proposal(&id);
+        assert!(outcome.succeeded);
+        let p = client.gov_get_proposal(&id).unwrap();
+        assert!(matches!(p.status, ProposalStatus::Succeeded));
+
+        // Finalizing twice is rejected; the succeeded proposal can be queued
+        assert_eq!(
+            client.try_gov_finalize_proposal(&id),
+            Err(Ok(GovernanceError::InvalidProposalStatus))
+        );
+        assert!(client.gov_queue_proposal(&admin, &id).succeeded);
+        let p = client.gov_get_proposal(&id).unwrap();
+        assert!(matches!(p.status, ProposalStatus::Queued));
+    }
+
+    #[test]
+    fn test_finalize_defeated_is_terminal() {
+        let (env, admin, token, client) = setup();
+        let proposer = Address::generate(&env);
+        let voter = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &voter, 500);
+
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+        let t = env.ledger().timestamp();
+        env.ledger().set_timestamp(t + 1);
+        client.gov_vote(&voter, &id, &VoteType::Against);
+
+        env.ledger().set_timestamp(t + 260_000);
+        let outcome = client.gov_finalize_proposal(&id);
+        assert!(!outcome.succeeded);
+        let p = client.gov_get_proposal(&id).unwrap();
+        assert!(matches!(p.status, ProposalStatus::Defeated));
+
+        assert_eq!(
+            client.try_gov_queue_proposal(&admin, &id),
+            Err(Ok(GovernanceError::InvalidProposalStatus))
+        );
+    }
+
+    // ────────────────────────────────────────────────────────────────────
+    // Execute
+    // ────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_execute_too_early() {
+        let (env, admin, token, client) = setup();
+        let proposer = Address::generate(&env);
+        let voter = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &voter, 500);
+
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+
+        let t = env.ledger().timestamp();
+        env.ledger().set_timestamp(t + 1);
+        client.gov_vote(&voter, &id, &VoteType::For);
+
+        // Past voting period
+        env.ledger().set_timestamp(t + 260_000);
+        let outcome = client.gov_queue_proposal(&admin, &id);
+        assert!(outcome.succeeded);
+
+        // Try to execute immediately (before execution_delay)
+        let result = client.try_gov_execute_proposal(&admin, &id);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_execute_not_queued() {
+        let (env, admin, token, client) = setup();
+        let proposer = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 1_000);
+
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+
+        let result = client.try_gov_execute_proposal(&admin, &id);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_vote_weight_cap_limits_whale() {
+        let (env, admin, token, client) = setup();
+        let proposer = Address::generate(&env);
+        let whale = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &whale, 50_000);
+
+        // Cap each voter at 10% of the staked total
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::VoteWeightCap(Some(VoteWeightCap {
+                max_vote_bps: 1_000,
+            })),
+            &String::from_str(&env, "Cap votes"),
+            &None,
+        );
+        let t = env.ledger().timestamp();
+        env.ledger().set_timestamp(t + 1);
+        client.gov_vote(&proposer, &id, &VoteType::For);
+        env.ledger().set_timestamp(t + 260_000);
+        client.gov_queue_proposal(&admin, &id);
+        env.ledger().set_timestamp(t + 260_000 + 86_401);
+        client.gov_execute_proposal(&admin, &id);
+
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+        env.ledger().set_timestamp(t + 260_000 + 86_402);
+        client.gov_vote(&whale, &id, &VoteType::Against);
+        client.gov_vote(&proposer, &id, &VoteType::For);
+
+        assert_eq!(client.gov_get_vote(&id, &whale).unwrap().voting_power, 5_100);
+        assert_eq!(client.gov_get_vote(&id, &proposer).unwrap().voting_power, 1_000);
+        let p = client.gov_get_proposal(&id).unwrap();
+        assert_eq!(p.against_votes, 5_100);
+
+        // New stake raises the cap for proposals created afterwards only
+        stake(&env, &client, &token, &Address::generate(&env), 49_000);
+        let later = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(false),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+        env.ledger().with_mut(|li| li.timestamp += 1);
+        client.gov_vote(&whale, &later, &VoteType::Against);
+        assert_eq!(
+            client.gov_get_vote(&later, &whale).unwrap().voting_power,
+            10_000
+        );
+    }
+
+    #[test]
+    fn test_quadratic_voting_snapshots_per_proposal() {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
         let voter = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
-        mint(&env, &token, &voter, 500);
+        stake(&env, &client, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &voter, 10_000);
 
-        let id = client.gov_create_proposal(
+        let before = client.gov_create_proposal(
             &proposer,
             &ProposalType::EmergencyPause(true),
-            &String::from_str(&env, "Test"),
+            &String::from_str(&env, "Linear"),
+            &None,
+        );
+        env.as_contract(&client.address, || set_quadratic_voting(&env, true));
+        let after = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Quadratic"),
             &None,
         );
+        assert!(!client.gov_get_proposal(&before).unwrap().quadratic);
+        assert!(client.gov_get_proposal(&after).unwrap().quadratic);
 
         let t = env.ledger().timestamp();
         env.ledger().set_timestamp(t + 1);
-        // Vote against
-        client.gov_vote(&voter, &id, &VoteType::Against);
-
-        // Advance past voting period
-        env.ledger().set_timestamp(t + 260_000);
+        client.gov_vote(&voter, &before, &VoteType::For);
+        client.gov_vote(&voter, &after, &VoteType::For);
 
-        let outcome = client.gov_queue_proposal(&admin, &id);
-        assert!(!outcome.succeeded);
-
-        let p = client.gov_get_proposal(&id).unwrap();
-        assert!(matches!(p.status, ProposalStatus::Defeated));
+        assert_eq!(client.gov_get_proposal(&before).unwrap().for_votes, 10_000);
+        assert_eq!(client.gov_get_proposal(&after).unwrap().for_votes, 100);
     }
 
     #[test]
-    fn test_queue_voting_not_ended() {
+    fn test_category_threshold_applied_at_creation() {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
+        let voter = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &voter, 1_200);
+
+        env.as_contract(&client.address, || {
+            set_category_threshold(
+                &env,
+                ProposalCategory::Generic,
+                Some(CategoryThreshold {
+                    voting_threshold: 6_600,
+                    quorum_bps: 2_000,
+                }),
+            )
+            .unwrap();
+        });
 
-        let id = client.gov_create_proposal(
+        let action = Action {
+            target: Address::generate(&env),
+            method: Symbol::new(&env, "upgrade"),
+            args: Vec::new(&env),
+            value: 0,
+        };
+        let generic = client.gov_create_proposal(
             &proposer,
-            &ProposalType::EmergencyPause(true),
-            &String::from_str(&env, "Test"),
+            &ProposalType::GenericAction(action),
+            &String::from_str(&env, "Upgrade"),
+            &Some(5_000),
+        );
+        let param = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::MinCollateralRatio(11_500),
+            &String::from_str(&env, "Tweak"),
             &None,
         );
 
-        // Don't advance time past voting window
-        let result = client.try_gov_queue_proposal(&admin, &id);
-        assert!(result.is_err());
+        let p = client.gov_get_proposal(&generic).unwrap();
+        assert_eq!(p.voting_threshold, 6_600);
+        assert_eq!(p.quorum_bps, 2_000);
+        let p = client.gov_get_proposal(&param).unwrap();
+        assert_eq!(p.voting_threshold, 5_000);
+        assert_eq!(p.quorum_bps, 400);
+
+        // 1_200 for vs 1_000 against: passes 50%, fails 66%
+        let t = env.ledger().timestamp();
+        env.ledger().set_timestamp(t + 1);
+        for id in [generic, param] {
+            client.gov_vote(&voter, &id, &VoteType::For);
+            client.gov_vote(&proposer, &id, &VoteType::Against);
+        }
+        env.ledger().set_timestamp(t + 260_000);
+        assert!(!client.gov_finalize_proposal(&generic).succeeded);
+        assert!(client.gov_finalize_proposal(&param).succeeded);
     }
 
-    // ────────────────────────────────────────────────────────────────────
-    // Execute
-    // ────────────────────────────────────────────────────────────────────
+    fn enable_emergency_track(env: &Env, client: &HelloContractClient) {
+        env.as_contract(&client.address, || {
+            set_emergency_track_config(
+                env,
+                Some(EmergencyTrackConfig {
+                    voting_period: 3_600,
+                    voting_threshold: 6_700,
+                    quorum_bps: 400,
+                    guardian_approvals: 1,
+                }),
+            )
+            .unwrap();
+        });
+    }
 
     #[test]
-    fn test_execute_too_early() {
+    fn test_emergency_proposal_fast_track() {
         let (env, admin, token, client) = setup();
-        let proposer = Address::generate(&env);
-        let voter = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
-        mint(&env, &token, &voter, 500);
+        client.gov_stake(&admin, &1_000);
+        env.ledger().with_mut(|li| li.timestamp += 1);
+        let guardian = Address::generate(&env);
+        client.gov_add_guardian(&admin, &guardian);
+        enable_emergency_track(&env, &client);
 
-        let id = client.gov_create_proposal(
-            &proposer,
+        let id = client.gov_create_emergency_proposal(
+            &admin,
             &ProposalType::EmergencyPause(true),
-            &String::from_str(&env, "Test"),
-            &None,
+            &String::from_str(&env, "Exploit response"),
         );
+        let p = client.gov_get_proposal(&id).unwrap();
+        assert!(p.emergency);
+        assert_eq!(p.voting_threshold, 6_700);
+        assert_eq!(p.end_time - p.start_time, 3_600);
 
         let t = env.ledger().timestamp();
         env.ledger().set_timestamp(t + 1);
-        client.gov_vote(&voter, &id, &VoteType::For);
+        client.gov_vote(&admin, &id, &VoteType::For);
+        env.ledger().set_timestamp(t + 3_601);
+        client.gov_queue_proposal(&admin, &id);
 
-        // Past voting period
-        env.ledger().set_timestamp(t + 260_000);
-        let outcome = client.gov_queue_proposal(&admin, &id);
-        assert!(outcome.succeeded);
+        // No timelock, but a guardian must co-sign
+        let p = client.gov_get_proposal(&id).unwrap();
+        assert_eq!(p.execution_time, Some(t + 3_601));
+        assert_eq!(
+            client.try_gov_execute_proposal(&admin, &id),
+            Err(Ok(GovernanceError::InsufficientApprovals))
+        );
 
-        // Try to execute immediately (before execution_delay)
-        let result = client.try_gov_execute_proposal(&admin, &id);
-        assert!(result.is_err());
+        client.gov_approve_emergency_proposal(&guardian, &id);
+        assert_eq!(
+            client.try_gov_approve_emergency_proposal(&guardian, &id),
+            Err(Ok(GovernanceError::AlreadyVoted))
+        );
+        client.gov_execute_proposal(&admin, &id);
+        let p = client.gov_get_proposal(&id).unwrap();
+        assert_eq!(p.status, ProposalStatus::Executed);
+        assert!(client.is_emergency_paused());
     }
 
     #[test]
-    fn test_execute_not_queued() {
-        let (env, admin, token, client) = setup();
-        let proposer = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
+    fn test_emergency_proposal_restrictions() {
+        let (env, admin, _token, client) = setup();
+        let desc = String::from_str(&env, "Emergency");
+
+        assert_eq!(
+            client.try_gov_create_emergency_proposal(
+                &admin,
+                &ProposalType::EmergencyPause(true),
+                &desc
+            ),
+            Err(Ok(GovernanceError::EmergencyTrackDisabled))
+        );
 
-        let id = client.gov_create_proposal(
-            &proposer,
-            &ProposalType::EmergencyPause(true),
-            &String::from_str(&env, "Test"),
-            &None,
+        env.as_contract(&client.address, || {
+            let weak = EmergencyTrackConfig {
+                voting_period: 3_600,
+                voting_threshold: 5_000,
+                quorum_bps: 400,
+                guardian_approvals: 1,
+            };
+            assert_eq!(
+                set_emergency_track_config(&env, Some(weak)),
+                Err(GovernanceError::InvalidThreshold)
+            );
+        });
+        enable_emergency_track(&env, &client);
+
+        // Only emergency actions can be fast-tracked
+        assert_eq!(
+            client.try_gov_create_emergency_proposal(
+                &admin,
+                &ProposalType::QuadraticVoting(true),
+                &desc
+            ),
+            Err(Ok(GovernanceError::InvalidProposal))
+        );
+        assert_eq!(
+            client.try_gov_create_emergency_proposal(
+                &admin,
+                &ProposalType::MinCollateralRatio(11_000),
+                &desc
+            ),
+            Err(Ok(GovernanceError::InvalidProposal))
         );
 
-        let result = client.try_gov_execute_proposal(&admin, &id);
-        assert!(result.is_err());
+        // Only guardians co-sign, and only emergency proposals
+        let guardian = Address::generate(&env);
+        client.gov_add_guardian(&admin, &guardian);
+        let emergency =
+            client.gov_create_emergency_proposal(&admin, &ProposalType::EmergencyPause(true), &desc);
+        let normal =
+            client.gov_create_proposal(&admin, &ProposalType::EmergencyPause(true), &desc, &None);
+        assert_eq!(
+            client.try_gov_approve_emergency_proposal(&Address::generate(&env), &emergency),
+            Err(Ok(GovernanceError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_gov_approve_emergency_proposal(&guardian, &normal),
+            Err(Ok(GovernanceError::InvalidProposal))
+        );
+    }
+
+    #[test]
+    fn test_vote_weight_cap_validation() {
+        let (env, _admin, _token, client) = setup();
+        env.as_contract(&client.address, || {
+            let bad = VoteWeightCap { max_vote_bps: 0 };
+            assert_eq!(
+                set_vote_weight_cap(&env, Some(bad)),
+                Err(GovernanceError::InvalidThreshold)
+            );
+        });
     }
 
     #[test]
@@ -1994,8 +3807,8 @@ mod tests {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
         let voter = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
-        mint(&env, &token, &voter, 500);
+        stake(&env, &client, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &voter, 500);
 
         // Use MinCollateralRatio — it delegates to risk_params which is
         // initialized by client.initialize(). Value must be within 10% of
@@ -2035,7 +3848,7 @@ mod tests {
     fn test_cancel_by_proposer() {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &proposer, 1_000);
 
         let id = client.gov_create_proposal(
             &proposer,
@@ -2053,7 +3866,7 @@ mod tests {
     fn test_cancel_by_admin() {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &proposer, 1_000);
 
         let id = client.gov_create_proposal(
             &proposer,
@@ -2072,7 +3885,7 @@ mod tests {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
         let rando = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &proposer, 1_000);
 
         let id = client.gov_create_proposal(
             &proposer,
@@ -2091,8 +3904,8 @@ mod tests {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
         let voter = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
-        mint(&env, &token, &voter, 500);
+        stake(&env, &client, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &voter, 500);
 
         let id = client.gov_create_proposal(
             &proposer,
@@ -2112,6 +3925,78 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_cancel_by_proposer_after_voting_rejected() {
+        let (env, admin, token, client) = setup();
+        let proposer = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 1_000);
+
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+
+        let t = env.ledger().timestamp();
+        env.ledger().set_timestamp(t + 260_000);
+        assert_eq!(
+            client.try_gov_cancel_proposal(&proposer, &id),
+            Err(Ok(GovernanceError::NotInVotingPeriod))
+        );
+    }
+
+    #[test]
+    fn test_veto_guardian_cancels_queued_proposal() {
+        let (env, admin, token, client) = setup();
+        let proposer = Address::generate(&env);
+        let veto = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 1_000);
+
+        assert_eq!(
+            client.try_gov_set_veto_guardian(&proposer, &Some(veto.clone())),
+            Err(Ok(GovernanceError::Unauthorized))
+        );
+        client.gov_set_veto_guardian(&admin, &Some(veto.clone()));
+        assert_eq!(client.gov_get_veto_guardian(), Some(veto.clone()));
+
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+        let t = env.ledger().timestamp();
+        env.ledger().set_timestamp(t + 1);
+        client.gov_vote(&proposer, &id, &VoteType::For);
+        env.ledger().set_timestamp(t + 260_000);
+        client.gov_queue_proposal(&admin, &id);
+
+        client.gov_cancel_proposal(&veto, &id);
+        let p = client.gov_get_proposal(&id).unwrap();
+        assert_eq!(p.status, ProposalStatus::Cancelled);
+
+        env.ledger().set_timestamp(t + 260_000 + 86_401);
+        assert!(client.try_gov_execute_proposal(&admin, &id).is_err());
+        assert_eq!(
+            client.try_gov_cancel_proposal(&veto, &id),
+            Err(Ok(GovernanceError::InvalidProposalStatus))
+        );
+
+        // Cleared guardian loses the veto
+        client.gov_set_veto_guardian(&admin, &None);
+        let id = client.gov_create_proposal(
+            &proposer,
+            &ProposalType::EmergencyPause(true),
+            &String::from_str(&env, "Test"),
+            &None,
+        );
+        assert_eq!(
+            client.try_gov_cancel_proposal(&veto, &id),
+            Err(Ok(GovernanceError::Unauthorized))
+        );
+    }
+
     // ────────────────────────────────────────────────────────────────────
     // Multisig
     // ────────────────────────────────────────────────────────────────────
@@ -2120,7 +4005,7 @@ mod tests {
     fn test_multisig_approve() {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &proposer, 1_000);
 
         let id = client.gov_create_proposal(
             &proposer,
@@ -2139,7 +4024,7 @@ mod tests {
     fn test_multisig_double_approve_rejected() {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &proposer, 1_000);
 
         let id = client.gov_create_proposal(
             &proposer,
@@ -2158,7 +4043,7 @@ mod tests {
         let (env, admin, token, client) = setup();
         let proposer = Address::generate(&env);
         let rando = Address::generate(&env);
-        mint(&env, &token, &proposer, 1_000);
+        stake(&env, &client, &token, &proposer, 1_000);
 
         let id = client.gov_create_proposal(
             &proposer,
@@ -2187,6 +4072,265 @@ mod tests {
         assert_eq!(ms.admins.len(), 2);
     }
 
+    #[test]
+    fn test_multisig_per_action_thresholds() {
+        let (env, admin, _token, client) = setup();
+        let signer2 = Address::generate(&env);
+        let signer3 = Address::generate(&env);
+        let mut signers = soroban_sdk::Vec::new(&env);
+        signers.push_back(admin.clone());
+        signers.push_back(signer2.clone());
+        signers.push_back(signer3.clone());
+        client.gov_set_multisig_config(&admin, &signers, &1);
+
+        client.gov_set_multisig_action_threshold(&admin, &ProposalCategory::Treasury, &Some(3));
+        assert_eq!(
+            client.try_gov_set_multisig_action_threshold(
+                &admin,
+                &ProposalCategory::Emergency,
+                &Some(4)
+            ),
+            Err(Ok(GovernanceError::InvalidMultisigConfig))
+        );
+        assert_eq!(
+            client.gov_get_multisig_action_threshold(&ProposalCategory::Treasury),
+            3
+        );
+        assert_eq!(
+            client.gov_get_multisig_action_threshold(&ProposalCategory::Emergency),
+            1
+        );
+
+        // Emergency action needs one approval
+        let pause = client.gov_create_proposal(
+            &admin,
+            &ProposalType::ResetCircuitBreaker(None),
+            &String::from_str(&env, "Reset breaker"),
+            &None,
+        );
+        client.gov_approve_proposal(&admin, &pause);
+        client.ms_execute(&admin, &pause);
+        assert_eq!(
+            client.gov_get_proposal(&pause).unwrap().status,
+            ProposalStatus::Executed
+        );
+        assert_eq!(
+            client.try_ms_execute(&admin, &pause),
+            Err(Ok(GovernanceError::AlreadyExecuted))
+        );
+
+        // Treasury action needs all three
+        let withdraw = client.gov_create_proposal(
+            &admin,
+            &ProposalType::WithdrawReserves(admin.clone(), None, 1),
+            &String::from_str(&env, "Withdraw"),
+            &None,
+        );
+        client.gov_approve_proposal(&admin, &withdraw);
+        client.gov_approve_proposal(&signer2, &withdraw);
+        assert_eq!(
+            client.try_ms_execute(&admin, &withdraw),
+            Err(Ok(GovernanceError::InsufficientApprovals))
+        );
+
+        // Shrinking the signer set below a category threshold is rejected
+        let mut two = soroban_sdk::Vec::new(&env);
+        two.push_back(admin.clone());
+        two.push_back(signer2.clone());
+        assert_eq!(
+            client.try_gov_set_multisig_config(&admin, &two, &1),
+            Err(Ok(GovernanceError::InvalidMultisigConfig))
+        );
+    }
+
+    #[test]
+    fn test_multisig_executes_only_allowlisted_actions() {
+        let (env, admin, _token, client) = setup();
+        let mut signers = soroban_sdk::Vec::new(&env);
+        signers.push_back(admin.clone());
+        client.gov_set_multisig_config(&admin, &signers, &1);
+
+        let approved = |proposal_type: ProposalType| {
+            let id = client.gov_create_proposal(
+                &admin,
+                &proposal_type,
+                &String::from_str(&env, "Multisig"),
+                &None,
+            );
+            client.gov_approve_proposal(&admin, &id);
+            id
+        };
+
+        // Risk, oracle and governance changes, and treasury actions other
+        // than reserve withdrawals, need a vote
+        let blocked = [
+            ProposalType::RiskParams(Some(15_000), None, None, None),
+            ProposalType::CategoryThreshold(ProposalCategory::Treasury, None),
+            ProposalType::EmergencyTrackConfig(None),
+            ProposalType::ForgiveDebt(Address::generate(&env), None, 10),
+            ProposalType::SlashKeeper(Address::generate(&env), 10),
+            ProposalType::CreateClaimCampaign(
+                Address::generate(&env),
+                soroban_sdk::BytesN::from_array(&env, &[1; 32]),
+                10,
+            ),
+            ProposalType::RescueTokens(Address::generate(&env), 10, admin.clone()),
+        ];
+        for proposal_type in blocked.iter() {
+            let id = approved(proposal_type.clone());
+            assert_eq!(
+                client.try_ms_execute(&admin, &id),
+                Err(Ok(GovernanceError::InvalidProposal))
+            );
+        }
+
+        // The collateral ratio waits out the voting window and delay
+        let id = approved(ProposalType::MinCollateralRatio(15_000));
+        assert_eq!(
+            client.try_ms_execute(&admin, &id),
+            Err(Ok(GovernanceError::ProposalNotReady))
+        );
+        let t = env.ledger().timestamp();
+        env.ledger().set_timestamp(t + 259_200 + 86_400);
+        client.ms_execute(&admin, &id);
+        assert_eq!(
+            client.gov_get_proposal(&id).unwrap().status,
+            ProposalStatus::Executed
+        );
+    }
+
+    #[test]
+    fn test_multisig_spend_limit_rolling_window() {
+        let (env, admin, token, client) = setup();
+        let asset = Some(token);
+        env.as_contract(&client.address, || {
+            env.storage().persistent().set(
+                &crate::reserve::ReserveDataKey::ReserveBalance(asset.clone()),
+                &1_000_i128,
+            );
+            set_multisig_spend_limit(&env, asset.clone(), Some(100)).unwrap();
+        });
+
+        let withdraw = |amount: i128| {
+            let id = client.gov_create_proposal(
+                &admin,
+                &ProposalType::WithdrawReserves(admin.clone(), asset.clone(), amount),
+                &String::from_str(&env, "Withdraw"),
+                &None,
+            );
+            client.gov_approve_proposal(&admin, &id);
+            client.try_ms_execute(&admin, &id)
+        };
+
+        assert!(withdraw(60).is_ok());
+        assert_eq!(withdraw(60), Err(Ok(GovernanceError::SpendLimitExceeded)));
+        assert_eq!(client.gov_get_multisig_spent(&asset), 60);
+
+        // The first spend rolls out of the window
+        let t = env.ledger().timestamp();
+        env.ledger().set_timestamp(t + 86_400);
+        assert_eq!(client.gov_get_multisig_spent(&asset), 0);
+        assert!(withdraw(100).is_ok());
+
+        // The multisig cannot lift its own limit
+        let id = client.gov_create_proposal(
+            &admin,
+            &ProposalType::MultisigSpendLimit(asset.clone(), None),
+            &String::from_str(&env, "Lift limit"),
+            &None,
+        );
+        client.gov_approve_proposal(&admin, &id);
+        assert_eq!(
+            client.try_ms_execute(&admin, &id),
+            Err(Ok(GovernanceError::InvalidProposal))
+        );
+    }
+
+    #[test]
+    fn test_multisig_outflow_requires_spend_limit() {
+        let (env, admin, _token, client) = setup();
+        let stray = create_test_token(&env, &admin);
+        env.as_contract(&client.address, || {
+            env.storage().persistent().set(
+                &crate::reserve::ReserveDataKey::ReserveBalance(Some(stray.clone())),
+                &1_000_i128,
+            );
+        });
+
+        let id = client.gov_create_proposal(
+            &admin,
+            &ProposalType::WithdrawReserves(admin.clone(), Some(stray.clone()), 10),
+            &String::from_str(&env, "Withdraw"),
+            &None,
+        );
+        client.gov_approve_proposal(&admin, &id);
+        assert_eq!(
+            client.try_ms_execute(&admin, &id),
+            Err(Ok(GovernanceError::SpendLimitNotSet))
+        );
+
+        // Reserve withdrawals, strategy withdrawals and arbitrary calls count
+        // as outflows
+        assert_eq!(
+            ProposalType::WithdrawReserves(admin.clone(), Some(stray.clone()), 25)
+                .treasury_outflow(),
+            Some((Some(stray.clone()), 25))
+        );
+        assert_eq!(
+            ProposalType::StrategyWithdraw(stray.clone(), 50).treasury_outflow(),
+            Some((Some(stray.clone()), 50))
+        );
+        let action = Action {
+            target: stray.clone(),
+            method: Symbol::new(&env, "transfer"),
+            args: soroban_sdk::Vec::new(&env),
+            value: 0,
+        };
+        assert_eq!(
+            ProposalType::GenericAction(action).treasury_outflow(),
+            Some((Some(stray), i128::MAX))
+        );
+    }
+
+    #[test]
+    fn test_multisig_cannot_fund_reward_emission() {
+        let (env, admin, token, client) = setup();
+        let mut signers = soroban_sdk::Vec::new(&env);
+        signers.push_back(admin.clone());
+        client.gov_set_multisig_config(&admin, &signers, &1);
+        env.as_contract(&client.address, || {
+            set_multisig_spend_limit(&env, Some(token.clone()), Some(100)).unwrap();
+        });
+
+        // A schedule paying far beyond the daily limit must go to a vote
+        let market = crate::rewards::RewardMarket {
+            asset: Some(token.clone()),
+            side: crate::rewards::RewardSide::Supply,
+        };
+        let schedule = crate::rewards::EmissionSchedule {
+            start_time: env.ledger().timestamp(),
+            epoch_duration: 3_600,
+            initial_budget: 1_000_000,
+            decay_bps: 0,
+            total_epochs: 10,
+        };
+        let id = client.gov_create_proposal(
+            &admin,
+            &ProposalType::RewardEmission(market.clone(), Some(schedule)),
+            &String::from_str(&env, "Emit"),
+            &None,
+        );
+        client.gov_approve_proposal(&admin, &id);
+        assert_eq!(
+            client.try_ms_execute(&admin, &id),
+            Err(Ok(GovernanceError::InvalidProposal))
+        );
+        assert_eq!(client.gov_get_multisig_spent(&Some(token)), 0);
+        env.as_contract(&client.address, || {
+            assert!(crate::rewards::get_reward_emission(&env, market).is_none());
+        });
+    }
+
     #[test]
     fn test_set_multisig_config_unauthorized() {
         let (env, admin, token, client) = setup();
@@ -2357,4 +4501,134 @@ mod tests {
         let result = client.try_gov_approve_recovery(&admin);
         assert!(result.is_err());
     }
+
+    // ────────────────────────────────────────────────────────────────────
+    // Parameter dispatch
+    // ────────────────────────────────────────────────────────────────────
+
+    /// Create, pass and execute a proposal in one go
+    fn pass_and_execute(
+        env: &Env,
+        admin: &Address,
+        token: &Address,
+        client: &HelloContractClient,
+        proposal_type: ProposalType,
+    ) {
+        let proposer = Address::generate(env);
+        stake(env, client, token, &proposer, 1_000);
+        let id = client.gov_create_proposal(
+            &proposer,
+            &proposal_type,
+            &String::from_str(env, "Parameter change"),
+            &None,
+        );
+        let t = env.ledger().timestamp();
+        env.ledger().set_timestamp(t + 1);
+        client.gov_vote(&proposer, &id, &VoteType::For);
+        env.ledger().set_timestamp(t + 260_000);
+        client.gov_queue_proposal(admin, &id);
+        env.ledger().set_timestamp(t + 260_000 + 86_401);
+        client.gov_execute_proposal(admin, &id);
+    }
+
+    #[test]
+    fn test_execute_asset_params() {
+        let (env, admin, token, client) = setup();
+        let asset = Address::generate(&env);
+        let params = crate::deposit::AssetParams {
+            deposit_enabled: true,
+            collateral_factor: 7_500,
+            max_deposit: 1_000_000,
+            borrow_fee_bps: 50,
+        };
+
+        pass_and_execute(
+            &env,
+            &admin,
+            &token,
+            &client,
+            ProposalType::AssetParams(asset.clone(), params.clone()),
+        );
+        assert_eq!(client.get_asset_params(&asset), Some(params));
+    }
+
+    #[test]
+    fn test_execute_interest_rate_config() {
+        let (env, admin, token, client) = setup();
+
+        pass_and_execute(
+            &env,
+            &admin,
+            &token,
+            &client,
+            ProposalType::InterestRateConfig(Some(300), None, None, None, None, None, Some(150)),
+        );
+        let config = client.get_interest_rate_config().unwrap();
+        assert_eq!(config.base_rate_bps, 300);
+        assert_eq!(config.kink_utilization_bps, 8_000);
+        assert_eq!(config.spread_bps, 150);
+    }
+
+    #[test]
+    fn test_execute_oracle_addresses() {
+        let (env, admin, token, client) = setup();
+        let asset = Address::generate(&env);
+        let primary = Address::generate(&env);
+        let fallback = Address::generate(&env);
+
+        pass_and_execute(
+            &env,
+            &admin,
+            &token,
+            &client,
+            ProposalType::OracleAddresses(
+                asset.clone(),
+                Some(primary.clone()),
+                Some(fallback.clone()),
+            ),
+        );
+        env.as_contract(&client.address, || {
+            let storage = env.storage().persistent();
+            let key = crate::oracle::OracleDataKey::PrimaryOracle(asset.clone());
+            assert_eq!(storage.get::<_, Address>(&key), Some(primary));
+            let key = crate::oracle::OracleDataKey::FallbackOracle(asset.clone());
+            assert_eq!(storage.get::<_, Address>(&key), Some(fallback));
+        });
+    }
+
+    #[test]
+    fn test_create_rejects_invalid_parameters() {
+        let (env, _admin, token, client) = setup();
+        let proposer = Address::generate(&env);
+        stake(&env, &client, &token, &proposer, 1_000);
+        let asset = Address::generate(&env);
+        let oracle = Address::generate(&env);
+
+        let invalid = [
+            ProposalType::AssetParams(
+                asset.clone(),
+                crate::deposit::AssetParams {
+                    deposit_enabled: true,
+                    collateral_factor: 12_000,
+                    max_deposit: 0,
+                    borrow_fee_bps: 0,
+                },
+            ),
+            ProposalType::InterestRateConfig(None, Some(10_000), None, None, None, None, None),
+            ProposalType::InterestRateConfig(None, None, None, None, Some(600), Some(500), None),
+            ProposalType::OracleAddresses(asset.clone(), Some(client.address.clone()), None),
+            ProposalType::OracleAddresses(asset, Some(oracle.clone()), Some(oracle)),
+        ];
+        for proposal_type in invalid {
+            assert_eq!(
+                client.try_gov_create_proposal(
+                    &proposer,
+                    &proposal_type,
+                    &String::from_str(&env, "Invalid"),
+                    &None,
+                ),
+                Err(Ok(GovernanceError::InvalidProposal))
+            );
+        }
+    }
 }
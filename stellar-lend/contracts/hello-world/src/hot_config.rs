@@ -0,0 +1,94 @@
+//! # Hot Configuration Cache
+//!
+//! Single instance-storage copy of the configuration every core flow
+//! consults: the operation pause switches, the emergency pause flag and the
+//! global risk parameters.
+//!
+//! These values live in separate persistent entries, each of which used to be
+//! read (and count against the read-entry budget) on every deposit, borrow,
+//! withdrawal and repayment. Instance storage is loaded with the contract
+//! anyway, so flows now [`load`] the cache once per invocation instead.
+//!
+//! The persistent entries stay the source of truth. Every writer of one of
+//! them calls [`refresh`] afterwards to rebuild the cache; if no cache has
+//! been written yet (e.g. right after an upgrade), [`load`] falls back to
+//! reading the persistent entries.
+//!
+//! ## Security
+//! - The cache is never written from user input, only rebuilt from the
+//!   admin- and governance-controlled persistent entries.
+
+#![allow(unused)]
+use soroban_sdk::{contracttype, Env, Map, Symbol};
+
+use crate::risk_management::PauseOperation;
+use crate::risk_params::RiskParams;
+
+/// Storage keys for the hot configuration cache
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum HotConfigDataKey {
+    /// Cached hot configuration (instance storage): HotConfig
+    HotConfig,
+}
+
+/// Configuration read by every core flow
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HotConfig {
+    /// Operation pause switches ("pause_deposit", ...)
+    pub pause_switches: Map<Symbol, bool>,
+    /// Global emergency pause
+    pub emergency_paused: bool,
+    /// Global risk parameters, if initialized
+    pub risk_params: Option<RiskParams>,
+}
+
+impl HotConfig {
+    /// Whether `operation`'s own switch is set, ignoring the emergency pause
+    pub fn is_switch_on(&self, env: &Env, operation: PauseOperation) -> bool {
+        operation
+            .switch_key(env)
+            .and_then(|key| self.pause_switches.get(key))
+            .unwrap_or(false)
+    }
+
+    /// Whether `operation` is blocked by its switch or the emergency pause
+    pub fn is_paused(&self, env: &Env, operation: PauseOperation) -> bool {
+        self.emergency_paused || self.is_switch_on(env, operation)
+    }
+
+    /// Minimum collateral ratio, if risk parameters are initialized
+    pub fn min_collateral_ratio(&self) -> Option<i128> {
+        self.risk_params.as_ref().map(|p| p.min_collateral_ratio)
+    }
+}
+
+/// Read the cached hot configuration, falling back to the persistent entries
+pub fn load(env: &Env) -> HotConfig {
+    env.storage()
+        .instance()
+        .get(&HotConfigDataKey::HotConfig)
+        .unwrap_or_else(|| build(env))
+}
+
+/// Rebuild the cache from the persistent entries
+///
+/// Must be called by every writer of the pause switches, the emergency pause
+/// or the risk parameters.
+pub fn refresh(env: &Env) {
+    env.storage()
+        .instance()
+        .set(&HotConfigDataKey::HotConfig, &build(env));
+}
+
+fn build(env: &Env) -> HotConfig {
+    HotConfig {
+        pause_switches: crate::risk_management::get_risk_config(env)
+            .map(|c| c.pause_switches)
+            .unwrap_or_else(|| Map::new(env)),
+        emergency_paused: crate::risk_management::stored_emergency_pause(env),
+        risk_params: crate::risk_params::stored_risk_params(env),
+    }
+}
@@ -0,0 +1,330 @@
+//! # Insurance Module
+//!
+//! Backstop for bad debt: debt left on a position after all of its collateral
+//! has been seized.
+//!
+//! ## Funding
+//! The fund itself is the per-asset `ReserveDataKey::InsuranceFund` balance
+//! kept by the reserve module. Besides the liquidation insurance share and
+//! donations, governance can route a share of protocol fees into it via an
+//! `InsuranceFeeShare` proposal:
+//! - the reserve-factor cut of repaid interest
+//! - borrow fees
+//!
+//! The share (0 - 5000 bps) is taken out of the protocol's cut, never out of
+//! what lenders earn.
+//!
+//! ## Bad Debt Resolution
+//! Anyone can call [`resolve_bad_debt`] on a position with debt but no
+//! collateral. The whole debt, accrued interest included, is written off in
+//! the assets it was borrowed in, as recorded by [`crate::asset_positions`]:
+//! each asset takes its principal plus a pro-rata share of the interest, and
+//! principal no asset accounts for is booked on the native asset. For each
+//! asset:
+//! 1. the insurance fund for that asset covers as much as it holds,
+//! 2. any remainder is socialized: it leaves the pool unrepaid, is borne by
+//!    suppliers and is added to the asset's recorded shortfall.
+//!
+//! The returned totals are valued at oracle prices so assets can be summed.
+//! Underwater positions that still hold collateral must be liquidated first.
+//!
+//! ## Events
+//! - `bad_debt_covered` — amount drawn from the insurance fund
+//! - `bad_debt_shortfall` — amount socialized, with the asset's running total
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::deposit::{DepositDataKey, Position};
+use crate::reserve::BASIS_POINTS_SCALE;
+
+/// Maximum share of protocol fees routed to the insurance fund (50%)
+pub const MAX_INSURANCE_FEE_SHARE_BPS: i128 = 5000;
+
+/// Errors that can occur during insurance operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum InsuranceError {
+    /// Fee share is outside the allowed range
+    InvalidFeeShare = 1,
+    /// Position has no debt to write off
+    NoDebt = 2,
+    /// Position still holds collateral and must be liquidated first
+    CollateralRemaining = 3,
+    /// Arithmetic overflow occurred
+    Overflow = 4,
+    /// A debt asset is priced but has no fresh price
+    PriceNotAvailable = 5,
+}
+
+/// Storage keys for insurance data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum InsuranceDataKey {
+    /// Share of protocol fees routed to the insurance fund: i128 (basis points)
+    FeeShare,
+    /// Cumulative bad debt not covered by the insurance fund: Shortfall(asset) -> i128
+    Shortfall(Option<Address>),
+}
+
+/// Outcome of resolving a bad-debt position, valued at oracle prices
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BadDebtResolution {
+    /// Value of all debt written off, including accrued interest
+    pub written_off: i128,
+    /// Value covered by the insurance funds
+    pub covered: i128,
+    /// Value socialized across suppliers
+    pub shortfall: i128,
+}
+
+/// Set the share of protocol fees routed to the insurance fund
+///
+/// # Errors
+/// * `InsuranceError::InvalidFeeShare` - If share is outside
+///   `[0, MAX_INSURANCE_FEE_SHARE_BPS]`
+///
+/// # Security
+/// * No authorization check - only reachable through an executed governance
+///   proposal (`ProposalType::InsuranceFeeShare`)
+#[allow(deprecated)]
+pub fn set_insurance_fee_share(env: &Env, share_bps: i128) -> Result<(), InsuranceError> {
+    if !(0..=MAX_INSURANCE_FEE_SHARE_BPS).contains(&share_bps) {
+        return Err(InsuranceError::InvalidFeeShare);
+    }
+
+    let old_share = get_insurance_fee_share(env);
+    env.storage()
+        .persistent()
+        .set(&InsuranceDataKey::FeeShare, &share_bps);
+
+    let topics = (Symbol::new(env, "insurance_fee_share_updated"),);
+    env.events().publish(topics, (old_share, share_bps));
+    crate::events::emit_param_change(env, "insurance_fee_share_bps", None, old_share, share_bps);
+
+    Ok(())
+}
+
+/// Get the share of protocol fees routed to the insurance fund
+///
+/// # Returns
+/// Share in basis points (defaults to 0 - fees stay in reserves)
+pub fn get_insurance_fee_share(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&InsuranceDataKey::FeeShare)
+        .unwrap_or(0)
+}
+
+/// Credit the insurance share of a protocol fee to the insurance fund
+///
+/// Called wherever the protocol books a fee, before crediting reserves.
+///
+/// # Returns
+/// The part of `fee_amount` left for reserves
+///
+/// # Errors
+/// * `InsuranceError::Overflow` - If arithmetic overflow occurs
+pub fn route_fee_share(
+    env: &Env,
+    asset: Option<Address>,
+    fee_amount: i128,
+) -> Result<i128, InsuranceError> {
+    let share = get_insurance_fee_share(env);
+    if fee_amount <= 0 || share == 0 {
+        return Ok(fee_amount);
+    }
+
+    let insured = fee_amount
+        .checked_mul(share)
+        .and_then(|v| v.checked_div(BASIS_POINTS_SCALE))
+        .ok_or(InsuranceError::Overflow)?;
+    crate::reserve::accrue_insurance_fund(env, asset, insured)
+        .map_err(|_| InsuranceError::Overflow)?;
+
+    fee_amount
+        .checked_sub(insured)
+        .ok_or(InsuranceError::Overflow)
+}
+
+/// Write off the debt of a position with no collateral left
+///
+/// Accrues interest, clears the position's debt and, for every asset the
+/// debt was borrowed in, draws what that asset's insurance fund holds and
+/// socializes the rest.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `user` - The borrower whose debt is written off
+///
+/// # Errors
+/// * `InsuranceError::NoDebt` - If the position has no debt
+/// * `InsuranceError::CollateralRemaining` - If the position still holds collateral
+/// * `InsuranceError::PriceNotAvailable` - If a debt asset has no fresh price
+/// * `InsuranceError::Overflow` - If arithmetic overflow occurs
+///
+/// # Security
+/// * Permissionless - a position without collateral can only be closed this way
+/// * The assets and amounts come from the per-asset ledger, never the caller
+#[allow(deprecated)]
+pub fn resolve_bad_debt(env: &Env, user: Address) -> Result<BadDebtResolution, InsuranceError> {
+    let position_key = DepositDataKey::Position(user.clone());
+    let mut position = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Position>(&position_key)
+        .ok_or(InsuranceError::NoDebt)?;
+
+    let collateral: i128 = env
+        .storage()
+        .persistent()
+        .get(&DepositDataKey::CollateralBalance(user.clone()))
+        .unwrap_or(0);
+    if collateral > 0 || position.collateral > 0 {
+        return Err(InsuranceError::CollateralRemaining);
+    }
+
+    crate::repay::accrue_interest(env, &user, &mut position)
+        .map_err(|_| InsuranceError::Overflow)?;
+    let total_debt = position
+        .debt
+        .checked_add(position.borrow_interest)
+        .ok_or(InsuranceError::Overflow)?;
+    if total_debt <= 0 {
+        return Err(InsuranceError::NoDebt);
+    }
+
+    let debts = debt_by_asset(env, &user, position.debt, position.borrow_interest)?;
+    position.debt = 0;
+    position.borrow_interest = 0;
+    position.last_accrual_time = env.ledger().timestamp();
+    env.storage().persistent().set(&position_key, &position);
+    crate::risk_params::update_liquidation_flag(env, &user, 0, 0);
+    crate::analytics::emit_health_factor_changed(env, &user, Symbol::new(env, "bad_debt"));
+    crate::repay::update_protocol_analytics_repay(env, total_debt)
+        .map_err(|_| InsuranceError::Overflow)?;
+
+    let mut resolution = BadDebtResolution {
+        written_off: 0,
+        covered: 0,
+        shortfall: 0,
+    };
+    for (asset, principal, amount) in debts.iter() {
+        crate::asset_positions::record_repayment(env, &user, asset.clone(), principal);
+        if amount <= 0 {
+            continue;
+        }
+        let covered = crate::reserve::draw_insurance_fund(env, asset.clone(), amount)
+            .map_err(|_| InsuranceError::Overflow)?;
+        let shortfall = amount - covered;
+
+        if covered > 0 {
+            let fund_balance = crate::reserve::get_insurance_fund_balance(env, asset.clone());
+            let topics = (Symbol::new(env, "bad_debt_covered"), user.clone());
+            env.events()
+                .publish(topics, (asset.clone(), covered, fund_balance));
+        }
+
+        if shortfall > 0 {
+            let total_shortfall = get_shortfall(env, asset.clone())
+                .checked_add(shortfall)
+                .ok_or(InsuranceError::Overflow)?;
+            env.storage().persistent().set(
+                &InsuranceDataKey::Shortfall(asset.clone()),
+                &total_shortfall,
+            );
+
+            let topics = (Symbol::new(env, "bad_debt_shortfall"), user.clone());
+            env.events()
+                .publish(topics, (asset.clone(), shortfall, total_shortfall));
+        }
+
+        let value = |amount: i128| {
+            crate::oracle::ratio_value(env, asset.as_ref(), amount)
+                .map_err(|_| InsuranceError::PriceNotAvailable)
+        };
+        resolution.written_off = resolution
+            .written_off
+            .checked_add(value(amount)?)
+            .ok_or(InsuranceError::Overflow)?;
+        resolution.covered = resolution
+            .covered
+            .checked_add(value(covered)?)
+            .ok_or(InsuranceError::Overflow)?;
+        resolution.shortfall = resolution
+            .shortfall
+            .checked_add(value(shortfall)?)
+            .ok_or(InsuranceError::Overflow)?;
+    }
+
+    Ok(resolution)
+}
+
+/// Split `principal` and `interest` of `user` over the assets they were
+/// borrowed in
+///
+/// Returns `(asset, recorded principal, amount written off)` per asset. Each
+/// asset's principal is capped at what the unified position still owes and
+/// principal no asset accounts for is booked on the native asset, as
+/// [`crate::migrations::migrate`] does. Interest is shared pro rata to
+/// principal, with the rounding remainder on the last asset.
+fn debt_by_asset(
+    env: &Env,
+    user: &Address,
+    principal: i128,
+    interest: i128,
+) -> Result<Vec<(Option<Address>, i128, i128)>, InsuranceError> {
+    let mut debts = Vec::new(env);
+    let mut remaining = principal;
+    let mut native = None;
+    for asset in crate::asset_positions::get_user_assets(env, user).iter() {
+        let recorded = crate::asset_positions::get_asset_position(env, user, asset.clone()).debt;
+        if recorded <= 0 {
+            continue;
+        }
+        let owed = recorded.min(remaining).max(0);
+        remaining -= owed;
+        if asset.is_none() {
+            native = Some(debts.len());
+        }
+        debts.push_back((asset, recorded, owed));
+    }
+    if remaining > 0 || debts.is_empty() {
+        match native {
+            Some(i) => {
+                let (asset, recorded, owed) = debts.get_unchecked(i);
+                debts.set(i, (asset, recorded, owed + remaining));
+            }
+            None => debts.push_back((None, 0, remaining)),
+        }
+    }
+
+    let mut allocated: i128 = 0;
+    let last = debts.len() - 1;
+    for i in 0..debts.len() {
+        let (asset, recorded, owed) = debts.get_unchecked(i);
+        let share = if i == last {
+            interest - allocated
+        } else if principal > 0 {
+            interest.checked_mul(owed).ok_or(InsuranceError::Overflow)? / principal
+        } else {
+            0
+        };
+        allocated += share;
+        let amount = owed.checked_add(share).ok_or(InsuranceError::Overflow)?;
+        debts.set(i, (asset, recorded, amount));
+    }
+
+    Ok(debts)
+}
+
+/// Get the cumulative bad debt socialized for an asset
+pub fn get_shortfall(env: &Env, asset: Option<Address>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&InsuranceDataKey::Shortfall(asset))
+        .unwrap_or(0)
+}
@@ -27,6 +27,13 @@
 //! | `rate_ceiling_bps` | 10000    | 100% maximum        |
 //! | `spread_bps`       | 200      | 2% supply/borrow gap|
 //!
+//! ## Per-Asset Curves
+//!
+//! Governance can give an asset its own [`AssetRateModel`] (base rate, slopes
+//! below and above the kink, optimal utilization). The asset's rates are then
+//! evaluated on that curve at the asset's utilization, and a borrower's
+//! principal in the asset grows with the asset's borrow index.
+//!
 //! ## Interest Accrual
 //!
 //! Simple interest: `interest = principal × rate_bps × elapsed_seconds / (10_000 × SECONDS_PER_YEAR)`
@@ -35,6 +42,20 @@
 //! the elapsed time into yearly chunks and compounds, preventing overflow on
 //! multi-year accumulations while remaining deterministic.
 //!
+//! ## Asset Indexes
+//!
+//! Each asset carries a supply exchange rate and a borrow index, both starting
+//! at `1e18` and growing at the asset's supply and borrow rates. They are
+//! checkpointed by [`accrue_asset`] before every action that changes the
+//! asset's utilization, and anyone can call [`accrue`] to bring an idle
+//! asset's indexes up to date.
+//!
+//! A position stores the borrow index of each asset it owes at its last
+//! accrual; its interest since then is its principal's growth along those
+//! indexes (see [`pending_position_interest`]). Accrual is therefore O(1) per
+//! asset, identical for every borrower of the asset, and unaffected by rate
+//! changes that happened between two of the user's transactions.
+//!
 //! ## Emergency Adjustment
 //!
 //! Admin can apply a positive or negative emergency adjustment to the calculated rate,
@@ -49,7 +70,7 @@
 //! - Checked arithmetic is used throughout — no unchecked ops.
 //! - `SECONDS_PER_YEAR = 365 × 86_400 = 31_536_000` (no leap seconds).
 
-use soroban_sdk::{contracterror, contracttype, Address, Env};
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
 
 use crate::deposit::{DepositDataKey, ProtocolAnalytics};
 
@@ -99,6 +120,18 @@ pub enum InterestRateDataKey {
     Admin,
     /// Placeholder for emergency rate adjustment status.
     EmergencyRateAdjustment,
+    /// Supply exchange-rate checkpoint for an asset (None = native XLM).
+    /// Value type: [`SupplyIndex`]
+    SupplyIndex(Option<Address>),
+    /// Borrow index checkpoint for an asset (None = native XLM).
+    /// Value type: [`BorrowIndex`]
+    BorrowIndex(Option<Address>),
+    /// Borrow index of an asset at a position's last accrual (user, asset).
+    /// Value type: `i128`
+    PositionIndex(Address, Option<Address>),
+    /// Governance-set rate curve for an asset (None = native XLM).
+    /// Value type: [`AssetRateModel`]
+    AssetRateModel(Option<Address>),
 }
 
 // =============================================================================
@@ -147,6 +180,28 @@ pub struct InterestRateConfig {
     pub last_update: u64,
 }
 
+/// Per-asset kink curve, overriding the protocol-wide curve for one asset.
+///
+/// Floor, ceiling, spread and the emergency adjustment still come from the
+/// protocol-wide [`InterestRateConfig`].
+///
+/// # Invariants
+/// - `0 ≤ base_rate_bps ≤ 10_000`
+/// - `0 < kink_utilization_bps < 10_000`
+/// - `0 ≤ multiplier_bps, jump_multiplier_bps ≤ MAX_SLOPE_BPS`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssetRateModel {
+    /// Rate at 0% utilization.
+    pub base_rate_bps: i128,
+    /// Optimal utilization where the curve steepens.
+    pub kink_utilization_bps: i128,
+    /// Slope below the kink.
+    pub multiplier_bps: i128,
+    /// Slope above the kink.
+    pub jump_multiplier_bps: i128,
+}
+
 // =============================================================================
 // Constants
 // =============================================================================
@@ -293,7 +348,20 @@ pub fn calculate_utilization(env: &Env) -> Result<i128, InterestRateError> {
 pub fn calculate_borrow_rate(env: &Env) -> Result<i128, InterestRateError> {
     let config = get_interest_rate_config(env).ok_or(InterestRateError::InvalidParameter)?;
     let utilization = calculate_utilization(env)?;
+    borrow_rate_at_utilization(&config, utilization)
+}
 
+/// Evaluate the kink model at a given utilization.
+///
+/// Shared by the protocol-wide and per-asset rate paths so both produce
+/// identical results for identical utilization.
+///
+/// # Errors
+/// - [`InterestRateError::Overflow`] on arithmetic overflow.
+fn borrow_rate_at_utilization(
+    config: &InterestRateConfig,
+    utilization: i128,
+) -> Result<i128, InterestRateError> {
     let mut rate = config.base_rate_bps;
 
     if utilization <= config.kink_utilization_bps {
@@ -366,6 +434,14 @@ pub fn calculate_supply_rate(env: &Env) -> Result<i128, InterestRateError> {
     let config = get_interest_rate_config(env).ok_or(InterestRateError::InvalidParameter)?;
     let borrow_rate = calculate_borrow_rate(env)?;
 
+    supply_rate_from_borrow_rate(&config, borrow_rate)
+}
+
+/// Derive the supply rate from a borrow rate: `max(borrow_rate − spread, floor)`.
+fn supply_rate_from_borrow_rate(
+    config: &InterestRateConfig,
+    borrow_rate: i128,
+) -> Result<i128, InterestRateError> {
     let supply_rate = borrow_rate
         .checked_sub(config.spread_bps)
         .ok_or(InterestRateError::Overflow)?;
@@ -374,6 +450,561 @@ pub fn calculate_supply_rate(env: &Env) -> Result<i128, InterestRateError> {
     Ok(supply_rate.max(config.rate_floor_bps))
 }
 
+// =============================================================================
+// Per-Asset Rates
+// =============================================================================
+
+/// Calculate utilization for a single asset in basis points.
+///
+/// Uses the asset's cross-asset supply/borrow totals when it has supply
+/// recorded. Assets without cross-asset supply live in the shared pool, so
+/// their utilization is the protocol-wide figure used by accrual.
+///
+/// # Errors
+/// - [`InterestRateError::Overflow`] on arithmetic overflow.
+pub fn calculate_asset_utilization(
+    env: &Env,
+    asset: Option<Address>,
+) -> Result<i128, InterestRateError> {
+    let total_supply = crate::cross_asset::get_total_supply_for(env, asset.clone());
+    if total_supply <= 0 {
+        return calculate_utilization(env);
+    }
+    let total_borrow = crate::cross_asset::get_total_borrow_for(env, asset);
+
+    let utilization = total_borrow
+        .checked_mul(BASIS_POINTS_SCALE)
+        .ok_or(InterestRateError::Overflow)?
+        .checked_div(total_supply)
+        .ok_or(InterestRateError::DivisionByZero)?;
+
+    Ok(utilization.max(0).min(BASIS_POINTS_SCALE))
+}
+
+/// Calculate the current borrow APR for an asset in basis points.
+///
+/// Runs the same kink model and emergency adjustment as
+/// [`calculate_borrow_rate`], evaluated at the asset's utilization and with
+/// the asset's own [`AssetRateModel`] curve when governance has set one.
+///
+/// # Errors
+/// - [`InterestRateError::InvalidParameter`] if config is missing.
+/// - [`InterestRateError::Overflow`] on arithmetic overflow.
+pub fn calculate_asset_borrow_rate(
+    env: &Env,
+    asset: Option<Address>,
+) -> Result<i128, InterestRateError> {
+    let config = config_for_asset(env, &asset)?;
+    let utilization = calculate_asset_utilization(env, asset)?;
+    borrow_rate_at_utilization(&config, utilization)
+}
+
+/// Calculate the current supply APR for an asset in basis points.
+///
+/// # Errors
+/// - Propagates errors from [`calculate_asset_borrow_rate`].
+pub fn calculate_asset_supply_rate(
+    env: &Env,
+    asset: Option<Address>,
+) -> Result<i128, InterestRateError> {
+    let config = get_interest_rate_config(env).ok_or(InterestRateError::InvalidParameter)?;
+    let borrow_rate = calculate_asset_borrow_rate(env, asset)?;
+    supply_rate_from_borrow_rate(&config, borrow_rate)
+}
+
+/// Protocol-wide config with the asset's curve applied, if it has one.
+fn config_for_asset(
+    env: &Env,
+    asset: &Option<Address>,
+) -> Result<InterestRateConfig, InterestRateError> {
+    let mut config = get_interest_rate_config(env).ok_or(InterestRateError::InvalidParameter)?;
+    if let Some(model) = get_asset_rate_model(env, asset.clone()) {
+        config.base_rate_bps = model.base_rate_bps;
+        config.kink_utilization_bps = model.kink_utilization_bps;
+        config.multiplier_bps = model.multiplier_bps;
+        config.jump_multiplier_bps = model.jump_multiplier_bps;
+    }
+    Ok(config)
+}
+
+/// Calculate the borrow APR a user's position accrues at, in basis points.
+///
+/// The position carries a single accrual clock, so each asset's current
+/// borrow rate is weighted by the user's principal in that asset. Principal
+/// not attributed to any asset accrues at the protocol-wide rate.
+///
+/// # Errors
+/// - Propagates errors from [`calculate_borrow_rate`] and
+///   [`calculate_asset_borrow_rate`].
+pub fn calculate_position_borrow_rate(
+    env: &Env,
+    user: &Address,
+    principal: i128,
+) -> Result<i128, InterestRateError> {
+    let pool_rate = calculate_borrow_rate(env)?;
+    if principal <= 0 {
+        return Ok(pool_rate);
+    }
+
+    let mut weighted: i128 = 0;
+    let mut attributed: i128 = 0;
+    for asset in crate::asset_positions::get_user_assets(env, user).iter() {
+        let debt = crate::asset_positions::get_asset_position(env, user, asset.clone()).debt;
+        if debt <= 0 {
+            continue;
+        }
+        let rate = calculate_asset_borrow_rate(env, asset)?;
+        weighted = debt
+            .checked_mul(rate)
+            .and_then(|v| v.checked_add(weighted))
+            .ok_or(InterestRateError::Overflow)?;
+        attributed = attributed.saturating_add(debt);
+    }
+
+    let unattributed = principal.saturating_sub(attributed).max(0);
+    weighted = unattributed
+        .checked_mul(pool_rate)
+        .and_then(|v| v.checked_add(weighted))
+        .ok_or(InterestRateError::Overflow)?;
+    let total = attributed
+        .checked_add(unattributed)
+        .ok_or(InterestRateError::Overflow)?;
+
+    Ok(weighted / total)
+}
+
+/// Get an asset's governance-set rate curve, if any.
+pub fn get_asset_rate_model(env: &Env, asset: Option<Address>) -> Option<AssetRateModel> {
+    env.storage()
+        .persistent()
+        .get(&InterestRateDataKey::AssetRateModel(asset))
+}
+
+/// Set (or clear with `None`) an asset's rate curve.
+///
+/// Only reachable through an executed governance proposal. The asset's
+/// indexes are checkpointed first, so interest already earned accrues at the
+/// old curve.
+///
+/// # Errors
+/// - [`InterestRateError::InvalidParameter`] if a value is out of range.
+/// - Propagates errors from [`accrue_asset`].
+pub fn set_asset_rate_model(
+    env: &Env,
+    asset: Option<Address>,
+    model: Option<AssetRateModel>,
+) -> Result<(), InterestRateError> {
+    let key = InterestRateDataKey::AssetRateModel(asset.clone());
+    let Some(model) = model else {
+        accrue_asset(env, asset)?;
+        env.storage().persistent().remove(&key);
+        return Ok(());
+    };
+
+    validate_asset_rate_model(&model)?;
+
+    accrue_asset(env, asset)?;
+    env.storage().persistent().set(&key, &model);
+    Ok(())
+}
+
+/// Range-check an asset rate curve.
+///
+/// # Errors
+/// - [`InterestRateError::InvalidParameter`] if a value is out of range.
+pub fn validate_asset_rate_model(model: &AssetRateModel) -> Result<(), InterestRateError> {
+    if !(0..=BASIS_POINTS_SCALE).contains(&model.base_rate_bps)
+        || model.kink_utilization_bps <= 0
+        || model.kink_utilization_bps >= BASIS_POINTS_SCALE
+        || !(0..=MAX_SLOPE_BPS).contains(&model.multiplier_bps)
+        || !(0..=MAX_SLOPE_BPS).contains(&model.jump_multiplier_bps)
+    {
+        return Err(InterestRateError::InvalidParameter);
+    }
+    Ok(())
+}
+
+// =============================================================================
+// Exchange Rate
+// =============================================================================
+
+/// Scale of the supply exchange rate (`1e18` = one unit of underlying per share).
+pub const EXCHANGE_RATE_SCALE: i128 = 1_000_000_000_000_000_000;
+
+/// Checkpointed supply exchange rate for an asset.
+///
+/// The rate starts at [`EXCHANGE_RATE_SCALE`] and grows with the asset's
+/// supply rate. It is re-checkpointed before every action that changes the
+/// asset's utilization, so each interval accrues at the rate that applied
+/// during it.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SupplyIndex {
+    /// Underlying per share, scaled by [`EXCHANGE_RATE_SCALE`].
+    pub rate: i128,
+    /// Ledger timestamp of the checkpoint.
+    pub last_update: u64,
+}
+
+/// Get the current supply exchange rate (underlying per share) for an asset.
+///
+/// Projects the last checkpoint forward to the current ledger time at the
+/// asset's current supply rate. Growth is rounded down so shares are never
+/// valued above what suppliers have actually earned. If no rate config is
+/// set the rate does not grow.
+///
+/// # Errors
+/// - [`InterestRateError::Overflow`] on arithmetic overflow.
+pub fn get_exchange_rate(env: &Env, asset: Option<Address>) -> Result<i128, InterestRateError> {
+    let now = env.ledger().timestamp();
+    let checkpoint = match env
+        .storage()
+        .persistent()
+        .get::<InterestRateDataKey, SupplyIndex>(&InterestRateDataKey::SupplyIndex(asset.clone()))
+    {
+        Some(checkpoint) => checkpoint,
+        None => return Ok(EXCHANGE_RATE_SCALE),
+    };
+    if now <= checkpoint.last_update || get_interest_rate_config(env).is_none() {
+        return Ok(checkpoint.rate);
+    }
+
+    let supply_rate = calculate_asset_supply_rate(env, asset)?;
+    let elapsed = (now - checkpoint.last_update) as i128;
+
+    // growth = rate * supply_rate_bps * elapsed / (10_000 * SECONDS_PER_YEAR)
+    let growth = checkpoint
+        .rate
+        .checked_mul(supply_rate)
+        .ok_or(InterestRateError::Overflow)?
+        .checked_mul(elapsed)
+        .ok_or(InterestRateError::Overflow)?
+        .checked_div(BASIS_POINTS_SCALE * SECONDS_PER_YEAR as i128)
+        .ok_or(InterestRateError::DivisionByZero)?;
+
+    checkpoint
+        .rate
+        .checked_add(growth.max(0))
+        .ok_or(InterestRateError::Overflow)
+}
+
+/// Persist the current exchange rate for an asset as a new checkpoint.
+///
+/// Must be called **before** supply or borrow balances change so the elapsed
+/// interval accrues at the rate that was in effect.
+///
+/// # Errors
+/// - Propagates errors from [`get_exchange_rate`].
+pub fn checkpoint_exchange_rate(
+    env: &Env,
+    asset: Option<Address>,
+) -> Result<i128, InterestRateError> {
+    let rate = get_exchange_rate(env, asset.clone())?;
+    env.storage().persistent().set(
+        &InterestRateDataKey::SupplyIndex(asset),
+        &SupplyIndex {
+            rate,
+            last_update: env.ledger().timestamp(),
+        },
+    );
+    Ok(rate)
+}
+
+// =============================================================================
+// Borrow Index
+// =============================================================================
+
+/// Scale of the borrow index (`1e18` = no interest accrued yet).
+pub const BORROW_INDEX_SCALE: i128 = 1_000_000_000_000_000_000;
+
+/// Checkpointed borrow index for an asset.
+///
+/// The index starts at [`BORROW_INDEX_SCALE`] and grows with the asset's
+/// borrow rate, so debt taken at index `i0` is worth `debt × i1 / i0` at
+/// index `i1`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BorrowIndex {
+    /// Accumulated borrow growth, scaled by [`BORROW_INDEX_SCALE`].
+    pub index: i128,
+    /// Ledger timestamp of the checkpoint.
+    pub last_update: u64,
+}
+
+/// Get the current borrow index for an asset.
+///
+/// Projects the last checkpoint forward to the current ledger time at the
+/// asset's current borrow rate. Growth is rounded up so borrowers are never
+/// charged less than the rate implies. If no rate config is set the index
+/// does not grow.
+///
+/// # Errors
+/// - [`InterestRateError::Overflow`] on arithmetic overflow.
+pub fn get_borrow_index(env: &Env, asset: Option<Address>) -> Result<i128, InterestRateError> {
+    let now = env.ledger().timestamp();
+    let checkpoint = match env
+        .storage()
+        .persistent()
+        .get::<InterestRateDataKey, BorrowIndex>(&InterestRateDataKey::BorrowIndex(asset.clone()))
+    {
+        Some(checkpoint) => checkpoint,
+        None => return Ok(BORROW_INDEX_SCALE),
+    };
+    if now <= checkpoint.last_update || get_interest_rate_config(env).is_none() {
+        return Ok(checkpoint.index);
+    }
+
+    let borrow_rate = calculate_asset_borrow_rate(env, asset)?;
+    let growth =
+        calculate_accrued_interest(checkpoint.index, checkpoint.last_update, now, borrow_rate)?;
+
+    checkpoint
+        .index
+        .checked_add(growth)
+        .ok_or(InterestRateError::Overflow)
+}
+
+/// Persist the current borrow index for an asset as a new checkpoint.
+///
+/// Must be called **before** supply or borrow balances change so the elapsed
+/// interval accrues at the rate that was in effect.
+///
+/// # Errors
+/// - Propagates errors from [`get_borrow_index`].
+pub fn checkpoint_borrow_index(
+    env: &Env,
+    asset: Option<Address>,
+) -> Result<i128, InterestRateError> {
+    let index = get_borrow_index(env, asset.clone())?;
+    env.storage().persistent().set(
+        &InterestRateDataKey::BorrowIndex(asset),
+        &BorrowIndex {
+            index,
+            last_update: env.ledger().timestamp(),
+        },
+    );
+    Ok(index)
+}
+
+/// An asset's indexes and rates right after accrual.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssetAccrual {
+    /// Supply exchange rate, scaled by [`EXCHANGE_RATE_SCALE`].
+    pub exchange_rate: i128,
+    /// Borrow index, scaled by [`BORROW_INDEX_SCALE`].
+    pub borrow_index: i128,
+    /// Utilization the next interval accrues at (basis points).
+    pub utilization_bps: i128,
+    /// Borrow APR the next interval accrues at (basis points).
+    pub borrow_rate_bps: i128,
+    /// Ledger timestamp of the accrual.
+    pub timestamp: u64,
+}
+
+/// Checkpoint an asset's supply exchange rate and borrow index.
+///
+/// # Errors
+/// - Propagates errors from [`checkpoint_exchange_rate`] and
+///   [`checkpoint_borrow_index`].
+pub fn accrue_asset(env: &Env, asset: Option<Address>) -> Result<AssetAccrual, InterestRateError> {
+    let exchange_rate = checkpoint_exchange_rate(env, asset.clone())?;
+    let borrow_index = checkpoint_borrow_index(env, asset.clone())?;
+    let (utilization_bps, borrow_rate_bps) = if get_interest_rate_config(env).is_some() {
+        (
+            calculate_asset_utilization(env, asset.clone())?,
+            calculate_asset_borrow_rate(env, asset)?,
+        )
+    } else {
+        (0, 0)
+    };
+
+    Ok(AssetAccrual {
+        exchange_rate,
+        borrow_index,
+        utilization_bps,
+        borrow_rate_bps,
+        timestamp: env.ledger().timestamp(),
+    })
+}
+
+/// Bring an asset's indexes up to date (permissionless keeper hook).
+///
+/// # Errors
+/// - Propagates errors from [`accrue_asset`].
+#[allow(deprecated)]
+pub fn accrue(env: &Env, asset: Option<Address>) -> Result<AssetAccrual, InterestRateError> {
+    let accrual = accrue_asset(env, asset.clone())?;
+
+    let topics = (Symbol::new(env, "interest_accrued"), asset);
+    env.events().publish(
+        topics,
+        (
+            accrual.exchange_rate,
+            accrual.borrow_index,
+            accrual.borrow_rate_bps,
+        ),
+    );
+
+    Ok(accrual)
+}
+
+// =============================================================================
+// Position Interest
+// =============================================================================
+
+/// Interest a position's `principal` has accrued since its last accrual.
+///
+/// Each asset's principal grows with that asset's borrow index from the
+/// snapshot taken at the position's last accrual:
+/// `interest = principal × (index_now − index_snapshot) / index_snapshot`.
+/// Principal not attributed to any asset accrues on the native index.
+/// Principal without a snapshot (positions opened before borrow indexes)
+/// accrues at the asset's current rate from `last_accrual_time`. The staker
+/// spread discount is rebated on the whole principal.
+///
+/// # Errors
+/// - [`InterestRateError::Overflow`] on arithmetic overflow.
+/// - Propagates errors from [`get_borrow_index`].
+pub fn pending_position_interest(
+    env: &Env,
+    user: &Address,
+    principal: i128,
+    last_accrual_time: u64,
+) -> Result<i128, InterestRateError> {
+    let mut interest: i128 = 0;
+    for (asset, amount) in position_principals(env, user, principal).iter() {
+        if amount <= 0 {
+            continue;
+        }
+        let index = get_borrow_index(env, asset.clone())?;
+        interest = principal_interest(env, user, &asset, amount, last_accrual_time, index)?
+            .checked_add(interest)
+            .ok_or(InterestRateError::Overflow)?;
+    }
+    discount_interest(env, user, principal, last_accrual_time, interest)
+}
+
+/// Like [`pending_position_interest`], then move the position's snapshots
+/// to the current borrow indexes.
+///
+/// Snapshots of assets the position no longer owes are removed.
+///
+/// # Errors
+/// - Same as [`pending_position_interest`].
+pub fn accrue_position_interest(
+    env: &Env,
+    user: &Address,
+    principal: i128,
+    last_accrual_time: u64,
+) -> Result<i128, InterestRateError> {
+    let mut interest: i128 = 0;
+    for (asset, amount) in position_principals(env, user, principal).iter() {
+        let key = InterestRateDataKey::PositionIndex(user.clone(), asset.clone());
+        if amount <= 0 {
+            env.storage().persistent().remove(&key);
+            continue;
+        }
+        let index = checkpoint_borrow_index(env, asset.clone())?;
+        interest = principal_interest(env, user, &asset, amount, last_accrual_time, index)?
+            .checked_add(interest)
+            .ok_or(InterestRateError::Overflow)?;
+        env.storage().persistent().set(&key, &index);
+    }
+    discount_interest(env, user, principal, last_accrual_time, interest)
+}
+
+/// Snapshot the borrow index of `asset` for a position that just borrowed it.
+///
+/// # Errors
+/// - Propagates errors from [`get_borrow_index`].
+pub fn snapshot_position_index(
+    env: &Env,
+    user: &Address,
+    asset: Option<Address>,
+) -> Result<(), InterestRateError> {
+    let index = get_borrow_index(env, asset.clone())?;
+    env.storage().persistent().set(
+        &InterestRateDataKey::PositionIndex(user.clone(), asset),
+        &index,
+    );
+    Ok(())
+}
+
+/// Principal owed in each asset of `user`'s position, native asset last.
+fn position_principals(env: &Env, user: &Address, principal: i128) -> Vec<(Option<Address>, i128)> {
+    let mut principals = Vec::new(env);
+    let mut attributed: i128 = 0;
+    let mut native: i128 = 0;
+    for asset in crate::asset_positions::get_user_assets(env, user).iter() {
+        let debt = crate::asset_positions::get_asset_position(env, user, asset.clone())
+            .debt
+            .max(0);
+        attributed = attributed.saturating_add(debt);
+        match asset {
+            Some(_) => principals.push_back((asset, debt)),
+            None => native = native.saturating_add(debt),
+        }
+    }
+    native = native.saturating_add(principal.saturating_sub(attributed).max(0));
+    principals.push_back((None, native));
+    principals
+}
+
+/// Interest on `principal` of `asset` up to borrow index `index`, rounded up.
+fn principal_interest(
+    env: &Env,
+    user: &Address,
+    asset: &Option<Address>,
+    principal: i128,
+    last_accrual_time: u64,
+    index: i128,
+) -> Result<i128, InterestRateError> {
+    let snapshot = env.storage().persistent().get::<InterestRateDataKey, i128>(
+        &InterestRateDataKey::PositionIndex(user.clone(), asset.clone()),
+    );
+    let Some(snapshot) = snapshot.filter(|snapshot| *snapshot > 0) else {
+        let rate_bps = calculate_asset_borrow_rate(env, asset.clone())?;
+        return calculate_accrued_interest(
+            principal,
+            last_accrual_time,
+            env.ledger().timestamp(),
+            rate_bps,
+        );
+    };
+
+    let numerator = principal
+        .checked_mul(index.saturating_sub(snapshot).max(0))
+        .ok_or(InterestRateError::Overflow)?;
+    let interest = numerator / snapshot;
+    if numerator % snapshot > 0 {
+        interest.checked_add(1).ok_or(InterestRateError::Overflow)
+    } else {
+        Ok(interest)
+    }
+}
+
+/// Rebate the staker spread discount on `principal` from `interest`.
+fn discount_interest(
+    env: &Env,
+    user: &Address,
+    principal: i128,
+    last_accrual_time: u64,
+    interest: i128,
+) -> Result<i128, InterestRateError> {
+    let discount_bps = crate::fee_discount::spread_discount_bps(env, user, last_accrual_time);
+    if discount_bps <= 0 || interest <= 0 {
+        return Ok(interest);
+    }
+    let rebate = calculate_accrued_interest(
+        principal,
+        last_accrual_time,
+        env.ledger().timestamp(),
+        discount_bps,
+    )?;
+    Ok(interest.saturating_sub(rebate).max(0))
+}
+
 // =============================================================================
 // Simple Interest Accrual
 // =============================================================================
@@ -572,9 +1203,96 @@ pub fn update_interest_rate_config(
     spread_bps: Option<i128>,
 ) -> Result<(), InterestRateError> {
     // Authorization
-    crate::admin::require_admin(env, &caller).map_err(|_| InterestRateError::Unauthorized)?;
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| InterestRateError::Unauthorized)?;
+
+    set_interest_rate_config_governed(
+        env,
+        base_rate_bps,
+        kink_utilization_bps,
+        multiplier_bps,
+        jump_multiplier_bps,
+        rate_floor_bps,
+        rate_ceiling_bps,
+        spread_bps,
+    )
+}
 
-    let config_key = InterestRateDataKey::InterestRateConfig;
+/// Update interest rate configuration parameters without an admin check.
+///
+/// Only reachable through an executed governance proposal. Takes the same
+/// optional parameters and applies the same range checks as
+/// [`update_interest_rate_config`].
+#[allow(clippy::too_many_arguments)]
+pub fn set_interest_rate_config_governed(
+    env: &Env,
+    base_rate_bps: Option<i128>,
+    kink_utilization_bps: Option<i128>,
+    multiplier_bps: Option<i128>,
+    jump_multiplier_bps: Option<i128>,
+    rate_floor_bps: Option<i128>,
+    rate_ceiling_bps: Option<i128>,
+    spread_bps: Option<i128>,
+) -> Result<(), InterestRateError> {
+    let mut config = merged_interest_rate_config(
+        env,
+        base_rate_bps,
+        kink_utilization_bps,
+        multiplier_bps,
+        jump_multiplier_bps,
+        rate_floor_bps,
+        rate_ceiling_bps,
+        spread_bps,
+    )?;
+
+    config.last_update = env.ledger().timestamp();
+    env.storage()
+        .persistent()
+        .set(&InterestRateDataKey::InterestRateConfig, &config);
+
+    Ok(())
+}
+
+/// Check an interest rate configuration update without applying it.
+///
+/// Used when a governance proposal is created so that invalid values are
+/// rejected before the vote.
+#[allow(clippy::too_many_arguments)]
+pub fn check_interest_rate_config(
+    env: &Env,
+    base_rate_bps: Option<i128>,
+    kink_utilization_bps: Option<i128>,
+    multiplier_bps: Option<i128>,
+    jump_multiplier_bps: Option<i128>,
+    rate_floor_bps: Option<i128>,
+    rate_ceiling_bps: Option<i128>,
+    spread_bps: Option<i128>,
+) -> Result<(), InterestRateError> {
+    merged_interest_rate_config(
+        env,
+        base_rate_bps,
+        kink_utilization_bps,
+        multiplier_bps,
+        jump_multiplier_bps,
+        rate_floor_bps,
+        rate_ceiling_bps,
+        spread_bps,
+    )
+    .map(|_| ())
+}
+
+/// Current configuration with the given updates applied and range-checked.
+#[allow(clippy::too_many_arguments)]
+fn merged_interest_rate_config(
+    env: &Env,
+    base_rate_bps: Option<i128>,
+    kink_utilization_bps: Option<i128>,
+    multiplier_bps: Option<i128>,
+    jump_multiplier_bps: Option<i128>,
+    rate_floor_bps: Option<i128>,
+    rate_ceiling_bps: Option<i128>,
+    spread_bps: Option<i128>,
+) -> Result<InterestRateConfig, InterestRateError> {
     let mut config = get_interest_rate_config(env).ok_or(InterestRateError::InvalidParameter)?;
 
     // --- Validate and apply each parameter ---
@@ -637,10 +1355,7 @@ pub fn update_interest_rate_config(
         config.spread_bps = spread;
     }
 
-    config.last_update = env.ledger().timestamp();
-    env.storage().persistent().set(&config_key, &config);
-
-    Ok(())
+    Ok(config)
 }
 
 // =============================================================================
@@ -671,7 +1386,8 @@ pub fn set_emergency_rate_adjustment(
     caller: Address,
     adjustment_bps: i128,
 ) -> Result<(), InterestRateError> {
-    crate::admin::require_admin(env, &caller).map_err(|_| InterestRateError::Unauthorized)?;
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| InterestRateError::Unauthorized)?;
 
     if adjustment_bps.abs() > BASIS_POINTS_SCALE {
         return Err(InterestRateError::InvalidParameter);
@@ -688,6 +1404,30 @@ pub fn set_emergency_rate_adjustment(
     Ok(())
 }
 
+/// Set the base rate without an admin check, returning the previous value.
+///
+/// Used by the risk engine, whose adjustments are bounded by governance-set
+/// bands before reaching this function.
+///
+/// # Errors
+/// - [`InterestRateError::InvalidParameter`] if config is missing or the rate
+///   is outside `[0, 10_000]`.
+pub fn apply_base_rate(env: &Env, base_rate_bps: i128) -> Result<i128, InterestRateError> {
+    if !(0..=BASIS_POINTS_SCALE).contains(&base_rate_bps) {
+        return Err(InterestRateError::InvalidParameter);
+    }
+
+    let config_key = InterestRateDataKey::InterestRateConfig;
+    let mut config = get_interest_rate_config(env).ok_or(InterestRateError::InvalidParameter)?;
+    let previous = config.base_rate_bps;
+
+    config.base_rate_bps = base_rate_bps;
+    config.last_update = env.ledger().timestamp();
+    env.storage().persistent().set(&config_key, &config);
+
+    Ok(previous)
+}
+
 // =============================================================================
 // Public Query Helpers
 // =============================================================================
@@ -0,0 +1,337 @@
+//! # Keeper Registry Module
+//!
+//! Registry of bonded liquidation and maintenance keepers.
+//!
+//! Keepers register by posting a bond in the governance-configured bond token.
+//! While their bond is at least `min_bond` and they have not started
+//! unbonding, they are *active* and get a rebate on keeper-incentivized calls:
+//! `rebate_bps` of the insurance cut withheld from their liquidation incentive
+//! is paid to them instead.
+//!
+//! ## Lifecycle
+//! 1. [`register_keeper`] — post a bond of at least `min_bond`.
+//! 2. [`request_unbond`] — stop acting as a keeper; starts the unbonding period.
+//! 3. [`withdraw_bond`] — reclaim the remaining bond once the period has passed.
+//!
+//! ## Security
+//! - Configuration and slashing are only reachable through executed governance
+//!   proposals. Slashed bond is credited to protocol reserves.
+//! - The unbonding period keeps a misbehaving keeper's bond slashable for a
+//!   while after they try to leave.
+//! - Each keeper records the token their bond was posted in, so bonds are
+//!   returned and slashed in that token even if governance later changes
+//!   `bond_token`. Only bonds in the current bond token make a keeper active.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, token::TokenClient, Address, Env, Symbol};
+
+use crate::reserve::ReserveDataKey;
+
+/// Basis points scale (100% = 10_000)
+pub const BASIS_POINTS_SCALE: i128 = 10_000;
+
+/// Errors that can occur during keeper registry operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum KeeperError {
+    /// Keeper registry is not configured
+    NotConfigured = 1,
+    /// Amount must be greater than zero
+    InvalidAmount = 2,
+    /// Keeper is already registered
+    AlreadyRegistered = 3,
+    /// Keeper is not registered
+    NotRegistered = 4,
+    /// Bond is below the configured minimum
+    BondTooLow = 5,
+    /// Keeper has not requested to unbond
+    NotUnbonding = 6,
+    /// Unbonding period has not passed
+    UnbondingNotReady = 7,
+    /// A parameter is out of range
+    InvalidParameter = 8,
+    /// Arithmetic overflow occurred
+    Overflow = 9,
+    /// Pool and strategy together cannot cover the transfer
+    InsufficientLiquidity = 10,
+}
+
+/// Storage keys for keeper registry data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum KeeperDataKey {
+    /// Registry configuration: KeeperConfig
+    Config,
+    /// Registered keeper: KeeperInfo
+    Keeper(Address),
+    /// Bond posted across all keepers per bond token: i128
+    TotalBonded(Address),
+}
+
+/// Keeper registry configuration
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeeperConfig {
+    /// Token bonds are posted in
+    pub bond_token: Address,
+    /// Minimum bond to register and stay active
+    pub min_bond: i128,
+    /// Seconds between requesting to unbond and withdrawing the bond
+    pub unbonding_period: u64,
+    /// Share of the liquidation insurance cut rebated to active keepers (basis points)
+    pub rebate_bps: i128,
+}
+
+/// A registered keeper
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeeperInfo {
+    /// Token the bond was posted in
+    pub bond_token: Address,
+    /// Bond currently posted
+    pub bond: i128,
+    pub registered_at: u64,
+    /// When the bond can be withdrawn (0 if not unbonding)
+    pub unbond_at: u64,
+}
+
+/// Set the keeper registry configuration
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `KeeperError::InvalidParameter` - If `min_bond <= 0` or the rebate is
+///   outside `[0, 10_000]`
+#[allow(deprecated)]
+pub fn set_config(env: &Env, config: KeeperConfig) -> Result<(), KeeperError> {
+    if config.min_bond <= 0 || !(0..=BASIS_POINTS_SCALE).contains(&config.rebate_bps) {
+        return Err(KeeperError::InvalidParameter);
+    }
+
+    env.storage().persistent().set(&KeeperDataKey::Config, &config);
+
+    let topics = (Symbol::new(env, "keeper_config_updated"),);
+    env.events().publish(
+        topics,
+        (
+            config.bond_token,
+            config.min_bond,
+            config.unbonding_period,
+            config.rebate_bps,
+        ),
+    );
+
+    Ok(())
+}
+
+/// Get the keeper registry configuration
+pub fn get_config(env: &Env) -> Option<KeeperConfig> {
+    env.storage().persistent().get(&KeeperDataKey::Config)
+}
+
+/// Register as a keeper by posting a bond
+///
+/// # Errors
+/// * `KeeperError::NotConfigured` - If the registry is not configured
+/// * `KeeperError::AlreadyRegistered` - If `keeper` is already registered
+/// * `KeeperError::BondTooLow` - If `bond < min_bond`
+#[allow(deprecated)]
+pub fn register_keeper(env: &Env, keeper: Address, bond: i128) -> Result<(), KeeperError> {
+    keeper.require_auth();
+
+    let config = get_config(env).ok_or(KeeperError::NotConfigured)?;
+    let key = KeeperDataKey::Keeper(keeper.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(KeeperError::AlreadyRegistered);
+    }
+    if bond < config.min_bond {
+        return Err(KeeperError::BondTooLow);
+    }
+
+    let info = KeeperInfo {
+        bond_token: config.bond_token.clone(),
+        bond,
+        registered_at: env.ledger().timestamp(),
+        unbond_at: 0,
+    };
+    env.storage().persistent().set(&key, &info);
+    add_total_bonded(env, &config.bond_token, bond)?;
+
+    TokenClient::new(env, &config.bond_token).transfer(
+        &keeper,
+        &env.current_contract_address(),
+        &bond,
+    );
+
+    let topics = (Symbol::new(env, "keeper_registered"), keeper);
+    env.events().publish(topics, bond);
+
+    Ok(())
+}
+
+/// Start unbonding; the keeper stops being active immediately
+///
+/// # Errors
+/// * `KeeperError::NotRegistered` - If `keeper` is not registered
+#[allow(deprecated)]
+pub fn request_unbond(env: &Env, keeper: Address) -> Result<u64, KeeperError> {
+    keeper.require_auth();
+
+    let config = get_config(env).ok_or(KeeperError::NotConfigured)?;
+    let key = KeeperDataKey::Keeper(keeper.clone());
+    let mut info: KeeperInfo = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(KeeperError::NotRegistered)?;
+
+    if info.unbond_at == 0 {
+        info.unbond_at = env
+            .ledger()
+            .timestamp()
+            .saturating_add(config.unbonding_period);
+        env.storage().persistent().set(&key, &info);
+    }
+
+    let topics = (Symbol::new(env, "keeper_unbonding"), keeper);
+    env.events().publish(topics, info.unbond_at);
+
+    Ok(info.unbond_at)
+}
+
+/// Withdraw the remaining bond after the unbonding period and deregister
+///
+/// # Errors
+/// * `KeeperError::NotRegistered` - If `keeper` is not registered
+/// * `KeeperError::NotUnbonding` - If `keeper` has not requested to unbond
+/// * `KeeperError::UnbondingNotReady` - If the unbonding period has not passed
+/// * `KeeperError::InsufficientLiquidity` - If pool and strategy cannot cover the bond
+#[allow(deprecated)]
+pub fn withdraw_bond(env: &Env, keeper: Address) -> Result<i128, KeeperError> {
+    keeper.require_auth();
+
+    let key = KeeperDataKey::Keeper(keeper.clone());
+    let info: KeeperInfo = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(KeeperError::NotRegistered)?;
+    if info.unbond_at == 0 {
+        return Err(KeeperError::NotUnbonding);
+    }
+    if env.ledger().timestamp() < info.unbond_at {
+        return Err(KeeperError::UnbondingNotReady);
+    }
+
+    env.storage().persistent().remove(&key);
+    add_total_bonded(env, &info.bond_token, -info.bond)?;
+    if info.bond > 0 {
+        crate::strategy::ensure_liquidity(env, &info.bond_token, info.bond)
+            .map_err(|_| KeeperError::InsufficientLiquidity)?;
+        TokenClient::new(env, &info.bond_token).transfer(
+            &env.current_contract_address(),
+            &keeper,
+            &info.bond,
+        );
+    }
+
+    let topics = (Symbol::new(env, "keeper_bond_withdrawn"), keeper);
+    env.events().publish(topics, info.bond);
+
+    Ok(info.bond)
+}
+
+/// Slash part of a keeper's bond into protocol reserves
+///
+/// Only reachable through an executed governance proposal. Slashing works
+/// during unbonding too.
+///
+/// # Errors
+/// * `KeeperError::InvalidAmount` - If `amount <= 0`
+/// * `KeeperError::NotRegistered` - If `keeper` is not registered
+#[allow(deprecated)]
+pub fn slash_keeper(env: &Env, keeper: Address, amount: i128) -> Result<i128, KeeperError> {
+    if amount <= 0 {
+        return Err(KeeperError::InvalidAmount);
+    }
+    let key = KeeperDataKey::Keeper(keeper.clone());
+    let mut info: KeeperInfo = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(KeeperError::NotRegistered)?;
+
+    let slashed = amount.min(info.bond);
+    info.bond -= slashed;
+    env.storage().persistent().set(&key, &info);
+    add_total_bonded(env, &info.bond_token, -slashed)?;
+
+    let reserve_key = ReserveDataKey::ReserveBalance(Some(info.bond_token.clone()));
+    let reserve: i128 = env.storage().persistent().get(&reserve_key).unwrap_or(0);
+    let new_reserve = reserve.checked_add(slashed).ok_or(KeeperError::Overflow)?;
+    env.storage().persistent().set(&reserve_key, &new_reserve);
+
+    let topics = (Symbol::new(env, "keeper_slashed"), keeper);
+    env.events().publish(topics, (slashed, info.bond));
+
+    Ok(info.bond)
+}
+
+/// Get a registered keeper
+pub fn get_keeper(env: &Env, keeper: &Address) -> Option<KeeperInfo> {
+    env.storage()
+        .persistent()
+        .get(&KeeperDataKey::Keeper(keeper.clone()))
+}
+
+/// Bond posted in `token` across all keepers
+pub fn get_total_bonded(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&KeeperDataKey::TotalBonded(token.clone()))
+        .unwrap_or(0)
+}
+
+fn add_total_bonded(env: &Env, token: &Address, delta: i128) -> Result<(), KeeperError> {
+    let total = get_total_bonded(env, token)
+        .checked_add(delta)
+        .ok_or(KeeperError::Overflow)?
+        .max(0);
+    env.storage()
+        .persistent()
+        .set(&KeeperDataKey::TotalBonded(token.clone()), &total);
+    Ok(())
+}
+
+/// Whether `keeper` is registered, bonded at or above `min_bond` in the
+/// current bond token and not unbonding
+pub fn is_active_keeper(env: &Env, keeper: &Address) -> bool {
+    let config = match get_config(env) {
+        Some(config) => config,
+        None => return false,
+    };
+    match get_keeper(env, keeper) {
+        Some(info) => {
+            info.unbond_at == 0
+                && info.bond_token == config.bond_token
+                && info.bond >= config.min_bond
+        }
+        None => false,
+    }
+}
+
+/// Portion of a liquidation insurance cut rebated to `keeper`
+///
+/// Returns 0 if `keeper` is not active.
+pub fn insurance_rebate(env: &Env, keeper: &Address, insurance_amount: i128) -> i128 {
+    if insurance_amount <= 0 || !is_active_keeper(env, keeper) {
+        return 0;
+    }
+    let rebate_bps = get_config(env).map(|c| c.rebate_bps).unwrap_or(0);
+    insurance_amount
+        .checked_mul(rebate_bps)
+        .map(|v| v / BASIS_POINTS_SCALE)
+        .unwrap_or(0)
+}
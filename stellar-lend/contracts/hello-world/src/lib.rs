@@ -5,33 +5,71 @@
 use soroban_sdk::{contract, contractimpl, Address, Env, Map, Symbol, Vec};
 use soroban_sdk::{contract, contractimpl, Address, Env, Map, Symbol, Vec, contracttype, contracterror};
 
+pub mod accounting;
+pub mod activity;
 pub mod admin;
 pub mod amm;
 pub mod analytics;
+pub mod asset_positions;
+pub mod asset_registry;
 pub mod borrow;
 pub mod bridge;
+pub mod capitalization;
+pub mod circuit_breaker;
+pub mod claims;
+pub mod credit_delegation;
+pub mod credit_history;
 pub mod config;
 pub mod config_snapshot;
 pub mod cross_asset;
+pub mod depeg;
 pub mod deposit;
+pub mod dust;
+pub mod emode;
 pub mod errors;
 pub mod events;
+pub mod fee_discount;
+pub mod flash_guard;
 pub mod flash_loan;
 pub mod governance;
+pub mod hot_config;
+pub mod insurance;
 pub mod interest_rate;
+pub mod keeper;
 pub mod liquidate;
+pub mod loyalty;
+pub mod migration;
+pub mod migrations;
 pub mod multisig;
 pub mod oracle;
+pub mod origination_fee;
+pub mod param_ramp;
+pub mod pause_downtime;
 pub mod recovery;
 pub mod reentrancy;
+pub mod referral;
 pub mod repay;
 pub mod reserve;
+pub mod revenue;
+pub mod risk_engine;
+pub mod rewards;
 pub mod risk_management;
 pub mod risk_params;
+pub mod risk_tier;
+pub mod savings;
+pub mod simulation;
 pub mod storage;
+pub mod strategy;
+pub mod supply_interest;
+pub mod throttle;
+pub mod ttl;
 pub mod types;
+pub mod upgrade;
 pub mod withdraw;
 
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
+
 #[cfg(test)]
 mod tests;
 // Legacy test suite currently mismatches contract API and is excluded from CI compile.
@@ -41,20 +79,14 @@ mod tests;
 use crate::oracle::OracleConfig;
 use crate::risk_management::{RiskConfig, RiskManagementError};
 
-/// Helper function to require admin authorization
-fn require_admin(env: &Env, caller: &Address) -> Result<(), RiskManagementError> {
+/// Helper function to require a role (or the super admin)
+fn require_role(
+    env: &Env,
+    caller: &Address,
+    role: crate::admin::Role,
+) -> Result<(), RiskManagementError> {
     caller.require_auth();
-    let admin_key = DepositDataKey::Admin;
-    let admin = env
-        .storage()
-        .persistent()
-        .get::<DepositDataKey, Address>(&admin_key)
-        .ok_or(RiskManagementError::Unauthorized)?;
-
-    if caller != &admin {
-        return Err(RiskManagementError::Unauthorized);
-    }
-    Ok(())
+    crate::admin::require_role(env, caller, role).map_err(|_| RiskManagementError::Unauthorized)
 }
 
 
@@ -84,8 +116,9 @@ use crate::config::{config_backup, config_get, config_restore, config_set, Confi
 use crate::config_snapshot::{get_config_snapshot, ConfigSnapshot};
 use crate::cross_asset::{
     get_asset_config_by_address, get_asset_list, get_total_borrow_for, get_total_supply_for,
-    get_user_asset_position, get_user_position_summary, initialize_asset, update_asset_config,
-    update_asset_price, AssetConfig, AssetKey, AssetPosition, CrossAssetError, UserPositionSummary,
+    get_user_active_assets, get_user_asset_position, get_user_position_summary, initialize_asset,
+    update_asset_config, update_asset_price, AssetConfig, AssetKey, AssetPosition, CrossAssetError,
+    UserPositionSummary,
 };
 use crate::deposit::{DepositDataKey, ProtocolAnalytics};
 use crate::flash_loan::{
@@ -141,8 +174,6 @@ pub enum AmmError {
     SlippageExceeded = 3,
 }
 
-pub mod reentrancy;
-
 
 /// The StellarLend core contract.
 #[contract]
@@ -160,6 +191,9 @@ impl HelloContract {
     }
 
     /// Initialize the contract with admin address.
+    ///
+    /// Also stores the network's native XLM Stellar Asset Contract as the native
+    /// asset address, so deposits and withdrawals with `asset = None` move XLM.
     pub fn initialize(env: Env, admin: Address) -> Result<(), RiskManagementError> {
         // Check if already initialized (comprehensive check)
         if crate::admin::has_admin(&env)
@@ -180,6 +214,11 @@ impl HelloContract {
                 RiskManagementError::Unauthorized
             }
         })?;
+        crate::migrations::initialize_schema_version(&env);
+        // The unit-test host has no native asset contract; tests that move native
+        // XLM register one with `set_native_asset_address`
+        #[cfg(not(test))]
+        crate::deposit::init_native_asset_address(&env);
         Ok(())
     }
 
@@ -192,6 +231,16 @@ impl HelloContract {
         crate::admin::set_admin(&env, new_admin, Some(caller))
     }
 
+    /// Permanently hand admin rights to governance (admin only, irreversible).
+    pub fn renounce_admin(env: Env, caller: Address) -> Result<(), crate::admin::AdminError> {
+        crate::admin::renounce_admin(&env, caller)
+    }
+
+    /// Whether admin rights were renounced to governance.
+    pub fn is_admin_renounced(env: Env) -> bool {
+        crate::admin::is_admin_renounced(&env)
+    }
+
     /// Grant a role to an address (admin only).
     pub fn grant_role(
         env: Env,
@@ -212,20 +261,52 @@ impl HelloContract {
         crate::admin::revoke_role(&env, caller, role, account)
     }
 
+    /// Give up a role held by `account`.
+    pub fn renounce_role(
+        env: Env,
+        account: Address,
+        role: Symbol,
+    ) -> Result<(), crate::admin::AdminError> {
+        crate::admin::renounce_role(&env, account, role)
+    }
+
+    /// Check whether an address holds a role.
+    pub fn has_role(env: Env, role: Symbol, account: Address) -> bool {
+        crate::admin::has_role(&env, role, account)
+    }
+
     /// Deposit collateral into the protocol.
     pub fn deposit_collateral(
         env: Env,
         user: Address,
         asset: Option<Address>,
         amount: i128,
-    ) -> Result<i128, crate::deposit::DepositError> {
-        crate::deposit::deposit_collateral(&env, user, asset, amount)
+    ) -> Result<i128, crate::errors::ProtocolError> {
+        Ok(crate::deposit::deposit_collateral(
+            &env, user, asset, amount,
+        )?)
+    }
+
+    /// Deposit collateral, attributing `user` to `referrer` if not already attributed.
+    pub fn deposit_collateral_with_referrer(
+        env: Env,
+        user: Address,
+        asset: Option<Address>,
+        amount: i128,
+        referrer: Option<Address>,
+    ) -> Result<i128, crate::errors::ProtocolError> {
+        referral::attribute(&env, &user, referrer);
+        Ok(crate::deposit::deposit_collateral(
+            &env, user, asset, amount,
+        )?)
     }
 
     /// Withdraw collateral from the protocol.
     ///
     /// Transfers `amount` of `asset` (or native XLM when `asset` is `None`)
-    /// back to `user`, subject to all safety and risk checks.
+    /// back to `user`, subject to all safety and risk checks. Passing
+    /// `i128::MAX` withdraws the user's whole balance of `asset`, including
+    /// supply interest compounded in the same call.
     ///
     /// # Authorization
     /// Only the position owner (`user`) can call this function.
@@ -234,10 +315,10 @@ impl HelloContract {
     /// # Errors
     /// - If `amount` ≤ 0 → `InvalidAmount`
     /// - If `user` did not authorize → `Unauthorized`
-    /// - If withdrawals are paused → `WithdrawPaused`
+    /// - If withdrawals are paused → `OperationPaused`
     /// - If `user` balance < `amount` → `InsufficientCollateral`
-    /// - If withdrawal breaks minimum collateral ratio → `InsufficientCollateralRatio`
-    /// - If withdrawal would make position liquidatable → `Undercollateralized`
+    /// - If withdrawal breaks minimum collateral ratio or would make the
+    ///   position liquidatable → `InsufficientCollateralRatio`
     ///
     /// # Security
     /// - Enforces post-withdraw health checks against latest risk parameters.
@@ -248,8 +329,10 @@ impl HelloContract {
         user: Address,
         asset: Option<Address>,
         amount: i128,
-    ) -> Result<i128, crate::withdraw::WithdrawError> {
-        crate::withdraw::withdraw_collateral(&env, user, asset, amount)
+    ) -> Result<i128, crate::errors::ProtocolError> {
+        Ok(crate::withdraw::withdraw_collateral(
+            &env, user, asset, amount,
+        )?)
     }
 
     /// Set native asset address (admin only).
@@ -257,18 +340,144 @@ impl HelloContract {
         env: Env,
         caller: Address,
         native_asset: Address,
-    ) -> Result<(), crate::deposit::DepositError> {
-        crate::deposit::set_native_asset_address(&env, caller, native_asset)
+    ) -> Result<(), crate::errors::ProtocolError> {
+        Ok(crate::deposit::set_native_asset_address(
+            &env,
+            caller,
+            native_asset,
+        )?)
     }
 
-    /// Withdraw collateral from the protocol.
-    pub fn withdraw_collateral(
+    /// Set or clear the per-account deposit cap for an asset (admin only).
+    pub fn set_user_deposit_cap(
+        env: Env,
+        caller: Address,
+        asset: Option<Address>,
+        cap: Option<i128>,
+    ) -> Result<(), crate::errors::ProtocolError> {
+        Ok(crate::deposit::set_user_deposit_cap(
+            &env, caller, asset, cap,
+        )?)
+    }
+
+    /// Get the per-account deposit cap for an asset (`None` = unlimited).
+    pub fn get_user_deposit_cap(env: Env, asset: Option<Address>) -> Option<i128> {
+        crate::deposit::get_user_deposit_cap(&env, asset)
+    }
+
+    /// Set the minimum debt and collateral a user may hold in an asset (risk admin only).
+    pub fn set_min_position_size(
+        env: Env,
+        caller: Address,
+        asset: Option<Address>,
+        sizes: crate::dust::MinPositionSize,
+    ) -> Result<(), crate::dust::DustError> {
+        crate::dust::set_min_position_size(&env, caller, asset, sizes)
+    }
+
+    /// Get the minimum position sizes for an asset (zeros = no minimum).
+    pub fn get_min_position_size(env: Env, asset: Option<Address>) -> crate::dust::MinPositionSize {
+        crate::dust::get_min_position_size(&env, asset)
+    }
+
+    /// Get an asset's deposit parameters, if set (changed through governance).
+    pub fn get_asset_params(env: Env, asset: Address) -> Option<crate::deposit::AssetParams> {
+        crate::deposit::get_asset_params(&env, asset)
+    }
+
+    /// Get governance-listed assets in listing order, skipping `offset` and returning at most `limit`.
+    pub fn get_listed_assets(env: Env, offset: u32, limit: u32) -> Vec<Address> {
+        asset_registry::get_listed_assets(&env, offset, limit)
+    }
+
+    /// Get the configuration an asset was listed with, if it was listed through governance.
+    pub fn get_asset_listing(env: Env, asset: Address) -> Option<asset_registry::ListedAsset> {
+        asset_registry::get_listing(&env, &asset)
+    }
+
+    /// Get the number of governance-listed assets.
+    pub fn get_listed_asset_count(env: Env) -> u32 {
+        asset_registry::listed_asset_count(&env)
+    }
+
+    /// Remove a delisted asset from the registry once no positions remain (anyone may call).
+    pub fn finalize_delisting(
+        env: Env,
+        asset: Address,
+    ) -> Result<(), asset_registry::AssetRegistryError> {
+        asset_registry::finalize_delisting(&env, asset)
+    }
+
+    /// Get the progress of an asset's delisting, if one is in progress.
+    pub fn get_sunset_status(env: Env, asset: Address) -> Option<asset_registry::SunsetStatus> {
+        asset_registry::get_sunset_status(&env, &asset)
+    }
+
+    /// Get a user's collateral and principal debt in one asset.
+    pub fn get_asset_position(
         env: Env,
         user: Address,
         asset: Option<Address>,
-        amount: i128,
-    ) -> Result<i128, crate::withdraw::WithdrawError> {
-        crate::withdraw::withdraw_collateral(&env, user, asset, amount)
+    ) -> asset_positions::AssetPosition {
+        asset_positions::get_asset_position(&env, &user, asset)
+    }
+
+    /// Include or exclude a deposited asset from the user's collateral.
+    pub fn set_use_as_collateral(
+        env: Env,
+        user: Address,
+        asset: Option<Address>,
+        enabled: bool,
+    ) -> Result<(), asset_positions::AssetPositionError> {
+        asset_positions::set_use_as_collateral(&env, user, asset, enabled)
+    }
+
+    /// Whether a user's deposits of an asset count as collateral.
+    pub fn is_collateral_enabled(env: Env, user: Address, asset: Option<Address>) -> bool {
+        asset_positions::is_collateral_enabled(&env, &user, &asset)
+    }
+
+    /// Opt into an e-mode category, or out of e-mode with `None`.
+    pub fn set_user_emode(
+        env: Env,
+        user: Address,
+        category: Option<u32>,
+    ) -> Result<(), crate::emode::EModeError> {
+        crate::emode::set_user_emode(&env, user, category)
+    }
+
+    /// Get the e-mode category a user opted into.
+    pub fn get_user_emode(env: Env, user: Address) -> Option<u32> {
+        crate::emode::get_user_emode(&env, &user)
+    }
+
+    /// Get an e-mode category's ratios.
+    pub fn get_emode_category(env: Env, id: u32) -> Option<crate::emode::EModeCategory> {
+        crate::emode::get_category(&env, id)
+    }
+
+    /// Get the e-mode category an asset belongs to.
+    pub fn get_asset_emode_category(env: Env, asset: Option<Address>) -> Option<u32> {
+        crate::emode::get_asset_category(&env, asset)
+    }
+
+    /// Get the assets a user has an open position in.
+    pub fn get_user_assets(env: Env, user: Address) -> Vec<Option<Address>> {
+        asset_positions::get_user_assets(&env, &user)
+    }
+
+    /// Get every asset that has held a position.
+    pub fn get_supported_assets(env: Env) -> Vec<Option<Address>> {
+        asset_positions::get_supported_assets(&env)
+    }
+
+    /// Largest amount of `asset` `user` could withdraw right now (0 while paused).
+    pub fn get_max_withdrawable(
+        env: Env,
+        user: Address,
+        asset: Option<Address>,
+    ) -> Result<i128, crate::errors::ProtocolError> {
+        Ok(crate::withdraw::get_max_withdrawable(&env, user, asset)?)
     }
 
     /// Set risk parameters (admin only).
@@ -280,7 +489,7 @@ impl HelloContract {
         close_factor: Option<i128>,
         liquidation_incentive: Option<i128>,
     ) -> Result<(), RiskManagementError> {
-        require_admin(&env, &caller)?;
+        require_role(&env, &caller, crate::admin::Role::RiskAdmin)?;
         check_emergency_pause(&env)?;
         risk_params::set_risk_params(
             &env,
@@ -305,6 +514,23 @@ impl HelloContract {
         })
     }
 
+    /// Set the volatility-based collateral factor haircut for an asset (admin only).
+    pub fn set_volatility_haircut(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        haircut: risk_params::VolatilityHaircut,
+    ) -> Result<(), RiskManagementError> {
+        require_role(&env, &caller, crate::admin::Role::RiskAdmin)?;
+        risk_params::set_volatility_haircut(&env, asset, haircut)
+            .map_err(|_| RiskManagementError::InvalidParameter)
+    }
+
+    /// Get the collateral factor haircut currently applied to an asset (bps).
+    pub fn get_collateral_haircut(env: Env, asset: Address) -> i128 {
+        risk_params::get_current_haircut_bps(&env, &asset)
+    }
+
     pub fn set_guardians(
         env: Env,
         caller: Address,
@@ -370,195 +596,727 @@ impl HelloContract {
         user: Address,
         asset: Option<Address>,
         amount: i128,
-    ) -> Result<i128, crate::borrow::BorrowError> {
-        crate::borrow::borrow_asset(&env, user, asset, amount)
+    ) -> Result<i128, crate::errors::ProtocolError> {
+        Ok(crate::borrow::borrow_asset(&env, user, asset, amount)?)
     }
 
-    /// Repay borrowed assets.
-    pub fn repay_debt(
+    /// Borrow assets, returning the amount received and the origination fee with its insurance/treasury split.
+    pub fn borrow_asset_with_receipt(
         env: Env,
         user: Address,
         asset: Option<Address>,
         amount: i128,
-    ) -> Result<(i128, i128, i128), crate::repay::RepayError> {
-        crate::repay::repay_debt(&env, user, asset, amount)
+    ) -> Result<crate::borrow::BorrowReceipt, crate::errors::ProtocolError> {
+        Ok(crate::borrow::borrow_asset_with_receipt(
+            &env, user, asset, amount,
+        )?)
     }
 
-    /// Liquidate an undercollateralized position.
-    pub fn liquidate(
+    /// Borrow assets, attributing `user` to `referrer` if not already attributed.
+    pub fn borrow_asset_with_referrer(
         env: Env,
-        liquidator: Address,
-        borrower: Address,
-        debt_asset: Option<Address>,
-        collateral_asset: Option<Address>,
+        user: Address,
+        asset: Option<Address>,
         amount: i128,
-    ) -> Result<i128, crate::liquidate::LiquidationError> {
-        let (repaid, _seized, _fee) = liquidate(&env, caller, borrower, asset, None, amount)?;
-        Ok(repaid)
+        referrer: Option<Address>,
+    ) -> Result<i128, crate::errors::ProtocolError> {
+        referral::attribute(&env, &user, referrer);
+        Ok(crate::borrow::borrow_asset(&env, user, asset, amount)?)
     }
 
-    /// Get current risk configuration.
-    pub fn get_risk_config(env: Env) -> Option<RiskConfig> {
-        risk_management::get_risk_config(&env)
+    /// Get the protocol origination fee charged on borrows without an asset-specific fee (basis points).
+    pub fn get_origination_fee_bps(env: Env) -> i128 {
+        origination_fee::get_origination_fee_bps(&env)
     }
 
-    pub fn set_pause_switch(
-        env: Env,
-        admin: Address,
-        operation: Symbol,
-        paused: bool,
-    ) -> Result<(), RiskManagementError> {
-        risk_management::set_pause_switch(&env, admin, operation, paused)
+    /// Get the referrer a user is attributed to.
+    pub fn get_referrer(env: Env, user: Address) -> Option<Address> {
+        referral::get_referrer(&env, &user)
     }
 
-    pub fn is_operation_paused(env: Env, operation: Symbol) -> bool {
-        risk_management::is_operation_paused(&env, operation)
+    /// Get the users, deposit volume and borrow volume brought in by a referrer.
+    pub fn get_referral_stats(env: Env, referrer: Address) -> referral::ReferralStats {
+        referral::get_referral_stats(&env, &referrer)
     }
 
-    pub fn is_emergency_paused(env: Env) -> bool {
-        risk_management::is_emergency_paused(&env)
+    /// Get a referrer's unclaimed rewards in an asset.
+    pub fn get_referral_rewards(env: Env, referrer: Address, asset: Option<Address>) -> i128 {
+        referral::get_referral_rewards(&env, &referrer, asset)
     }
 
-    pub fn set_emergency_pause(
-        env: Env,
-        admin: Address,
-        paused: bool,
-    ) -> Result<(), RiskManagementError> {
-        risk_management::set_emergency_pause(&env, admin, paused)
+    /// Get the share of the treasury fee paid to referrers (basis points).
+    pub fn get_referral_share_bps(env: Env) -> i128 {
+        referral::get_referral_share_bps(&env)
     }
 
+    /// Claim a referrer's accrued rewards in an asset. Returns the amount paid.
+    pub fn claim_referral_rewards(
+        env: Env,
+        referrer: Address,
+        asset: Option<Address>,
+    ) -> Result<i128, referral::ReferralError> {
+        referral::claim_referral_rewards(&env, referrer, asset)
+    }
 
-    /// Get minimum collateral ratio.
-    /// Get a read-only configuration snapshot of the protocol
+    /// Deposit collateral and borrow against it in one transaction.
     ///
-    /// # Returns
-    /// Returns Some(ConfigSnapshot) if initialized, None otherwise.
-    /// No authorization required - safe for any caller.
-    pub fn get_config_snapshot(env: Env) -> Option<ConfigSnapshot> {
-        get_config_snapshot(&env)
+    /// The collateral ratio is checked once, after both steps; any failure
+    /// reverts the deposit as well. Returns the total debt after the borrow.
+    pub fn deposit_and_borrow(
+        env: Env,
+        user: Address,
+        collateral_asset: Option<Address>,
+        collateral_amount: i128,
+        debt_asset: Option<Address>,
+        borrow_amount: i128,
+    ) -> Result<i128, crate::errors::ProtocolError> {
+        crate::borrow::deposit_and_borrow(
+            &env,
+            user,
+            collateral_asset,
+            collateral_amount,
+            debt_asset,
+            borrow_amount,
+        )
     }
 
-    /// Get minimum collateral ratio
-    ///
-    /// # Returns
-    /// Returns the minimum collateral ratio in basis points
-    pub fn get_min_collateral_ratio(env: Env) -> Result<i128, RiskManagementError> {
-        risk_params::get_min_collateral_ratio(&env)
-            .map_err(|_| RiskManagementError::InvalidParameter)
+    /// Let `delegatee` borrow up to `amount` of `asset` against the delegator's collateral (0 revokes).
+    pub fn approve_credit(
+        env: Env,
+        delegator: Address,
+        delegatee: Address,
+        asset: Option<Address>,
+        amount: i128,
+    ) -> Result<(), crate::credit_delegation::CreditDelegationError> {
+        crate::credit_delegation::approve_credit(&env, delegator, delegatee, asset, amount)
     }
 
-    /// Get liquidation threshold.
-    pub fn get_liquidation_threshold(env: Env) -> Result<i128, RiskManagementError> {
-        risk_params::get_liquidation_threshold(&env)
-            .map_err(|_| RiskManagementError::InvalidParameter)
+    /// Get the remaining credit `delegatee` may borrow against `delegator`.
+    pub fn get_credit_allowance(
+        env: Env,
+        delegator: Address,
+        delegatee: Address,
+        asset: Option<Address>,
+    ) -> i128 {
+        crate::credit_delegation::get_credit_allowance(&env, delegator, delegatee, asset)
     }
 
-    /// Get close factor.
-    pub fn get_close_factor(env: Env) -> Result<i128, RiskManagementError> {
-        risk_params::get_close_factor(&env).map_err(|_| RiskManagementError::InvalidParameter)
+    /// Borrow against the delegator's position using an approved credit allowance.
+    ///
+    /// The debt is booked on `delegator`; the tokens go to `delegatee`.
+    pub fn borrow_on_behalf(
+        env: Env,
+        delegatee: Address,
+        delegator: Address,
+        asset: Option<Address>,
+        amount: i128,
+    ) -> Result<i128, crate::errors::ProtocolError> {
+        Ok(crate::credit_delegation::borrow_on_behalf(
+            &env, delegatee, delegator, asset, amount,
+        )?)
     }
 
-    /// Get liquidation incentive.
-    pub fn get_liquidation_incentive(env: Env) -> Result<i128, RiskManagementError> {
-        risk_params::get_liquidation_incentive(&env)
-            .map_err(|_| RiskManagementError::InvalidParameter)
+    /// Quote a borrow (fees, staker discount, rate, resulting debt) without executing it.
+    pub fn preview_borrow(
+        env: Env,
+        user: Address,
+        asset: Option<Address>,
+        amount: i128,
+    ) -> Result<crate::borrow::BorrowPreview, crate::errors::ProtocolError> {
+        Ok(crate::borrow::preview_borrow(&env, user, asset, amount)?)
     }
 
-    /// Get current utilization (in basis points).
-    pub fn get_utilization(env: Env) -> i128 {
-        interest_rate::calculate_utilization(&env).unwrap_or(0)
+    /// Project a user's health after a deposit, withdrawal, borrow or repayment
+    /// without executing it.
+    pub fn simulate_action(
+        env: Env,
+        user: Address,
+        action: simulation::SimulatedAction,
+        asset: Option<Address>,
+        amount: i128,
+    ) -> Result<simulation::SimulationResult, simulation::SimulationError> {
+        simulation::simulate_action(&env, user, action, asset, amount)
     }
 
-    /// Get current borrow rate (in basis points).
-    pub fn get_borrow_rate(env: Env) -> i128 {
-        interest_rate::calculate_borrow_rate(&env).unwrap_or(0)
+    /// Largest amount of `asset` `user` could borrow right now (0 while paused).
+    pub fn get_max_borrowable(
+        env: Env,
+        user: Address,
+        asset: Option<Address>,
+    ) -> Result<i128, crate::errors::ProtocolError> {
+        Ok(crate::borrow::get_max_borrowable(&env, user, asset)?)
     }
 
-    /// Get current supply rate (in basis points).
-    pub fn get_supply_rate(env: Env) -> i128 {
-        interest_rate::calculate_supply_rate(&env).unwrap_or(0)
+    /// Configure the governance staker fee discount (admin only).
+    pub fn set_fee_discount_config(
+        env: Env,
+        caller: Address,
+        min_stake: i128,
+        discount_bps: i128,
+    ) -> Result<(), crate::fee_discount::FeeDiscountError> {
+        crate::fee_discount::set_fee_discount_config(&env, caller, min_stake, discount_bps)
     }
 
-    /// Get protocol utilization in basis points.
-    pub fn get_utilization(env: Env) -> i128 {
-        analytics::get_protocol_utilization(&env).unwrap_or(0)
+    /// Get the fee discount (basis points) that applies to a user.
+    pub fn get_fee_discount_bps(env: Env, user: Address) -> i128 {
+        crate::fee_discount::get_fee_discount_bps(&env, &user)
     }
 
-    /// Configure flash-loan parameters (admin only).
-    pub fn configure_flash_loan(
+    /// Replace the loyalty tiers, lowest first (risk admin only).
+    pub fn set_loyalty_tiers(
         env: Env,
         caller: Address,
-        config: FlashLoanConfig,
-    ) -> Result<(), crate::flash_loan::FlashLoanError> {
-        flash_loan::configure_flash_loan(&env, caller, config)
+        tiers: Vec<loyalty::LoyaltyTier>,
+    ) -> Result<(), loyalty::LoyaltyError> {
+        loyalty::set_loyalty_tiers(&env, caller, tiers)
     }
 
-    /// Set flash-loan fee in basis points (admin only).
-    pub fn set_flash_loan_fee(
+    /// Get the configured loyalty tiers, lowest first.
+    pub fn get_loyalty_tiers(env: Env) -> Vec<loyalty::LoyaltyTier> {
+        loyalty::get_loyalty_tiers(&env)
+    }
+
+    /// Get a user's current loyalty tier (0 = none).
+    pub fn get_user_tier(env: Env, user: Address) -> u32 {
+        loyalty::get_user_tier(&env, &user)
+    }
+
+    /// Enable or disable the same-ledger deposit guard (admin only).
+    pub fn set_same_ledger_guard(
         env: Env,
         caller: Address,
-        fee_bps: i128,
-    ) -> Result<(), crate::flash_loan::FlashLoanError> {
-        flash_loan::set_flash_loan_fee(&env, caller, fee_bps)
+        enabled: bool,
+    ) -> Result<(), crate::flash_guard::FlashGuardError> {
+        crate::flash_guard::set_same_ledger_guard(&env, caller, enabled)
     }
 
-    /// Set emergency interest-rate adjustment in basis points (admin only).
-    pub fn set_emergency_rate_adjustment(
+    /// Check whether the same-ledger deposit guard is enabled.
+    pub fn is_same_ledger_guard_enabled(env: Env) -> bool {
+        crate::flash_guard::is_same_ledger_guard_enabled(&env)
+    }
+
+    /// Get the minimum collateral holding period for an asset in seconds.
+    pub fn get_min_holding_period(env: Env, asset: Option<Address>) -> u64 {
+        crate::flash_guard::get_min_holding_period(&env, asset)
+    }
+
+    /// Get a user's collateral that does not yet count toward borrowing power.
+    pub fn get_maturing_collateral(env: Env, user: Address) -> i128 {
+        crate::flash_guard::get_maturing_collateral(&env, &user)
+    }
+
+    /// Configure the volume circuit breaker for an asset (admin only).
+    pub fn set_circuit_breaker(
         env: Env,
         caller: Address,
-        adjustment_bps: i128,
-    ) -> Result<(), crate::interest_rate::InterestRateError> {
-        interest_rate::set_emergency_rate_adjustment(&env, caller, adjustment_bps)
+        asset: Option<Address>,
+        config: crate::circuit_breaker::CircuitBreakerConfig,
+    ) -> Result<(), crate::circuit_breaker::CircuitBreakerError> {
+        crate::circuit_breaker::set_config(&env, caller, asset, config)
     }
 
-    /// Update interest rate model configuration (admin only).
-    #[allow(clippy::too_many_arguments)]
-    pub fn update_interest_rate_config(
+    /// Clear a tripped volume circuit breaker (admin only).
+    pub fn reset_circuit_breaker(
         env: Env,
-        admin: Address,
-        base_rate: Option<i128>,
-        kink: Option<i128>,
-        multiplier: Option<i128>,
-        jump_multiplier: Option<i128>,
-        rate_floor: Option<i128>,
-        rate_ceiling: Option<i128>,
-        spread: Option<i128>,
-    ) -> Result<(), RiskManagementError> {
-        require_admin(&env, &admin)?;
-        interest_rate::update_interest_rate_config(
-            &env,
-            admin,
-            base_rate,
-            kink,
-            multiplier,
-            jump_multiplier,
-            rate_floor,
-            rate_ceiling,
-            spread,
-        )
-        .map_err(|_| RiskManagementError::InvalidParameter)
+        caller: Address,
+        asset: Option<Address>,
+    ) -> Result<(), crate::circuit_breaker::CircuitBreakerError> {
+        crate::circuit_breaker::reset(&env, caller, asset)
     }
 
-    /// Get current protocol utilization in basis points (0–10 000).
-    pub fn get_utilization(env: Env) -> i128 {
-        interest_rate::calculate_utilization(&env).unwrap_or(0)
+    /// Check whether the volume circuit breaker is tripped for an asset.
+    pub fn is_circuit_breaker_tripped(env: Env, asset: Option<Address>) -> bool {
+        crate::circuit_breaker::is_tripped(&env, asset)
     }
 
-    /// Set an emergency rate adjustment (admin only).
-    ///
-    /// The adjustment is added to the calculated borrow rate.
-    /// Bounded to ±10 000 bps (±100%).
-    pub fn set_emergency_rate_adjustment(
+    /// Set per-user borrow and withdrawal rate limits (admin only).
+    pub fn set_throttle_config(
         env: Env,
-        admin: Address,
-        adjustment_bps: i128,
-    ) -> Result<(), RiskManagementError> {
-        require_admin(&env, &admin)?;
-        interest_rate::set_emergency_rate_adjustment(&env, admin, adjustment_bps)
-            .map_err(|_| RiskManagementError::InvalidParameter)
+        caller: Address,
+        config: crate::throttle::ThrottleConfig,
+    ) -> Result<(), crate::throttle::ThrottleError> {
+        crate::throttle::set_config(&env, caller, config)
     }
 
-    /// Get the current interest rate configuration.
+    /// Get the number of operations a user made in the current rate limit window.
+    pub fn get_operation_count(
+        env: Env,
+        user: Address,
+        operation: crate::throttle::ThrottledOperation,
+    ) -> u32 {
+        crate::throttle::get_operation_count(&env, &user, operation)
+    }
+
+    /// Repay borrowed assets; `i128::MAX` repays the whole debt.
+    pub fn repay_debt(
+        env: Env,
+        user: Address,
+        asset: Option<Address>,
+        amount: i128,
+    ) -> Result<(i128, i128, i128), crate::errors::ProtocolError> {
+        Ok(crate::repay::repay_debt(&env, user, asset, amount)?)
+    }
+
+    /// Repay debt with the user's own collateral, valued at oracle prices.
+    ///
+    /// Returns (remaining_debt, collateral_used).
+    pub fn repay_with_collateral(
+        env: Env,
+        user: Address,
+        debt_asset: Option<Address>,
+        collateral_asset: Option<Address>,
+        amount: i128,
+    ) -> Result<(i128, i128), crate::errors::ProtocolError> {
+        Ok(crate::repay::repay_with_collateral(
+            &env,
+            user,
+            debt_asset,
+            collateral_asset,
+            amount,
+        )?)
+    }
+
+    /// Liquidate an undercollateralized position.
+    pub fn liquidate(
+        env: Env,
+        liquidator: Address,
+        borrower: Address,
+        debt_asset: Option<Address>,
+        collateral_asset: Option<Address>,
+        amount: i128,
+    ) -> Result<i128, crate::liquidate::LiquidationError> {
+        let (repaid, _seized, _fee) =
+            liquidate(&env, liquidator, borrower, debt_asset, collateral_asset, amount)?;
+        Ok(repaid)
+    }
+
+    /// Liquidate just enough debt to bring the borrower to `target_health_factor`
+    /// (basis points), capped by the close factor.
+    pub fn liquidate_to_target(
+        env: Env,
+        liquidator: Address,
+        borrower: Address,
+        debt_asset: Option<Address>,
+        collateral_asset: Option<Address>,
+        target_health_factor: i128,
+    ) -> Result<crate::liquidate::TargetLiquidation, crate::liquidate::LiquidationError> {
+        crate::liquidate::liquidate_to_target(
+            &env,
+            liquidator,
+            borrower,
+            debt_asset,
+            collateral_asset,
+            target_health_factor,
+        )
+    }
+
+    /// Check whether only whitelisted liquidators may liquidate.
+    pub fn is_liquidator_whitelist_enabled(env: Env) -> bool {
+        crate::liquidate::is_liquidator_whitelist_enabled(&env)
+    }
+
+    /// Check whether an address is on the liquidator whitelist.
+    pub fn is_whitelisted_liquidator(env: Env, liquidator: Address) -> bool {
+        crate::liquidate::is_whitelisted_liquidator(&env, &liquidator)
+    }
+
+    /// Get the contract version, bumped by every governance upgrade.
+    pub fn version(env: Env) -> u32 {
+        crate::upgrade::version(&env)
+    }
+
+    /// Get the layout version of stored data.
+    pub fn schema_version(env: Env) -> u32 {
+        crate::migrations::schema_version(&env)
+    }
+
+    /// Migrate up to 50 users' data to the next schema version (upgrader only).
+    pub fn migrate(
+        env: Env,
+        caller: Address,
+        users: Vec<Address>,
+    ) -> Result<u32, crate::migrations::MigrationsError> {
+        crate::migrations::migrate(&env, caller, users)
+    }
+
+    /// Record the next schema version once every user is migrated (upgrader only).
+    pub fn complete_migration(
+        env: Env,
+        caller: Address,
+    ) -> Result<u32, crate::migrations::MigrationsError> {
+        crate::migrations::complete_migration(&env, caller)
+    }
+
+    /// Preview a liquidation, including the insurance fund's share of the incentive.
+    pub fn preview_liquidation(
+        env: Env,
+        borrower: Address,
+        debt_asset: Option<Address>,
+        collateral_asset: Option<Address>,
+        amount: i128,
+    ) -> Result<crate::liquidate::LiquidationPreview, crate::liquidate::LiquidationError> {
+        crate::liquidate::preview_liquidation(&env, borrower, debt_asset, collateral_asset, amount)
+    }
+
+    /// Get the share of liquidation incentives routed to the insurance fund (bps).
+    pub fn get_liquidation_insurance_share(env: Env) -> i128 {
+        reserve::get_liquidation_insurance_share(&env)
+    }
+
+    /// Get the insurance fund balance for an asset.
+    pub fn get_insurance_fund_balance(env: Env, asset: Option<Address>) -> i128 {
+        reserve::get_insurance_fund_balance(&env, asset)
+    }
+
+    /// Get the share of protocol fees routed to the insurance fund (bps).
+    pub fn get_insurance_fee_share(env: Env) -> i128 {
+        crate::insurance::get_insurance_fee_share(&env)
+    }
+
+    /// Write off the debt of a position with no collateral left, drawing on
+    /// the insurance fund of each debt asset first and socializing the rest.
+    pub fn resolve_bad_debt(
+        env: Env,
+        user: Address,
+    ) -> Result<crate::insurance::BadDebtResolution, crate::insurance::InsuranceError> {
+        crate::insurance::resolve_bad_debt(&env, user)
+    }
+
+    /// Get the cumulative bad debt socialized for an asset.
+    pub fn get_bad_debt_shortfall(env: Env, asset: Option<Address>) -> i128 {
+        crate::insurance::get_shortfall(&env, asset)
+    }
+
+    /// Repay a borrower's debt from reserves, recorded as a receivable (admin only).
+    pub fn emergency_repay(
+        env: Env,
+        caller: Address,
+        borrower: Address,
+        asset: Option<Address>,
+        amount: i128,
+    ) -> Result<i128, reserve::ReserveError> {
+        reserve::emergency_repay(&env, caller, borrower, asset, amount)
+    }
+
+    /// Pay back an emergency-repay receivable into reserves.
+    pub fn settle_receivable(
+        env: Env,
+        borrower: Address,
+        asset: Option<Address>,
+        amount: i128,
+    ) -> Result<i128, reserve::ReserveError> {
+        reserve::settle_receivable(&env, borrower, asset, amount)
+    }
+
+    /// Donate tokens to protocol reserves or the insurance fund.
+    pub fn donate(
+        env: Env,
+        donor: Address,
+        asset: Option<Address>,
+        amount: i128,
+        target: reserve::DonationTarget,
+    ) -> Result<i128, reserve::ReserveError> {
+        reserve::donate(&env, donor, asset, amount, target)
+    }
+
+    /// Get the emergency-repay receivable owed by a borrower.
+    pub fn get_receivable(env: Env, borrower: Address, asset: Option<Address>) -> i128 {
+        reserve::get_receivable(&env, &borrower, asset)
+    }
+
+    /// Compare an asset's actual token balance with recorded liabilities.
+    pub fn reconcile(
+        env: Env,
+        asset: Option<Address>,
+    ) -> Result<crate::accounting::Reconciliation, crate::accounting::AccountingError> {
+        crate::accounting::reconcile(&env, asset)
+    }
+
+    /// Move token balance exceeding recorded liabilities into reserves (treasurer or admin).
+    pub fn sweep_surplus(
+        env: Env,
+        caller: Address,
+        asset: Option<Address>,
+    ) -> Result<i128, crate::accounting::AccountingError> {
+        crate::accounting::sweep_surplus(&env, caller, asset)
+    }
+
+    /// Get current risk configuration.
+    pub fn get_risk_config(env: Env) -> Option<RiskConfig> {
+        risk_management::get_risk_config(&env)
+    }
+
+    pub fn set_pause_switch(
+        env: Env,
+        admin: Address,
+        operation: risk_management::PauseOperation,
+        paused: bool,
+    ) -> Result<(), RiskManagementError> {
+        risk_management::set_pause_switch(&env, admin, operation, paused)
+    }
+
+    pub fn is_operation_paused(env: Env, operation: risk_management::PauseOperation) -> bool {
+        risk_management::is_operation_paused(&env, operation)
+    }
+
+    pub fn is_emergency_paused(env: Env) -> bool {
+        risk_management::is_emergency_paused(&env)
+    }
+
+    pub fn set_emergency_pause(
+        env: Env,
+        admin: Address,
+        paused: bool,
+    ) -> Result<(), RiskManagementError> {
+        risk_management::set_emergency_pause(&env, admin, paused)
+    }
+
+
+    /// Get minimum collateral ratio.
+    /// Get a read-only configuration snapshot of the protocol
+    ///
+    /// # Returns
+    /// Returns Some(ConfigSnapshot) if initialized, None otherwise.
+    /// No authorization required - safe for any caller.
+    pub fn get_config_snapshot(env: Env) -> Option<ConfigSnapshot> {
+        get_config_snapshot(&env)
+    }
+
+    /// Get minimum collateral ratio
+    ///
+    /// # Returns
+    /// Returns the minimum collateral ratio in basis points
+    pub fn get_min_collateral_ratio(env: Env) -> Result<i128, RiskManagementError> {
+        risk_params::get_min_collateral_ratio(&env)
+            .map_err(|_| RiskManagementError::InvalidParameter)
+    }
+
+    /// Get the maximum risk parameter change per admin update (basis points).
+    pub fn get_max_parameter_change_bps(env: Env) -> i128 {
+        risk_params::get_max_parameter_change_bps(&env)
+    }
+
+    /// Get the duration over which collateral factor and liquidation threshold changes ramp in (seconds).
+    pub fn get_param_ramp_duration(env: Env) -> u64 {
+        param_ramp::get_ramp_duration(&env)
+    }
+
+    /// Get the running ramp of a collateral factor or the liquidation threshold, if any.
+    pub fn get_param_ramp(
+        env: Env,
+        param: param_ramp::RampedParam,
+    ) -> Option<param_ramp::ParamRamp> {
+        param_ramp::get_ramp(&env, param)
+    }
+
+    /// Get the hysteresis buffer around the liquidation threshold (basis points).
+    pub fn get_liquidation_hysteresis_bps(env: Env) -> i128 {
+        risk_params::get_liquidation_hysteresis_bps(&env)
+    }
+
+    /// Get liquidation threshold.
+    pub fn get_liquidation_threshold(env: Env) -> Result<i128, RiskManagementError> {
+        risk_params::get_liquidation_threshold(&env)
+            .map_err(|_| RiskManagementError::InvalidParameter)
+    }
+
+    /// Get close factor.
+    pub fn get_close_factor(env: Env) -> Result<i128, RiskManagementError> {
+        risk_params::get_close_factor(&env).map_err(|_| RiskManagementError::InvalidParameter)
+    }
+
+    /// Get liquidation incentive.
+    pub fn get_liquidation_incentive(env: Env) -> Result<i128, RiskManagementError> {
+        risk_params::get_liquidation_incentive(&env)
+            .map_err(|_| RiskManagementError::InvalidParameter)
+    }
+
+    /// Get current utilization (in basis points).
+    pub fn get_utilization(env: Env) -> i128 {
+        interest_rate::calculate_utilization(&env).unwrap_or(0)
+    }
+
+    /// Get current borrow rate (in basis points).
+    pub fn get_borrow_rate(env: Env) -> i128 {
+        interest_rate::calculate_borrow_rate(&env).unwrap_or(0)
+    }
+
+    /// Get current supply rate (in basis points).
+    pub fn get_supply_rate(env: Env) -> i128 {
+        interest_rate::calculate_supply_rate(&env).unwrap_or(0)
+    }
+
+    /// Get current borrow APR for an asset (in basis points).
+    ///
+    /// This is the rate the asset's borrow index, and so every position
+    /// borrowing the asset, accrues at.
+    pub fn get_asset_borrow_rate(env: Env, asset: Option<Address>) -> i128 {
+        interest_rate::calculate_asset_borrow_rate(&env, asset).unwrap_or(0)
+    }
+
+    /// Get current supply APR for an asset (in basis points).
+    ///
+    /// This is the rate the asset's supply exchange rate grows at.
+    pub fn get_asset_supply_rate(env: Env, asset: Option<Address>) -> i128 {
+        interest_rate::calculate_asset_supply_rate(&env, asset).unwrap_or(0)
+    }
+
+    /// Get an asset's governance-set rate curve, if any.
+    pub fn get_asset_rate_model(
+        env: Env,
+        asset: Option<Address>,
+    ) -> Option<interest_rate::AssetRateModel> {
+        interest_rate::get_asset_rate_model(&env, asset)
+    }
+
+    /// Get the supply exchange rate (underlying per share, scaled by 1e18) for an asset.
+    pub fn get_exchange_rate(env: Env, asset: Option<Address>) -> i128 {
+        interest_rate::get_exchange_rate(&env, asset)
+            .unwrap_or(interest_rate::EXCHANGE_RATE_SCALE)
+    }
+
+    /// Get the borrow index (accumulated borrow growth, scaled by 1e18) for an asset.
+    pub fn get_borrow_index(env: Env, asset: Option<Address>) -> i128 {
+        interest_rate::get_borrow_index(&env, asset).unwrap_or(interest_rate::BORROW_INDEX_SCALE)
+    }
+
+    /// Bring an asset's supply exchange rate and borrow index up to date (permissionless).
+    pub fn accrue(
+        env: Env,
+        asset: Option<Address>,
+    ) -> Result<interest_rate::AssetAccrual, InterestRateError> {
+        interest_rate::accrue(&env, asset)
+    }
+
+    /// Enable or disable auto-compounding of supply interest into collateral.
+    pub fn set_auto_compound(
+        env: Env,
+        user: Address,
+        asset: Option<Address>,
+        enabled: bool,
+    ) -> Result<(), crate::supply_interest::SupplyInterestError> {
+        crate::supply_interest::set_auto_compound(&env, user, asset, enabled)
+    }
+
+    /// Check whether a user auto-compounds supply interest.
+    pub fn is_auto_compound(env: Env, user: Address) -> bool {
+        crate::supply_interest::is_auto_compound(&env, &user)
+    }
+
+    /// Enable or disable repaying debt from earned supply interest.
+    pub fn set_auto_repay(
+        env: Env,
+        user: Address,
+        asset: Option<Address>,
+        enabled: bool,
+    ) -> Result<(), crate::supply_interest::SupplyInterestError> {
+        crate::supply_interest::set_auto_repay(&env, user, asset, enabled)
+    }
+
+    /// Check whether a user repays debt from supply interest.
+    pub fn is_auto_repay(env: Env, user: Address) -> bool {
+        crate::supply_interest::is_auto_repay(&env, &user)
+    }
+
+    /// Get supply interest a user has earned in native XLM but not claimed.
+    pub fn get_claimable_interest(env: Env, user: Address) -> i128 {
+        crate::supply_interest::get_claimable_interest(&env, &user)
+    }
+
+    /// Get supply interest a user has earned in an asset but not claimed.
+    pub fn get_claimable_interest_for(env: Env, user: Address, asset: Option<Address>) -> i128 {
+        crate::supply_interest::get_claimable_interest_for(&env, &user, asset)
+    }
+
+    /// Claim earned supply interest.
+    pub fn claim_supply_interest(
+        env: Env,
+        user: Address,
+        asset: Option<Address>,
+    ) -> Result<i128, crate::supply_interest::SupplyInterestError> {
+        crate::supply_interest::claim_supply_interest(&env, user, asset)
+    }
+
+    /// Get protocol utilization in basis points.
+    pub fn get_utilization(env: Env) -> i128 {
+        analytics::get_protocol_utilization(&env).unwrap_or(0)
+    }
+
+    /// Configure flash-loan parameters (admin only).
+    pub fn configure_flash_loan(
+        env: Env,
+        caller: Address,
+        config: FlashLoanConfig,
+    ) -> Result<(), crate::flash_loan::FlashLoanError> {
+        flash_loan::configure_flash_loan(&env, caller, config)
+    }
+
+    /// Set flash-loan fee in basis points (admin only).
+    pub fn set_flash_loan_fee(
+        env: Env,
+        caller: Address,
+        fee_bps: i128,
+    ) -> Result<(), crate::flash_loan::FlashLoanError> {
+        flash_loan::set_flash_loan_fee(&env, caller, fee_bps)
+    }
+
+    /// Set emergency interest-rate adjustment in basis points (admin only).
+    pub fn set_emergency_rate_adjustment(
+        env: Env,
+        caller: Address,
+        adjustment_bps: i128,
+    ) -> Result<(), crate::interest_rate::InterestRateError> {
+        interest_rate::set_emergency_rate_adjustment(&env, caller, adjustment_bps)
+    }
+
+    /// Update interest rate model configuration (admin only).
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_interest_rate_config(
+        env: Env,
+        admin: Address,
+        base_rate: Option<i128>,
+        kink: Option<i128>,
+        multiplier: Option<i128>,
+        jump_multiplier: Option<i128>,
+        rate_floor: Option<i128>,
+        rate_ceiling: Option<i128>,
+        spread: Option<i128>,
+    ) -> Result<(), RiskManagementError> {
+        require_role(&env, &admin, crate::admin::Role::RiskAdmin)?;
+        interest_rate::update_interest_rate_config(
+            &env,
+            admin,
+            base_rate,
+            kink,
+            multiplier,
+            jump_multiplier,
+            rate_floor,
+            rate_ceiling,
+            spread,
+        )
+        .map_err(|_| RiskManagementError::InvalidParameter)
+    }
+
+    /// Get current protocol utilization in basis points (0–10 000).
+    pub fn get_utilization(env: Env) -> i128 {
+        interest_rate::calculate_utilization(&env).unwrap_or(0)
+    }
+
+    /// Set an emergency rate adjustment (admin only).
+    ///
+    /// The adjustment is added to the calculated borrow rate.
+    /// Bounded to ±10 000 bps (±100%).
+    pub fn set_emergency_rate_adjustment(
+        env: Env,
+        admin: Address,
+        adjustment_bps: i128,
+    ) -> Result<(), RiskManagementError> {
+        require_role(&env, &admin, crate::admin::Role::RiskAdmin)?;
+        interest_rate::set_emergency_rate_adjustment(&env, admin, adjustment_bps)
+            .map_err(|_| RiskManagementError::InvalidParameter)
+    }
+
+    /// Get the current interest rate configuration.
     pub fn get_interest_rate_config(env: Env) -> Option<InterestRateConfig> {
         interest_rate::get_interest_rate_config(&env)
     }
@@ -573,136 +1331,554 @@ impl HelloContract {
             .map_err(|_| RiskManagementError::InsufficientCollateralRatio)
     }
 
-    /// Enforce minimum collateral ratio.
-    pub fn require_min_collateral_ratio(
-        env: Env,
-        collateral_value: i128,
-        debt_value: i128,
-    ) -> Result<(), RiskManagementError> {
-        risk_params::require_min_collateral_ratio(&env, collateral_value, debt_value)
-            .map_err(|_| RiskManagementError::InsufficientCollateralRatio)
+    /// Enforce minimum collateral ratio.
+    pub fn require_min_collateral_ratio(
+        env: Env,
+        collateral_value: i128,
+        debt_value: i128,
+    ) -> Result<(), RiskManagementError> {
+        risk_params::require_min_collateral_ratio(&env, collateral_value, debt_value)
+            .map_err(|_| RiskManagementError::InsufficientCollateralRatio)
+    }
+
+    /// Check if position can be liquidated.
+    pub fn can_be_liquidated(
+        env: Env,
+        collateral_value: i128,
+        debt_value: i128,
+    ) -> Result<bool, RiskManagementError> {
+        can_be_liquidated(&env, collateral_value, debt_value)
+            .map_err(|_| RiskManagementError::InvalidParameter)
+    }
+
+    /// Get maximum liquidatable amount.
+    pub fn get_max_liquidatable_amount(
+        env: Env,
+        debt_value: i128,
+    ) -> Result<i128, RiskManagementError> {
+        get_max_liquidatable_amount(&env, debt_value).map_err(|_| RiskManagementError::Overflow)
+    }
+
+    /// Calculate liquidation incentive amount.
+    pub fn get_liquidation_incentive_amount(
+        env: Env,
+        liquidated_amount: i128,
+    ) -> Result<i128, RiskManagementError> {
+        get_liquidation_incentive_amount(&env, liquidated_amount)
+            .map_err(|_| RiskManagementError::Overflow)
+    }
+
+    /// Refresh analytics for a user.
+    pub fn refresh_user_analytics(_env: Env, _user: Address) -> Result<(), RiskManagementError> {
+        Ok(())
+    }
+
+    /// Claim accumulated protocol reserves (admin only)
+    /// Claim accumulated protocol reserves (admin only).
+    pub fn claim_reserves(
+        env: Env,
+        caller: Address,
+        asset: Option<Address>,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), RiskManagementError> {
+        require_role(&env, &caller, crate::admin::Role::Treasurer)?;
+        reserve::release_reserves(&env, to, asset, amount)
+            .map_err(|_| RiskManagementError::InvalidParameter)?;
+        Ok(())
+    }
+
+    /// Get current protocol reserve balance for an asset.
+    pub fn get_reserve_balance(env: Env, asset: Option<Address>) -> i128 {
+        reserve::get_reserves(&env, asset)
+    }
+
+    /// Get withdrawable reserves for an asset: the reserve-factor share of interest plus fees.
+    pub fn get_reserves(env: Env, asset: Option<Address>) -> i128 {
+        reserve::get_reserves(&env, asset)
+    }
+
+    /// Withdraw protocol reserves to an address (admin only).
+    pub fn withdraw_reserves(
+        env: Env,
+        caller: Address,
+        to: Address,
+        asset: Option<Address>,
+        amount: i128,
+    ) -> Result<i128, reserve::ReserveError> {
+        reserve::withdraw_reserves(&env, caller, to, asset, amount)
+    }
+
+    /// Set the share of borrower interest kept as reserves for an asset (admin only).
+    pub fn set_reserve_factor(
+        env: Env,
+        caller: Address,
+        asset: Option<Address>,
+        reserve_factor_bps: i128,
+    ) -> Result<(), reserve::ReserveError> {
+        reserve::set_reserve_factor(&env, caller, asset, reserve_factor_bps)
+    }
+
+    /// Get the reserve factor for an asset in basis points.
+    pub fn get_reserve_factor(env: Env, asset: Option<Address>) -> i128 {
+        reserve::get_reserve_factor(&env, asset)
+    }
+
+    /// Get cumulative protocol revenue (interest, fees, liquidation penalties, flash-loan fees) for an asset.
+    pub fn get_revenue_report(env: Env, asset: Option<Address>) -> revenue::RevenueReport {
+        revenue::get_revenue_report(&env, asset)
+    }
+
+    /// Get protocol-owned liquidity seeded into the pool for an asset.
+    pub fn get_protocol_owned_liquidity(env: Env, asset: Option<Address>) -> i128 {
+        reserve::get_protocol_owned_liquidity(&env, asset)
+    }
+
+    /// Generate a comprehensive protocol report.
+    pub fn get_protocol_report(env: Env) -> Result<ProtocolReport, AnalyticsError> {
+        generate_protocol_report(&env)
+    }
+
+    /// Generate a comprehensive report for a specific user.
+    pub fn get_user_report(env: Env, user: Address) -> Result<UserReport, AnalyticsError> {
+        generate_user_report(&env, &user)
+    }
+
+    /// Retrieve recent protocol activity entries.
+    pub fn get_recent_activity(
+        env: Env,
+        limit: u32,
+        offset: u32,
+    ) -> Result<soroban_sdk::Vec<analytics::ActivityEntry>, AnalyticsError> {
+        get_recent_activity(&env, limit, offset)
+    }
+
+    /// Retrieve activity entries for a specific user.
+    pub fn get_user_activity(
+        env: Env,
+        user: Address,
+        limit: u32,
+        offset: u32,
+    ) -> Result<soroban_sdk::Vec<analytics::ActivityEntry>, AnalyticsError> {
+        get_user_activity_feed(&env, &user, limit, offset)
+    }
+
+    /// Get one page of a user's activity history, oldest entry first.
+    pub fn get_activity(
+        env: Env,
+        user: Address,
+        page: u32,
+    ) -> soroban_sdk::Vec<crate::deposit::Activity> {
+        activity::get_activity(&env, &user, page)
+    }
+
+    /// Get the range of a user's stored activity pages.
+    pub fn get_activity_index(env: Env, user: Address) -> activity::ActivityIndex {
+        activity::get_activity_index(&env, &user)
+    }
+
+    /// Get user analytics metrics.
+    pub fn get_user_analytics(
+        env: Env,
+        user: Address,
+    ) -> Result<crate::analytics::UserMetrics, crate::analytics::AnalyticsError> {
+        analytics::get_user_activity_summary(&env, &user)
+    }
+
+    /// Get protocol analytics metrics.
+    pub fn get_protocol_analytics(
+        env: Env,
+    ) -> Result<crate::analytics::ProtocolMetrics, crate::analytics::AnalyticsError> {
+        analytics::get_protocol_stats(&env)
+    }
+
+    /// Get raw protocol-wide deposit, borrow and TVL totals.
+    pub fn get_protocol_totals(env: Env) -> crate::deposit::ProtocolAnalytics {
+        analytics::get_protocol_totals(&env)
+    }
+
+    /// Get supply, borrows and utilization of one asset.
+    pub fn get_asset_utilization(env: Env, asset: Option<Address>) -> analytics::AssetUtilization {
+        analytics::get_asset_utilization(&env, asset)
+    }
+
+    /// Get supply, borrows, utilization, borrow and supply APR and reserves of one asset.
+    pub fn get_market_data(env: Env, asset: Option<Address>) -> analytics::MarketData {
+        analytics::get_market_data(&env, asset)
+    }
+
+    /// Get supply, borrows and utilization of every asset that has held a position.
+    pub fn get_all_asset_utilization(env: Env) -> Vec<analytics::AssetUtilization> {
+        analytics::get_all_asset_utilization(&env)
+    }
+
+    /// Get the number of accounts with outstanding debt.
+    pub fn get_total_borrowers(env: Env) -> u32 {
+        analytics::get_total_borrowers(&env)
+    }
+
+    /// Get the number of borrowers at each risk level.
+    pub fn get_health_distribution(env: Env) -> analytics::HealthDistribution {
+        analytics::get_health_distribution(&env)
+    }
+
+    /// Get health factor, collateral value and debt value for up to 50 accounts.
+    pub fn get_account_health_batch(
+        env: Env,
+        users: Vec<Address>,
+    ) -> Result<Vec<analytics::AccountHealth>, AnalyticsError> {
+        analytics::get_account_health_batch(&env, users)
+    }
+
+    /// Get collateral value, debt value, health factor and liquidatability for one account.
+    pub fn get_account_health(
+        env: Env,
+        user: Address,
+    ) -> Result<analytics::AccountHealth, AnalyticsError> {
+        analytics::get_account_health(&env, &user)
+    }
+
+    /// Accrue an account's interest and emit `health_factor_changed` (permissionless keeper hook).
+    pub fn poke(env: Env, user: Address) -> Result<analytics::AccountHealth, AnalyticsError> {
+        analytics::poke(&env, user)
+    }
+
+    /// Extend the storage TTL of an account's position entries (permissionless).
+    pub fn bump_position(env: Env, user: Address) {
+        crate::ttl::bump_position(&env, &user)
+    }
+
+    /// Extend the storage TTL of the instance and protocol-wide state (permissionless).
+    pub fn bump_protocol_state(env: Env) {
+        crate::ttl::bump_protocol_state(&env)
+    }
+
+    /// Get collateral, debt, health factor and borrowing and withdrawal headroom for an account.
+    pub fn get_position_summary(
+        env: Env,
+        user: Address,
+    ) -> Result<analytics::PositionSummary, AnalyticsError> {
+        analytics::get_position_summary(&env, &user)
+    }
+
+    /// Get the telemetry verbosity level used by the flows.
+    pub fn get_analytics_verbosity(env: Env) -> crate::analytics::AnalyticsVerbosity {
+        analytics::get_verbosity(&env)
+    }
+
+    // ============================================================================
+    // Rewards Methods
+    // ============================================================================
+
+    /// Set the decaying emission schedule for an asset (admin only).
+    pub fn set_emission_schedule(
+        env: Env,
+        caller: Address,
+        asset: Option<Address>,
+        schedule: rewards::EmissionSchedule,
+    ) -> Result<(), rewards::RewardsError> {
+        rewards::set_emission_schedule(&env, caller, asset, schedule)
+    }
+
+    /// Get the emission schedule for an asset.
+    pub fn get_emission_schedule(
+        env: Env,
+        asset: Option<Address>,
+    ) -> Option<rewards::EmissionSchedule> {
+        rewards::get_emission_schedule(&env, asset)
+    }
+
+    /// Get the currently active emission epoch for an asset.
+    pub fn get_current_epoch(
+        env: Env,
+        asset: Option<Address>,
+    ) -> Result<Option<u32>, rewards::RewardsError> {
+        rewards::get_current_epoch(&env, asset)
+    }
+
+    /// Get the reward budget of an emission epoch.
+    pub fn get_epoch_budget(
+        env: Env,
+        asset: Option<Address>,
+        epoch: u32,
+    ) -> Result<i128, rewards::RewardsError> {
+        rewards::get_epoch_budget(&env, asset, epoch)
+    }
+
+    /// Get the current per-second emission rate for an asset.
+    pub fn get_emission_rate(
+        env: Env,
+        asset: Option<Address>,
+    ) -> Result<i128, rewards::RewardsError> {
+        rewards::get_current_emission_rate(&env, asset)
+    }
+
+    /// Set the emission schedule of the gauge-weighted pool (admin only).
+    pub fn set_gauge_pool_schedule(
+        env: Env,
+        caller: Address,
+        schedule: rewards::EmissionSchedule,
+    ) -> Result<(), rewards::RewardsError> {
+        rewards::set_gauge_pool_schedule(&env, caller, schedule)
+    }
+
+    /// Register an asset as a reward gauge (admin only).
+    pub fn add_gauge(
+        env: Env,
+        caller: Address,
+        asset: Option<Address>,
+    ) -> Result<(), rewards::RewardsError> {
+        rewards::add_gauge(&env, caller, asset)
+    }
+
+    /// Allocate a share of voting power to a gauge for the current period.
+    pub fn vote_gauge_weight(
+        env: Env,
+        voter: Address,
+        asset: Option<Address>,
+        weight_bps: u32,
+    ) -> Result<i128, rewards::RewardsError> {
+        rewards::vote_gauge_weight(&env, voter, asset, weight_bps)
+    }
+
+    /// Finalize gauge weights for a completed voting period.
+    pub fn finalize_gauge_weights(env: Env, period: u64) -> Result<(), rewards::RewardsError> {
+        rewards::finalize_gauge_weights(&env, period)
+    }
+
+    /// Get the latest finalized weight of a gauge in basis points.
+    pub fn get_gauge_weight(env: Env, asset: Option<Address>) -> i128 {
+        rewards::get_gauge_weight(&env, asset)
+    }
+
+    /// Get the liquidity mining schedule of a market.
+    pub fn get_reward_emission(
+        env: Env,
+        market: rewards::RewardMarket,
+    ) -> Option<rewards::EmissionSchedule> {
+        rewards::get_reward_emission(&env, market)
+    }
+
+    /// Get every liquidity mining market that has been funded.
+    pub fn get_reward_markets(env: Env) -> Vec<rewards::RewardMarket> {
+        rewards::get_reward_markets(&env)
+    }
+
+    /// Get the liquidity mining rewards a user could claim now.
+    pub fn get_pending_rewards(env: Env, user: Address) -> i128 {
+        rewards::get_pending_rewards(&env, &user)
+    }
+
+    /// Claim a user's liquidity mining rewards in the governance token. Returns the amount paid.
+    pub fn claim_rewards(env: Env, user: Address) -> Result<i128, rewards::RewardsError> {
+        rewards::claim_rewards(&env, user)
+    }
+
+    // ============================================================================
+    // Claims Methods
+    // ============================================================================
+
+    /// Claim a Merkle-proof entitlement from a campaign.
+    pub fn claim(
+        env: Env,
+        campaign_id: u64,
+        user: Address,
+        amount: i128,
+        proof: Vec<soroban_sdk::BytesN<32>>,
+    ) -> Result<i128, claims::ClaimsError> {
+        claims::claim(&env, campaign_id, user, amount, proof)
+    }
+
+    /// Get a claim campaign by id.
+    pub fn get_claim_campaign(env: Env, campaign_id: u64) -> Option<claims::ClaimCampaign> {
+        claims::get_campaign(&env, campaign_id)
+    }
+
+    /// Check whether an address has claimed from a campaign.
+    pub fn has_claimed(env: Env, campaign_id: u64, user: Address) -> bool {
+        claims::has_claimed(&env, campaign_id, &user)
+    }
+
+    // ============================================================================
+    // Risk Engine Methods
+    // ============================================================================
+
+    /// Run the risk engine for an asset, returning ids of adjustments made.
+    pub fn run_risk_engine(
+        env: Env,
+        asset: Option<Address>,
+    ) -> Result<Vec<u64>, risk_engine::RiskEngineError> {
+        risk_engine::run_risk_engine(&env, asset)
+    }
+
+    /// Get a recorded risk engine adjustment.
+    pub fn get_risk_adjustment(env: Env, id: u64) -> Option<risk_engine::RiskAdjustment> {
+        risk_engine::get_adjustment(&env, id)
+    }
+
+    // ============================================================================
+    // Credit History Methods
+    // ============================================================================
+
+    /// Get a user's credit record.
+    pub fn get_credit_record(env: Env, user: Address) -> credit_history::CreditRecord {
+        credit_history::get_record(&env, &user)
+    }
+
+    /// Get a user's history-based collateral factor adjustment in basis points.
+    pub fn get_credit_adjustment(env: Env, user: Address) -> i128 {
+        credit_history::get_adjustment_bps(&env, &user)
     }
 
-    /// Check if position can be liquidated.
-    pub fn can_be_liquidated(
+    // ============================================================================
+    // Interest Capitalization Methods
+    // ============================================================================
+
+    /// Set the interest capitalization interval in seconds (admin only, 0 disables).
+    pub fn set_capitalization_interval(
         env: Env,
-        collateral_value: i128,
-        debt_value: i128,
-    ) -> Result<bool, RiskManagementError> {
-        can_be_liquidated(&env, collateral_value, debt_value)
-            .map_err(|_| RiskManagementError::InvalidParameter)
+        caller: Address,
+        seconds: u64,
+    ) -> Result<(), capitalization::CapitalizationError> {
+        capitalization::set_interval(&env, caller, seconds)
     }
 
-    /// Get maximum liquidatable amount.
-    pub fn get_max_liquidatable_amount(
-        env: Env,
-        debt_value: i128,
-    ) -> Result<i128, RiskManagementError> {
-        get_max_liquidatable_amount(&env, debt_value).map_err(|_| RiskManagementError::Overflow)
+    /// Get the interest capitalization interval in seconds.
+    pub fn get_capitalization_interval(env: Env) -> u64 {
+        capitalization::get_interval(&env)
     }
 
-    /// Calculate liquidation incentive amount.
-    pub fn get_liquidation_incentive_amount(
-        env: Env,
-        liquidated_amount: i128,
-    ) -> Result<i128, RiskManagementError> {
-        get_liquidation_incentive_amount(&env, liquidated_amount)
-            .map_err(|_| RiskManagementError::Overflow)
+    // ============================================================================
+    // Keeper Methods
+    // ============================================================================
+
+    /// Register as a keeper by posting a bond.
+    pub fn register_keeper(env: Env, keeper: Address, bond: i128) -> Result<(), keeper::KeeperError> {
+        keeper::register_keeper(&env, keeper, bond)
     }
 
-    /// Refresh analytics for a user.
-    pub fn refresh_user_analytics(_env: Env, _user: Address) -> Result<(), RiskManagementError> {
-        Ok(())
+    /// Start unbonding as a keeper, returning when the bond can be withdrawn.
+    pub fn request_keeper_unbond(env: Env, keeper: Address) -> Result<u64, keeper::KeeperError> {
+        keeper::request_unbond(&env, keeper)
     }
 
-    /// Claim accumulated protocol reserves (admin only)
-    /// Claim accumulated protocol reserves (admin only).
-    pub fn claim_reserves(
-        env: Env,
-        caller: Address,
-        asset: Option<Address>,
-        _to: Address,
-        amount: i128,
-    ) -> Result<(), RiskManagementError> {
-        require_admin(&env, &caller)?;
+    /// Withdraw a keeper bond after unbonding.
+    pub fn withdraw_keeper_bond(env: Env, keeper: Address) -> Result<i128, keeper::KeeperError> {
+        keeper::withdraw_bond(&env, keeper)
+    }
 
-        let reserve_key = DepositDataKey::ProtocolReserve(asset.clone());
-        let mut reserve_balance = env
-            .storage()
-            .persistent()
-            .get::<DepositDataKey, i128>(&reserve_key)
-            .unwrap_or(0);
+    /// Get a registered keeper.
+    pub fn get_keeper(env: Env, keeper: Address) -> Option<keeper::KeeperInfo> {
+        keeper::get_keeper(&env, &keeper)
+    }
 
-        if amount > reserve_balance {
-            return Err(RiskManagementError::InvalidParameter);
-        }
+    /// Check whether a keeper is active.
+    pub fn is_active_keeper(env: Env, keeper: Address) -> bool {
+        keeper::is_active_keeper(&env, &keeper)
+    }
 
-        if let Some(_asset_addr) = asset {
-            #[cfg(not(test))]
-            {
-                let token_client = soroban_sdk::token::Client::new(&env, &_asset_addr);
-                token_client.transfer(&env.current_contract_address(), &to, &amount);
-            }
-        }
+    // ============================================================================
+    // Strategy Methods
+    // ============================================================================
 
-        reserve_balance -= amount;
-        env.storage()
-            .persistent()
-            .set(&reserve_key, &reserve_balance);
-        Ok(())
+    /// Harvest strategy yield for an asset into protocol reserves.
+    pub fn harvest_strategy(env: Env, asset: Address) -> Result<i128, strategy::StrategyError> {
+        strategy::harvest(&env, asset)
     }
 
-    /// Get current protocol reserve balance for an asset.
-    pub fn get_reserve_balance(env: Env, asset: Option<Address>) -> i128 {
-        let reserve_key = DepositDataKey::ProtocolReserve(asset);
-        env.storage()
-            .persistent()
-            .get::<DepositDataKey, i128>(&reserve_key)
-            .unwrap_or(0)
+    /// Get the strategy configuration for an asset.
+    pub fn get_strategy(env: Env, asset: Address) -> Option<strategy::StrategyConfig> {
+        strategy::get_strategy(&env, &asset)
     }
 
-    /// Generate a comprehensive protocol report.
-    pub fn get_protocol_report(env: Env) -> Result<ProtocolReport, AnalyticsError> {
-        generate_protocol_report(&env)
+    /// Get the principal allocated to an asset's strategy.
+    pub fn get_strategy_allocation(env: Env, asset: Address) -> i128 {
+        strategy::get_allocated(&env, &asset)
     }
 
-    /// Generate a comprehensive report for a specific user.
-    pub fn get_user_report(env: Env, user: Address) -> Result<UserReport, AnalyticsError> {
-        generate_user_report(&env, &user)
+    /// Get liquidity available to users for an asset, including strategy principal.
+    pub fn get_available_liquidity(env: Env, asset: Address) -> i128 {
+        strategy::get_available_liquidity(&env, &asset)
     }
 
-    /// Retrieve recent protocol activity entries.
-    pub fn get_recent_activity(
+    // ============================================================================
+    // Savings Methods
+    // ============================================================================
+
+    /// Move an asset into the savings bucket to earn the savings rate.
+    pub fn join_savings(
         env: Env,
-        limit: u32,
-        offset: u32,
-    ) -> Result<soroban_sdk::Vec<analytics::ActivityEntry>, AnalyticsError> {
-        get_recent_activity(&env, limit, offset)
+        user: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<i128, savings::SavingsError> {
+        savings::join_savings(&env, user, asset, amount)
     }
 
-    /// Retrieve activity entries for a specific user.
-    pub fn get_user_activity(
+    /// Withdraw an asset from the savings bucket.
+    pub fn exit_savings(
         env: Env,
         user: Address,
-        limit: u32,
-        offset: u32,
-    ) -> Result<soroban_sdk::Vec<analytics::ActivityEntry>, AnalyticsError> {
-        get_user_activity_feed(&env, &user, limit, offset)
+        asset: Address,
+        amount: i128,
+    ) -> Result<i128, savings::SavingsError> {
+        savings::exit_savings(&env, user, asset, amount)
     }
 
-    /// Get user analytics metrics.
-    pub fn get_user_analytics(
+    /// Get a user's savings balance including accrued interest.
+    pub fn get_savings_balance(env: Env, user: Address, asset: Address) -> i128 {
+        savings::get_savings_balance(&env, &user, &asset)
+    }
+
+    /// Get an asset's savings rate in basis points per year.
+    pub fn get_savings_rate(env: Env, asset: Address) -> i128 {
+        savings::get_savings_rate(&env, &asset)
+    }
+
+    /// Get an asset's savings rate index and totals.
+    pub fn get_savings_state(env: Env, asset: Address) -> savings::SavingsState {
+        savings::get_savings_state(&env, &asset)
+    }
+
+    // ============================================================================
+    // Migration Methods
+    // ============================================================================
+
+    /// Allow or disallow migrations from a lending contract (admin only).
+    pub fn set_migration_source(
         env: Env,
-        user: Address,
-    ) -> Result<crate::analytics::UserMetrics, crate::analytics::AnalyticsError> {
-        analytics::get_user_activity_summary(&env, &user)
+        caller: Address,
+        source: Address,
+        allowed: bool,
+    ) -> Result<(), migration::MigrationError> {
+        migration::set_migration_source(&env, caller, source, allowed)
     }
 
-    /// Get protocol analytics metrics.
-    pub fn get_protocol_analytics(
+    /// Check whether migrations from a lending contract are allowed.
+    pub fn is_migration_source(env: Env, source: Address) -> bool {
+        migration::is_migration_source(&env, &source)
+    }
+
+    /// Migrate a position from a whitelisted lending contract in one transaction.
+    #[allow(clippy::too_many_arguments)]
+    pub fn migrate_position(
         env: Env,
-    ) -> Result<crate::analytics::ProtocolMetrics, crate::analytics::AnalyticsError> {
-        analytics::get_protocol_stats(&env)
+        user: Address,
+        source: Address,
+        debt_asset: Address,
+        debt_amount: i128,
+        collateral_asset: Address,
+        collateral_amount: i128,
+    ) -> Result<i128, migration::MigrationError> {
+        migration::migrate_position(
+            &env,
+            user,
+            source,
+            debt_asset,
+            debt_amount,
+            collateral_asset,
+            collateral_amount,
+        )
     }
 
     // ============================================================================
@@ -717,26 +1893,85 @@ impl HelloContract {
         price: i128,
         decimals: u32,
         oracle: Address,
-    ) -> i128 {
-        oracle::update_price_feed(&env, caller, asset, price, decimals, oracle)
-            .expect("Oracle error")
+    ) -> Result<i128, errors::ProtocolError> {
+        Ok(oracle::update_price_feed(&env, caller, asset, price, decimals, oracle)?)
     }
 
     /// Get current price for an asset.
-    pub fn get_price(env: Env, asset: Address) -> i128 {
-        oracle::get_price(&env, &asset).expect("Oracle error")
+    pub fn get_price(env: Env, asset: Address) -> Result<i128, errors::ProtocolError> {
+        Ok(oracle::get_price(&env, &asset)?)
+    }
+
+    /// Get the current price for an asset, failing if it is stale or missing.
+    pub fn get_asset_price(env: Env, asset: Address) -> Result<i128, oracle::OracleError> {
+        oracle::get_price(&env, &asset)
+    }
+
+    /// Value of an amount of an asset in oracle price units.
+    pub fn value_of(env: Env, asset: Address, amount: i128) -> Result<i128, oracle::OracleError> {
+        oracle::value_of(&env, &asset, amount)
+    }
+
+    /// Convert an amount of one asset into the equivalent amount of another.
+    pub fn convert(
+        env: Env,
+        asset_in: Address,
+        asset_out: Address,
+        amount: i128,
+    ) -> Result<i128, oracle::OracleError> {
+        oracle::convert(&env, &asset_in, &asset_out, amount)
+    }
+
+    /// Whitelist or remove an ed25519 price reporter key (admin only).
+    pub fn set_signed_reporter(
+        env: Env,
+        caller: Address,
+        reporter: soroban_sdk::BytesN<32>,
+        allowed: bool,
+    ) -> Result<(), oracle::OracleError> {
+        oracle::set_signed_reporter(&env, caller, reporter, allowed)
+    }
+
+    /// Submit a price signed off-chain by a whitelisted reporter.
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_signed_price(
+        env: Env,
+        reporter: soroban_sdk::BytesN<32>,
+        asset: Address,
+        price: i128,
+        decimals: u32,
+        timestamp: u64,
+        nonce: u64,
+        signature: soroban_sdk::BytesN<64>,
+    ) -> Result<i128, oracle::OracleError> {
+        oracle::submit_signed_price(
+            &env, reporter, asset, price, decimals, timestamp, nonce, signature,
+        )
+    }
+
+    /// Get recent price volatility for an asset in basis points.
+    pub fn get_price_volatility(env: Env, asset: Address) -> i128 {
+        oracle::get_price_volatility(&env, &asset)
     }
 
     /// Configure oracle parameters (admin only)
     /// Configure oracle parameters (admin only).
-    pub fn configure_oracle(env: Env, caller: Address, config: OracleConfig) {
-        oracle::configure_oracle(&env, caller, config).expect("Oracle error")
+    pub fn configure_oracle(
+        env: Env,
+        caller: Address,
+        config: OracleConfig,
+    ) -> Result<(), errors::ProtocolError> {
+        Ok(oracle::configure_oracle(&env, caller, config)?)
     }
 
     /// Set primary oracle for an asset (admin only).
-    pub fn set_primary_oracle(env: Env, caller: Address, asset: Address, primary_oracle: Address) {
-        oracle::set_primary_oracle(&env, caller, asset, primary_oracle)
-            .unwrap_or_else(|e| panic!("Oracle error: {:?}", e))
+    pub fn set_primary_oracle(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        primary_oracle: Address,
+    ) -> Result<(), errors::ProtocolError> {
+        Ok(oracle::set_primary_oracle(&env, caller, asset, primary_oracle)?)
     }
 
     /// Set fallback oracle for an asset (admin only).
@@ -745,8 +1980,52 @@ impl HelloContract {
         caller: Address,
         asset: Address,
         fallback_oracle: Address,
-    ) {
-        oracle::set_fallback_oracle(&env, caller, asset, fallback_oracle).expect("Oracle error")
+    ) -> Result<(), errors::ProtocolError> {
+        Ok(oracle::set_fallback_oracle(&env, caller, asset, fallback_oracle)?)
+    }
+
+    /// Set the oracle configuration for an asset (admin only).
+    pub fn set_asset_oracle_config(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        config: oracle::AssetOracleConfig,
+    ) -> Result<(), oracle::OracleError> {
+        oracle::set_asset_oracle_config(&env, caller, asset, config)
+    }
+
+    /// Get the oracle configuration for an asset.
+    pub fn get_asset_oracle_config(env: Env, asset: Address) -> Option<oracle::AssetOracleConfig> {
+        oracle::get_asset_oracle_config(&env, &asset)
+    }
+
+    /// Flag an asset as a stablecoin and configure its depeg breaker, or unflag it (admin only).
+    pub fn set_depeg_config(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        config: Option<depeg::DepegConfig>,
+    ) -> Result<(), depeg::DepegError> {
+        depeg::set_depeg_config(&env, caller, asset, config)
+    }
+
+    /// Get the depeg breaker configuration for an asset.
+    pub fn get_depeg_config(env: Env, asset: Address) -> Option<depeg::DepegConfig> {
+        depeg::get_depeg_config(&env, &asset)
+    }
+
+    /// Get the depeg deviation tracking for an asset.
+    pub fn get_depeg_state(env: Env, asset: Address) -> depeg::DepegState {
+        depeg::get_depeg_state(&env, &asset)
+    }
+
+    /// Unfreeze an asset frozen by the depeg breaker (admin only).
+    pub fn clear_depeg_freeze(
+        env: Env,
+        caller: Address,
+        asset: Address,
+    ) -> Result<(), depeg::DepegError> {
+        depeg::clear_depeg_freeze(&env, caller, asset)
     }
 
     // ============================================================================
@@ -762,14 +2041,14 @@ impl HelloContract {
     pub fn set_pause_switch(
         env: Env,
         admin: Address,
-        operation: Symbol,
+        operation: risk_management::PauseOperation,
         paused: bool,
     ) -> Result<(), RiskManagementError> {
         risk_management::set_pause_switch(&env, admin, operation, paused)
     }
 
     /// Check if an operation is paused.
-    pub fn is_operation_paused(env: Env, operation: Symbol) -> bool {
+    pub fn is_operation_paused(env: Env, operation: risk_management::PauseOperation) -> bool {
         risk_management::is_operation_paused(&env, operation)
     }
 
@@ -778,6 +2057,59 @@ impl HelloContract {
         risk_management::is_emergency_paused(&env)
     }
 
+    /// Pause or unpause deposits, withdrawals or borrows of a single asset (pause guardian or admin).
+    pub fn set_asset_pause(
+        env: Env,
+        caller: Address,
+        asset: Option<Address>,
+        operation: risk_management::PauseOperation,
+        paused: bool,
+    ) -> Result<(), RiskManagementError> {
+        risk_management::set_asset_pause(&env, caller, asset, operation, paused)
+    }
+
+    /// Check if an operation is paused for a single asset by its own switch.
+    pub fn is_asset_paused(
+        env: Env,
+        asset: Option<Address>,
+        operation: risk_management::PauseOperation,
+    ) -> bool {
+        risk_management::is_asset_paused(&env, asset, operation)
+    }
+
+    /// Freeze an asset for wind-down: no new deposits or borrows, exits still allowed (risk admin or admin).
+    pub fn set_asset_frozen(
+        env: Env,
+        caller: Address,
+        asset: Option<Address>,
+        frozen: bool,
+    ) -> Result<(), RiskManagementError> {
+        risk_management::set_asset_frozen(&env, caller, asset, frozen)
+    }
+
+    /// Check if an asset is frozen, manually or by the depeg breaker.
+    pub fn is_asset_frozen(env: Env, asset: Option<Address>) -> bool {
+        risk_management::is_asset_frozen(&env, asset)
+    }
+
+    /// Get every pause switch and the emergency pause in one call.
+    pub fn get_pause_state(env: Env) -> risk_management::PauseState {
+        risk_management::get_pause_state(&env)
+    }
+
+    /// Get when an operation's pause switch was last set and cleared, and its total paused time.
+    pub fn get_pause_downtime(
+        env: Env,
+        operation: risk_management::PauseOperation,
+    ) -> pause_downtime::PauseDowntime {
+        pause_downtime::get_operation_downtime(&env, operation)
+    }
+
+    /// Get when the emergency pause was last set and cleared, and its total paused time.
+    pub fn get_emergency_pause_downtime(env: Env) -> pause_downtime::PauseDowntime {
+        pause_downtime::get_emergency_downtime(&env)
+    }
+
     /// Set emergency pause (admin only).
     pub fn set_emergency_pause(
         env: Env,
@@ -986,6 +2318,48 @@ impl HelloContract {
         get_asset_list(&env)
     }
 
+    /// List a cross-asset market using a risk tier's parameters.
+    pub fn list_asset_in_tier(
+        env: Env,
+        asset: Option<Address>,
+        tier: crate::risk_tier::RiskTier,
+        reserve_factor: i128,
+        price: i128,
+    ) -> Result<(), crate::risk_tier::RiskTierError> {
+        crate::risk_tier::list_asset(&env, asset, tier, reserve_factor, price)
+    }
+
+    /// Move a listed asset to another risk tier (admin only).
+    pub fn set_asset_tier(
+        env: Env,
+        caller: Address,
+        asset: Option<Address>,
+        tier: crate::risk_tier::RiskTier,
+    ) -> Result<(), crate::risk_tier::RiskTierError> {
+        crate::risk_tier::set_asset_tier(&env, caller, asset, tier)
+    }
+
+    /// Get a risk tier's current parameters.
+    pub fn get_tier_params(
+        env: Env,
+        tier: crate::risk_tier::RiskTier,
+    ) -> crate::risk_tier::TierParams {
+        crate::risk_tier::get_tier_params(&env, tier)
+    }
+
+    /// Get the risk tier an asset belongs to, if any.
+    pub fn get_asset_tier(env: Env, asset: Option<Address>) -> Option<crate::risk_tier::RiskTier> {
+        crate::risk_tier::get_asset_tier(&env, asset)
+    }
+
+    /// Get the assets in a risk tier.
+    pub fn get_tier_assets(
+        env: Env,
+        tier: crate::risk_tier::RiskTier,
+    ) -> soroban_sdk::Vec<Option<Address>> {
+        crate::risk_tier::get_tier_assets(&env, tier)
+    }
+
     /// Deposit collateral for cross-asset lending.
     pub fn cross_asset_deposit(
         env: Env,
@@ -1043,6 +2417,11 @@ impl HelloContract {
         get_user_position_summary(&env, &user)
     }
 
+    /// Get the assets a user currently has collateral or debt in.
+    pub fn get_user_active_assets(env: Env, user: Address) -> soroban_sdk::Vec<AssetKey> {
+        get_user_active_assets(&env, &user)
+    }
+
     /// Get total supply for a specific asset.
     pub fn get_total_supply_for(env: Env, asset: Option<Address>) -> i128 {
         get_total_supply_for(&env, asset)
@@ -1100,6 +2479,58 @@ impl HelloContract {
         )
     }
 
+    /// Create an emergency fast-track proposal (short vote, no timelock, guardian co-sign).
+    pub fn gov_create_emergency_proposal(
+        env: Env,
+        proposer: Address,
+        proposal_type: ProposalType,
+        description: soroban_sdk::String,
+    ) -> Result<u64, errors::GovernanceError> {
+        governance::create_emergency_proposal(&env, proposer, proposal_type, description)
+    }
+
+    /// Co-sign an emergency proposal as a guardian.
+    pub fn gov_approve_emergency_proposal(
+        env: Env,
+        guardian: Address,
+        proposal_id: u64,
+    ) -> Result<(), errors::GovernanceError> {
+        governance::approve_emergency_proposal(&env, guardian, proposal_id)
+    }
+
+    /// Get the emergency fast-track configuration, if enabled.
+    pub fn gov_get_emergency_track_config(
+        env: Env,
+    ) -> Option<crate::types::EmergencyTrackConfig> {
+        governance::get_emergency_track_config(&env)
+    }
+
+    /// Stake vote tokens; they count on proposals created after this ledger.
+    pub fn gov_stake(
+        env: Env,
+        staker: Address,
+        amount: i128,
+    ) -> Result<i128, errors::GovernanceError> {
+        governance::stake_votes(&env, staker, amount)
+    }
+
+    /// Withdraw staked vote tokens.
+    pub fn gov_unstake(
+        env: Env,
+        staker: Address,
+        amount: i128,
+    ) -> Result<i128, errors::GovernanceError> {
+        governance::unstake_votes(&env, staker, amount)
+    }
+
+    /// Get a user's staked vote tokens, as of `timestamp` if given.
+    pub fn gov_get_staked_votes(env: Env, user: Address, timestamp: Option<u64>) -> i128 {
+        match timestamp {
+            Some(timestamp) => governance::get_staked_votes_at(&env, &user, timestamp),
+            None => governance::get_staked_votes(&env, &user),
+        }
+    }
+
     /// Cast a vote on a proposal.
     pub fn gov_vote(
         env: Env,
@@ -1119,6 +2550,19 @@ impl HelloContract {
         governance::queue_proposal(&env, caller, proposal_id)
     }
 
+    /// Get the per-voter vote weight cap, if set.
+    pub fn gov_get_vote_weight_cap(env: Env) -> Option<crate::types::VoteWeightCap> {
+        governance::get_vote_weight_cap(&env)
+    }
+
+    /// Settle a proposal as Succeeded or Defeated after voting ends (permissionless).
+    pub fn gov_finalize_proposal(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<ProposalOutcome, errors::GovernanceError> {
+        governance::finalize_proposal(&env, proposal_id)
+    }
+
     /// Execute a queued proposal.
     pub fn gov_execute_proposal(
         env: Env,
@@ -1128,7 +2572,7 @@ impl HelloContract {
         governance::execute_proposal(&env, executor, proposal_id)
     }
 
-    /// Cancel a proposal.
+    /// Cancel a proposal as its proposer, the admin or the veto guardian.
     pub fn gov_cancel_proposal(
         env: Env,
         caller: Address,
@@ -1137,6 +2581,20 @@ impl HelloContract {
         governance::cancel_proposal(&env, caller, proposal_id)
     }
 
+    /// Set or clear the veto guardian (admin only).
+    pub fn gov_set_veto_guardian(
+        env: Env,
+        caller: Address,
+        guardian: Option<Address>,
+    ) -> Result<(), errors::GovernanceError> {
+        governance::set_veto_guardian(&env, caller, guardian)
+    }
+
+    /// Get the veto guardian, if set.
+    pub fn gov_get_veto_guardian(env: Env) -> Option<Address> {
+        governance::get_veto_guardian(&env)
+    }
+
     /// Approve a proposal as multisig admin.
     pub fn gov_approve_proposal(
         env: Env,
@@ -1275,6 +2733,34 @@ impl HelloContract {
         governance::get_admin(&env)
     }
 
+    /// Set or clear the multisig approval threshold for an action category.
+    pub fn gov_set_multisig_action_threshold(
+        env: Env,
+        caller: Address,
+        category: crate::types::ProposalCategory,
+        threshold: Option<u32>,
+    ) -> Result<(), errors::GovernanceError> {
+        governance::set_multisig_action_threshold(&env, caller, category, threshold)
+    }
+
+    /// Get the multisig approvals required for an action category.
+    pub fn gov_get_multisig_action_threshold(
+        env: Env,
+        category: crate::types::ProposalCategory,
+    ) -> u32 {
+        governance::get_multisig_action_threshold(&env, category)
+    }
+
+    /// Get the multisig's rolling 24-hour spend limit for an asset, if set.
+    pub fn gov_get_multisig_spend_limit(env: Env, asset: Option<Address>) -> Option<i128> {
+        governance::get_multisig_spend_limit(&env, asset)
+    }
+
+    /// Get the amount of an asset the multisig moved in the last 24 hours.
+    pub fn gov_get_multisig_spent(env: Env, asset: Option<Address>) -> i128 {
+        governance::get_multisig_spent(&env, asset)
+    }
+
     /// Get multisig configuration.
     pub fn gov_get_multisig_config(env: Env) -> Option<MultisigConfig> {
         governance::get_multisig_config(&env)
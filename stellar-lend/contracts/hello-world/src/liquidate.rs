@@ -1,19 +1,51 @@
+//! # Liquidation Module
+//!
+//! Liquidates undercollateralized positions at a fixed incentive.
+//!
+//! A liquidator repays up to the close factor of a borrower's debt and
+//! immediately receives the equivalent collateral plus the liquidation
+//! incentive (minus any insurance share), priced by the oracle at execution
+//! time.
+//!
+//! The incentive is the global risk parameter, or the collateral asset's
+//! risk tier incentive when it belongs to one.
+//!
+//! Eligibility uses the governed hysteresis buffer around the liquidation
+//! threshold (see [`can_liquidate_position`]), so a position right at the
+//! boundary does not flip in and out of liquidatability on rounding.
+//!
+//! ## Target Health Factor
+//! [`liquidate_to_target`] repays only as much debt as brings the borrower
+//! back to a chosen health factor instead of a fixed amount, still capped by
+//! the close factor. Because the seized collateral carries the incentive,
+//! liquidating raises the health factor only while it is above
+//! `10_000 + incentive`; below that no repayment can reach the target.
+//!
+//! ## Liquidator Whitelist
+//! Governance can restrict liquidations to whitelisted addresses, e.g. while
+//! the protocol launches. The mode and the whitelist are only changed through
+//! executed proposals; with the mode off, anyone can liquidate and the list is
+//! kept but ignored.
+
 #![allow(unused)]
-use crate::events::{emit_liquidation, LiquidationEvent};
-use soroban_sdk::{contracterror, Address, Env, IntoVal, Map, Symbol, Val, Vec, I256, token};
+use crate::events::{
+    emit_liquidation, emit_liquidation_v1, LiquidationEvent, LiquidationEventV1, EVENT_SCHEMA_VERSION,
+};
+use soroban_sdk::{contracterror, contracttype, Address, Env, IntoVal, Map, Symbol, Val, Vec, I256, token};
 use soroban_sdk::token::Client as TokenClient;
 
 use crate::deposit::{
     add_activity_log, emit_analytics_updated_event, emit_position_updated_event,
-    emit_user_activity_tracked_event, update_protocol_analytics, AssetParams, DepositDataKey,
-    Position, ProtocolAnalytics, UserAnalytics,
+    emit_user_activity_tracked_event, AssetParams, DepositDataKey, Position, ProtocolAnalytics,
+    UserAnalytics,
 };
 use crate::oracle::get_price;
 use crate::risk_management::{
     is_emergency_paused, is_operation_paused, require_operation_not_paused, RiskManagementError,
 };
 use crate::risk_params::{
-    can_be_liquidated, get_liquidation_incentive_amount, get_max_liquidatable_amount, get_risk_params,
+    can_be_liquidated, can_liquidate_position, get_liquidation_incentive_amount,
+    get_max_liquidatable_amount, get_risk_params, update_liquidation_flag,
 };
 
 /// Errors that can occur during liquidation operations
@@ -42,7 +74,67 @@ pub enum LiquidationError {
     /// Invalid debt asset
     InvalidDebtAsset = 10,
     /// Price not available for asset
-    PriceNotAvailable = 10,
+    PriceNotAvailable = 11,
+    /// Whitelist mode is on and the caller is not a whitelisted liquidator
+    LiquidatorNotWhitelisted = 12,
+    /// No repayment can bring the position to the target health factor
+    TargetUnreachable = 13,
+    /// Pool and strategy together cannot cover the transfer
+    InsufficientLiquidity = 14,
+}
+
+/// Storage keys for liquidation data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum LiquidationDataKey {
+    /// Whether only whitelisted liquidators may liquidate: bool
+    WhitelistEnabled,
+    /// Whitelisted liquidator: Liquidator(address) -> bool
+    Liquidator(Address),
+}
+
+/// Quote for a liquidation, as returned by `preview_liquidation`
+///
+/// `collateral_seized` is taken from the borrower; of that,
+/// `insurance_collateral` (a share of the incentive portion) is credited to the
+/// insurance fund and the remainder goes to the liquidator.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LiquidationPreview {
+    /// Debt that will be repaid (after close factor and debt caps)
+    pub debt_to_repay: i128,
+    /// Total collateral taken from the borrower
+    pub collateral_seized: i128,
+    /// Collateral transferred to the liquidator
+    pub liquidator_collateral: i128,
+    /// Collateral credited to the insurance fund
+    pub insurance_collateral: i128,
+    /// Liquidation incentive (basis points)
+    pub incentive_bps: i128,
+    /// Share of the incentive routed to insurance (basis points)
+    pub insurance_share_bps: i128,
+    /// Debt asset price used for the quote
+    pub debt_price: i128,
+    /// Collateral asset price used for the quote
+    pub collateral_price: i128,
+}
+
+/// Result of [`liquidate_to_target`]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TargetLiquidation {
+    /// Debt repaid by the liquidator
+    pub debt_repaid: i128,
+    /// Total collateral taken from the borrower
+    pub collateral_seized: i128,
+    /// Liquidation incentive amount
+    pub incentive_amount: i128,
+    /// Borrower's health factor before the liquidation (basis points)
+    pub health_factor_before: i128,
+    /// Borrower's health factor after the liquidation (basis points,
+    /// `i128::MAX` with no debt left)
+    pub health_factor_after: i128,
 }
 
 /// Helper to get asset decimals from the token contract or default to 7 for XLM.
@@ -88,7 +180,10 @@ fn get_asset_price(env: &Env, asset: &Address) -> i128 {
 }
 
 /// Helper to calculate current debt including interest since last accrual.
-fn calculate_accrued_debt(env: &Env, position: &Position) -> Result<i128, LiquidationError> {
+///
+/// Read-only: the position's borrow index snapshots are not moved, so only
+/// quotes and health checks may use it.
+fn calculate_accrued_debt(env: &Env, borrower: &Address, position: &Position) -> Result<i128, LiquidationError> {
     let current_time = env.ledger().timestamp();
     let principal = position.debt;
     let stored_interest = position.borrow_interest;
@@ -100,15 +195,13 @@ fn calculate_accrued_debt(env: &Env, position: &Position) -> Result<i128, Liquid
         return Ok(principal.checked_add(stored_interest).ok_or(LiquidationError::Overflow)?);
     }
 
-    let rate_bps = crate::interest_rate::calculate_borrow_rate(env)
-        .map_err(|_| LiquidationError::Overflow)?;
-    
-    let delta_interest = crate::interest_rate::calculate_accrued_interest(
+    let delta_interest = crate::interest_rate::pending_position_interest(
+        env,
+        borrower,
         principal,
         position.last_accrual_time,
-        current_time,
-        rate_bps,
-    ).map_err(|_| LiquidationError::Overflow)?;
+    )
+    .map_err(|_| LiquidationError::Overflow)?;
 
     principal
         .checked_add(stored_interest)
@@ -125,8 +218,10 @@ fn calculate_accrued_debt(env: &Env, position: &Position) -> Result<i128, Liquid
 /// 1. Verifies position health (must be below liquidation threshold).
 /// 2. Enforces close factor (maximum repayment per transaction).
 /// 3. Calculates incentive-adjusted collateral to seize using I256 precision.
-/// 4. Updates borrower state and global analytics.
-/// 5. Transfers debt from liquidator and collateral to liquidator.
+/// 4. Withholds the governed insurance share of the incentive for the insurance fund,
+///    rebating part of it to active registered keepers.
+/// 5. Updates borrower state and global analytics.
+/// 6. Transfers debt from liquidator and the remaining collateral to liquidator.
 ///
 /// # Equations
 /// - `max_repayable = total_debt * close_factor`
@@ -134,14 +229,18 @@ fn calculate_accrued_debt(env: &Env, position: &Position) -> Result<i128, Liquid
 ///
 /// # Errors
 /// * `InvalidAmount`: Debt amount <= 0.
+/// * `Reentrancy`: Called while another protected entry point is executing.
 /// * `LiquidationPaused`: Protocol or specific operation is paused.
 /// * `NotLiquidatable`: Borrower position is healthy or non-existent.
+/// * `InvalidCollateralAsset`: Borrower holds no seizable collateral in `collateral_asset`.
 /// * `PriceNotAvailable`: Oracle prices missing or invalid.
 /// * `Overflow`: Mathematical overflow during precision scaling.
+/// * `InsufficientLiquidity`: Pool and strategy cannot cover the seized collateral.
 ///
 /// # Security
 /// * Uses Checks-Effects-Interactions (CEI) to prevent reentrancy during cross-contract token transfers.
-/// * Implements strict capping to ensure seized collateral never exceeds available borrower balance.
+/// * Holds the reentrancy guard for the whole call, so token callbacks cannot re-enter lending flows.
+/// * Implements strict capping to ensure seized collateral never exceeds what the borrower holds in `collateral_asset`.
 pub fn liquidate(
     env: &Env,
     liquidator: Address,
@@ -154,16 +253,23 @@ pub fn liquidate(
     if debt_amount <= 0 {
         return Err(LiquidationError::InvalidAmount);
     }
-    
+
+    // Check for reentrancy; held until the token transfers below complete
+    let _guard =
+        crate::reentrancy::ReentrancyGuard::new(env).map_err(|_| LiquidationError::Reentrancy)?;
+
     // Explicit authorization check for liquidator
     liquidator.require_auth();
+    if is_liquidator_whitelist_enabled(env) && !is_whitelisted_liquidator(env, &liquidator) {
+        return Err(LiquidationError::LiquidatorNotWhitelisted);
+    }
 
     // 2. Authorization and Pause Checks
     if is_emergency_paused(env) {
         return Err(LiquidationError::LiquidationPaused);
     }
 
-    require_operation_not_paused(env, Symbol::new(env, "pause_liquidate"))
+    require_operation_not_paused(env, crate::risk_management::PauseOperation::Liquidate)
         .map_err(|_| LiquidationError::LiquidationPaused)?;
 
     // 3. Load Borrower State
@@ -171,38 +277,300 @@ pub fn liquidate(
     let mut position = env.storage().persistent()
         .get::<DepositDataKey, Position>(&position_key)
         .ok_or(LiquidationError::NotLiquidatable)?;
-
-    // 4. Load Collateral State
     let collateral_key = DepositDataKey::CollateralBalance(borrower.clone());
     let borrower_collateral = env.storage().persistent()
         .get::<DepositDataKey, i128>(&collateral_key)
         .unwrap_or(0);
 
-    // 5. Fetch Prices and Decimals (Interactions - allowed here as they don't modify state)
+    // Accrue interest into the position, moving its borrow index snapshots,
+    // so the quote and settlement below charge it exactly once
+    crate::borrow::accrue_interest(env, &borrower, &mut position)
+        .map_err(|_| LiquidationError::Overflow)?;
+    env.storage().persistent().set(&position_key, &position);
+
+    // 4-7. Health check, close factor and seizure math
+    let quote = preview_liquidation(env, borrower.clone(), debt_asset.clone(), collateral_asset.clone(), debt_amount)?;
+    let actual_debt_liquidated = quote.debt_to_repay;
+    let collateral_seized = quote.collateral_seized;
+    let debt_price = quote.debt_price;
+    let collateral_price = quote.collateral_price;
+
+    // Active registered keepers get part of the insurance cut back
+    let keeper_rebate =
+        crate::keeper::insurance_rebate(env, &liquidator, quote.insurance_collateral);
+    let insurance_collateral = quote.insurance_collateral - keeper_rebate;
+    let liquidator_collateral = quote
+        .liquidator_collateral
+        .checked_add(keeper_rebate)
+        .ok_or(LiquidationError::Overflow)?;
+
+    let incentive_amount = get_liquidation_incentive_amount(env, actual_debt_liquidated)
+        .unwrap_or(0);
+
+    // 8. UPDATE STORAGE (EFFECTS)
+    
+    // Resolve Interest and Debt (mirroring repay_debt logic)
+    // Interest is paid first, then principal.
+    let interest_repaid = actual_debt_liquidated.min(position.borrow_interest);
+    position.borrow_interest -= interest_repaid;
+    position.debt = position
+        .debt
+        .checked_sub(actual_debt_liquidated - interest_repaid)
+        .unwrap_or(0);
+
+    position.collateral = borrower_collateral.checked_sub(collateral_seized).unwrap_or(0);
+
+    env.storage().persistent().set(&position_key, &position);
+    env.storage().persistent().set(&collateral_key, &position.collateral);
+    crate::asset_positions::record_repayment(env, &borrower, debt_asset.clone(), actual_debt_liquidated - interest_repaid);
+    crate::asset_positions::record_seizure(env, &borrower, collateral_asset.clone(), collateral_seized);
+    crate::credit_history::record_liquidation(env, &borrower);
+
+    // Value what is left by oracle, as the eligibility check does
+    let value_after = crate::asset_positions::position_value(
+        env,
+        &borrower,
+        position.collateral,
+        position.debt,
+        position.borrow_interest,
+    )
+    .map_err(|_| LiquidationError::PriceNotAvailable)?;
+    let debt_value_after = value_after
+        .debt
+        .checked_add(value_after.interest)
+        .ok_or(LiquidationError::Overflow)?;
+    update_liquidation_flag(env, &borrower, value_after.collateral, debt_value_after);
+
+    update_protocol_analytics_liquidation(env, actual_debt_liquidated, collateral_seized)
+        .map_err(|_| LiquidationError::Overflow)?;
+
+    // The insurance share of the incentive stays in the contract
+    crate::reserve::accrue_insurance_fund(env, collateral_asset.clone(), insurance_collateral)
+        .map_err(|_| LiquidationError::Overflow)?;
+
+    // Apply the debt asset's reserve factor to the repaid interest, as repay_debt does
+    let (interest_reserve, _) =
+        crate::reserve::accrue_reserve(env, debt_asset.clone(), interest_repaid)
+            .map_err(|_| LiquidationError::Overflow)?;
+
+    crate::revenue::record(env, debt_asset.clone(), crate::revenue::RevenueSource::Interest, interest_reserve);
+    crate::revenue::record(
+        env,
+        collateral_asset.clone(),
+        crate::revenue::RevenueSource::LiquidationPenalty,
+        insurance_collateral,
+    );
+
+    // 9. EXTERNAL INTERACTIONS (TRANSFERS)
+    // Transfers are performed LAST to follow CEI pattern
+    
+    let debt_addr = match &debt_asset {
+        Some(ref addr) => addr.clone(),
+        None => get_native_asset_address(env)?,
+    };
+    let debt_client = TokenClient::new(env, &debt_addr);
+    debt_client.transfer_from(&env.current_contract_address(), &liquidator, &env.current_contract_address(), &actual_debt_liquidated);
+
+    let col_addr = match &collateral_asset {
+        Some(ref addr) => addr.clone(),
+        None => get_native_asset_address(env)?,
+    };
+    crate::strategy::ensure_liquidity(env, &col_addr, liquidator_collateral)
+        .map_err(|_| LiquidationError::InsufficientLiquidity)?;
+    let col_client = TokenClient::new(env, &col_addr);
+    col_client.transfer(&env.current_contract_address(), &liquidator, &liquidator_collateral);
+
+    // 10. EMIT EVENTS
+    let total_debt_after = position.debt.saturating_add(position.borrow_interest);
+    let health_factor_after = health_factor(value_after.collateral, debt_value_after)?;
+    emit_liquidation_v1(
+        env,
+        LiquidationEventV1 {
+            schema_version: EVENT_SCHEMA_VERSION,
+            liquidator: liquidator.clone(),
+            borrower: borrower.clone(),
+            debt_asset: debt_asset.clone(),
+            collateral_asset: collateral_asset.clone(),
+            debt_liquidated: actual_debt_liquidated,
+            collateral_seized,
+            incentive_amount,
+            borrower_collateral_after: position.collateral,
+            borrower_principal_debt_after: position.debt,
+            borrower_interest_after: position.borrow_interest,
+            borrower_total_debt_after: total_debt_after,
+            borrower_health_factor_after: health_factor_after,
+            borrower_risk_level_after: crate::analytics::calculate_user_risk_level(
+                health_factor_after,
+            ),
+            timestamp: position.last_accrual_time,
+        },
+    );
+    emit_liquidation(env, LiquidationEvent {
+        liquidator: liquidator.clone(),
+        borrower: borrower.clone(),
+        debt_asset,
+        collateral_asset,
+        debt_liquidated: actual_debt_liquidated,
+        collateral_seized,
+        incentive_amount,
+        debt_price,
+        collateral_price,
+        timestamp: position.last_accrual_time,
+    });
+    
+    emit_position_updated_event(env, &borrower, &position, Symbol::new(env, "liquidate"), position.last_accrual_time);
+    crate::ttl::bump_position(env, &borrower);
+    crate::analytics::emit_health_factor_changed(env, &borrower, Symbol::new(env, "liquidate"));
+    add_activity_log(env, &borrower, Symbol::new(env, "liquidate"), actual_debt_liquidated, debt_asset.clone(), position.last_accrual_time).ok();
+
+    Ok((actual_debt_liquidated, collateral_seized, incentive_amount))
+}
+
+/// Turn liquidator whitelist mode on or off
+///
+/// Only reachable through an executed governance proposal.
+#[allow(deprecated)]
+pub fn set_liquidator_whitelist_enabled(env: &Env, enabled: bool) {
+    env.storage()
+        .persistent()
+        .set(&LiquidationDataKey::WhitelistEnabled, &enabled);
+
+    let topics = (Symbol::new(env, "liquidator_whitelist_updated"),);
+    env.events().publish(topics, enabled);
+}
+
+/// Whether only whitelisted liquidators may liquidate (off by default)
+pub fn is_liquidator_whitelist_enabled(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&LiquidationDataKey::WhitelistEnabled)
+        .unwrap_or(false)
+}
+
+/// Add an address to the liquidator whitelist
+///
+/// Only reachable through an executed governance proposal.
+#[allow(deprecated)]
+pub fn add_liquidator(env: &Env, liquidator: Address) {
+    env.storage()
+        .persistent()
+        .set(&LiquidationDataKey::Liquidator(liquidator.clone()), &true);
+
+    let topics = (Symbol::new(env, "liquidator_added"), liquidator);
+    env.events().publish(topics, ());
+}
+
+/// Remove an address from the liquidator whitelist
+///
+/// Only reachable through an executed governance proposal.
+#[allow(deprecated)]
+pub fn remove_liquidator(env: &Env, liquidator: Address) {
+    env.storage()
+        .persistent()
+        .remove(&LiquidationDataKey::Liquidator(liquidator.clone()));
+
+    let topics = (Symbol::new(env, "liquidator_removed"), liquidator);
+    env.events().publish(topics, ());
+}
+
+/// Whether `liquidator` is on the liquidator whitelist
+pub fn is_whitelisted_liquidator(env: &Env, liquidator: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&LiquidationDataKey::Liquidator(liquidator.clone()))
+        .unwrap_or(false)
+}
+
+/// Preview a liquidation without executing it
+///
+/// Runs the same health check, close factor and seizure math as [`liquidate`]
+/// and reports how the seized collateral is split between the liquidator and
+/// the insurance fund.
+///
+/// # Errors
+/// * `InvalidAmount`: Debt amount <= 0 or nothing liquidatable.
+/// * `NotLiquidatable`: Borrower position is healthy or non-existent.
+/// * `InvalidCollateralAsset`: Borrower holds no collateral in `collateral_asset`
+///   or has excluded it from collateral.
+/// * `PriceNotAvailable`: Oracle prices missing or invalid.
+/// * `Overflow`: Mathematical overflow during precision scaling.
+pub fn preview_liquidation(
+    env: &Env,
+    borrower: Address,
+    debt_asset: Option<Address>,
+    collateral_asset: Option<Address>,
+    debt_amount: i128,
+) -> Result<LiquidationPreview, LiquidationError> {
+    if debt_amount <= 0 {
+        return Err(LiquidationError::InvalidAmount);
+    }
+
+    // Load Borrower State
+    let position = env.storage().persistent()
+        .get::<DepositDataKey, Position>(&DepositDataKey::Position(borrower.clone()))
+        .ok_or(LiquidationError::NotLiquidatable)?;
+    let borrower_collateral = env.storage().persistent()
+        .get::<DepositDataKey, i128>(&DepositDataKey::CollateralBalance(borrower.clone()))
+        .unwrap_or(0);
+
+    // Collateral the borrower excluded from collateral cannot be seized
+    if !crate::asset_positions::is_collateral_enabled(env, &borrower, &collateral_asset) {
+        return Err(LiquidationError::InvalidCollateralAsset);
+    }
+    // Only collateral held in `collateral_asset` (or not yet attributed to
+    // any asset) can be paid out in it
+    let asset_collateral =
+        crate::asset_positions::get_asset_position(env, &borrower, collateral_asset.clone())
+            .collateral;
+    let unattributed =
+        crate::asset_positions::unattributed_collateral(env, &borrower, borrower_collateral);
+    let held_collateral = asset_collateral.saturating_add(unattributed);
+    if held_collateral <= 0 {
+        return Err(LiquidationError::InvalidCollateralAsset);
+    }
+    let seizable_collateral = borrower_collateral
+        .saturating_sub(crate::asset_positions::disabled_collateral(env, &borrower))
+        .min(held_collateral)
+        .max(0);
+
+    // Fetch Prices and Decimals
     let (debt_price, collateral_price) = get_liquidation_prices(env, &debt_asset, &collateral_asset)?;
     let debt_decimals = get_asset_decimals(env, &debt_asset);
     let collateral_decimals = get_asset_decimals(env, &collateral_asset);
 
-    // 6. ENFORCE HEALTH AND CLOSE FACTOR
+    // ENFORCE HEALTH AND CLOSE FACTOR
     // Accrue debt up to current timestamp for accurate health assessment
-    let current_total_debt = calculate_accrued_debt(env, &position)?;
-    
-    if !can_be_liquidated(env, borrower_collateral, current_total_debt).unwrap_or(false) {
+    let current_total_debt = calculate_accrued_debt(env, &borrower, &position)?;
+
+    // Compare collateral and debt by oracle value across assets
+    let value = crate::asset_positions::position_value(
+        env,
+        &borrower,
+        borrower_collateral,
+        position.debt,
+        current_total_debt.saturating_sub(position.debt),
+    )
+    .map_err(|_| LiquidationError::PriceNotAvailable)?;
+    let debt_value = value.debt.checked_add(value.interest).ok_or(LiquidationError::Overflow)?;
+
+    if !can_liquidate_position(env, &borrower, value.collateral, debt_value).unwrap_or(false) {
         return Err(LiquidationError::NotLiquidatable);
     }
 
     let max_liquidatable = get_max_liquidatable_amount(env, current_total_debt)
         .map_err(|_| LiquidationError::Overflow)?;
+    let max_liquidatable =
+        crate::loyalty::protected_max_liquidatable(env, &borrower, max_liquidatable);
     let actual_debt_liquidated = debt_amount.min(max_liquidatable).min(current_total_debt);
 
     if actual_debt_liquidated <= 0 {
         return Err(LiquidationError::InvalidAmount);
     }
 
-    // 7. CALCULATE SEIZURE WITH PRECISION MATH
+    // CALCULATE SEIZURE WITH PRECISION MATH
     // math: amount * price_debt * (10000 + incentive) * 10^col_decimals / (price_col * 10000 * 10^debt_decimals)
     
-    let incentive_bps = get_risk_params(env).map(|p| p.liquidation_incentive).unwrap_or(1000);
+    let incentive_bps = incentive_bps_for(env, &collateral_asset);
     let bonus_multiplier = 10000i128.checked_add(incentive_bps).ok_or(LiquidationError::Overflow)?;
     
     let amount_256 = I256::from_i128(env, actual_debt_liquidated);
@@ -232,75 +600,176 @@ pub fn liquidate(
     let mut collateral_seized = seized_256.to_i128().ok_or(LiquidationError::Overflow)?;
 
     // Cap seizure at available collateral
-    collateral_seized = collateral_seized.min(borrower_collateral);
-    
-    let incentive_amount = get_liquidation_incentive_amount(env, actual_debt_liquidated)
-        .unwrap_or(0);
-
-    // 8. UPDATE STORAGE (EFFECTS)
-    
-    // Resolve Interest and Debt (mirroring repay_debt logic)
-    // Interest is paid first, then principal.
-    let total_interest_to_repay = position.borrow_interest.checked_add(
-        current_total_debt.checked_sub(position.debt + position.borrow_interest).unwrap_or(0)
-    ).ok_or(LiquidationError::Overflow)?;
+    collateral_seized = collateral_seized.min(seizable_collateral);
+
+    // SPLIT THE INCENTIVE
+    // The incentive portion of the seizure is seized * incentive / (10000 + incentive);
+    // the governed insurance share of it is withheld from the liquidator.
+    let insurance_share_bps = crate::reserve::get_liquidation_insurance_share(env);
+    let incentive_collateral = collateral_seized
+        .checked_mul(incentive_bps)
+        .and_then(|v| v.checked_div(bonus_multiplier))
+        .ok_or(LiquidationError::Overflow)?;
+    let insurance_collateral = incentive_collateral
+        .checked_mul(insurance_share_bps)
+        .and_then(|v| v.checked_div(10000))
+        .ok_or(LiquidationError::Overflow)?
+        .max(0);
+    let liquidator_collateral = collateral_seized
+        .checked_sub(insurance_collateral)
+        .ok_or(LiquidationError::Overflow)?;
+
+    Ok(LiquidationPreview {
+        debt_to_repay: actual_debt_liquidated,
+        collateral_seized,
+        liquidator_collateral,
+        insurance_collateral,
+        incentive_bps,
+        insurance_share_bps,
+        debt_price,
+        collateral_price,
+    })
+}
 
-    if actual_debt_liquidated <= total_interest_to_repay {
-        position.borrow_interest = total_interest_to_repay - actual_debt_liquidated;
-    } else {
-        let remaining_to_principal = actual_debt_liquidated - total_interest_to_repay;
-        position.borrow_interest = 0;
-        position.debt = position.debt.checked_sub(remaining_to_principal).unwrap_or(0);
+/// Liquidate just enough debt to restore `target_health_factor`
+///
+/// Computes the debt whose repayment, with the collateral it seizes at the
+/// incentive, brings the borrower's health factor (collateral value ×
+/// 10_000 / debt value) to at least `target_health_factor`, rounded up to the
+/// next unit of `debt_asset`. The amount is then liquidated exactly as by
+/// [`liquidate`], so the close factor still caps it; `health_factor_after`
+/// reports where the borrower ended up.
+///
+/// # Errors
+/// * `InvalidAmount`: Target <= 0 or already met.
+/// * `TargetUnreachable`: Health factor is at or below `10_000 + incentive`,
+///   where every liquidation lowers it further.
+/// * Any error of [`liquidate`].
+pub fn liquidate_to_target(
+    env: &Env,
+    liquidator: Address,
+    borrower: Address,
+    debt_asset: Option<Address>,
+    collateral_asset: Option<Address>,
+    target_health_factor: i128,
+) -> Result<TargetLiquidation, LiquidationError> {
+    if target_health_factor <= 0 {
+        return Err(LiquidationError::InvalidAmount);
     }
-    
-    position.collateral = borrower_collateral.checked_sub(collateral_seized).unwrap_or(0);
-    position.last_accrual_time = env.ledger().timestamp();
-
-    env.storage().persistent().set(&position_key, &position);
-    env.storage().persistent().set(&collateral_key, &position.collateral);
-
-    update_protocol_analytics(env, actual_debt_liquidated, collateral_seized)
-        .map_err(|_| LiquidationError::Overflow)?;
 
-    // 9. EXTERNAL INTERACTIONS (TRANSFERS)
-    // Transfers are performed LAST to follow CEI pattern
-    
-    let debt_addr = match &debt_asset {
-        Some(ref addr) => addr.clone(),
-        None => get_native_asset_address(env)?,
-    };
-    let debt_client = TokenClient::new(env, &debt_addr);
-    debt_client.transfer_from(&env.current_contract_address(), &liquidator, &env.current_contract_address(), &actual_debt_liquidated);
+    let (collateral_value, debt_value) = position_health(env, &borrower)?;
+    if debt_value == 0 {
+        return Err(LiquidationError::NotLiquidatable);
+    }
+    let health_factor_before = health_factor(collateral_value, debt_value)?;
+    if health_factor_before >= target_health_factor {
+        return Err(LiquidationError::InvalidAmount);
+    }
 
-    let col_addr = match &collateral_asset {
-        Some(ref addr) => addr.clone(),
-        None => get_native_asset_address(env)?,
-    };
-    let col_client = TokenClient::new(env, &col_addr);
-    col_client.transfer(&env.current_contract_address(), &liquidator, &collateral_seized);
+    // Repaying `r` of value seizes `r × bonus / 10_000`, so the target holds when
+    // (collateral × 10_000 − r × bonus) ≥ target × (debt − r), i.e.
+    // r ≥ (target × debt − collateral × 10_000) / (target − bonus)
+    let bonus = 10000i128
+        .checked_add(incentive_bps_for(env, &collateral_asset))
+        .ok_or(LiquidationError::Overflow)?;
+    if health_factor_before <= bonus || target_health_factor <= bonus {
+        return Err(LiquidationError::TargetUnreachable);
+    }
+    let shortfall = target_health_factor
+        .checked_mul(debt_value)
+        .and_then(|v| v.checked_sub(collateral_value.checked_mul(10000)?))
+        .ok_or(LiquidationError::Overflow)?;
+    let step = target_health_factor - bonus;
+    let repay_value = shortfall
+        .checked_add(step - 1)
+        .ok_or(LiquidationError::Overflow)?
+        / step;
+
+    // Round up to whole units of the debt asset
+    let mut debt_amount = crate::oracle::ratio_amount(env, debt_asset.as_ref(), repay_value)
+        .map_err(|_| LiquidationError::PriceNotAvailable)?;
+    let covered = crate::oracle::ratio_value(env, debt_asset.as_ref(), debt_amount)
+        .map_err(|_| LiquidationError::PriceNotAvailable)?;
+    if covered < repay_value {
+        debt_amount = debt_amount
+            .checked_add(1)
+            .ok_or(LiquidationError::Overflow)?;
+    }
 
-    // 10. EMIT EVENTS
-    emit_liquidation(env, LiquidationEvent {
-        liquidator: liquidator.clone(),
-        borrower: borrower.clone(),
+    let (debt_repaid, collateral_seized, incentive_amount) = liquidate(
+        env,
+        liquidator,
+        borrower.clone(),
         debt_asset,
         collateral_asset,
-        debt_liquidated: actual_debt_liquidated,
+        debt_amount.max(1),
+    )?;
+
+    let (collateral_value, debt_value) = position_health(env, &borrower)?;
+    Ok(TargetLiquidation {
+        debt_repaid,
         collateral_seized,
         incentive_amount,
-        debt_price,
-        collateral_price,
-        timestamp: position.last_accrual_time,
-    });
-    
-    emit_position_updated_event(env, &borrower, &position);
-    add_activity_log(env, &borrower, Symbol::new(env, "liquidate"), actual_debt_liquidated, debt_asset.clone(), position.last_accrual_time).ok();
+        health_factor_before,
+        health_factor_after: health_factor(collateral_value, debt_value)?,
+    })
+}
 
-    Ok((actual_debt_liquidated, collateral_seized, incentive_amount))
+/// Liquidation incentive for seizing `collateral_asset` (basis points)
+///
+/// Tiered collateral uses its tier's incentive.
+fn incentive_bps_for(env: &Env, collateral_asset: &Option<Address>) -> i128 {
+    crate::risk_tier::liquidation_incentive_for(env, collateral_asset).unwrap_or_else(|| {
+        get_risk_params(env)
+            .map(|p| p.liquidation_incentive)
+            .unwrap_or(1000)
+    })
+}
+
+/// Borrower's collateral and debt (with interest accrued up to now) by oracle value
+fn position_health(env: &Env, borrower: &Address) -> Result<(i128, i128), LiquidationError> {
+    let Some(position) = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Position>(&DepositDataKey::Position(borrower.clone()))
+    else {
+        return Ok((0, 0));
+    };
+    let collateral = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, i128>(&DepositDataKey::CollateralBalance(borrower.clone()))
+        .unwrap_or(0);
+    let total_debt = calculate_accrued_debt(env, borrower, &position)?;
+
+    let value = crate::asset_positions::position_value(
+        env,
+        borrower,
+        collateral,
+        position.debt,
+        total_debt.saturating_sub(position.debt),
+    )
+    .map_err(|_| LiquidationError::PriceNotAvailable)?;
+    let debt_value = value
+        .debt
+        .checked_add(value.interest)
+        .ok_or(LiquidationError::Overflow)?;
+    Ok((value.collateral, debt_value))
+}
+
+/// Health factor in basis points (`i128::MAX` with no debt)
+fn health_factor(collateral_value: i128, debt_value: i128) -> Result<i128, LiquidationError> {
+    if debt_value == 0 {
+        return Ok(i128::MAX);
+    }
+    collateral_value
+        .checked_mul(10000)
+        .map(|v| v / debt_value)
+        .ok_or(LiquidationError::Overflow)
 }
 
 /// Update protocol analytics after liquidation
-fn update_protocol_analytics(
+fn update_protocol_analytics_liquidation(
     env: &Env,
     debt_liquidated: i128,
     collateral_seized: i128,
@@ -0,0 +1,195 @@
+//! # Loyalty Tier Module
+//!
+//! Ranks users into loyalty tiers by how much and how long they have used
+//! the protocol, and gives higher tiers better terms.
+//!
+//! The risk admin configures up to [`MAX_LOYALTY_TIERS`] tiers, each with the
+//! lifetime volume (deposits plus borrows, from the user's analytics) and the
+//! account age (time since first interaction) needed to reach it. A user's
+//! tier is the highest one whose thresholds they meet; `0` means no tier.
+//!
+//! Benefits of a tier ([`LoyaltyTier`]):
+//! - `fee_discount_bps` off the origination fee on borrows. A user who also
+//!   qualifies for the staker discount (see [`crate::fee_discount`]) gets the
+//!   larger of the two, not both.
+//! - `close_factor_reduction_bps` taken off the close factor when the user is
+//!   liquidated, so less of their debt can be repaid per liquidation. The
+//!   reduced close factor never falls below half the configured one.
+//!
+//! The tier is recomputed on every read, so account age promotes users
+//! without a transaction; `UserAnalytics::loyalty_tier` records the tier as
+//! of the user's last activity.
+//!
+//! ## Security
+//! - Tiers are set by the `risk_admin` role or the super admin.
+//! - Discounts are capped at [`crate::fee_discount::MAX_FEE_DISCOUNT_BPS`]
+//!   and close factor reductions at [`MAX_CLOSE_FACTOR_REDUCTION_BPS`]; with
+//!   the half close factor floor a tier can never make a position
+//!   unliquidatable.
+//! - Volume comes from per-user analytics, which are only written at full
+//!   analytics verbosity; at lower levels users keep their current volume.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::deposit::{DepositDataKey, UserAnalytics};
+
+/// Maximum number of loyalty tiers
+pub const MAX_LOYALTY_TIERS: u32 = 5;
+
+/// Largest close factor reduction a tier may grant (5%)
+pub const MAX_CLOSE_FACTOR_REDUCTION_BPS: i128 = 500;
+
+/// Errors that can occur when configuring loyalty tiers
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum LoyaltyError {
+    /// Caller is not authorized
+    Unauthorized = 1,
+    /// A tier is out of range or tiers are out of order
+    InvalidTier = 2,
+}
+
+/// Storage keys for loyalty data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum LoyaltyDataKey {
+    /// Configured tiers, lowest first: Vec<LoyaltyTier>
+    Tiers,
+}
+
+/// Thresholds and benefits of one loyalty tier
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyTier {
+    /// Lifetime deposits plus borrows needed
+    pub min_volume: i128,
+    /// Seconds since the user's first interaction needed
+    pub min_age: u64,
+    /// Discount on the origination fee (basis points)
+    pub fee_discount_bps: i128,
+    /// Points taken off the close factor when liquidated (basis points)
+    pub close_factor_reduction_bps: i128,
+}
+
+/// Replace the loyalty tiers
+///
+/// `tiers` are listed lowest first; each must require at least as much
+/// volume and age as the one before and grant at least the same benefits.
+/// An empty list disables tiers.
+///
+/// # Errors
+/// * `LoyaltyError::Unauthorized` - If caller is not a risk admin or the admin
+/// * `LoyaltyError::InvalidTier` - If a tier is out of range or out of order
+#[allow(deprecated)]
+pub fn set_loyalty_tiers(
+    env: &Env,
+    caller: Address,
+    tiers: Vec<LoyaltyTier>,
+) -> Result<(), LoyaltyError> {
+    caller.require_auth();
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| LoyaltyError::Unauthorized)?;
+
+    if tiers.len() > MAX_LOYALTY_TIERS {
+        return Err(LoyaltyError::InvalidTier);
+    }
+    let mut previous: Option<LoyaltyTier> = None;
+    for tier in tiers.iter() {
+        if tier.min_volume < 0
+            || !(0..=crate::fee_discount::MAX_FEE_DISCOUNT_BPS).contains(&tier.fee_discount_bps)
+            || !(0..=MAX_CLOSE_FACTOR_REDUCTION_BPS).contains(&tier.close_factor_reduction_bps)
+        {
+            return Err(LoyaltyError::InvalidTier);
+        }
+        if let Some(prev) = previous {
+            if tier.min_volume < prev.min_volume
+                || tier.min_age < prev.min_age
+                || tier.fee_discount_bps < prev.fee_discount_bps
+                || tier.close_factor_reduction_bps < prev.close_factor_reduction_bps
+            {
+                return Err(LoyaltyError::InvalidTier);
+            }
+        }
+        previous = Some(tier);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&LoyaltyDataKey::Tiers, &tiers);
+
+    let topics = (Symbol::new(env, "loyalty_tiers_updated"), caller);
+    env.events().publish(topics, tiers);
+
+    Ok(())
+}
+
+/// Configured loyalty tiers, lowest first
+pub fn get_loyalty_tiers(env: &Env) -> Vec<LoyaltyTier> {
+    env.storage()
+        .persistent()
+        .get(&LoyaltyDataKey::Tiers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Tier reached by a user with `analytics` at `timestamp` (`0` = none)
+pub fn tier_for(env: &Env, analytics: &UserAnalytics, timestamp: u64) -> u32 {
+    let volume = analytics
+        .total_deposits
+        .saturating_add(analytics.total_borrows);
+    let age = timestamp.saturating_sub(analytics.first_interaction);
+
+    let mut reached = 0;
+    for (i, tier) in get_loyalty_tiers(env).iter().enumerate() {
+        if volume < tier.min_volume || age < tier.min_age {
+            break;
+        }
+        reached = i as u32 + 1;
+    }
+    reached
+}
+
+/// Current loyalty tier of `user` (`0` = none)
+pub fn get_user_tier(env: &Env, user: &Address) -> u32 {
+    let analytics: Option<UserAnalytics> = env
+        .storage()
+        .persistent()
+        .get(&DepositDataKey::UserAnalytics(user.clone()));
+    match analytics {
+        Some(analytics) => tier_for(env, &analytics, env.ledger().timestamp()),
+        None => 0,
+    }
+}
+
+/// Benefits of `user`'s current tier, if any
+pub fn get_user_tier_benefits(env: &Env, user: &Address) -> Option<LoyaltyTier> {
+    match get_user_tier(env, user) {
+        0 => None,
+        tier => get_loyalty_tiers(env).get(tier - 1),
+    }
+}
+
+/// Origination fee discount of `user`'s tier (basis points)
+pub fn fee_discount_bps(env: &Env, user: &Address) -> i128 {
+    get_user_tier_benefits(env, user)
+        .map(|tier| tier.fee_discount_bps)
+        .unwrap_or(0)
+}
+
+/// Largest debt repayable in one liquidation of `user`
+///
+/// `max_liquidatable` is the amount allowed by the configured close factor;
+/// it is scaled down by the user's tier reduction.
+pub fn protected_max_liquidatable(env: &Env, user: &Address, max_liquidatable: i128) -> i128 {
+    let reduction = get_user_tier_benefits(env, user)
+        .map(|tier| tier.close_factor_reduction_bps)
+        .unwrap_or(0);
+    let close_factor = crate::risk_params::get_close_factor(env).unwrap_or(0);
+    if reduction == 0 || close_factor <= 0 {
+        return max_liquidatable;
+    }
+    let protected = (close_factor - reduction).max(close_factor / 2);
+    max_liquidatable.saturating_mul(protected) / close_factor
+}
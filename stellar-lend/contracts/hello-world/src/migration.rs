@@ -0,0 +1,294 @@
+//! # Position Migration Module
+//!
+//! Moves a user's position from another lending protocol into StellarLend in a
+//! single transaction.
+//!
+//! [`migrate_position`] fronts the user's debt on the source protocol out of
+//! the pool's own liquidity, acting as a flash loan, then:
+//! 1. repays the debt on the source protocol on the user's behalf,
+//! 2. withdraws the freed collateral from the source into this contract and
+//!    credits it to the user's position,
+//! 3. re-borrows the fronted amount against that collateral, which repays the
+//!    flash loan.
+//!
+//! If the resulting position does not meet the minimum collateral ratio, the
+//! whole migration reverts, including the calls to the source protocol.
+//!
+//! ## Source Interface
+//! A source protocol must expose:
+//! - `repay_for(user: Address, asset: Address, amount: i128)` — reduce `user`'s
+//!   debt by `amount` of `asset`, which has already been transferred to it.
+//! - `withdraw_for(user: Address, asset: Address, amount: i128, to: Address)`
+//!   — send `amount` of `user`'s collateral to `to`, with `user`'s
+//!   authorization.
+//!
+//! ## Security
+//! - Only admin-whitelisted source contracts can be called.
+//! - The user must authorize the migration; the source sees the same
+//!   authorization for the collateral withdrawal.
+//! - Collateral is credited from the balance actually received, never from
+//!   the requested amount.
+//! - Migrated collateral counts toward the same-ledger deposit guard but not
+//!   toward holding periods, since it was already locked in the source.
+
+#![allow(unused)]
+use soroban_sdk::{
+    contracterror, contracttype, token::TokenClient, Address, Env, IntoVal, Symbol, Val, Vec,
+};
+
+use crate::deposit::{AssetParams, DepositDataKey, Position};
+use crate::risk_management::PauseOperation;
+
+/// Errors that can occur during position migration
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MigrationError {
+    /// Caller is not authorized
+    Unauthorized = 1,
+    /// Amount must be greater than zero
+    InvalidAmount = 2,
+    /// Asset address is invalid
+    InvalidAsset = 3,
+    /// Source contract is not whitelisted
+    SourceNotAllowed = 4,
+    /// Deposits or borrows are paused
+    Paused = 5,
+    /// Pool cannot front the debt amount
+    InsufficientLiquidity = 6,
+    /// Source sent less collateral than requested
+    CollateralNotReceived = 7,
+    /// Migrated position would be below the minimum collateral ratio
+    InsufficientCollateralRatio = 8,
+    /// Circuit breaker is tripped for the debt asset
+    CircuitBreakerTripped = 9,
+    /// Reentrancy detected
+    Reentrancy = 10,
+    /// Arithmetic overflow occurred
+    Overflow = 11,
+}
+
+/// Storage keys for migration data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum MigrationDataKey {
+    /// Whether a lending contract may be migrated from: bool
+    Source(Address),
+}
+
+/// Allow or disallow migrations from a lending contract (admin only)
+///
+/// # Errors
+/// * `MigrationError::Unauthorized` - If caller is not admin
+#[allow(deprecated)]
+pub fn set_migration_source(
+    env: &Env,
+    caller: Address,
+    source: Address,
+    allowed: bool,
+) -> Result<(), MigrationError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| MigrationError::Unauthorized)?;
+
+    let key = MigrationDataKey::Source(source.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    let topics = (Symbol::new(env, "migration_source_updated"), caller);
+    env.events().publish(topics, (source, allowed));
+
+    Ok(())
+}
+
+/// Whether migrations from `source` are allowed
+pub fn is_migration_source(env: &Env, source: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&MigrationDataKey::Source(source.clone()))
+        .unwrap_or(false)
+}
+
+/// Migrate `user`'s position from a whitelisted lending contract
+///
+/// Repays `debt_amount` of `debt_asset` on `source`, moves `collateral_amount`
+/// of `collateral_asset` into this contract and opens the same debt here.
+/// Returns the user's total debt after the migration.
+///
+/// # Errors
+/// * `MigrationError::InvalidAmount` - If either amount is <= 0
+/// * `MigrationError::SourceNotAllowed` - If `source` is not whitelisted
+/// * `MigrationError::Paused` - If deposits or borrows are paused
+/// * `MigrationError::InvalidAsset` - If an asset is this contract
+/// * `MigrationError::InsufficientLiquidity` - If the pool cannot front the debt
+/// * `MigrationError::CollateralNotReceived` - If the source sends less collateral
+/// * `MigrationError::InsufficientCollateralRatio` - If the migrated position is
+///   below the minimum collateral ratio
+#[allow(deprecated)]
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_position(
+    env: &Env,
+    user: Address,
+    source: Address,
+    debt_asset: Address,
+    debt_amount: i128,
+    collateral_asset: Address,
+    collateral_amount: i128,
+) -> Result<i128, MigrationError> {
+    user.require_auth();
+
+    if debt_amount <= 0 || collateral_amount <= 0 {
+        return Err(MigrationError::InvalidAmount);
+    }
+    if !is_migration_source(env, &source) {
+        return Err(MigrationError::SourceNotAllowed);
+    }
+
+    let _guard =
+        crate::reentrancy::ReentrancyGuard::new(env).map_err(|_| MigrationError::Reentrancy)?;
+
+    let config = crate::hot_config::load(env);
+    if config.is_paused(env, PauseOperation::Deposit)
+        || config.is_paused(env, PauseOperation::Borrow)
+    {
+        return Err(MigrationError::Paused);
+    }
+
+    let this = env.current_contract_address();
+    if debt_asset == this || collateral_asset == this {
+        return Err(MigrationError::InvalidAsset);
+    }
+
+    crate::circuit_breaker::record_volume(env, Some(debt_asset.clone()), debt_amount)
+        .map_err(|_| MigrationError::CircuitBreakerTripped)?;
+
+    // Front the debt out of pool liquidity and repay it on the source
+    crate::strategy::ensure_liquidity(env, &debt_asset, debt_amount)
+        .map_err(|_| MigrationError::InsufficientLiquidity)?;
+    let debt_token = TokenClient::new(env, &debt_asset);
+    if debt_token.balance(&this) < debt_amount {
+        return Err(MigrationError::InsufficientLiquidity);
+    }
+    debt_token.transfer(&this, &source, &debt_amount);
+
+    let args: Vec<Val> = (user.clone(), debt_asset.clone(), debt_amount).into_val(env);
+    env.invoke_contract::<()>(&source, &Symbol::new(env, "repay_for"), args);
+
+    // Pull the freed collateral into this contract
+    let collateral_token = TokenClient::new(env, &collateral_asset);
+    let balance_before = collateral_token.balance(&this);
+    let args: Vec<Val> = (
+        user.clone(),
+        collateral_asset.clone(),
+        collateral_amount,
+        this.clone(),
+    )
+        .into_val(env);
+    env.invoke_contract::<()>(&source, &Symbol::new(env, "withdraw_for"), args);
+    let received = collateral_token
+        .balance(&this)
+        .checked_sub(balance_before)
+        .ok_or(MigrationError::Overflow)?;
+    if received < collateral_amount {
+        return Err(MigrationError::CollateralNotReceived);
+    }
+
+    // Credit the collateral and re-borrow the fronted amount
+    let timestamp = env.ledger().timestamp();
+    let position_key = DepositDataKey::Position(user.clone());
+    let mut position = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Position>(&position_key)
+        .unwrap_or(Position {
+            collateral: 0,
+            debt: 0,
+            borrow_interest: 0,
+            last_accrual_time: timestamp,
+        });
+    crate::borrow::accrue_interest(env, &user, &mut position)
+        .map_err(|_| MigrationError::Overflow)?;
+
+    let collateral_key = DepositDataKey::CollateralBalance(user.clone());
+    let collateral = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, i128>(&collateral_key)
+        .unwrap_or(0)
+        .checked_add(received)
+        .ok_or(MigrationError::Overflow)?;
+    position.collateral = position
+        .collateral
+        .checked_add(received)
+        .ok_or(MigrationError::Overflow)?;
+    position.debt = position
+        .debt
+        .checked_add(debt_amount)
+        .ok_or(MigrationError::Overflow)?;
+    position.last_accrual_time = timestamp;
+
+    let collateral_factor = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, AssetParams>(&DepositDataKey::AssetParams(
+            collateral_asset.clone(),
+        ))
+        .map(|params| params.collateral_factor)
+        .unwrap_or(10000);
+    let collateral_factor = crate::risk_params::effective_collateral_factor(
+        env,
+        Some(&collateral_asset),
+        collateral_factor,
+    );
+    let collateral_factor =
+        crate::credit_history::adjusted_collateral_factor(env, &user, collateral_factor);
+    let min_ratio = crate::risk_params::get_min_collateral_ratio(env).unwrap_or(15000);
+    let ratio = crate::borrow::calculate_collateral_ratio(
+        crate::flash_guard::borrowable_collateral(env, &user, collateral),
+        position.debt,
+        position.borrow_interest,
+        collateral_factor,
+    )
+    .ok_or(MigrationError::Overflow)?;
+    if ratio < min_ratio {
+        return Err(MigrationError::InsufficientCollateralRatio);
+    }
+
+    env.storage().persistent().set(&collateral_key, &collateral);
+    env.storage().persistent().set(&position_key, &position);
+    crate::asset_positions::record_deposit(env, &user, Some(collateral_asset.clone()), received)
+        .map_err(|_| MigrationError::Overflow)?;
+    crate::asset_positions::record_borrow(env, &user, Some(debt_asset.clone()), debt_amount)
+        .map_err(|_| MigrationError::Overflow)?;
+    crate::interest_rate::snapshot_position_index(env, &user, Some(debt_asset.clone()))
+        .map_err(|_| MigrationError::Overflow)?;
+    crate::flash_guard::record_deposit(env, &user);
+    crate::credit_history::record_borrow(env, &user);
+
+    crate::deposit::update_protocol_analytics(env, received, true)
+        .map_err(|_| MigrationError::Overflow)?;
+    crate::borrow::update_protocol_analytics_borrow(env, debt_amount)
+        .map_err(|_| MigrationError::Overflow)?;
+    crate::deposit::emit_position_updated_event(
+        env,
+        &user,
+        &position,
+        Symbol::new(env, "migrate"),
+        timestamp,
+    );
+    crate::analytics::emit_health_factor_changed(env, &user, Symbol::new(env, "migrate"));
+
+    let topics = (Symbol::new(env, "position_migrated"), user);
+    env.events().publish(
+        topics,
+        (source, debt_asset, debt_amount, collateral_asset, received),
+    );
+
+    position
+        .debt
+        .checked_add(position.borrow_interest)
+        .ok_or(MigrationError::Overflow)
+}
@@ -0,0 +1,191 @@
+//! # Storage Migrations Module
+//!
+//! Tracks the layout version of the contract's storage and moves existing
+//! data forward after an upgrade changes it.
+//!
+//! A fresh deployment starts at [`CURRENT_SCHEMA_VERSION`]. A deployment that
+//! predates versioning has no stored version and reads as
+//! [`LEGACY_SCHEMA_VERSION`]. To move from one version to the next, an
+//! upgrader:
+//! 1. calls [`migrate`] with batches of at most [`MAX_MIGRATION_BATCH`] users
+//!    until every user with a position has been covered,
+//! 2. calls [`complete_migration`] to record the next version.
+//!
+//! Migrating a user twice is harmless, so a failed or repeated batch can
+//! simply be resubmitted.
+//!
+//! ## Versions
+//! - **1** — positions live only in the aggregated
+//!   [`Position`](crate::deposit::Position).
+//! - **2** — positions are also tracked per asset by
+//!   [`crate::asset_positions`]. Migrating from 1 attributes each user's
+//!   unattributed collateral and principal debt to the native asset, the only
+//!   asset the aggregated layout held.
+//!
+//! ## Security
+//! - Only the `upgrader` role or the super admin can migrate.
+//! - Flows keep accepting unattributed balances, so users not yet migrated
+//!   are never locked out while batches are in progress.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::admin::{require_role, Role};
+use crate::deposit::{DepositDataKey, Position};
+
+/// Schema version of deployments that predate versioning
+pub const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+/// Schema version written by this code
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// Maximum number of users migrated in one call
+pub const MAX_MIGRATION_BATCH: u32 = 50;
+
+/// Errors that can occur during storage migrations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MigrationsError {
+    /// Caller is not an upgrader or the admin
+    Unauthorized = 1,
+    /// Storage is already at the current schema version
+    AlreadyCurrent = 2,
+    /// Batch holds more than `MAX_MIGRATION_BATCH` users
+    BatchTooLarge = 3,
+    /// Arithmetic overflow occurred
+    Overflow = 4,
+}
+
+/// Storage keys for migration data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum MigrationsDataKey {
+    /// Layout version of stored data: SchemaVersion -> u32
+    SchemaVersion,
+}
+
+/// Schema version of the stored data
+pub fn schema_version(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&MigrationsDataKey::SchemaVersion)
+        .unwrap_or(LEGACY_SCHEMA_VERSION)
+}
+
+/// Mark a fresh deployment as already on the current schema
+pub fn initialize_schema_version(env: &Env) {
+    env.storage()
+        .persistent()
+        .set(&MigrationsDataKey::SchemaVersion, &CURRENT_SCHEMA_VERSION);
+}
+
+/// Migrate `users` from the stored schema version to the next one
+///
+/// # Returns
+/// The number of users whose data changed
+///
+/// # Errors
+/// * `MigrationsError::Unauthorized` - If caller is not an upgrader or the admin
+/// * `MigrationsError::AlreadyCurrent` - If storage is at the current version
+/// * `MigrationsError::BatchTooLarge` - If `users` exceeds `MAX_MIGRATION_BATCH`
+#[allow(deprecated)]
+pub fn migrate(env: &Env, caller: Address, users: Vec<Address>) -> Result<u32, MigrationsError> {
+    caller.require_auth();
+    require_role(env, &caller, Role::Upgrader).map_err(|_| MigrationsError::Unauthorized)?;
+
+    let version = schema_version(env);
+    if version >= CURRENT_SCHEMA_VERSION {
+        return Err(MigrationsError::AlreadyCurrent);
+    }
+    if users.len() > MAX_MIGRATION_BATCH {
+        return Err(MigrationsError::BatchTooLarge);
+    }
+
+    let mut migrated = 0u32;
+    for user in users.iter() {
+        if migrate_user(env, version, &user)? {
+            migrated += 1;
+        }
+    }
+
+    let topics = (Symbol::new(env, "migration_batch"), version);
+    env.events().publish(topics, (users.len(), migrated));
+
+    Ok(migrated)
+}
+
+/// Record that every user has been migrated to the next schema version
+///
+/// # Returns
+/// The new schema version
+///
+/// # Errors
+/// * `MigrationsError::Unauthorized` - If caller is not an upgrader or the admin
+/// * `MigrationsError::AlreadyCurrent` - If storage is at the current version
+#[allow(deprecated)]
+pub fn complete_migration(env: &Env, caller: Address) -> Result<u32, MigrationsError> {
+    caller.require_auth();
+    require_role(env, &caller, Role::Upgrader).map_err(|_| MigrationsError::Unauthorized)?;
+
+    let version = schema_version(env);
+    if version >= CURRENT_SCHEMA_VERSION {
+        return Err(MigrationsError::AlreadyCurrent);
+    }
+
+    let next = version + 1;
+    env.storage()
+        .persistent()
+        .set(&MigrationsDataKey::SchemaVersion, &next);
+
+    let topics = (Symbol::new(env, "schema_migrated"), caller);
+    env.events().publish(topics, (version, next));
+
+    Ok(next)
+}
+
+/// Move one user's data from `from_version` to the next version
+fn migrate_user(env: &Env, from_version: u32, user: &Address) -> Result<bool, MigrationsError> {
+    match from_version {
+        1 => attribute_legacy_balances(env, user),
+        _ => Ok(false),
+    }
+}
+
+/// 1 → 2: book unattributed collateral and principal on the native asset
+fn attribute_legacy_balances(env: &Env, user: &Address) -> Result<bool, MigrationsError> {
+    let collateral = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, i128>(&DepositDataKey::CollateralBalance(user.clone()))
+        .unwrap_or(0);
+    let debt = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Position>(&DepositDataKey::Position(user.clone()))
+        .map(|position| position.debt)
+        .unwrap_or(0);
+
+    let unattributed_collateral =
+        crate::asset_positions::unattributed_collateral(env, user, collateral);
+    let attributed_debt = crate::asset_positions::get_user_assets(env, user)
+        .iter()
+        .fold(0i128, |sum, asset| {
+            sum.saturating_add(crate::asset_positions::get_asset_position(env, user, asset).debt)
+        });
+    let unattributed_debt = debt.saturating_sub(attributed_debt).max(0);
+
+    if unattributed_collateral == 0 && unattributed_debt == 0 {
+        return Ok(false);
+    }
+    if unattributed_collateral > 0 {
+        crate::asset_positions::record_deposit(env, user, None, unattributed_collateral)
+            .map_err(|_| MigrationsError::Overflow)?;
+    }
+    if unattributed_debt > 0 {
+        crate::asset_positions::record_borrow(env, user, None, unattributed_debt)
+            .map_err(|_| MigrationsError::Overflow)?;
+    }
+    Ok(true)
+}
@@ -14,12 +14,45 @@
 //! - Staleness threshold defaults to 1 hour; configurable by admin.
 //! - Sanity-check bounds on min/max price are enforced on every update.
 //! - Only the admin or the designated oracle address may submit price updates.
+//!
+//! ## Per-Asset Configuration
+//! Each asset can carry an [`AssetOracleConfig`] holding its feed address,
+//! expected decimals, heartbeat and deviation limit. When present it overrides
+//! the global staleness and deviation settings for that asset, so every
+//! consumer reading prices through this module sees the same knobs.
+//!
+//! ## Conversions
+//! [`value_of`] and [`convert`] expose the price math used for liquidation
+//! seizures, so integrators value and swap amounts exactly as the protocol
+//! does: `value = amount × price / 10^token_decimals` and
+//! `amount_out = amount_in × price_in × 10^dec_out / (price_out × 10^dec_in)`,
+//! computed in 256-bit precision and rounded down.
+//!
+//! ## Ratio Valuation
+//! Borrow, withdrawal and liquidation health checks compare collateral and
+//! debt held in different assets through [`ratio_value`]:
+//! `value = amount × price / 10^price_decimals`, i.e. base units scaled by the
+//! asset's price relative to 1.0. Native XLM is priced through the configured
+//! native asset address. An asset with no price source at all is valued 1:1,
+//! but a priced asset whose price is stale or missing fails the check rather
+//! than falling back to face value.
+//!
+//! ## Signed Price Submission
+//! On networks without a live pull-based oracle, whitelisted reporters can
+//! sign prices off-chain with ed25519 keys and anyone can relay them with
+//! [`submit_signed_price`]. A submission is accepted only if the reporter key
+//! is whitelisted, the signed timestamp is not in the future and no older than
+//! the staleness window, the nonce is strictly greater than the reporter's
+//! last one, and the signature verifies. Accepted prices go through the same
+//! sanity and deviation checks as direct updates.
 
 #![allow(unused)]
 use crate::deposit::DepositDataKey;
 use crate::events::{emit_price_updated, PriceUpdatedEvent};
-use crate::risk_management::get_admin;
-use soroban_sdk::{contracterror, contracttype, Address, Env, IntoVal, Map, Symbol, Val, Vec};
+use soroban_sdk::{
+    contracterror, contracttype, token::TokenClient, xdr::ToXdr, Address, Bytes, BytesN, Env,
+    IntoVal, Map, Symbol, Val, Vec, I256,
+};
 
 /// Errors that can occur during oracle operations
 #[contracterror]
@@ -44,6 +77,10 @@ pub enum OracleError {
     AssetNotSupported = 8,
     /// Fallback oracle not configured
     FallbackNotConfigured = 9,
+    /// Signed submission reuses or rewinds the reporter's nonce
+    InvalidNonce = 10,
+    /// Signed submission timestamp is in the future
+    InvalidTimestamp = 11,
 }
 
 /// Storage keys for oracle-related data
@@ -71,6 +108,18 @@ pub enum OracleDataKey {
     OracleConfig,
     /// Pause switches specifically for oracle updates: Map<Symbol, bool>
     PauseSwitches,
+    /// Recent price volatility for an asset (EWMA of absolute moves, bps)
+    /// Value type: i128
+    PriceVolatility(Address),
+    /// Whitelisted ed25519 reporter key for signed submissions
+    /// Value type: bool
+    SignedReporter(BytesN<32>),
+    /// Last nonce accepted from a signed reporter
+    /// Value type: u64
+    ReporterNonce(BytesN<32>),
+    /// Per-asset oracle configuration
+    /// Value type: AssetOracleConfig
+    AssetConfig(Address),
 }
 
 /// Price feed data structure
@@ -115,6 +164,20 @@ pub struct OracleConfig {
     pub max_price: i128,
 }
 
+/// Per-asset oracle configuration
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssetOracleConfig {
+    /// Oracle address that feeds this asset's price
+    pub feed: Address,
+    /// Decimals the feed reports prices in
+    pub decimals: u32,
+    /// Maximum age of a price before it is considered stale (seconds)
+    pub heartbeat: u64,
+    /// Maximum price move between updates in basis points
+    pub max_deviation_bps: i128,
+}
+
 /// Default configuration values
 const DEFAULT_MAX_DEVIATION_BPS: i128 = 500; // 5%
 const DEFAULT_MAX_STALENESS_SECONDS: u64 = 3600; // 1 hour
@@ -122,6 +185,9 @@ const DEFAULT_CACHE_TTL_SECONDS: u64 = 300; // 5 minutes
 const DEFAULT_MIN_PRICE: i128 = 1;
 const DEFAULT_MAX_PRICE: i128 = i128::MAX;
 
+/// Weight of the latest price move in the volatility EWMA (20%)
+const VOLATILITY_EWMA_WEIGHT_BPS: i128 = 2000;
+
 /// Get default oracle configuration
 fn get_default_config() -> OracleConfig {
     OracleConfig {
@@ -142,6 +208,22 @@ fn get_oracle_config(env: &Env) -> OracleConfig {
         .unwrap_or_else(get_default_config)
 }
 
+/// Staleness window for an asset: its heartbeat if configured, else the global setting
+fn max_staleness_seconds(env: &Env, asset: &Address) -> u64 {
+    match get_asset_oracle_config(env, asset) {
+        Some(config) => config.heartbeat,
+        None => get_oracle_config(env).max_staleness_seconds,
+    }
+}
+
+/// Deviation limit for an asset: its own limit if configured, else the global setting
+fn max_deviation_bps(env: &Env, asset: &Address) -> i128 {
+    match get_asset_oracle_config(env, asset) {
+        Some(config) => config.max_deviation_bps,
+        None => get_oracle_config(env).max_deviation_bps,
+    }
+}
+
 /// Get primary oracle for an asset
 fn get_primary_oracle(env: &Env, asset: &Address) -> Option<Address> {
     let key = OracleDataKey::PrimaryOracle(asset.clone());
@@ -173,8 +255,7 @@ fn validate_price(env: &Env, price: i128) -> Result<(), OracleError> {
 }
 
 /// Check if price is stale
-fn is_price_stale(env: &Env, last_updated: u64) -> bool {
-    let config = get_oracle_config(env);
+fn is_price_stale(env: &Env, asset: &Address, last_updated: u64) -> bool {
     let current_time = env.ledger().timestamp();
 
     if current_time < last_updated {
@@ -182,17 +263,20 @@ fn is_price_stale(env: &Env, last_updated: u64) -> bool {
     }
 
     let age = current_time - last_updated;
-    age > config.max_staleness_seconds
+    age > max_staleness_seconds(env, asset)
 }
 
 /// Check price deviation between two prices
-fn check_price_deviation(env: &Env, new_price: i128, old_price: i128) -> Result<(), OracleError> {
+fn check_price_deviation(
+    env: &Env,
+    asset: &Address,
+    new_price: i128,
+    old_price: i128,
+) -> Result<(), OracleError> {
     if old_price == 0 {
         return Ok(()); // No previous price to compare
     }
 
-    let config = get_oracle_config(env);
-
     // Calculate deviation: |new - old| / old * 10000 (basis points)
     let diff = if new_price > old_price {
         new_price
@@ -210,7 +294,7 @@ fn check_price_deviation(env: &Env, new_price: i128, old_price: i128) -> Result<
         .checked_div(old_price)
         .ok_or(OracleError::Overflow)?;
 
-    if deviation_bps > config.max_deviation_bps {
+    if deviation_bps > max_deviation_bps(env, asset) {
         return Err(OracleError::PriceDeviationExceeded);
     }
 
@@ -282,7 +366,7 @@ pub fn update_price_feed(
     }
 
     // Validate caller authorization
-    let is_admin = get_admin(env).map(|admin| admin == caller).unwrap_or(false);
+    let is_admin = crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin).is_ok();
     let primary = get_primary_oracle(env, &asset);
     let fallback = get_fallback_oracle(env, &asset);
 
@@ -300,6 +384,7 @@ pub fn update_price_feed(
 
     // Validate price
     validate_price(env, price)?;
+    check_feed_decimals(env, &asset, decimals)?;
 
     // Determine target storage key and get current feed for deviation check
     let feed_key = if is_fallback && !is_primary && !is_admin {
@@ -315,7 +400,10 @@ pub fn update_price_feed(
 
     // Check price deviation if we have a previous price
     if let Some(ref feed) = current_feed {
-        check_price_deviation(env, price, feed.price)?;
+        check_price_deviation(env, &asset, price, feed.price)?;
+        if matches!(feed_key, OracleDataKey::PriceFeed(_)) {
+            update_price_volatility(env, &asset, price, feed.price)?;
+        }
     }
 
     // Create new price feed
@@ -331,6 +419,11 @@ pub fn update_price_feed(
     // Update storage
     env.storage().persistent().set(&feed_key, &new_feed);
 
+    // Primary updates of stablecoins feed the depeg breaker
+    if matches!(feed_key, OracleDataKey::PriceFeed(_)) {
+        crate::depeg::check_price(env, &asset, price, decimals);
+    }
+
     // When admin submits a price, register the oracle address as the primary oracle
     // for the asset so subsequent calls from that oracle are authorized.
     if is_admin {
@@ -357,6 +450,49 @@ pub fn update_price_feed(
     Ok(price)
 }
 
+/// Fold the latest primary price move into the asset's volatility EWMA
+///
+/// `volatility = (move_bps × w + volatility × (10_000 − w)) / 10_000`, where
+/// `move_bps = |new − old| × 10_000 / old` and `w` is `VOLATILITY_EWMA_WEIGHT_BPS`.
+fn update_price_volatility(
+    env: &Env,
+    asset: &Address,
+    new_price: i128,
+    old_price: i128,
+) -> Result<(), OracleError> {
+    if old_price <= 0 {
+        return Ok(());
+    }
+    let move_bps = (new_price - old_price)
+        .abs()
+        .checked_mul(10_000)
+        .ok_or(OracleError::Overflow)?
+        / old_price;
+
+    let previous = get_price_volatility(env, asset);
+    let volatility = move_bps
+        .checked_mul(VOLATILITY_EWMA_WEIGHT_BPS)
+        .and_then(|v| v.checked_add(previous.checked_mul(10_000 - VOLATILITY_EWMA_WEIGHT_BPS)?))
+        .ok_or(OracleError::Overflow)?
+        / 10_000;
+
+    env.storage()
+        .persistent()
+        .set(&OracleDataKey::PriceVolatility(asset.clone()), &volatility);
+    Ok(())
+}
+
+/// Get recent price volatility for an asset in basis points
+///
+/// Exponentially weighted average of absolute moves between consecutive
+/// primary feed updates. Returns 0 until the asset has two updates.
+pub fn get_price_volatility(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&OracleDataKey::PriceVolatility(asset.clone()))
+        .unwrap_or(0)
+}
+
 /// Get price for an asset with fallback support
 ///
 /// # Arguments
@@ -379,7 +515,7 @@ pub fn get_price(env: &Env, asset: &Address) -> Result<i128, OracleError> {
         .get::<OracleDataKey, PriceFeed>(&feed_key)
     {
         // Check if price is stale
-        if is_price_stale(env, feed.last_updated) {
+        if is_price_stale(env, asset, feed.last_updated) {
             // Try fallback oracle
             if let Ok(fallback_price) = get_fallback_price(env, asset) {
                 return Ok(fallback_price);
@@ -415,7 +551,7 @@ fn get_fallback_price(env: &Env, asset: &Address) -> Result<i128, OracleError> {
             .get::<OracleDataKey, PriceFeed>(&feed_key)
         {
             // Check if fallback price is valid and from authorized oracle
-            if feed.oracle == fallback_oracle && !is_price_stale(env, feed.last_updated) {
+            if feed.oracle == fallback_oracle && !is_price_stale(env, asset, feed.last_updated) {
                 cache_price(env, asset, feed.price);
                 return Ok(feed.price);
             }
@@ -425,6 +561,400 @@ fn get_fallback_price(env: &Env, asset: &Address) -> Result<i128, OracleError> {
     Err(OracleError::FallbackNotConfigured)
 }
 
+/// Value of `amount` of `asset` in oracle price units
+///
+/// `value = amount × price / 10^token_decimals`, rounded down.
+///
+/// # Errors
+/// * Any error from [`get_price`]
+/// * `OracleError::Overflow` - If the result does not fit in an i128
+pub fn value_of(env: &Env, asset: &Address, amount: i128) -> Result<i128, OracleError> {
+    let price = get_price(env, asset)?;
+    let scale = 10i128
+        .checked_pow(TokenClient::new(env, asset).decimals())
+        .ok_or(OracleError::Overflow)?;
+
+    I256::from_i128(env, amount)
+        .mul(&I256::from_i128(env, price))
+        .div(&I256::from_i128(env, scale))
+        .to_i128()
+        .ok_or(OracleError::Overflow)
+}
+
+/// Convert `amount` of `asset_in` into the equivalent amount of `asset_out`
+///
+/// `amount_out = amount × price_in × 10^dec_out / (price_out × 10^dec_in)`,
+/// rounded down. This is the same conversion liquidations use to size
+/// collateral seizures, before the liquidation incentive.
+///
+/// # Errors
+/// * Any error from [`get_price`] for either asset
+/// * `OracleError::Overflow` - If the result does not fit in an i128
+pub fn convert(
+    env: &Env,
+    asset_in: &Address,
+    asset_out: &Address,
+    amount: i128,
+) -> Result<i128, OracleError> {
+    if asset_in == asset_out {
+        return Ok(amount);
+    }
+
+    let price_in = get_price(env, asset_in)?;
+    let price_out = get_price(env, asset_out)?;
+    let scale_in = 10i128
+        .checked_pow(TokenClient::new(env, asset_in).decimals())
+        .ok_or(OracleError::Overflow)?;
+    let scale_out = 10i128
+        .checked_pow(TokenClient::new(env, asset_out).decimals())
+        .ok_or(OracleError::Overflow)?;
+
+    let numerator = I256::from_i128(env, amount)
+        .mul(&I256::from_i128(env, price_in))
+        .mul(&I256::from_i128(env, scale_out));
+    let denominator = I256::from_i128(env, price_out).mul(&I256::from_i128(env, scale_in));
+
+    numerator
+        .div(&denominator)
+        .to_i128()
+        .ok_or(OracleError::Overflow)
+}
+
+/// Price of `asset` for ratio valuation, or `None` if it has no price source
+///
+/// `None` as the asset is native XLM, priced through the configured native
+/// asset address. Returns the price and the decimals it is quoted in.
+fn ratio_price(env: &Env, asset: Option<&Address>) -> Result<Option<(i128, u32)>, OracleError> {
+    let asset = match asset {
+        Some(asset) => asset.clone(),
+        None => match env
+            .storage()
+            .persistent()
+            .get::<DepositDataKey, Address>(&DepositDataKey::NativeAssetAddress)
+        {
+            Some(native) => native,
+            None => return Ok(None),
+        },
+    };
+
+    let storage = env.storage().persistent();
+    let decimals = match get_asset_oracle_config(env, &asset) {
+        Some(config) => config.decimals,
+        None => match storage
+            .get::<OracleDataKey, PriceFeed>(&OracleDataKey::PriceFeed(asset.clone()))
+            .or_else(|| {
+                storage.get::<OracleDataKey, PriceFeed>(&OracleDataKey::FallbackFeed(asset.clone()))
+            }) {
+            Some(feed) => feed.decimals,
+            None if get_fallback_oracle(env, &asset).is_some() => {
+                return Err(OracleError::FallbackNotConfigured)
+            }
+            None => return Ok(None),
+        },
+    };
+
+    let price = get_price(env, &asset)?;
+    Ok(Some((price, decimals)))
+}
+
+/// Value of `amount` of `asset` for collateral ratio math
+///
+/// `value = amount × price / 10^price_decimals`, rounded down: base units
+/// scaled by the asset's price relative to 1.0. Assets without any price
+/// source are valued 1:1.
+///
+/// # Errors
+/// * Any error from [`get_price`] if the asset is priced but has no fresh price
+/// * `OracleError::Overflow` - If the result does not fit in an i128
+pub fn ratio_value(env: &Env, asset: Option<&Address>, amount: i128) -> Result<i128, OracleError> {
+    let Some((price, decimals)) = ratio_price(env, asset)? else {
+        return Ok(amount);
+    };
+    let scale = 10i128.checked_pow(decimals).ok_or(OracleError::Overflow)?;
+
+    I256::from_i128(env, amount)
+        .mul(&I256::from_i128(env, price))
+        .div(&I256::from_i128(env, scale))
+        .to_i128()
+        .ok_or(OracleError::Overflow)
+}
+
+/// Amount of `asset` worth `value` in collateral ratio math, rounded down
+///
+/// Inverse of [`ratio_value`].
+///
+/// # Errors
+/// * Any error from [`get_price`] if the asset is priced but has no fresh price
+/// * `OracleError::Overflow` - If the result does not fit in an i128
+pub fn ratio_amount(env: &Env, asset: Option<&Address>, value: i128) -> Result<i128, OracleError> {
+    let Some((price, decimals)) = ratio_price(env, asset)? else {
+        return Ok(value);
+    };
+    let scale = 10i128.checked_pow(decimals).ok_or(OracleError::Overflow)?;
+
+    I256::from_i128(env, value)
+        .mul(&I256::from_i128(env, scale))
+        .div(&I256::from_i128(env, price))
+        .to_i128()
+        .ok_or(OracleError::Overflow)
+}
+
+/// Reject updates whose decimals differ from the asset's configured feed decimals
+fn check_feed_decimals(env: &Env, asset: &Address, decimals: u32) -> Result<(), OracleError> {
+    match get_asset_oracle_config(env, asset) {
+        Some(config) if config.decimals != decimals => Err(OracleError::InvalidPrice),
+        _ => Ok(()),
+    }
+}
+
+/// Set the oracle configuration for an asset (admin only)
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The address calling this function (must be admin)
+/// * `asset` - The asset address
+/// * `config` - The asset's oracle configuration
+pub fn set_asset_oracle_config(
+    env: &Env,
+    caller: Address,
+    asset: Address,
+    config: AssetOracleConfig,
+) -> Result<(), OracleError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| OracleError::Unauthorized)?;
+    apply_asset_oracle_config(env, asset, config)
+}
+
+/// Store an asset's oracle configuration without an authorization check
+///
+/// Used by governance execution. The configured feed also becomes the asset's
+/// primary oracle so it is authorized to push prices.
+///
+/// # Errors
+/// * `OracleError::InvalidOracle` - If the feed is this contract
+/// * `OracleError::InvalidPrice` - If the heartbeat is zero or the deviation
+///   limit is outside `(0, 10_000]`
+#[allow(deprecated)]
+pub fn apply_asset_oracle_config(
+    env: &Env,
+    asset: Address,
+    config: AssetOracleConfig,
+) -> Result<(), OracleError> {
+    validate_asset_oracle_config(env, &config)?;
+
+    env.storage()
+        .persistent()
+        .set(&OracleDataKey::AssetConfig(asset.clone()), &config);
+    env.storage()
+        .persistent()
+        .set(&OracleDataKey::PrimaryOracle(asset.clone()), &config.feed);
+
+    let topics = (Symbol::new(env, "asset_oracle_config_updated"), asset);
+    env.events().publish(
+        topics,
+        (
+            config.feed,
+            config.decimals,
+            config.heartbeat,
+            config.max_deviation_bps,
+        ),
+    );
+
+    Ok(())
+}
+
+/// Check an asset oracle configuration without storing it
+///
+/// # Errors
+/// * `OracleError::InvalidOracle` - If the feed is this contract
+/// * `OracleError::InvalidPrice` - If the heartbeat is zero or the deviation
+///   limit is outside `(0, 10_000]`
+pub fn validate_asset_oracle_config(
+    env: &Env,
+    config: &AssetOracleConfig,
+) -> Result<(), OracleError> {
+    if config.feed == env.current_contract_address() {
+        return Err(OracleError::InvalidOracle);
+    }
+    if config.heartbeat == 0 || config.max_deviation_bps <= 0 || config.max_deviation_bps > 10000 {
+        return Err(OracleError::InvalidPrice);
+    }
+    Ok(())
+}
+
+/// Get the oracle configuration for an asset, if one is set
+pub fn get_asset_oracle_config(env: &Env, asset: &Address) -> Option<AssetOracleConfig> {
+    env.storage()
+        .persistent()
+        .get(&OracleDataKey::AssetConfig(asset.clone()))
+}
+
+/// Whitelist or remove an ed25519 reporter key for signed submissions
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The address calling this function (must be admin)
+/// * `reporter` - The reporter's ed25519 public key
+/// * `allowed` - Whether the key may submit prices
+pub fn set_signed_reporter(
+    env: &Env,
+    caller: Address,
+    reporter: BytesN<32>,
+    allowed: bool,
+) -> Result<(), OracleError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| OracleError::Unauthorized)?;
+
+    let key = OracleDataKey::SignedReporter(reporter);
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    Ok(())
+}
+
+/// Check whether a reporter key is whitelisted
+pub fn is_signed_reporter(env: &Env, reporter: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&OracleDataKey::SignedReporter(reporter.clone()))
+}
+
+/// Message a reporter signs for a price submission
+///
+/// `xdr(contract) || xdr(asset) || price (16 bytes BE) || decimals (4 bytes BE)
+///  || timestamp (8 bytes BE) || nonce (8 bytes BE)`
+///
+/// Binding the contract address prevents replay against other deployments.
+pub fn signed_price_message(
+    env: &Env,
+    asset: &Address,
+    price: i128,
+    decimals: u32,
+    timestamp: u64,
+    nonce: u64,
+) -> Bytes {
+    let mut message = env.current_contract_address().to_xdr(env);
+    message.append(&asset.clone().to_xdr(env));
+    message.append(&Bytes::from_array(env, &price.to_be_bytes()));
+    message.append(&Bytes::from_array(env, &decimals.to_be_bytes()));
+    message.append(&Bytes::from_array(env, &timestamp.to_be_bytes()));
+    message.append(&Bytes::from_array(env, &nonce.to_be_bytes()));
+    message
+}
+
+/// Accept a price signed off-chain by a whitelisted reporter
+///
+/// Anyone may relay the submission. The stored feed records the contract
+/// itself as the oracle.
+///
+/// # Errors
+/// * `OracleError::OraclePaused` - If oracle updates are paused
+/// * `OracleError::Unauthorized` - If the reporter key is not whitelisted
+/// * `OracleError::InvalidTimestamp` - If the signed timestamp is in the future
+///   or not after the feed's current `last_updated`
+/// * `OracleError::StalePrice` - If the signed timestamp is older than the staleness window
+/// * `OracleError::InvalidNonce` - If the nonce is not above the last accepted one
+/// * `OracleError::InvalidPrice` / `PriceDeviationExceeded` - As for direct updates
+///
+/// # Panics
+/// If the signature does not verify.
+#[allow(clippy::too_many_arguments)]
+pub fn submit_signed_price(
+    env: &Env,
+    reporter: BytesN<32>,
+    asset: Address,
+    price: i128,
+    decimals: u32,
+    timestamp: u64,
+    nonce: u64,
+    signature: BytesN<64>,
+) -> Result<i128, OracleError> {
+    if let Some(pause_map) = env
+        .storage()
+        .persistent()
+        .get::<OracleDataKey, Map<Symbol, bool>>(&OracleDataKey::PauseSwitches)
+    {
+        if pause_map.get(Symbol::new(env, "pause_oracle")).unwrap_or(false) {
+            return Err(OracleError::OraclePaused);
+        }
+    }
+
+    if !is_signed_reporter(env, &reporter) {
+        return Err(OracleError::Unauthorized);
+    }
+
+    // Freshness
+    let now = env.ledger().timestamp();
+    if timestamp > now {
+        return Err(OracleError::InvalidTimestamp);
+    }
+    if now - timestamp > max_staleness_seconds(env, &asset) {
+        return Err(OracleError::StalePrice);
+    }
+
+    // Replay protection
+    let nonce_key = OracleDataKey::ReporterNonce(reporter.clone());
+    if let Some(last_nonce) = env.storage().persistent().get::<_, u64>(&nonce_key) {
+        if nonce <= last_nonce {
+            return Err(OracleError::InvalidNonce);
+        }
+    }
+
+    // Strictly increasing per feed, so an older signed price cannot roll the
+    // feed back or refresh its staleness clock
+    let feed_key = OracleDataKey::PriceFeed(asset.clone());
+    let current_feed = env
+        .storage()
+        .persistent()
+        .get::<OracleDataKey, PriceFeed>(&feed_key);
+    if let Some(feed) = &current_feed {
+        if timestamp <= feed.last_updated {
+            return Err(OracleError::InvalidTimestamp);
+        }
+    }
+
+    let message = signed_price_message(env, &asset, price, decimals, timestamp, nonce);
+    env.crypto().ed25519_verify(&reporter, &message, &signature);
+
+    validate_price(env, price)?;
+    check_feed_decimals(env, &asset, decimals)?;
+    if let Some(feed) = current_feed {
+        check_price_deviation(env, &asset, price, feed.price)?;
+        update_price_volatility(env, &asset, price, feed.price)?;
+    }
+
+    env.storage().persistent().set(&nonce_key, &nonce);
+    let oracle = env.current_contract_address();
+    env.storage().persistent().set(
+        &feed_key,
+        &PriceFeed {
+            price,
+            last_updated: timestamp,
+            oracle: oracle.clone(),
+            decimals,
+        },
+    );
+    crate::depeg::check_price(env, &asset, price, decimals);
+    cache_price(env, &asset, price);
+
+    emit_price_updated(
+        env,
+        PriceUpdatedEvent {
+            actor: oracle.clone(),
+            asset,
+            price,
+            decimals,
+            oracle,
+            timestamp,
+        },
+    );
+
+    Ok(price)
+}
+
 /// Set primary oracle for an asset
 ///
 /// # Arguments
@@ -439,11 +969,8 @@ pub fn set_primary_oracle(
     primary_oracle: Address,
 ) -> Result<(), OracleError> {
     // Check authorization
-    let admin = get_admin(env).ok_or(OracleError::Unauthorized)?;
-
-    if caller != admin {
-        return Err(OracleError::Unauthorized);
-    }
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| OracleError::Unauthorized)?;
 
     // Set primary oracle
     let primary_key = OracleDataKey::PrimaryOracle(asset);
@@ -468,7 +995,8 @@ pub fn set_fallback_oracle(
     fallback_oracle: Address,
 ) -> Result<(), OracleError> {
     // Check authorization
-    crate::admin::require_admin(env, &caller).map_err(|_| OracleError::Unauthorized)?;
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| OracleError::Unauthorized)?;
 
     // Validate oracle address
     if fallback_oracle == env.current_contract_address() {
@@ -484,6 +1012,72 @@ pub fn set_fallback_oracle(
     Ok(())
 }
 
+/// Set an asset's primary and/or fallback oracle without an authorization check
+///
+/// Used by governance execution. `None` leaves that oracle unchanged.
+///
+/// # Errors
+/// * `OracleError::InvalidOracle` - If either oracle is this contract, or the
+///   primary and fallback would be the same address
+#[allow(deprecated)]
+pub fn apply_oracle_addresses(
+    env: &Env,
+    asset: Address,
+    primary_oracle: Option<Address>,
+    fallback_oracle: Option<Address>,
+) -> Result<(), OracleError> {
+    validate_oracle_addresses(env, &asset, &primary_oracle, &fallback_oracle)?;
+
+    if let Some(primary) = primary_oracle.clone() {
+        env.storage()
+            .persistent()
+            .set(&OracleDataKey::PrimaryOracle(asset.clone()), &primary);
+    }
+    if let Some(fallback) = fallback_oracle.clone() {
+        env.storage()
+            .persistent()
+            .set(&OracleDataKey::FallbackOracle(asset.clone()), &fallback);
+    }
+
+    let topics = (Symbol::new(env, "oracle_addresses_updated"), asset);
+    env.events()
+        .publish(topics, (primary_oracle, fallback_oracle));
+
+    Ok(())
+}
+
+/// Check an oracle address update against the asset's current oracles
+///
+/// # Errors
+/// * `OracleError::InvalidOracle` - If either oracle is this contract, or the
+///   primary and fallback would be the same address
+pub fn validate_oracle_addresses(
+    env: &Env,
+    asset: &Address,
+    primary_oracle: &Option<Address>,
+    fallback_oracle: &Option<Address>,
+) -> Result<(), OracleError> {
+    let this = env.current_contract_address();
+    if primary_oracle.as_ref() == Some(&this) || fallback_oracle.as_ref() == Some(&this) {
+        return Err(OracleError::InvalidOracle);
+    }
+
+    let primary = primary_oracle.clone().or_else(|| {
+        env.storage()
+            .persistent()
+            .get(&OracleDataKey::PrimaryOracle(asset.clone()))
+    });
+    let fallback = fallback_oracle.clone().or_else(|| {
+        env.storage()
+            .persistent()
+            .get(&OracleDataKey::FallbackOracle(asset.clone()))
+    });
+    if primary.is_some() && primary == fallback {
+        return Err(OracleError::InvalidOracle);
+    }
+    Ok(())
+}
+
 /// Configure oracle parameters
 ///
 /// # Arguments
@@ -496,7 +1090,8 @@ pub fn configure_oracle(
     config: OracleConfig,
 ) -> Result<(), OracleError> {
     // Check authorization
-    crate::admin::require_admin(env, &caller).map_err(|_| OracleError::Unauthorized)?;
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| OracleError::Unauthorized)?;
 
     // Validate configuration
     if config.max_deviation_bps <= 0 || config.max_deviation_bps > 10000 {
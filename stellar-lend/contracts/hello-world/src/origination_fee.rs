@@ -0,0 +1,130 @@
+//! # Origination Fee Module
+//!
+//! Protocol-wide fee charged on each borrow, in basis points of the borrowed
+//! amount.
+//!
+//! The fee is deducted from what the borrower receives; the full amount is
+//! still added to debt. An asset with its own deposit parameters charges its
+//! `AssetParams::borrow_fee_bps` instead, so per-asset fees keep precedence;
+//! every other borrow, including native XLM, pays the protocol rate set here.
+//!
+//! Collected fees are split by the insurance fee share (see
+//! [`crate::insurance::route_fee_share`]): that share funds the insurance
+//! fund, the borrower's referrer may get part of the rest (see
+//! [`crate::referral`]) and what remains goes to the treasury reserve.
+//! Cumulative totals of each part are reported in the `fees` section of the
+//! protocol analytics report.
+//!
+//! ## Security
+//! - The rate is only set through an executed governance proposal and is
+//!   capped at [`MAX_ORIGINATION_FEE_BPS`].
+//! - Totals are written only by the borrow flow; there is no external setter.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Env, Symbol};
+
+use crate::deposit::AssetParams;
+
+/// Highest protocol origination fee (10%)
+pub const MAX_ORIGINATION_FEE_BPS: i128 = 1_000;
+
+/// Errors that can occur when configuring the origination fee
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum OriginationFeeError {
+    /// Fee is negative or above `MAX_ORIGINATION_FEE_BPS`
+    InvalidFee = 1,
+}
+
+/// Storage keys for origination fee data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum OriginationFeeDataKey {
+    /// Protocol origination fee in basis points: i128
+    FeeBps,
+    /// Cumulative fees collected and their split: FeeMetrics
+    Totals,
+}
+
+/// Cumulative origination fees, summed over all assets in their own units
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FeeMetrics {
+    /// Fees charged on borrows
+    pub origination_fees: i128,
+    /// Part routed to the insurance fund
+    pub insurance_share: i128,
+    /// Part set aside for referrers
+    pub referral_share: i128,
+    /// Part credited to the treasury reserve
+    pub treasury_share: i128,
+}
+
+/// Set the protocol origination fee
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `OriginationFeeError::InvalidFee` - If outside `[0, MAX_ORIGINATION_FEE_BPS]`
+#[allow(deprecated)]
+pub fn set_origination_fee_bps(env: &Env, fee_bps: i128) -> Result<(), OriginationFeeError> {
+    if !(0..=MAX_ORIGINATION_FEE_BPS).contains(&fee_bps) {
+        return Err(OriginationFeeError::InvalidFee);
+    }
+
+    let old_fee_bps = get_origination_fee_bps(env);
+    env.storage()
+        .persistent()
+        .set(&OriginationFeeDataKey::FeeBps, &fee_bps);
+
+    let topics = (Symbol::new(env, "origination_fee_updated"),);
+    env.events().publish(topics, fee_bps);
+    crate::events::emit_param_change(env, "origination_fee_bps", None, old_fee_bps, fee_bps);
+
+    Ok(())
+}
+
+/// Protocol origination fee in basis points (0 if unset)
+pub fn get_origination_fee_bps(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&OriginationFeeDataKey::FeeBps)
+        .unwrap_or(0)
+}
+
+/// Fee rate charged on a borrow of an asset with deposit parameters `params`
+pub fn fee_bps_for(env: &Env, params: Option<&AssetParams>) -> i128 {
+    match params {
+        Some(params) => params.borrow_fee_bps,
+        None => get_origination_fee_bps(env),
+    }
+}
+
+/// Add a collected fee and its insurance and referral shares to the totals
+pub fn record(env: &Env, fee_amount: i128, insurance_share: i128, referral_share: i128) {
+    if fee_amount <= 0 {
+        return;
+    }
+    let mut totals = get_fee_metrics(env);
+    totals.origination_fees = totals.origination_fees.saturating_add(fee_amount);
+    totals.insurance_share = totals.insurance_share.saturating_add(insurance_share);
+    totals.referral_share = totals.referral_share.saturating_add(referral_share);
+    totals.treasury_share = totals.treasury_share.saturating_add(
+        fee_amount
+            .saturating_sub(insurance_share)
+            .saturating_sub(referral_share),
+    );
+    env.storage()
+        .persistent()
+        .set(&OriginationFeeDataKey::Totals, &totals);
+}
+
+/// Cumulative origination fees and their split
+pub fn get_fee_metrics(env: &Env) -> FeeMetrics {
+    env.storage()
+        .persistent()
+        .get(&OriginationFeeDataKey::Totals)
+        .unwrap_or_default()
+}
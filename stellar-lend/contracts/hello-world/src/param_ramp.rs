@@ -0,0 +1,163 @@
+//! # Parameter Ramp Module
+//!
+//! Spreads collateral factor and liquidation threshold changes over time.
+//!
+//! Cutting a collateral factor or raising the liquidation threshold in one
+//! step can make healthy positions liquidatable in the same ledger. When a
+//! ramp duration is configured, such a change is stored as its new value
+//! right away but applied gradually: reads interpolate linearly from the
+//! value in force when the change was made to the new one over the duration.
+//!
+//! Ramped parameters ([`RampedParam`]):
+//! - an asset's collateral factor (`AssetParams::collateral_factor`), changed
+//!   by `ProposalType::AssetParams`,
+//! - the global liquidation threshold, changed by the admin's
+//!   `set_risk_params` or by `ProposalType::RiskParams`.
+//!
+//! A change made while a ramp is running starts the new ramp from the
+//! current interpolated value, so values never jump. With no duration
+//! configured (the default) changes apply immediately, as before.
+//!
+//! ## Security
+//! - The duration is only set through an executed governance proposal and is
+//!   bounded by [`MAX_RAMP_DURATION`].
+//! - Ramps are started only by the parameter setters; there is no external
+//!   setter.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+/// Longest configurable ramp (30 days)
+pub const MAX_RAMP_DURATION: u64 = 30 * 86_400;
+
+/// Errors that can occur when configuring parameter ramps
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ParamRampError {
+    /// Duration exceeds `MAX_RAMP_DURATION`
+    InvalidDuration = 1,
+}
+
+/// Parameters that change gradually
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RampedParam {
+    /// Collateral factor of an asset's deposit parameters
+    CollateralFactor(Address),
+    /// Global liquidation threshold
+    LiquidationThreshold,
+}
+
+/// Storage keys for parameter ramp data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum ParamRampDataKey {
+    /// Seconds over which changes are spread: u64
+    Duration,
+    /// Ongoing or last ramp of a parameter: ParamRamp
+    Ramp(RampedParam),
+}
+
+/// Linear transition of a parameter
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamRamp {
+    /// Value in force when the change was made
+    pub from: i128,
+    /// New configured value
+    pub to: i128,
+    /// Timestamp the change was made
+    pub start_time: u64,
+    /// Seconds until `to` is fully in force
+    pub duration: u64,
+}
+
+/// Set the ramp duration for future changes (`0` = immediate)
+///
+/// Only reachable through an executed governance proposal. Ramps already
+/// running keep their duration.
+///
+/// # Errors
+/// * `ParamRampError::InvalidDuration` - If above [`MAX_RAMP_DURATION`]
+#[allow(deprecated)]
+pub fn set_ramp_duration(env: &Env, duration: u64) -> Result<(), ParamRampError> {
+    if duration > MAX_RAMP_DURATION {
+        return Err(ParamRampError::InvalidDuration);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&ParamRampDataKey::Duration, &duration);
+
+    let topics = (Symbol::new(env, "param_ramp_duration_updated"),);
+    env.events().publish(topics, duration);
+
+    Ok(())
+}
+
+/// Seconds over which collateral factor and liquidation threshold changes are spread
+pub fn get_ramp_duration(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&ParamRampDataKey::Duration)
+        .unwrap_or(0)
+}
+
+/// Record a change of `param` from `configured` (its stored value) to `to`
+///
+/// Starts a ramp from the value currently in force when a duration is
+/// configured; otherwise clears any running ramp so `to` applies at once.
+#[allow(deprecated)]
+pub fn start(env: &Env, param: RampedParam, configured: i128, to: i128) {
+    let key = ParamRampDataKey::Ramp(param.clone());
+    let duration = get_ramp_duration(env);
+    let from = current(env, &param, configured);
+    if duration == 0 || from == to {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+
+    let ramp = ParamRamp {
+        from,
+        to,
+        start_time: env.ledger().timestamp(),
+        duration,
+    };
+    env.storage().persistent().set(&key, &ramp);
+
+    let topics = (Symbol::new(env, "param_ramp_started"),);
+    env.events().publish(topics, (param, ramp));
+}
+
+/// Value of `param` in force now, given its stored value `configured`
+///
+/// Returns `configured` unless a ramp towards it is still running.
+pub fn current(env: &Env, param: &RampedParam, configured: i128) -> i128 {
+    let ramp: Option<ParamRamp> = env
+        .storage()
+        .persistent()
+        .get(&ParamRampDataKey::Ramp(param.clone()));
+    match ramp {
+        Some(ramp) if ramp.to == configured => interpolate(env, &ramp),
+        _ => configured,
+    }
+}
+
+/// Running ramp of `param`, if its end has not been reached
+pub fn get_ramp(env: &Env, param: RampedParam) -> Option<ParamRamp> {
+    env.storage()
+        .persistent()
+        .get::<ParamRampDataKey, ParamRamp>(&ParamRampDataKey::Ramp(param))
+        .filter(|ramp| env.ledger().timestamp() < ramp.start_time.saturating_add(ramp.duration))
+}
+
+fn interpolate(env: &Env, ramp: &ParamRamp) -> i128 {
+    let elapsed = env.ledger().timestamp().saturating_sub(ramp.start_time);
+    if elapsed >= ramp.duration {
+        return ramp.to;
+    }
+    // |to - from| <= 50_000 bps and duration <= 30 days, so this cannot overflow
+    ramp.from + (ramp.to - ramp.from) * elapsed as i128 / ramp.duration as i128
+}
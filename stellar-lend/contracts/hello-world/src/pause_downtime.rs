@@ -0,0 +1,109 @@
+//! # Pause Downtime Module
+//!
+//! Records when each pause switch and the emergency pause were last activated
+//! and cleared, and accumulates the total time each has spent paused.
+//!
+//! The accumulated total only ever grows, so a consumer that wants to exclude
+//! paused periods from an interval (e.g. interest accrual or a reward program)
+//! can snapshot [`total_paused`] at the start and subtract it from the value
+//! at the end. Operators can derive uptime the same way.
+//!
+//! ## Security
+//! - Records are written only by the risk management pause setters; there is
+//!   no external setter.
+//! - Re-applying the current state is a no-op, so repeated pauses do not reset
+//!   the start of an ongoing pause.
+
+#![allow(unused)]
+use soroban_sdk::{contracttype, Env};
+
+use crate::risk_management::PauseOperation;
+
+/// Storage keys for pause downtime data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum PauseDowntimeDataKey {
+    /// Downtime of an operation's pause switch: PauseDowntime
+    Operation(PauseOperation),
+    /// Downtime of the emergency pause: PauseDowntime
+    Emergency,
+}
+
+/// Pause history of a switch
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PauseDowntime {
+    /// Start of the ongoing pause, if paused
+    pub paused_since: Option<u64>,
+    /// Timestamp the switch was last activated (0 if never)
+    pub last_paused_at: u64,
+    /// Timestamp the switch was last cleared (0 if never)
+    pub last_cleared_at: u64,
+    /// Seconds spent paused, including the ongoing pause up to now
+    pub total_paused: u64,
+}
+
+/// Record a pause switch transition for `operation`
+pub fn record_operation(env: &Env, operation: PauseOperation, paused: bool) {
+    record(env, PauseDowntimeDataKey::Operation(operation), paused);
+}
+
+/// Record an emergency pause transition
+pub fn record_emergency(env: &Env, paused: bool) {
+    record(env, PauseDowntimeDataKey::Emergency, paused);
+}
+
+/// Pause history of an operation's switch
+pub fn get_operation_downtime(env: &Env, operation: PauseOperation) -> PauseDowntime {
+    with_ongoing(env, stored(env, &PauseDowntimeDataKey::Operation(operation)))
+}
+
+/// Pause history of the emergency pause
+pub fn get_emergency_downtime(env: &Env) -> PauseDowntime {
+    with_ongoing(env, stored(env, &PauseDowntimeDataKey::Emergency))
+}
+
+/// Seconds an operation has been unavailable, from its switch or the emergency pause
+///
+/// Overlapping periods are counted once per source, so this is an upper
+/// bound when both were active at the same time.
+pub fn total_paused(env: &Env, operation: PauseOperation) -> u64 {
+    get_operation_downtime(env, operation)
+        .total_paused
+        .saturating_add(get_emergency_downtime(env).total_paused)
+}
+
+fn stored(env: &Env, key: &PauseDowntimeDataKey) -> PauseDowntime {
+    env.storage().persistent().get(key).unwrap_or_default()
+}
+
+fn with_ongoing(env: &Env, mut downtime: PauseDowntime) -> PauseDowntime {
+    if let Some(since) = downtime.paused_since {
+        let ongoing = env.ledger().timestamp().saturating_sub(since);
+        downtime.total_paused = downtime.total_paused.saturating_add(ongoing);
+    }
+    downtime
+}
+
+fn record(env: &Env, key: PauseDowntimeDataKey, paused: bool) {
+    let mut downtime = stored(env, &key);
+    let now = env.ledger().timestamp();
+
+    match (downtime.paused_since, paused) {
+        (None, true) => {
+            downtime.paused_since = Some(now);
+            downtime.last_paused_at = now;
+        }
+        (Some(since), false) => {
+            downtime.total_paused = downtime
+                .total_paused
+                .saturating_add(now.saturating_sub(since));
+            downtime.paused_since = None;
+            downtime.last_cleared_at = now;
+        }
+        _ => return,
+    }
+
+    env.storage().persistent().set(&key, &downtime);
+}
@@ -1,6 +1,3 @@
-#![cfg(not(tarpaulin_include))]
-#![allow(unexpected_cfgs)]
-use soroban_sdk::{Env, Symbol};
 //! Reentrancy protection for same-transaction nested calls.
 //!
 //! Soroban executes contract invocations synchronously within a single invocation tree. A
@@ -11,6 +8,17 @@ use soroban_sdk::{Env, Symbol};
 //! The guard does not persist across transactions and does not replace authorization,
 //! pause-switch, or collateral checks. It is a defense-in-depth layer for fund-moving entry
 //! points that perform external contract calls.
+//!
+//! ## Protected Entry Points
+//! [`crate::deposit::deposit_collateral`], [`crate::withdraw::withdraw_collateral`],
+//! [`crate::borrow::borrow_asset`], [`crate::repay::repay_debt`],
+//! [`crate::repay::repay_with_collateral`] and [`crate::liquidate::liquidate`] acquire the
+//! guard before their first external call and hold it until they return, so a token callback
+//! cannot enter any of them, or another one, while state is half-updated. Each maps a held lock
+//! to its own `Reentrancy` error variant.
+
+#![cfg(not(tarpaulin_include))]
+#![allow(unexpected_cfgs)]
 
 use soroban_sdk::{contracttype, Env};
 
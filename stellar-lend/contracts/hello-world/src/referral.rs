@@ -0,0 +1,259 @@
+//! # Referral Module
+//!
+//! Tracks which referrer brought each user to the protocol and pays
+//! referrers a share of the fees their users generate.
+//!
+//! A user is attributed to a referrer by the first deposit or borrow that
+//! names one (`deposit_collateral_with_referrer` or
+//! `borrow_asset_with_referrer`). The attribution is permanent: later calls
+//! naming another referrer, and self-referrals, are ignored. From then on
+//! every deposit and borrow by the user adds to the referrer's volume in
+//! [`ReferralStats`], whichever entrypoint is used.
+//!
+//! ## Rewards
+//! When a referred user borrows, [`get_referral_share_bps`] of the treasury
+//! part of the origination fee (what is left after the insurance share) is
+//! set aside for the referrer in the borrowed asset instead of being credited
+//! to reserves. Referrers withdraw it with [`claim_referral_rewards`].
+//!
+//! ## Security
+//! - The share is only set through an executed governance proposal, is
+//!   capped at [`MAX_REFERRAL_SHARE_BPS`] and defaults to 0.
+//! - Rewards are carved out of protocol fees only; user funds are never
+//!   touched.
+//! - Claims require the referrer's authorization.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+/// Highest share of the treasury fee paid to referrers (50%)
+pub const MAX_REFERRAL_SHARE_BPS: i128 = 5_000;
+
+/// Errors that can occur in referral operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReferralError {
+    /// Share is negative or above `MAX_REFERRAL_SHARE_BPS`
+    InvalidShare = 1,
+    /// Referrer has no rewards to claim in the asset
+    NoRewards = 2,
+    /// Pool and strategy together cannot cover the transfer
+    InsufficientLiquidity = 3,
+}
+
+/// Storage keys for referral data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum ReferralDataKey {
+    /// Share of the treasury fee paid to referrers in basis points: i128
+    ShareBps,
+    /// Referrer a user is attributed to: Address
+    Referrer(Address),
+    /// Volume brought in by a referrer: ReferralStats
+    Stats(Address),
+    /// Unclaimed rewards of a referrer per asset: i128
+    Rewards(Address, Option<Address>),
+    /// Unclaimed rewards across all referrers per asset: i128
+    TotalRewards(Option<Address>),
+}
+
+/// Activity of the users attributed to a referrer, summed over all assets in
+/// their own units
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReferralStats {
+    /// Users attributed to the referrer
+    pub referred_users: u32,
+    /// Collateral deposited by referred users
+    pub deposit_volume: i128,
+    /// Amount borrowed by referred users
+    pub borrow_volume: i128,
+}
+
+/// Set the share of the treasury fee paid to referrers
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `ReferralError::InvalidShare` - If outside `[0, MAX_REFERRAL_SHARE_BPS]`
+#[allow(deprecated)]
+pub fn set_referral_share_bps(env: &Env, share_bps: i128) -> Result<(), ReferralError> {
+    if !(0..=MAX_REFERRAL_SHARE_BPS).contains(&share_bps) {
+        return Err(ReferralError::InvalidShare);
+    }
+
+    let old_share_bps = get_referral_share_bps(env);
+    env.storage()
+        .persistent()
+        .set(&ReferralDataKey::ShareBps, &share_bps);
+
+    let topics = (Symbol::new(env, "referral_share_updated"),);
+    env.events().publish(topics, share_bps);
+    crate::events::emit_param_change(env, "referral_share_bps", None, old_share_bps, share_bps);
+
+    Ok(())
+}
+
+/// Share of the treasury fee paid to referrers in basis points (0 if unset)
+pub fn get_referral_share_bps(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ReferralDataKey::ShareBps)
+        .unwrap_or(0)
+}
+
+/// Attribute `user` to `referrer` unless already attributed
+///
+/// Self-referrals and `None` are ignored.
+#[allow(deprecated)]
+pub fn attribute(env: &Env, user: &Address, referrer: Option<Address>) {
+    let referrer = match referrer {
+        Some(referrer) if referrer != *user => referrer,
+        _ => return,
+    };
+    let key = ReferralDataKey::Referrer(user.clone());
+    if env.storage().persistent().has(&key) {
+        return;
+    }
+    env.storage().persistent().set(&key, &referrer);
+
+    let mut stats = get_referral_stats(env, &referrer);
+    stats.referred_users = stats.referred_users.saturating_add(1);
+    save_stats(env, &referrer, &stats);
+
+    let topics = (Symbol::new(env, "referral_attributed"), referrer);
+    env.events().publish(topics, user.clone());
+}
+
+/// Referrer `user` is attributed to, if any
+pub fn get_referrer(env: &Env, user: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&ReferralDataKey::Referrer(user.clone()))
+}
+
+/// Add a deposit by `user` to their referrer's volume
+pub fn record_deposit(env: &Env, user: &Address, amount: i128) {
+    if let Some(referrer) = get_referrer(env, user) {
+        let mut stats = get_referral_stats(env, &referrer);
+        stats.deposit_volume = stats.deposit_volume.saturating_add(amount);
+        save_stats(env, &referrer, &stats);
+    }
+}
+
+/// Add a borrow by `user` to their referrer's volume and set aside the
+/// referrer's part of `treasury_fee`
+///
+/// # Returns
+/// The part of `treasury_fee` owed to the referrer (0 for unreferred users)
+pub fn record_borrow(
+    env: &Env,
+    user: &Address,
+    asset: Option<Address>,
+    amount: i128,
+    treasury_fee: i128,
+) -> i128 {
+    let referrer = match get_referrer(env, user) {
+        Some(referrer) => referrer,
+        None => return 0,
+    };
+    let mut stats = get_referral_stats(env, &referrer);
+    stats.borrow_volume = stats.borrow_volume.saturating_add(amount);
+    save_stats(env, &referrer, &stats);
+
+    let reward = treasury_fee
+        .saturating_mul(get_referral_share_bps(env))
+        .saturating_div(10_000);
+    if reward > 0 {
+        let key = ReferralDataKey::Rewards(referrer, asset.clone());
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&key, &balance.saturating_add(reward));
+        let total = get_total_referral_rewards(env, asset.clone()).saturating_add(reward);
+        set_total_rewards(env, &asset, total);
+    }
+    reward
+}
+
+/// Volume brought in by `referrer`
+pub fn get_referral_stats(env: &Env, referrer: &Address) -> ReferralStats {
+    env.storage()
+        .persistent()
+        .get(&ReferralDataKey::Stats(referrer.clone()))
+        .unwrap_or_default()
+}
+
+/// Unclaimed rewards of `referrer` in `asset`
+pub fn get_referral_rewards(env: &Env, referrer: &Address, asset: Option<Address>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ReferralDataKey::Rewards(referrer.clone(), asset))
+        .unwrap_or(0)
+}
+
+/// Unclaimed rewards in `asset` across all referrers
+pub fn get_total_referral_rewards(env: &Env, asset: Option<Address>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ReferralDataKey::TotalRewards(asset))
+        .unwrap_or(0)
+}
+
+fn set_total_rewards(env: &Env, asset: &Option<Address>, total: i128) {
+    env.storage()
+        .persistent()
+        .set(&ReferralDataKey::TotalRewards(asset.clone()), &total);
+}
+
+/// Pay out the rewards `referrer` has accrued in `asset`
+///
+/// # Returns
+/// The amount paid
+///
+/// # Errors
+/// * `ReferralError::NoRewards` - If nothing has accrued in `asset`
+/// * `ReferralError::InsufficientLiquidity` - If pool and strategy cannot cover the rewards
+#[allow(deprecated)]
+pub fn claim_referral_rewards(
+    env: &Env,
+    referrer: Address,
+    asset: Option<Address>,
+) -> Result<i128, ReferralError> {
+    referrer.require_auth();
+
+    let amount = get_referral_rewards(env, &referrer, asset.clone());
+    if amount <= 0 {
+        return Err(ReferralError::NoRewards);
+    }
+    env.storage()
+        .persistent()
+        .remove(&ReferralDataKey::Rewards(referrer.clone(), asset.clone()));
+    let total = get_total_referral_rewards(env, asset.clone())
+        .saturating_sub(amount)
+        .max(0);
+    set_total_rewards(env, &asset, total);
+
+    #[cfg(not(test))]
+    {
+        if let Some(ref asset_addr) = asset {
+            crate::strategy::ensure_liquidity(env, asset_addr, amount)
+                .map_err(|_| ReferralError::InsufficientLiquidity)?;
+            let token_client = soroban_sdk::token::Client::new(env, asset_addr);
+            token_client.transfer(&env.current_contract_address(), &referrer, &amount);
+        }
+    }
+
+    let topics = (Symbol::new(env, "referral_rewards_claimed"), referrer);
+    env.events().publish(topics, (asset, amount));
+
+    Ok(amount)
+}
+
+fn save_stats(env: &Env, referrer: &Address, stats: &ReferralStats) {
+    env.storage()
+        .persistent()
+        .set(&ReferralDataKey::Stats(referrer.clone()), stats);
+}
@@ -9,15 +9,31 @@
 //! 1. Accrued interest is paid first.
 //! 2. Any remaining repayment amount reduces the principal debt.
 //!
+//! ## Repaying Everything
+//! Passing [`REPAY_ALL`] (`i128::MAX`) as the amount repays the whole debt,
+//! including interest accrued in the same call. Any amount above the debt is
+//! capped the same way, so no dust is left behind by interest that accrued
+//! between quoting and executing.
+//!
 //! ## Dust Handling
 //! When the remaining debt (principal + interest) becomes very small (less than
 //! DUST_THRESHOLD), it is automatically zeroed out to prevent precision issues
 //! and ensure clean final states.
 //!
+//! ## Repaying with Collateral
+//! [`repay_with_collateral`] lets a borrower close debt with their own
+//! collateral instead of the debt asset. The collateral is valued against the
+//! debt at oracle prices, rounded in the protocol's favour, and stays in the
+//! contract in place of the repaid tokens; no swap is made. Positions that are
+//! already liquidatable cannot use it, so they cannot sidestep the
+//! liquidation incentive.
+//!
 //! ## Invariants
 //! - Repay amount must be strictly positive.
 //! - User must have outstanding debt to repay.
-//! - Token transfers use `transfer_from`, requiring prior user approval.
+//! - Token transfers use `transfer_from`, requiring prior user approval; the
+//!   user's balance and allowance are checked first so a short repayment fails
+//!   with a typed error rather than a token contract panic.
 //! - Events reflect actual processed amounts, ensuring alignment with final state.
 
 #![allow(unused)]
@@ -28,7 +44,10 @@ use crate::deposit::{
     emit_user_activity_tracked_event, update_protocol_analytics, update_user_analytics, Activity,
     DepositDataKey, Position, ProtocolAnalytics, UserAnalytics,
 };
-use crate::events::{emit_repay, RepayEvent};
+use crate::events::{emit_repay, emit_repay_v1, RepayEvent, RepayEventV1, EVENT_SCHEMA_VERSION};
+
+/// Amount that repays the whole outstanding debt, interest included
+pub const REPAY_ALL: i128 = i128::MAX;
 
 /// Dust threshold for debt cleanup
 /// When total debt (principal + interest) falls below this amount, it's zeroed out
@@ -53,68 +72,53 @@ pub enum RepayError {
     Overflow = 6,
     /// Reentrancy detected
     Reentrancy = 7,
-}
-
-/// Calculate interest accrued since last accrual time
-///
-/// Uses dynamic interest rate based on current protocol utilization.
-///
-/// # Arguments
-/// * `env` - The Soroban environment
-/// * `principal` - The principal amount to calculate interest on
-/// * `last_accrual_time` - The timestamp of the last interest accrual
-/// * `current_time` - The current ledger timestamp
-///
-/// # Returns
-/// * `Result<i128, RepayError>` - The accrued interest amount or an error
-fn calculate_accrued_interest(
-    env: &Env,
-    principal: i128,
-    last_accrual_time: u64,
-    current_time: u64,
-) -> Result<i128, RepayError> {
-    if principal == 0 {
-        return Ok(0);
-    }
-    if current_time <= last_accrual_time {
-        return Ok(0);
-    }
-    let rate_bps =
-        crate::interest_rate::calculate_borrow_rate(env).map_err(|_| RepayError::Overflow)?;
-    crate::interest_rate::calculate_accrued_interest(
-        principal,
-        last_accrual_time,
-        current_time,
-        rate_bps,
-    )
-    .map_err(|_| RepayError::Overflow)
+    /// Not enough collateral of the asset to cover the repayment
+    InsufficientCollateral = 8,
+    /// A priced asset has no fresh price
+    PriceNotAvailable = 9,
+    /// Position is liquidatable and must go through liquidation
+    Liquidatable = 10,
+    /// User has not approved the protocol to pull the repayment
+    InsufficientAllowance = 11,
 }
 
 /// Accrue interest on a position
 ///
-/// Updates the position's borrow_interest and last_accrual_time based on elapsed time
-/// and the current interest rate.
+/// Updates the position's borrow_interest and last_accrual_time from the growth of
+/// its assets' borrow indexes, then applies any due capitalization.
 ///
 /// # Arguments
 /// * `env` - The Soroban environment
+/// * `user` - The borrower
 /// * `position` - A mutable reference to the user's position
 ///
 /// # Returns
 /// * `Result<(), RepayError>` - Success or an error
-fn accrue_interest(env: &Env, position: &mut Position) -> Result<(), RepayError> {
+pub(crate) fn accrue_interest(
+    env: &Env,
+    user: &Address,
+    position: &mut Position,
+) -> Result<(), RepayError> {
     let current_time = env.ledger().timestamp();
+    let new_interest = crate::interest_rate::accrue_position_interest(
+        env,
+        user,
+        position.debt,
+        position.last_accrual_time,
+    )
+    .map_err(|_| RepayError::Overflow)?;
     if position.debt == 0 {
         position.borrow_interest = 0;
         position.last_accrual_time = current_time;
+        crate::capitalization::capitalize(env, user, position).map_err(|_| RepayError::Overflow)?;
         return Ok(());
     }
-    let new_interest =
-        calculate_accrued_interest(env, position.debt, position.last_accrual_time, current_time)?;
     position.borrow_interest = position
         .borrow_interest
         .checked_add(new_interest)
         .ok_or(RepayError::Overflow)?;
     position.last_accrual_time = current_time;
+    crate::capitalization::capitalize(env, user, position).map_err(|_| RepayError::Overflow)?;
     Ok(())
 }
 
@@ -132,6 +136,56 @@ fn get_native_asset_address(env: &Env) -> Result<Address, RepayError> {
         .ok_or(RepayError::InvalidAsset)
 }
 
+/// Debt `user` could repay right now, interest accrued up to now included
+///
+/// Larger repayments are accepted and settle exactly this amount. Returns
+/// `0` whenever the repayment would be rejected outright: repayments paused,
+/// no debt, or native XLM repaid before its token address is configured.
+///
+/// # Errors
+/// * `RepayError::Overflow` - If interest accrual overflows
+pub fn get_max_repayable(
+    env: &Env,
+    user: &Address,
+    asset: Option<Address>,
+) -> Result<i128, RepayError> {
+    let repay_switch_on = crate::hot_config::load(env)
+        .is_switch_on(env, crate::risk_management::PauseOperation::Repay);
+    let legacy_paused = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Map<Symbol, bool>>(&DepositDataKey::PauseSwitches)
+        .and_then(|pause_map| pause_map.get(Symbol::new(env, "pause_repay")))
+        .unwrap_or(false);
+    let valid_asset = match &asset {
+        Some(addr) => addr != &env.current_contract_address(),
+        None => get_native_asset_address(env).is_ok(),
+    };
+    if repay_switch_on || legacy_paused || !valid_asset {
+        return Ok(0);
+    }
+
+    let Some(position) = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Position>(&DepositDataKey::Position(user.clone()))
+    else {
+        return Ok(0);
+    };
+    let pending = crate::borrow::calculate_accrued_interest(
+        env,
+        user,
+        position.debt,
+        position.last_accrual_time,
+    )
+    .map_err(|_| RepayError::Overflow)?;
+    position
+        .debt
+        .checked_add(position.borrow_interest)
+        .and_then(|v| v.checked_add(pending))
+        .ok_or(RepayError::Overflow)
+}
+
 /// Repay debt function
 ///
 /// Allows users to repay their borrowed assets, reducing debt and accrued interest.
@@ -146,7 +200,7 @@ fn get_native_asset_address(env: &Env) -> Result<Address, RepayError> {
 /// * `env` - The Soroban environment
 /// * `user` - The address of the user repaying debt
 /// * `asset` - The address of the asset contract to repay (None for native XLM)
-/// * `amount` - The amount to repay
+/// * `amount` - The amount to repay, or [`REPAY_ALL`] for the whole debt
 ///
 /// # Returns
 /// Returns a tuple `(remaining_debt, interest_paid, principal_paid)` upon successful execution.
@@ -155,6 +209,8 @@ fn get_native_asset_address(env: &Env) -> Result<Address, RepayError> {
 /// * `RepayError::InvalidAmount` - If amount is zero or negative
 /// * `RepayError::InvalidAsset` - If asset address is invalid or not configured
 /// * `RepayError::InsufficientBalance` - If user doesn't have enough balance
+/// * `RepayError::InsufficientAllowance` - If user has not approved the protocol for the
+///   amount repaid
 /// * `RepayError::RepayPaused` - If repayments are paused
 /// * `RepayError::NoDebt` - If user has no debt to repay
 /// * `RepayError::Overflow` - If calculation overflow occurs
@@ -178,7 +234,14 @@ pub fn repay_debt(
     let _guard =
         crate::reentrancy::ReentrancyGuard::new(env).map_err(|_| RepayError::Reentrancy)?;
 
-    // Check if repayments are paused
+    // Check the repay switch; the emergency pause deliberately leaves
+    // repayments open so borrowers can reduce risk
+    if crate::hot_config::load(env).is_switch_on(env, crate::risk_management::PauseOperation::Repay)
+    {
+        return Err(RepayError::RepayPaused);
+    }
+
+    // Legacy pause switches
     let pause_switches_key = DepositDataKey::PauseSwitches;
     if let Some(pause_map) = env
         .storage()
@@ -194,6 +257,11 @@ pub fn repay_debt(
 
     let timestamp = env.ledger().timestamp();
 
+    // Checkpoint the asset indexes and settle supply interest before utilization changes
+    crate::interest_rate::accrue_asset(env, asset.clone()).map_err(|_| RepayError::Overflow)?;
+    crate::supply_interest::settle_supply_interest(env, &user, asset.clone())
+        .map_err(|_| RepayError::Overflow)?;
+
     // Determine the asset contract address to use
     let asset_addr = match &asset {
         Some(addr) => {
@@ -205,21 +273,6 @@ pub fn repay_debt(
         None => get_native_asset_address(env)?,
     };
 
-    let reserve_factor = if let Some(asset_addr) = asset.as_ref() {
-        let params_key = DepositDataKey::AssetParams(asset_addr.clone());
-        if let Some(params) = env
-            .storage()
-            .persistent()
-            .get::<DepositDataKey, crate::deposit::AssetParams>(&params_key)
-        {
-            params.borrow_fee_bps.max(1000) // Use asset-specific fee or default 10%
-        } else {
-            1000 // Default 10%
-        }
-    } else {
-        1000 // Default 10%
-    };
-
     // Get user position
     let position_key = DepositDataKey::Position(user.clone());
     let mut position = env
@@ -233,14 +286,15 @@ pub fn repay_debt(
     }
 
     // Accrue interest before repayment
-    accrue_interest(env, &mut position)?;
+    accrue_interest(env, &user, &mut position)?;
 
     let total_debt = position
         .debt
         .checked_add(position.borrow_interest)
         .ok_or(RepayError::Overflow)?;
 
-    let repay_amount = if amount >= total_debt {
+    // REPAY_ALL, or any amount above the debt, settles the debt exactly
+    let repay_amount = if amount == REPAY_ALL || amount >= total_debt {
         total_debt
     } else {
         amount
@@ -254,27 +308,28 @@ pub fn repay_debt(
         position.borrow_interest
     };
 
-    let principal_paid = actual_repay_amount
+    let principal_paid = repay_amount
         .checked_sub(interest_paid)
         .ok_or(RepayError::Overflow)?;
 
-    // Handle asset transfer - user pays the contract 
+    // Handle asset transfer - user pays the contract
     // Uses standardized SRC-20 transfer format requiring pre-authorization
-    #[cfg(not(test))]
-    {
-        let token_client = soroban_sdk::token::Client::new(env, &asset_addr);
-        let user_balance = token_client.balance(&user);
-        if user_balance < repay_amount {
-            return Err(RepayError::InsufficientBalance);
-        }
-
-        token_client.transfer_from(
-            &env.current_contract_address(), // spender
-            &user,                           // from
-            &env.current_contract_address(), // to
-            &repay_amount,
-        );
+    let token_client = soroban_sdk::token::Client::new(env, &asset_addr);
+    let user_balance = token_client.balance(&user);
+    if user_balance < repay_amount {
+        return Err(RepayError::InsufficientBalance);
     }
+    let allowance = token_client.allowance(&user, &env.current_contract_address());
+    if allowance < repay_amount {
+        return Err(RepayError::InsufficientAllowance);
+    }
+
+    token_client.transfer_from(
+        &env.current_contract_address(), // spender
+        &user,                           // from
+        &env.current_contract_address(), // to
+        &repay_amount,
+    );
 
     // Update position ensuring no underflow during integer truncation
     position.borrow_interest = position
@@ -291,33 +346,23 @@ pub fn repay_debt(
 
     // Save final updated position state
     env.storage().persistent().set(&position_key, &position);
+    crate::asset_positions::record_repayment(env, &user, asset.clone(), principal_paid);
 
-    // Apply portion of paid interest to protocol reserves
-    if interest_paid > 0 {
-        let reserve_amount = interest_paid
-            .checked_mul(reserve_factor)
-            .ok_or(RepayError::Overflow)?
-            .checked_div(10000)
-            .unwrap_or(0); // Floor rounding bounds protocol take to >= 0
-            
-        if reserve_amount > 0 {
-            let reserve_key = DepositDataKey::ProtocolReserve(asset.clone());
-            let current_reserve = env
-                .storage()
-                .persistent()
-                .get::<DepositDataKey, i128>(&reserve_key)
-                .unwrap_or(0);
-            env.storage().persistent().set(
-                &reserve_key,
-                &(current_reserve
-                    .checked_add(reserve_amount)
-                    .ok_or(RepayError::Overflow)?),
-            );
-        }
-    }
+    // Apply the asset's reserve factor to the paid interest
+    let (interest_reserve, _) = crate::reserve::accrue_reserve(env, asset.clone(), interest_paid)
+        .map_err(|_| RepayError::Overflow)?;
+
+    crate::revenue::record(
+        env,
+        asset.clone(),
+        crate::revenue::RevenueSource::Interest,
+        interest_reserve,
+    );
 
     update_user_analytics_repay(env, &user, repay_amount, timestamp)?;
     update_protocol_analytics_repay(env, repay_amount)?;
+    let repay_value = crate::oracle::ratio_value(env, asset.as_ref(), repay_amount).unwrap_or(0);
+    crate::credit_history::record_repayment(env, &user, repay_value, position.borrow_interest == 0);
     
     // Add to activity log tracking for metrics
     add_activity_log(
@@ -338,13 +383,29 @@ pub fn repay_debt(
         timestamp,
     };
     log_repay(env, event);
+    emit_repay_v1(
+        env,
+        RepayEventV1 {
+            schema_version: EVENT_SCHEMA_VERSION,
+            user: user.clone(),
+            asset: asset.clone(),
+            amount: repay_amount,
+            interest_paid,
+            principal_paid,
+            collateral_after: position.collateral,
+            total_debt_after: position.debt.saturating_add(position.borrow_interest),
+            timestamp,
+        },
+    );
     emit_position_updated_event(env, &user, &position);
-    emit_analytics_updated_event(env, &user, "repay", final_repay_amount, timestamp);
+    crate::ttl::bump_position(env, &user);
+    crate::analytics::emit_health_factor_changed(env, &user, Symbol::new(env, "repay"));
+    emit_analytics_updated_event(env, &user, "repay", repay_amount, timestamp);
     emit_user_activity_tracked_event(
         env,
         &user,
         Symbol::new(env, "repay"),
-        final_repay_amount,
+        repay_amount,
         timestamp,
     );
 
@@ -356,6 +417,212 @@ pub fn repay_debt(
     Ok((remaining_debt, interest_paid, principal_paid))
 }
 
+/// Repay debt with the user's own collateral
+///
+/// Accrues interest, caps `amount` at the outstanding debt and removes
+/// collateral of `collateral_asset` worth the repaid amount at oracle prices.
+/// Interest is paid first, then principal, exactly as in [`repay_debt`].
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `user` - The borrower, who must authorize the call
+/// * `debt_asset` - The asset being repaid (None for native asset)
+/// * `collateral_asset` - The collateral used to pay (None for native asset)
+/// * `amount` - Maximum amount of `debt_asset` to repay; [`REPAY_ALL`] repays
+///   the whole debt
+///
+/// # Returns
+/// Tuple of (remaining_debt, collateral_used)
+///
+/// # Errors
+/// * `RepayError::InvalidAmount` - If amount <= 0
+/// * `RepayError::InvalidAsset` - If either asset is the contract itself
+/// * `RepayError::RepayPaused` - If repayments or withdrawals are paused
+/// * `RepayError::NoDebt` - If the user has no debt
+/// * `RepayError::Liquidatable` - If the position is liquidatable
+/// * `RepayError::InsufficientCollateral` - If the user holds too little of
+///   `collateral_asset`
+/// * `RepayError::PriceNotAvailable` - If a priced asset has no fresh price
+/// * `RepayError::Overflow` - If arithmetic overflow occurs
+pub fn repay_with_collateral(
+    env: &Env,
+    user: Address,
+    debt_asset: Option<Address>,
+    collateral_asset: Option<Address>,
+    amount: i128,
+) -> Result<(i128, i128), RepayError> {
+    if amount <= 0 {
+        return Err(RepayError::InvalidAmount);
+    }
+    user.require_auth();
+
+    let _guard =
+        crate::reentrancy::ReentrancyGuard::new(env).map_err(|_| RepayError::Reentrancy)?;
+
+    // Collateral leaves the position, so the withdraw pause applies as well
+    let config = crate::hot_config::load(env);
+    if config.is_switch_on(env, crate::risk_management::PauseOperation::Repay)
+        || config.is_paused(env, crate::risk_management::PauseOperation::Withdraw)
+    {
+        return Err(RepayError::RepayPaused);
+    }
+
+    let contract = env.current_contract_address();
+    if debt_asset.as_ref() == Some(&contract) || collateral_asset.as_ref() == Some(&contract) {
+        return Err(RepayError::InvalidAsset);
+    }
+
+    let timestamp = env.ledger().timestamp();
+    for asset in [debt_asset.clone(), collateral_asset.clone()] {
+        crate::interest_rate::accrue_asset(env, asset.clone()).map_err(|_| RepayError::Overflow)?;
+        crate::supply_interest::settle_supply_interest(env, &user, asset)
+            .map_err(|_| RepayError::Overflow)?;
+    }
+
+    let position_key = DepositDataKey::Position(user.clone());
+    let mut position = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Position>(&position_key)
+        .ok_or(RepayError::NoDebt)?;
+    accrue_interest(env, &user, &mut position)?;
+
+    let total_debt = position
+        .debt
+        .checked_add(position.borrow_interest)
+        .ok_or(RepayError::Overflow)?;
+    if total_debt == 0 {
+        return Err(RepayError::NoDebt);
+    }
+
+    let collateral_key = DepositDataKey::CollateralBalance(user.clone());
+    let current_collateral: i128 = env
+        .storage()
+        .persistent()
+        .get(&collateral_key)
+        .unwrap_or(0);
+
+    let value = crate::asset_positions::position_value(
+        env,
+        &user,
+        current_collateral,
+        position.debt,
+        position.borrow_interest,
+    )
+    .map_err(|_| RepayError::PriceNotAvailable)?;
+    let debt_value = value
+        .debt
+        .checked_add(value.interest)
+        .ok_or(RepayError::Overflow)?;
+    if crate::risk_params::can_liquidate_position(env, &user, value.collateral, debt_value)
+        .unwrap_or(false)
+    {
+        return Err(RepayError::Liquidatable);
+    }
+
+    // Value the repayment, then round the collateral needed up
+    let repay_amount = amount.min(total_debt);
+    let repay_value = crate::oracle::ratio_value(env, debt_asset.as_ref(), repay_amount)
+        .map_err(|_| RepayError::PriceNotAvailable)?;
+    let mut collateral_used =
+        crate::oracle::ratio_amount(env, collateral_asset.as_ref(), repay_value)
+            .map_err(|_| RepayError::PriceNotAvailable)?;
+    if crate::oracle::ratio_value(env, collateral_asset.as_ref(), collateral_used)
+        .map_err(|_| RepayError::PriceNotAvailable)?
+        < repay_value
+    {
+        collateral_used = collateral_used.checked_add(1).ok_or(RepayError::Overflow)?;
+    }
+    if collateral_used > current_collateral {
+        return Err(RepayError::InsufficientCollateral);
+    }
+
+    let new_collateral = current_collateral - collateral_used;
+    crate::asset_positions::record_withdrawal(
+        env,
+        &user,
+        collateral_asset.clone(),
+        collateral_used,
+        current_collateral,
+    )
+    .map_err(|_| RepayError::InsufficientCollateral)?;
+    env.storage()
+        .persistent()
+        .set(&collateral_key, &new_collateral);
+
+    // Interest first, then principal
+    let interest_paid = repay_amount.min(position.borrow_interest);
+    let principal_paid = repay_amount - interest_paid;
+    position.borrow_interest -= interest_paid;
+    position.debt -= principal_paid;
+    position.collateral = new_collateral;
+    position.last_accrual_time = timestamp;
+    env.storage().persistent().set(&position_key, &position);
+    crate::asset_positions::record_repayment(env, &user, debt_asset.clone(), principal_paid);
+
+    let (interest_reserve, _) =
+        crate::reserve::accrue_reserve(env, debt_asset.clone(), interest_paid)
+            .map_err(|_| RepayError::Overflow)?;
+    crate::revenue::record(
+        env,
+        debt_asset.clone(),
+        crate::revenue::RevenueSource::Interest,
+        interest_reserve,
+    );
+
+    update_user_analytics_repay(env, &user, repay_amount, timestamp)?;
+    update_protocol_analytics_repay(env, repay_amount)?;
+    crate::credit_history::record_repayment(env, &user, repay_value, position.borrow_interest == 0);
+    add_activity_log(
+        env,
+        &user,
+        Symbol::new(env, "repay_with_collateral"),
+        repay_amount,
+        debt_asset.clone(),
+        timestamp,
+    )
+    .map_err(|_| RepayError::Overflow)?;
+
+    log_repay(
+        env,
+        RepayEvent {
+            user: user.clone(),
+            asset: debt_asset.clone(),
+            amount: repay_amount,
+            timestamp,
+        },
+    );
+    emit_repay_v1(
+        env,
+        RepayEventV1 {
+            schema_version: EVENT_SCHEMA_VERSION,
+            user: user.clone(),
+            asset: debt_asset.clone(),
+            amount: repay_amount,
+            interest_paid,
+            principal_paid,
+            collateral_after: position.collateral,
+            total_debt_after: position.debt.saturating_add(position.borrow_interest),
+            timestamp,
+        },
+    );
+    let operation = Symbol::new(env, "repay_with_collateral");
+    emit_position_updated_event(env, &user, &position, operation.clone(), timestamp);
+    crate::ttl::bump_position(env, &user);
+    crate::analytics::emit_health_factor_changed(env, &user, operation);
+    #[allow(deprecated)]
+    env.events().publish(
+        (Symbol::new(env, "repaid_with_collateral"), user),
+        (debt_asset, repay_amount, collateral_asset, collateral_used),
+    );
+
+    let remaining_debt = position
+        .debt
+        .checked_add(position.borrow_interest)
+        .ok_or(RepayError::Overflow)?;
+    Ok((remaining_debt, collateral_used))
+}
+
 /// Update user analytics after repayment
 ///
 /// # Arguments
@@ -372,6 +639,9 @@ fn update_user_analytics_repay(
     amount: i128,
     timestamp: u64,
 ) -> Result<(), RepayError> {
+    if !crate::analytics::tracks_user_activity(env) {
+        return Ok(());
+    }
     let analytics_key = DepositDataKey::UserAnalytics(user.clone());
     let mut analytics = env
         .storage()
@@ -411,6 +681,7 @@ fn update_user_analytics_repay(
 
     analytics.transaction_count = analytics.transaction_count.saturating_add(1);
     analytics.last_activity = timestamp;
+    analytics.loyalty_tier = crate::loyalty::tier_for(env, &analytics, timestamp);
 
     env.storage().persistent().set(&analytics_key, &analytics);
     Ok(())
@@ -424,7 +695,7 @@ fn update_user_analytics_repay(
 ///
 /// # Returns
 /// * `Result<(), RepayError>` - Success or an error
-fn update_protocol_analytics_repay(env: &Env, amount: i128) -> Result<(), RepayError> {
+pub(crate) fn update_protocol_analytics_repay(env: &Env, amount: i128) -> Result<(), RepayError> {
     let analytics_key = DepositDataKey::ProtocolAnalytics;
     let mut analytics = env
         .storage()
@@ -19,17 +19,57 @@
 //! - Reserves accrue automatically when interest is calculated during repayment
 //! - Formula: `reserve_amount = total_interest * reserve_factor / 10000`
 //! - Tracked separately per asset in persistent storage
+//! - Borrow and flash-loan fees are credited to the older
+//!   `DepositDataKey::ProtocolReserve` bucket; [`get_reserves`] reports both
 //!
 //! ### Treasury Withdrawal
 //! - Admin can withdraw accrued reserves to a treasury address
 //! - Withdrawals are bounded by the actual reserve balance
 //! - Cannot withdraw user funds (collateral or principal)
+//! - [`withdraw_reserves`] lets the admin, or an executed `WithdrawReserves`
+//!   proposal, send reserves to any address; it draws on fee reserves first
 //! - All withdrawals are logged via events
 //!
 //! ## Storage Layout
 //! - `ReserveBalance(asset)` — accumulated reserve per asset
 //! - `ReserveFactor(asset)` — reserve factor per asset (basis points)
 //! - `TreasuryAddress` — destination address for reserve withdrawals
+//! - `ProtocolOwnedLiquidity(asset)` — reserves seeded back into the pool by governance
+//! - `ProtocolOwnedLiquidityAssets` — assets that have received protocol-owned liquidity;
+//!   amounts are only summed across assets after valuing them at oracle prices
+//!
+//! ### Protocol-Owned Liquidity
+//! - Governance can move accrued reserves into the lending pool as protocol-owned
+//!   liquidity (POL) via a `SeedProtocolLiquidity` proposal
+//! - POL counts towards pool deposits (and so earns supply yield) but is not tied
+//!   to any user position, so no user can withdraw it
+//! - POL is tracked separately so analytics can distinguish user vs protocol TVL
+//!
+//! ### Insurance Fund
+//! - Governance sets a share (0 - 5000 bps) of every liquidation incentive that
+//!   is withheld from the liquidator and credited to the insurance fund via a
+//!   `LiquidationInsuranceShare` proposal
+//! - The withheld collateral stays in the contract and is tracked per asset
+//! - An `InsuranceFeeShare` proposal also routes a share of the reserve cut of
+//!   interest and of borrow fees into the fund (see the insurance module)
+//! - The fund is drawn on first when bad debt is written off
+//!
+//! ### Emergency Repay
+//! - When liquidating a borrower would realize a large loss (e.g. during an
+//!   oracle outage), the admin can repay the borrower's debt from reserves
+//! - The amount is recorded as a receivable owed by the borrower, who can pay
+//!   it back into reserves later
+//!
+//! ### Debt Forgiveness
+//! - A `ForgiveDebt` governance proposal repays a borrower's debt from
+//!   reserves the same way, but without a receivable (e.g. to make a borrower
+//!   whole after a protocol bug)
+//!
+//! ### Donations
+//! - Anyone can `donate` tokens (e.g. ecosystem grants, penalty payments)
+//!   straight into the reserve balance or the insurance fund
+//! - The tokens are transferred in and credited in one step, so donations never
+//!   show up as unexplained surplus in reconciliation
 //!
 //! ## Security Invariants
 //! - Reserve factor must be between 0 and 5000 bps (0% - 50%)
@@ -39,9 +79,10 @@
 //! - All state changes emit events for transparency and auditability
 
 #![allow(unused)]
-use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
 
-use crate::deposit::DepositDataKey;
+use crate::admin::Role;
+use crate::deposit::{DepositDataKey, Position};
 
 /// Maximum allowed reserve factor (50% = 5000 basis points)
 /// This ensures that at least 50% of interest always goes to lenders
@@ -53,6 +94,10 @@ pub const DEFAULT_RESERVE_FACTOR_BPS: i128 = 1000;
 /// Basis points scale (100% = 10000 basis points)
 pub const BASIS_POINTS_SCALE: i128 = 10000;
 
+/// Maximum share of the liquidation incentive routed to insurance (50%)
+/// This ensures liquidators always keep most of the incentive
+pub const MAX_LIQUIDATION_INSURANCE_SHARE_BPS: i128 = 5000;
+
 /// Errors that can occur during reserve and treasury operations
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -74,6 +119,14 @@ pub enum ReserveError {
     Overflow = 7,
     /// Treasury address not configured
     TreasuryNotSet = 8,
+    /// Insurance share exceeds maximum allowed value
+    InvalidInsuranceShare = 9,
+    /// Borrower has no debt to repay
+    NoDebt = 10,
+    /// Borrower has no outstanding receivable
+    NoReceivable = 11,
+    /// Pool and strategy together cannot cover the transfer
+    InsufficientLiquidity = 12,
 }
 
 /// Storage keys for reserve and treasury data
@@ -90,6 +143,30 @@ pub enum ReserveDataKey {
     /// Treasury address: TreasuryAddress -> Address
     /// Destination for reserve withdrawals
     TreasuryAddress,
+    /// Protocol-owned liquidity per asset: ProtocolOwnedLiquidity(asset) -> i128
+    /// Reserves seeded into the pool through governance
+    ProtocolOwnedLiquidity(Option<Address>),
+    /// Assets that have received protocol-owned liquidity: Vec<Option<Address>>
+    ProtocolOwnedLiquidityAssets,
+    /// Insurance fund balance per asset: InsuranceFund(asset) -> i128
+    /// Funded by the insurance share of liquidation incentives
+    InsuranceFund(Option<Address>),
+    /// Share of the liquidation incentive routed to the insurance fund: i128
+    /// (basis points of the incentive, governed)
+    LiquidationInsuranceShare,
+    /// Reserve-funded emergency repayments owed by a borrower:
+    /// Receivable(borrower, asset) -> i128
+    Receivable(Address, Option<Address>),
+}
+
+/// Where a donation is credited
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DonationTarget {
+    /// Protocol reserve balance
+    Reserves,
+    /// Insurance fund
+    InsuranceFund,
 }
 
 /// Initialize reserve configuration for an asset
@@ -166,7 +243,7 @@ pub fn set_reserve_factor(
 ) -> Result<(), ReserveError> {
     // Require admin authorization
     caller.require_auth();
-    require_admin(env, &caller)?;
+    require_role(env, &caller, Role::RiskAdmin)?;
 
     // Validate reserve factor
     if reserve_factor_bps < 0 || reserve_factor_bps > MAX_RESERVE_FACTOR_BPS {
@@ -256,20 +333,21 @@ pub fn accrue_reserve(
         .checked_sub(reserve_amount)
         .ok_or(ReserveError::Overflow)?;
 
-    // Update reserve balance
+    // Route the insurance share, then update reserve balance
+    let retained = crate::insurance::route_fee_share(env, asset.clone(), reserve_amount)
+        .map_err(|_| ReserveError::Overflow)?;
     let balance_key = ReserveDataKey::ReserveBalance(asset.clone());
     let current_balance: i128 = env.storage().persistent().get(&balance_key).unwrap_or(0);
 
     let new_balance = current_balance
-        .checked_add(reserve_amount)
+        .checked_add(retained)
         .ok_or(ReserveError::Overflow)?;
 
     env.storage().persistent().set(&balance_key, &new_balance);
 
     // Emit event
     let topics = (Symbol::new(env, "reserve_accrued"),);
-    env.events()
-        .publish(topics, (asset, reserve_amount, new_balance));
+    env.events().publish(topics, (asset, retained, new_balance));
 
     Ok((reserve_amount, lender_amount))
 }
@@ -312,7 +390,7 @@ pub fn set_treasury_address(
 ) -> Result<(), ReserveError> {
     // Require admin authorization
     caller.require_auth();
-    require_admin(env, &caller)?;
+    require_role(env, &caller, Role::Treasurer)?;
 
     // Validate treasury address
     if treasury == env.current_contract_address() {
@@ -364,6 +442,7 @@ pub fn get_treasury_address(env: &Env) -> Option<Address> {
 /// * `ReserveError::InvalidAmount` - If amount <= 0
 /// * `ReserveError::InsufficientReserve` - If amount > reserve balance
 /// * `ReserveError::Overflow` - If arithmetic overflow occurs
+/// * `ReserveError::InsufficientLiquidity` - If pool and strategy cannot cover `amount`
 ///
 /// # Security
 /// * Requires admin authorization
@@ -381,7 +460,7 @@ pub fn withdraw_reserve_funds(
 ) -> Result<i128, ReserveError> {
     // Require admin authorization
     caller.require_auth();
-    require_admin(env, &caller)?;
+    require_role(env, &caller, Role::Treasurer)?;
 
     // Validate amount
     if amount <= 0 {
@@ -420,6 +499,8 @@ pub fn withdraw_reserve_funds(
     #[cfg(not(test))]
     {
         if let Some(ref asset_addr) = asset {
+            crate::strategy::ensure_liquidity(env, asset_addr, amount)
+                .map_err(|_| ReserveError::InsufficientLiquidity)?;
             let token_client = soroban_sdk::token::Client::new(env, asset_addr);
             token_client.transfer(&env.current_contract_address(), &treasury, &amount);
         }
@@ -428,27 +509,611 @@ pub fn withdraw_reserve_funds(
     Ok(amount)
 }
 
-/// Helper function to require admin authorization
+/// Total withdrawable reserves for an asset: accrued interest reserves plus
+/// borrow and flash-loan fees
+pub fn get_reserves(env: &Env, asset: Option<Address>) -> i128 {
+    get_fee_reserve(env, asset.clone()).saturating_add(get_reserve_balance(env, asset))
+}
+
+fn get_fee_reserve(env: &Env, asset: Option<Address>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DepositDataKey::ProtocolReserve(asset))
+        .unwrap_or(0)
+}
+
+/// Withdraw reserves to an arbitrary address (admin only)
+///
+/// # Errors
+/// * `ReserveError::Unauthorized` - If caller is not admin
+/// * Any error from [`release_reserves`]
+pub fn withdraw_reserves(
+    env: &Env,
+    caller: Address,
+    to: Address,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<i128, ReserveError> {
+    caller.require_auth();
+    require_role(env, &caller, Role::Treasurer)?;
+    release_reserves(env, to, asset, amount)
+}
+
+/// Send `amount` of reserves for `asset` to `to`
+///
+/// Fee reserves are drawn down before accrued interest reserves. Returns the
+/// reserves left for the asset.
+///
+/// # Errors
+/// * `ReserveError::InvalidAmount` - If amount <= 0
+/// * `ReserveError::InvalidTreasury` - If `to` is this contract
+/// * `ReserveError::InsufficientReserve` - If amount > [`get_reserves`]
+/// * `ReserveError::InsufficientLiquidity` - If pool and strategy cannot cover `amount`
+///
+/// # Security
+/// * No authorization check - reached through [`withdraw_reserves`] or an
+///   executed governance proposal (`ProposalType::WithdrawReserves`)
+#[allow(deprecated)]
+pub fn release_reserves(
+    env: &Env,
+    to: Address,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<i128, ReserveError> {
+    if amount <= 0 {
+        return Err(ReserveError::InvalidAmount);
+    }
+    if to == env.current_contract_address() {
+        return Err(ReserveError::InvalidTreasury);
+    }
+
+    let fee_reserve = get_fee_reserve(env, asset.clone());
+    let interest_reserve = get_reserve_balance(env, asset.clone());
+    if amount > fee_reserve.saturating_add(interest_reserve) {
+        return Err(ReserveError::InsufficientReserve);
+    }
+
+    let from_fees = amount.min(fee_reserve);
+    let from_interest = amount - from_fees;
+    env.storage().persistent().set(
+        &DepositDataKey::ProtocolReserve(asset.clone()),
+        &(fee_reserve - from_fees),
+    );
+    env.storage().persistent().set(
+        &ReserveDataKey::ReserveBalance(asset.clone()),
+        &(interest_reserve - from_interest),
+    );
+    let remaining = get_reserves(env, asset.clone());
+
+    let topics = (Symbol::new(env, "reserves_withdrawn"), to.clone());
+    env.events()
+        .publish(topics, (asset.clone(), amount, remaining));
+
+    #[cfg(not(test))]
+    {
+        if let Some(ref asset_addr) = asset {
+            crate::strategy::ensure_liquidity(env, asset_addr, amount)
+                .map_err(|_| ReserveError::InsufficientLiquidity)?;
+            let token_client = soroban_sdk::token::Client::new(env, asset_addr);
+            token_client.transfer(&env.current_contract_address(), &to, &amount);
+        }
+    }
+
+    Ok(remaining)
+}
+
+/// Seed protocol-owned liquidity from accrued reserves
+///
+/// Moves `amount` out of the reserve balance for `asset` and into the lending
+/// pool as protocol-owned liquidity. The amount is added to protocol deposits
+/// and TVL so it earns supply yield like any other liquidity, but it is booked
+/// under `ProtocolOwnedLiquidity` rather than a user position, so it can never
+/// be withdrawn by users.
 ///
 /// # Arguments
 /// * `env` - The Soroban environment
-/// * `caller` - The caller address to check
+/// * `asset` - The asset address (None for native asset)
+/// * `amount` - Amount of reserves to seed into the pool
+///
+/// # Returns
+/// The new protocol-owned liquidity balance for the asset
+///
+/// # Errors
+/// * `ReserveError::InvalidAmount` - If amount <= 0
+/// * `ReserveError::InsufficientReserve` - If amount > reserve balance
+/// * `ReserveError::Overflow` - If arithmetic overflow occurs
+///
+/// # Security
+/// * No authorization check - only reachable through an executed governance
+///   proposal (`ProposalType::SeedProtocolLiquidity`)
+/// * Bounded by the accrued reserve balance, so user funds are never touched
+#[allow(deprecated)]
+pub fn seed_protocol_liquidity(
+    env: &Env,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<i128, ReserveError> {
+    if amount <= 0 {
+        return Err(ReserveError::InvalidAmount);
+    }
+
+    // Debit reserves
+    let balance_key = ReserveDataKey::ReserveBalance(asset.clone());
+    let current_balance: i128 = env.storage().persistent().get(&balance_key).unwrap_or(0);
+    if amount > current_balance {
+        return Err(ReserveError::InsufficientReserve);
+    }
+    let new_balance = current_balance
+        .checked_sub(amount)
+        .ok_or(ReserveError::Overflow)?;
+
+    // Credit protocol-owned liquidity
+    let pol_key = ReserveDataKey::ProtocolOwnedLiquidity(asset.clone());
+    let new_pol = get_protocol_owned_liquidity(env, asset.clone())
+        .checked_add(amount)
+        .ok_or(ReserveError::Overflow)?;
+
+    // Seeded reserves become pool liquidity
+    crate::deposit::update_protocol_analytics(env, amount, true)
+        .map_err(|_| ReserveError::Overflow)?;
+
+    env.storage().persistent().set(&balance_key, &new_balance);
+    env.storage().persistent().set(&pol_key, &new_pol);
+    let mut pol_assets = get_protocol_owned_liquidity_assets(env);
+    if !pol_assets.contains(&asset) {
+        pol_assets.push_back(asset.clone());
+        env.storage()
+            .persistent()
+            .set(&ReserveDataKey::ProtocolOwnedLiquidityAssets, &pol_assets);
+    }
+
+    // Emit event
+    let topics = (Symbol::new(env, "pol_seeded"),);
+    env.events().publish(topics, (asset, amount, new_pol, new_balance));
+
+    Ok(new_pol)
+}
+
+/// Get the protocol-owned liquidity for an asset
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `asset` - The asset address (None for native asset)
+///
+/// # Returns
+/// Protocol-owned liquidity seeded into the pool for the asset
+pub fn get_protocol_owned_liquidity(env: &Env, asset: Option<Address>) -> i128 {
+    let pol_key = ReserveDataKey::ProtocolOwnedLiquidity(asset);
+    env.storage().persistent().get(&pol_key).unwrap_or(0)
+}
+
+/// Get the assets that have received protocol-owned liquidity
+pub fn get_protocol_owned_liquidity_assets(env: &Env) -> Vec<Option<Address>> {
+    env.storage()
+        .persistent()
+        .get(&ReserveDataKey::ProtocolOwnedLiquidityAssets)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Get the value of protocol-owned liquidity across all assets
+///
+/// Each asset's amount is valued at its oracle price (see
+/// [`crate::oracle::ratio_value`]) before summing, so amounts of differently
+/// priced assets are never added directly.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+///
+/// # Errors
+/// * Any oracle error if a priced asset has no fresh price
+pub fn get_protocol_owned_liquidity_value(env: &Env) -> Result<i128, crate::oracle::OracleError> {
+    let mut total: i128 = 0;
+    for asset in get_protocol_owned_liquidity_assets(env).iter() {
+        let amount = get_protocol_owned_liquidity(env, asset.clone());
+        let value = crate::oracle::ratio_value(env, asset.as_ref(), amount)?;
+        total = total
+            .checked_add(value)
+            .ok_or(crate::oracle::OracleError::Overflow)?;
+    }
+    Ok(total)
+}
+
+/// Set the share of the liquidation incentive routed to the insurance fund
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `share_bps` - Share of the incentive in basis points (0-5000)
+///
+/// # Errors
+/// * `ReserveError::InvalidInsuranceShare` - If share is outside
+///   `[0, MAX_LIQUIDATION_INSURANCE_SHARE_BPS]`
+///
+/// # Security
+/// * No authorization check - only reachable through an executed governance
+///   proposal (`ProposalType::LiquidationInsuranceShare`)
+#[allow(deprecated)]
+pub fn set_liquidation_insurance_share(env: &Env, share_bps: i128) -> Result<(), ReserveError> {
+    if !(0..=MAX_LIQUIDATION_INSURANCE_SHARE_BPS).contains(&share_bps) {
+        return Err(ReserveError::InvalidInsuranceShare);
+    }
+
+    let old_share = get_liquidation_insurance_share(env);
+    env.storage()
+        .persistent()
+        .set(&ReserveDataKey::LiquidationInsuranceShare, &share_bps);
+
+    // Emit event
+    let topics = (Symbol::new(env, "liq_insurance_share_updated"),);
+    env.events().publish(topics, (old_share, share_bps));
+
+    Ok(())
+}
+
+/// Get the share of the liquidation incentive routed to the insurance fund
+///
+/// # Returns
+/// Share in basis points (defaults to 0 - the liquidator keeps the full incentive)
+pub fn get_liquidation_insurance_share(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ReserveDataKey::LiquidationInsuranceShare)
+        .unwrap_or(0)
+}
+
+/// Credit the insurance fund for an asset
+///
+/// Called during liquidation with the collateral withheld from the liquidator.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `asset` - The asset address (None for native asset)
+/// * `amount` - Amount to credit
+///
+/// # Returns
+/// The new insurance fund balance for the asset
+///
+/// # Errors
+/// * `ReserveError::Overflow` - If arithmetic overflow occurs
+#[allow(deprecated)]
+pub fn accrue_insurance_fund(
+    env: &Env,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<i128, ReserveError> {
+    if amount <= 0 {
+        return Ok(get_insurance_fund_balance(env, asset));
+    }
+
+    let new_balance = get_insurance_fund_balance(env, asset.clone())
+        .checked_add(amount)
+        .ok_or(ReserveError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&ReserveDataKey::InsuranceFund(asset.clone()), &new_balance);
+
+    // Emit event
+    let topics = (Symbol::new(env, "insurance_fund_accrued"),);
+    env.events().publish(topics, (asset, amount, new_balance));
+
+    Ok(new_balance)
+}
+
+/// Get the insurance fund balance for an asset
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `asset` - The asset address (None for native asset)
+///
+/// # Returns
+/// Insurance fund balance for the asset
+pub fn get_insurance_fund_balance(env: &Env, asset: Option<Address>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ReserveDataKey::InsuranceFund(asset))
+        .unwrap_or(0)
+}
+
+/// Draw up to `amount` from the insurance fund for an asset
+///
+/// Used to cover bad debt written off by the insurance module.
+///
+/// # Returns
+/// Amount actually drawn, bounded by the fund balance
+///
+/// # Errors
+/// * `ReserveError::InvalidAmount` - If amount <= 0
+pub fn draw_insurance_fund(
+    env: &Env,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<i128, ReserveError> {
+    if amount <= 0 {
+        return Err(ReserveError::InvalidAmount);
+    }
+
+    let balance = get_insurance_fund_balance(env, asset.clone());
+    let drawn = amount.min(balance);
+    if drawn > 0 {
+        env.storage()
+            .persistent()
+            .set(&ReserveDataKey::InsuranceFund(asset), &(balance - drawn));
+    }
+
+    Ok(drawn)
+}
+
+/// Repay a borrower's debt from reserves (admin only)
+///
+/// Emergency tool for when liquidation would realize a large loss, e.g.
+/// during an oracle outage. Accrued interest is repaid first, then principal.
+/// The repaid amount is moved out of the reserve balance for `asset` and
+/// recorded as a receivable owed by `borrower`.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The caller address (must be admin)
+/// * `borrower` - The borrower whose debt is repaid
+/// * `asset` - The asset address (None for native asset)
+/// * `amount` - Maximum amount to repay
+///
+/// # Returns
+/// Actual amount repaid
 ///
 /// # Errors
 /// * `ReserveError::Unauthorized` - If caller is not admin
-fn require_admin(env: &Env, caller: &Address) -> Result<(), ReserveError> {
-    let admin_key = DepositDataKey::Admin;
-    let admin = env
+/// * `ReserveError::InvalidAmount` - If amount <= 0
+/// * `ReserveError::NoDebt` - If the borrower has no debt
+/// * `ReserveError::InsufficientReserve` - If the repayment exceeds the reserve balance
+/// * `ReserveError::Overflow` - If arithmetic overflow occurs
+#[allow(deprecated)]
+pub fn emergency_repay(
+    env: &Env,
+    caller: Address,
+    borrower: Address,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<i128, ReserveError> {
+    caller.require_auth();
+    require_role(env, &caller, Role::Treasurer)?;
+
+    let repaid = repay_from_reserves(env, &borrower, asset.clone(), amount)?;
+
+    let receivable_key = ReserveDataKey::Receivable(borrower.clone(), asset.clone());
+    let receivable = get_receivable(env, &borrower, asset.clone())
+        .checked_add(repaid)
+        .ok_or(ReserveError::Overflow)?;
+    env.storage().persistent().set(&receivable_key, &receivable);
+
+    let topics = (Symbol::new(env, "emergency_repay"), caller, borrower);
+    env.events().publish(topics, (asset, repaid, receivable));
+
+    Ok(repaid)
+}
+
+/// Forgive part of a borrower's debt, funded from reserves
+///
+/// Only reachable through an executed governance proposal, e.g. to make a
+/// borrower whole after a protocol bug. Works like [`emergency_repay`] but
+/// records no receivable: the borrower owes nothing for the forgiven amount.
+///
+/// # Returns
+/// Actual amount forgiven
+///
+/// # Errors
+/// * `ReserveError::InvalidAmount` - If amount <= 0
+/// * `ReserveError::NoDebt` - If the borrower has no debt
+/// * `ReserveError::InsufficientReserve` - If the amount exceeds the reserve balance
+/// * `ReserveError::Overflow` - If arithmetic overflow occurs
+#[allow(deprecated)]
+pub fn forgive_debt(
+    env: &Env,
+    borrower: Address,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<i128, ReserveError> {
+    let forgiven = repay_from_reserves(env, &borrower, asset.clone(), amount)?;
+
+    let remaining = env
         .storage()
         .persistent()
-        .get::<DepositDataKey, Address>(&admin_key)
-        .ok_or(ReserveError::Unauthorized)?;
+        .get::<DepositDataKey, Position>(&DepositDataKey::Position(borrower.clone()))
+        .map(|p| p.debt + p.borrow_interest)
+        .unwrap_or(0);
+
+    let topics = (Symbol::new(env, "debt_forgiven"), borrower);
+    env.events().publish(topics, (asset, forgiven, remaining));
 
-    if caller != &admin {
-        return Err(ReserveError::Unauthorized);
+    Ok(forgiven)
+}
+
+/// Repay up to `amount` of a borrower's debt out of the reserve balance,
+/// interest first, then principal
+fn repay_from_reserves(
+    env: &Env,
+    borrower: &Address,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<i128, ReserveError> {
+    if amount <= 0 {
+        return Err(ReserveError::InvalidAmount);
     }
 
-    Ok(())
+    let position_key = DepositDataKey::Position(borrower.clone());
+    let mut position = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Position>(&position_key)
+        .ok_or(ReserveError::NoDebt)?;
+    crate::repay::accrue_interest(env, borrower, &mut position)
+        .map_err(|_| ReserveError::Overflow)?;
+
+    let total_debt = position
+        .debt
+        .checked_add(position.borrow_interest)
+        .ok_or(ReserveError::Overflow)?;
+    if total_debt == 0 {
+        return Err(ReserveError::NoDebt);
+    }
+    let repaid = amount.min(total_debt);
+
+    let balance_key = ReserveDataKey::ReserveBalance(asset.clone());
+    let reserve_balance: i128 = env.storage().persistent().get(&balance_key).unwrap_or(0);
+    if repaid > reserve_balance {
+        return Err(ReserveError::InsufficientReserve);
+    }
+
+    // Interest first, then principal
+    let interest_paid = repaid.min(position.borrow_interest);
+    position.borrow_interest -= interest_paid;
+    position.debt -= repaid - interest_paid;
+    env.storage().persistent().set(&position_key, &position);
+    crate::asset_positions::record_repayment(env, borrower, asset.clone(), repaid - interest_paid);
+    crate::analytics::emit_health_factor_changed(env, borrower, Symbol::new(env, "reserve_repay"));
+
+    env.storage()
+        .persistent()
+        .set(&balance_key, &(reserve_balance - repaid));
+
+    Ok(repaid)
+}
+
+/// Pay back a receivable from an emergency repay into reserves
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `borrower` - The borrower owing the receivable
+/// * `asset` - The asset address (None for native asset)
+/// * `amount` - Maximum amount to pay back
+///
+/// # Returns
+/// Remaining receivable
+///
+/// # Errors
+/// * `ReserveError::InvalidAmount` - If amount <= 0
+/// * `ReserveError::NoReceivable` - If the borrower owes nothing
+/// * `ReserveError::Overflow` - If arithmetic overflow occurs
+#[allow(deprecated)]
+pub fn settle_receivable(
+    env: &Env,
+    borrower: Address,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<i128, ReserveError> {
+    borrower.require_auth();
+
+    if amount <= 0 {
+        return Err(ReserveError::InvalidAmount);
+    }
+    let receivable = get_receivable(env, &borrower, asset.clone());
+    if receivable == 0 {
+        return Err(ReserveError::NoReceivable);
+    }
+    let paid = amount.min(receivable);
+
+    #[cfg(not(test))]
+    {
+        if let Some(ref asset_addr) = asset {
+            let token_client = soroban_sdk::token::Client::new(env, asset_addr);
+            token_client.transfer(&borrower, &env.current_contract_address(), &paid);
+        }
+    }
+
+    let remaining = receivable - paid;
+    let receivable_key = ReserveDataKey::Receivable(borrower.clone(), asset.clone());
+    if remaining == 0 {
+        env.storage().persistent().remove(&receivable_key);
+    } else {
+        env.storage().persistent().set(&receivable_key, &remaining);
+    }
+
+    let balance_key = ReserveDataKey::ReserveBalance(asset.clone());
+    let new_balance = get_reserve_balance(env, asset.clone())
+        .checked_add(paid)
+        .ok_or(ReserveError::Overflow)?;
+    env.storage().persistent().set(&balance_key, &new_balance);
+
+    let topics = (Symbol::new(env, "receivable_settled"), borrower);
+    env.events().publish(topics, (asset, paid, remaining));
+
+    Ok(remaining)
+}
+
+/// Get the emergency-repay receivable owed by a borrower for an asset
+pub fn get_receivable(env: &Env, borrower: &Address, asset: Option<Address>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ReserveDataKey::Receivable(borrower.clone(), asset))
+        .unwrap_or(0)
+}
+
+/// Donate tokens to protocol reserves or the insurance fund
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `donor` - The address sending the tokens
+/// * `asset` - The asset address (None for native asset)
+/// * `amount` - Amount to donate
+/// * `target` - Bucket to credit
+///
+/// # Returns
+/// The new balance of the credited bucket
+///
+/// # Errors
+/// * `ReserveError::InvalidAmount` - If amount <= 0
+/// * `ReserveError::InvalidAsset` - If the asset cannot be resolved
+/// * `ReserveError::Overflow` - If arithmetic overflow occurs
+#[allow(deprecated)]
+pub fn donate(
+    env: &Env,
+    donor: Address,
+    asset: Option<Address>,
+    amount: i128,
+    target: DonationTarget,
+) -> Result<i128, ReserveError> {
+    donor.require_auth();
+
+    if amount <= 0 {
+        return Err(ReserveError::InvalidAmount);
+    }
+    let asset_addr =
+        crate::accounting::asset_address(env, &asset).map_err(|_| ReserveError::InvalidAsset)?;
+
+    soroban_sdk::token::Client::new(env, &asset_addr).transfer(
+        &donor,
+        &env.current_contract_address(),
+        &amount,
+    );
+
+    let new_balance = match target {
+        DonationTarget::Reserves => {
+            let new_balance = get_reserve_balance(env, asset.clone())
+                .checked_add(amount)
+                .ok_or(ReserveError::Overflow)?;
+            env.storage()
+                .persistent()
+                .set(&ReserveDataKey::ReserveBalance(asset.clone()), &new_balance);
+            new_balance
+        }
+        DonationTarget::InsuranceFund => accrue_insurance_fund(env, asset.clone(), amount)?,
+    };
+
+    let topics = (Symbol::new(env, "donation_received"), donor);
+    env.events().publish(topics, (asset, amount, target, new_balance));
+
+    Ok(new_balance)
+}
+
+/// Helper function to require a role (or the super admin)
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The caller address to check
+/// * `role` - The role the operation requires
+///
+/// # Errors
+/// * `ReserveError::Unauthorized` - If caller neither holds the role nor is admin
+fn require_role(env: &Env, caller: &Address, role: Role) -> Result<(), ReserveError> {
+    crate::admin::require_role(env, caller, role).map_err(|_| ReserveError::Unauthorized)
 }
 
 /// Get reserve statistics for an asset
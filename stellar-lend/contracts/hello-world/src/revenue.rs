@@ -0,0 +1,87 @@
+//! # Protocol Revenue Module
+//!
+//! Cumulative per-asset counters of what the protocol has earned, so treasury
+//! managers can read earnings on-chain without indexing events.
+//!
+//! ## Sources
+//! - `interest_accrued` — the reserve factor's cut of borrow interest paid by
+//!   borrowers (through repayments and liquidations); the rest belongs to
+//!   lenders. Positions accrue interest in a single balance without an asset,
+//!   so interest is attributed to the asset it is paid in.
+//! - `fees_collected` — borrow fees credited to reserves.
+//! - `liquidation_penalties` — share of liquidation incentives retained by the
+//!   protocol's insurance fund, in the collateral asset.
+//! - `flash_loan_fees` — flash loan fees credited to reserves.
+//!
+//! Counters only ever increase; withdrawals from reserves do not reduce them.
+//!
+//! ## Security
+//! - Counters are written only by the flows that collect the revenue; there is
+//!   no external setter.
+//! - Recording saturates instead of failing, so bookkeeping can never block a
+//!   repayment, liquidation or flash loan.
+
+#![allow(unused)]
+use soroban_sdk::{contracttype, Address, Env};
+
+/// Storage keys for protocol revenue data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum RevenueDataKey {
+    /// Cumulative revenue per asset: RevenueReport
+    Report(Option<Address>),
+}
+
+/// Revenue categories tracked per asset
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RevenueSource {
+    Interest,
+    Fee,
+    LiquidationPenalty,
+    FlashLoanFee,
+}
+
+/// Cumulative protocol revenue for an asset
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RevenueReport {
+    pub interest_accrued: i128,
+    pub fees_collected: i128,
+    pub liquidation_penalties: i128,
+    pub flash_loan_fees: i128,
+    /// Timestamp of the last recorded revenue (0 if none)
+    pub updated_at: u64,
+}
+
+/// Add `amount` of revenue from `source` to `asset`'s counters
+///
+/// Non-positive amounts are ignored.
+pub fn record(env: &Env, asset: Option<Address>, source: RevenueSource, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+
+    let mut report = get_revenue_report(env, asset.clone());
+    let counter = match source {
+        RevenueSource::Interest => &mut report.interest_accrued,
+        RevenueSource::Fee => &mut report.fees_collected,
+        RevenueSource::LiquidationPenalty => &mut report.liquidation_penalties,
+        RevenueSource::FlashLoanFee => &mut report.flash_loan_fees,
+    };
+    *counter = counter.saturating_add(amount);
+    report.updated_at = env.ledger().timestamp();
+
+    env.storage()
+        .persistent()
+        .set(&RevenueDataKey::Report(asset), &report);
+}
+
+/// Cumulative protocol revenue for an asset
+pub fn get_revenue_report(env: &Env, asset: Option<Address>) -> RevenueReport {
+    env.storage()
+        .persistent()
+        .get(&RevenueDataKey::Report(asset))
+        .unwrap_or_default()
+}
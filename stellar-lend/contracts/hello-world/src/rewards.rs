@@ -0,0 +1,899 @@
+//! # Rewards Module
+//!
+//! Reward emission management for StellarLend liquidity incentives.
+//!
+//! ## Emission Schedules
+//! Instead of a single static reward rate that has to be retuned by hand,
+//! each asset is configured with an [`EmissionSchedule`]: a sequence of
+//! fixed-length epochs whose reward budget decays geometrically.
+//!
+//! - Epoch `n` starts at `start_time + n * epoch_duration`.
+//! - `budget(0) = initial_budget`
+//! - `budget(n) = budget(n - 1) * (10_000 - decay_bps) / 10_000`
+//!
+//! The current epoch is derived from the ledger timestamp on every read, so
+//! the schedule advances automatically without any keeper transaction.
+//!
+//! ## Gauge Voting
+//! Holders of the governance vote token split a shared gauge emission pool
+//! across assets ("gauges"). Voting happens in weekly periods:
+//!
+//! 1. The admin registers gauges with [`add_gauge`] and sets the pool schedule.
+//! 2. During period `p`, stakers call [`vote_gauge_weight`] to allocate basis
+//!    points of the vote tokens they had staked before `p` started to gauges
+//!    (at most 100% per period). Tokens staked or moved mid-period do not add
+//!    voting power until the next period.
+//! 3. After period `p` ends anyone may call [`finalize_gauge_weights`], which
+//!    snapshots each gauge's share of the total votes.
+//! 4. Emission reads use the weights of the most recently finalized period.
+//!
+//! ## Liquidity Mining
+//! Governance funds reward markets: an [`EmissionSchedule`] for the suppliers
+//! or the borrowers of one asset ([`RewardMarket`]), set by
+//! `ProposalType::RewardEmission`. Rewards are paid in the governance vote
+//! token out of the contract's balance, which the treasury tops up.
+//!
+//! Accrual is index based. Each market keeps a reward index, the rewards
+//! emitted per unit of balance (scaled by [`REWARD_INDEX_SCALE`]) since the
+//! market was created, and each user the index at their last checkpoint.
+//! Every per-asset position change (see [`crate::asset_positions`]) first
+//! advances the index over the elapsed time and credits
+//! `balance × (index − user index)` to the user. A market's supply balance
+//! is the user's collateral in the asset, its borrow balance their principal
+//! debt. Emissions while a market has no balance at all are not distributed.
+//!
+//! [`claim_rewards`] settles every market and pays the total;
+//! [`get_pending_rewards`] returns the same amount without writing.
+//!
+//! ## Security
+//! - Only the protocol admin can create or replace a schedule or register gauges.
+//! - Reward markets are only changed through an executed governance proposal.
+//! - All arithmetic is checked; decay iteration is bounded by [`MAX_EPOCHS`].
+//! - Gauge iteration is bounded by [`MAX_GAUGES`], reward market iteration
+//!   by [`MAX_REWARD_MARKETS`].
+//! - Checkpoints saturate instead of failing so they can never block a flow.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, token::TokenClient, Address, Env, Vec};
+
+use crate::events::{
+    emit_emission_schedule_set, emit_gauge_vote_cast, emit_gauge_weights_finalized,
+    EmissionScheduleSetEvent, GaugeVoteCastEvent, GaugeWeightsFinalizedEvent,
+};
+
+/// Basis points scale (100% = 10_000)
+pub const BASIS_POINTS_SCALE: i128 = 10_000;
+
+/// Upper bound on the number of epochs a schedule can run for.
+/// Bounds the decay loop and guarantees the budget reaches its tail in finite time.
+pub const MAX_EPOCHS: u32 = 520;
+
+/// Minimum epoch duration (1 hour)
+pub const MIN_EPOCH_DURATION: u64 = 3_600;
+
+/// Length of a gauge voting period (1 week)
+pub const GAUGE_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+/// Maximum number of registered gauges
+pub const MAX_GAUGES: u32 = 32;
+
+/// Maximum number of liquidity mining markets
+pub const MAX_REWARD_MARKETS: u32 = 32;
+
+/// Fixed-point scale of reward indexes
+pub const REWARD_INDEX_SCALE: i128 = 1_000_000_000_000;
+
+/// Errors that can occur during rewards operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RewardsError {
+    /// Caller is not authorized
+    Unauthorized = 1,
+    /// A parameter is out of range
+    InvalidParameter = 2,
+    /// No emission schedule exists for the asset
+    ScheduleNotFound = 3,
+    /// Arithmetic overflow occurred
+    Overflow = 4,
+    /// Asset is not a registered gauge
+    GaugeNotFound = 5,
+    /// Gauge is already registered or the gauge limit is reached
+    GaugeAlreadyExists = 6,
+    /// Voter has no governance token balance
+    NoVotingPower = 7,
+    /// Voter's allocations for the period would exceed 100%
+    VoteAllocationExceeded = 8,
+    /// Voting period has not ended yet
+    PeriodNotEnded = 9,
+    /// Voting period has already been finalized
+    AlreadyFinalized = 10,
+    /// Governance (and therefore the vote token) is not initialized
+    GovernanceNotInitialized = 11,
+    /// User has no rewards to claim
+    NothingToClaim = 12,
+    /// [`MAX_REWARD_MARKETS`] liquidity mining markets already exist
+    MarketLimitReached = 13,
+    /// Pool and strategy together cannot cover the transfer
+    InsufficientLiquidity = 14,
+}
+
+/// Storage keys for rewards data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum RewardsDataKey {
+    /// Emission schedule per asset: EmissionSchedule(asset) -> EmissionSchedule
+    EmissionSchedule(Option<Address>),
+    /// Emission schedule for the gauge-weighted pool: EmissionSchedule
+    GaugePoolSchedule,
+    /// Registered gauges: Vec<Option<Address>>
+    Gauges,
+    /// Basis points of voting power a voter has allocated in a period: u32
+    GaugeVoterAllocation(u64, Address),
+    /// Raw vote weight accumulated by a gauge in a period: i128
+    GaugeVotes(u64, Option<Address>),
+    /// Total raw vote weight cast in a period: i128
+    GaugeTotalVotes(u64),
+    /// Finalized share of a gauge for a period (basis points): i128
+    FinalizedGaugeWeight(u64, Option<Address>),
+    /// Most recently finalized period: u64
+    LastFinalizedPeriod,
+    /// Liquidity mining markets ever funded: Vec<RewardMarket>
+    RewardMarkets,
+    /// Emission schedule of a liquidity mining market: EmissionSchedule
+    RewardEmission(RewardMarket),
+    /// Reward index of a liquidity mining market: RewardIndex
+    RewardIndex(RewardMarket),
+    /// Market reward index at a user's last checkpoint: i128
+    UserRewardIndex(Address, RewardMarket),
+    /// Rewards settled to a user and not yet claimed: i128
+    AccruedRewards(Address),
+}
+
+/// Side of a market that earns liquidity mining rewards
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RewardSide {
+    /// Suppliers, by collateral deposited in the asset
+    Supply,
+    /// Borrowers, by principal borrowed in the asset
+    Borrow,
+}
+
+/// Suppliers or borrowers of one asset
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardMarket {
+    pub asset: Option<Address>,
+    pub side: RewardSide,
+}
+
+/// Accrual state of a liquidity mining market
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RewardIndex {
+    /// Rewards per unit of balance since the market was created, scaled by [`REWARD_INDEX_SCALE`]
+    pub index: i128,
+    /// Timestamp the index was last advanced
+    pub last_update: u64,
+}
+
+/// Decaying per-epoch emission schedule for an asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmissionSchedule {
+    /// Timestamp at which epoch 0 begins
+    pub start_time: u64,
+    /// Length of each epoch in seconds
+    pub epoch_duration: u64,
+    /// Reward budget for epoch 0
+    pub initial_budget: i128,
+    /// Budget decay applied at every epoch boundary (basis points)
+    pub decay_bps: i128,
+    /// Number of epochs after which emissions stop
+    pub total_epochs: u32,
+}
+
+/// Create or replace the emission schedule for an asset (admin only)
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The caller address (must be admin)
+/// * `asset` - The asset address (None for native asset)
+/// * `schedule` - The new emission schedule
+///
+/// # Errors
+/// * `RewardsError::Unauthorized` - If caller is not admin
+/// * `RewardsError::InvalidParameter` - If the schedule is malformed
+pub fn set_emission_schedule(
+    env: &Env,
+    caller: Address,
+    asset: Option<Address>,
+    schedule: EmissionSchedule,
+) -> Result<(), RewardsError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::Treasurer)
+        .map_err(|_| RewardsError::Unauthorized)?;
+    validate_schedule(&schedule)?;
+
+    env.storage()
+        .persistent()
+        .set(&RewardsDataKey::EmissionSchedule(asset.clone()), &schedule);
+
+    emit_emission_schedule_set(
+        env,
+        EmissionScheduleSetEvent {
+            actor: caller,
+            asset,
+            start_time: schedule.start_time,
+            epoch_duration: schedule.epoch_duration,
+            initial_budget: schedule.initial_budget,
+            decay_bps: schedule.decay_bps,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+
+    Ok(())
+}
+
+/// Get the emission schedule for an asset
+pub fn get_emission_schedule(env: &Env, asset: Option<Address>) -> Option<EmissionSchedule> {
+    env.storage()
+        .persistent()
+        .get(&RewardsDataKey::EmissionSchedule(asset))
+}
+
+/// Get the epoch index active at the current ledger timestamp
+///
+/// Returns `None` before the schedule starts or after its final epoch.
+pub fn get_current_epoch(env: &Env, asset: Option<Address>) -> Result<Option<u32>, RewardsError> {
+    let schedule = get_emission_schedule(env, asset).ok_or(RewardsError::ScheduleNotFound)?;
+    Ok(epoch_at(&schedule, env.ledger().timestamp()))
+}
+
+/// Get the reward budget for a given epoch of an asset's schedule
+///
+/// # Errors
+/// * `RewardsError::ScheduleNotFound` - If the asset has no schedule
+/// * `RewardsError::Overflow` - If arithmetic overflow occurs
+pub fn get_epoch_budget(env: &Env, asset: Option<Address>, epoch: u32) -> Result<i128, RewardsError> {
+    let schedule = get_emission_schedule(env, asset).ok_or(RewardsError::ScheduleNotFound)?;
+    epoch_budget(&schedule, epoch)
+}
+
+/// Get the per-second emission rate at the current ledger timestamp
+///
+/// The rate is the asset's own schedule rate plus its finalized gauge share
+/// of the gauge pool rate. Each component is `0` outside its active window.
+///
+/// # Errors
+/// * `RewardsError::ScheduleNotFound` - If neither an asset schedule nor a
+///   gauge pool schedule exists
+pub fn get_current_emission_rate(env: &Env, asset: Option<Address>) -> Result<i128, RewardsError> {
+    let own = get_emission_schedule(env, asset.clone());
+    let pool: Option<EmissionSchedule> = env
+        .storage()
+        .persistent()
+        .get(&RewardsDataKey::GaugePoolSchedule);
+    if own.is_none() && pool.is_none() {
+        return Err(RewardsError::ScheduleNotFound);
+    }
+
+    let now = env.ledger().timestamp();
+    let mut rate = match own {
+        Some(schedule) => schedule_rate(&schedule, now)?,
+        None => 0,
+    };
+
+    if let Some(pool) = pool {
+        let weight = get_gauge_weight(env, asset);
+        let share = schedule_rate(&pool, now)?
+            .checked_mul(weight)
+            .ok_or(RewardsError::Overflow)?
+            .checked_div(BASIS_POINTS_SCALE)
+            .ok_or(RewardsError::Overflow)?;
+        rate = rate.checked_add(share).ok_or(RewardsError::Overflow)?;
+    }
+
+    Ok(rate)
+}
+
+// =============================================================================
+// Gauge Voting
+// =============================================================================
+
+/// Set the emission schedule of the gauge-weighted pool (admin only)
+///
+/// # Errors
+/// * `RewardsError::Unauthorized` - If caller is not admin
+/// * `RewardsError::InvalidParameter` - If the schedule is malformed
+pub fn set_gauge_pool_schedule(
+    env: &Env,
+    caller: Address,
+    schedule: EmissionSchedule,
+) -> Result<(), RewardsError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::Treasurer)
+        .map_err(|_| RewardsError::Unauthorized)?;
+    validate_schedule(&schedule)?;
+
+    env.storage()
+        .persistent()
+        .set(&RewardsDataKey::GaugePoolSchedule, &schedule);
+
+    emit_emission_schedule_set(
+        env,
+        EmissionScheduleSetEvent {
+            actor: caller,
+            asset: None,
+            start_time: schedule.start_time,
+            epoch_duration: schedule.epoch_duration,
+            initial_budget: schedule.initial_budget,
+            decay_bps: schedule.decay_bps,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+
+    Ok(())
+}
+
+/// Register an asset as a gauge (admin only)
+///
+/// # Errors
+/// * `RewardsError::Unauthorized` - If caller is not admin
+/// * `RewardsError::GaugeAlreadyExists` - If already registered or at [`MAX_GAUGES`]
+pub fn add_gauge(env: &Env, caller: Address, asset: Option<Address>) -> Result<(), RewardsError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::Treasurer)
+        .map_err(|_| RewardsError::Unauthorized)?;
+
+    let mut gauges = get_gauges(env);
+    if gauges.contains(&asset) || gauges.len() >= MAX_GAUGES {
+        return Err(RewardsError::GaugeAlreadyExists);
+    }
+    gauges.push_back(asset);
+    env.storage().persistent().set(&RewardsDataKey::Gauges, &gauges);
+    Ok(())
+}
+
+/// Get all registered gauges
+pub fn get_gauges(env: &Env) -> Vec<Option<Address>> {
+    env.storage()
+        .persistent()
+        .get(&RewardsDataKey::Gauges)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Gauge voting period containing `timestamp`
+pub fn gauge_period_at(timestamp: u64) -> u64 {
+    timestamp / GAUGE_PERIOD
+}
+
+/// Allocate a share of the caller's voting power to a gauge for the current period
+///
+/// Voting power is the voter's staked vote tokens as of the end of the last
+/// ledger before the period started (see
+/// [`crate::governance::get_staked_votes_at`]), so the same tokens cannot be
+/// staked, voted and moved to another account within one period. A voter may
+/// split their power across several gauges, but the sum of `weight_bps`
+/// within a period may not exceed 10_000.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `voter` - The voter address (must authorize)
+/// * `asset` - The gauge to vote for
+/// * `weight_bps` - Share of the voter's power to allocate (basis points)
+///
+/// # Returns
+/// The vote weight added to the gauge
+///
+/// # Errors
+/// * `RewardsError::GovernanceNotInitialized` - If there is no vote token
+/// * `RewardsError::GaugeNotFound` - If `asset` is not a registered gauge
+/// * `RewardsError::InvalidParameter` - If `weight_bps` is not in `(0, 10_000]`
+/// * `RewardsError::VoteAllocationExceeded` - If the voter would exceed 100%
+/// * `RewardsError::NoVotingPower` - If the voter had no stake at the period start
+pub fn vote_gauge_weight(
+    env: &Env,
+    voter: Address,
+    asset: Option<Address>,
+    weight_bps: u32,
+) -> Result<i128, RewardsError> {
+    voter.require_auth();
+
+    if crate::governance::get_config(env).is_none() {
+        return Err(RewardsError::GovernanceNotInitialized);
+    }
+    if !get_gauges(env).contains(&asset) {
+        return Err(RewardsError::GaugeNotFound);
+    }
+    if weight_bps == 0 || weight_bps as i128 > BASIS_POINTS_SCALE {
+        return Err(RewardsError::InvalidParameter);
+    }
+
+    let now = env.ledger().timestamp();
+    let period = gauge_period_at(now);
+
+    let allocation_key = RewardsDataKey::GaugeVoterAllocation(period, voter.clone());
+    let allocated: u32 = env.storage().persistent().get(&allocation_key).unwrap_or(0);
+    let new_allocated = allocated
+        .checked_add(weight_bps)
+        .ok_or(RewardsError::Overflow)?;
+    if new_allocated as i128 > BASIS_POINTS_SCALE {
+        return Err(RewardsError::VoteAllocationExceeded);
+    }
+
+    let period_start = period
+        .checked_mul(GAUGE_PERIOD)
+        .ok_or(RewardsError::Overflow)?;
+    let balance =
+        crate::governance::get_staked_votes_at(env, &voter, period_start.saturating_sub(1));
+    if balance <= 0 {
+        return Err(RewardsError::NoVotingPower);
+    }
+    let vote_weight = balance
+        .checked_mul(weight_bps as i128)
+        .ok_or(RewardsError::Overflow)?
+        .checked_div(BASIS_POINTS_SCALE)
+        .ok_or(RewardsError::Overflow)?;
+
+    let gauge_key = RewardsDataKey::GaugeVotes(period, asset.clone());
+    let gauge_votes: i128 = env.storage().persistent().get(&gauge_key).unwrap_or(0);
+    let total_key = RewardsDataKey::GaugeTotalVotes(period);
+    let total_votes: i128 = env.storage().persistent().get(&total_key).unwrap_or(0);
+
+    env.storage().persistent().set(&allocation_key, &new_allocated);
+    env.storage().persistent().set(
+        &gauge_key,
+        &gauge_votes
+            .checked_add(vote_weight)
+            .ok_or(RewardsError::Overflow)?,
+    );
+    env.storage().persistent().set(
+        &total_key,
+        &total_votes
+            .checked_add(vote_weight)
+            .ok_or(RewardsError::Overflow)?,
+    );
+
+    emit_gauge_vote_cast(
+        env,
+        GaugeVoteCastEvent {
+            voter,
+            asset,
+            period,
+            weight_bps,
+            vote_weight,
+            timestamp: now,
+        },
+    );
+
+    Ok(vote_weight)
+}
+
+/// Finalize gauge weights for a completed voting period
+///
+/// Permissionless. Converts raw votes into per-gauge shares (basis points)
+/// and marks `period` as the latest finalized period. Periods with no votes
+/// finalize to zero weight for every gauge.
+///
+/// # Errors
+/// * `RewardsError::PeriodNotEnded` - If `period` has not ended
+/// * `RewardsError::AlreadyFinalized` - If `period` (or a later one) is finalized
+pub fn finalize_gauge_weights(env: &Env, period: u64) -> Result<(), RewardsError> {
+    let now = env.ledger().timestamp();
+    if period >= gauge_period_at(now) {
+        return Err(RewardsError::PeriodNotEnded);
+    }
+    if let Some(last) = get_last_finalized_period(env) {
+        if period <= last {
+            return Err(RewardsError::AlreadyFinalized);
+        }
+    }
+
+    let total_votes: i128 = env
+        .storage()
+        .persistent()
+        .get(&RewardsDataKey::GaugeTotalVotes(period))
+        .unwrap_or(0);
+
+    for asset in get_gauges(env).iter() {
+        let votes: i128 = env
+            .storage()
+            .persistent()
+            .get(&RewardsDataKey::GaugeVotes(period, asset.clone()))
+            .unwrap_or(0);
+        let weight = if total_votes > 0 {
+            votes
+                .checked_mul(BASIS_POINTS_SCALE)
+                .ok_or(RewardsError::Overflow)?
+                .checked_div(total_votes)
+                .ok_or(RewardsError::Overflow)?
+        } else {
+            0
+        };
+        env.storage()
+            .persistent()
+            .set(&RewardsDataKey::FinalizedGaugeWeight(period, asset), &weight);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&RewardsDataKey::LastFinalizedPeriod, &period);
+
+    emit_gauge_weights_finalized(
+        env,
+        GaugeWeightsFinalizedEvent {
+            period,
+            total_votes,
+            timestamp: now,
+        },
+    );
+
+    Ok(())
+}
+
+/// Get the most recently finalized gauge period, if any
+pub fn get_last_finalized_period(env: &Env) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&RewardsDataKey::LastFinalizedPeriod)
+}
+
+/// Get the finalized weight (basis points) of a gauge for the latest finalized period
+///
+/// Returns `0` if no period has been finalized or the asset is not a gauge.
+pub fn get_gauge_weight(env: &Env, asset: Option<Address>) -> i128 {
+    match get_last_finalized_period(env) {
+        Some(period) => env
+            .storage()
+            .persistent()
+            .get(&RewardsDataKey::FinalizedGaugeWeight(period, asset))
+            .unwrap_or(0),
+        None => 0,
+    }
+}
+
+// =============================================================================
+// Liquidity Mining
+// =============================================================================
+
+/// Set or end the emission schedule of a liquidity mining market
+///
+/// Only reachable through an executed governance proposal. The market is
+/// checkpointed under its old schedule first; `None` stops emissions while
+/// keeping what users have earned so far claimable.
+///
+/// # Errors
+/// * `RewardsError::InvalidParameter` - If the schedule is malformed
+/// * `RewardsError::MarketLimitReached` - If a new market would exceed [`MAX_REWARD_MARKETS`]
+#[allow(deprecated)]
+pub fn set_reward_emission(
+    env: &Env,
+    market: RewardMarket,
+    schedule: Option<EmissionSchedule>,
+) -> Result<(), RewardsError> {
+    validate_reward_emission(env, &market, schedule.as_ref())?;
+
+    let mut markets = get_reward_markets(env);
+    if markets.contains(&market) {
+        let totals = crate::asset_positions::get_asset_totals(env, market.asset.clone());
+        let index = accrue_market(env, &market, side_balance(&totals, market.side));
+        save_market_index(env, &market, &index);
+    } else {
+        markets.push_back(market.clone());
+        env.storage()
+            .persistent()
+            .set(&RewardsDataKey::RewardMarkets, &markets);
+        let index = RewardIndex {
+            index: 0,
+            last_update: env.ledger().timestamp(),
+        };
+        save_market_index(env, &market, &index);
+    }
+
+    let key = RewardsDataKey::RewardEmission(market.clone());
+    match schedule.clone() {
+        Some(schedule) => env.storage().persistent().set(&key, &schedule),
+        None => env.storage().persistent().remove(&key),
+    }
+
+    let topics = (soroban_sdk::Symbol::new(env, "reward_emission_updated"),);
+    env.events().publish(topics, (market, schedule));
+
+    Ok(())
+}
+
+/// Check a reward emission change before it is proposed or applied
+pub fn validate_reward_emission(
+    env: &Env,
+    market: &RewardMarket,
+    schedule: Option<&EmissionSchedule>,
+) -> Result<(), RewardsError> {
+    if let Some(schedule) = schedule {
+        validate_schedule(schedule)?;
+    }
+    let markets = get_reward_markets(env);
+    if !markets.contains(market) && markets.len() >= MAX_REWARD_MARKETS {
+        return Err(RewardsError::MarketLimitReached);
+    }
+    Ok(())
+}
+
+/// Emission schedule of a liquidity mining market
+pub fn get_reward_emission(env: &Env, market: RewardMarket) -> Option<EmissionSchedule> {
+    env.storage()
+        .persistent()
+        .get(&RewardsDataKey::RewardEmission(market))
+}
+
+/// Liquidity mining markets ever funded
+pub fn get_reward_markets(env: &Env) -> Vec<RewardMarket> {
+    env.storage()
+        .persistent()
+        .get(&RewardsDataKey::RewardMarkets)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Settle `user`'s rewards in both markets of `asset` before their position changes
+///
+/// `previous` is the user's position and `totals` the asset totals as they
+/// stood up to now.
+pub fn checkpoint(
+    env: &Env,
+    user: &Address,
+    asset: Option<Address>,
+    previous: &crate::asset_positions::AssetPosition,
+    totals: &crate::asset_positions::AssetPosition,
+) {
+    for side in [RewardSide::Supply, RewardSide::Borrow] {
+        let market = RewardMarket {
+            asset: asset.clone(),
+            side,
+        };
+        if !env
+            .storage()
+            .persistent()
+            .has(&RewardsDataKey::RewardIndex(market.clone()))
+        {
+            continue;
+        }
+        let index = accrue_market(env, &market, side_balance(totals, side));
+        save_market_index(env, &market, &index);
+        let earned = earned_since(env, user, &market, &index, side_balance(previous, side));
+        add_accrued(env, user, earned);
+        env.storage().persistent().set(
+            &RewardsDataKey::UserRewardIndex(user.clone(), market),
+            &index.index,
+        );
+    }
+}
+
+/// Rewards `user` could claim now
+pub fn get_pending_rewards(env: &Env, user: &Address) -> i128 {
+    let mut pending = get_accrued(env, user);
+    for market in get_reward_markets(env).iter() {
+        let totals = crate::asset_positions::get_asset_totals(env, market.asset.clone());
+        let index = accrue_market(env, &market, side_balance(&totals, market.side));
+        let position = crate::asset_positions::get_asset_position(env, user, market.asset.clone());
+        pending = pending.saturating_add(earned_since(
+            env,
+            user,
+            &market,
+            &index,
+            side_balance(&position, market.side),
+        ));
+    }
+    pending
+}
+
+/// Settle every liquidity mining market for `user` and pay their rewards
+///
+/// Rewards are paid in the governance vote token.
+///
+/// # Returns
+/// The amount paid
+///
+/// # Errors
+/// * `RewardsError::GovernanceNotInitialized` - If there is no vote token
+/// * `RewardsError::NothingToClaim` - If no rewards have accrued
+/// * `RewardsError::InsufficientLiquidity` - If pool and strategy cannot cover the rewards
+#[allow(deprecated)]
+pub fn claim_rewards(env: &Env, user: Address) -> Result<i128, RewardsError> {
+    user.require_auth();
+    let config =
+        crate::governance::get_config(env).ok_or(RewardsError::GovernanceNotInitialized)?;
+
+    for market in get_reward_markets(env).iter() {
+        let position = crate::asset_positions::get_asset_position(env, &user, market.asset.clone());
+        let totals = crate::asset_positions::get_asset_totals(env, market.asset.clone());
+        checkpoint(env, &user, market.asset.clone(), &position, &totals);
+    }
+
+    let amount = get_accrued(env, &user);
+    if amount <= 0 {
+        return Err(RewardsError::NothingToClaim);
+    }
+    env.storage()
+        .persistent()
+        .remove(&RewardsDataKey::AccruedRewards(user.clone()));
+
+    crate::strategy::ensure_liquidity(env, &config.vote_token, amount)
+        .map_err(|_| RewardsError::InsufficientLiquidity)?;
+    TokenClient::new(env, &config.vote_token).transfer(
+        &env.current_contract_address(),
+        &user,
+        &amount,
+    );
+
+    let topics = (soroban_sdk::Symbol::new(env, "rewards_claimed"), user);
+    env.events().publish(topics, amount);
+
+    Ok(amount)
+}
+
+/// Index of `market` advanced to now, given its total balance `total`
+fn accrue_market(env: &Env, market: &RewardMarket, total: i128) -> RewardIndex {
+    let mut index: RewardIndex = env
+        .storage()
+        .persistent()
+        .get(&RewardsDataKey::RewardIndex(market.clone()))
+        .unwrap_or_default();
+    let now = env.ledger().timestamp();
+    if now <= index.last_update {
+        return index;
+    }
+
+    if let Some(schedule) = get_reward_emission(env, market.clone()) {
+        if total > 0 {
+            let emitted = emitted_between(&schedule, index.last_update, now);
+            index.index = index.index.saturating_add(
+                emitted
+                    .saturating_mul(REWARD_INDEX_SCALE)
+                    .checked_div(total)
+                    .unwrap_or(0),
+            );
+        }
+    }
+    index.last_update = now;
+    index
+}
+
+fn save_market_index(env: &Env, market: &RewardMarket, index: &RewardIndex) {
+    env.storage()
+        .persistent()
+        .set(&RewardsDataKey::RewardIndex(market.clone()), index);
+}
+
+/// Rewards earned on `balance` since `user`'s last checkpoint in `market`
+fn earned_since(
+    env: &Env,
+    user: &Address,
+    market: &RewardMarket,
+    index: &RewardIndex,
+    balance: i128,
+) -> i128 {
+    let user_index: i128 = env
+        .storage()
+        .persistent()
+        .get(&RewardsDataKey::UserRewardIndex(
+            user.clone(),
+            market.clone(),
+        ))
+        .unwrap_or(0);
+    balance
+        .saturating_mul(index.index.saturating_sub(user_index))
+        .checked_div(REWARD_INDEX_SCALE)
+        .unwrap_or(0)
+}
+
+fn get_accrued(env: &Env, user: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&RewardsDataKey::AccruedRewards(user.clone()))
+        .unwrap_or(0)
+}
+
+fn add_accrued(env: &Env, user: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let accrued = get_accrued(env, user).saturating_add(amount);
+    env.storage()
+        .persistent()
+        .set(&RewardsDataKey::AccruedRewards(user.clone()), &accrued);
+}
+
+fn side_balance(position: &crate::asset_positions::AssetPosition, side: RewardSide) -> i128 {
+    match side {
+        RewardSide::Supply => position.collateral,
+        RewardSide::Borrow => position.debt,
+    }
+}
+
+/// Rewards a schedule emits between `from` and `to`
+///
+/// Each epoch's budget is spread evenly over the epoch.
+fn emitted_between(schedule: &EmissionSchedule, from: u64, to: u64) -> i128 {
+    let duration = schedule.epoch_duration;
+    let end = schedule
+        .start_time
+        .saturating_add(duration.saturating_mul(schedule.total_epochs as u64));
+    let from = from.max(schedule.start_time);
+    let to = to.min(end);
+    if from >= to || duration == 0 {
+        return 0;
+    }
+
+    let mut epoch = ((from - schedule.start_time) / duration) as u32;
+    let mut budget = epoch_budget(schedule, epoch).unwrap_or(0);
+    let retain_bps = BASIS_POINTS_SCALE - schedule.decay_bps;
+    let mut emitted: i128 = 0;
+    let mut t = from;
+    while t < to {
+        let epoch_end = schedule.start_time + (epoch as u64 + 1) * duration;
+        let until = epoch_end.min(to);
+        emitted =
+            emitted.saturating_add(budget.saturating_mul((until - t) as i128) / duration as i128);
+        t = until;
+        epoch += 1;
+        budget = budget.saturating_mul(retain_bps) / BASIS_POINTS_SCALE;
+    }
+    emitted
+}
+
+/// Per-second rate of a schedule at `timestamp`
+fn schedule_rate(schedule: &EmissionSchedule, timestamp: u64) -> Result<i128, RewardsError> {
+    match epoch_at(schedule, timestamp) {
+        Some(epoch) => epoch_budget(schedule, epoch)?
+            .checked_div(schedule.epoch_duration as i128)
+            .ok_or(RewardsError::Overflow),
+        None => Ok(0),
+    }
+}
+
+/// Validate schedule bounds
+fn validate_schedule(schedule: &EmissionSchedule) -> Result<(), RewardsError> {
+    if schedule.epoch_duration < MIN_EPOCH_DURATION
+        || schedule.initial_budget < 0
+        || !(0..BASIS_POINTS_SCALE).contains(&schedule.decay_bps)
+        || schedule.total_epochs == 0
+        || schedule.total_epochs > MAX_EPOCHS
+    {
+        return Err(RewardsError::InvalidParameter);
+    }
+    Ok(())
+}
+
+/// Epoch containing `timestamp`, or `None` outside the schedule window
+fn epoch_at(schedule: &EmissionSchedule, timestamp: u64) -> Option<u32> {
+    if timestamp < schedule.start_time || schedule.epoch_duration == 0 {
+        return None;
+    }
+    let epoch = (timestamp - schedule.start_time) / schedule.epoch_duration;
+    if epoch >= schedule.total_epochs as u64 {
+        return None;
+    }
+    Some(epoch as u32)
+}
+
+/// Budget for `epoch` after applying geometric decay
+fn epoch_budget(schedule: &EmissionSchedule, epoch: u32) -> Result<i128, RewardsError> {
+    if epoch >= schedule.total_epochs {
+        return Ok(0);
+    }
+    let retain_bps = BASIS_POINTS_SCALE
+        .checked_sub(schedule.decay_bps)
+        .ok_or(RewardsError::Overflow)?;
+
+    let mut budget = schedule.initial_budget;
+    for _ in 0..epoch {
+        if budget == 0 {
+            break;
+        }
+        budget = budget
+            .checked_mul(retain_bps)
+            .ok_or(RewardsError::Overflow)?
+            .checked_div(BASIS_POINTS_SCALE)
+            .ok_or(RewardsError::Overflow)?;
+    }
+    Ok(budget)
+}
@@ -0,0 +1,424 @@
+//! # Risk Engine Module
+//!
+//! Optional automated tuning of risk parameters within governance-set bands.
+//!
+//! Once enabled by governance, anyone may call [`run_risk_engine`] for an
+//! asset (at most once per cooldown). Each run nudges parameters by one step
+//! towards the target implied by market conditions:
+//!
+//! - **Base borrow rate** (rate model): raised by `step_bps` while protocol
+//!   utilization is above `target_utilization_bps`, lowered while below,
+//!   always kept within `[min_base_rate_bps, max_base_rate_bps]`. The base
+//!   rate is shared by every asset, so this step runs at most once per
+//!   cooldown no matter which asset the run is for.
+//! - **Collateral factor** (per asset): lowered by `step_bps` while the
+//!   asset's oracle price volatility exceeds `volatility_threshold_bps`,
+//!   raised back otherwise, always kept within
+//!   `[min_collateral_factor, max_collateral_factor]`.
+//!
+//! Every change is recorded as a [`RiskAdjustment`], emitted as a
+//! `RiskEngineAdjustmentEvent`, and can be undone by a
+//! `RevertRiskAdjustment` governance proposal.
+//!
+//! ## Security
+//! - Settings and bands can only be changed through executed governance
+//!   proposals; the engine can never move a parameter outside its band.
+//! - Runs are rate-limited per asset by the governance-set cooldown, and the
+//!   protocol-wide base rate step by a single global cooldown, so runs for
+//!   many (even unregistered) assets cannot stack rate steps.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::deposit::DepositDataKey;
+use crate::events::{
+    emit_risk_engine_adjustment, emit_risk_engine_reverted, RiskEngineAdjustmentEvent,
+    RiskEngineRevertedEvent,
+};
+
+/// Basis points scale (100% = 10_000)
+pub const BASIS_POINTS_SCALE: i128 = 10_000;
+
+/// Parameter symbol for base rate adjustments
+const BASE_RATE: Symbol = symbol_short!("base_rate");
+
+/// Parameter symbol for collateral factor adjustments
+const COLLATERAL_FACTOR: Symbol = symbol_short!("cf");
+
+/// Errors that can occur during risk engine operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RiskEngineError {
+    /// A band or setting is out of range
+    InvalidParameter = 1,
+    /// The risk engine is not enabled
+    NotEnabled = 2,
+    /// The asset was adjusted too recently
+    CooldownActive = 3,
+    /// Adjustment record does not exist
+    AdjustmentNotFound = 4,
+    /// Adjustment has already been reverted
+    AlreadyReverted = 5,
+    /// Applying a parameter failed
+    ApplyFailed = 6,
+}
+
+/// Storage keys for risk engine data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum RiskEngineDataKey {
+    /// Engine settings: RiskEngineSettings
+    Settings,
+    /// Band for the base borrow rate: RateBand
+    RateBand,
+    /// Band for an asset's collateral factor: CollateralBand
+    CollateralBand(Option<Address>),
+    /// Timestamp of the last run for an asset: u64
+    LastRun(Option<Address>),
+    /// Timestamp of the last base rate step, across all assets: u64
+    LastRateRun,
+    /// Next adjustment id: u64
+    NextAdjustmentId,
+    /// Recorded adjustment: RiskAdjustment
+    Adjustment(u64),
+}
+
+/// Global risk engine settings
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RiskEngineSettings {
+    /// Whether automatic adjustments are allowed
+    pub enabled: bool,
+    /// Minimum seconds between runs for the same asset
+    pub cooldown_seconds: u64,
+}
+
+/// Governance band for the base borrow rate
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateBand {
+    /// Lowest base rate the engine may set (bps)
+    pub min_base_rate_bps: i128,
+    /// Highest base rate the engine may set (bps)
+    pub max_base_rate_bps: i128,
+    /// Change applied per run (bps)
+    pub step_bps: i128,
+    /// Utilization the engine steers towards (bps)
+    pub target_utilization_bps: i128,
+}
+
+/// Governance band for an asset's collateral factor
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollateralBand {
+    /// Lowest collateral factor the engine may set (bps)
+    pub min_collateral_factor: i128,
+    /// Highest collateral factor the engine may set (bps)
+    pub max_collateral_factor: i128,
+    /// Change applied per run (bps)
+    pub step_bps: i128,
+    /// Volatility above which the factor is tightened (bps)
+    pub volatility_threshold_bps: i128,
+}
+
+/// Record of a single automatic adjustment
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RiskAdjustment {
+    pub id: u64,
+    /// Asset the adjustment was made for
+    pub asset: Option<Address>,
+    /// Adjusted parameter (`base_rate` or `cf`)
+    pub parameter: Symbol,
+    pub old_value: i128,
+    pub new_value: i128,
+    pub timestamp: u64,
+    pub reverted: bool,
+}
+
+// ============================================================================
+// Governance Configuration
+// ============================================================================
+
+/// Enable or disable the engine and set its cooldown
+///
+/// Only reachable through an executed governance proposal.
+pub fn set_settings(env: &Env, enabled: bool, cooldown_seconds: u64) {
+    env.storage().persistent().set(
+        &RiskEngineDataKey::Settings,
+        &RiskEngineSettings {
+            enabled,
+            cooldown_seconds,
+        },
+    );
+}
+
+/// Set the base rate band
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `RiskEngineError::InvalidParameter` - If the band is empty, out of range
+///   or has a non-positive step
+pub fn set_rate_band(env: &Env, band: RateBand) -> Result<(), RiskEngineError> {
+    validate_band(band.min_base_rate_bps, band.max_base_rate_bps, band.step_bps)?;
+    if band.target_utilization_bps <= 0 || band.target_utilization_bps > BASIS_POINTS_SCALE {
+        return Err(RiskEngineError::InvalidParameter);
+    }
+    env.storage()
+        .persistent()
+        .set(&RiskEngineDataKey::RateBand, &band);
+    Ok(())
+}
+
+/// Set the collateral factor band for an asset
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `RiskEngineError::InvalidParameter` - If the band is empty, out of range
+///   or has a non-positive step
+pub fn set_collateral_band(
+    env: &Env,
+    asset: Option<Address>,
+    band: CollateralBand,
+) -> Result<(), RiskEngineError> {
+    validate_band(
+        band.min_collateral_factor,
+        band.max_collateral_factor,
+        band.step_bps,
+    )?;
+    if band.volatility_threshold_bps < 0 {
+        return Err(RiskEngineError::InvalidParameter);
+    }
+    env.storage()
+        .persistent()
+        .set(&RiskEngineDataKey::CollateralBand(asset), &band);
+    Ok(())
+}
+
+pub fn get_settings(env: &Env) -> Option<RiskEngineSettings> {
+    env.storage().persistent().get(&RiskEngineDataKey::Settings)
+}
+
+pub fn get_adjustment(env: &Env, id: u64) -> Option<RiskAdjustment> {
+    env.storage()
+        .persistent()
+        .get(&RiskEngineDataKey::Adjustment(id))
+}
+
+// ============================================================================
+// Engine
+// ============================================================================
+
+/// Run the risk engine for an asset
+///
+/// Permissionless. Applies at most one base rate step (if a rate band is
+/// set and the global rate cooldown has passed) and one collateral factor
+/// step (if the asset has a configured band).
+///
+/// # Returns
+/// Ids of the adjustments made (possibly empty)
+///
+/// # Errors
+/// * `RiskEngineError::NotEnabled` - If governance has not enabled the engine
+/// * `RiskEngineError::CooldownActive` - If the asset ran within the cooldown
+/// * `RiskEngineError::ApplyFailed` - If a parameter could not be read or set
+pub fn run_risk_engine(env: &Env, asset: Option<Address>) -> Result<Vec<u64>, RiskEngineError> {
+    let settings = get_settings(env)
+        .filter(|s| s.enabled)
+        .ok_or(RiskEngineError::NotEnabled)?;
+
+    let now = env.ledger().timestamp();
+    let last_run_key = RiskEngineDataKey::LastRun(asset.clone());
+    if let Some(last_run) = env.storage().persistent().get::<_, u64>(&last_run_key) {
+        if now < last_run.saturating_add(settings.cooldown_seconds) {
+            return Err(RiskEngineError::CooldownActive);
+        }
+    }
+    env.storage().persistent().set(&last_run_key, &now);
+
+    let mut ids = Vec::new(env);
+
+    let rate_band = env
+        .storage()
+        .persistent()
+        .get::<_, RateBand>(&RiskEngineDataKey::RateBand);
+    let rate_cooled_down = match env
+        .storage()
+        .persistent()
+        .get::<_, u64>(&RiskEngineDataKey::LastRateRun)
+    {
+        Some(last_rate_run) => now >= last_rate_run.saturating_add(settings.cooldown_seconds),
+        None => true,
+    };
+    if let Some(band) = rate_band.filter(|_| rate_cooled_down) {
+        env.storage()
+            .persistent()
+            .set(&RiskEngineDataKey::LastRateRun, &now);
+        let config = crate::interest_rate::get_interest_rate_config(env)
+            .ok_or(RiskEngineError::ApplyFailed)?;
+        let utilization = crate::interest_rate::calculate_utilization(env)
+            .map_err(|_| RiskEngineError::ApplyFailed)?;
+
+        let current = config.base_rate_bps;
+        let target = if utilization > band.target_utilization_bps {
+            current.saturating_add(band.step_bps)
+        } else if utilization < band.target_utilization_bps {
+            current.saturating_sub(band.step_bps)
+        } else {
+            current
+        };
+        let target = target.clamp(band.min_base_rate_bps, band.max_base_rate_bps);
+
+        if target != current {
+            crate::interest_rate::apply_base_rate(env, target)
+                .map_err(|_| RiskEngineError::ApplyFailed)?;
+            ids.push_back(record(env, asset.clone(), BASE_RATE, current, target));
+        }
+    }
+
+    if let Some(band) = env
+        .storage()
+        .persistent()
+        .get::<_, CollateralBand>(&RiskEngineDataKey::CollateralBand(asset.clone()))
+    {
+        let current = crate::cross_asset::get_asset_config_by_address(env, asset.clone())
+            .map_err(|_| RiskEngineError::ApplyFailed)?
+            .collateral_factor;
+        let volatility = asset_volatility(env, &asset);
+
+        let target = if volatility > band.volatility_threshold_bps {
+            current.saturating_sub(band.step_bps)
+        } else {
+            current.saturating_add(band.step_bps)
+        };
+        let target = target.clamp(band.min_collateral_factor, band.max_collateral_factor);
+
+        if target != current {
+            crate::cross_asset::apply_collateral_factor(env, asset.clone(), target)
+                .map_err(|_| RiskEngineError::ApplyFailed)?;
+            ids.push_back(record(env, asset, COLLATERAL_FACTOR, current, target));
+        }
+    }
+
+    Ok(ids)
+}
+
+/// Undo an automatic adjustment by restoring its previous value
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `RiskEngineError::AdjustmentNotFound` - If the id is unknown
+/// * `RiskEngineError::AlreadyReverted` - If it was already reverted
+/// * `RiskEngineError::ApplyFailed` - If the previous value cannot be applied
+pub fn revert_adjustment(env: &Env, id: u64) -> Result<(), RiskEngineError> {
+    let mut adjustment = get_adjustment(env, id).ok_or(RiskEngineError::AdjustmentNotFound)?;
+    if adjustment.reverted {
+        return Err(RiskEngineError::AlreadyReverted);
+    }
+
+    if adjustment.parameter == BASE_RATE {
+        crate::interest_rate::apply_base_rate(env, adjustment.old_value)
+            .map_err(|_| RiskEngineError::ApplyFailed)?;
+    } else {
+        crate::cross_asset::apply_collateral_factor(
+            env,
+            adjustment.asset.clone(),
+            adjustment.old_value,
+        )
+        .map_err(|_| RiskEngineError::ApplyFailed)?;
+    }
+
+    adjustment.reverted = true;
+    env.storage()
+        .persistent()
+        .set(&RiskEngineDataKey::Adjustment(id), &adjustment);
+
+    emit_risk_engine_reverted(
+        env,
+        RiskEngineRevertedEvent {
+            id,
+            asset: adjustment.asset,
+            parameter: adjustment.parameter,
+            restored_value: adjustment.old_value,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn validate_band(min: i128, max: i128, step: i128) -> Result<(), RiskEngineError> {
+    if min < 0 || max > BASIS_POINTS_SCALE || min > max || step <= 0 {
+        return Err(RiskEngineError::InvalidParameter);
+    }
+    Ok(())
+}
+
+/// Oracle volatility for an asset; native XLM resolves through its contract address
+fn asset_volatility(env: &Env, asset: &Option<Address>) -> i128 {
+    let address = match asset {
+        Some(addr) => Some(addr.clone()),
+        None => env
+            .storage()
+            .persistent()
+            .get::<DepositDataKey, Address>(&DepositDataKey::NativeAssetAddress),
+    };
+    address
+        .map(|addr| crate::oracle::get_price_volatility(env, &addr))
+        .unwrap_or(0)
+}
+
+/// Store an adjustment record and emit its event
+fn record(
+    env: &Env,
+    asset: Option<Address>,
+    parameter: Symbol,
+    old_value: i128,
+    new_value: i128,
+) -> u64 {
+    let id: u64 = env
+        .storage()
+        .persistent()
+        .get(&RiskEngineDataKey::NextAdjustmentId)
+        .unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&RiskEngineDataKey::NextAdjustmentId, &(id + 1));
+
+    let timestamp = env.ledger().timestamp();
+    env.storage().persistent().set(
+        &RiskEngineDataKey::Adjustment(id),
+        &RiskAdjustment {
+            id,
+            asset: asset.clone(),
+            parameter: parameter.clone(),
+            old_value,
+            new_value,
+            timestamp,
+            reverted: false,
+        },
+    );
+
+    emit_risk_engine_adjustment(
+        env,
+        RiskEngineAdjustmentEvent {
+            id,
+            asset,
+            parameter,
+            old_value,
+            new_value,
+            timestamp,
+        },
+    );
+
+    id
+}
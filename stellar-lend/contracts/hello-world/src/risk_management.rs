@@ -9,13 +9,26 @@
 //! - **Liquidation incentive** (default 10%): bonus awarded to liquidators
 //!
 //! ## Pause Controls
-//! - Per-operation pause switches (deposit, withdraw, borrow, repay, liquidate)
+//! - Per-operation pause switches, addressed by [`PauseOperation`] (deposit,
+//!   withdraw, borrow, repay, liquidate, flash loan, oracle)
 //! - Global emergency pause that halts all operations immediately
+//! - [`get_pause_state`] returns every switch plus the emergency flag in one
+//!   [`PauseState`] for monitoring
+//! - Per-asset switches ([`set_asset_pause`]) halt deposits, withdrawals or
+//!   borrows of a single asset (e.g. a depegging stablecoin) while the rest of
+//!   the protocol keeps running
+//! - Frozen assets ([`set_asset_frozen`]) reject new deposits and borrows but
+//!   still allow repayments, withdrawals and liquidations, so a market can be
+//!   wound down without trapping existing positions
+//! - Every pause and unpause is recorded by [`crate::pause_downtime`], which
+//!   accumulates total paused time per operation
 //!
 //! ## Safety
 //! - Parameter changes are limited to ±10% per update to prevent drastic shifts.
 //! - Min collateral ratio must always be ≥ liquidation threshold.
-//! - Only the admin address can modify risk parameters.
+//! - Only the admin address or a `RiskAdmin` can modify risk parameters.
+//! - Pause switches and the emergency pause need the `PauseGuardian` role (or
+//!   the admin).
 
 #![allow(unused)]
 use crate::events::{
@@ -73,6 +86,12 @@ pub enum RiskDataKey {
     /// Timelock for safety of sensitive parameter changes
     /// Value type: u64 (timestamp)
     ParameterChangeTimelock,
+    /// Pause switch of an operation for a single asset (`None` = native XLM)
+    /// Value type: bool
+    AssetPause(Option<Address>, PauseOperation),
+    /// Wind-down freeze of a single asset (`None` = native XLM)
+    /// Value type: bool
+    AssetFrozen(Option<Address>),
 }
 
 /// Risk configuration parameters for pause switches
@@ -87,7 +106,7 @@ pub struct RiskConfig {
 
 /// Pause switch operation types
 #[contracttype]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum PauseOperation {
     /// Pause deposit operations
     Deposit,
@@ -99,10 +118,58 @@ pub enum PauseOperation {
     Repay,
     /// Pause liquidation operations
     Liquidate,
-    /// Pause all operations (emergency)
+    /// Pause flash loans
+    FlashLoan,
+    /// Pause oracle price updates
+    Oracle,
+    /// Every switch above at once (distinct from the emergency pause)
     All,
 }
 
+/// Operations with an individual pause switch
+const PAUSABLE_OPERATIONS: [PauseOperation; 7] = [
+    PauseOperation::Deposit,
+    PauseOperation::Withdraw,
+    PauseOperation::Borrow,
+    PauseOperation::Repay,
+    PauseOperation::Liquidate,
+    PauseOperation::FlashLoan,
+    PauseOperation::Oracle,
+];
+
+impl PauseOperation {
+    /// Key of the operation's switch in `RiskConfig::pause_switches`
+    ///
+    /// `All` has no switch of its own and maps to `None`.
+    pub fn switch_key(&self, env: &Env) -> Option<Symbol> {
+        let key = match self {
+            PauseOperation::Deposit => "pause_deposit",
+            PauseOperation::Withdraw => "pause_withdraw",
+            PauseOperation::Borrow => "pause_borrow",
+            PauseOperation::Repay => "pause_repay",
+            PauseOperation::Liquidate => "pause_liquidate",
+            PauseOperation::FlashLoan => "pause_flash_loan",
+            PauseOperation::Oracle => "pause_oracle",
+            PauseOperation::All => return None,
+        };
+        Some(Symbol::new(env, key))
+    }
+}
+
+/// Snapshot of every pause switch and the emergency pause
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PauseState {
+    pub deposit: bool,
+    pub withdraw: bool,
+    pub borrow: bool,
+    pub repay: bool,
+    pub liquidate: bool,
+    pub flash_loan: bool,
+    pub oracle: bool,
+    pub emergency: bool,
+}
+
 /// Initialize risk management system
 ///
 /// Sets up default risk parameters and admin address.
@@ -140,6 +207,7 @@ pub fn initialize_risk_management(env: &Env, admin: Address) -> Result<(), RiskM
     // Initialize emergency pause as false
     let emergency_key = RiskDataKey::EmergencyPause;
     env.storage().persistent().set(&emergency_key, &false);
+    crate::hot_config::refresh(env);
 
     emit_admin_action(
         env,
@@ -190,7 +258,7 @@ pub fn get_risk_config(env: &Env) -> Option<RiskConfig> {
 /// # Arguments
 /// * `env` - The Soroban environment
 /// * `caller` - The caller address (must be admin)
-/// * `operation` - The operation to pause/unpause (as Symbol)
+/// * `operation` - The operation to pause/unpause (`All` sets every switch)
 /// * `paused` - Whether to pause (true) or unpause (false)
 ///
 /// # Returns
@@ -201,29 +269,12 @@ pub fn get_risk_config(env: &Env) -> Option<RiskConfig> {
 pub fn set_pause_switch(
     env: &Env,
     caller: Address,
-    operation: Symbol,
+    operation: PauseOperation,
     paused: bool,
 ) -> Result<(), RiskManagementError> {
-    // Check admin
-    require_admin(env, &caller)?;
-
-    // Get current config
-    let mut config = get_risk_config(env).ok_or(RiskManagementError::InvalidParameter)?;
-
-    // Update pause switch
-    config.pause_switches.set(operation.clone(), paused);
-
-    // Update timestamp
-    config.last_update = env.ledger().timestamp();
-
-    // Save config
-    let config_key = RiskDataKey::RiskConfig;
-    env.storage().persistent().set(&config_key, &config);
-
-    // Emit event
-    emit_pause_switch_updated_event(env, &caller, &operation, paused);
-
-    Ok(())
+    let mut switches = Map::new(env);
+    switches.set(operation, paused);
+    set_pause_switches(env, caller, switches)
 }
 
 /// Set multiple pause switches at once (admin only)
@@ -231,24 +282,66 @@ pub fn set_pause_switch(
 /// # Arguments
 /// * `env` - The Soroban environment
 /// * `caller` - The caller address (must be admin)
-/// * `switches` - Map of operation symbols to pause states
+/// * `switches` - Map of operations to pause states (`All` sets every switch)
 ///
 /// # Returns
 /// Returns Ok(()) on success
 pub fn set_pause_switches(
     env: &Env,
     caller: Address,
-    switches: Map<Symbol, bool>,
+    switches: Map<PauseOperation, bool>,
+) -> Result<(), RiskManagementError> {
+    // Check pause guardian
+    crate::admin::require_role(env, &caller, crate::admin::Role::PauseGuardian)
+        .map_err(|_| RiskManagementError::Unauthorized)?;
+
+    apply_pause_switches(env, &caller, switches)
+}
+
+/// Set a pause switch without the pause guardian check
+///
+/// Only reachable through an executed governance proposal, whose vote
+/// stands in for the guardian role. Events name the contract as caller.
+pub fn set_pause_switch_governed(
+    env: &Env,
+    operation: PauseOperation,
+    paused: bool,
 ) -> Result<(), RiskManagementError> {
-    // Check admin
-    require_admin(env, &caller)?;
+    let mut switches = Map::new(env);
+    switches.set(operation, paused);
+    apply_pause_switches(env, &env.current_contract_address(), switches)
+}
 
+fn apply_pause_switches(
+    env: &Env,
+    caller: &Address,
+    switches: Map<PauseOperation, bool>,
+) -> Result<(), RiskManagementError> {
     // Get current config
     let mut config = get_risk_config(env).ok_or(RiskManagementError::InvalidParameter)?;
 
-    // Update all pause switches
+    // Resolve operations to switch keys, expanding `All`
+    let mut updates: Map<Symbol, bool> = Map::new(env);
     for (op, paused) in switches.iter() {
-        config.pause_switches.set(op, paused);
+        match op.switch_key(env) {
+            Some(key) => {
+                updates.set(key, paused);
+                crate::pause_downtime::record_operation(env, op, paused);
+            }
+            None => {
+                for each in PAUSABLE_OPERATIONS.iter() {
+                    if let Some(key) = each.switch_key(env) {
+                        updates.set(key, paused);
+                        crate::pause_downtime::record_operation(env, *each, paused);
+                    }
+                }
+            }
+        }
+    }
+
+    // Update all pause switches
+    for (key, paused) in updates.iter() {
+        config.pause_switches.set(key, paused);
     }
 
     // Update timestamp
@@ -257,36 +350,55 @@ pub fn set_pause_switches(
     // Save config
     let config_key = RiskDataKey::RiskConfig;
     env.storage().persistent().set(&config_key, &config);
+    crate::hot_config::refresh(env);
 
     // Emit event
-    emit_pause_switches_updated_event(env, &caller, &switches);
+    emit_pause_switches_updated_event(env, caller, &updates);
 
     Ok(())
 }
 
 /// Check if an operation is paused
-pub fn is_operation_paused(env: &Env, operation: Symbol) -> bool {
-    if let Some(config) = get_risk_config(env) {
-        config.pause_switches.get(operation).unwrap_or(false)
-    } else {
-        false
+///
+/// `All` is paused only when every individual switch is.
+pub fn is_operation_paused(env: &Env, operation: PauseOperation) -> bool {
+    let config = crate::hot_config::load(env);
+    match operation {
+        PauseOperation::All => PAUSABLE_OPERATIONS
+            .iter()
+            .all(|op| config.is_switch_on(env, *op)),
+        _ => config.is_switch_on(env, operation),
     }
 }
 
 /// Require that an operation is not paused
 pub fn require_operation_not_paused(
     env: &Env,
-    operation: Symbol,
+    operation: PauseOperation,
 ) -> Result<(), RiskManagementError> {
-    if is_operation_paused(env, operation.clone()) {
+    if is_operation_paused(env, operation) {
         return Err(RiskManagementError::OperationPaused);
     }
     Ok(())
 }
 
+/// All pause switches plus the emergency pause, for monitoring
+pub fn get_pause_state(env: &Env) -> PauseState {
+    PauseState {
+        deposit: is_operation_paused(env, PauseOperation::Deposit),
+        withdraw: is_operation_paused(env, PauseOperation::Withdraw),
+        borrow: is_operation_paused(env, PauseOperation::Borrow),
+        repay: is_operation_paused(env, PauseOperation::Repay),
+        liquidate: is_operation_paused(env, PauseOperation::Liquidate),
+        flash_loan: is_operation_paused(env, PauseOperation::FlashLoan),
+        oracle: is_operation_paused(env, PauseOperation::Oracle),
+        emergency: is_emergency_paused(env),
+    }
+}
+
 /// Check if operation is paused (public helper for other modules)
 /// This is a convenience function that can be called from other modules
-pub fn check_operation_paused(env: &Env, operation: Symbol) -> bool {
+pub fn check_operation_paused(env: &Env, operation: PauseOperation) -> bool {
     // First check emergency pause
     if is_emergency_paused(env) {
         return true;
@@ -311,21 +423,39 @@ pub fn set_emergency_pause(
     caller: Address,
     paused: bool,
 ) -> Result<(), RiskManagementError> {
-    // Check admin
-    require_admin(env, &caller)?;
+    // Check pause guardian
+    crate::admin::require_role(env, &caller, crate::admin::Role::PauseGuardian)
+        .map_err(|_| RiskManagementError::Unauthorized)?;
+
+    apply_emergency_pause(env, &caller, paused);
+    Ok(())
+}
 
+/// Set emergency pause without the pause guardian check
+///
+/// Only reachable through an executed governance proposal.
+pub fn set_emergency_pause_governed(env: &Env, paused: bool) {
+    apply_emergency_pause(env, &env.current_contract_address(), paused);
+}
+
+fn apply_emergency_pause(env: &Env, caller: &Address, paused: bool) {
     // Set emergency pause
     let emergency_key = RiskDataKey::EmergencyPause;
     env.storage().persistent().set(&emergency_key, &paused);
+    crate::hot_config::refresh(env);
+    crate::pause_downtime::record_emergency(env, paused);
 
     // Emit event
-    emit_emergency_pause_event(env, &caller, paused);
-
-    Ok(())
+    emit_emergency_pause_event(env, caller, paused);
 }
 
-/// Check if emergency pause is active
+/// Check if emergency pause is active (served from the hot configuration cache)
 pub fn is_emergency_paused(env: &Env) -> bool {
+    crate::hot_config::load(env).emergency_paused
+}
+
+/// Read the emergency pause from its persistent entry, bypassing the cache
+pub(crate) fn stored_emergency_pause(env: &Env) -> bool {
     let emergency_key = RiskDataKey::EmergencyPause;
     env.storage()
         .persistent()
@@ -341,17 +471,139 @@ pub fn check_emergency_pause(env: &Env) -> Result<(), RiskManagementError> {
     Ok(())
 }
 
-/// Emit pause switch updated event
-fn emit_pause_switch_updated_event(env: &Env, caller: &Address, operation: &Symbol, paused: bool) {
-    emit_pause_state_changed(
-        env,
-        PauseStateChangedEvent {
-            actor: caller.clone(),
-            operation: operation.clone(),
-            paused,
-            timestamp: env.ledger().timestamp(),
-        },
-    );
+/// Operations that can be paused per asset
+const ASSET_PAUSABLE_OPERATIONS: [PauseOperation; 3] = [
+    PauseOperation::Deposit,
+    PauseOperation::Withdraw,
+    PauseOperation::Borrow,
+];
+
+/// Pause or unpause an operation for a single asset
+///
+/// Only deposits, withdrawals and borrows can be paused per asset; `All`
+/// sets all three. The global switches and the emergency pause still apply
+/// on top.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The caller address (must be a pause guardian or the admin)
+/// * `asset` - The asset to pause (`None` for native XLM)
+/// * `operation` - The operation to pause/unpause
+/// * `paused` - Whether to pause (true) or unpause (false)
+///
+/// # Errors
+/// * `RiskManagementError::Unauthorized` - If caller is not a pause guardian or the admin
+/// * `RiskManagementError::InvalidParameter` - If the operation cannot be paused per asset
+#[allow(deprecated)]
+pub fn set_asset_pause(
+    env: &Env,
+    caller: Address,
+    asset: Option<Address>,
+    operation: PauseOperation,
+    paused: bool,
+) -> Result<(), RiskManagementError> {
+    caller.require_auth();
+    crate::admin::require_role(env, &caller, crate::admin::Role::PauseGuardian)
+        .map_err(|_| RiskManagementError::Unauthorized)?;
+
+    let operations: &[PauseOperation] = match operation {
+        PauseOperation::All => &ASSET_PAUSABLE_OPERATIONS,
+        PauseOperation::Deposit => &[PauseOperation::Deposit],
+        PauseOperation::Withdraw => &[PauseOperation::Withdraw],
+        PauseOperation::Borrow => &[PauseOperation::Borrow],
+        _ => return Err(RiskManagementError::InvalidParameter),
+    };
+
+    for op in operations.iter() {
+        let key = RiskDataKey::AssetPause(asset.clone(), *op);
+        if paused {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    let topics = (Symbol::new(env, "asset_pause_updated"), caller);
+    env.events()
+        .publish(topics, (asset, operation, paused, env.ledger().timestamp()));
+
+    Ok(())
+}
+
+/// Whether `operation` is paused for `asset` by its per-asset switch
+///
+/// Ignores the global switches and the emergency pause; `All` is paused
+/// only when deposits, withdrawals and borrows all are.
+pub fn is_asset_paused(env: &Env, asset: Option<Address>, operation: PauseOperation) -> bool {
+    let stored = |op: PauseOperation| {
+        env.storage()
+            .persistent()
+            .get::<RiskDataKey, bool>(&RiskDataKey::AssetPause(asset.clone(), op))
+            .unwrap_or(false)
+    };
+    match operation {
+        PauseOperation::All => ASSET_PAUSABLE_OPERATIONS.iter().all(|op| stored(*op)),
+        _ => stored(operation),
+    }
+}
+
+/// Freeze or unfreeze an asset
+///
+/// A frozen asset rejects new deposits and borrows; repayments, withdrawals
+/// and liquidations keep working so existing positions can unwind. Unlike
+/// [`set_asset_pause`], this is meant for winding a market down rather than
+/// halting it.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The caller address (must be a risk admin or the admin)
+/// * `asset` - The asset to freeze (`None` for native XLM)
+/// * `frozen` - Whether to freeze (true) or unfreeze (false)
+///
+/// # Errors
+/// * `RiskManagementError::Unauthorized` - If caller is not a risk admin or the admin
+#[allow(deprecated)]
+pub fn set_asset_frozen(
+    env: &Env,
+    caller: Address,
+    asset: Option<Address>,
+    frozen: bool,
+) -> Result<(), RiskManagementError> {
+    caller.require_auth();
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| RiskManagementError::Unauthorized)?;
+
+    store_asset_frozen(env, asset.clone(), frozen);
+
+    let topics = (Symbol::new(env, "asset_frozen_updated"), caller);
+    env.events()
+        .publish(topics, (asset, frozen, env.ledger().timestamp()));
+
+    Ok(())
+}
+
+/// Write an asset's freeze flag without an access check (for governance flows)
+pub(crate) fn store_asset_frozen(env: &Env, asset: Option<Address>, frozen: bool) {
+    let key = RiskDataKey::AssetFrozen(asset);
+    if frozen {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Whether `asset` is frozen, either by [`set_asset_frozen`] or by the depeg
+/// breaker
+pub fn is_asset_frozen(env: &Env, asset: Option<Address>) -> bool {
+    if let Some(ref asset_addr) = asset {
+        if crate::depeg::is_frozen(env, asset_addr) {
+            return true;
+        }
+    }
+    env.storage()
+        .persistent()
+        .get::<RiskDataKey, bool>(&RiskDataKey::AssetFrozen(asset))
+        .unwrap_or(false)
 }
 
 /// Emit pause switches updated event
@@ -31,6 +31,14 @@ pub enum RiskParamsError {
 pub enum RiskParamsDataKey {
     /// Risk configuration parameters
     RiskParamsConfig,
+    /// Volatility-based collateral factor haircut per asset
+    VolatilityHaircut(Address),
+    /// Governed maximum change per admin update (basis points of the old value)
+    MaxParameterChangeBps,
+    /// Hysteresis buffer around the liquidation threshold (ratio basis points): i128
+    LiquidationHysteresisBps,
+    /// Borrower that became liquidatable and has not yet recovered above the upper mark: bool
+    LiquidationFlagged(Address),
 }
 
 /// Risk parameters
@@ -53,6 +61,24 @@ pub struct RiskParams {
     pub last_update: u64,
 }
 
+/// Collateral factor haircut applied while an asset's price is volatile
+///
+/// Volatility is the oracle's EWMA of absolute price moves (basis points).
+/// At or above `severe_threshold_bps` the severe haircut applies, at or above
+/// `threshold_bps` the normal one, otherwise none.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VolatilityHaircut {
+    /// Volatility at which the haircut starts (in basis points)
+    pub threshold_bps: i128,
+    /// Haircut applied to the collateral factor above `threshold_bps` (in basis points)
+    pub haircut_bps: i128,
+    /// Volatility at which the severe haircut starts (in basis points)
+    pub severe_threshold_bps: i128,
+    /// Haircut applied above `severe_threshold_bps` (in basis points)
+    pub severe_haircut_bps: i128,
+}
+
 /// Constants for parameter validation
 const BASIS_POINTS_SCALE: i128 = 10_000; // 100% = 10,000 basis points
 const MIN_COLLATERAL_RATIO_MIN: i128 = 10_000; // 100% minimum
@@ -63,7 +89,10 @@ const CLOSE_FACTOR_MIN: i128 = 0; // 0% minimum
 const CLOSE_FACTOR_MAX: i128 = BASIS_POINTS_SCALE; // 100% maximum
 const LIQUIDATION_INCENTIVE_MIN: i128 = 0; // 0% minimum
 const LIQUIDATION_INCENTIVE_MAX: i128 = 5_000; // 50% maximum (safety limit)
-const MAX_PARAMETER_CHANGE_BPS: i128 = 1_000; // 10% maximum change per update
+const MAX_PARAMETER_CHANGE_BPS: i128 = 1_000; // 10% default maximum change per update
+const PARAMETER_CHANGE_LIMIT_MIN: i128 = 100; // 1% lowest governable step limit
+const PARAMETER_CHANGE_LIMIT_MAX: i128 = 5_000; // 50% highest governable step limit
+const LIQUIDATION_HYSTERESIS_MAX: i128 = 500; // 5% widest buffer around the liquidation threshold
 
 /// Initialize risk parameters
 ///
@@ -91,12 +120,33 @@ pub fn initialize_risk_params(env: &Env) -> Result<(), RiskParamsError> {
 
     let config_key = RiskParamsDataKey::RiskParamsConfig;
     env.storage().persistent().set(&config_key, &default_config);
+    crate::hot_config::refresh(env);
 
     Ok(())
 }
 
-/// Get current risk parameters
+/// Get current risk parameters (served from the hot configuration cache)
+///
+/// A liquidation threshold change still ramping in (see
+/// [`crate::param_ramp`]) is returned at its current interpolated value.
 pub fn get_risk_params(env: &Env) -> Option<RiskParams> {
+    let mut config = configured_risk_params(env)?;
+    config.liquidation_threshold = crate::param_ramp::current(
+        env,
+        &crate::param_ramp::RampedParam::LiquidationThreshold,
+        config.liquidation_threshold,
+    )
+    .min(config.min_collateral_ratio);
+    Some(config)
+}
+
+/// Risk parameters as configured, ignoring any running ramp
+fn configured_risk_params(env: &Env) -> Option<RiskParams> {
+    crate::hot_config::load(env).risk_params
+}
+
+/// Read risk parameters from their persistent entry, bypassing the cache
+pub(crate) fn stored_risk_params(env: &Env) -> Option<RiskParams> {
     let config_key = RiskParamsDataKey::RiskParamsConfig;
     env.storage()
         .persistent()
@@ -140,15 +190,22 @@ fn validate_risk_params(config: &RiskParams) -> Result<(), RiskParamsError> {
 }
 
 /// Validate parameter change doesn't exceed maximum allowed change
-fn validate_parameter_change(old_value: i128, new_value: i128) -> Result<(), RiskParamsError> {
+fn validate_parameter_change(
+    env: &Env,
+    old_value: i128,
+    new_value: i128,
+) -> Result<(), RiskParamsError> {
     let change = if new_value > old_value {
         new_value - old_value
     } else {
         old_value - new_value
     };
 
-    // Calculate maximum allowed change (10% of old value)
-    let max_change = (old_value * MAX_PARAMETER_CHANGE_BPS) / BASIS_POINTS_SCALE;
+    // Calculate maximum allowed change (governed share of old value, 10% by default)
+    let max_change = old_value
+        .checked_mul(get_max_parameter_change_bps(env))
+        .ok_or(RiskParamsError::Overflow)?
+        / BASIS_POINTS_SCALE;
 
     if change > max_change {
         return Err(RiskParamsError::ParameterChangeTooLarge);
@@ -157,9 +214,48 @@ fn validate_parameter_change(old_value: i128, new_value: i128) -> Result<(), Ris
     Ok(())
 }
 
+/// Get the maximum change per admin update (basis points of the old value)
+pub fn get_max_parameter_change_bps(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&RiskParamsDataKey::MaxParameterChangeBps)
+        .unwrap_or(MAX_PARAMETER_CHANGE_BPS)
+}
+
+/// Set the maximum change per admin update
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `RiskParamsError::InvalidParameter` - If the limit is outside `[100, 5000]`
+#[allow(deprecated)]
+pub fn set_max_parameter_change_bps(env: &Env, limit_bps: i128) -> Result<(), RiskParamsError> {
+    if !(PARAMETER_CHANGE_LIMIT_MIN..=PARAMETER_CHANGE_LIMIT_MAX).contains(&limit_bps) {
+        return Err(RiskParamsError::InvalidParameter);
+    }
+
+    let old_limit_bps = get_max_parameter_change_bps(env);
+    env.storage()
+        .persistent()
+        .set(&RiskParamsDataKey::MaxParameterChangeBps, &limit_bps);
+
+    let topics = (Symbol::new(env, "parameter_change_limit_updated"),);
+    env.events().publish(topics, limit_bps);
+    crate::events::emit_param_change(
+        env,
+        "max_parameter_change_bps",
+        None,
+        old_limit_bps,
+        limit_bps,
+    );
+
+    Ok(())
+}
+
 /// Set risk parameters (admin only - caller check should be done by the contract)
 ///
-/// Updates risk parameters with validation and change limits.
+/// Updates risk parameters with validation and the governed per-update
+/// change limit.
 ///
 /// # Arguments
 /// * `env` - The Soroban environment
@@ -177,43 +273,147 @@ pub fn set_risk_params(
     close_factor: Option<i128>,
     liquidation_incentive: Option<i128>,
 ) -> Result<(), RiskParamsError> {
-    let mut config = get_risk_params(env).ok_or(RiskParamsError::InvalidParameter)?;
+    update_risk_params(
+        env,
+        min_collateral_ratio,
+        liquidation_threshold,
+        close_factor,
+        liquidation_incentive,
+        true,
+    )
+}
+
+/// Set risk parameters without the per-update change limit
+///
+/// Only reachable through an executed governance proposal, whose vote and
+/// timelock stand in for the step limit. Range validation still applies.
+pub fn set_risk_params_governed(
+    env: &Env,
+    min_collateral_ratio: Option<i128>,
+    liquidation_threshold: Option<i128>,
+    close_factor: Option<i128>,
+    liquidation_incentive: Option<i128>,
+) -> Result<(), RiskParamsError> {
+    update_risk_params(
+        env,
+        min_collateral_ratio,
+        liquidation_threshold,
+        close_factor,
+        liquidation_incentive,
+        false,
+    )
+}
+
+fn update_risk_params(
+    env: &Env,
+    min_collateral_ratio: Option<i128>,
+    liquidation_threshold: Option<i128>,
+    close_factor: Option<i128>,
+    liquidation_incentive: Option<i128>,
+    enforce_change_limit: bool,
+) -> Result<(), RiskParamsError> {
+    let mut config = merged_risk_params(
+        env,
+        min_collateral_ratio,
+        liquidation_threshold,
+        close_factor,
+        liquidation_incentive,
+        enforce_change_limit,
+    )?;
+
+    // Ramp a liquidation threshold change in over the configured duration
+    if let Some(previous) = configured_risk_params(env) {
+        if previous.liquidation_threshold != config.liquidation_threshold {
+            crate::param_ramp::start(
+                env,
+                crate::param_ramp::RampedParam::LiquidationThreshold,
+                previous.liquidation_threshold,
+                config.liquidation_threshold,
+            );
+        }
+    }
+
+    // Update timestamp
+    config.last_update = env.ledger().timestamp();
+
+    // Save config
+    let config_key = RiskParamsDataKey::RiskParamsConfig;
+    env.storage().persistent().set(&config_key, &config);
+    crate::hot_config::refresh(env);
+
+    // Emit event
+    emit_risk_params_updated_event(env, &config);
+
+    Ok(())
+}
+
+/// Check a governed risk parameter update against the current config without
+/// applying it
+///
+/// Used when a proposal is created so that out-of-range values are rejected
+/// before the vote instead of failing at execution.
+pub fn check_risk_params_governed(
+    env: &Env,
+    min_collateral_ratio: Option<i128>,
+    liquidation_threshold: Option<i128>,
+    close_factor: Option<i128>,
+    liquidation_incentive: Option<i128>,
+) -> Result<(), RiskParamsError> {
+    merged_risk_params(
+        env,
+        min_collateral_ratio,
+        liquidation_threshold,
+        close_factor,
+        liquidation_incentive,
+        false,
+    )
+    .map(|_| ())
+}
+
+/// Current risk parameters with the given updates applied and validated
+fn merged_risk_params(
+    env: &Env,
+    min_collateral_ratio: Option<i128>,
+    liquidation_threshold: Option<i128>,
+    close_factor: Option<i128>,
+    liquidation_incentive: Option<i128>,
+    enforce_change_limit: bool,
+) -> Result<RiskParams, RiskParamsError> {
+    let mut config = configured_risk_params(env).ok_or(RiskParamsError::InvalidParameter)?;
 
     // Update parameters if provided
     if let Some(mcr) = min_collateral_ratio {
-        validate_parameter_change(config.min_collateral_ratio, mcr)?;
+        if enforce_change_limit {
+            validate_parameter_change(env, config.min_collateral_ratio, mcr)?;
+        }
         config.min_collateral_ratio = mcr;
     }
 
     if let Some(lt) = liquidation_threshold {
-        validate_parameter_change(config.liquidation_threshold, lt)?;
+        if enforce_change_limit {
+            validate_parameter_change(env, config.liquidation_threshold, lt)?;
+        }
         config.liquidation_threshold = lt;
     }
 
     if let Some(cf) = close_factor {
-        validate_parameter_change(config.close_factor, cf)?;
+        if enforce_change_limit {
+            validate_parameter_change(env, config.close_factor, cf)?;
+        }
         config.close_factor = cf;
     }
 
     if let Some(li) = liquidation_incentive {
-        validate_parameter_change(config.liquidation_incentive, li)?;
+        if enforce_change_limit {
+            validate_parameter_change(env, config.liquidation_incentive, li)?;
+        }
         config.liquidation_incentive = li;
     }
 
     // Validate the updated config
     validate_risk_params(&config)?;
 
-    // Update timestamp
-    config.last_update = env.ledger().timestamp();
-
-    // Save config
-    let config_key = RiskParamsDataKey::RiskParamsConfig;
-    env.storage().persistent().set(&config_key, &config);
-
-    // Emit event
-    emit_risk_params_updated_event(env, &config);
-
-    Ok(())
+    Ok(config)
 }
 
 /// Emit risk parameters updated event
@@ -223,6 +423,94 @@ fn emit_risk_params_updated_event(env: &Env, config: &RiskParams) {
     env.events().publish(topics, config.clone());
 }
 
+/// Set the volatility haircut for an asset
+///
+/// # Errors
+/// * `RiskParamsError::InvalidParameter` - If thresholds are negative or out of
+///   order, or haircuts are outside `[0, 10000]` or decrease with severity
+pub fn set_volatility_haircut(
+    env: &Env,
+    asset: Address,
+    haircut: VolatilityHaircut,
+) -> Result<(), RiskParamsError> {
+    if haircut.threshold_bps < 0
+        || haircut.severe_threshold_bps < haircut.threshold_bps
+        || !(0..=BASIS_POINTS_SCALE).contains(&haircut.haircut_bps)
+        || !(haircut.haircut_bps..=BASIS_POINTS_SCALE).contains(&haircut.severe_haircut_bps)
+    {
+        return Err(RiskParamsError::InvalidParameter);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&RiskParamsDataKey::VolatilityHaircut(asset.clone()), &haircut);
+
+    #[allow(deprecated)]
+    env.events().publish(
+        (Symbol::new(env, "volatility_haircut_updated"), asset),
+        haircut,
+    );
+
+    Ok(())
+}
+
+/// Get the volatility haircut configuration for an asset
+pub fn get_volatility_haircut(env: &Env, asset: &Address) -> Option<VolatilityHaircut> {
+    env.storage()
+        .persistent()
+        .get(&RiskParamsDataKey::VolatilityHaircut(asset.clone()))
+}
+
+/// Get the haircut (in basis points) currently applied to an asset's collateral factor
+///
+/// Returns 0 when no haircut is configured or volatility is below the threshold.
+pub fn get_current_haircut_bps(env: &Env, asset: &Address) -> i128 {
+    let haircut = match get_volatility_haircut(env, asset) {
+        Some(haircut) => haircut,
+        None => return 0,
+    };
+    let volatility = crate::oracle::get_price_volatility(env, asset);
+
+    if volatility >= haircut.severe_threshold_bps {
+        haircut.severe_haircut_bps
+    } else if volatility >= haircut.threshold_bps {
+        haircut.haircut_bps
+    } else {
+        0
+    }
+}
+
+/// Apply any running change ramp, the current volatility haircut and any
+/// delisting ramp to a collateral factor
+///
+/// `effective = ramped * (10000 - haircut) / 10000`, then scaled by
+/// [`crate::asset_registry::sunset_collateral_factor`], where `ramped` is the
+/// configured factor interpolated by [`crate::param_ramp`]
+pub fn effective_collateral_factor(
+    env: &Env,
+    asset: Option<&Address>,
+    collateral_factor: i128,
+) -> i128 {
+    let Some(addr) = asset else {
+        return collateral_factor;
+    };
+    let collateral_factor = crate::param_ramp::current(
+        env,
+        &crate::param_ramp::RampedParam::CollateralFactor(addr.clone()),
+        collateral_factor,
+    );
+    let haircut_bps = get_current_haircut_bps(env, addr);
+    let collateral_factor = if haircut_bps == 0 {
+        collateral_factor
+    } else {
+        collateral_factor
+            .checked_mul(BASIS_POINTS_SCALE - haircut_bps)
+            .map(|v| v / BASIS_POINTS_SCALE)
+            .unwrap_or(0)
+    };
+    crate::asset_registry::sunset_collateral_factor(env, addr, collateral_factor)
+}
+
 /// Get minimum collateral ratio
 pub fn get_min_collateral_ratio(env: &Env) -> Result<i128, RiskParamsError> {
     let config = get_risk_params(env).ok_or(RiskParamsError::InvalidParameter)?;
@@ -330,6 +618,127 @@ pub fn require_min_collateral_ratio(
     Ok(())
 }
 
+/// Get the hysteresis buffer around the liquidation threshold (default 0)
+pub fn get_liquidation_hysteresis_bps(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&RiskParamsDataKey::LiquidationHysteresisBps)
+        .unwrap_or(0)
+}
+
+/// Set the hysteresis buffer around the liquidation threshold
+///
+/// A position becomes liquidatable once its collateral ratio falls below
+/// `liquidation_threshold - buffer` and stays liquidatable until it recovers
+/// to at least `liquidation_threshold + buffer`. Only reachable through an
+/// executed governance proposal.
+///
+/// # Errors
+/// * `RiskParamsError::InvalidParameter` - If the buffer is outside `[0, 500]`
+#[allow(deprecated)]
+pub fn set_liquidation_hysteresis_bps(env: &Env, buffer_bps: i128) -> Result<(), RiskParamsError> {
+    if !(0..=LIQUIDATION_HYSTERESIS_MAX).contains(&buffer_bps) {
+        return Err(RiskParamsError::InvalidParameter);
+    }
+
+    let old_buffer_bps = get_liquidation_hysteresis_bps(env);
+    env.storage()
+        .persistent()
+        .set(&RiskParamsDataKey::LiquidationHysteresisBps, &buffer_bps);
+
+    let topics = (Symbol::new(env, "liquidation_hysteresis_updated"),);
+    env.events().publish(topics, buffer_bps);
+    crate::events::emit_param_change(
+        env,
+        "liquidation_hysteresis_bps",
+        None,
+        old_buffer_bps,
+        buffer_bps,
+    );
+
+    Ok(())
+}
+
+/// Whether `borrower` is flagged as liquidatable inside the hysteresis band
+pub fn is_liquidation_flagged(env: &Env, borrower: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&RiskParamsDataKey::LiquidationFlagged(borrower.clone()))
+        .unwrap_or(false)
+}
+
+/// Liquidation eligibility of a borrower's position, with hysteresis
+///
+/// Below the lower mark the position is liquidatable, at or above the upper
+/// mark it is safe, and in between it keeps its flagged state. With a zero
+/// buffer and no e-mode category in effect this matches [`can_be_liquidated`].
+pub fn can_liquidate_position(
+    env: &Env,
+    borrower: &Address,
+    collateral_value: i128,
+    debt_value: i128,
+) -> Result<bool, RiskParamsError> {
+    let config = get_risk_params(env).ok_or(RiskParamsError::InvalidParameter)?;
+
+    if debt_value == 0 {
+        return Ok(false);
+    }
+
+    let ratio = (collateral_value * BASIS_POINTS_SCALE)
+        .checked_div(debt_value)
+        .ok_or(RiskParamsError::InvalidParameter)?;
+    let buffer = get_liquidation_hysteresis_bps(env);
+    let threshold = crate::emode::liquidation_threshold_for(env, borrower)
+        .unwrap_or(config.liquidation_threshold);
+
+    if ratio < threshold - buffer {
+        return Ok(true);
+    }
+    if ratio >= threshold + buffer {
+        return Ok(false);
+    }
+    Ok(is_liquidation_flagged(env, borrower))
+}
+
+/// Update a borrower's hysteresis flag after a liquidation
+///
+/// The flag stays set while the position remains below the upper mark, so
+/// it can keep being liquidated until it has clearly recovered.
+pub fn update_liquidation_flag(
+    env: &Env,
+    borrower: &Address,
+    collateral_value: i128,
+    debt_value: i128,
+) {
+    let upper_mark = get_risk_params(env)
+        .map(|config| {
+            crate::emode::liquidation_threshold_for(env, borrower)
+                .unwrap_or(config.liquidation_threshold)
+                + get_liquidation_hysteresis_bps(env)
+        })
+        .unwrap_or(0);
+    let flagged = debt_value > 0
+        && collateral_value
+            .checked_mul(BASIS_POINTS_SCALE)
+            .map(|scaled| scaled / debt_value < upper_mark)
+            .unwrap_or(false);
+    set_liquidation_flag(env, borrower, flagged);
+}
+
+/// Record a re-evaluation of a borrower's health
+///
+/// `liquidatable` is what [`can_liquidate_position`] just returned, so a
+/// position that dipped below the lower mark stays flagged inside the band
+/// and is cleared once it reaches the upper mark.
+pub fn set_liquidation_flag(env: &Env, borrower: &Address, liquidatable: bool) {
+    let key = RiskParamsDataKey::LiquidationFlagged(borrower.clone());
+    if liquidatable {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
 /// Can be liquidated check
 pub fn can_be_liquidated(
     env: &Env,
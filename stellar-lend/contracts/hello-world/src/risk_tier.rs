@@ -0,0 +1,282 @@
+//! # Risk Tier Module
+//!
+//! Named risk tiers with pre-packaged parameters for cross-asset markets.
+//!
+//! Each [`RiskTier`] carries a [`TierParams`] bundle: collateral factor,
+//! liquidation threshold, supply and borrow caps, and liquidation incentive.
+//! Tiers start from built-in presets that governance can override.
+//!
+//! - [`list_asset`] registers a cross-asset market using its tier's
+//!   parameters.
+//! - [`set_asset_tier`] moves a listed asset to another tier.
+//! - [`set_tier_params`] changes a tier and re-applies it to every asset in
+//!   the tier in the same call, so either all of them update or none do.
+//!
+//! Liquidations of a tiered collateral asset use the tier's incentive instead
+//! of the global one.
+//!
+//! ## Security
+//! - Listing goes through the cross-asset admin; re-tiering is super-admin only.
+//! - Tier parameters are only changed through executed governance proposals.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol, Vec};
+
+use crate::cross_asset::{self, AssetConfig};
+
+/// Basis points scale (100% = 10_000)
+pub const BASIS_POINTS_SCALE: i128 = 10_000;
+
+/// Maximum liquidation incentive (50%)
+pub const MAX_TIER_INCENTIVE_BPS: i128 = 5_000;
+
+/// Errors that can occur during risk tier operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RiskTierError {
+    /// Caller is not authorized
+    Unauthorized = 1,
+    /// A parameter is out of range
+    InvalidParameter = 2,
+    /// Asset is not a listed cross-asset market
+    AssetNotListed = 3,
+    /// Registering the asset with the cross-asset module failed
+    ListingFailed = 4,
+}
+
+/// Named risk tiers
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RiskTier {
+    Stable,
+    BlueChip,
+    Volatile,
+    Experimental,
+}
+
+/// Parameters shared by every asset in a tier
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierParams {
+    /// Collateral factor (basis points)
+    pub collateral_factor: i128,
+    /// Liquidation threshold (basis points)
+    pub liquidation_threshold: i128,
+    /// Supply cap (0 = unlimited)
+    pub max_supply: i128,
+    /// Borrow cap (0 = unlimited)
+    pub max_borrow: i128,
+    /// Liquidation incentive (basis points)
+    pub liquidation_incentive: i128,
+}
+
+/// Storage keys for risk tier data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum RiskTierDataKey {
+    /// Governance override of a tier's parameters: TierParams
+    Params(RiskTier),
+    /// Tier an asset belongs to: RiskTier
+    AssetTier(Option<Address>),
+    /// Assets in a tier: Vec<Option<Address>>
+    Members(RiskTier),
+}
+
+/// Built-in parameters for a tier
+pub fn preset_params(tier: RiskTier) -> TierParams {
+    let (collateral_factor, liquidation_threshold, liquidation_incentive) = match tier {
+        RiskTier::Stable => (9_000, 9_500, 300),
+        RiskTier::BlueChip => (7_500, 8_000, 500),
+        RiskTier::Volatile => (5_000, 6_500, 1_000),
+        RiskTier::Experimental => (2_500, 4_000, 1_500),
+    };
+    TierParams {
+        collateral_factor,
+        liquidation_threshold,
+        max_supply: 0,
+        max_borrow: 0,
+        liquidation_incentive,
+    }
+}
+
+/// Current parameters for a tier (governance override or preset)
+pub fn get_tier_params(env: &Env, tier: RiskTier) -> TierParams {
+    env.storage()
+        .persistent()
+        .get(&RiskTierDataKey::Params(tier))
+        .unwrap_or_else(|| preset_params(tier))
+}
+
+/// Tier an asset belongs to, if any
+pub fn get_asset_tier(env: &Env, asset: Option<Address>) -> Option<RiskTier> {
+    env.storage()
+        .persistent()
+        .get(&RiskTierDataKey::AssetTier(asset))
+}
+
+/// Assets in a tier
+pub fn get_tier_assets(env: &Env, tier: RiskTier) -> Vec<Option<Address>> {
+    env.storage()
+        .persistent()
+        .get(&RiskTierDataKey::Members(tier))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Liquidation incentive for a collateral asset's tier, if it has one
+pub fn liquidation_incentive_for(env: &Env, asset: &Option<Address>) -> Option<i128> {
+    get_asset_tier(env, asset.clone()).map(|tier| get_tier_params(env, tier).liquidation_incentive)
+}
+
+/// List a cross-asset market with its tier's parameters
+///
+/// Requires cross-asset admin authorization.
+///
+/// # Errors
+/// * `RiskTierError::ListingFailed` - If the cross-asset module rejects the
+///   asset (unauthorized, already listed, invalid price or reserve factor)
+#[allow(deprecated)]
+pub fn list_asset(
+    env: &Env,
+    asset: Option<Address>,
+    tier: RiskTier,
+    reserve_factor: i128,
+    price: i128,
+) -> Result<(), RiskTierError> {
+    let params = get_tier_params(env, tier);
+    let config = AssetConfig {
+        asset: asset.clone(),
+        collateral_factor: params.collateral_factor,
+        liquidation_threshold: params.liquidation_threshold,
+        reserve_factor,
+        max_supply: params.max_supply,
+        max_borrow: params.max_borrow,
+        can_collateralize: true,
+        can_borrow: true,
+        price,
+        price_updated_at: env.ledger().timestamp(),
+    };
+    cross_asset::initialize_asset(env, asset.clone(), config)
+        .map_err(|_| RiskTierError::ListingFailed)?;
+
+    add_member(env, &asset, tier);
+
+    let topics = (Symbol::new(env, "asset_listed_in_tier"), tier);
+    env.events().publish(topics, asset);
+
+    Ok(())
+}
+
+/// Move a listed asset to another tier and apply its parameters (admin only)
+///
+/// # Errors
+/// * `RiskTierError::Unauthorized` - If caller is not admin
+/// * `RiskTierError::AssetNotListed` - If the asset is not a cross-asset market
+#[allow(deprecated)]
+pub fn set_asset_tier(
+    env: &Env,
+    caller: Address,
+    asset: Option<Address>,
+    tier: RiskTier,
+) -> Result<(), RiskTierError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| RiskTierError::Unauthorized)?;
+
+    apply_params(env, &asset, &get_tier_params(env, tier))?;
+
+    if let Some(previous) = get_asset_tier(env, asset.clone()) {
+        remove_member(env, &asset, previous);
+    }
+    add_member(env, &asset, tier);
+
+    let topics = (Symbol::new(env, "asset_tier_updated"), caller);
+    env.events().publish(topics, (asset, tier));
+
+    Ok(())
+}
+
+/// Change a tier's parameters and apply them to every asset in the tier
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `RiskTierError::InvalidParameter` - If a factor is outside `[0, 10_000]`,
+///   the collateral factor exceeds the liquidation threshold, a cap is
+///   negative or the incentive exceeds `MAX_TIER_INCENTIVE_BPS`
+#[allow(deprecated)]
+pub fn set_tier_params(
+    env: &Env,
+    tier: RiskTier,
+    params: TierParams,
+) -> Result<(), RiskTierError> {
+    if !(0..=BASIS_POINTS_SCALE).contains(&params.collateral_factor)
+        || !(0..=BASIS_POINTS_SCALE).contains(&params.liquidation_threshold)
+        || params.collateral_factor > params.liquidation_threshold
+        || params.max_supply < 0
+        || params.max_borrow < 0
+        || !(0..=MAX_TIER_INCENTIVE_BPS).contains(&params.liquidation_incentive)
+    {
+        return Err(RiskTierError::InvalidParameter);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&RiskTierDataKey::Params(tier), &params);
+
+    let members = get_tier_assets(env, tier);
+    for asset in members.iter() {
+        apply_params(env, &asset, &params)?;
+    }
+
+    let topics = (Symbol::new(env, "risk_tier_updated"), tier);
+    env.events().publish(
+        topics,
+        (
+            params.collateral_factor,
+            params.liquidation_threshold,
+            params.liquidation_incentive,
+            members.len(),
+        ),
+    );
+
+    Ok(())
+}
+
+fn apply_params(env: &Env, asset: &Option<Address>, params: &TierParams) -> Result<(), RiskTierError> {
+    cross_asset::apply_tier_params(
+        env,
+        asset.clone(),
+        params.collateral_factor,
+        params.liquidation_threshold,
+        params.max_supply,
+        params.max_borrow,
+    )
+    .map_err(|e| match e {
+        cross_asset::CrossAssetError::AssetNotConfigured => RiskTierError::AssetNotListed,
+        _ => RiskTierError::InvalidParameter,
+    })
+}
+
+fn add_member(env: &Env, asset: &Option<Address>, tier: RiskTier) {
+    let mut members = get_tier_assets(env, tier);
+    if !members.contains(asset) {
+        members.push_back(asset.clone());
+        env.storage()
+            .persistent()
+            .set(&RiskTierDataKey::Members(tier), &members);
+    }
+    env.storage()
+        .persistent()
+        .set(&RiskTierDataKey::AssetTier(asset.clone()), &tier);
+}
+
+fn remove_member(env: &Env, asset: &Option<Address>, tier: RiskTier) {
+    let mut members = get_tier_assets(env, tier);
+    if let Some(index) = members.first_index_of(asset) {
+        members.remove(index);
+        env.storage()
+            .persistent()
+            .set(&RiskTierDataKey::Members(tier), &members);
+    }
+}
@@ -0,0 +1,340 @@
+//! # Savings Rate Module
+//!
+//! A savings bucket where users park an asset (typically a stablecoin) to earn
+//! a governance-set rate, separate from collateral accounting: savings never
+//! count as collateral and are not lent against a position.
+//!
+//! Each asset has a rate index that starts at [`SAVINGS_INDEX_SCALE`] and grows
+//! at the asset's savings rate. A saver's balance is stored as a normalized
+//! amount, `deposit × SCALE / index`, so it grows with the index without
+//! per-user updates.
+//!
+//! ## Funding
+//! Interest is paid out of the asset's protocol reserve (fee revenue). Every
+//! accrual ("drip") moves the interest owed to savers out of the reserve; if
+//! the reserve cannot cover it, the index only grows by what the reserve can
+//! fund, so savers are never promised more than the protocol has earned.
+//!
+//! ## Security
+//! - The rate is only set through executed governance proposals and capped at
+//!   [`MAX_SAVINGS_RATE_BPS`].
+//! - The index is dripped at the old rate before a rate change.
+//! - Exits round the burned normalized amount up, so rounding always favours
+//!   the pool.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, token::TokenClient, Address, Env, Symbol};
+
+use crate::deposit::DepositDataKey;
+use crate::risk_management::PauseOperation;
+
+/// Scale of the savings rate index (`1e18` = 1.0)
+pub const SAVINGS_INDEX_SCALE: i128 = 1_000_000_000_000_000_000;
+
+/// Maximum savings rate (20% APY)
+pub const MAX_SAVINGS_RATE_BPS: i128 = 2_000;
+
+/// Basis points scale (100% = 10_000)
+const BASIS_POINTS_SCALE: i128 = 10_000;
+
+/// Seconds in a year (365 days)
+const SECONDS_PER_YEAR: i128 = 365 * 86_400;
+
+/// Errors that can occur during savings operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SavingsError {
+    /// Amount must be greater than zero
+    InvalidAmount = 1,
+    /// A parameter is out of range
+    InvalidParameter = 2,
+    /// Amount exceeds the saver's balance
+    InsufficientBalance = 3,
+    /// Deposits or withdrawals are paused
+    Paused = 4,
+    /// Arithmetic overflow occurred
+    Overflow = 5,
+    /// Pool and strategy together cannot cover the transfer
+    InsufficientLiquidity = 6,
+}
+
+/// Storage keys for savings data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum SavingsDataKey {
+    /// Savings rate per asset in basis points per year: i128
+    Rate(Address),
+    /// Rate index and totals per asset: SavingsState
+    State(Address),
+    /// Normalized savings balance per (user, asset): i128
+    Balance(Address, Address),
+}
+
+/// Rate index and totals of an asset's savings bucket
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SavingsState {
+    /// Rate index (`SAVINGS_INDEX_SCALE` = 1.0)
+    pub index: i128,
+    /// Sum of all savers' normalized balances
+    pub total_normalized: i128,
+    /// Timestamp of the last drip
+    pub updated_at: u64,
+}
+
+/// Set an asset's savings rate
+///
+/// Only reachable through an executed governance proposal. Interest up to now
+/// is dripped at the old rate first.
+///
+/// # Errors
+/// * `SavingsError::InvalidParameter` - If the rate is outside
+///   `[0, MAX_SAVINGS_RATE_BPS]`
+#[allow(deprecated)]
+pub fn set_savings_rate(env: &Env, asset: Address, rate_bps: i128) -> Result<(), SavingsError> {
+    if !(0..=MAX_SAVINGS_RATE_BPS).contains(&rate_bps) {
+        return Err(SavingsError::InvalidParameter);
+    }
+
+    drip(env, &asset)?;
+    env.storage()
+        .persistent()
+        .set(&SavingsDataKey::Rate(asset.clone()), &rate_bps);
+
+    let topics = (Symbol::new(env, "savings_rate_updated"),);
+    env.events().publish(topics, (asset, rate_bps));
+
+    Ok(())
+}
+
+/// Savings rate of an asset in basis points per year (default 0)
+pub fn get_savings_rate(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&SavingsDataKey::Rate(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Savings state of an asset, including interest accrued since the last drip
+pub fn get_savings_state(env: &Env, asset: &Address) -> SavingsState {
+    accrue(env, asset)
+        .map(|(state, _)| state)
+        .unwrap_or_else(|_| stored_state(env, asset))
+}
+
+/// Savings balance of `user` in `asset`, including accrued interest
+pub fn get_savings_balance(env: &Env, user: &Address, asset: &Address) -> i128 {
+    let state = get_savings_state(env, asset);
+    to_underlying(stored_balance(env, user, asset), state.index).unwrap_or(0)
+}
+
+/// Savings owed to all savers of `asset` as of the last drip
+///
+/// Interest accrued since then is still held in the asset's reserve.
+pub fn get_savings_total(env: &Env, asset: &Address) -> i128 {
+    let state = stored_state(env, asset);
+    to_underlying(state.total_normalized, state.index).unwrap_or(0)
+}
+
+/// Move `amount` of `asset` from `user` into the savings bucket
+///
+/// Returns the user's savings balance afterwards.
+///
+/// # Errors
+/// * `SavingsError::InvalidAmount` - If amount <= 0 or too small to record
+/// * `SavingsError::Paused` - If deposits are paused
+#[allow(deprecated)]
+pub fn join_savings(
+    env: &Env,
+    user: Address,
+    asset: Address,
+    amount: i128,
+) -> Result<i128, SavingsError> {
+    user.require_auth();
+
+    if amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    if crate::hot_config::load(env).is_paused(env, PauseOperation::Deposit) {
+        return Err(SavingsError::Paused);
+    }
+
+    let mut state = drip(env, &asset)?;
+    let normalized = amount
+        .checked_mul(SAVINGS_INDEX_SCALE)
+        .ok_or(SavingsError::Overflow)?
+        / state.index;
+    if normalized == 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    TokenClient::new(env, &asset).transfer(&user, &env.current_contract_address(), &amount);
+
+    let balance = stored_balance(env, &user, &asset)
+        .checked_add(normalized)
+        .ok_or(SavingsError::Overflow)?;
+    state.total_normalized = state
+        .total_normalized
+        .checked_add(normalized)
+        .ok_or(SavingsError::Overflow)?;
+    save(env, &user, &asset, balance, &state);
+
+    let topics = (Symbol::new(env, "savings_joined"), user);
+    env.events().publish(topics, (asset, amount, state.index));
+
+    to_underlying(balance, state.index)
+}
+
+/// Withdraw `amount` of `asset` from `user`'s savings
+///
+/// Returns the user's remaining savings balance.
+///
+/// # Errors
+/// * `SavingsError::InvalidAmount` - If amount <= 0
+/// * `SavingsError::InsufficientBalance` - If amount exceeds the savings balance
+/// * `SavingsError::Paused` - If withdrawals are paused
+/// * `SavingsError::InsufficientLiquidity` - If pool and strategy cannot cover `amount`
+#[allow(deprecated)]
+pub fn exit_savings(
+    env: &Env,
+    user: Address,
+    asset: Address,
+    amount: i128,
+) -> Result<i128, SavingsError> {
+    user.require_auth();
+
+    if amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    if crate::hot_config::load(env).is_paused(env, PauseOperation::Withdraw) {
+        return Err(SavingsError::Paused);
+    }
+
+    let mut state = drip(env, &asset)?;
+    let normalized = stored_balance(env, &user, &asset);
+    if amount > to_underlying(normalized, state.index)? {
+        return Err(SavingsError::InsufficientBalance);
+    }
+
+    // Round up so the pool never pays out more than was burned
+    let burned = amount
+        .checked_mul(SAVINGS_INDEX_SCALE)
+        .and_then(|v| v.checked_add(state.index - 1))
+        .ok_or(SavingsError::Overflow)?
+        / state.index;
+    let burned = burned.min(normalized);
+
+    let balance = normalized - burned;
+    state.total_normalized = state
+        .total_normalized
+        .checked_sub(burned)
+        .ok_or(SavingsError::Overflow)?;
+    save(env, &user, &asset, balance, &state);
+
+    crate::strategy::ensure_liquidity(env, &asset, amount)
+        .map_err(|_| SavingsError::InsufficientLiquidity)?;
+    TokenClient::new(env, &asset).transfer(&env.current_contract_address(), &user, &amount);
+
+    let topics = (Symbol::new(env, "savings_exited"), user);
+    env.events().publish(topics, (asset, amount, state.index));
+
+    to_underlying(balance, state.index)
+}
+
+/// Bring an asset's index up to date, paying the interest from its reserve
+pub fn drip(env: &Env, asset: &Address) -> Result<SavingsState, SavingsError> {
+    let (state, cost) = accrue(env, asset)?;
+
+    if cost > 0 {
+        let reserve_key = DepositDataKey::ProtocolReserve(Some(asset.clone()));
+        let reserve: i128 = env.storage().persistent().get(&reserve_key).unwrap_or(0);
+        env.storage().persistent().set(&reserve_key, &(reserve - cost));
+    }
+    env.storage()
+        .persistent()
+        .set(&SavingsDataKey::State(asset.clone()), &state);
+
+    Ok(state)
+}
+
+/// Index growth since the last drip and the reserve it consumes
+fn accrue(env: &Env, asset: &Address) -> Result<(SavingsState, i128), SavingsError> {
+    let mut state = stored_state(env, asset);
+    let now = env.ledger().timestamp();
+    let elapsed = now.saturating_sub(state.updated_at) as i128;
+    state.updated_at = now;
+
+    let rate_bps = get_savings_rate(env, asset);
+    if elapsed == 0 || rate_bps == 0 || state.total_normalized == 0 {
+        return Ok((state, 0));
+    }
+
+    let mut growth = state
+        .index
+        .checked_mul(rate_bps)
+        .and_then(|v| v.checked_mul(elapsed))
+        .ok_or(SavingsError::Overflow)?
+        / (BASIS_POINTS_SCALE * SECONDS_PER_YEAR);
+    let mut cost = mul_index(state.total_normalized, growth)?;
+
+    // Only grow the index by what the reserve can fund
+    let reserve: i128 = env
+        .storage()
+        .persistent()
+        .get(&DepositDataKey::ProtocolReserve(Some(asset.clone())))
+        .unwrap_or(0);
+    let reserve = reserve.max(0);
+    if cost > reserve {
+        growth = growth
+            .checked_mul(reserve)
+            .ok_or(SavingsError::Overflow)?
+            / cost;
+        cost = mul_index(state.total_normalized, growth)?;
+    }
+
+    state.index = state
+        .index
+        .checked_add(growth)
+        .ok_or(SavingsError::Overflow)?;
+    Ok((state, cost))
+}
+
+fn stored_state(env: &Env, asset: &Address) -> SavingsState {
+    env.storage()
+        .persistent()
+        .get(&SavingsDataKey::State(asset.clone()))
+        .unwrap_or(SavingsState {
+            index: SAVINGS_INDEX_SCALE,
+            total_normalized: 0,
+            updated_at: env.ledger().timestamp(),
+        })
+}
+
+fn stored_balance(env: &Env, user: &Address, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&SavingsDataKey::Balance(user.clone(), asset.clone()))
+        .unwrap_or(0)
+}
+
+fn save(env: &Env, user: &Address, asset: &Address, balance: i128, state: &SavingsState) {
+    let key = SavingsDataKey::Balance(user.clone(), asset.clone());
+    if balance == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &balance);
+    }
+    env.storage()
+        .persistent()
+        .set(&SavingsDataKey::State(asset.clone()), state);
+}
+
+fn to_underlying(normalized: i128, index: i128) -> Result<i128, SavingsError> {
+    mul_index(normalized, index)
+}
+
+fn mul_index(amount: i128, index: i128) -> Result<i128, SavingsError> {
+    Ok(amount.checked_mul(index).ok_or(SavingsError::Overflow)? / SAVINGS_INDEX_SCALE)
+}
@@ -0,0 +1,178 @@
+//! # Position Simulation Module
+//!
+//! What-if queries for frontends: [`simulate_action`] projects a user's
+//! position after a deposit, withdrawal, borrow or repayment without
+//! executing it, so a UI can show the resulting health and warn before the
+//! user signs a transaction that would revert.
+//!
+//! The current position is valued as in
+//! [`crate::analytics::get_account_health`], with interest accrued up to now.
+//! The action's amount is valued at the same oracle prices and applied to
+//! collateral (deposit, withdraw) or debt (borrow, repay); collateral the
+//! user has excluded from collateral does not change the collateral value.
+//!
+//! Whether the action would revert is decided against the largest amount it
+//! currently accepts, taken from the same limit views the flows mirror:
+//! [`crate::deposit::get_max_depositable`],
+//! [`crate::withdraw::get_max_withdrawable`],
+//! [`crate::borrow::get_max_borrowable`] and
+//! [`crate::repay::get_max_repayable`]. Token balances and allowances are
+//! not checked.
+//!
+//! ## Security
+//! - Read-only: nothing is written, interest is not accrued and no event is
+//!   emitted.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env};
+
+use crate::analytics::health_factor_of;
+
+const BASIS_POINTS: i128 = 10_000;
+
+/// Errors that can occur when simulating an action
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SimulationError {
+    /// A priced asset held by the account or involved in the action has no
+    /// fresh price
+    PriceNotAvailable = 1,
+    /// Arithmetic overflow occurred
+    Overflow = 2,
+}
+
+/// Action to simulate
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SimulatedAction {
+    /// Deposit collateral
+    Deposit,
+    /// Withdraw collateral
+    Withdraw,
+    /// Borrow against collateral
+    Borrow,
+    /// Repay debt
+    Repay,
+}
+
+/// Projected outcome of an action, as returned by [`simulate_action`]
+///
+/// Values are in the oracle's quote unit; assets without a price source count
+/// one-to-one.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationResult {
+    /// Whether executing the action now would revert
+    pub would_revert: bool,
+    /// Largest amount the action currently accepts (`i128::MAX` = no limit)
+    pub max_amount: i128,
+    /// Collateral value before the action
+    pub collateral_value_before: i128,
+    /// Debt value before the action, including interest accrued up to now
+    pub debt_value_before: i128,
+    /// Health factor before the action in basis points (`i128::MAX` with no debt)
+    pub health_factor_before: i128,
+    /// Collateral value after the action
+    pub collateral_value_after: i128,
+    /// Debt value after the action
+    pub debt_value_after: i128,
+    /// Health factor after the action in basis points (`i128::MAX` with no debt)
+    pub health_factor_after: i128,
+    /// Debt to collateral value after the action in basis points
+    /// (`i128::MAX` with debt but no collateral)
+    pub loan_to_value_after: i128,
+    /// Whether the account would be liquidatable after the action
+    pub is_liquidatable_after: bool,
+}
+
+/// Project `user`'s position after `action` of `amount` of `asset`
+///
+/// Reverting actions are still projected, so a UI can show why: an amount
+/// above `max_amount` or a non-positive amount sets `would_revert`. A
+/// repayment above the outstanding debt settles the debt and is not counted
+/// as reverting.
+///
+/// # Errors
+/// * `SimulationError::PriceNotAvailable` - If a priced asset has no fresh price
+/// * `SimulationError::Overflow` - If arithmetic overflow occurs
+pub fn simulate_action(
+    env: &Env,
+    user: Address,
+    action: SimulatedAction,
+    asset: Option<Address>,
+    amount: i128,
+) -> Result<SimulationResult, SimulationError> {
+    let before = crate::analytics::get_account_health(env, &user)
+        .map_err(|_| SimulationError::PriceNotAvailable)?;
+    let value = crate::oracle::ratio_value(env, asset.as_ref(), amount.max(0))
+        .map_err(|_| SimulationError::PriceNotAvailable)?;
+    let counts_as_collateral = crate::asset_positions::is_collateral_enabled(env, &user, &asset);
+
+    let mut collateral_value = before.collateral_value;
+    let mut debt_value = before.debt_value;
+    let max_amount = match action {
+        SimulatedAction::Deposit => {
+            if counts_as_collateral {
+                collateral_value = collateral_value
+                    .checked_add(value)
+                    .ok_or(SimulationError::Overflow)?;
+            }
+            crate::deposit::get_max_depositable(env, &user, asset.clone())
+        }
+        SimulatedAction::Withdraw => {
+            if counts_as_collateral {
+                collateral_value = collateral_value.saturating_sub(value).max(0);
+            }
+            crate::withdraw::get_max_withdrawable(env, user.clone(), asset.clone())
+                .map_err(|_| SimulationError::PriceNotAvailable)?
+        }
+        SimulatedAction::Borrow => {
+            debt_value = debt_value
+                .checked_add(value)
+                .ok_or(SimulationError::Overflow)?;
+            crate::borrow::get_max_borrowable(env, user.clone(), asset.clone())
+                .map_err(|_| SimulationError::PriceNotAvailable)?
+        }
+        SimulatedAction::Repay => {
+            debt_value = debt_value.saturating_sub(value).max(0);
+            let repayable = crate::repay::get_max_repayable(env, &user, asset.clone())
+                .map_err(|_| SimulationError::Overflow)?;
+            // Overpayments are capped at the debt rather than rejected
+            if repayable > 0 {
+                i128::MAX
+            } else {
+                0
+            }
+        }
+    };
+
+    let health_factor_after =
+        health_factor_of(collateral_value, debt_value).map_err(|_| SimulationError::Overflow)?;
+    let loan_to_value_after = match (collateral_value, debt_value) {
+        (_, 0) => 0,
+        (0, _) => i128::MAX,
+        _ => {
+            debt_value
+                .checked_mul(BASIS_POINTS)
+                .ok_or(SimulationError::Overflow)?
+                / collateral_value
+        }
+    };
+    let is_liquidatable_after =
+        crate::risk_params::can_liquidate_position(env, &user, collateral_value, debt_value)
+            .unwrap_or(false);
+
+    Ok(SimulationResult {
+        would_revert: amount <= 0 || amount > max_amount,
+        max_amount,
+        collateral_value_before: before.collateral_value,
+        debt_value_before: before.debt_value,
+        health_factor_before: before.health_factor,
+        collateral_value_after: collateral_value,
+        debt_value_after: debt_value,
+        health_factor_after,
+        loan_to_value_after,
+        is_liquidatable_after,
+    })
+}
@@ -20,6 +20,18 @@ pub enum GovernanceDataKey {
 
     RecoveryRequest,
     RecoveryApprovals,
+
+    VoteWeightCap,
+    QuadraticVoting,
+    CategoryThreshold(crate::types::ProposalCategory),
+    EmergencyTrackConfig,
+    EmergencyApprovals(u64),
+    MultisigActionThresholds,
+    MultisigSpendLimit(Option<Address>),
+    MultisigSpendLog(Option<Address>),
+    VoteCheckpoints(Address),
+    VetoGuardian,
+    TotalStakeCheckpoints,
 }
 
 #[derive(Clone)]
@@ -0,0 +1,331 @@
+//! # Strategy Module
+//!
+//! Lets governance put a bounded share of idle pool liquidity to work in an
+//! external, whitelisted yield strategy contract.
+//!
+//! ## Strategy Interface
+//! A strategy contract must expose:
+//! - `deposit(from: Address, amount: i128)` — record `amount` already
+//!   transferred to the strategy on behalf of `from`
+//! - `withdraw(to: Address, amount: i128)` — send `amount` back to `to`
+//! - `balance(owner: Address) -> i128` — current value held for `owner`,
+//!   including accrued yield
+//!
+//! ## Accounting
+//! - `Allocated(asset)` tracks the principal placed in the strategy. Moving
+//!   liquidity between the pool and the strategy does not touch deposit or
+//!   borrow totals, so utilization is unaffected.
+//! - Anything the strategy holds above the allocated principal is yield;
+//!   [`harvest`] pulls it back and credits it to protocol reserves.
+//! - Every transfer of tokens out of the contract (withdrawals, borrows,
+//!   liquidations, flash loans, claims, reserve and escrow releases) calls
+//!   [`ensure_liquidity`] first, which recalls principal from the strategy
+//!   whenever the contract's own balance falls short, so users can always
+//!   access the full pool.
+//!
+//! ## Security
+//! - Strategies are set, funded and defunded only through executed governance
+//!   proposals.
+//! - Allocation is capped at `max_allocation_bps` of the asset's pool
+//!   liquidity as recorded by the per-asset ledger ([`get_pool_liquidity`]),
+//!   itself capped at [`MAX_STRATEGY_ALLOCATION_BPS`]. The raw token balance
+//!   is never used for the cap, so reserves, escrowed tokens and stray
+//!   transfers held in the same token cannot be deployed.
+//! - A strategy cannot be replaced while it still holds principal.
+
+#![allow(unused)]
+use soroban_sdk::{
+    contracterror, contracttype, token::TokenClient, Address, Env, IntoVal, Symbol, Val, Vec,
+};
+
+use crate::reserve::ReserveDataKey;
+
+/// Basis points scale (100% = 10_000)
+pub const BASIS_POINTS_SCALE: i128 = 10_000;
+
+/// Maximum share of pool liquidity that may be allocated to a strategy (50%)
+pub const MAX_STRATEGY_ALLOCATION_BPS: i128 = 5_000;
+
+/// Errors that can occur during strategy operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum StrategyError {
+    /// No strategy is configured for the asset
+    NotConfigured = 1,
+    /// A parameter is out of range
+    InvalidParameter = 2,
+    /// Allocation would exceed the configured cap
+    CapExceeded = 3,
+    /// Amount exceeds the principal allocated to the strategy
+    InsufficientAllocation = 4,
+    /// Strategy still holds principal and cannot be replaced
+    StrategyActive = 5,
+    /// Arithmetic overflow occurred
+    Overflow = 6,
+    /// Pool and strategy together cannot cover the requested amount
+    InsufficientLiquidity = 7,
+}
+
+/// Storage keys for strategy data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum StrategyDataKey {
+    /// Strategy configuration per asset: StrategyConfig
+    Config(Address),
+    /// Principal allocated to the asset's strategy: i128
+    Allocated(Address),
+}
+
+/// Strategy configuration for an asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StrategyConfig {
+    /// Whitelisted strategy contract
+    pub strategy: Address,
+    /// Maximum share of pool liquidity allocated to the strategy (basis points)
+    pub max_allocation_bps: i128,
+}
+
+/// Set (or replace) the strategy for an asset
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `StrategyError::InvalidParameter` - If the cap is outside
+///   `[0, MAX_STRATEGY_ALLOCATION_BPS]` or the strategy is this contract
+/// * `StrategyError::StrategyActive` - If a different strategy still holds principal
+#[allow(deprecated)]
+pub fn set_strategy(
+    env: &Env,
+    asset: Address,
+    strategy: Address,
+    max_allocation_bps: i128,
+) -> Result<(), StrategyError> {
+    if !(0..=MAX_STRATEGY_ALLOCATION_BPS).contains(&max_allocation_bps)
+        || strategy == env.current_contract_address()
+    {
+        return Err(StrategyError::InvalidParameter);
+    }
+    if let Some(current) = get_strategy(env, &asset) {
+        if current.strategy != strategy && get_allocated(env, &asset) > 0 {
+            return Err(StrategyError::StrategyActive);
+        }
+    }
+
+    let config = StrategyConfig {
+        strategy,
+        max_allocation_bps,
+    };
+    env.storage()
+        .persistent()
+        .set(&StrategyDataKey::Config(asset.clone()), &config);
+
+    let topics = (Symbol::new(env, "strategy_set"), asset);
+    env.events()
+        .publish(topics, (config.strategy, max_allocation_bps));
+
+    Ok(())
+}
+
+/// Move idle pool liquidity into the asset's strategy
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `StrategyError::NotConfigured` - If no strategy is set for the asset
+/// * `StrategyError::InvalidParameter` - If `amount <= 0`
+/// * `StrategyError::CapExceeded` - If the allocation would exceed the cap
+#[allow(deprecated)]
+pub fn strategy_deposit(env: &Env, asset: Address, amount: i128) -> Result<i128, StrategyError> {
+    let config = get_strategy(env, &asset).ok_or(StrategyError::NotConfigured)?;
+    if amount <= 0 {
+        return Err(StrategyError::InvalidParameter);
+    }
+
+    let contract = env.current_contract_address();
+    let token = TokenClient::new(env, &asset);
+    let idle = token.balance(&contract);
+    let allocated = get_allocated(env, &asset);
+
+    let new_allocated = allocated
+        .checked_add(amount)
+        .ok_or(StrategyError::Overflow)?;
+    let cap = get_pool_liquidity(env, &asset)
+        .checked_mul(config.max_allocation_bps)
+        .ok_or(StrategyError::Overflow)?
+        / BASIS_POINTS_SCALE;
+    if amount > idle || new_allocated > cap {
+        return Err(StrategyError::CapExceeded);
+    }
+
+    // Effects before the external calls
+    set_allocated(env, &asset, new_allocated);
+
+    token.transfer(&contract, &config.strategy, &amount);
+    let args: Vec<Val> = (contract, amount).into_val(env);
+    env.invoke_contract::<()>(&config.strategy, &Symbol::new(env, "deposit"), args);
+
+    let topics = (Symbol::new(env, "strategy_deposit"), asset);
+    env.events().publish(topics, (amount, new_allocated));
+
+    Ok(new_allocated)
+}
+
+/// Recall principal from the asset's strategy back into the pool
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Errors
+/// * `StrategyError::NotConfigured` - If no strategy is set for the asset
+/// * `StrategyError::InvalidParameter` - If `amount <= 0`
+/// * `StrategyError::InsufficientAllocation` - If `amount` exceeds the principal
+#[allow(deprecated)]
+pub fn strategy_withdraw(env: &Env, asset: Address, amount: i128) -> Result<i128, StrategyError> {
+    let config = get_strategy(env, &asset).ok_or(StrategyError::NotConfigured)?;
+    if amount <= 0 {
+        return Err(StrategyError::InvalidParameter);
+    }
+
+    let allocated = get_allocated(env, &asset);
+    if amount > allocated {
+        return Err(StrategyError::InsufficientAllocation);
+    }
+    let new_allocated = allocated - amount;
+    set_allocated(env, &asset, new_allocated);
+
+    recall(env, &config.strategy, amount);
+
+    let topics = (Symbol::new(env, "strategy_withdraw"), asset);
+    env.events().publish(topics, (amount, new_allocated));
+
+    Ok(new_allocated)
+}
+
+/// Pull yield earned by the asset's strategy into protocol reserves
+///
+/// Permissionless: yield is whatever the strategy holds above the allocated
+/// principal.
+///
+/// # Returns
+/// The amount harvested (0 if there is no yield)
+///
+/// # Errors
+/// * `StrategyError::NotConfigured` - If no strategy is set for the asset
+/// * `StrategyError::Overflow` - If arithmetic overflow occurs
+#[allow(deprecated)]
+pub fn harvest(env: &Env, asset: Address) -> Result<i128, StrategyError> {
+    let config = get_strategy(env, &asset).ok_or(StrategyError::NotConfigured)?;
+
+    let args: Vec<Val> = (env.current_contract_address(),).into_val(env);
+    let held: i128 = env.invoke_contract(&config.strategy, &Symbol::new(env, "balance"), args);
+    let yield_amount = held.saturating_sub(get_allocated(env, &asset));
+    if yield_amount <= 0 {
+        return Ok(0);
+    }
+
+    let reserve_key = ReserveDataKey::ReserveBalance(Some(asset.clone()));
+    let reserve: i128 = env.storage().persistent().get(&reserve_key).unwrap_or(0);
+    let new_reserve = reserve
+        .checked_add(yield_amount)
+        .ok_or(StrategyError::Overflow)?;
+    env.storage().persistent().set(&reserve_key, &new_reserve);
+
+    recall(env, &config.strategy, yield_amount);
+
+    let topics = (Symbol::new(env, "strategy_harvest"), asset);
+    env.events().publish(topics, (yield_amount, new_reserve));
+
+    Ok(yield_amount)
+}
+
+/// Make sure the contract holds at least `amount` of `asset`, recalling
+/// principal from the strategy to cover any shortfall
+///
+/// Called before every transfer of tokens out of the contract.
+///
+/// # Errors
+/// * `StrategyError::InsufficientLiquidity` - If pool and strategy together
+///   cannot cover `amount`
+#[allow(deprecated)]
+pub fn ensure_liquidity(env: &Env, asset: &Address, amount: i128) -> Result<(), StrategyError> {
+    let allocated = get_allocated(env, asset);
+    if allocated <= 0 {
+        return Ok(());
+    }
+
+    let idle = TokenClient::new(env, asset).balance(&env.current_contract_address());
+    if idle >= amount {
+        return Ok(());
+    }
+    let shortfall = amount - idle;
+    if shortfall > allocated {
+        return Err(StrategyError::InsufficientLiquidity);
+    }
+
+    let config = get_strategy(env, asset).ok_or(StrategyError::NotConfigured)?;
+    let new_allocated = allocated - shortfall;
+    set_allocated(env, asset, new_allocated);
+
+    recall(env, &config.strategy, shortfall);
+
+    let topics = (Symbol::new(env, "strategy_recalled"), asset.clone());
+    env.events().publish(topics, (shortfall, new_allocated));
+
+    Ok(())
+}
+
+/// Get the strategy configuration for an asset
+pub fn get_strategy(env: &Env, asset: &Address) -> Option<StrategyConfig> {
+    env.storage()
+        .persistent()
+        .get(&StrategyDataKey::Config(asset.clone()))
+}
+
+/// Principal currently allocated to the asset's strategy
+pub fn get_allocated(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StrategyDataKey::Allocated(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Lendable pool liquidity of `asset` recorded by the per-asset ledger
+///
+/// `supply + protocol-owned liquidity − borrows`. Principal placed in the
+/// strategy still counts, since allocating does not change these totals.
+/// Reserves, escrowed stakes and bonds, savings deposits and stray transfers
+/// held in the same token are not pool liquidity.
+pub fn get_pool_liquidity(env: &Env, asset: &Address) -> i128 {
+    let ledger_asset = if crate::deposit::get_native_asset_address(env).as_ref() == Some(asset) {
+        None
+    } else {
+        Some(asset.clone())
+    };
+    let totals = crate::asset_positions::get_asset_totals(env, ledger_asset.clone());
+    totals
+        .collateral
+        .saturating_add(crate::reserve::get_protocol_owned_liquidity(env, ledger_asset))
+        .saturating_sub(totals.debt)
+        .max(0)
+}
+
+/// Liquidity available to users: idle balance plus allocated principal
+pub fn get_available_liquidity(env: &Env, asset: &Address) -> i128 {
+    TokenClient::new(env, asset)
+        .balance(&env.current_contract_address())
+        .saturating_add(get_allocated(env, asset))
+}
+
+fn set_allocated(env: &Env, asset: &Address, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&StrategyDataKey::Allocated(asset.clone()), &amount);
+}
+
+/// Ask the strategy to send `amount` back to this contract
+fn recall(env: &Env, strategy: &Address, amount: i128) {
+    let args: Vec<Val> = (env.current_contract_address(), amount).into_val(env);
+    env.invoke_contract::<()>(strategy, &Symbol::new(env, "withdraw"), args);
+}
@@ -0,0 +1,497 @@
+//! # Supply Interest Module
+//!
+//! Settles the supply interest earned on a user's collateral.
+//!
+//! Interest is measured with the supply exchange rate maintained by the
+//! interest rate module: each user keeps a snapshot of the rate at their last
+//! settlement, and on every interaction
+//! `earned = collateral × (rate_now − rate_snapshot) / rate_snapshot`.
+//!
+//! What happens to `earned` depends on the user's flags:
+//! - **auto-repay** (opt-in): applied first against the user's outstanding
+//!   borrow interest and then principal, so yield steadily deleverages looped
+//!   positions. Only the part left over once the debt is cleared continues below.
+//! - **auto-compound enabled**: folded into `Position.collateral` (and the
+//!   collateral balance), counted as new deposits in protocol analytics.
+//! - **auto-compound disabled** (default): added to a claimable balance the
+//!   user can withdraw with [`claim_supply_interest`].
+//!
+//! ## Per-Asset Accounting
+//! Each asset is settled separately: the user keeps one rate snapshot and one
+//! claimable balance per (user, asset) pair, and earns on the collateral
+//! recorded in that asset by the per-asset position ledger. Native XLM also
+//! earns on collateral no asset accounts for, so positions opened before
+//! per-asset tracking keep earning as before.
+//! Interest is always claimed in the asset it was earned in.
+//!
+//! ## Security
+//! - Only the user can toggle their flags or claim their interest.
+//! - Settlement runs before balances change, so interest is always earned on
+//!   the collateral that was actually supplied during the interval.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, token::TokenClient, Address, Env, Symbol};
+
+use crate::deposit::{DepositDataKey, Position, ProtocolAnalytics, UserAnalytics};
+
+/// Errors that can occur during supply interest operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SupplyInterestError {
+    /// Nothing to claim
+    NothingToClaim = 1,
+    /// Arithmetic overflow occurred
+    Overflow = 2,
+    /// Native asset address is not configured
+    InvalidAsset = 3,
+    /// Pool and strategy together cannot cover the transfer
+    InsufficientLiquidity = 4,
+    /// Earned interest or the debt it repays has no fresh price
+    PriceUnavailable = 5,
+}
+
+/// Storage keys for supply interest data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum SupplyInterestDataKey {
+    /// Auto-compound flag per user: bool
+    AutoCompound(Address),
+    /// Exchange rate at the user's last settlement of an asset: i128
+    RateSnapshot(Address, Option<Address>),
+    /// Supply interest earned in an asset but not yet claimed: i128
+    Claimable(Address, Option<Address>),
+    /// Auto-repay flag per user: bool
+    AutoRepay(Address),
+    /// Claimable supply interest across all users per asset: i128
+    TotalClaimable(Option<Address>),
+}
+
+fn snapshot_key(user: &Address, asset: &Option<Address>) -> SupplyInterestDataKey {
+    SupplyInterestDataKey::RateSnapshot(user.clone(), asset.clone())
+}
+
+fn claimable_key(user: &Address, asset: &Option<Address>) -> SupplyInterestDataKey {
+    SupplyInterestDataKey::Claimable(user.clone(), asset.clone())
+}
+
+/// Enable or disable auto-compounding of supply interest for a user
+///
+/// Interest earned up to now is settled under the previous setting first.
+///
+/// # Errors
+/// * `SupplyInterestError::Overflow` - If settlement overflows
+#[allow(deprecated)]
+pub fn set_auto_compound(
+    env: &Env,
+    user: Address,
+    asset: Option<Address>,
+    enabled: bool,
+) -> Result<(), SupplyInterestError> {
+    user.require_auth();
+
+    settle_supply_interest(env, &user, asset)?;
+    env.storage()
+        .persistent()
+        .set(&SupplyInterestDataKey::AutoCompound(user.clone()), &enabled);
+
+    let topics = (Symbol::new(env, "auto_compound_updated"), user);
+    env.events().publish(topics, enabled);
+
+    Ok(())
+}
+
+/// Whether auto-compounding is enabled for a user (default `false`)
+pub fn is_auto_compound(env: &Env, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&SupplyInterestDataKey::AutoCompound(user.clone()))
+        .unwrap_or(false)
+}
+
+/// Enable or disable repaying debt from earned supply interest for a user
+///
+/// Interest earned up to now is settled under the previous setting first.
+///
+/// # Errors
+/// * `SupplyInterestError::Overflow` - If settlement overflows
+#[allow(deprecated)]
+pub fn set_auto_repay(
+    env: &Env,
+    user: Address,
+    asset: Option<Address>,
+    enabled: bool,
+) -> Result<(), SupplyInterestError> {
+    user.require_auth();
+
+    settle_supply_interest(env, &user, asset)?;
+    env.storage()
+        .persistent()
+        .set(&SupplyInterestDataKey::AutoRepay(user.clone()), &enabled);
+
+    let topics = (Symbol::new(env, "auto_repay_updated"), user);
+    env.events().publish(topics, enabled);
+
+    Ok(())
+}
+
+/// Whether auto-repay is enabled for a user (default `false`)
+pub fn is_auto_repay(env: &Env, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&SupplyInterestDataKey::AutoRepay(user.clone()))
+        .unwrap_or(false)
+}
+
+/// Supply interest a user has earned in native XLM but not yet claimed
+pub fn get_claimable_interest(env: &Env, user: &Address) -> i128 {
+    get_claimable_interest_for(env, user, None)
+}
+
+/// Supply interest a user has earned in `asset` but not yet claimed
+pub fn get_claimable_interest_for(env: &Env, user: &Address, asset: Option<Address>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&claimable_key(user, &asset))
+        .unwrap_or(0)
+}
+
+/// Claimable supply interest in `asset` across all users
+pub fn get_total_claimable(env: &Env, asset: Option<Address>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&SupplyInterestDataKey::TotalClaimable(asset))
+        .unwrap_or(0)
+}
+
+fn add_total_claimable(env: &Env, asset: &Option<Address>, delta: i128) {
+    let total = get_total_claimable(env, asset.clone())
+        .saturating_add(delta)
+        .max(0);
+    env.storage().persistent().set(
+        &SupplyInterestDataKey::TotalClaimable(asset.clone()),
+        &total,
+    );
+}
+
+/// Collateral that earns supply interest in `asset`
+fn earning_collateral(env: &Env, user: &Address, asset: &Option<Address>) -> i128 {
+    let attributed =
+        crate::asset_positions::get_asset_position(env, user, asset.clone()).collateral;
+    if asset.is_some() {
+        return attributed;
+    }
+
+    let unified: i128 = env
+        .storage()
+        .persistent()
+        .get(&DepositDataKey::CollateralBalance(user.clone()))
+        .unwrap_or(0);
+    attributed.saturating_add(crate::asset_positions::unattributed_collateral(env, user, unified))
+}
+
+/// Settle the supply interest earned by `user` in `asset` since their last
+/// interaction with it
+///
+/// Called at the start of every deposit, withdraw, borrow and repay, after the
+/// asset's exchange rate has been checkpointed.
+///
+/// # Returns
+/// The interest settled in this call
+///
+/// # Errors
+/// * `SupplyInterestError::Overflow` - If arithmetic overflow occurs
+#[allow(deprecated)]
+pub fn settle_supply_interest(
+    env: &Env,
+    user: &Address,
+    asset: Option<Address>,
+) -> Result<i128, SupplyInterestError> {
+    let rate_now = crate::interest_rate::get_exchange_rate(env, asset.clone())
+        .map_err(|_| SupplyInterestError::Overflow)?;
+    let snapshot_key = snapshot_key(user, &asset);
+    let snapshot: i128 = env.storage().persistent().get(&snapshot_key).unwrap_or(rate_now);
+    env.storage().persistent().set(&snapshot_key, &rate_now);
+
+    let collateral = earning_collateral(env, user, &asset);
+    if collateral <= 0 || snapshot <= 0 || rate_now <= snapshot {
+        return Ok(0);
+    }
+
+    let earned = collateral
+        .checked_mul(rate_now - snapshot)
+        .ok_or(SupplyInterestError::Overflow)?
+        .checked_div(snapshot)
+        .ok_or(SupplyInterestError::Overflow)?;
+    if earned <= 0 {
+        return Ok(0);
+    }
+
+    let remaining = if is_auto_repay(env, user) {
+        earned
+            .checked_sub(repay_from_interest(env, user, &asset, earned)?)
+            .ok_or(SupplyInterestError::Overflow)?
+    } else {
+        earned
+    };
+    if remaining <= 0 {
+        return Ok(earned);
+    }
+
+    if is_auto_compound(env, user) {
+        compound(env, user, &asset, remaining)?;
+        let topics = (Symbol::new(env, "supply_interest_compounded"), user.clone());
+        env.events().publish(topics, remaining);
+    } else {
+        let claimable = get_claimable_interest_for(env, user, asset.clone())
+            .checked_add(remaining)
+            .ok_or(SupplyInterestError::Overflow)?;
+        env.storage()
+            .persistent()
+            .set(&claimable_key(user, &asset), &claimable);
+        add_total_claimable(env, &asset, remaining);
+        let topics = (Symbol::new(env, "supply_interest_accrued"), user.clone());
+        env.events().publish(topics, (remaining, claimable));
+    }
+
+    Ok(earned)
+}
+
+/// Transfer a user's claimable supply interest in `asset` to them
+///
+/// # Returns
+/// The amount claimed
+///
+/// # Errors
+/// * `SupplyInterestError::NothingToClaim` - If nothing is claimable
+/// * `SupplyInterestError::InvalidAsset` - If `asset` is native and the native
+///   asset address is not configured
+/// * `SupplyInterestError::InsufficientLiquidity` - If pool and strategy cannot
+///   cover the claim
+#[allow(deprecated)]
+pub fn claim_supply_interest(
+    env: &Env,
+    user: Address,
+    asset: Option<Address>,
+) -> Result<i128, SupplyInterestError> {
+    user.require_auth();
+
+    settle_supply_interest(env, &user, asset.clone())?;
+    let amount = get_claimable_interest_for(env, &user, asset.clone());
+    if amount <= 0 {
+        return Err(SupplyInterestError::NothingToClaim);
+    }
+
+    let key = claimable_key(&user, &asset);
+    let token = match &asset {
+        Some(addr) => addr.clone(),
+        None => env
+            .storage()
+            .persistent()
+            .get::<DepositDataKey, Address>(&DepositDataKey::NativeAssetAddress)
+            .ok_or(SupplyInterestError::InvalidAsset)?,
+    };
+
+    // Clear the balance before the external call
+    env.storage().persistent().remove(&key);
+    add_total_claimable(env, &asset, -amount);
+    crate::strategy::ensure_liquidity(env, &token, amount)
+        .map_err(|_| SupplyInterestError::InsufficientLiquidity)?;
+    TokenClient::new(env, &token).transfer(&env.current_contract_address(), &user, &amount);
+
+    let topics = (Symbol::new(env, "supply_interest_claimed"), user);
+    env.events().publish(topics, amount);
+
+    Ok(amount)
+}
+
+/// Apply earned interest against the user's borrow interest, then principal
+///
+/// `earned` is denominated in `asset` (the asset it was earned in). It is
+/// valued with [`crate::oracle::ratio_value`] and that value first clears
+/// the user's borrow interest, then repays principal in each asset the user
+/// owes, converted back into that asset's units with
+/// [`crate::oracle::ratio_amount`]. Principal is recorded against the debt
+/// asset in the per-asset position ledger, never against the supply asset;
+/// debt no asset accounts for is repaid last, valued 1:1.
+///
+/// # Returns
+/// The amount of `asset` consumed (at most `earned`)
+///
+/// # Errors
+/// * `SupplyInterestError::PriceUnavailable` - If the supply asset or a debt asset has no fresh price
+/// * `SupplyInterestError::Overflow` - If arithmetic overflows
+#[allow(deprecated)]
+fn repay_from_interest(
+    env: &Env,
+    user: &Address,
+    asset: &Option<Address>,
+    earned: i128,
+) -> Result<i128, SupplyInterestError> {
+    let position_key = DepositDataKey::Position(user.clone());
+    let mut position = match env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Position>(&position_key)
+    {
+        Some(position) => position,
+        None => return Ok(0),
+    };
+    if position.borrow_interest <= 0 && position.debt <= 0 {
+        return Ok(0);
+    }
+
+    let earned_value = crate::oracle::ratio_value(env, asset.as_ref(), earned)
+        .map_err(|_| SupplyInterestError::PriceUnavailable)?;
+    let interest_paid = earned_value.min(position.borrow_interest.max(0));
+    let mut value_left = earned_value - interest_paid;
+    let mut principal_paid = 0i128;
+
+    for debt_asset in crate::asset_positions::get_user_assets(env, user).iter() {
+        if value_left <= 0 || position.debt <= 0 {
+            break;
+        }
+        let owed = crate::asset_positions::get_asset_position(env, user, debt_asset.clone()).debt;
+        if owed <= 0 {
+            continue;
+        }
+        let amount = crate::oracle::ratio_amount(env, debt_asset.as_ref(), value_left)
+            .map_err(|_| SupplyInterestError::PriceUnavailable)?
+            .min(owed)
+            .min(position.debt);
+        if amount <= 0 {
+            continue;
+        }
+        let value = crate::oracle::ratio_value(env, debt_asset.as_ref(), amount)
+            .map_err(|_| SupplyInterestError::PriceUnavailable)?;
+        crate::asset_positions::record_repayment(env, user, debt_asset.clone(), amount);
+        position.debt -= amount;
+        principal_paid += amount;
+        value_left -= value.min(value_left);
+    }
+
+    // Debt from before per-asset tracking is valued 1:1, like unattributed collateral
+    let attributed = crate::asset_positions::get_user_assets(env, user)
+        .iter()
+        .fold(0i128, |sum, debt_asset| {
+            sum.saturating_add(
+                crate::asset_positions::get_asset_position(env, user, debt_asset).debt,
+            )
+        });
+    let unattributed = position
+        .debt
+        .saturating_sub(attributed)
+        .max(0)
+        .min(value_left);
+    if unattributed > 0 {
+        position.debt -= unattributed;
+        principal_paid += unattributed;
+        value_left -= unattributed;
+    }
+
+    let repaid = interest_paid + principal_paid;
+    if repaid <= 0 {
+        return Ok(0);
+    }
+
+    position.borrow_interest -= interest_paid;
+    env.storage().persistent().set(&position_key, &position);
+    crate::credit_history::record_repayment(
+        env,
+        user,
+        earned_value - value_left,
+        position.borrow_interest == 0,
+    );
+
+    let analytics_key = DepositDataKey::UserAnalytics(user.clone());
+    if let Some(mut analytics) = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, UserAnalytics>(&analytics_key)
+    {
+        analytics.total_repayments = analytics
+            .total_repayments
+            .checked_add(repaid)
+            .ok_or(SupplyInterestError::Overflow)?;
+        analytics.debt_value = analytics.debt_value.checked_sub(repaid).unwrap_or(0).max(0);
+        env.storage().persistent().set(&analytics_key, &analytics);
+    }
+
+    let protocol_key = DepositDataKey::ProtocolAnalytics;
+    if let Some(mut analytics) = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, ProtocolAnalytics>(&protocol_key)
+    {
+        analytics.total_borrows = analytics.total_borrows.checked_sub(repaid).unwrap_or(0).max(0);
+        env.storage().persistent().set(&protocol_key, &analytics);
+    }
+
+    let topics = (Symbol::new(env, "supply_interest_repaid"), user.clone());
+    env.events()
+        .publish(topics, (repaid, position.debt + position.borrow_interest));
+
+    // Round the consumed supply amount up so value is never created
+    let used_value = earned_value - value_left;
+    let consumed = if used_value >= earned_value {
+        earned
+    } else {
+        let amount = crate::oracle::ratio_amount(env, asset.as_ref(), used_value)
+            .map_err(|_| SupplyInterestError::PriceUnavailable)?;
+        let short = crate::oracle::ratio_value(env, asset.as_ref(), amount)
+            .map_err(|_| SupplyInterestError::PriceUnavailable)?
+            < used_value;
+        (amount + i128::from(short)).min(earned)
+    };
+    Ok(consumed)
+}
+
+/// Fold interest earned in `asset` into the user's collateral and analytics
+fn compound(
+    env: &Env,
+    user: &Address,
+    asset: &Option<Address>,
+    earned: i128,
+) -> Result<(), SupplyInterestError> {
+    let collateral: i128 = env
+        .storage()
+        .persistent()
+        .get(&DepositDataKey::CollateralBalance(user.clone()))
+        .unwrap_or(0);
+    let new_collateral = collateral
+        .checked_add(earned)
+        .ok_or(SupplyInterestError::Overflow)?;
+    crate::asset_positions::record_deposit(env, user, asset.clone(), earned)
+        .map_err(|_| SupplyInterestError::Overflow)?;
+    env.storage().persistent().set(
+        &DepositDataKey::CollateralBalance(user.clone()),
+        &new_collateral,
+    );
+
+    let position_key = DepositDataKey::Position(user.clone());
+    if let Some(mut position) = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Position>(&position_key)
+    {
+        position.collateral = new_collateral;
+        env.storage().persistent().set(&position_key, &position);
+    }
+
+    let analytics_key = DepositDataKey::UserAnalytics(user.clone());
+    if let Some(mut analytics) = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, UserAnalytics>(&analytics_key)
+    {
+        analytics.collateral_value = analytics
+            .collateral_value
+            .checked_add(earned)
+            .ok_or(SupplyInterestError::Overflow)?;
+        env.storage().persistent().set(&analytics_key, &analytics);
+    }
+
+    crate::deposit::update_protocol_analytics(env, earned, true)
+        .map_err(|_| SupplyInterestError::Overflow)
+}
@@ -3,6 +3,7 @@
 use crate::{
     borrow::BorrowError,
     deposit::{AssetParams, DepositDataKey, DepositError, Position},
+    liquidate::LiquidationError,
     reentrancy::{is_locked, ReentrancyGuard, REENTRANCY_ERROR_CODE},
     repay::RepayError,
     withdraw::WithdrawError,
@@ -19,6 +20,10 @@ impl MaliciousToken {
         1_000_000
     }
 
+    pub fn allowance(_env: Env, _from: Address, _spender: Address) -> i128 {
+        1_000_000
+    }
+
     pub fn transfer_from(env: Env, _spender: Address, from: Address, _to: Address, _amount: i128) {
         attempt_callback_reentry(&env, &from);
     }
@@ -185,8 +190,20 @@ fn protected_entrypoints_map_preexisting_lock_to_operation_errors() {
             crate::borrow::borrow_asset(&env, user.clone(), Some(token_id.clone()), 100);
         assert_eq!(borrow_result, Err(BorrowError::Reentrancy));
 
-        let repay_result = crate::repay::repay_debt(&env, user.clone(), Some(token_id), 100);
+        let repay_result =
+            crate::repay::repay_debt(&env, user.clone(), Some(token_id.clone()), 100);
         assert_eq!(repay_result, Err(RepayError::Reentrancy));
+
+        let liquidator = Address::generate(&env);
+        let liquidate_result = crate::liquidate::liquidate(
+            &env,
+            liquidator,
+            user.clone(),
+            Some(token_id.clone()),
+            Some(token_id),
+            100,
+        );
+        assert_eq!(liquidate_result, Err(LiquidationError::Reentrancy));
     });
 
     env.as_contract(&contract_id, || {
@@ -0,0 +1,127 @@
+//! # Account Health Tests
+//!
+//! Covers `get_account_health_batch`: per-account values in request order,
+//! accounts without positions, pending interest and the batch size limit.
+//! Also covers `health_factor_changed` events and the `poke` keeper hook.
+
+#![cfg(test)]
+
+use crate::analytics::{AnalyticsError, MAX_HEALTH_BATCH_SIZE};
+use crate::deposit::{DepositDataKey, Position};
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
+
+fn setup() -> (Env, HelloContractClient<'static>) {
+    let (env, client, _admin) = setup_pool();
+    (env, client)
+}
+
+#[test]
+fn test_health_batch_reports_each_account_in_order() {
+    let (env, client) = setup();
+    let healthy = Address::generate(&env);
+    let underwater = Address::generate(&env);
+    let empty = Address::generate(&env);
+    client.test_set_position(&healthy, &2_000, &1_000, &0);
+    client.test_set_position(&underwater, &1_000, &900, &100);
+
+    let mut users = Vec::new(&env);
+    users.push_back(healthy.clone());
+    users.push_back(underwater.clone());
+    users.push_back(empty.clone());
+    let batch = client.get_account_health_batch(&users);
+    assert_eq!(batch.len(), 3);
+
+    let first = batch.get(0).unwrap();
+    assert_eq!(first.user, healthy);
+    assert_eq!(first.collateral_value, 2_000);
+    assert_eq!(first.debt_value, 1_000);
+    assert_eq!(first.health_factor, 20_000);
+    assert!(!first.is_liquidatable);
+
+    let second = batch.get(1).unwrap();
+    assert_eq!(second.user, underwater);
+    assert_eq!(second.debt_value, 1_000);
+    assert_eq!(second.health_factor, 10_000);
+    assert!(second.is_liquidatable);
+
+    let third = batch.get(2).unwrap();
+    assert_eq!(third.user, empty);
+    assert_eq!(third.collateral_value, 0);
+    assert_eq!(third.debt_value, 0);
+    assert_eq!(third.health_factor, i128::MAX);
+    assert!(!third.is_liquidatable);
+}
+
+#[test]
+fn test_health_batch_includes_pending_interest() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+    client.test_set_position(&user, &2_000, &1_000, &0);
+
+    env.ledger().with_mut(|li| li.timestamp += 365 * 86_400);
+
+    let mut users = Vec::new(&env);
+    users.push_back(user);
+    let health = client.get_account_health_batch(&users).get(0).unwrap();
+    assert!(health.debt_value > 1_000);
+    assert!(health.health_factor < 20_000);
+}
+
+#[test]
+fn test_health_batch_size_limit() {
+    let (env, client) = setup();
+
+    let mut users = Vec::new(&env);
+    for _ in 0..MAX_HEALTH_BATCH_SIZE {
+        users.push_back(Address::generate(&env));
+    }
+    assert_eq!(
+        client.get_account_health_batch(&users).len(),
+        MAX_HEALTH_BATCH_SIZE
+    );
+
+    users.push_back(Address::generate(&env));
+    assert_eq!(
+        client.try_get_account_health_batch(&users),
+        Err(Ok(AnalyticsError::InvalidParameter))
+    );
+}
+
+#[test]
+fn test_deposit_emits_health_factor_changed() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &1_000);
+
+    let topic = Symbol::new(&env, "health_factor_changed");
+    let emitted = env.events().all().iter().any(|(_, topics, _)| {
+        let first: Symbol = topics.first().unwrap().into_val(&env);
+        first == topic
+    });
+    assert!(emitted);
+}
+
+#[test]
+fn test_poke_accrues_interest_and_reports_health() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+    client.test_set_position(&user, &2_000, &1_000, &0);
+
+    env.ledger().with_mut(|li| li.timestamp += 365 * 86_400);
+
+    let health = client.poke(&user);
+    assert!(health.debt_value > 1_000);
+    assert_eq!(client.get_account_health(&user), health);
+
+    let stored: Position = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&DepositDataKey::Position(user.clone()))
+            .unwrap()
+    });
+    assert_eq!(stored.debt + stored.borrow_interest, health.debt_value);
+    assert_eq!(stored.last_accrual_time, env.ledger().timestamp());
+}
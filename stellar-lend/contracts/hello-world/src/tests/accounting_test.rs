@@ -0,0 +1,250 @@
+//! # Accounting Reconciliation Tests
+//!
+//! Covers `reconcile` for balanced accounting after real deposits and
+//! borrows, escrowed stakes, bonds and savings, surplus from direct transfers
+//! and unbacked liabilities, treasurer-only sweeping of surplus into reserves
+//! and governance rescue of non-protocol tokens, which refuses tokens still
+//! escrowed for users.
+
+#![cfg(test)]
+
+use crate::accounting::AccountingError;
+use crate::keeper::{self, KeeperConfig};
+use crate::reserve::ReserveDataKey;
+use crate::testutils::{self, setup_pool};
+use crate::types::ProposalType;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, Symbol};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, admin) = setup_pool();
+    let asset = env.register_stellar_asset_contract(admin.clone());
+
+    (env, client, asset)
+}
+
+fn set_reserve(env: &Env, client: &HelloContractClient<'static>, asset: &Address, amount: i128) {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&ReserveDataKey::ReserveBalance(Some(asset.clone())), &amount);
+    });
+}
+
+fn treasurer(env: &Env, client: &HelloContractClient<'static>) -> Address {
+    let admin = env.as_contract(&client.address, || crate::admin::get_admin(env).unwrap());
+    let treasurer = Address::generate(env);
+    client.grant_role(&admin, &Symbol::new(env, "treasurer"), &treasurer);
+    treasurer
+}
+
+#[test]
+fn test_reconcile_empty_is_balanced() {
+    let (_env, client, asset) = setup();
+    let report = client.reconcile(&Some(asset));
+    assert_eq!(report.actual_balance, 0);
+    assert_eq!(report.expected_balance, 0);
+    assert!(report.balanced);
+}
+
+#[test]
+fn test_reconcile_flags_direct_transfer_surplus() {
+    let (env, client, asset) = setup();
+    StellarAssetClient::new(&env, &asset).mint(&client.address, &1_000);
+
+    let report = client.reconcile(&Some(asset));
+    assert_eq!(report.actual_balance, 1_000);
+    assert_eq!(report.discrepancy, 1_000);
+    assert!(!report.balanced);
+}
+
+#[test]
+fn test_reconcile_counts_reserves() {
+    let (env, client, asset) = setup();
+    StellarAssetClient::new(&env, &asset).mint(&client.address, &1_000);
+    set_reserve(&env, &client, &asset, 1_000);
+
+    let report = client.reconcile(&Some(asset.clone()));
+    assert_eq!(report.reserves, 1_000);
+    assert!(report.balanced);
+
+    // Reserves not backed by tokens
+    set_reserve(&env, &client, &asset, 1_500);
+    let report = client.reconcile(&Some(asset));
+    assert_eq!(report.discrepancy, -500);
+}
+
+#[test]
+fn test_reconcile_after_deposit_and_borrow() {
+    let (env, client, admin) = testutils::setup_pool();
+    let asset = env.register_stellar_asset_contract(admin);
+    let supplier = Address::generate(&env);
+    let borrower = Address::generate(&env);
+    testutils::supply(&env, &client, &supplier, &asset, 10_000);
+    testutils::supply(&env, &client, &borrower, &asset, 5_000);
+    testutils::advance_ledgers(&env, 1);
+    client.borrow_asset(&borrower, &Some(asset.clone()), &2_000);
+
+    let report = client.reconcile(&Some(asset));
+    assert_eq!(report.total_deposits, 15_000);
+    assert_eq!(report.total_borrows, 2_000);
+    assert!(report.balanced);
+}
+
+#[test]
+fn test_reconcile_counts_escrowed_tokens() {
+    let (env, client, admin) = testutils::setup_pool();
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&user, &10_000);
+
+    client.gov_initialize(
+        &admin,
+        &asset,
+        &Some(259_200),
+        &Some(86_400),
+        &Some(400),
+        &Some(100),
+        &Some(604_800),
+        &Some(5_000),
+    );
+    client.gov_stake(&user, &1_000);
+    env.as_contract(&client.address, || {
+        keeper::set_config(
+            &env,
+            KeeperConfig {
+                bond_token: asset.clone(),
+                min_bond: 1_000,
+                unbonding_period: 0,
+                rebate_bps: 0,
+            },
+        )
+        .unwrap();
+    });
+    client.register_keeper(&user, &2_000);
+    client.join_savings(&user, &asset, &3_000);
+
+    let report = client.reconcile(&Some(asset.clone()));
+    assert_eq!(report.escrowed, 6_000);
+    assert_eq!(report.actual_balance, 6_000);
+    assert!(report.balanced);
+    assert_eq!(
+        client.try_sweep_surplus(&admin, &Some(asset)),
+        Err(Ok(AccountingError::NoSurplus))
+    );
+}
+
+#[test]
+fn test_reconcile_native_requires_configuration() {
+    let (_env, client, _asset) = setup();
+    assert_eq!(
+        client.try_reconcile(&None),
+        Err(Ok(AccountingError::InvalidAsset))
+    );
+}
+
+#[test]
+fn test_sweep_surplus_credits_reserves() {
+    let (env, client, asset) = setup();
+    StellarAssetClient::new(&env, &asset).mint(&client.address, &1_000);
+    set_reserve(&env, &client, &asset, 300);
+    let treasurer = treasurer(&env, &client);
+
+    assert_eq!(
+        client.try_sweep_surplus(&Address::generate(&env), &Some(asset.clone())),
+        Err(Ok(AccountingError::Unauthorized))
+    );
+    assert_eq!(client.sweep_surplus(&treasurer, &Some(asset.clone())), 700);
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            crate::reserve::get_reserve_balance(&env, Some(asset.clone())),
+            1_000
+        );
+    });
+    assert!(client.reconcile(&Some(asset.clone())).balanced);
+
+    assert_eq!(
+        client.try_sweep_surplus(&treasurer, &Some(asset)),
+        Err(Ok(AccountingError::NoSurplus))
+    );
+}
+
+#[test]
+fn test_sweep_surplus_rejects_shortfall() {
+    let (env, client, asset) = setup();
+    set_reserve(&env, &client, &asset, 500);
+    let treasurer = treasurer(&env, &client);
+    assert_eq!(
+        client.try_sweep_surplus(&treasurer, &Some(asset)),
+        Err(Ok(AccountingError::NoSurplus))
+    );
+}
+
+/// Pass a proposal and return whether execution succeeded
+fn execute(env: &Env, client: &HelloContractClient, proposal: ProposalType) -> bool {
+    let (id, voter) = testutils::queue_governance_proposal(env, client, &proposal);
+    client.try_gov_execute_proposal(&voter, &id).is_ok()
+}
+
+#[test]
+fn test_rescue_tokens_via_governance() {
+    let (env, client, admin) = setup_pool();
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    testutils::setup_governance(&env, &client, &admin);
+    StellarAssetClient::new(&env, &asset).mint(&client.address, &1_000);
+    let recipient = Address::generate(&env);
+
+    assert!(execute(
+        &env,
+        &client,
+        ProposalType::RescueTokens(asset.clone(), 600, recipient.clone()),
+    ));
+    assert_eq!(TokenClient::new(&env, &asset).balance(&recipient), 600);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&client.address), 400);
+}
+
+#[test]
+fn test_rescue_refuses_protocol_assets() {
+    let (env, client, admin) = setup_pool();
+    let gov_token = testutils::setup_governance(&env, &client, &admin);
+    StellarAssetClient::new(&env, &gov_token).mint(&client.address, &1_000);
+
+    assert!(!execute(
+        &env,
+        &client,
+        ProposalType::RescueTokens(gov_token.clone(), 100, Address::generate(&env)),
+    ));
+    // Only the voter's stake joined the surplus
+    assert_eq!(
+        TokenClient::new(&env, &gov_token).balance(&client.address),
+        1_000 + testutils::GOVERNANCE_VOTER_STAKE
+    );
+
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            crate::accounting::rescue_tokens(&env, gov_token.clone(), 100, Address::generate(&env)),
+            Err(AccountingError::ProtocolAsset)
+        );
+    });
+}
+
+#[test]
+fn test_rescue_refuses_escrowed_tokens() {
+    let (env, client, asset) = setup();
+    let saver = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&saver, &1_000);
+    client.join_savings(&saver, &asset, &1_000);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            crate::accounting::rescue_tokens(&env, asset.clone(), 1_000, Address::generate(&env)),
+            Err(AccountingError::ProtocolAsset)
+        );
+    });
+    assert_eq!(
+        TokenClient::new(&env, &asset).balance(&client.address),
+        1_000
+    );
+}
@@ -0,0 +1,133 @@
+//! # Activity History Tests
+//!
+//! Covers per-user activity pages, the page index, archival of old pages and
+//! the bounded protocol-wide recent activity feed.
+
+#![cfg(test)]
+
+use crate::activity::{
+    ActivityIndex, ACTIVITY_PAGE_SIZE, MAX_ACTIVITY_PAGES, RECENT_ACTIVITY_SIZE,
+};
+use crate::deposit::{Activity, DepositDataKey};
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
+
+fn setup() -> (Env, HelloContractClient<'static>) {
+    let (env, client, _admin) = setup_pool();
+    (env, client)
+}
+
+fn deposits(client: &HelloContractClient, user: &Address, count: u32) {
+    for i in 0..count {
+        client.deposit_collateral(user, &None, &(i as i128 + 1));
+    }
+}
+
+#[test]
+fn test_first_page_fills_before_next_opens() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+
+    deposits(&client, &user, ACTIVITY_PAGE_SIZE);
+    assert_eq!(
+        client.get_activity_index(&user),
+        ActivityIndex {
+            first_page: 0,
+            last_page: 0,
+            total_entries: ACTIVITY_PAGE_SIZE as u64,
+        }
+    );
+
+    let page = client.get_activity(&user, &0);
+    assert_eq!(page.len(), ACTIVITY_PAGE_SIZE);
+    assert_eq!(page.get(0).unwrap().amount, 1);
+    assert_eq!(
+        page.get(ACTIVITY_PAGE_SIZE - 1).unwrap().amount,
+        ACTIVITY_PAGE_SIZE as i128
+    );
+
+    client.deposit_collateral(&user, &None, &1_000);
+    assert_eq!(client.get_activity_index(&user).last_page, 1);
+    let page = client.get_activity(&user, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().amount, 1_000);
+}
+
+#[test]
+fn test_history_is_per_user() {
+    let (env, client) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    deposits(&client, &alice, 3);
+    deposits(&client, &bob, 1);
+    client.borrow_asset(&alice, &None, &1);
+
+    let page = client.get_activity(&alice, &0);
+    assert_eq!(page.len(), 4);
+    assert!(page.iter().all(|a| a.user == alice));
+    assert_eq!(
+        page.get(3).unwrap().activity_type,
+        Symbol::new(&env, "borrow")
+    );
+    assert_eq!(client.get_activity(&bob, &0).len(), 1);
+    assert_eq!(
+        client.get_activity_index(&Address::generate(&env)),
+        ActivityIndex::default()
+    );
+}
+
+#[test]
+fn test_oldest_page_archived_past_limit() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+
+    deposits(&client, &user, ACTIVITY_PAGE_SIZE * MAX_ACTIVITY_PAGES);
+    assert_eq!(client.get_activity_index(&user).first_page, 0);
+    assert_eq!(client.get_activity(&user, &0).len(), ACTIVITY_PAGE_SIZE);
+
+    client.deposit_collateral(&user, &None, &1);
+    let topic = Symbol::new(&env, "activity_page_archived");
+    let archived = env.events().all().iter().any(|(_, topics, _)| {
+        let first: Symbol = topics.first().unwrap().into_val(&env);
+        first == topic
+    });
+    assert!(archived);
+
+    assert_eq!(
+        client.get_activity_index(&user),
+        ActivityIndex {
+            first_page: 1,
+            last_page: MAX_ACTIVITY_PAGES,
+            total_entries: (ACTIVITY_PAGE_SIZE * MAX_ACTIVITY_PAGES) as u64 + 1,
+        }
+    );
+    assert_eq!(client.get_activity(&user, &0).len(), 0);
+    assert_eq!(client.get_activity(&user, &1).len(), ACTIVITY_PAGE_SIZE);
+    assert_eq!(client.get_activity(&user, &MAX_ACTIVITY_PAGES).len(), 1);
+}
+
+#[test]
+fn test_recent_feed_is_bounded() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+
+    deposits(&client, &user, RECENT_ACTIVITY_SIZE + 5);
+
+    env.as_contract(&client.address, || {
+        let log: Vec<Activity> = env
+            .storage()
+            .persistent()
+            .get(&DepositDataKey::ActivityLog)
+            .unwrap();
+        assert_eq!(log.len(), RECENT_ACTIVITY_SIZE);
+        // The oldest entries were dropped from the feed
+        assert_eq!(log.get(0).unwrap().amount, 6);
+    });
+    assert_eq!(
+        client.get_activity_index(&user).total_entries,
+        (RECENT_ACTIVITY_SIZE + 5) as u64
+    );
+}
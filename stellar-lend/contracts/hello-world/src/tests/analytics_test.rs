@@ -413,9 +413,12 @@ fn test_activity_log_eviction_at_capacity() {
     let all = client.get_recent_activity(&10_000, &0);
     assert_eq!(all.len(), 10_000, "log must hold exactly 10,000 entries");
 
-    // Add one more via the public API to trigger eviction.
+    // Add one more to trigger eviction.
     let new_user = Address::generate(&env);
-    client.deposit_collateral(&new_user, &None, &1);
+    env.as_contract(&contract_id, || {
+        crate::analytics::record_activity(&env, &new_user, Symbol::new(&env, "deposit"), 1, None)
+            .unwrap();
+    });
 
     let after = client.get_recent_activity(&10_000, &0);
     assert_eq!(after.len(), 10_000, "log must still be capped at 10,000");
@@ -435,7 +438,10 @@ fn test_activity_log_eviction_drops_oldest_entry() {
     inject_activity(&env, &contract_id, &user, 10_000);
 
     // Push one more to trigger pop_front.
-    client.deposit_collateral(&user, &None, &1);
+    env.as_contract(&contract_id, || {
+        crate::analytics::record_activity(&env, &user, Symbol::new(&env, "deposit"), 1, None)
+            .unwrap();
+    });
 
     // The oldest surviving entry is now at the last position (highest offset).
     // tail is empty because total is still 10_000; the oldest is at offset 9999.
@@ -0,0 +1,126 @@
+//! # Asset Delisting Tests
+//!
+//! Covers the `ProposalType::DelistAsset` sunset: the asset is frozen, its
+//! collateral factor ramps to zero, and it leaves the registry once every
+//! position is closed.
+
+#![cfg(test)]
+
+use crate::asset_registry::{AssetListing, AssetRegistryError};
+use crate::deposit::AssetParams;
+use crate::errors::ProtocolError;
+use crate::oracle::AssetOracleConfig;
+use crate::testutils::{execute_governance_proposal, setup_governance, setup_pool};
+use crate::types::ProposalType;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, admin) = setup_pool();
+    setup_governance(&env, &client, &admin);
+    (env, client, admin)
+}
+
+/// Register a token, list it through governance and return its address
+fn list_token(env: &Env, client: &HelloContractClient, admin: &Address) -> Address {
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let listing = AssetListing {
+        params: AssetParams {
+            deposit_enabled: true,
+            collateral_factor: 8_000,
+            max_deposit: 0,
+            borrow_fee_bps: 0,
+        },
+        oracle: AssetOracleConfig {
+            feed: Address::generate(env),
+            decimals: 7,
+            heartbeat: 600,
+            max_deviation_bps: 1_000,
+        },
+        rate_model: None,
+    };
+    execute_governance_proposal(
+        env,
+        client,
+        &ProposalType::ListAsset(asset.clone(), listing),
+    );
+    asset
+}
+
+#[test]
+fn test_delisting_freezes_and_ramps_collateral_factor() {
+    let (env, client, admin) = setup();
+    let asset = list_token(&env, &client, &admin);
+    assert_eq!(client.get_sunset_status(&asset), None);
+
+    execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::DelistAsset(asset.clone(), 1_000),
+    );
+    let start = env.ledger().timestamp();
+    assert!(client.is_asset_frozen(&Some(asset.clone())));
+
+    let status = client.get_sunset_status(&asset).unwrap();
+    assert_eq!(status.started_at, start);
+    assert_eq!(status.ramp_ends_at, start + 1_000);
+    assert_eq!(status.collateral_factor_scale_bps, 10_000);
+    assert!(status.can_finalize);
+
+    env.ledger().with_mut(|li| li.timestamp = start + 250);
+    assert_eq!(
+        client
+            .get_sunset_status(&asset)
+            .unwrap()
+            .collateral_factor_scale_bps,
+        7_500
+    );
+    env.ledger().with_mut(|li| li.timestamp = start + 1_000);
+    assert_eq!(
+        client
+            .get_sunset_status(&asset)
+            .unwrap()
+            .collateral_factor_scale_bps,
+        0
+    );
+}
+
+#[test]
+fn test_finalize_waits_for_positions_to_close() {
+    let (env, client, admin) = setup();
+    let asset = list_token(&env, &client, &admin);
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&user, &5_000);
+    client.deposit_collateral(&user, &Some(asset.clone()), &5_000);
+
+    assert_eq!(
+        client.try_finalize_delisting(&asset),
+        Err(Ok(AssetRegistryError::NotDelisting))
+    );
+    execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::DelistAsset(asset.clone(), 1_000),
+    );
+
+    // Frozen: no new deposits, but the existing one can leave
+    assert_eq!(
+        client.try_deposit_collateral(&user, &Some(asset.clone()), &1),
+        Err(Ok(ProtocolError::AssetFrozen))
+    );
+    let status = client.get_sunset_status(&asset).unwrap();
+    assert_eq!(status.open_positions, 1);
+    assert!(!status.can_finalize);
+    assert_eq!(
+        client.try_finalize_delisting(&asset),
+        Err(Ok(AssetRegistryError::PositionsOpen))
+    );
+
+    client.withdraw_collateral(&user, &Some(asset.clone()), &5_000);
+    client.finalize_delisting(&asset);
+    assert_eq!(client.get_listed_asset_count(), 0);
+    assert_eq!(client.get_asset_listing(&asset), None);
+    assert_eq!(client.get_sunset_status(&asset), None);
+}
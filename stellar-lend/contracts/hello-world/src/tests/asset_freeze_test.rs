@@ -0,0 +1,60 @@
+//! # Asset Freeze Tests
+//!
+//! Covers the wind-down freeze: frozen assets reject new deposits and borrows
+//! while repayments and withdrawals keep working.
+
+#![cfg(test)]
+
+use crate::errors::ProtocolError;
+use crate::risk_management::RiskManagementError;
+use crate::testutils::setup_pool;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+#[test]
+fn test_set_asset_frozen_access_control() {
+    let (env, client, admin) = setup_pool();
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_asset_frozen(&stranger, &None, &true),
+        Err(Ok(RiskManagementError::Unauthorized))
+    );
+    assert!(!client.is_asset_frozen(&None));
+
+    client.set_asset_frozen(&admin, &None, &true);
+    assert!(client.is_asset_frozen(&None));
+    client.set_asset_frozen(&admin, &None, &false);
+    assert!(!client.is_asset_frozen(&None));
+}
+
+#[test]
+fn test_frozen_asset_blocks_new_exposure() {
+    let (env, client, admin) = setup_pool();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+
+    client.set_asset_frozen(&admin, &None, &true);
+    assert_eq!(
+        client.try_deposit_collateral(&user, &None, &1_000),
+        Err(Ok(ProtocolError::AssetFrozen))
+    );
+    assert_eq!(
+        client.try_borrow_asset(&user, &None, &1_000),
+        Err(Ok(ProtocolError::AssetFrozen))
+    );
+    assert_eq!(client.get_max_borrowable(&user, &None), 0);
+}
+
+#[test]
+fn test_frozen_asset_allows_exits() {
+    let (env, client, admin) = setup_pool();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    client.borrow_asset(&user, &None, &2_000);
+
+    client.set_asset_frozen(&admin, &None, &true);
+    client.repay_debt(&user, &None, &2_000);
+    client.withdraw_collateral(&user, &None, &5_000);
+    assert!(client.get_max_withdrawable(&user, &None) > 0);
+}
@@ -0,0 +1,138 @@
+//! # Asset Listing Tests
+//!
+//! Covers listing new markets through `ProposalType::ListAsset`: the full
+//! configuration is applied on execution, the registry pages in listing
+//! order, and invalid or duplicate listings are rejected at creation.
+
+#![cfg(test)]
+
+use crate::asset_registry::AssetListing;
+use crate::deposit::AssetParams;
+use crate::errors::GovernanceError;
+use crate::interest_rate::AssetRateModel;
+use crate::oracle::AssetOracleConfig;
+use crate::testutils::{execute_governance_proposal, setup_governance, setup_pool};
+use crate::types::ProposalType;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, Env, String};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, admin) = setup_pool();
+    let gov_token = setup_governance(&env, &client, &admin);
+    let voter = Address::generate(&env);
+    StellarAssetClient::new(&env, &gov_token).mint(&voter, &1_000);
+    client.gov_stake(&voter, &1_000);
+    (env, client, voter)
+}
+
+fn listing(env: &Env) -> AssetListing {
+    AssetListing {
+        params: AssetParams {
+            deposit_enabled: true,
+            collateral_factor: 7_500,
+            max_deposit: 1_000_000,
+            borrow_fee_bps: 50,
+        },
+        oracle: AssetOracleConfig {
+            feed: Address::generate(env),
+            decimals: 7,
+            heartbeat: 600,
+            max_deviation_bps: 1_000,
+        },
+        rate_model: Some(AssetRateModel {
+            base_rate_bps: 100,
+            kink_utilization_bps: 8_000,
+            multiplier_bps: 1_000,
+            jump_multiplier_bps: 5_000,
+        }),
+    }
+}
+
+#[test]
+fn test_list_asset_applies_full_config() {
+    let (env, client, voter) = setup();
+    let asset = Address::generate(&env);
+    let config = listing(&env);
+
+    execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::ListAsset(asset.clone(), config.clone()),
+    );
+
+    assert_eq!(client.get_asset_params(&asset), Some(config.params.clone()));
+    assert_eq!(
+        client.get_asset_oracle_config(&asset),
+        Some(config.oracle.clone())
+    );
+    assert_eq!(
+        client.get_asset_rate_model(&Some(asset.clone())),
+        config.rate_model.clone()
+    );
+    let entry = client.get_asset_listing(&asset).unwrap();
+    assert_eq!(entry.listing, config);
+    assert_eq!(entry.listed_at, env.ledger().timestamp());
+}
+
+#[test]
+fn test_listed_assets_pagination() {
+    let (env, client, voter) = setup();
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::ListAsset(first.clone(), listing(&env)),
+    );
+    execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::ListAsset(second.clone(), listing(&env)),
+    );
+
+    assert_eq!(client.get_listed_asset_count(), 2);
+    let all = client.get_listed_assets(&0, &10);
+    assert_eq!(all.len(), 2);
+    assert_eq!(all.get(0), Some(first));
+    assert_eq!(all.get(1), Some(second.clone()));
+
+    let page = client.get_listed_assets(&1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0), Some(second));
+    assert_eq!(client.get_listed_assets(&2, &10).len(), 0);
+}
+
+#[test]
+fn test_invalid_or_duplicate_listing_rejected() {
+    let (env, client, voter) = setup();
+    let asset = Address::generate(&env);
+
+    let mut bad = listing(&env);
+    bad.params.collateral_factor = 10_001;
+    assert_eq!(
+        client.try_gov_create_proposal(
+            &voter,
+            &ProposalType::ListAsset(asset.clone(), bad),
+            &String::from_str(&env, "List asset"),
+            &None,
+        ),
+        Err(Ok(GovernanceError::InvalidProposal))
+    );
+
+    execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::ListAsset(asset.clone(), listing(&env)),
+    );
+    assert_eq!(
+        client.try_gov_create_proposal(
+            &voter,
+            &ProposalType::ListAsset(asset, listing(&env)),
+            &String::from_str(&env, "List asset again"),
+            &None,
+        ),
+        Err(Ok(GovernanceError::InvalidProposal))
+    );
+}
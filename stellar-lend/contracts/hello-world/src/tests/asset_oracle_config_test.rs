@@ -0,0 +1,131 @@
+//! # Per-Asset Oracle Configuration Tests
+//!
+//! Covers setting an asset's oracle configuration through the admin and
+//! governance paths, and the heartbeat, deviation and decimals overrides it
+//! applies to price updates.
+
+#![cfg(test)]
+
+use crate::oracle::{AssetOracleConfig, OracleError};
+use crate::testutils::{execute_governance_proposal, setup_governance, setup_pool};
+use crate::types::ProposalType;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, admin) = setup_pool();
+
+    let asset = Address::generate(&env);
+
+    (env, client, admin, asset)
+}
+
+fn config(feed: &Address) -> AssetOracleConfig {
+    AssetOracleConfig {
+        feed: feed.clone(),
+        decimals: 7,
+        heartbeat: 600,
+        max_deviation_bps: 1_000,
+    }
+}
+
+#[test]
+fn test_set_and_get_asset_oracle_config() {
+    let (env, client, admin, asset) = setup();
+    let feed = Address::generate(&env);
+    assert_eq!(client.get_asset_oracle_config(&asset), None);
+
+    client.set_asset_oracle_config(&admin, &asset, &config(&feed));
+    assert_eq!(client.get_asset_oracle_config(&asset), Some(config(&feed)));
+
+    // The configured feed is authorized to push prices
+    assert_eq!(client.update_price_feed(&feed, &asset, &10_000_000, &7, &feed), 10_000_000);
+}
+
+#[test]
+fn test_set_asset_oracle_config_validation() {
+    let (env, client, admin, asset) = setup();
+    let feed = Address::generate(&env);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_asset_oracle_config(&stranger, &asset, &config(&feed)),
+        Err(Ok(OracleError::Unauthorized))
+    );
+
+    let mut bad = config(&feed);
+    bad.heartbeat = 0;
+    assert_eq!(
+        client.try_set_asset_oracle_config(&admin, &asset, &bad),
+        Err(Ok(OracleError::InvalidPrice))
+    );
+
+    let mut bad = config(&feed);
+    bad.max_deviation_bps = 10_001;
+    assert_eq!(
+        client.try_set_asset_oracle_config(&admin, &asset, &bad),
+        Err(Ok(OracleError::InvalidPrice))
+    );
+
+    assert_eq!(
+        client.try_set_asset_oracle_config(&admin, &asset, &config(&client.address)),
+        Err(Ok(OracleError::InvalidOracle))
+    );
+}
+
+#[test]
+fn test_asset_deviation_limit_overrides_global() {
+    let (env, client, admin, asset) = setup();
+    let feed = Address::generate(&env);
+    client.set_asset_oracle_config(&admin, &asset, &config(&feed));
+    client.update_price_feed(&admin, &asset, &10_000_000, &7, &feed);
+
+    // 8% move: above the 5% global default, within the asset's 10% limit
+    client.update_price_feed(&admin, &asset, &10_800_000, &7, &feed);
+    assert_eq!(client.get_price(&asset), 10_800_000);
+
+    let mut tight = config(&feed);
+    tight.max_deviation_bps = 100;
+    client.set_asset_oracle_config(&admin, &asset, &tight);
+    assert!(client
+        .try_update_price_feed(&admin, &asset, &11_000_000, &7, &feed)
+        .is_err());
+}
+
+#[test]
+fn test_asset_heartbeat_overrides_global_staleness() {
+    let (env, client, admin, asset) = setup();
+    let feed = Address::generate(&env);
+    client.set_asset_oracle_config(&admin, &asset, &config(&feed));
+    client.update_price_feed(&admin, &asset, &10_000_000, &7, &feed);
+
+    // Past the cache TTL and the 600s heartbeat, still inside the global hour
+    env.ledger().with_mut(|li| li.timestamp += 601);
+    assert!(client.try_get_price(&asset).is_err());
+}
+
+#[test]
+fn test_mismatched_decimals_rejected() {
+    let (env, client, admin, asset) = setup();
+    let feed = Address::generate(&env);
+    client.set_asset_oracle_config(&admin, &asset, &config(&feed));
+    assert!(client
+        .try_update_price_feed(&admin, &asset, &10_000_000, &8, &feed)
+        .is_err());
+}
+
+#[test]
+fn test_asset_oracle_config_via_governance() {
+    let (env, client, admin, asset) = setup();
+    setup_governance(&env, &client, &admin);
+
+    let feed = Address::generate(&env);
+    execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::AssetOracleConfig(asset.clone(), config(&feed)),
+    );
+
+    assert_eq!(client.get_asset_oracle_config(&asset), Some(config(&feed)));
+}
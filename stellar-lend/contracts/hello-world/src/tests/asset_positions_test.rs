@@ -0,0 +1,250 @@
+//! # Per-Asset Position Tests
+//!
+//! Covers the `(user, asset)` ledger kept next to the unified position:
+//! separate balances per deposited asset, the asset registries, withdrawals
+//! limited to the asset's own collateral, legacy unattributed collateral,
+//! per-asset debt from borrows and repayments, excluding an asset from
+//! collateral, and liquidations seizing only collateral held in the asset.
+
+#![cfg(test)]
+
+use crate::asset_positions::{AssetPosition, AssetPositionError};
+use crate::errors::ProtocolError;
+use crate::liquidate::LiquidationError;
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+struct Setup {
+    env: Env,
+    client: HelloContractClient<'static>,
+    admin: Address,
+    usdc: Address,
+    xlm: Address,
+}
+
+fn setup() -> Setup {
+    let (env, client, admin) = setup_pool();
+
+    let usdc = env.register_stellar_asset_contract(admin.clone());
+    let xlm = env.register_stellar_asset_contract(admin.clone());
+
+    Setup {
+        env,
+        client,
+        admin,
+        usdc,
+        xlm,
+    }
+}
+
+/// Mint `amount` of `asset` to `user` and deposit it as collateral
+fn deposit(s: &Setup, user: &Address, asset: &Address, amount: i128) {
+    StellarAssetClient::new(&s.env, asset).mint(user, &amount);
+    TokenClient::new(&s.env, asset).approve(user, &s.client.address, &amount, &1_000);
+    s.client
+        .deposit_collateral(user, &Some(asset.clone()), &amount);
+}
+
+#[test]
+fn test_deposits_are_tracked_per_asset() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    deposit(&s, &user, &s.usdc, 1_000);
+    deposit(&s, &user, &s.xlm, 300);
+
+    assert_eq!(
+        s.client.get_asset_position(&user, &Some(s.usdc.clone())),
+        AssetPosition {
+            collateral: 1_000,
+            debt: 0,
+        }
+    );
+    assert_eq!(
+        s.client
+            .get_asset_position(&user, &Some(s.xlm.clone()))
+            .collateral,
+        300
+    );
+
+    let assets = s.client.get_user_assets(&user);
+    assert_eq!(assets.len(), 2);
+    assert!(assets.contains(&Some(s.usdc.clone())));
+    assert!(assets.contains(&Some(s.xlm.clone())));
+    assert_eq!(s.client.get_supported_assets().len(), 2);
+}
+
+#[test]
+fn test_withdraw_limited_to_asset_collateral() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    deposit(&s, &user, &s.usdc, 1_000);
+    deposit(&s, &user, &s.xlm, 300);
+
+    // The unified balance covers 500, but only 300 was deposited as XLM
+    StellarAssetClient::new(&s.env, &s.xlm).mint(&s.client.address, &500);
+    assert_eq!(
+        s.client
+            .try_withdraw_collateral(&user, &Some(s.xlm.clone()), &500),
+        Err(Ok(ProtocolError::InsufficientCollateral))
+    );
+
+    s.client
+        .withdraw_collateral(&user, &Some(s.xlm.clone()), &300);
+    assert_eq!(
+        s.client.get_asset_position(&user, &Some(s.xlm.clone())),
+        AssetPosition::default()
+    );
+    let assets = s.client.get_user_assets(&user);
+    assert_eq!(assets.len(), 1);
+    assert_eq!(assets.get(0).unwrap(), Some(s.usdc.clone()));
+
+    // Closed positions stay in the registry
+    assert_eq!(s.client.get_supported_assets().len(), 2);
+}
+
+#[test]
+fn test_unattributed_collateral_is_withdrawable() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    s.client.test_set_position(&user, &1_000, &0, &0);
+    StellarAssetClient::new(&s.env, &s.usdc).mint(&s.client.address, &1_000);
+
+    s.client
+        .withdraw_collateral(&user, &Some(s.usdc.clone()), &400);
+    assert_eq!(TokenClient::new(&s.env, &s.usdc).balance(&user), 400);
+    assert_eq!(
+        s.client.get_asset_position(&user, &Some(s.usdc.clone())),
+        AssetPosition::default()
+    );
+}
+
+#[test]
+fn test_borrow_and_repay_track_asset_debt() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    s.client.deposit_collateral(&user, &None, &10_000);
+    s.client.borrow_asset(&user, &None, &2_000);
+
+    assert_eq!(
+        s.client.get_asset_position(&user, &None),
+        AssetPosition {
+            collateral: 10_000,
+            debt: 2_000,
+        }
+    );
+
+    s.client.repay_debt(&user, &None, &500);
+    assert_eq!(s.client.get_asset_position(&user, &None).debt, 1_500);
+    assert!(s.client.get_user_assets(&user).contains(&None));
+}
+
+#[test]
+fn test_disabled_collateral_not_counted() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    deposit(&s, &user, &s.usdc, 1_000);
+    deposit(&s, &user, &s.xlm, 1_000);
+    s.client.borrow_asset(&user, &None, &1_000);
+
+    // 1_000 of USDC alone does not cover 1_000 of debt at 110%
+    assert_eq!(
+        s.client
+            .try_set_use_as_collateral(&user, &Some(s.xlm.clone()), &false),
+        Err(Ok(AssetPositionError::InsufficientCollateralRatio))
+    );
+    assert!(s.client.is_collateral_enabled(&user, &Some(s.xlm.clone())));
+
+    s.client.repay_debt(&user, &None, &200);
+    s.client
+        .set_use_as_collateral(&user, &Some(s.xlm.clone()), &false);
+    assert!(!s.client.is_collateral_enabled(&user, &Some(s.xlm.clone())));
+    assert_eq!(
+        s.client.try_borrow_asset(&user, &None, &200),
+        Err(Ok(ProtocolError::MaxBorrowExceeded))
+    );
+
+    s.client
+        .set_use_as_collateral(&user, &Some(s.xlm.clone()), &true);
+    s.client.borrow_asset(&user, &None, &200);
+}
+
+#[test]
+fn test_disabled_collateral_withdrawable_without_ratio_check() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    deposit(&s, &user, &s.usdc, 1_000);
+    deposit(&s, &user, &s.xlm, 1_000);
+    s.client.borrow_asset(&user, &None, &800);
+
+    s.client
+        .set_use_as_collateral(&user, &Some(s.xlm.clone()), &false);
+    s.client
+        .withdraw_collateral(&user, &Some(s.xlm.clone()), &1_000);
+    assert_eq!(TokenClient::new(&s.env, &s.xlm).balance(&user), 1_000);
+
+    // Enabled collateral is still checked
+    assert_eq!(
+        s.client
+            .try_withdraw_collateral(&user, &Some(s.usdc.clone()), &200),
+        Err(Ok(ProtocolError::InsufficientCollateralRatio))
+    );
+}
+
+#[test]
+fn test_disable_requires_collateral_in_asset() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    deposit(&s, &user, &s.usdc, 1_000);
+
+    assert_eq!(
+        s.client
+            .try_set_use_as_collateral(&user, &Some(s.xlm.clone()), &false),
+        Err(Ok(AssetPositionError::NoCollateral))
+    );
+}
+
+#[test]
+fn test_liquidation_seizes_only_collateral_held_in_asset() {
+    let s = setup();
+    let borrower = Address::generate(&s.env);
+    deposit(&s, &borrower, &s.usdc, 1_000);
+    // Underwater, with all of the collateral held in usdc
+    s.client.test_set_position(&borrower, &1_000, &1_000, &0);
+    for asset in [&s.usdc, &s.xlm] {
+        s.client
+            .update_price_feed(&s.admin, asset, &10_000_000, &7, &s.admin);
+    }
+
+    assert_eq!(
+        s.client.try_preview_liquidation(
+            &borrower,
+            &Some(s.usdc.clone()),
+            &Some(s.xlm.clone()),
+            &500,
+        ),
+        Err(Ok(LiquidationError::InvalidCollateralAsset))
+    );
+    let liquidator = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_liquidate(
+            &liquidator,
+            &borrower,
+            &Some(s.usdc.clone()),
+            &Some(s.xlm.clone()),
+            &500,
+        ),
+        Err(Ok(LiquidationError::InvalidCollateralAsset))
+    );
+
+    let preview = s.client.preview_liquidation(
+        &borrower,
+        &Some(s.usdc.clone()),
+        &Some(s.usdc.clone()),
+        &500,
+    );
+    assert!(preview.collateral_seized > 0);
+    assert!(preview.collateral_seized <= 1_000);
+}
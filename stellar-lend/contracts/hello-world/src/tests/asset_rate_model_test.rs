@@ -0,0 +1,138 @@
+//! # Per-Asset Rate Model Tests
+//!
+//! Covers governance-set kink curves per asset: rates below and above the
+//! optimal utilization, positions accruing at the rate of the asset they
+//! borrowed, clearing a curve and parameter validation.
+
+#![cfg(test)]
+
+use crate::interest_rate::{self, AssetRateModel, InterestRateError};
+use crate::testutils::{setup_pool, supply};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, Vec};
+
+const YEAR: u64 = 365 * 86_400;
+
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, admin) = setup_pool();
+
+    let asset = env.register_stellar_asset_contract(admin);
+    (env, client, asset)
+}
+
+/// 5% base, 80% optimal utilization, 40% and 80% slopes
+fn model() -> AssetRateModel {
+    AssetRateModel {
+        base_rate_bps: 500,
+        kink_utilization_bps: 8_000,
+        multiplier_bps: 4_000,
+        jump_multiplier_bps: 8_000,
+    }
+}
+
+fn set_model(
+    env: &Env,
+    client: &HelloContractClient,
+    asset: &Address,
+    model: Option<AssetRateModel>,
+) -> Result<(), InterestRateError> {
+    env.as_contract(&client.address, || {
+        interest_rate::set_asset_rate_model(env, Some(asset.clone()), model)
+    })
+}
+
+/// Deposit 10_000 and borrow `amount` of `asset` for a fresh user
+fn open_position(
+    env: &Env,
+    client: &HelloContractClient,
+    asset: Option<Address>,
+    amount: i128,
+) -> Address {
+    let user = Address::generate(env);
+    client.deposit_collateral(&user, &None, &10_000);
+    client.borrow_asset(&user, &asset, &amount);
+    user
+}
+
+#[test]
+fn test_asset_curve_below_kink() {
+    let (env, client, asset) = setup();
+    set_model(&env, &client, &asset, Some(model())).unwrap();
+    assert_eq!(
+        client.get_asset_rate_model(&Some(asset.clone())),
+        Some(model())
+    );
+
+    // 50% utilization: 5% + 50/80 × 40%; the pool curve gives 1% + 50/80 × 20%
+    open_position(&env, &client, None, 5_000);
+    assert_eq!(client.get_asset_borrow_rate(&Some(asset.clone())), 3_000);
+    assert_eq!(client.get_asset_borrow_rate(&None), 1_350);
+}
+
+#[test]
+fn test_asset_curve_above_kink() {
+    let (env, client, asset) = setup();
+    set_model(&env, &client, &asset, Some(model())).unwrap();
+
+    // 90% utilization: 5% + 40% + 10/20 × 80%; the pool gives 1% + 20% + 10/20 × 100%
+    open_position(&env, &client, None, 9_000);
+    open_position(&env, &client, None, 9_000);
+    assert_eq!(client.get_asset_borrow_rate(&Some(asset.clone())), 8_500);
+    assert_eq!(client.get_asset_borrow_rate(&None), 7_100);
+}
+
+#[test]
+fn test_position_accrues_at_borrowed_asset_rate() {
+    let (env, client, asset) = setup();
+    set_model(&env, &client, &asset, Some(model())).unwrap();
+
+    // Collateral supplied in the asset itself keeps total deposits at 20_000
+    let asset_borrower = Address::generate(&env);
+    supply(&env, &client, &asset_borrower, &asset, 10_000);
+    client.borrow_asset(&asset_borrower, &Some(asset.clone()), &5_000);
+    let pool_borrower = open_position(&env, &client, None, 5_000);
+
+    env.ledger().with_mut(|li| li.timestamp += YEAR);
+
+    let mut users = Vec::new(&env);
+    users.push_back(asset_borrower);
+    users.push_back(pool_borrower);
+    let health = client.get_account_health_batch(&users);
+
+    // 50% utilization: 30% on the asset's curve, 13.5% on the pool curve
+    assert_eq!(health.get(0).unwrap().debt_value, 6_500);
+    assert_eq!(health.get(1).unwrap().debt_value, 5_675);
+}
+
+#[test]
+fn test_clearing_model_restores_pool_curve() {
+    let (env, client, asset) = setup();
+    set_model(&env, &client, &asset, Some(model())).unwrap();
+    open_position(&env, &client, None, 5_000);
+    assert_eq!(client.get_asset_borrow_rate(&Some(asset.clone())), 3_000);
+
+    set_model(&env, &client, &asset, None).unwrap();
+    assert_eq!(client.get_asset_rate_model(&Some(asset.clone())), None);
+    assert_eq!(client.get_asset_borrow_rate(&Some(asset.clone())), 1_350);
+}
+
+#[test]
+fn test_asset_model_validation() {
+    let (env, client, asset) = setup();
+
+    let mut bad = model();
+    bad.kink_utilization_bps = 10_000;
+    assert_eq!(
+        set_model(&env, &client, &asset, Some(bad)),
+        Err(InterestRateError::InvalidParameter)
+    );
+
+    let mut bad = model();
+    bad.base_rate_bps = -1;
+    assert_eq!(
+        set_model(&env, &client, &asset, Some(bad)),
+        Err(InterestRateError::InvalidParameter)
+    );
+    assert_eq!(client.get_asset_rate_model(&Some(asset)), None);
+}
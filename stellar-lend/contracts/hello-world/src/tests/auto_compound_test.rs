@@ -0,0 +1,253 @@
+//! # Supply Interest Auto-Compounding Tests
+//!
+//! Covers settlement of supply interest into debt repayment (auto-repay),
+//! collateral (auto-compound) or a claimable balance (default).
+
+#![cfg(test)]
+
+use crate::deposit::{DepositDataKey, Position, ProtocolAnalytics};
+use crate::interest_rate::{InterestRateDataKey, SupplyIndex, EXCHANGE_RATE_SCALE};
+use crate::supply_interest::{settle_supply_interest, SupplyInterestDataKey, SupplyInterestError};
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env};
+
+const SECONDS_PER_YEAR: u64 = 365 * 86_400;
+
+/// Contract at 40% utilization (900 bps supply rate) with a supplier holding 10_000
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, _admin) = setup_pool();
+
+    let user = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        storage.set(
+            &DepositDataKey::ProtocolAnalytics,
+            &ProtocolAnalytics {
+                total_deposits: 10_000,
+                total_borrows: 4_000,
+                total_value_locked: 10_000,
+            },
+        );
+        storage.set(
+            &InterestRateDataKey::SupplyIndex(None),
+            &SupplyIndex {
+                rate: EXCHANGE_RATE_SCALE,
+                last_update: 0,
+            },
+        );
+        storage.set(
+            &DepositDataKey::Position(user.clone()),
+            &Position {
+                collateral: 10_000,
+                debt: 0,
+                borrow_interest: 0,
+                last_accrual_time: 0,
+            },
+        );
+        storage.set(&DepositDataKey::CollateralBalance(user.clone()), &10_000i128);
+    });
+
+    (env, client, user)
+}
+
+fn collateral_of(env: &Env, client: &HelloContractClient, user: &Address) -> i128 {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&DepositDataKey::CollateralBalance(user.clone()))
+            .unwrap()
+    })
+}
+
+#[test]
+fn test_auto_compound_folds_interest_into_collateral() {
+    let (env, client, user) = setup();
+    client.set_auto_compound(&user, &None, &true);
+    assert!(client.is_auto_compound(&user));
+
+    env.ledger().set_timestamp(SECONDS_PER_YEAR);
+    // Turning the flag off settles the year under auto-compound
+    client.set_auto_compound(&user, &None, &false);
+
+    assert_eq!(collateral_of(&env, &client, &user), 10_900);
+    assert_eq!(client.get_claimable_interest(&user), 0);
+    env.as_contract(&client.address, || {
+        let position: Position = env
+            .storage()
+            .persistent()
+            .get(&DepositDataKey::Position(user.clone()))
+            .unwrap();
+        assert_eq!(position.collateral, 10_900);
+        let analytics: ProtocolAnalytics = env
+            .storage()
+            .persistent()
+            .get(&DepositDataKey::ProtocolAnalytics)
+            .unwrap();
+        assert_eq!(analytics.total_deposits, 10_900);
+    });
+}
+
+#[test]
+fn test_interest_is_claimable_by_default() {
+    let (env, client, user) = setup();
+    env.as_contract(&client.address, || {
+        settle_supply_interest(&env, &user, None).unwrap();
+    });
+
+    env.ledger().set_timestamp(SECONDS_PER_YEAR);
+    let earned = env.as_contract(&client.address, || {
+        settle_supply_interest(&env, &user, None).unwrap()
+    });
+
+    assert_eq!(earned, 900);
+    assert_eq!(client.get_claimable_interest(&user), 900);
+    assert_eq!(collateral_of(&env, &client, &user), 10_000);
+}
+
+#[test]
+fn test_rate_snapshots_are_kept_per_asset() {
+    let (env, client, user) = setup();
+    let token = Some(Address::generate(&env));
+    env.as_contract(&client.address, || {
+        settle_supply_interest(&env, &user, None).unwrap();
+    });
+
+    // Settling another asset must not move the native snapshot forward
+    env.ledger().set_timestamp(SECONDS_PER_YEAR);
+    let earned = env.as_contract(&client.address, || {
+        settle_supply_interest(&env, &user, token.clone()).unwrap();
+        assert_eq!(
+            env.storage()
+                .persistent()
+                .get::<_, i128>(&SupplyInterestDataKey::RateSnapshot(user.clone(), None)),
+            Some(EXCHANGE_RATE_SCALE)
+        );
+        settle_supply_interest(&env, &user, None).unwrap()
+    });
+    assert_eq!(earned, 900);
+}
+
+#[test]
+fn test_claim_with_nothing_earned_fails() {
+    let (_env, client, user) = setup();
+    assert_eq!(
+        client.try_claim_supply_interest(&user, &None),
+        Err(Ok(SupplyInterestError::NothingToClaim))
+    );
+}
+
+fn set_debt(env: &Env, client: &HelloContractClient, user: &Address, debt: i128, interest: i128) {
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DepositDataKey::Position(user.clone()),
+            &Position {
+                collateral: 10_000,
+                debt,
+                borrow_interest: interest,
+                last_accrual_time: 0,
+            },
+        );
+    });
+}
+
+fn position_of(env: &Env, client: &HelloContractClient, user: &Address) -> Position {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&DepositDataKey::Position(user.clone()))
+            .unwrap()
+    })
+}
+
+#[test]
+fn test_auto_repay_pays_interest_then_principal() {
+    let (env, client, user) = setup();
+    set_debt(&env, &client, &user, 5_000, 100);
+    client.set_auto_repay(&user, &None, &true);
+    assert!(client.is_auto_repay(&user));
+
+    env.ledger().set_timestamp(SECONDS_PER_YEAR);
+    let earned = env.as_contract(&client.address, || {
+        settle_supply_interest(&env, &user, None).unwrap()
+    });
+
+    assert_eq!(earned, 900);
+    let position = position_of(&env, &client, &user);
+    assert_eq!(position.borrow_interest, 0);
+    assert_eq!(position.debt, 4_200);
+    assert_eq!(client.get_claimable_interest(&user), 0);
+    env.as_contract(&client.address, || {
+        let analytics: ProtocolAnalytics = env
+            .storage()
+            .persistent()
+            .get(&DepositDataKey::ProtocolAnalytics)
+            .unwrap();
+        assert_eq!(analytics.total_borrows, 3_100);
+    });
+}
+
+#[test]
+fn test_auto_repay_surplus_follows_compound_setting() {
+    let (env, client, user) = setup();
+    set_debt(&env, &client, &user, 500, 100);
+    client.set_auto_repay(&user, &None, &true);
+
+    env.ledger().set_timestamp(SECONDS_PER_YEAR);
+    env.as_contract(&client.address, || {
+        settle_supply_interest(&env, &user, None).unwrap();
+    });
+
+    let position = position_of(&env, &client, &user);
+    assert_eq!(position.debt, 0);
+    assert_eq!(position.borrow_interest, 0);
+    // 900 earned, 600 repaid, the rest is claimable
+    assert_eq!(client.get_claimable_interest(&user), 300);
+}
+
+#[test]
+fn test_auto_repay_disabled_leaves_debt() {
+    let (env, client, user) = setup();
+    set_debt(&env, &client, &user, 5_000, 0);
+    env.as_contract(&client.address, || {
+        settle_supply_interest(&env, &user, None).unwrap();
+    });
+
+    env.ledger().set_timestamp(SECONDS_PER_YEAR);
+    env.as_contract(&client.address, || {
+        settle_supply_interest(&env, &user, None).unwrap();
+    });
+
+    assert_eq!(position_of(&env, &client, &user).debt, 5_000);
+    assert_eq!(client.get_claimable_interest(&user), 900);
+}
+
+#[test]
+fn test_auto_repay_converts_by_price_into_the_debt_asset() {
+    let (env, client, user) = setup();
+    let admin = env.as_contract(&client.address, || crate::admin::get_admin(&env).unwrap());
+    let debt_asset = Address::generate(&env);
+    // One unit of the debt asset is worth 2 of native XLM
+    crate::testutils::set_price(&client, &admin, &debt_asset, 200_000_000);
+    set_debt(&env, &client, &user, 1_000, 0);
+    env.as_contract(&client.address, || {
+        crate::asset_positions::record_borrow(&env, &user, Some(debt_asset.clone()), 1_000)
+            .unwrap();
+    });
+    client.set_auto_repay(&user, &None, &true);
+
+    env.ledger().set_timestamp(SECONDS_PER_YEAR);
+    env.as_contract(&client.address, || {
+        settle_supply_interest(&env, &user, None).unwrap();
+    });
+
+    // 900 XLM of interest is worth 450 of the debt asset
+    assert_eq!(position_of(&env, &client, &user).debt, 550);
+    assert_eq!(
+        client.get_asset_position(&user, &Some(debt_asset)).debt,
+        550
+    );
+    assert_eq!(client.get_asset_position(&user, &None).debt, 0);
+    assert_eq!(client.get_claimable_interest(&user), 0);
+}
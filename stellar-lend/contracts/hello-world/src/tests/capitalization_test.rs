@@ -0,0 +1,96 @@
+//! # Interest Capitalization Tests
+//!
+//! Covers the admin-configured schedule that rolls accrued borrow interest
+//! into principal during interest accrual.
+
+#![cfg(test)]
+
+use crate::capitalization::CapitalizationError;
+use crate::deposit::{DepositDataKey, Position};
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+const DEBT: i128 = 1_000_000_000;
+
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, admin) = setup_pool();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    (env, client, admin)
+}
+
+fn open_position(client: &HelloContractClient, user: &Address) {
+    client.test_set_position(user, &(DEBT * 2), &DEBT, &0);
+}
+
+/// Run an accrual the way borrow and repay do and persist the result
+fn accrue(env: &Env, client: &HelloContractClient, user: &Address) -> Position {
+    env.as_contract(&client.address, || {
+        let key = DepositDataKey::Position(user.clone());
+        let mut position: Position = env.storage().persistent().get(&key).unwrap();
+        crate::repay::accrue_interest(env, user, &mut position).unwrap();
+        env.storage().persistent().set(&key, &position);
+        position
+    })
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+#[test]
+fn test_capitalization_disabled_by_default() {
+    let (env, client, _admin) = setup();
+    assert_eq!(client.get_capitalization_interval(), 0);
+
+    let user = Address::generate(&env);
+    open_position(&client, &user);
+    advance(&env, 30 * 86_400);
+    let position = accrue(&env, &client, &user);
+    assert_eq!(position.debt, DEBT);
+    assert!(position.borrow_interest > 0);
+}
+
+#[test]
+fn test_interest_rolls_into_debt_each_interval() {
+    let (env, client, admin) = setup();
+    client.set_capitalization_interval(&admin, &86_400);
+
+    let user = Address::generate(&env);
+    open_position(&client, &user);
+    // First accrual starts the clock
+    accrue(&env, &client, &user);
+
+    advance(&env, 43_200);
+    let position = accrue(&env, &client, &user);
+    assert_eq!(position.debt, DEBT);
+    let pending = position.borrow_interest;
+    assert!(pending > 0);
+
+    advance(&env, 43_200);
+    let position = accrue(&env, &client, &user);
+    assert!(position.debt > DEBT + pending);
+    assert_eq!(position.borrow_interest, 0);
+
+    // Not due again until another full interval has passed
+    advance(&env, 3_600);
+    let position = accrue(&env, &client, &user);
+    assert!(position.borrow_interest > 0);
+}
+
+#[test]
+fn test_capitalization_interval_validation() {
+    let (env, client, admin) = setup();
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_capitalization_interval(&stranger, &86_400),
+        Err(Ok(CapitalizationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_capitalization_interval(&admin, &60),
+        Err(Ok(CapitalizationError::InvalidParameter))
+    );
+    client.set_capitalization_interval(&admin, &0);
+}
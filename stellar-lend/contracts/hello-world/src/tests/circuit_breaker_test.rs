@@ -0,0 +1,123 @@
+//! # Volume Circuit Breaker Tests
+//!
+//! Covers per-ledger borrow/withdrawal volume tracking, automatic tripping on
+//! abnormal volume, cooldown and early reset.
+
+#![cfg(test)]
+
+use crate::circuit_breaker::{CircuitBreakerConfig, CircuitBreakerError};
+use crate::errors::ProtocolError;
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, admin) = setup_pool();
+
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &1_000_000);
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+
+    (env, client, admin, user)
+}
+
+fn config() -> CircuitBreakerConfig {
+    CircuitBreakerConfig {
+        trip_multiple_bps: 30_000,
+        min_volume: 10_000,
+        cooldown_ledgers: 10,
+    }
+}
+
+fn next_ledger(env: &Env) {
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+}
+
+#[test]
+fn test_unconfigured_asset_never_trips() {
+    let (_env, client, _admin, user) = setup();
+    client.withdraw_collateral(&user, &None, &500_000);
+    assert!(!client.is_circuit_breaker_tripped(&None));
+}
+
+#[test]
+fn test_volume_above_floor_trips_breaker() {
+    let (env, client, admin, user) = setup();
+    client.set_circuit_breaker(&admin, &None, &config());
+
+    client.withdraw_collateral(&user, &None, &5_000);
+    assert!(!client.is_circuit_breaker_tripped(&None));
+
+    // Pushes this ledger's volume past the 10_000 floor
+    client.withdraw_collateral(&user, &None, &6_000);
+    assert!(client.is_circuit_breaker_tripped(&None));
+
+    assert_eq!(
+        client.try_withdraw_collateral(&user, &None, &1),
+        Err(Ok(ProtocolError::CircuitBreakerTripped))
+    );
+    assert_eq!(
+        client.try_borrow_asset(&user, &None, &1),
+        Err(Ok(ProtocolError::CircuitBreakerTripped))
+    );
+
+    // Cooldown expires
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    assert!(!client.is_circuit_breaker_tripped(&None));
+    client.withdraw_collateral(&user, &None, &1);
+}
+
+#[test]
+fn test_threshold_follows_average_volume() {
+    let (env, client, admin, user) = setup();
+    let mut cfg = config();
+    cfg.min_volume = 1_500;
+    client.set_circuit_breaker(&admin, &None, &cfg);
+
+    // Build an average of normal volume
+    client.withdraw_collateral(&user, &None, &1_000);
+    for _ in 0..20 {
+        next_ledger(&env);
+        client.withdraw_collateral(&user, &None, &1_000);
+    }
+    assert!(!client.is_circuit_breaker_tripped(&None));
+
+    // A ledger at well over 3x normal volume trips
+    next_ledger(&env);
+    client.withdraw_collateral(&user, &None, &5_000);
+    assert!(client.is_circuit_breaker_tripped(&None));
+}
+
+#[test]
+fn test_admin_can_reset_early() {
+    let (_env, client, admin, user) = setup();
+    client.set_circuit_breaker(&admin, &None, &config());
+    client.withdraw_collateral(&user, &None, &20_000);
+    assert!(client.is_circuit_breaker_tripped(&None));
+
+    client.reset_circuit_breaker(&admin, &None);
+    assert!(!client.is_circuit_breaker_tripped(&None));
+    client.withdraw_collateral(&user, &None, &1);
+}
+
+#[test]
+fn test_config_validation_and_auth() {
+    let (env, client, admin, _user) = setup();
+    let mut bad = config();
+    bad.trip_multiple_bps = 10_000;
+    assert_eq!(
+        client.try_set_circuit_breaker(&admin, &None, &bad),
+        Err(Ok(CircuitBreakerError::InvalidParameter))
+    );
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_circuit_breaker(&stranger, &None, &config()),
+        Err(Ok(CircuitBreakerError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_reset_circuit_breaker(&stranger, &None),
+        Err(Ok(CircuitBreakerError::Unauthorized))
+    );
+}
@@ -0,0 +1,110 @@
+//! # Merkle Claims Tests
+//!
+//! Covers campaign creation through governance, proof verification, and
+//! double-claim and over-claim protection.
+
+#![cfg(test)]
+
+use crate::claims::{create_campaign, hash_pair, leaf_hash, ClaimsError};
+use crate::testutils::{execute_governance_proposal, setup_governance, setup_pool};
+use crate::types::ProposalType;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, BytesN, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, admin) = setup_pool();
+    setup_governance(&env, &client, &admin);
+    (env, client, admin)
+}
+
+/// Two-leaf tree for (alice, 300) and (bob, 700); returns the root
+fn two_leaf_root(env: &Env, alice: &Address, bob: &Address) -> BytesN<32> {
+    hash_pair(env, &leaf_hash(env, alice, 300), &leaf_hash(env, bob, 700))
+}
+
+/// Reward token funded to the contract, and a campaign over it
+fn funded_campaign(
+    env: &Env,
+    client: &HelloContractClient,
+    admin: &Address,
+    root: BytesN<32>,
+    total: i128,
+) -> (u64, Address) {
+    let reward = env.register_stellar_asset_contract(admin.clone());
+    StellarAssetClient::new(env, &reward).mint(&client.address, &total);
+    let id = env.as_contract(&client.address, || {
+        create_campaign(env, reward.clone(), root, total).unwrap()
+    });
+    (id, reward)
+}
+
+#[test]
+fn test_claim_with_valid_proof() {
+    let (env, client, admin) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let root = two_leaf_root(&env, &alice, &bob);
+    let (id, reward) = funded_campaign(&env, &client, &admin, root, 1_000);
+
+    let proof = vec![&env, leaf_hash(&env, &bob, 700)];
+    client.claim(&id, &alice, &300, &proof);
+
+    assert_eq!(TokenClient::new(&env, &reward).balance(&alice), 300);
+    assert!(client.has_claimed(&id, &alice));
+    assert_eq!(client.get_claim_campaign(&id).unwrap().claimed_amount, 300);
+
+    assert_eq!(
+        client.try_claim(&id, &alice, &300, &proof),
+        Err(Ok(ClaimsError::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_claim_with_wrong_amount_fails() {
+    let (env, client, admin) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let root = two_leaf_root(&env, &alice, &bob);
+    let (id, _reward) = funded_campaign(&env, &client, &admin, root, 1_000);
+
+    let proof = vec![&env, leaf_hash(&env, &bob, 700)];
+    assert_eq!(
+        client.try_claim(&id, &alice, &700, &proof),
+        Err(Ok(ClaimsError::InvalidProof))
+    );
+}
+
+#[test]
+fn test_claims_cannot_exceed_funded_total() {
+    let (env, client, admin) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let root = two_leaf_root(&env, &alice, &bob);
+    let (id, _reward) = funded_campaign(&env, &client, &admin, root, 800);
+
+    client.claim(&id, &bob, &700, &vec![&env, leaf_hash(&env, &alice, 300)]);
+    assert_eq!(
+        client.try_claim(&id, &alice, &300, &vec![&env, leaf_hash(&env, &bob, 700)]),
+        Err(Ok(ClaimsError::CampaignExhausted))
+    );
+}
+
+#[test]
+fn test_campaign_created_via_governance() {
+    let (env, client, admin) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let reward = env.register_stellar_asset_contract(admin.clone());
+
+    execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::CreateClaimCampaign(reward, two_leaf_root(&env, &alice, &bob), 1_000),
+    );
+
+    let campaign = client.get_claim_campaign(&0).unwrap();
+    assert_eq!(campaign.total_amount, 1_000);
+    assert_eq!(campaign.claimed_amount, 0);
+}
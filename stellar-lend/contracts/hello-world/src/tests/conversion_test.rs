@@ -0,0 +1,58 @@
+//! # Asset Conversion Tests
+//!
+//! Covers the `value_of` and `convert` views built on oracle prices and
+//! token decimals.
+
+#![cfg(test)]
+
+use crate::oracle::OracleError;
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address, Address) {
+    let (env, client, admin) = setup_pool();
+
+    // Stellar asset contracts report 7 decimals
+    let usdc = env.register_stellar_asset_contract(admin.clone());
+    let xlm = env.register_stellar_asset_contract(admin.clone());
+    client.update_price_feed(&admin, &usdc, &10_000_000, &7, &admin);
+    client.update_price_feed(&admin, &xlm, &1_250_000, &7, &admin);
+
+    (env, client, admin, usdc, xlm)
+}
+
+#[test]
+fn test_value_of() {
+    let (_env, client, _admin, usdc, xlm) = setup();
+    // 100 USDC at $1.00
+    assert_eq!(client.value_of(&usdc, &1_000_000_000), 1_000_000_000);
+    // 100 XLM at $0.125
+    assert_eq!(client.value_of(&xlm, &1_000_000_000), 125_000_000);
+}
+
+#[test]
+fn test_convert_between_assets() {
+    let (_env, client, _admin, usdc, xlm) = setup();
+    assert_eq!(client.convert(&usdc, &xlm, &1_000_000_000), 8_000_000_000);
+    assert_eq!(client.convert(&xlm, &usdc, &8_000_000_000), 1_000_000_000);
+    assert_eq!(client.convert(&xlm, &xlm, &123), 123);
+}
+
+#[test]
+fn test_convert_rounds_down() {
+    let (_env, client, _admin, usdc, xlm) = setup();
+    // 1 stroop of XLM is worth 0.125 stroops of USDC
+    assert_eq!(client.convert(&xlm, &usdc, &1), 0);
+}
+
+#[test]
+fn test_convert_without_price_fails() {
+    let (env, client, admin, usdc, _xlm) = setup();
+    let unpriced = env.register_stellar_asset_contract(admin);
+    assert_eq!(
+        client.try_convert(&usdc, &unpriced, &1_000),
+        Err(Ok(OracleError::FallbackNotConfigured))
+    );
+}
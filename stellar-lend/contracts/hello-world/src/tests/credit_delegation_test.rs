@@ -0,0 +1,97 @@
+//! # Credit Delegation Tests
+//!
+//! Covers borrowing against a delegator's collateral within an approved
+//! allowance, allowance consumption and revocation, and the delegator's
+//! collateral ratio still bounding delegated borrows.
+
+#![cfg(test)]
+
+use crate::credit_delegation::CreditDelegationError;
+use crate::errors::ProtocolError;
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+/// Delegator with 10_000 native collateral and no debt
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, _admin) = setup_pool();
+
+    let delegator = Address::generate(&env);
+    let delegatee = Address::generate(&env);
+    client.deposit_collateral(&delegator, &None, &10_000);
+
+    (env, client, delegator, delegatee)
+}
+
+#[test]
+fn test_borrow_on_behalf_books_debt_on_delegator() {
+    let (_, client, delegator, delegatee) = setup();
+    client.approve_credit(&delegator, &delegatee, &None, &3_000);
+
+    assert_eq!(
+        client.borrow_on_behalf(&delegatee, &delegator, &None, &2_000),
+        2_000
+    );
+    assert_eq!(client.get_asset_position(&delegator, &None).debt, 2_000);
+    assert_eq!(client.get_asset_position(&delegatee, &None).debt, 0);
+    assert_eq!(
+        client.get_credit_allowance(&delegator, &delegatee, &None),
+        1_000
+    );
+
+    assert_eq!(
+        client.try_borrow_on_behalf(&delegatee, &delegator, &None, &1_001),
+        Err(Ok(ProtocolError::InsufficientAllowance))
+    );
+}
+
+#[test]
+fn test_borrow_on_behalf_requires_allowance() {
+    let (env, client, delegator, delegatee) = setup();
+
+    assert_eq!(
+        client.try_borrow_on_behalf(&delegatee, &delegator, &None, &100),
+        Err(Ok(ProtocolError::InsufficientAllowance))
+    );
+
+    // Allowances are per asset and revocable
+    let other = Address::generate(&env);
+    client.approve_credit(&delegator, &delegatee, &Some(other), &500);
+    client.approve_credit(&delegator, &delegatee, &None, &500);
+    client.approve_credit(&delegator, &delegatee, &None, &0);
+    assert_eq!(
+        client.try_borrow_on_behalf(&delegatee, &delegator, &None, &100),
+        Err(Ok(ProtocolError::InsufficientAllowance))
+    );
+}
+
+#[test]
+fn test_delegator_collateral_ratio_applies() {
+    let (_, client, delegator, delegatee) = setup();
+    client.approve_credit(&delegator, &delegatee, &None, &50_000);
+
+    // 10_000 at 110% supports 9_090
+    assert_eq!(
+        client.try_borrow_on_behalf(&delegatee, &delegator, &None, &9_500),
+        Err(Ok(ProtocolError::MaxBorrowExceeded))
+    );
+    assert_eq!(
+        client.get_credit_allowance(&delegator, &delegatee, &None),
+        50_000
+    );
+}
+
+#[test]
+fn test_approve_credit_validation() {
+    let (_, client, delegator, delegatee) = setup();
+
+    assert_eq!(
+        client.try_approve_credit(&delegator, &delegatee, &None, &-1),
+        Err(Ok(CreditDelegationError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_approve_credit(&delegator, &delegator, &None, &1_000),
+        Err(Ok(CreditDelegationError::SelfDelegation))
+    );
+}
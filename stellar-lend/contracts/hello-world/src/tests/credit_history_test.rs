@@ -0,0 +1,254 @@
+//! # Credit History Tests
+//!
+//! Covers recording of borrows, on-time repayments and liquidations, and the
+//! governance-enabled collateral factor adjustment applied in borrow and
+//! withdraw checks.
+
+#![cfg(test)]
+
+use crate::credit_history::{self, CreditHistoryConfig, CreditHistoryError};
+use crate::deposit::{AssetParams, DepositDataKey, Position};
+use crate::testutils::{setup_pool, supply};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, admin) = setup_pool();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    // Borrowable liquidity in the pool
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    supply(&env, &client, &Address::generate(&env), &asset, 1_000_000);
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DepositDataKey::AssetParams(asset.clone()),
+            &AssetParams {
+                deposit_enabled: true,
+                collateral_factor: 8_000,
+                max_deposit: 0,
+                borrow_fee_bps: 0,
+            },
+        );
+    });
+
+    (env, client, admin, asset)
+}
+
+fn give_collateral(env: &Env, client: &HelloContractClient, user: &Address, amount: i128) {
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DepositDataKey::Position(user.clone()),
+            &Position {
+                collateral: amount,
+                debt: 0,
+                borrow_interest: 0,
+                last_accrual_time: env.ledger().timestamp(),
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&DepositDataKey::CollateralBalance(user.clone()), &amount);
+    });
+}
+
+fn config() -> CreditHistoryConfig {
+    CreditHistoryConfig {
+        enabled: true,
+        min_on_time_repays: 3,
+        min_account_age: 86_400,
+        min_loan_duration: 3_600,
+        min_repay_value: 100,
+        bonus_bps: 500,
+        penalty_per_liquidation_bps: 1_000,
+        max_penalty_bps: 2_500,
+    }
+}
+
+fn enable(env: &Env, client: &HelloContractClient) {
+    env.as_contract(&client.address, || {
+        credit_history::set_config(env, config()).unwrap();
+    });
+}
+
+#[test]
+fn test_borrow_stamps_first_borrow_time() {
+    let (env, client, _admin, asset) = setup();
+    let user = Address::generate(&env);
+    give_collateral(&env, &client, &user, 10_000);
+
+    client.borrow_asset(&user, &Some(asset), &1_000);
+    let record = client.get_credit_record(&user);
+    assert_eq!(record.first_borrow_at, 1_000);
+    assert_eq!(record.liquidations, 0);
+}
+
+#[test]
+fn test_good_standing_raises_borrow_limit() {
+    let (env, client, _admin, asset) = setup();
+    let user = Address::generate(&env);
+    give_collateral(&env, &client, &user, 10_000);
+    let baseline = client
+        .preview_borrow(&user, &Some(asset.clone()), &100)
+        .max_borrowable;
+
+    enable(&env, &client);
+    for _ in 0..3 {
+        env.as_contract(&client.address, || {
+            credit_history::record_borrow(&env, &user);
+        });
+        env.ledger().with_mut(|li| li.timestamp += 3_600);
+        env.as_contract(&client.address, || {
+            credit_history::record_repayment(&env, &user, 1_000, true);
+        });
+    }
+    assert_eq!(client.get_credit_record(&user).on_time_repays, 3);
+    // Not old enough yet
+    assert_eq!(client.get_credit_adjustment(&user), 0);
+
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    assert_eq!(client.get_credit_adjustment(&user), 500);
+    let boosted = client
+        .preview_borrow(&user, &Some(asset), &100)
+        .max_borrowable;
+    assert!(boosted > baseline);
+}
+
+#[test]
+fn test_late_repayments_do_not_count() {
+    let (env, client, _admin, _asset) = setup();
+    let user = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        credit_history::record_borrow(&env, &user);
+        credit_history::record_repayment(&env, &user, 1_000, false);
+        credit_history::record_repayment(&env, &user, 1_000, true);
+    });
+    assert_eq!(client.get_credit_record(&user).on_time_repays, 1);
+}
+
+#[test]
+fn test_quick_small_or_repeated_repayments_do_not_count() {
+    let (env, client, _admin, _asset) = setup();
+    let user = Address::generate(&env);
+    enable(&env, &client);
+
+    // Nothing borrowed yet
+    env.as_contract(&client.address, || {
+        credit_history::record_repayment(&env, &user, 1_000, true);
+        credit_history::record_borrow(&env, &user);
+        // Repaid before the minimum loan duration
+        credit_history::record_repayment(&env, &user, 1_000, true);
+    });
+    assert_eq!(client.get_credit_record(&user).on_time_repays, 0);
+
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    env.as_contract(&client.address, || {
+        // Dust-sized
+        credit_history::record_repayment(&env, &user, 99, true);
+        credit_history::record_repayment(&env, &user, 100, true);
+        // Same borrow again
+        credit_history::record_repayment(&env, &user, 1_000, true);
+    });
+    assert_eq!(client.get_credit_record(&user).on_time_repays, 1);
+}
+
+#[test]
+fn test_good_standing_raises_withdraw_limit() {
+    let (env, client, _admin, asset) = setup();
+    let user = Address::generate(&env);
+    give_collateral(&env, &client, &user, 10_000);
+    env.as_contract(&client.address, || {
+        let mut position: Position = env
+            .storage()
+            .persistent()
+            .get(&DepositDataKey::Position(user.clone()))
+            .unwrap();
+        position.debt = 5_000;
+        env.storage()
+            .persistent()
+            .set(&DepositDataKey::Position(user.clone()), &position);
+    });
+    // 6_700 × 80% / 5_000 is below the 110% minimum, 6_700 × 84% is not
+    let withdraw = |env: &Env| {
+        env.as_contract(&client.address, || {
+            crate::withdraw::validate_collateral_ratio_after_withdraw(
+                &env,
+                &user,
+                3_300,
+                Some(&asset),
+            )
+            .is_ok()
+        })
+    };
+    assert!(!withdraw(&env));
+
+    enable(&env, &client);
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &credit_history::CreditHistoryDataKey::Record(user.clone()),
+            &credit_history::CreditRecord {
+                on_time_repays: 3,
+                liquidations: 0,
+                first_borrow_at: 1,
+                open_borrow_at: 0,
+            },
+        );
+    });
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    assert_eq!(client.get_credit_adjustment(&user), 500);
+    assert!(withdraw(&env));
+}
+
+#[test]
+fn test_liquidations_lower_borrow_limit() {
+    let (env, client, _admin, asset) = setup();
+    let user = Address::generate(&env);
+    give_collateral(&env, &client, &user, 10_000);
+    let baseline = client
+        .preview_borrow(&user, &Some(asset.clone()), &100)
+        .max_borrowable;
+
+    enable(&env, &client);
+    env.as_contract(&client.address, || {
+        credit_history::record_liquidation(&env, &user);
+    });
+    assert_eq!(client.get_credit_adjustment(&user), -1_000);
+    let reduced = client
+        .preview_borrow(&user, &Some(asset), &100)
+        .max_borrowable;
+    assert!(reduced < baseline);
+
+    // Penalty is capped
+    env.as_contract(&client.address, || {
+        for _ in 0..5 {
+            credit_history::record_liquidation(&env, &user);
+        }
+    });
+    assert_eq!(client.get_credit_adjustment(&user), -2_500);
+}
+
+#[test]
+fn test_disabled_config_has_no_effect() {
+    let (env, client, _admin, _asset) = setup();
+    let user = Address::generate(&env);
+    let mut disabled = config();
+    disabled.enabled = false;
+    env.as_contract(&client.address, || {
+        credit_history::set_config(&env, disabled).unwrap();
+        credit_history::record_liquidation(&env, &user);
+    });
+    assert_eq!(client.get_credit_adjustment(&user), 0);
+}
+
+#[test]
+fn test_config_validation() {
+    let (env, client, _admin, _asset) = setup();
+    let mut bad = config();
+    bad.bonus_bps = 501;
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            credit_history::set_config(&env, bad),
+            Err(CreditHistoryError::InvalidParameter)
+        );
+    });
+}
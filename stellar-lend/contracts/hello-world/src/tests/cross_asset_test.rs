@@ -9,8 +9,9 @@
 //! - Supply and borrow caps
 //! - Edge cases (zero amounts, overflow, re-initialization)
 //! - Read-only queries
+//! - Per-user active asset tracking
 
-use crate::cross_asset::{AssetConfig, CrossAssetError};
+use crate::cross_asset::{AssetConfig, AssetKey, CrossAssetError};
 use crate::{HelloContract, HelloContractClient};
 use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env};
 
@@ -1153,3 +1154,76 @@ fn test_config_update_preserves_price() {
     assert_eq!(fetched.price, 50_000_000); // Price preserved
     assert_eq!(fetched.collateral_factor, 5000);
 }
+
+// ============================================================================
+// 14. Active Assets
+// ============================================================================
+
+#[test]
+fn test_active_assets_track_open_positions() {
+    let (env, client, _admin) = setup();
+    let token_addr = Address::generate(&env);
+    client.initialize_asset(&None, &default_config(&env));
+    client.initialize_asset(&Some(token_addr.clone()), &token_config(&env, &token_addr));
+
+    let user = Address::generate(&env);
+    assert_eq!(client.get_user_active_assets(&user).len(), 0);
+
+    client.cross_asset_deposit(&user, &None, &10000_0000000);
+    client.cross_asset_borrow(&user, &Some(token_addr.clone()), &100_0000000);
+    client.cross_asset_deposit(&user, &None, &1000_0000000);
+
+    let active = client.get_user_active_assets(&user);
+    assert_eq!(active.len(), 2);
+    assert_eq!(active.get(0).unwrap(), AssetKey::Native);
+    assert_eq!(active.get(1).unwrap(), AssetKey::Token(token_addr.clone()));
+
+    // Fully repaying closes the token position
+    client.cross_asset_repay(&user, &Some(token_addr.clone()), &100_0000000);
+    let active = client.get_user_active_assets(&user);
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap(), AssetKey::Native);
+
+    client.cross_asset_withdraw(&user, &None, &11000_0000000);
+    assert_eq!(client.get_user_active_assets(&user).len(), 0);
+}
+
+#[test]
+fn test_failed_borrow_does_not_activate_asset() {
+    let (env, client, _admin) = setup();
+    let token_addr = Address::generate(&env);
+    client.initialize_asset(&None, &default_config(&env));
+    client.initialize_asset(&Some(token_addr.clone()), &token_config(&env, &token_addr));
+
+    let user = Address::generate(&env);
+    client.cross_asset_deposit(&user, &None, &1000_0000000);
+
+    let result = client.try_cross_asset_borrow(&user, &Some(token_addr), &1000_0000000);
+    assert_eq!(result, Err(Ok(CrossAssetError::ExceedsBorrowCapacity)));
+
+    let active = client.get_user_active_assets(&user);
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap(), AssetKey::Native);
+}
+
+#[test]
+fn test_summary_ignores_assets_user_does_not_hold() {
+    let (env, client, _admin) = setup();
+    let other_addr = Address::generate(&env);
+    client.initialize_asset(&None, &default_config(&env));
+    client.initialize_asset(&Some(other_addr.clone()), &token_config(&env, &other_addr));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = li.timestamp + 3601;
+    });
+    // Only the asset the user holds gets a fresh price
+    client.update_asset_price(&None, &10_000_000);
+
+    let user = Address::generate(&env);
+    client.cross_asset_deposit(&user, &None, &10000_0000000);
+    client.cross_asset_borrow(&user, &None, &1000_0000000);
+
+    let summary = client.get_user_position_summary(&user);
+    assert_eq!(summary.total_collateral_value, 10000_0000000);
+    assert_eq!(summary.total_debt_value, 1000_0000000);
+}
@@ -0,0 +1,139 @@
+//! # Dashboard Analytics Tests
+//!
+//! Covers the read-only protocol analytics views: raw protocol totals,
+//! per-asset utilization and market data, the borrower count and the health
+//! distribution.
+
+#![cfg(test)]
+
+use crate::analytics::{AssetUtilization, HealthDistribution};
+use crate::deposit::{DepositDataKey, ProtocolAnalytics};
+use crate::testutils::setup_pool;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::Address;
+
+#[test]
+fn test_protocol_totals() {
+    let (env, client, _admin) = setup_pool();
+    assert_eq!(
+        client.get_protocol_totals(),
+        ProtocolAnalytics {
+            total_deposits: 0,
+            total_borrows: 0,
+            total_value_locked: 0,
+        }
+    );
+
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    client.borrow_asset(&user, &None, &2_000);
+
+    let totals = client.get_protocol_totals();
+    assert_eq!(totals.total_deposits, 10_000);
+    assert_eq!(totals.total_borrows, 2_000);
+}
+
+#[test]
+fn test_asset_utilization() {
+    let (env, client, _admin) = setup_pool();
+    assert_eq!(client.get_asset_utilization(&None).utilization_rate, 0);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.deposit_collateral(&alice, &None, &10_000);
+    client.deposit_collateral(&bob, &None, &10_000);
+    client.borrow_asset(&alice, &None, &3_000);
+    client.borrow_asset(&bob, &None, &2_000);
+
+    let expected = AssetUtilization {
+        asset: None,
+        total_supply: 20_000,
+        total_borrows: 5_000,
+        utilization_rate: 2_500,
+    };
+    assert_eq!(client.get_asset_utilization(&None), expected);
+
+    let all = client.get_all_asset_utilization();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all.get(0).unwrap(), expected);
+}
+
+#[test]
+fn test_market_data() {
+    let (env, client, _admin) = setup_pool();
+    env.as_contract(&client.address, || {
+        crate::origination_fee::set_origination_fee_bps(&env, 100).unwrap();
+    });
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    client.borrow_asset(&user, &None, &4_000);
+
+    let market = client.get_market_data(&None);
+    assert_eq!(market.asset, None);
+    assert_eq!(market.total_supplied, 10_000);
+    assert_eq!(market.total_borrowed, 4_000);
+    assert_eq!(market.utilization_rate, client.get_utilization());
+    assert_eq!(market.borrow_rate, client.get_asset_borrow_rate(&None));
+    assert_eq!(market.supply_rate, client.get_asset_supply_rate(&None));
+    assert!(market.borrow_rate > 0);
+    assert_eq!(market.reserves, client.get_reserves(&None));
+    assert_eq!(market.reserves, 40);
+}
+
+#[test]
+fn test_borrowers_counted_by_risk_level() {
+    let (env, client, _admin) = setup_pool();
+    let depositor = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.deposit_collateral(&depositor, &None, &10_000);
+    assert_eq!(client.get_total_borrowers(), 0);
+
+    client.deposit_collateral(&alice, &None, &10_000);
+    client.borrow_asset(&alice, &None, &1_000);
+    client.deposit_collateral(&bob, &None, &10_000);
+    client.borrow_asset(&bob, &None, &500);
+    // A second borrow keeps the borrower at the same level
+    client.borrow_asset(&bob, &None, &500);
+
+    assert_eq!(client.get_total_borrowers(), 2);
+    assert_eq!(
+        client.get_health_distribution(),
+        HealthDistribution {
+            low: 2,
+            ..HealthDistribution::default()
+        }
+    );
+}
+
+#[test]
+fn test_full_repayment_leaves_distribution() {
+    let (env, client, admin) = setup_pool();
+    let native = env.register_stellar_asset_contract(admin.clone());
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DepositDataKey::NativeAssetAddress, &native);
+    });
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &native).mint(&user, &1_600);
+    TokenClient::new(&env, &native).approve(
+        &user,
+        &client.address,
+        &1_600,
+        &(env.ledger().sequence() + 100),
+    );
+    client.deposit_collateral(&user, &None, &1_000);
+    client.borrow_asset(&user, &None, &500);
+    assert_eq!(client.get_total_borrowers(), 1);
+
+    client.repay_debt(&user, &None, &600);
+
+    assert_eq!(client.get_total_borrowers(), 0);
+    assert_eq!(
+        client.get_health_distribution(),
+        HealthDistribution::default()
+    );
+}
@@ -0,0 +1,160 @@
+//! # Stablecoin Depeg Breaker Tests
+//!
+//! Covers the deviation clock driven by primary price updates, freezing after
+//! the grace period, rejection of deposits and borrows of a frozen asset,
+//! resetting on recovery and the admin-only configuration and unfreeze.
+
+#![cfg(test)]
+
+use crate::depeg::{DepegConfig, DepegError};
+use crate::errors::ProtocolError;
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+/// 1.0 with 7 price decimals
+const PEG: i128 = 10_000_000;
+const DECIMALS: u32 = 7;
+
+/// Stablecoin with a 2% band and a one-hour grace period, priced at the peg
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, admin) = setup_pool();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let asset = Address::generate(&env);
+    client.set_depeg_config(
+        &admin,
+        &asset,
+        &Some(DepegConfig {
+            band_bps: 200,
+            grace_period: 3_600,
+        }),
+    );
+
+    client.update_price_feed(&admin, &asset, &PEG, &DECIMALS, &admin);
+    (env, client, admin, asset)
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+#[test]
+fn test_price_within_band_does_not_start_clock() {
+    let (_env, client, admin, asset) = setup();
+    client.update_price_feed(&admin, &asset, &9_850_000, &DECIMALS, &admin);
+
+    let state = client.get_depeg_state(&asset);
+    assert_eq!(state.deviating_since, None);
+    assert_eq!(state.last_deviation_bps, 150);
+    assert!(!state.frozen);
+}
+
+#[test]
+fn test_sustained_depeg_freezes_after_grace_period() {
+    let (env, client, admin, asset) = setup();
+
+    client.update_price_feed(&admin, &asset, &9_600_000, &DECIMALS, &admin);
+    assert_eq!(client.get_depeg_state(&asset).deviating_since, Some(1_000));
+
+    advance(&env, 1_800);
+    client.update_price_feed(&admin, &asset, &9_600_000, &DECIMALS, &admin);
+    assert!(!client.get_depeg_state(&asset).frozen);
+
+    advance(&env, 1_800);
+    client.update_price_feed(&admin, &asset, &9_600_000, &DECIMALS, &admin);
+    let state = client.get_depeg_state(&asset);
+    assert!(state.frozen);
+    assert_eq!(state.last_deviation_bps, 400);
+}
+
+#[test]
+fn test_frozen_asset_rejects_deposits_and_borrows() {
+    let (env, client, admin, asset) = setup();
+    client.update_price_feed(&admin, &asset, &9_600_000, &DECIMALS, &admin);
+    advance(&env, 3_600);
+    client.update_price_feed(&admin, &asset, &9_600_000, &DECIMALS, &admin);
+
+    let user = Address::generate(&env);
+    assert_eq!(
+        client.try_deposit_collateral(&user, &Some(asset.clone()), &1_000),
+        Err(Ok(ProtocolError::AssetFrozen))
+    );
+    assert_eq!(
+        client.try_borrow_asset(&user, &Some(asset.clone()), &1_000),
+        Err(Ok(ProtocolError::AssetFrozen))
+    );
+}
+
+#[test]
+fn test_recovery_resets_deviation_clock() {
+    let (env, client, admin, asset) = setup();
+
+    client.update_price_feed(&admin, &asset, &9_600_000, &DECIMALS, &admin);
+    advance(&env, 3_000);
+    client.update_price_feed(&admin, &asset, &PEG, &DECIMALS, &admin);
+    assert_eq!(client.get_depeg_state(&asset).deviating_since, None);
+
+    advance(&env, 1_000);
+    client.update_price_feed(&admin, &asset, &9_600_000, &DECIMALS, &admin);
+    advance(&env, 1_000);
+    client.update_price_feed(&admin, &asset, &9_600_000, &DECIMALS, &admin);
+    assert!(!client.get_depeg_state(&asset).frozen);
+}
+
+#[test]
+fn test_freeze_persists_until_admin_clears() {
+    let (env, client, admin, asset) = setup();
+    client.update_price_feed(&admin, &asset, &9_600_000, &DECIMALS, &admin);
+    advance(&env, 3_600);
+    client.update_price_feed(&admin, &asset, &9_600_000, &DECIMALS, &admin);
+
+    client.update_price_feed(&admin, &asset, &PEG, &DECIMALS, &admin);
+    assert!(client.get_depeg_state(&asset).frozen);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_clear_depeg_freeze(&stranger, &asset),
+        Err(Ok(DepegError::Unauthorized))
+    );
+
+    client.clear_depeg_freeze(&admin, &asset);
+    assert!(!client.get_depeg_state(&asset).frozen);
+}
+
+#[test]
+fn test_unflagged_asset_is_not_monitored() {
+    let (env, client, admin, _asset) = setup();
+    let other = Address::generate(&env);
+
+    client.update_price_feed(&admin, &other, &5_000_000, &DECIMALS, &admin);
+    advance(&env, 86_400);
+    client.update_price_feed(&admin, &other, &5_000_000, &DECIMALS, &admin);
+
+    assert_eq!(client.get_depeg_state(&other), Default::default());
+}
+
+#[test]
+fn test_set_depeg_config_validation() {
+    let (env, client, admin, asset) = setup();
+
+    assert_eq!(
+        client.try_set_depeg_config(
+            &admin,
+            &asset,
+            &Some(DepegConfig {
+                band_bps: 0,
+                grace_period: 0,
+            })
+        ),
+        Err(Ok(DepegError::InvalidParameter))
+    );
+    assert_eq!(
+        client.try_set_depeg_config(&Address::generate(&env), &asset, &None),
+        Err(Ok(DepegError::Unauthorized))
+    );
+
+    client.set_depeg_config(&admin, &asset, &None);
+    assert_eq!(client.get_depeg_config(&asset), None);
+}
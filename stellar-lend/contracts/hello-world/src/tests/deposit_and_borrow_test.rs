@@ -0,0 +1,65 @@
+//! # Deposit-and-Borrow Tests
+//!
+//! Covers opening a position in one call, the single ratio check against the
+//! combined result, reverting the deposit when the borrow fails and the
+//! same-ledger guard.
+
+#![cfg(test)]
+
+use crate::errors::ProtocolError;
+use crate::testutils::setup_pool;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+#[test]
+fn test_deposit_and_borrow_opens_position() {
+    let (env, client, _) = setup_pool();
+    let user = Address::generate(&env);
+
+    let total_debt = client.deposit_and_borrow(&user, &None, &10_000, &None, &5_000);
+    assert_eq!(total_debt, 5_000);
+
+    let position = client.get_asset_position(&user, &None);
+    assert_eq!(position.collateral, 10_000);
+    assert_eq!(position.debt, 5_000);
+}
+
+#[test]
+fn test_failed_borrow_reverts_deposit() {
+    let (env, client, _) = setup_pool();
+    let user = Address::generate(&env);
+
+    // 1_000 at 110% supports 909
+    assert_eq!(
+        client.try_deposit_and_borrow(&user, &None, &1_000, &None, &950),
+        Err(Ok(ProtocolError::MaxBorrowExceeded))
+    );
+    assert_eq!(client.get_asset_position(&user, &None).collateral, 0);
+}
+
+#[test]
+fn test_deposit_and_borrow_rejects_zero_amounts() {
+    let (env, client, _) = setup_pool();
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_deposit_and_borrow(&user, &None, &0, &None, &100),
+        Err(Ok(ProtocolError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_deposit_and_borrow(&user, &None, &1_000, &None, &0),
+        Err(Ok(ProtocolError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_same_ledger_guard_applies() {
+    let (env, client, admin) = setup_pool();
+    let user = Address::generate(&env);
+    client.set_same_ledger_guard(&admin, &true);
+
+    assert_eq!(
+        client.try_deposit_and_borrow(&user, &None, &10_000, &None, &5_000),
+        Err(Ok(ProtocolError::SameLedgerDeposit))
+    );
+}
@@ -419,8 +419,8 @@ fn test_deposit_collateral_activity_log_limit() {
 
     let user = Address::generate(&env);
 
-    // Make deposits to hit the 1000 activity log limit
-    for _ in 0..1001 {
+    // Make deposits to hit the recent activity limit
+    for _ in 0..=crate::activity::RECENT_ACTIVITY_SIZE {
         client.deposit_collateral(&user, &None, &1);
     }
 
@@ -430,7 +430,6 @@ fn test_deposit_collateral_activity_log_limit() {
             .persistent()
             .get(&DepositDataKey::ActivityLog)
             .unwrap();
-        assert!(log.len() <= 1000);
-        assert_eq!(log.len(), 1000); // Verify limit was maintained
+        assert_eq!(log.len(), crate::activity::RECENT_ACTIVITY_SIZE); // Verify limit was maintained
     });
 }
@@ -0,0 +1,55 @@
+//! # Donation Tests
+//!
+//! Covers donating tokens into reserves and the insurance fund, and that
+//! donations keep accounting reconciled.
+
+#![cfg(test)]
+
+use crate::reserve::{DonationTarget, ReserveError};
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, admin) = setup_pool();
+
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let donor = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&donor, &10_000);
+
+    (env, client, asset, donor)
+}
+
+#[test]
+fn test_donate_to_reserves() {
+    let (env, client, asset, donor) = setup();
+    let balance = client.donate(&donor, &Some(asset.clone()), &4_000, &DonationTarget::Reserves);
+    assert_eq!(balance, 4_000);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&client.address), 4_000);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&donor), 6_000);
+    assert!(client.reconcile(&Some(asset)).balanced);
+}
+
+#[test]
+fn test_donate_to_insurance_fund() {
+    let (_env, client, asset, donor) = setup();
+    client.donate(&donor, &Some(asset.clone()), &2_500, &DonationTarget::InsuranceFund);
+    assert_eq!(client.get_insurance_fund_balance(&Some(asset.clone())), 2_500);
+    assert!(client.reconcile(&Some(asset)).balanced);
+}
+
+#[test]
+fn test_donate_rejects_invalid_input() {
+    let (_env, client, asset, donor) = setup();
+    assert_eq!(
+        client.try_donate(&donor, &Some(asset), &0, &DonationTarget::Reserves),
+        Err(Ok(ReserveError::InvalidAmount))
+    );
+    // Native asset not configured
+    assert_eq!(
+        client.try_donate(&donor, &None, &100, &DonationTarget::Reserves),
+        Err(Ok(ReserveError::InvalidAsset))
+    );
+}
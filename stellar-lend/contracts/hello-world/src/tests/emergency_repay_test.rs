@@ -0,0 +1,126 @@
+//! # Emergency Repay Tests
+//!
+//! Covers admin repayment of a borrower's debt from reserves, the resulting
+//! receivable and paying it back, and governance debt forgiveness.
+
+#![cfg(test)]
+
+use crate::deposit::{DepositDataKey, Position};
+use crate::reserve::{ReserveDataKey, ReserveError};
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, admin) = setup_pool();
+
+    let borrower = Address::generate(&env);
+    client.deposit_collateral(&borrower, &None, &100_000);
+    client.borrow_asset(&borrower, &None, &10_000);
+
+    (env, client, admin, borrower)
+}
+
+fn fund_reserve(env: &Env, client: &HelloContractClient<'static>, amount: i128) {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&ReserveDataKey::ReserveBalance(None), &amount);
+    });
+}
+
+fn position(env: &Env, client: &HelloContractClient<'static>, user: &Address) -> Position {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get::<DepositDataKey, Position>(&DepositDataKey::Position(user.clone()))
+            .unwrap()
+    })
+}
+
+#[test]
+fn test_emergency_repay_records_receivable() {
+    let (env, client, admin, borrower) = setup();
+    fund_reserve(&env, &client, 50_000);
+    let debt_before = position(&env, &client, &borrower).debt;
+
+    let repaid = client.emergency_repay(&admin, &borrower, &None, &4_000);
+    assert_eq!(repaid, 4_000);
+    assert_eq!(position(&env, &client, &borrower).debt, debt_before - 4_000);
+    assert_eq!(client.get_receivable(&borrower, &None), 4_000);
+    env.as_contract(&client.address, || {
+        assert_eq!(crate::reserve::get_reserve_balance(&env, None), 46_000);
+    });
+}
+
+#[test]
+fn test_emergency_repay_capped_at_debt() {
+    let (env, client, admin, borrower) = setup();
+    fund_reserve(&env, &client, 50_000);
+    let debt_before = position(&env, &client, &borrower).debt;
+
+    let repaid = client.emergency_repay(&admin, &borrower, &None, &1_000_000);
+    assert_eq!(repaid, debt_before);
+    assert_eq!(position(&env, &client, &borrower).debt, 0);
+
+    assert_eq!(
+        client.try_emergency_repay(&admin, &borrower, &None, &1),
+        Err(Ok(ReserveError::NoDebt))
+    );
+}
+
+#[test]
+fn test_emergency_repay_bounded_by_reserves() {
+    let (env, client, admin, borrower) = setup();
+    fund_reserve(&env, &client, 1_000);
+    assert_eq!(
+        client.try_emergency_repay(&admin, &borrower, &None, &5_000),
+        Err(Ok(ReserveError::InsufficientReserve))
+    );
+}
+
+#[test]
+fn test_emergency_repay_admin_only() {
+    let (env, client, _admin, borrower) = setup();
+    fund_reserve(&env, &client, 50_000);
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_emergency_repay(&stranger, &borrower, &None, &1_000),
+        Err(Ok(ReserveError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_settle_receivable_restores_reserves() {
+    let (env, client, admin, borrower) = setup();
+    fund_reserve(&env, &client, 50_000);
+    client.emergency_repay(&admin, &borrower, &None, &4_000);
+
+    assert_eq!(client.settle_receivable(&borrower, &None, &1_500), 2_500);
+    assert_eq!(client.settle_receivable(&borrower, &None, &10_000), 0);
+    assert_eq!(client.get_receivable(&borrower, &None), 0);
+    env.as_contract(&client.address, || {
+        assert_eq!(crate::reserve::get_reserve_balance(&env, None), 50_000);
+    });
+
+    assert_eq!(
+        client.try_settle_receivable(&borrower, &None, &1),
+        Err(Ok(ReserveError::NoReceivable))
+    );
+}
+
+#[test]
+fn test_forgive_debt_leaves_no_receivable() {
+    let (env, client, _admin, borrower) = setup();
+    fund_reserve(&env, &client, 50_000);
+    let debt_before = position(&env, &client, &borrower).debt;
+
+    env.as_contract(&client.address, || {
+        let forgiven = crate::reserve::forgive_debt(&env, borrower.clone(), None, 4_000).unwrap();
+        assert_eq!(forgiven, 4_000);
+        assert_eq!(crate::reserve::get_reserve_balance(&env, None), 46_000);
+    });
+    assert_eq!(position(&env, &client, &borrower).debt, debt_before - 4_000);
+    assert_eq!(client.get_receivable(&borrower, &None), 0);
+}
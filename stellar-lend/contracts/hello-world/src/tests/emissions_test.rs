@@ -0,0 +1,91 @@
+//! # Emission Schedule Tests
+//!
+//! Covers epoch derivation from the ledger timestamp, geometric budget decay,
+//! and admin-only schedule configuration.
+
+#![cfg(test)]
+
+use crate::rewards::{EmissionSchedule, RewardsError};
+use crate::{HelloContract, HelloContractClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env};
+
+const WEEK: u64 = 7 * 24 * 60 * 60;
+
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(HelloContract, ());
+    let client = HelloContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+    (env, client, admin)
+}
+
+fn schedule(start_time: u64) -> EmissionSchedule {
+    EmissionSchedule {
+        start_time,
+        epoch_duration: WEEK,
+        initial_budget: 1_000_000,
+        decay_bps: 1_000, // 10% per epoch
+        total_epochs: 52,
+    }
+}
+
+#[test]
+fn test_epoch_budget_decays_geometrically() {
+    let (env, client, admin) = setup();
+    let asset = Some(Address::generate(&env));
+    client.set_emission_schedule(&admin, &asset, &schedule(1_000));
+
+    assert_eq!(client.get_epoch_budget(&asset, &0), 1_000_000);
+    assert_eq!(client.get_epoch_budget(&asset, &1), 900_000);
+    assert_eq!(client.get_epoch_budget(&asset, &2), 810_000);
+    assert_eq!(client.get_epoch_budget(&asset, &52), 0);
+}
+
+#[test]
+fn test_epoch_advances_with_timestamp() {
+    let (env, client, admin) = setup();
+    let asset = Some(Address::generate(&env));
+    client.set_emission_schedule(&admin, &asset, &schedule(1_000));
+
+    env.ledger().set_timestamp(500);
+    assert_eq!(client.get_current_epoch(&asset), None);
+    assert_eq!(client.get_emission_rate(&asset), 0);
+
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(client.get_current_epoch(&asset), Some(0));
+    assert_eq!(client.get_emission_rate(&asset), 1_000_000 / WEEK as i128);
+
+    env.ledger().set_timestamp(1_000 + 2 * WEEK + 5);
+    assert_eq!(client.get_current_epoch(&asset), Some(2));
+    assert_eq!(client.get_emission_rate(&asset), 810_000 / WEEK as i128);
+
+    env.ledger().set_timestamp(1_000 + 52 * WEEK);
+    assert_eq!(client.get_current_epoch(&asset), None);
+}
+
+#[test]
+fn test_set_emission_schedule_requires_admin() {
+    let (env, client, _admin) = setup();
+    let stranger = Address::generate(&env);
+    let result = client.try_set_emission_schedule(&stranger, &None, &schedule(0));
+    assert_eq!(result, Err(Ok(RewardsError::Unauthorized)));
+}
+
+#[test]
+fn test_set_emission_schedule_rejects_invalid_decay() {
+    let (_env, client, admin) = setup();
+    let mut s = schedule(0);
+    s.decay_bps = 10_000;
+    let result = client.try_set_emission_schedule(&admin, &None, &s);
+    assert_eq!(result, Err(Ok(RewardsError::InvalidParameter)));
+}
+
+#[test]
+fn test_missing_schedule_errors() {
+    let (_env, client, _admin) = setup();
+    let result = client.try_get_epoch_budget(&None, &0);
+    assert_eq!(result, Err(Ok(RewardsError::ScheduleNotFound)));
+}
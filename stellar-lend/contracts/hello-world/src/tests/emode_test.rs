@@ -0,0 +1,138 @@
+//! # E-Mode Tests
+//!
+//! Covers borrowing at a category's elevated ratios when collateral and debt
+//! share a category, falling back to the global ratios for other assets,
+//! refusing to leave e-mode when the position depends on it, and category
+//! validation.
+
+#![cfg(test)]
+
+use crate::emode::{self, EModeCategory, EModeError};
+use crate::errors::ProtocolError;
+use crate::testutils::{setup_pool, supply};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+const STABLES: u32 = 1;
+
+struct Setup {
+    env: Env,
+    client: HelloContractClient<'static>,
+    user: Address,
+    usdc: Address,
+    usdt: Address,
+}
+
+/// 102% minimum ratio and 101% liquidation threshold
+fn stables() -> EModeCategory {
+    EModeCategory {
+        min_collateral_ratio: 10_200,
+        liquidation_threshold: 10_100,
+    }
+}
+
+/// User with 1_000 USDC deposited and 1_000 USDT of pool liquidity; USDC and
+/// USDT form the stablecoin category
+fn setup() -> Setup {
+    let (env, client, admin) = setup_pool();
+
+    let usdc = env.register_stellar_asset_contract(admin.clone());
+    let usdt = env.register_stellar_asset_contract(admin.clone());
+    env.as_contract(&client.address, || {
+        emode::set_category(&env, STABLES, stables()).unwrap();
+        emode::set_asset_category(&env, Some(usdc.clone()), Some(STABLES)).unwrap();
+        emode::set_asset_category(&env, Some(usdt.clone()), Some(STABLES)).unwrap();
+    });
+
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&user, &1_000);
+    TokenClient::new(&env, &usdc).approve(&user, &client.address, &1_000, &1_000);
+    client.deposit_collateral(&user, &Some(usdc.clone()), &1_000);
+    supply(&env, &client, &Address::generate(&env), &usdt, 1_000);
+
+    Setup {
+        env,
+        client,
+        user,
+        usdc,
+        usdt,
+    }
+}
+
+#[test]
+fn test_emode_raises_borrowing_power() {
+    let s = setup();
+
+    // 1_000 at the global 110% supports 909
+    assert_eq!(
+        s.client
+            .try_borrow_asset(&s.user, &Some(s.usdt.clone()), &950),
+        Err(Ok(ProtocolError::MaxBorrowExceeded))
+    );
+
+    // 1_000 at 102% supports 980
+    s.client.set_user_emode(&s.user, &Some(STABLES));
+    assert_eq!(s.client.get_user_emode(&s.user), Some(STABLES));
+    s.client.borrow_asset(&s.user, &Some(s.usdt.clone()), &950);
+    assert_eq!(
+        s.client
+            .try_borrow_asset(&s.user, &Some(s.usdt.clone()), &40),
+        Err(Ok(ProtocolError::MaxBorrowExceeded))
+    );
+}
+
+#[test]
+fn test_asset_outside_category_uses_global_ratios() {
+    let s = setup();
+    s.client.set_user_emode(&s.user, &Some(STABLES));
+
+    let other = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_borrow_asset(&s.user, &Some(other), &950),
+        Err(Ok(ProtocolError::MaxBorrowExceeded))
+    );
+}
+
+#[test]
+fn test_leaving_emode_checks_global_ratio() {
+    let s = setup();
+    s.client.set_user_emode(&s.user, &Some(STABLES));
+    s.client.borrow_asset(&s.user, &Some(s.usdt.clone()), &950);
+
+    assert_eq!(
+        s.client.try_set_user_emode(&s.user, &None),
+        Err(Ok(EModeError::InsufficientCollateralRatio))
+    );
+    assert_eq!(s.client.get_user_emode(&s.user), Some(STABLES));
+}
+
+#[test]
+fn test_category_validation() {
+    let s = setup();
+    assert_eq!(
+        s.client.try_set_user_emode(&s.user, &Some(7)),
+        Err(Ok(EModeError::CategoryNotFound))
+    );
+    assert_eq!(s.client.get_emode_category(&STABLES), Some(stables()));
+    assert_eq!(
+        s.client.get_asset_emode_category(&Some(s.usdc.clone())),
+        Some(STABLES)
+    );
+
+    s.env.as_contract(&s.client.address, || {
+        let below_par = EModeCategory {
+            min_collateral_ratio: 10_200,
+            liquidation_threshold: 9_900,
+        };
+        assert_eq!(
+            emode::set_category(&s.env, 2, below_par),
+            Err(EModeError::InvalidParameter)
+        );
+        assert_eq!(
+            emode::set_asset_category(&s.env, None, Some(2)),
+            Err(EModeError::CategoryNotFound)
+        );
+    });
+}
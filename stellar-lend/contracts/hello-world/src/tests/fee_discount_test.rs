@@ -0,0 +1,166 @@
+//! # Staker Fee Discount Tests
+//!
+//! Covers the governance staker discount on borrow fees and the protocol
+//! spread, as reported by `preview_borrow`, and that only stake held over
+//! the whole interval qualifies.
+
+#![cfg(test)]
+
+use crate::deposit::{AssetParams, DepositDataKey, Position};
+use crate::fee_discount::FeeDiscountError;
+use crate::testutils::{setup_governance, setup_pool, supply};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address, Address) {
+    let (env, client, admin) = setup_pool();
+    let gov_token = setup_governance(&env, &client, &admin);
+
+    // Borrowable liquidity in the pool
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    supply(&env, &client, &Address::generate(&env), &asset, 1_000_000);
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DepositDataKey::AssetParams(asset.clone()),
+            &AssetParams {
+                deposit_enabled: true,
+                collateral_factor: 10000,
+                max_deposit: 0,
+                borrow_fee_bps: 200,
+            },
+        );
+    });
+
+    (env, client, admin, gov_token, asset)
+}
+
+/// Stake `amount` vote tokens for `user`, then close the ledger
+fn stake(
+    env: &Env,
+    client: &HelloContractClient,
+    gov_token: &Address,
+    user: &Address,
+    amount: i128,
+) {
+    StellarAssetClient::new(env, gov_token).mint(user, &amount);
+    client.gov_stake(user, &amount);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
+}
+
+fn give_collateral(env: &Env, client: &HelloContractClient, user: &Address, amount: i128) {
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DepositDataKey::Position(user.clone()),
+            &Position {
+                collateral: amount,
+                debt: 0,
+                borrow_interest: 0,
+                last_accrual_time: env.ledger().timestamp(),
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&DepositDataKey::CollateralBalance(user.clone()), &amount);
+    });
+}
+
+#[test]
+fn test_preview_borrow_without_stake_has_no_discount() {
+    let (env, client, admin, _gov_token, asset) = setup();
+    client.set_fee_discount_config(&admin, &1_000, &5_000);
+    let user = Address::generate(&env);
+    give_collateral(&env, &client, &user, 10_000);
+
+    let preview = client.preview_borrow(&user, &Some(asset), &1_000);
+    assert_eq!(preview.fee_discount_bps, 0);
+    assert_eq!(preview.fee_amount, 20);
+    assert_eq!(preview.receive_amount, 980);
+}
+
+#[test]
+fn test_preview_borrow_applies_staker_discount() {
+    let (env, client, admin, gov_token, asset) = setup();
+    client.set_fee_discount_config(&admin, &1_000, &5_000);
+    let user = Address::generate(&env);
+    stake(&env, &client, &gov_token, &user, 1_000);
+    give_collateral(&env, &client, &user, 10_000);
+
+    let preview = client.preview_borrow(&user, &Some(asset), &1_000);
+    assert_eq!(preview.fee_discount_bps, 5_000);
+    assert_eq!(preview.fee_amount, 10);
+    assert_eq!(preview.receive_amount, 990);
+    assert!(preview.borrow_rate_bps <= client.get_borrow_rate());
+}
+
+#[test]
+fn test_borrow_charges_discounted_fee() {
+    let (env, client, admin, gov_token, asset) = setup();
+    client.set_fee_discount_config(&admin, &1_000, &5_000);
+    let user = Address::generate(&env);
+    stake(&env, &client, &gov_token, &user, 1_000);
+    give_collateral(&env, &client, &user, 10_000);
+
+    client.borrow_asset(&user, &Some(asset.clone()), &1_000);
+    assert_eq!(client.get_reserve_balance(&Some(asset)), 10);
+}
+
+#[test]
+fn test_unstaked_or_fresh_tokens_do_not_qualify() {
+    let (env, client, admin, gov_token, _asset) = setup();
+    client.set_fee_discount_config(&admin, &1_000, &5_000);
+    let user = Address::generate(&env);
+
+    // Wallet balance alone earns nothing
+    StellarAssetClient::new(&env, &gov_token).mint(&user, &1_000);
+    assert_eq!(client.get_fee_discount_bps(&user), 0);
+
+    // Stake only counts once it has been held past the ledger it was added in
+    client.gov_stake(&user, &1_000);
+    assert_eq!(client.get_fee_discount_bps(&user), 0);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
+    assert_eq!(client.get_fee_discount_bps(&user), 5_000);
+}
+
+#[test]
+fn test_spread_discount_requires_stake_over_accrual_interval() {
+    let (env, client, admin, gov_token, _asset) = setup();
+    client.set_fee_discount_config(&admin, &1_000, &5_000);
+    let user = Address::generate(&env);
+    let start = env.ledger().timestamp();
+    stake(&env, &client, &gov_token, &user, 1_000);
+
+    // Briefly unstaking mid-interval forfeits the discount for that interval
+    env.ledger().set_timestamp(start + 100);
+    client.gov_unstake(&user, &600);
+    env.ledger().set_timestamp(start + 101);
+    client.gov_stake(&user, &600);
+    env.ledger().set_timestamp(start + 200);
+
+    assert_eq!(client.get_fee_discount_bps(&user), 5_000);
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            crate::fee_discount::get_fee_discount_bps_since(&env, &user, start + 1),
+            0
+        );
+        assert_eq!(
+            crate::fee_discount::get_fee_discount_bps_since(&env, &user, start + 101),
+            5_000
+        );
+    });
+}
+
+#[test]
+fn test_set_fee_discount_config_validation() {
+    let (env, client, admin, _gov_token, _asset) = setup();
+    assert_eq!(
+        client.try_set_fee_discount_config(&admin, &1_000, &6_000),
+        Err(Ok(FeeDiscountError::InvalidParameter))
+    );
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_fee_discount_config(&stranger, &1_000, &1_000),
+        Err(Ok(FeeDiscountError::Unauthorized))
+    );
+}
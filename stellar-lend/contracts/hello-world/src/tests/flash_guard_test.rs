@@ -0,0 +1,146 @@
+//! # Flash-Capital Guard Tests
+//!
+//! Covers the same-ledger guard that blocks borrowing or withdrawing in the
+//! ledger a user deposited in, and the per-asset minimum holding period
+//! before new collateral counts toward borrowing power.
+
+#![cfg(test)]
+
+use crate::errors::ProtocolError;
+use crate::flash_guard::{self, FlashGuardError};
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+#[test]
+fn test_guard_disabled_by_default() {
+    let (env, client, _admin) = setup_pool();
+    assert!(!client.is_same_ledger_guard_enabled());
+
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    client.borrow_asset(&user, &None, &1_000);
+}
+
+#[test]
+fn test_same_ledger_borrow_rejected() {
+    let (env, client, admin) = setup_pool();
+    client.set_same_ledger_guard(&admin, &true);
+
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    assert_eq!(
+        client.try_borrow_asset(&user, &None, &1_000),
+        Err(Ok(ProtocolError::SameLedgerDeposit))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    client.borrow_asset(&user, &None, &1_000);
+}
+
+#[test]
+fn test_same_ledger_withdraw_rejected() {
+    let (env, client, admin) = setup_pool();
+    client.set_same_ledger_guard(&admin, &true);
+
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    assert_eq!(
+        client.try_withdraw_collateral(&user, &None, &1_000),
+        Err(Ok(ProtocolError::SameLedgerDeposit))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    client.withdraw_collateral(&user, &None, &1_000);
+}
+
+#[test]
+fn test_guard_only_affects_depositor() {
+    let (env, client, admin) = setup_pool();
+    client.set_same_ledger_guard(&admin, &true);
+
+    let depositor = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.deposit_collateral(&other, &None, &10_000);
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+
+    client.deposit_collateral(&depositor, &None, &10_000);
+    client.borrow_asset(&other, &None, &1_000);
+}
+
+#[test]
+fn test_set_guard_requires_admin() {
+    let (env, client, _admin) = setup_pool();
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_same_ledger_guard(&stranger, &true),
+        Err(Ok(FlashGuardError::Unauthorized))
+    );
+}
+
+fn set_holding_period(env: &Env, client: &HelloContractClient, seconds: u64) {
+    env.as_contract(&client.address, || {
+        flash_guard::set_min_holding_period(env, None, seconds).unwrap();
+    });
+}
+
+#[test]
+fn test_maturing_collateral_excluded_from_borrowing_power() {
+    let (env, client, _admin) = setup_pool();
+    set_holding_period(&env, &client, 3_600);
+    assert_eq!(client.get_min_holding_period(&None), 3_600);
+
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    assert_eq!(client.get_maturing_collateral(&user), 10_000);
+    assert_eq!(
+        client.try_borrow_asset(&user, &None, &1_000),
+        Err(Ok(ProtocolError::MaxBorrowExceeded))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    assert_eq!(client.get_maturing_collateral(&user), 0);
+    client.borrow_asset(&user, &None, &1_000);
+}
+
+#[test]
+fn test_new_deposit_extends_maturity() {
+    let (env, client, _admin) = setup_pool();
+    set_holding_period(&env, &client, 3_600);
+
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    env.ledger().with_mut(|li| li.timestamp += 1_800);
+    client.deposit_collateral(&user, &None, &5_000);
+
+    env.ledger().with_mut(|li| li.timestamp += 1_800);
+    assert_eq!(client.get_maturing_collateral(&user), 15_000);
+    env.ledger().with_mut(|li| li.timestamp += 1_800);
+    assert_eq!(client.get_maturing_collateral(&user), 0);
+}
+
+#[test]
+fn test_maturing_collateral_can_be_withdrawn() {
+    let (env, client, _admin) = setup_pool();
+    set_holding_period(&env, &client, 3_600);
+
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    client.withdraw_collateral(&user, &None, &10_000);
+}
+
+#[test]
+fn test_holding_period_is_capped() {
+    let (env, client, _admin) = setup_pool();
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            flash_guard::set_min_holding_period(
+                &env,
+                None,
+                flash_guard::MAX_HOLDING_PERIOD_SECONDS + 1
+            ),
+            Err(FlashGuardError::InvalidParameter)
+        );
+    });
+}
@@ -0,0 +1,149 @@
+//! # Gauge Voting Tests
+//!
+//! Covers weekly gauge votes weighted by vote tokens staked before the
+//! period, period finalization, and emission rates derived from finalized
+//! weights.
+
+#![cfg(test)]
+
+use crate::rewards::{EmissionSchedule, RewardsError, GAUGE_PERIOD};
+use crate::testutils::{setup_governance, setup_pool};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, admin) = setup_pool();
+    let token = setup_governance(&env, &client, &admin);
+    (env, client, admin, token)
+}
+
+/// Voter who stakes `amount` vote tokens at the current ledger
+fn voter_with(env: &Env, client: &HelloContractClient, token: &Address, amount: i128) -> Address {
+    let voter = Address::generate(env);
+    StellarAssetClient::new(env, token).mint(&voter, &amount);
+    client.gov_stake(&voter, &amount);
+    voter
+}
+
+#[test]
+fn test_gauge_votes_finalize_into_weights() {
+    let (env, client, admin, token) = setup();
+    let usdc = Some(Address::generate(&env));
+    let eth = Some(Address::generate(&env));
+    client.add_gauge(&admin, &usdc);
+    client.add_gauge(&admin, &eth);
+
+    let alice = voter_with(&env, &client, &token, 3_000);
+    let bob = voter_with(&env, &client, &token, 1_000);
+    env.ledger().set_timestamp(GAUGE_PERIOD * 10 + 1);
+    client.vote_gauge_weight(&alice, &usdc, &10_000);
+    client.vote_gauge_weight(&bob, &eth, &10_000);
+
+    // Cannot finalize while the period is still running
+    assert_eq!(
+        client.try_finalize_gauge_weights(&10),
+        Err(Ok(RewardsError::PeriodNotEnded))
+    );
+
+    env.ledger().set_timestamp(GAUGE_PERIOD * 11);
+    client.finalize_gauge_weights(&10);
+    assert_eq!(client.get_gauge_weight(&usdc), 7_500);
+    assert_eq!(client.get_gauge_weight(&eth), 2_500);
+
+    assert_eq!(
+        client.try_finalize_gauge_weights(&10),
+        Err(Ok(RewardsError::AlreadyFinalized))
+    );
+}
+
+#[test]
+fn test_gauge_allocation_cannot_exceed_full_power() {
+    let (env, client, admin, token) = setup();
+    let usdc = Some(Address::generate(&env));
+    let eth = Some(Address::generate(&env));
+    client.add_gauge(&admin, &usdc);
+    client.add_gauge(&admin, &eth);
+
+    let alice = voter_with(&env, &client, &token, 1_000);
+    env.ledger().set_timestamp(GAUGE_PERIOD);
+    client.vote_gauge_weight(&alice, &usdc, &6_000);
+    assert_eq!(
+        client.try_vote_gauge_weight(&alice, &eth, &5_000),
+        Err(Ok(RewardsError::VoteAllocationExceeded))
+    );
+    client.vote_gauge_weight(&alice, &eth, &4_000);
+}
+
+#[test]
+fn test_vote_on_unregistered_gauge_fails() {
+    let (env, client, _admin, token) = setup();
+    let alice = voter_with(&env, &client, &token, 1_000);
+    env.ledger().set_timestamp(GAUGE_PERIOD);
+    let result = client.try_vote_gauge_weight(&alice, &Some(Address::generate(&env)), &5_000);
+    assert_eq!(result, Err(Ok(RewardsError::GaugeNotFound)));
+}
+
+#[test]
+fn test_vote_without_tokens_fails() {
+    let (env, client, admin, _token) = setup();
+    let usdc = Some(Address::generate(&env));
+    client.add_gauge(&admin, &usdc);
+    let nobody = Address::generate(&env);
+    let result = client.try_vote_gauge_weight(&nobody, &usdc, &5_000);
+    assert_eq!(result, Err(Ok(RewardsError::NoVotingPower)));
+}
+
+#[test]
+fn test_only_stake_from_before_the_period_counts() {
+    let (env, client, admin, token) = setup();
+    let usdc = Some(Address::generate(&env));
+    client.add_gauge(&admin, &usdc);
+
+    // Wallet balances carry no gauge voting power
+    env.ledger().set_timestamp(GAUGE_PERIOD + 1);
+    let holder = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&holder, &1_000);
+    assert_eq!(
+        client.try_vote_gauge_weight(&holder, &usdc, &5_000),
+        Err(Ok(RewardsError::NoVotingPower))
+    );
+
+    // Staking mid-period only counts from the next period on
+    client.gov_stake(&holder, &1_000);
+    assert_eq!(
+        client.try_vote_gauge_weight(&holder, &usdc, &5_000),
+        Err(Ok(RewardsError::NoVotingPower))
+    );
+    env.ledger().set_timestamp(GAUGE_PERIOD * 2);
+    assert_eq!(client.vote_gauge_weight(&holder, &usdc, &5_000), 500);
+}
+
+#[test]
+fn test_emission_rate_uses_finalized_weight() {
+    let (env, client, admin, token) = setup();
+    let usdc = Some(Address::generate(&env));
+    client.add_gauge(&admin, &usdc);
+    client.set_gauge_pool_schedule(
+        &admin,
+        &EmissionSchedule {
+            start_time: 0,
+            epoch_duration: GAUGE_PERIOD,
+            initial_budget: GAUGE_PERIOD as i128 * 100,
+            decay_bps: 0,
+            total_epochs: 52,
+        },
+    );
+
+    // No finalized weight yet: gauge receives nothing from the pool
+    assert_eq!(client.get_emission_rate(&usdc), 0);
+
+    let alice = voter_with(&env, &client, &token, 1_000);
+    env.ledger().set_timestamp(GAUGE_PERIOD);
+    client.vote_gauge_weight(&alice, &usdc, &10_000);
+    env.ledger().set_timestamp(GAUGE_PERIOD * 2);
+    client.finalize_gauge_weights(&1);
+
+    assert_eq!(client.get_emission_rate(&usdc), 100);
+}
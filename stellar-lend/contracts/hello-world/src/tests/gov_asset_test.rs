@@ -1,11 +1,12 @@
 #![cfg(test)]
 
 use crate::cross_asset::AssetConfig;
+use crate::risk_management::PauseOperation;
 use crate::types::{ProposalStatus, ProposalType, VoteType};
 use crate::{HelloContract, HelloContractClient};
 use soroban_sdk::{
     testutils::{Address as _, Ledger as _},
-    vec, Address, Env, String, Symbol, Val,
+    vec, Address, Env, String, Val,
 };
 
 fn create_test_token(env: &Env, admin: &Address) -> Address {
@@ -62,6 +63,8 @@ fn test_governance_asset_config_update() {
     sac.mint(&voter, &5000);
 
     let client = setup_protocol(&env, &admin, &vote_token);
+    client.gov_stake(&voter, &5000);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
 
     // Initialize USDC asset
     let initial_config = AssetConfig {
@@ -132,13 +135,15 @@ fn test_governance_pause_unpause() {
     sac.mint(&voter, &5000);
 
     let client = setup_protocol(&env, &admin, &vote_token);
+    client.gov_stake(&voter, &5000);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
 
     // Initially not paused
-    let op = Symbol::new(&env, "deposit");
+    let op = PauseOperation::Deposit;
     assert!(!client.is_operation_paused(&op));
 
     // Create proposal to pause deposit
-    let proposal_type = ProposalType::PauseSwitch(op.clone(), true);
+    let proposal_type = ProposalType::PauseSwitch(op, true);
     let proposal_id = client.gov_create_proposal(
         &proposer,
         &proposal_type,
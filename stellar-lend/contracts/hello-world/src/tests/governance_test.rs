@@ -92,6 +92,10 @@ fn test_voting_flow() {
     mint_tokens(&env, &token, &voter3, 200);
 
     let client = setup_governance(&env, &admin, &token);
+    client.gov_stake(&voter1, &500);
+    client.gov_stake(&voter2, &300);
+    client.gov_stake(&voter3, &200);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
 
     let proposal_id = client.gov_create_proposal(
         &proposer,
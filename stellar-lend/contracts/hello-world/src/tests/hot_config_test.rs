@@ -0,0 +1,68 @@
+//! # Hot Configuration Cache Tests
+//!
+//! Covers the instance-storage cache of pause switches, emergency pause and
+//! risk parameters read by the core flows.
+
+#![cfg(test)]
+
+use crate::errors::ProtocolError;
+use crate::hot_config::{self, HotConfigDataKey};
+use crate::risk_management::PauseOperation;
+use crate::testutils::setup_pool;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+#[test]
+fn test_cache_tracks_writes() {
+    let (env, client, admin) = setup_pool();
+    client.set_risk_params(&admin, &Some(11_500), &None, &None, &None);
+    client.set_pause_switch(&admin, &PauseOperation::Borrow, &true);
+    client.set_emergency_pause(&admin, &true);
+
+    env.as_contract(&client.address, || {
+        let config = hot_config::load(&env);
+        assert!(config.is_switch_on(&env, PauseOperation::Borrow));
+        assert!(!config.is_switch_on(&env, PauseOperation::Deposit));
+        assert!(config.emergency_paused);
+        assert_eq!(config.min_collateral_ratio(), Some(11_500));
+    });
+}
+
+#[test]
+fn test_load_falls_back_without_cache() {
+    let (env, client, admin) = setup_pool();
+    client.set_pause_switch(&admin, &PauseOperation::Deposit, &true);
+
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&HotConfigDataKey::HotConfig);
+        let config = hot_config::load(&env);
+        assert!(config.is_switch_on(&env, PauseOperation::Deposit));
+        assert_eq!(config.min_collateral_ratio(), Some(11_000));
+    });
+}
+
+#[test]
+fn test_flows_honor_cached_switches() {
+    let (env, client, admin) = setup_pool();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &100_000);
+    client.borrow_asset(&user, &None, &10_000);
+
+    client.set_pause_switch(&admin, &PauseOperation::Deposit, &true);
+    assert_eq!(
+        client.try_deposit_collateral(&user, &None, &1_000),
+        Err(Ok(ProtocolError::OperationPaused))
+    );
+
+    client.set_pause_switch(&admin, &PauseOperation::Repay, &true);
+    assert_eq!(
+        client.try_repay_debt(&user, &None, &1_000),
+        Err(Ok(ProtocolError::OperationPaused))
+    );
+
+    client.set_emergency_pause(&admin, &true);
+    assert_eq!(
+        client.try_borrow_asset(&user, &None, &1_000),
+        Err(Ok(ProtocolError::OperationPaused))
+    );
+}
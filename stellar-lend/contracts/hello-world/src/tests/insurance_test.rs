@@ -0,0 +1,182 @@
+//! # Insurance Tests
+//!
+//! Covers writing off bad debt against the insurance fund, socializing the
+//! uncovered remainder, eligibility checks and routing a share of borrow fees
+//! into the fund.
+
+#![cfg(test)]
+
+use crate::deposit::{AssetParams, DepositDataKey, Position};
+use crate::insurance::{self, BadDebtResolution, InsuranceError};
+use crate::reserve::ReserveDataKey;
+use crate::testutils::{set_price, setup_pool, supply};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+/// Borrower with 10_000 of debt whose collateral has all been seized
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, _admin) = setup_pool();
+
+    let borrower = Address::generate(&env);
+    client.deposit_collateral(&borrower, &None, &100_000);
+    client.borrow_asset(&borrower, &None, &10_000);
+    seize_collateral(&env, &client, &borrower);
+    (env, client, borrower)
+}
+
+fn seize_collateral(env: &Env, client: &HelloContractClient, borrower: &Address) {
+    env.as_contract(&client.address, || {
+        let key = DepositDataKey::Position(borrower.clone());
+        let mut position: Position = env.storage().persistent().get(&key).unwrap();
+        position.collateral = 0;
+        env.storage().persistent().set(&key, &position);
+        env.storage()
+            .persistent()
+            .set(&DepositDataKey::CollateralBalance(borrower.clone()), &0i128);
+    });
+}
+
+fn fund_insurance(env: &Env, client: &HelloContractClient, amount: i128) {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&ReserveDataKey::InsuranceFund(None), &amount);
+    });
+}
+
+fn debt(env: &Env, client: &HelloContractClient, user: &Address) -> i128 {
+    env.as_contract(&client.address, || {
+        let position: Position = env
+            .storage()
+            .persistent()
+            .get(&DepositDataKey::Position(user.clone()))
+            .unwrap();
+        position.debt + position.borrow_interest
+    })
+}
+
+#[test]
+fn test_bad_debt_covered_by_insurance_fund() {
+    let (env, client, borrower) = setup();
+    fund_insurance(&env, &client, 50_000);
+
+    assert_eq!(
+        client.resolve_bad_debt(&borrower),
+        BadDebtResolution {
+            written_off: 10_000,
+            covered: 10_000,
+            shortfall: 0,
+        }
+    );
+    assert_eq!(debt(&env, &client, &borrower), 0);
+    assert_eq!(client.get_insurance_fund_balance(&None), 40_000);
+    assert_eq!(client.get_bad_debt_shortfall(&None), 0);
+    assert_eq!(client.get_asset_position(&borrower, &None).debt, 0);
+}
+
+#[test]
+fn test_uncovered_bad_debt_is_socialized() {
+    let (env, client, borrower) = setup();
+    fund_insurance(&env, &client, 4_000);
+
+    assert_eq!(
+        client.resolve_bad_debt(&borrower),
+        BadDebtResolution {
+            written_off: 10_000,
+            covered: 4_000,
+            shortfall: 6_000,
+        }
+    );
+    assert_eq!(debt(&env, &client, &borrower), 0);
+    assert_eq!(client.get_insurance_fund_balance(&None), 0);
+    assert_eq!(client.get_bad_debt_shortfall(&None), 6_000);
+
+    // Nothing left to write off
+    assert_eq!(
+        client.try_resolve_bad_debt(&borrower),
+        Err(Ok(InsuranceError::NoDebt))
+    );
+}
+
+#[test]
+fn test_bad_debt_written_off_in_each_borrowed_asset() {
+    let (env, client, admin) = setup_pool();
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    supply(&env, &client, &Address::generate(&env), &asset, 10_000);
+    set_price(&client, &admin, &asset, 200_000_000);
+    fund_insurance(&env, &client, 50_000);
+
+    let borrower = Address::generate(&env);
+    client.deposit_collateral(&borrower, &None, &100_000);
+    client.borrow_asset(&borrower, &None, &10_000);
+    client.borrow_asset(&borrower, &Some(asset.clone()), &5_000);
+    seize_collateral(&env, &client, &borrower);
+
+    // The native debt is covered; the priced asset has no fund and 5_000 of
+    // it, worth 10_000, is socialized
+    assert_eq!(
+        client.resolve_bad_debt(&borrower),
+        BadDebtResolution {
+            written_off: 20_000,
+            covered: 10_000,
+            shortfall: 10_000,
+        }
+    );
+    assert_eq!(debt(&env, &client, &borrower), 0);
+    assert_eq!(client.get_insurance_fund_balance(&None), 40_000);
+    assert_eq!(client.get_bad_debt_shortfall(&None), 0);
+    assert_eq!(client.get_bad_debt_shortfall(&Some(asset.clone())), 5_000);
+    assert_eq!(client.get_asset_position(&borrower, &None).debt, 0);
+    assert_eq!(client.get_asset_position(&borrower, &Some(asset)).debt, 0);
+}
+
+#[test]
+fn test_position_with_collateral_must_be_liquidated_first() {
+    let (env, client, _) = setup();
+    let borrower = Address::generate(&env);
+    client.deposit_collateral(&borrower, &None, &100_000);
+    client.borrow_asset(&borrower, &None, &10_000);
+
+    assert_eq!(
+        client.try_resolve_bad_debt(&borrower),
+        Err(Ok(InsuranceError::CollateralRemaining))
+    );
+    assert_eq!(
+        client.try_resolve_bad_debt(&Address::generate(&env)),
+        Err(Ok(InsuranceError::NoDebt))
+    );
+}
+
+#[test]
+fn test_borrow_fee_share_routed_to_insurance() {
+    let (env, client, _) = setup();
+    let asset = env.register_stellar_asset_contract(Address::generate(&env));
+    supply(&env, &client, &Address::generate(&env), &asset, 1_000);
+    let user = Address::generate(&env);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            insurance::set_insurance_fee_share(&env, 5_001),
+            Err(InsuranceError::InvalidFeeShare)
+        );
+        insurance::set_insurance_fee_share(&env, 2_500).unwrap();
+
+        let params = AssetParams {
+            deposit_enabled: true,
+            collateral_factor: 7000,
+            max_deposit: 0,
+            borrow_fee_bps: 200,
+        };
+        env.storage()
+            .persistent()
+            .set(&DepositDataKey::AssetParams(asset.clone()), &params);
+    });
+    assert_eq!(client.get_insurance_fee_share(), 2_500);
+
+    // 2% of 1_000: a quarter of the fee goes to insurance
+    client.deposit_collateral(&user, &None, &10_000);
+    client.borrow_asset(&user, &Some(asset.clone()), &1_000);
+    assert_eq!(client.get_insurance_fund_balance(&Some(asset.clone())), 5);
+    assert_eq!(client.get_reserve_balance(&Some(asset)), 15);
+}
@@ -908,3 +908,251 @@ fn test_sequential_config_updates() {
     assert_ne!(rate1, rate2); // multiplier change should alter rate
     assert_eq!(supply_rate, borrow_rate - 100);
 }
+
+// =============================================================================
+// PER-ASSET RATE VIEW TESTS
+// =============================================================================
+
+fn set_asset_totals(
+    env: &Env,
+    contract_id: &Address,
+    asset: &Address,
+    total_supply: i128,
+    total_borrow: i128,
+) {
+    use crate::cross_asset::AssetKey;
+    use soroban_sdk::{symbol_short, Map};
+
+    env.as_contract(contract_id, || {
+        let key = AssetKey::Token(asset.clone());
+        let mut supplies: Map<AssetKey, i128> = Map::new(env);
+        supplies.set(key.clone(), total_supply);
+        let mut borrows: Map<AssetKey, i128> = Map::new(env);
+        borrows.set(key, total_borrow);
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("supplies"), &supplies);
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("borrows"), &borrows);
+    });
+}
+
+/// An asset with no dedicated supply uses the shared pool rate that accrual uses
+#[test]
+fn test_asset_rate_falls_back_to_pool_rate() {
+    let env = create_test_env();
+    let (contract_id, _admin, client) = setup_contract_with_admin(&env);
+    set_protocol_analytics(&env, &contract_id, 10000, 4000);
+    let asset = Address::generate(&env);
+
+    assert_eq!(
+        client.get_asset_borrow_rate(&Some(asset.clone())),
+        client.get_borrow_rate()
+    );
+    assert_eq!(
+        client.get_asset_supply_rate(&Some(asset)),
+        client.get_supply_rate()
+    );
+}
+
+/// An asset with its own supply/borrow totals is priced at its own utilization
+#[test]
+fn test_asset_rate_uses_asset_utilization() {
+    let env = create_test_env();
+    let (contract_id, _admin, client) = setup_contract_with_admin(&env);
+    set_protocol_analytics(&env, &contract_id, 10000, 0);
+    let asset = Address::generate(&env);
+    // 90% utilization for this asset
+    set_asset_totals(&env, &contract_id, &asset, 10000, 9000);
+
+    // rate = 100 + 2000 + (1000/2000)*10000 = 7100
+    assert_eq!(client.get_asset_borrow_rate(&Some(asset.clone())), 7100);
+    assert_eq!(client.get_asset_supply_rate(&Some(asset)), 7100 - 200);
+    // Pool rate is unaffected
+    assert_eq!(client.get_borrow_rate(), 100);
+}
+
+/// The per-asset views report exactly the rates the asset's indexes accrue at
+#[test]
+fn test_asset_rate_views_match_accrual() {
+    use crate::interest_rate::{
+        checkpoint_borrow_index, checkpoint_exchange_rate, BORROW_INDEX_SCALE, EXCHANGE_RATE_SCALE,
+    };
+    use soroban_sdk::testutils::Ledger as _;
+
+    let env = create_test_env();
+    let (contract_id, _admin, client) = setup_contract_with_admin(&env);
+    set_protocol_analytics(&env, &contract_id, 10000, 4000);
+    let asset = Address::generate(&env);
+    set_asset_totals(&env, &contract_id, &asset, 10000, 9000);
+    let asset = Some(asset);
+
+    env.as_contract(&contract_id, || {
+        checkpoint_borrow_index(&env, asset.clone()).unwrap();
+        checkpoint_exchange_rate(&env, asset.clone()).unwrap();
+    });
+    let borrow_rate = client.get_asset_borrow_rate(&asset);
+    let supply_rate = client.get_asset_supply_rate(&asset);
+    assert_ne!(borrow_rate, client.get_borrow_rate());
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + SECONDS_PER_YEAR);
+    assert_eq!(
+        client.get_borrow_index(&asset),
+        BORROW_INDEX_SCALE + BORROW_INDEX_SCALE * borrow_rate / 10_000
+    );
+    assert_eq!(
+        client.get_exchange_rate(&asset),
+        EXCHANGE_RATE_SCALE + EXCHANGE_RATE_SCALE * supply_rate / 10_000
+    );
+}
+
+// =============================================================================
+// EXCHANGE RATE TESTS
+// =============================================================================
+
+/// Without a checkpoint the exchange rate is exactly one
+#[test]
+fn test_exchange_rate_starts_at_scale() {
+    let env = create_test_env();
+    let (_contract_id, _admin, client) = setup_contract_with_admin(&env);
+    assert_eq!(
+        client.get_exchange_rate(&None),
+        crate::interest_rate::EXCHANGE_RATE_SCALE
+    );
+}
+
+/// The exchange rate grows from its checkpoint at the current supply rate
+#[test]
+fn test_exchange_rate_grows_with_supply_rate() {
+    use crate::interest_rate::{InterestRateDataKey, SupplyIndex, EXCHANGE_RATE_SCALE};
+    use soroban_sdk::testutils::Ledger as _;
+
+    let env = create_test_env();
+    let (contract_id, _admin, client) = setup_contract_with_admin(&env);
+    // 40% utilization: borrow 1100 bps, supply 900 bps
+    set_protocol_analytics(&env, &contract_id, 10000, 4000);
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(
+            &InterestRateDataKey::SupplyIndex(None),
+            &SupplyIndex {
+                rate: EXCHANGE_RATE_SCALE,
+                last_update: 0,
+            },
+        );
+    });
+
+    env.ledger().set_timestamp(SECONDS_PER_YEAR);
+    assert_eq!(
+        client.get_exchange_rate(&None),
+        EXCHANGE_RATE_SCALE + EXCHANGE_RATE_SCALE * 900 / 10000
+    );
+}
+
+// =============================================================================
+// BORROW INDEX TESTS
+// =============================================================================
+
+/// Without a checkpoint the borrow index is exactly one
+#[test]
+fn test_borrow_index_starts_at_scale() {
+    let env = create_test_env();
+    let (_contract_id, _admin, client) = setup_contract_with_admin(&env);
+    assert_eq!(
+        client.get_borrow_index(&None),
+        crate::interest_rate::BORROW_INDEX_SCALE
+    );
+}
+
+/// `accrue` checkpoints both indexes, which then grow at the asset's rates
+#[test]
+fn test_accrue_checkpoints_asset_indexes() {
+    use crate::interest_rate::{AssetAccrual, BORROW_INDEX_SCALE, EXCHANGE_RATE_SCALE};
+    use soroban_sdk::testutils::Ledger as _;
+
+    let env = create_test_env();
+    let (contract_id, _admin, client) = setup_contract_with_admin(&env);
+    // 40% utilization: borrow 1100 bps, supply 900 bps
+    set_protocol_analytics(&env, &contract_id, 10000, 4000);
+    client.accrue(&None);
+
+    env.ledger().set_timestamp(SECONDS_PER_YEAR);
+    assert_eq!(
+        client.get_borrow_index(&None),
+        BORROW_INDEX_SCALE + BORROW_INDEX_SCALE * 1100 / 10000
+    );
+    assert_eq!(
+        client.accrue(&None),
+        AssetAccrual {
+            exchange_rate: EXCHANGE_RATE_SCALE + EXCHANGE_RATE_SCALE * 900 / 10000,
+            borrow_index: BORROW_INDEX_SCALE + BORROW_INDEX_SCALE * 1100 / 10000,
+            utilization_bps: 4000,
+            borrow_rate_bps: 1100,
+            timestamp: SECONDS_PER_YEAR,
+        }
+    );
+
+    // Checkpointed: no further growth within the same ledger
+    assert_eq!(
+        client.get_borrow_index(&None),
+        BORROW_INDEX_SCALE + BORROW_INDEX_SCALE * 1100 / 10000
+    );
+}
+
+// =============================================================================
+// POSITION INTEREST TESTS
+// =============================================================================
+
+/// Principal grows with the borrow index from the position's snapshot
+#[test]
+fn test_position_interest_follows_borrow_index() {
+    use crate::interest_rate::{
+        accrue_asset, accrue_position_interest, pending_position_interest, snapshot_position_index,
+    };
+    use soroban_sdk::testutils::Ledger as _;
+
+    let env = create_test_env();
+    let (contract_id, _admin, _client) = setup_contract_with_admin(&env);
+    // 40% utilization: borrow 1100 bps
+    set_protocol_analytics(&env, &contract_id, 10000, 4000);
+    let user = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        accrue_asset(&env, None).unwrap();
+        snapshot_position_index(&env, &user, None).unwrap();
+    });
+
+    env.ledger().set_timestamp(SECONDS_PER_YEAR);
+    env.as_contract(&contract_id, || {
+        // The position's own timestamp does not matter once it has a snapshot
+        assert_eq!(
+            pending_position_interest(&env, &user, 10_000, SECONDS_PER_YEAR / 2),
+            Ok(1_100)
+        );
+        assert_eq!(accrue_position_interest(&env, &user, 10_000, 0), Ok(1_100));
+        assert_eq!(
+            pending_position_interest(&env, &user, 10_000, SECONDS_PER_YEAR),
+            Ok(0)
+        );
+    });
+}
+
+/// Positions without a snapshot accrue at the current rate from their last accrual
+#[test]
+fn test_position_interest_without_snapshot_uses_elapsed_time() {
+    use crate::interest_rate::pending_position_interest;
+    use soroban_sdk::testutils::Ledger as _;
+
+    let env = create_test_env();
+    let (contract_id, _admin, _client) = setup_contract_with_admin(&env);
+    set_protocol_analytics(&env, &contract_id, 10000, 4000);
+    let user = Address::generate(&env);
+
+    env.ledger().set_timestamp(SECONDS_PER_YEAR);
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            pending_position_interest(&env, &user, 10_000, SECONDS_PER_YEAR / 2),
+            Ok(550)
+        );
+    });
+}
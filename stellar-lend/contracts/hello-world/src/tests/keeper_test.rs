@@ -0,0 +1,173 @@
+//! # Keeper Registry Tests
+//!
+//! Covers bonding, unbonding, governance slashing and the liquidation
+//! insurance rebate for active keepers.
+
+#![cfg(test)]
+
+use crate::keeper::{self, KeeperConfig, KeeperError};
+use crate::reserve::get_reserve_balance;
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, admin) = setup_pool();
+
+    let bond_token = env.register_stellar_asset_contract(admin.clone());
+    env.as_contract(&client.address, || {
+        keeper::set_config(
+            &env,
+            KeeperConfig {
+                bond_token: bond_token.clone(),
+                min_bond: 1_000,
+                unbonding_period: 86_400,
+                rebate_bps: 5_000,
+            },
+        )
+        .unwrap();
+    });
+
+    (env, client, admin, bond_token)
+}
+
+fn funded_keeper(env: &Env, bond_token: &Address) -> Address {
+    let keeper = Address::generate(env);
+    StellarAssetClient::new(env, bond_token).mint(&keeper, &5_000);
+    keeper
+}
+
+#[test]
+fn test_register_keeper_posts_bond() {
+    let (env, client, _admin, bond_token) = setup();
+    let keeper = funded_keeper(&env, &bond_token);
+
+    client.register_keeper(&keeper, &2_000);
+    assert!(client.is_active_keeper(&keeper));
+    assert_eq!(client.get_keeper(&keeper).unwrap().bond, 2_000);
+    let token = TokenClient::new(&env, &bond_token);
+    assert_eq!(token.balance(&keeper), 3_000);
+    assert_eq!(token.balance(&client.address), 2_000);
+
+    assert_eq!(
+        client.try_register_keeper(&keeper, &2_000),
+        Err(Ok(KeeperError::AlreadyRegistered))
+    );
+}
+
+#[test]
+fn test_register_requires_min_bond() {
+    let (env, client, _admin, bond_token) = setup();
+    let keeper = funded_keeper(&env, &bond_token);
+    assert_eq!(
+        client.try_register_keeper(&keeper, &999),
+        Err(Ok(KeeperError::BondTooLow))
+    );
+}
+
+#[test]
+fn test_unbond_and_withdraw() {
+    let (env, client, _admin, bond_token) = setup();
+    let keeper = funded_keeper(&env, &bond_token);
+    client.register_keeper(&keeper, &2_000);
+
+    assert_eq!(
+        client.try_withdraw_keeper_bond(&keeper),
+        Err(Ok(KeeperError::NotUnbonding))
+    );
+
+    let unbond_at = client.request_keeper_unbond(&keeper);
+    assert!(!client.is_active_keeper(&keeper));
+    assert_eq!(
+        client.try_withdraw_keeper_bond(&keeper),
+        Err(Ok(KeeperError::UnbondingNotReady))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = unbond_at);
+    assert_eq!(client.withdraw_keeper_bond(&keeper), 2_000);
+    assert_eq!(client.get_keeper(&keeper), None);
+    assert_eq!(TokenClient::new(&env, &bond_token).balance(&keeper), 5_000);
+}
+
+#[test]
+fn test_slash_moves_bond_to_reserves() {
+    let (env, client, _admin, bond_token) = setup();
+    let keeper = funded_keeper(&env, &bond_token);
+    client.register_keeper(&keeper, &1_500);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(keeper::slash_keeper(&env, keeper.clone(), 600), Ok(900));
+        assert_eq!(get_reserve_balance(&env, Some(bond_token.clone())), 600);
+    });
+    // Below min_bond after the slash
+    assert!(!client.is_active_keeper(&keeper));
+}
+
+#[test]
+fn test_bond_stays_in_posted_token_after_config_change() {
+    let (env, client, admin, bond_token) = setup();
+    let keeper = funded_keeper(&env, &bond_token);
+    client.register_keeper(&keeper, &2_000);
+    assert_eq!(client.get_keeper(&keeper).unwrap().bond_token, bond_token);
+
+    let new_token = env.register_stellar_asset_contract(admin);
+    env.as_contract(&client.address, || {
+        keeper::set_config(
+            &env,
+            KeeperConfig {
+                bond_token: new_token.clone(),
+                min_bond: 1_000,
+                unbonding_period: 86_400,
+                rebate_bps: 5_000,
+            },
+        )
+        .unwrap();
+    });
+    // A bond in the old token no longer makes the keeper active
+    assert!(!client.is_active_keeper(&keeper));
+
+    env.as_contract(&client.address, || {
+        assert_eq!(keeper::slash_keeper(&env, keeper.clone(), 500), Ok(1_500));
+        assert_eq!(get_reserve_balance(&env, Some(bond_token.clone())), 500);
+        assert_eq!(get_reserve_balance(&env, Some(new_token.clone())), 0);
+    });
+
+    let unbond_at = client.request_keeper_unbond(&keeper);
+    env.ledger().with_mut(|li| li.timestamp = unbond_at);
+    assert_eq!(client.withdraw_keeper_bond(&keeper), 1_500);
+    assert_eq!(TokenClient::new(&env, &bond_token).balance(&keeper), 4_500);
+}
+
+#[test]
+fn test_insurance_rebate_only_for_active_keepers() {
+    let (env, client, _admin, bond_token) = setup();
+    let keeper = funded_keeper(&env, &bond_token);
+    let outsider = Address::generate(&env);
+    client.register_keeper(&keeper, &1_000);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(keeper::insurance_rebate(&env, &keeper, 400), 200);
+        assert_eq!(keeper::insurance_rebate(&env, &outsider, 400), 0);
+    });
+}
+
+#[test]
+fn test_config_validation() {
+    let (env, client, _admin, bond_token) = setup();
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            keeper::set_config(
+                &env,
+                KeeperConfig {
+                    bond_token: bond_token.clone(),
+                    min_bond: 0,
+                    unbonding_period: 0,
+                    rebate_bps: 0,
+                },
+            ),
+            Err(KeeperError::InvalidParameter)
+        );
+    });
+}
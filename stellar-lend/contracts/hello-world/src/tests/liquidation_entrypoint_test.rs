@@ -0,0 +1,171 @@
+//! # Liquidation Entrypoint Tests
+//!
+//! Exercises `liquidate` end to end with real tokens: the liquidator repays
+//! part of an underwater borrower's debt, receives the collateral plus the
+//! incentive, and the borrower's position is reduced accordingly. Also covers
+//! close factor clamping, rejection of healthy positions and interest being
+//! charged once across a liquidation.
+
+#![cfg(test)]
+
+use crate::deposit::{DepositDataKey, Position};
+use crate::liquidate::LiquidationError;
+use crate::testutils::{advance_time, setup_pool};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+const PRICE: i128 = 10_000_000;
+
+struct Setup {
+    env: Env,
+    client: HelloContractClient<'static>,
+    borrower: Address,
+    liquidator: Address,
+    debt_asset: Address,
+    collateral_asset: Address,
+}
+
+/// Borrower with `collateral` against 1_000 of debt at equal prices; the pool
+/// holds the collateral tokens and the liquidator holds 1_000 of the debt asset
+fn setup(collateral: i128) -> Setup {
+    let (env, client, admin) = setup_pool();
+
+    let debt_asset = env.register_stellar_asset_contract(admin.clone());
+    let collateral_asset = env.register_stellar_asset_contract(admin.clone());
+    client.update_price_feed(&admin, &debt_asset, &PRICE, &7, &admin);
+    client.update_price_feed(&admin, &collateral_asset, &PRICE, &7, &admin);
+    StellarAssetClient::new(&env, &collateral_asset).mint(&client.address, &collateral);
+
+    let liquidator = Address::generate(&env);
+    StellarAssetClient::new(&env, &debt_asset).mint(&liquidator, &1_000);
+    TokenClient::new(&env, &debt_asset).approve(&liquidator, &client.address, &1_000, &1_000);
+
+    let borrower = Address::generate(&env);
+    client.test_set_position(&borrower, &collateral, &1_000, &0);
+
+    Setup {
+        env,
+        client,
+        borrower,
+        liquidator,
+        debt_asset,
+        collateral_asset,
+    }
+}
+
+fn position(s: &Setup) -> Position {
+    s.env.as_contract(&s.client.address, || {
+        s.env
+            .storage()
+            .persistent()
+            .get::<DepositDataKey, Position>(&DepositDataKey::Position(s.borrower.clone()))
+            .unwrap()
+    })
+}
+
+/// Accrue the borrower's interest as any state-changing entry point would
+fn accrue(s: &Setup) -> Position {
+    let mut position = position(s);
+    s.env.as_contract(&s.client.address, || {
+        crate::borrow::accrue_interest(&s.env, &s.borrower, &mut position).unwrap();
+        s.env
+            .storage()
+            .persistent()
+            .set(&DepositDataKey::Position(s.borrower.clone()), &position);
+    });
+    position
+}
+
+#[test]
+fn test_liquidate_repays_debt_and_seizes_collateral_with_incentive() {
+    let s = setup(1_000);
+
+    let repaid = s.client.liquidate(
+        &s.liquidator,
+        &s.borrower,
+        &Some(s.debt_asset.clone()),
+        &Some(s.collateral_asset.clone()),
+        &400,
+    );
+    assert_eq!(repaid, 400);
+
+    // 400 of debt buys 400 of collateral plus the 10% incentive
+    let position = position(&s);
+    assert_eq!(position.debt, 600);
+    assert_eq!(position.collateral, 560);
+
+    let debt_token = TokenClient::new(&s.env, &s.debt_asset);
+    let collateral_token = TokenClient::new(&s.env, &s.collateral_asset);
+    assert_eq!(debt_token.balance(&s.liquidator), 600);
+    assert_eq!(debt_token.balance(&s.client.address), 400);
+    assert_eq!(collateral_token.balance(&s.liquidator), 440);
+    assert_eq!(collateral_token.balance(&s.client.address), 560);
+}
+
+#[test]
+fn test_liquidate_clamps_to_close_factor() {
+    let s = setup(1_000);
+
+    let repaid = s.client.liquidate(
+        &s.liquidator,
+        &s.borrower,
+        &Some(s.debt_asset.clone()),
+        &Some(s.collateral_asset.clone()),
+        &900,
+    );
+
+    // Default close factor is 50% of the debt
+    assert_eq!(repaid, 500);
+    assert_eq!(position(&s).debt, 500);
+    assert_eq!(
+        TokenClient::new(&s.env, &s.debt_asset).balance(&s.liquidator),
+        500
+    );
+}
+
+#[test]
+fn test_liquidate_healthy_position_fails() {
+    let s = setup(5_000);
+
+    assert_eq!(
+        s.client.try_liquidate(
+            &s.liquidator,
+            &s.borrower,
+            &Some(s.debt_asset.clone()),
+            &Some(s.collateral_asset.clone()),
+            &100,
+        ),
+        Err(Ok(LiquidationError::NotLiquidatable))
+    );
+    assert_eq!(position(&s).debt, 1_000);
+}
+
+#[test]
+fn test_liquidate_charges_interest_once() {
+    let s = setup(1_000);
+    // Snapshot the borrower's borrow index, then let interest build up
+    accrue(&s);
+    advance_time(&s.env, 365 * 86_400);
+
+    s.client.liquidate(
+        &s.liquidator,
+        &s.borrower,
+        &Some(s.debt_asset.clone()),
+        &Some(s.collateral_asset.clone()),
+        &400,
+    );
+    let after = position(&s);
+    let index_at_liquidation = s.client.get_borrow_index(&None);
+
+    advance_time(&s.env, 365 * 86_400);
+    let index_now = s.client.get_borrow_index(&None);
+    let accrued = accrue(&s);
+
+    // Only the index growth since the liquidation is charged again
+    let growth = after.debt * (index_now - index_at_liquidation);
+    let expected = growth / index_at_liquidation + i128::from(growth % index_at_liquidation > 0);
+    assert!(expected > 0);
+    assert_eq!(accrued.borrow_interest - after.borrow_interest, expected);
+}
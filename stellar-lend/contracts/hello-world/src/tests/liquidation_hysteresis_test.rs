@@ -0,0 +1,147 @@
+//! # Liquidation Hysteresis Tests
+//!
+//! Covers the governed buffer around the liquidation threshold: positions
+//! become liquidatable below the lower mark and stay so until they recover to
+//! the upper mark, whether they got there through a liquidation or a price
+//! move.
+
+#![cfg(test)]
+
+use crate::liquidate::LiquidationError;
+use crate::risk_params::{
+    can_liquidate_position, is_liquidation_flagged, set_liquidation_hysteresis_bps,
+    update_liquidation_flag, RiskParamsError,
+};
+use crate::testutils::{setup_pool, supply};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+const PRICE: i128 = 10_000_000;
+
+fn set_buffer(env: &Env, client: &HelloContractClient, buffer_bps: i128) {
+    env.as_contract(&client.address, || {
+        set_liquidation_hysteresis_bps(env, buffer_bps).unwrap();
+    });
+}
+
+fn eligible(env: &Env, client: &HelloContractClient, borrower: &Address, collateral: i128) -> bool {
+    env.as_contract(&client.address, || {
+        can_liquidate_position(env, borrower, collateral, 1_000).unwrap()
+    })
+}
+
+#[test]
+fn test_zero_buffer_matches_threshold() {
+    let (env, client, _admin) = setup_pool();
+    let borrower = Address::generate(&env);
+    assert_eq!(client.get_liquidation_hysteresis_bps(), 0);
+
+    // Default liquidation threshold is 105%
+    assert!(eligible(&env, &client, &borrower, 1_049));
+    assert!(!eligible(&env, &client, &borrower, 1_050));
+}
+
+#[test]
+fn test_band_requires_margin_below_threshold() {
+    let (env, client, _admin) = setup_pool();
+    set_buffer(&env, &client, 200);
+    let borrower = Address::generate(&env);
+
+    // Marks are 103% and 107%
+    assert!(!eligible(&env, &client, &borrower, 1_040));
+    assert!(eligible(&env, &client, &borrower, 1_029));
+}
+
+#[test]
+fn test_flagged_position_stays_liquidatable_until_upper_mark() {
+    let (env, client, _admin) = setup_pool();
+    set_buffer(&env, &client, 200);
+    let borrower = Address::generate(&env);
+
+    // A liquidation that leaves the position inside the band keeps it flagged
+    env.as_contract(&client.address, || {
+        update_liquidation_flag(&env, &borrower, 1_060, 1_000);
+        assert!(is_liquidation_flagged(&env, &borrower));
+    });
+    assert!(eligible(&env, &client, &borrower, 1_060));
+    assert!(!eligible(&env, &client, &borrower, 1_070));
+
+    // Recovering to the upper mark clears the flag
+    env.as_contract(&client.address, || {
+        update_liquidation_flag(&env, &borrower, 1_070, 1_000);
+        assert!(!is_liquidation_flagged(&env, &borrower));
+    });
+    assert!(!eligible(&env, &client, &borrower, 1_060));
+}
+
+#[test]
+fn test_price_dip_keeps_position_flagged_inside_band() {
+    let (env, client, admin) = setup_pool();
+    set_buffer(&env, &client, 200);
+    let debt_asset = env.register_stellar_asset_contract(admin.clone());
+    let collateral_asset = env.register_stellar_asset_contract(admin.clone());
+    supply(&env, &client, &Address::generate(&env), &debt_asset, 10_000);
+    client.update_price_feed(&admin, &debt_asset, &PRICE, &7, &admin);
+    client.update_price_feed(&admin, &collateral_asset, &(2 * PRICE), &7, &admin);
+
+    let borrower = Address::generate(&env);
+    supply(&env, &client, &borrower, &collateral_asset, 1_000);
+    client.borrow_asset(&borrower, &Some(debt_asset), &1_800);
+    let set_collateral_price = |price: i128| {
+        client.update_price_feed(&admin, &collateral_asset, &price, &7, &admin);
+    };
+
+    // Marks are 103% and 107%; 106% is inside the band and not yet flagged
+    set_collateral_price(19_100_000);
+    assert!(!client.poke(&borrower).is_liquidatable);
+
+    // Dipping below the lower mark flags the position...
+    set_collateral_price(18_400_000);
+    assert!(client.poke(&borrower).is_liquidatable);
+    assert!(env.as_contract(&client.address, || is_liquidation_flagged(&env, &borrower)));
+
+    // ...which stays liquidatable after recovering into the band
+    set_collateral_price(18_900_000);
+    assert!(client.poke(&borrower).is_liquidatable);
+
+    // Reaching the upper mark clears the flag
+    set_collateral_price(19_300_000);
+    assert!(!client.poke(&borrower).is_liquidatable);
+    set_collateral_price(18_900_000);
+    assert!(!client.poke(&borrower).is_liquidatable);
+}
+
+#[test]
+fn test_preview_respects_band() {
+    let (env, client, admin) = setup_pool();
+    set_buffer(&env, &client, 200);
+    let debt_asset = env.register_stellar_asset_contract(admin.clone());
+    let collateral_asset = env.register_stellar_asset_contract(admin.clone());
+    client.update_price_feed(&admin, &debt_asset, &PRICE, &7, &admin);
+    client.update_price_feed(&admin, &collateral_asset, &PRICE, &7, &admin);
+
+    let borrower = Address::generate(&env);
+    client.test_set_position(&borrower, &1_040, &1_000, &0);
+    let result = client.try_preview_liquidation(
+        &borrower,
+        &Some(debt_asset.clone()),
+        &Some(collateral_asset.clone()),
+        &100,
+    );
+    assert_eq!(result, Err(Ok(LiquidationError::NotLiquidatable)));
+
+    client.test_set_position(&borrower, &1_020, &1_000, &0);
+    let preview =
+        client.preview_liquidation(&borrower, &Some(debt_asset), &Some(collateral_asset), &100);
+    assert_eq!(preview.debt_to_repay, 100);
+}
+
+#[test]
+fn test_buffer_is_capped() {
+    let (env, client, _admin) = setup_pool();
+    let result = env.as_contract(&client.address, || {
+        set_liquidation_hysteresis_bps(&env, 501)
+    });
+    assert_eq!(result, Err(RiskParamsError::InvalidParameter));
+}
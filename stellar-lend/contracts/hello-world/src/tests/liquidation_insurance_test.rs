@@ -0,0 +1,102 @@
+//! # Liquidation Insurance Share Tests
+//!
+//! Covers the governed split of the liquidation incentive between the
+//! liquidator and the insurance fund, as reported by `preview_liquidation`.
+
+#![cfg(test)]
+
+use crate::deposit::{DepositDataKey, Position};
+use crate::reserve::{set_liquidation_insurance_share, ReserveError};
+use crate::testutils::{execute_governance_proposal, setup_governance, setup_pool};
+use crate::types::ProposalType;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+const PRICE: i128 = 10_000_000;
+
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, admin) = setup_pool();
+    setup_governance(&env, &client, &admin);
+    (env, client, admin)
+}
+
+/// Register a priced asset and an underwater borrower (collateral 1000, debt 1000)
+fn underwater_borrower(
+    env: &Env,
+    client: &HelloContractClient,
+    admin: &Address,
+) -> (Address, Address, Address) {
+    let debt_asset = env.register_stellar_asset_contract(admin.clone());
+    let collateral_asset = env.register_stellar_asset_contract(admin.clone());
+    client.update_price_feed(admin, &debt_asset, &PRICE, &7, admin);
+    client.update_price_feed(admin, &collateral_asset, &PRICE, &7, admin);
+
+    let borrower = Address::generate(env);
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DepositDataKey::Position(borrower.clone()),
+            &Position {
+                collateral: 1_000,
+                debt: 1_000,
+                borrow_interest: 0,
+                last_accrual_time: env.ledger().timestamp(),
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&DepositDataKey::CollateralBalance(borrower.clone()), &1_000i128);
+    });
+    (borrower, debt_asset, collateral_asset)
+}
+
+#[test]
+fn test_preview_without_insurance_share_pays_full_incentive() {
+    let (env, client, admin) = setup();
+    let (borrower, debt_asset, collateral_asset) = underwater_borrower(&env, &client, &admin);
+
+    let preview =
+        client.preview_liquidation(&borrower, &Some(debt_asset), &Some(collateral_asset), &500);
+    assert_eq!(preview.debt_to_repay, 500);
+    assert_eq!(preview.collateral_seized, 550);
+    assert_eq!(preview.insurance_collateral, 0);
+    assert_eq!(preview.liquidator_collateral, 550);
+}
+
+#[test]
+fn test_preview_splits_incentive_with_insurance_fund() {
+    let (env, client, admin) = setup();
+    let (borrower, debt_asset, collateral_asset) = underwater_borrower(&env, &client, &admin);
+    env.as_contract(&client.address, || {
+        set_liquidation_insurance_share(&env, 5_000).unwrap();
+    });
+
+    let preview =
+        client.preview_liquidation(&borrower, &Some(debt_asset), &Some(collateral_asset), &500);
+    // 550 seized, of which 50 is incentive; half of the incentive is insured
+    assert_eq!(preview.collateral_seized, 550);
+    assert_eq!(preview.insurance_share_bps, 5_000);
+    assert_eq!(preview.insurance_collateral, 25);
+    assert_eq!(preview.liquidator_collateral, 525);
+}
+
+#[test]
+fn test_insurance_share_is_capped() {
+    let (env, client, _admin) = setup();
+    let result = env.as_contract(&client.address, || {
+        set_liquidation_insurance_share(&env, 5_001)
+    });
+    assert_eq!(result, Err(ReserveError::InvalidInsuranceShare));
+}
+
+#[test]
+fn test_insurance_share_set_via_governance() {
+    let (env, client, _admin) = setup();
+    execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::LiquidationInsuranceShare(2_000),
+    );
+
+    assert_eq!(client.get_liquidation_insurance_share(), 2_000);
+}
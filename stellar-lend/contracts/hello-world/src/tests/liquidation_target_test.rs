@@ -0,0 +1,119 @@
+//! # Target Health Factor Liquidation Tests
+//!
+//! Covers `liquidate_to_target`: repaying exactly enough to restore a target
+//! health factor, the close factor cap, and targets no liquidation can reach.
+
+#![cfg(test)]
+
+use crate::liquidate::{LiquidationError, TargetLiquidation};
+use crate::risk_tier::{RiskTier, RiskTierDataKey};
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+const PRICE: i128 = 10_000_000;
+
+struct Setup {
+    env: Env,
+    client: HelloContractClient<'static>,
+    borrower: Address,
+    liquidator: Address,
+    debt_asset: Address,
+    collateral_asset: Address,
+}
+
+/// Borrower with `collateral` against 1_000 of debt at equal prices; the
+/// collateral asset is in the stable tier (3% incentive) when `stable`
+fn setup(collateral: i128, stable: bool) -> Setup {
+    let (env, client, admin) = setup_pool();
+
+    let debt_asset = env.register_stellar_asset_contract(admin.clone());
+    let collateral_asset = env.register_stellar_asset_contract(admin.clone());
+    client.update_price_feed(&admin, &debt_asset, &PRICE, &7, &admin);
+    client.update_price_feed(&admin, &collateral_asset, &PRICE, &7, &admin);
+    StellarAssetClient::new(&env, &collateral_asset).mint(&client.address, &collateral);
+    if stable {
+        env.as_contract(&client.address, || {
+            env.storage().persistent().set(
+                &RiskTierDataKey::AssetTier(Some(collateral_asset.clone())),
+                &RiskTier::Stable,
+            );
+        });
+    }
+
+    let liquidator = Address::generate(&env);
+    StellarAssetClient::new(&env, &debt_asset).mint(&liquidator, &1_000);
+    TokenClient::new(&env, &debt_asset).approve(&liquidator, &client.address, &1_000, &1_000);
+
+    let borrower = Address::generate(&env);
+    client.test_set_position(&borrower, &collateral, &1_000, &0);
+
+    Setup {
+        env,
+        client,
+        borrower,
+        liquidator,
+        debt_asset,
+        collateral_asset,
+    }
+}
+
+fn liquidate_to(s: &Setup, target: i128) -> Result<TargetLiquidation, LiquidationError> {
+    s.client
+        .try_liquidate_to_target(
+            &s.liquidator,
+            &s.borrower,
+            &Some(s.debt_asset.clone()),
+            &Some(s.collateral_asset.clone()),
+            &target,
+        )
+        .map(|result| result.unwrap())
+        .map_err(|err| err.unwrap())
+}
+
+#[test]
+fn test_liquidate_to_target_repays_exact_amount() {
+    let s = setup(1_040, true);
+
+    // (10_450 × 1_000 − 1_040 × 10_000) / (10_450 − 10_300) = 333.3, rounded up
+    let result = liquidate_to(&s, 10_450).unwrap();
+    assert_eq!(result.debt_repaid, 334);
+    assert_eq!(result.collateral_seized, 344);
+    assert_eq!(result.health_factor_before, 10_400);
+    // 696 of collateral against 666 of debt
+    assert_eq!(result.health_factor_after, 10_450);
+
+    assert_eq!(
+        TokenClient::new(&s.env, &s.debt_asset).balance(&s.liquidator),
+        666
+    );
+}
+
+#[test]
+fn test_liquidate_to_target_capped_by_close_factor() {
+    let s = setup(1_040, true);
+
+    let result = liquidate_to(&s, 20_000).unwrap();
+    assert_eq!(result.debt_repaid, 500);
+    assert_eq!(result.collateral_seized, 515);
+    assert_eq!(result.health_factor_after, 10_500);
+}
+
+#[test]
+fn test_liquidate_to_target_rejects_unreachable_and_met_targets() {
+    // At 100% with a 10% incentive every liquidation lowers the health factor
+    let s = setup(1_000, false);
+    assert_eq!(
+        liquidate_to(&s, 10_500),
+        Err(LiquidationError::TargetUnreachable)
+    );
+
+    let s = setup(1_040, true);
+    assert_eq!(
+        liquidate_to(&s, 10_400),
+        Err(LiquidationError::InvalidAmount)
+    );
+    assert_eq!(liquidate_to(&s, 0), Err(LiquidationError::InvalidAmount));
+}
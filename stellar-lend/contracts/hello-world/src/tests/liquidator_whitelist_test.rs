@@ -0,0 +1,79 @@
+//! # Liquidator Whitelist Tests
+//!
+//! Covers permissionless liquidation while whitelist mode is off, rejecting
+//! unlisted liquidators while it is on, and adding and removing liquidators.
+
+#![cfg(test)]
+
+use crate::liquidate::{self, LiquidationError};
+use crate::testutils::setup_pool;
+use crate::types::{ProposalCategory, ProposalType};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, _admin) = setup_pool();
+
+    // No position: any liquidation that passes the gate is not liquidatable
+    let borrower = Address::generate(&env);
+
+    (env, client, borrower)
+}
+
+#[test]
+fn test_anyone_can_liquidate_when_mode_off() {
+    let (env, client, borrower) = setup();
+    assert!(!client.is_liquidator_whitelist_enabled());
+
+    assert_eq!(
+        client.try_liquidate(&Address::generate(&env), &borrower, &None, &None, &500),
+        Err(Ok(LiquidationError::NotLiquidatable))
+    );
+}
+
+#[test]
+fn test_whitelist_mode_gates_liquidators() {
+    let (env, client, borrower) = setup();
+    let keeper = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        liquidate::set_liquidator_whitelist_enabled(&env, true);
+    });
+    assert!(client.is_liquidator_whitelist_enabled());
+
+    assert_eq!(
+        client.try_liquidate(&keeper, &borrower, &None, &None, &500),
+        Err(Ok(LiquidationError::LiquidatorNotWhitelisted))
+    );
+
+    env.as_contract(&client.address, || {
+        liquidate::add_liquidator(&env, keeper.clone());
+    });
+    assert!(client.is_whitelisted_liquidator(&keeper));
+    assert_eq!(
+        client.try_liquidate(&keeper, &borrower, &None, &None, &500),
+        Err(Ok(LiquidationError::NotLiquidatable))
+    );
+
+    env.as_contract(&client.address, || {
+        liquidate::remove_liquidator(&env, keeper.clone());
+    });
+    assert!(!client.is_whitelisted_liquidator(&keeper));
+    assert_eq!(
+        client.try_liquidate(&keeper, &borrower, &None, &None, &500),
+        Err(Ok(LiquidationError::LiquidatorNotWhitelisted))
+    );
+}
+
+#[test]
+fn test_whitelist_proposals_are_parameter_changes() {
+    let (env, _, _) = setup();
+    assert_eq!(
+        ProposalType::LiquidatorWhitelist(true).category(),
+        ProposalCategory::Parameter
+    );
+    assert_eq!(
+        ProposalType::Liquidator(Address::generate(&env), true).category(),
+        ProposalCategory::Parameter
+    );
+}
@@ -0,0 +1,175 @@
+//! # Liquidity Mining Tests
+//!
+//! Covers index-based reward accrual for suppliers and borrowers, pending
+//! reward views, claims in the governance token and governed reward markets.
+
+#![cfg(test)]
+
+use crate::errors::GovernanceError;
+use crate::rewards::{EmissionSchedule, RewardMarket, RewardSide, RewardsError};
+use crate::testutils::{execute_governance_proposal, setup_governance, setup_pool};
+use crate::types::ProposalType;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String};
+
+const HOUR: u64 = 3_600;
+
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, admin) = setup_pool();
+    env.ledger().set_timestamp(10_000);
+
+    let gov_token = setup_governance(&env, &client, &admin);
+    (env, client, admin, gov_token)
+}
+
+fn market(side: RewardSide) -> RewardMarket {
+    RewardMarket { asset: None, side }
+}
+
+/// 1_000 reward tokens per second for ten hours
+fn schedule(start_time: u64) -> EmissionSchedule {
+    EmissionSchedule {
+        start_time,
+        epoch_duration: HOUR,
+        initial_budget: 1_000 * HOUR as i128,
+        decay_bps: 0,
+        total_epochs: 10,
+    }
+}
+
+fn fund(env: &Env, client: &HelloContractClient, side: RewardSide) {
+    env.as_contract(&client.address, || {
+        crate::rewards::set_reward_emission(
+            env,
+            market(side),
+            Some(schedule(env.ledger().timestamp())),
+        )
+        .unwrap();
+    });
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger()
+        .with_mut(|li| li.timestamp = li.timestamp + seconds);
+}
+
+#[test]
+fn test_suppliers_share_emissions_by_balance() {
+    let (env, client, _admin, _gov_token) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.deposit_collateral(&alice, &None, &1_000);
+    fund(&env, &client, RewardSide::Supply);
+
+    advance(&env, 100);
+    assert_eq!(client.get_pending_rewards(&alice), 100_000);
+
+    client.deposit_collateral(&bob, &None, &3_000);
+    advance(&env, 100);
+    assert_eq!(client.get_pending_rewards(&alice), 125_000);
+    assert_eq!(client.get_pending_rewards(&bob), 75_000);
+}
+
+#[test]
+fn test_borrowers_earn_on_principal() {
+    let (env, client, _admin, _gov_token) = setup();
+    let user = Address::generate(&env);
+    fund(&env, &client, RewardSide::Borrow);
+    client.deposit_collateral(&user, &None, &10_000);
+
+    advance(&env, 50);
+    assert_eq!(client.get_pending_rewards(&user), 0);
+
+    client.borrow_asset(&user, &None, &1_000);
+    advance(&env, 50);
+    assert_eq!(client.get_pending_rewards(&user), 50_000);
+}
+
+#[test]
+fn test_emissions_stop_at_schedule_end() {
+    let (env, client, _admin, _gov_token) = setup();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &1_000);
+    fund(&env, &client, RewardSide::Supply);
+
+    advance(&env, 20 * HOUR);
+    assert_eq!(client.get_pending_rewards(&user), 10 * 1_000 * HOUR as i128);
+}
+
+#[test]
+fn test_claim_rewards_pays_governance_token() {
+    let (env, client, _admin, gov_token) = setup();
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &gov_token).mint(&client.address, &1_000_000);
+
+    assert_eq!(
+        client.try_claim_rewards(&user),
+        Err(Ok(RewardsError::NothingToClaim))
+    );
+
+    client.deposit_collateral(&user, &None, &1_000);
+    fund(&env, &client, RewardSide::Supply);
+    advance(&env, 100);
+
+    assert_eq!(client.claim_rewards(&user), 100_000);
+    assert_eq!(TokenClient::new(&env, &gov_token).balance(&user), 100_000);
+    assert_eq!(client.get_pending_rewards(&user), 0);
+
+    advance(&env, 10);
+    assert_eq!(client.get_pending_rewards(&user), 10_000);
+}
+
+#[test]
+fn test_ending_emission_keeps_earned_rewards() {
+    let (env, client, _admin, _gov_token) = setup();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &1_000);
+    fund(&env, &client, RewardSide::Supply);
+    advance(&env, 100);
+
+    env.as_contract(&client.address, || {
+        crate::rewards::set_reward_emission(&env, market(RewardSide::Supply), None).unwrap();
+    });
+    advance(&env, 100);
+
+    assert_eq!(client.get_pending_rewards(&user), 100_000);
+    assert_eq!(
+        client.get_reward_emission(&market(RewardSide::Supply)),
+        None
+    );
+    assert_eq!(client.get_reward_markets().len(), 1);
+}
+
+#[test]
+fn test_reward_emission_via_governance() {
+    let (env, client, _admin, gov_token) = setup();
+    let voter = Address::generate(&env);
+    StellarAssetClient::new(&env, &gov_token).mint(&voter, &1_000);
+    client.gov_stake(&voter, &1_000);
+
+    let mut invalid = schedule(env.ledger().timestamp());
+    invalid.epoch_duration = 0;
+    assert_eq!(
+        client.try_gov_create_proposal(
+            &voter,
+            &ProposalType::RewardEmission(market(RewardSide::Supply), Some(invalid)),
+            &String::from_str(&env, "Malformed schedule"),
+            &None,
+        ),
+        Err(Ok(GovernanceError::InvalidProposal))
+    );
+
+    let t = env.ledger().timestamp();
+    execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::RewardEmission(market(RewardSide::Supply), Some(schedule(t))),
+    );
+
+    assert_eq!(
+        client.get_reward_emission(&market(RewardSide::Supply)),
+        Some(schedule(t))
+    );
+}
@@ -0,0 +1,158 @@
+//! # Loyalty Tier Tests
+//!
+//! Covers tier configuration, tier computation from volume and account age,
+//! the tier fee discount on borrows and close factor protection.
+
+#![cfg(test)]
+
+use crate::deposit::{DepositDataKey, UserAnalytics};
+use crate::loyalty::{LoyaltyError, LoyaltyTier};
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env, Vec};
+
+const MONTH: u64 = 30 * 86_400;
+
+fn tiers(env: &Env) -> Vec<LoyaltyTier> {
+    vec![
+        env,
+        LoyaltyTier {
+            min_volume: 1_000,
+            min_age: 0,
+            fee_discount_bps: 1_000,
+            close_factor_reduction_bps: 100,
+        },
+        LoyaltyTier {
+            min_volume: 10_000,
+            min_age: MONTH,
+            fee_discount_bps: 2_500,
+            close_factor_reduction_bps: 500,
+        },
+    ]
+}
+
+fn stored_tier(env: &Env, client: &HelloContractClient, user: &Address) -> u32 {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get::<DepositDataKey, UserAnalytics>(&DepositDataKey::UserAnalytics(user.clone()))
+            .unwrap()
+            .loyalty_tier
+    })
+}
+
+#[test]
+fn test_no_tier_without_configuration() {
+    let (env, client, _admin) = setup_pool();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &50_000);
+
+    assert_eq!(client.get_user_tier(&user), 0);
+    assert_eq!(stored_tier(&env, &client, &user), 0);
+}
+
+#[test]
+fn test_tier_follows_volume_and_age() {
+    let (env, client, admin) = setup_pool();
+    client.set_loyalty_tiers(&admin, &tiers(&env));
+    let user = Address::generate(&env);
+
+    client.deposit_collateral(&user, &None, &500);
+    assert_eq!(client.get_user_tier(&user), 0);
+
+    client.deposit_collateral(&user, &None, &5_000);
+    assert_eq!(client.get_user_tier(&user), 1);
+    assert_eq!(stored_tier(&env, &client, &user), 1);
+
+    // Enough volume for tier 2, but the account is too young
+    client.deposit_collateral(&user, &None, &10_000);
+    assert_eq!(client.get_user_tier(&user), 1);
+
+    // Age alone promotes the user, without a transaction
+    env.ledger()
+        .with_mut(|li| li.timestamp = li.timestamp + MONTH);
+    assert_eq!(client.get_user_tier(&user), 2);
+    assert_eq!(stored_tier(&env, &client, &user), 1);
+}
+
+#[test]
+fn test_tier_discounts_origination_fee() {
+    let (env, client, admin) = setup_pool();
+    env.as_contract(&client.address, || {
+        crate::origination_fee::set_origination_fee_bps(&env, 100).unwrap();
+    });
+    client.set_loyalty_tiers(&admin, &tiers(&env));
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &20_000);
+    env.ledger()
+        .with_mut(|li| li.timestamp = li.timestamp + MONTH);
+
+    let preview = client.preview_borrow(&user, &None, &1_000);
+    assert_eq!(preview.fee_discount_bps, 2_500);
+
+    let receipt = client.borrow_asset_with_receipt(&user, &None, &1_000);
+    assert_eq!(receipt.origination_fee, 8);
+    assert_eq!(receipt.amount_received, 992);
+}
+
+#[test]
+fn test_tier_reduces_close_factor() {
+    let (env, client, admin) = setup_pool();
+    client.set_loyalty_tiers(&admin, &tiers(&env));
+    let loyal = Address::generate(&env);
+    let newcomer = Address::generate(&env);
+    client.deposit_collateral(&loyal, &None, &20_000);
+    env.ledger()
+        .with_mut(|li| li.timestamp = li.timestamp + MONTH);
+
+    env.as_contract(&client.address, || {
+        // Default close factor is 50%; tier 2 takes 5 points off
+        assert_eq!(
+            crate::loyalty::protected_max_liquidatable(&env, &loyal, 5_000),
+            4_500
+        );
+        assert_eq!(
+            crate::loyalty::protected_max_liquidatable(&env, &newcomer, 5_000),
+            5_000
+        );
+    });
+}
+
+#[test]
+fn test_set_loyalty_tiers_validation() {
+    let (env, client, admin) = setup_pool();
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_loyalty_tiers(&stranger, &tiers(&env)),
+        Err(Ok(LoyaltyError::Unauthorized))
+    );
+
+    let descending = vec![
+        &env,
+        tiers(&env).get(1).unwrap(),
+        tiers(&env).get(0).unwrap(),
+    ];
+    assert_eq!(
+        client.try_set_loyalty_tiers(&admin, &descending),
+        Err(Ok(LoyaltyError::InvalidTier))
+    );
+
+    let mut too_generous = tiers(&env);
+    too_generous.set(
+        1,
+        LoyaltyTier {
+            min_volume: 10_000,
+            min_age: MONTH,
+            fee_discount_bps: 2_500,
+            close_factor_reduction_bps: crate::loyalty::MAX_CLOSE_FACTOR_REDUCTION_BPS + 1,
+        },
+    );
+    assert_eq!(
+        client.try_set_loyalty_tiers(&admin, &too_generous),
+        Err(Ok(LoyaltyError::InvalidTier))
+    );
+
+    client.set_loyalty_tiers(&admin, &tiers(&env));
+    assert_eq!(client.get_loyalty_tiers(), tiers(&env));
+}
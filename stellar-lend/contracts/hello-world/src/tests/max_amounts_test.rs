@@ -0,0 +1,90 @@
+//! # Max Amounts Tests
+//!
+//! Covers `get_max_borrowable` and `get_max_withdrawable`: amounts that match
+//! what borrow and withdraw actually allow, accrued interest, and pauses.
+
+#![cfg(test)]
+
+use crate::errors::ProtocolError;
+use crate::risk_management::PauseOperation;
+use crate::testutils::setup_pool;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::Address;
+
+#[test]
+fn test_max_amounts_without_position() {
+    let (env, client, _) = setup_pool();
+    let user = Address::generate(&env);
+
+    assert_eq!(client.get_max_borrowable(&user, &None), 0);
+    assert_eq!(client.get_max_withdrawable(&user, &None), 0);
+
+    // Without debt all collateral can leave
+    client.deposit_collateral(&user, &None, &11_000);
+    assert_eq!(client.get_max_withdrawable(&user, &None), 11_000);
+    assert_eq!(client.get_max_borrowable(&user, &None), 10_000);
+}
+
+#[test]
+fn test_max_amounts_match_borrow_and_withdraw() {
+    let (env, client, _) = setup_pool();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &11_000);
+    client.borrow_asset(&user, &None, &5_000);
+
+    // 11_000 supports 10_000 of debt at 110%; 5_000 of debt needs 5_500
+    assert_eq!(client.get_max_borrowable(&user, &None), 5_000);
+    assert_eq!(client.get_max_withdrawable(&user, &None), 5_500);
+
+    assert_eq!(
+        client.try_withdraw_collateral(&user, &None, &5_501),
+        Err(Ok(ProtocolError::InsufficientCollateralRatio))
+    );
+    client.withdraw_collateral(&user, &None, &5_500);
+    assert_eq!(client.get_max_withdrawable(&user, &None), 0);
+    assert_eq!(client.get_max_borrowable(&user, &None), 0);
+
+    client.deposit_collateral(&user, &None, &5_500);
+    let max = client.get_max_borrowable(&user, &None);
+    assert_eq!(max, 5_000);
+    client.borrow_asset(&user, &None, &max);
+    assert_eq!(client.get_max_borrowable(&user, &None), 0);
+    assert_eq!(
+        client.try_borrow_asset(&user, &None, &1),
+        Err(Ok(ProtocolError::MaxBorrowExceeded))
+    );
+}
+
+#[test]
+fn test_max_amounts_include_accrued_interest() {
+    let (env, client, _) = setup_pool();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    client.borrow_asset(&user, &None, &5_000);
+
+    // 50% utilization: 13.5% for a year brings debt to 5_675
+    env.ledger().with_mut(|li| li.timestamp += 365 * 86_400);
+    assert_eq!(client.get_max_borrowable(&user, &None), 9_090 - 5_675);
+    // 5_675 of debt needs 6_242.5, rounded up
+    assert_eq!(client.get_max_withdrawable(&user, &None), 10_000 - 6_243);
+}
+
+#[test]
+fn test_max_amounts_zero_while_paused() {
+    let (env, client, admin) = setup_pool();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+
+    client.set_pause_switch(&admin, &PauseOperation::Borrow, &true);
+    assert_eq!(client.get_max_borrowable(&user, &None), 0);
+    assert_eq!(client.get_max_withdrawable(&user, &None), 10_000);
+
+    client.set_pause_switch(&admin, &PauseOperation::Withdraw, &true);
+    assert_eq!(client.get_max_withdrawable(&user, &None), 0);
+
+    client.set_pause_switch(&admin, &PauseOperation::Borrow, &false);
+    client.set_pause_switch(&admin, &PauseOperation::Withdraw, &false);
+    client.set_emergency_pause(&admin, &true);
+    assert_eq!(client.get_max_borrowable(&user, &None), 0);
+    assert_eq!(client.get_max_withdrawable(&user, &None), 0);
+}
@@ -0,0 +1,203 @@
+//! # Position Migration Tests
+//!
+//! Covers the source whitelist and one-transaction migration of a position
+//! from an external lending contract: repaying the source, moving the freed
+//! collateral in and re-borrowing the fronted debt.
+
+#![cfg(test)]
+
+use crate::deposit::{DepositDataKey, Position};
+use crate::migration::MigrationError;
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+/// Minimal lending contract holding one user's collateral and debt
+#[contract]
+pub struct MockLender;
+
+#[contractimpl]
+impl MockLender {
+    pub fn open(env: Env, user: Address, collateral: i128, debt: i128) {
+        env.storage()
+            .instance()
+            .set(&(Symbol::new(&env, "collateral"), user.clone()), &collateral);
+        env.storage()
+            .instance()
+            .set(&(Symbol::new(&env, "debt"), user), &debt);
+    }
+
+    pub fn debt(env: Env, user: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&(Symbol::new(&env, "debt"), user))
+            .unwrap_or(0)
+    }
+
+    pub fn repay_for(env: Env, user: Address, _asset: Address, amount: i128) {
+        let debt = Self::debt(env.clone(), user.clone());
+        if amount > debt {
+            panic!("repay exceeds debt");
+        }
+        env.storage()
+            .instance()
+            .set(&(Symbol::new(&env, "debt"), user), &(debt - amount));
+    }
+
+    pub fn withdraw_for(env: Env, user: Address, asset: Address, amount: i128, to: Address) {
+        user.require_auth();
+        if Self::debt(env.clone(), user.clone()) > 0 {
+            panic!("position still has debt");
+        }
+        let key = (Symbol::new(&env, "collateral"), user);
+        let collateral: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(collateral - amount));
+        TokenClient::new(&env, &asset).transfer(&env.current_contract_address(), &to, &amount);
+    }
+}
+
+/// Pool with 10_000 of the debt asset and a whitelisted lender holding
+/// `collateral` for `user` against `debt`
+fn setup(
+    collateral: i128,
+    debt: i128,
+) -> (
+    Env,
+    HelloContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let (env, client, admin) = setup_pool();
+
+    let debt_asset = env.register_stellar_asset_contract(admin.clone());
+    let collateral_asset = env.register_stellar_asset_contract(admin.clone());
+    StellarAssetClient::new(&env, &debt_asset).mint(&client.address, &10_000);
+
+    let user = Address::generate(&env);
+    let lender = env.register(MockLender, ());
+    StellarAssetClient::new(&env, &collateral_asset).mint(&lender, &collateral);
+    MockLenderClient::new(&env, &lender).open(&user, &collateral, &debt);
+    client.set_migration_source(&admin, &lender, &true);
+
+    (
+        env,
+        client,
+        admin,
+        user,
+        lender,
+        debt_asset,
+        collateral_asset,
+    )
+}
+
+fn position(env: &Env, contract: &Address, user: &Address) -> Option<Position> {
+    env.as_contract(contract, || {
+        env.storage()
+            .persistent()
+            .get::<DepositDataKey, Position>(&DepositDataKey::Position(user.clone()))
+    })
+}
+
+#[test]
+fn test_set_migration_source_admin_only() {
+    let (env, client, admin, _user, lender, _debt_asset, _collateral_asset) = setup(1_000, 0);
+    assert!(client.is_migration_source(&lender));
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_migration_source(&stranger, &lender, &false),
+        Err(Ok(MigrationError::Unauthorized))
+    );
+
+    client.set_migration_source(&admin, &lender, &false);
+    assert!(!client.is_migration_source(&lender));
+}
+
+#[test]
+fn test_migrate_position_moves_collateral_and_debt() {
+    let (env, client, _admin, user, lender, debt_asset, collateral_asset) = setup(5_000, 2_000);
+
+    let total_debt = client.migrate_position(
+        &user,
+        &lender,
+        &debt_asset,
+        &2_000,
+        &collateral_asset,
+        &5_000,
+    );
+    assert_eq!(total_debt, 2_000);
+
+    let position = position(&env, &client.address, &user).unwrap();
+    assert_eq!(position.collateral, 5_000);
+    assert_eq!(position.debt, 2_000);
+
+    assert_eq!(MockLenderClient::new(&env, &lender).debt(&user), 0);
+    assert_eq!(TokenClient::new(&env, &debt_asset).balance(&lender), 2_000);
+    assert_eq!(
+        TokenClient::new(&env, &debt_asset).balance(&client.address),
+        8_000
+    );
+    assert_eq!(
+        TokenClient::new(&env, &collateral_asset).balance(&client.address),
+        5_000
+    );
+}
+
+#[test]
+fn test_migrate_position_rejects_unlisted_source() {
+    let (env, client, admin, user, lender, debt_asset, collateral_asset) = setup(5_000, 2_000);
+    client.set_migration_source(&admin, &lender, &false);
+
+    assert_eq!(
+        client.try_migrate_position(&user, &lender, &debt_asset, &2_000, &collateral_asset, &5_000),
+        Err(Ok(MigrationError::SourceNotAllowed))
+    );
+    assert_eq!(MockLenderClient::new(&env, &lender).debt(&user), 2_000);
+}
+
+#[test]
+fn test_migrate_position_rejects_undercollateralized_position() {
+    let (env, client, _admin, user, lender, debt_asset, collateral_asset) = setup(1_000, 1_000);
+
+    assert_eq!(
+        client.try_migrate_position(&user, &lender, &debt_asset, &1_000, &collateral_asset, &1_000),
+        Err(Ok(MigrationError::InsufficientCollateralRatio))
+    );
+
+    // The repayment on the source reverts with the migration
+    assert_eq!(MockLenderClient::new(&env, &lender).debt(&user), 1_000);
+    assert!(position(&env, &client.address, &user).is_none());
+}
+
+#[test]
+fn test_migrate_position_requires_pool_liquidity() {
+    let (_env, client, _admin, user, lender, debt_asset, collateral_asset) =
+        setup(50_000, 20_000);
+
+    assert_eq!(
+        client.try_migrate_position(
+            &user,
+            &lender,
+            &debt_asset,
+            &20_000,
+            &collateral_asset,
+            &50_000
+        ),
+        Err(Ok(MigrationError::InsufficientLiquidity))
+    );
+}
+
+#[test]
+fn test_migrate_position_rejects_zero_amounts() {
+    let (_env, client, _admin, user, lender, debt_asset, collateral_asset) = setup(5_000, 2_000);
+
+    assert_eq!(
+        client.try_migrate_position(&user, &lender, &debt_asset, &0, &collateral_asset, &5_000),
+        Err(Ok(MigrationError::InvalidAmount))
+    );
+}
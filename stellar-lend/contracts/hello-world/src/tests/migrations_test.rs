@@ -0,0 +1,104 @@
+//! # Storage Migration Tests
+//!
+//! Covers the schema version of fresh deployments, attributing legacy
+//! aggregated positions to the per-asset ledger in batches, and access and
+//! batch size checks.
+
+#![cfg(test)]
+
+use crate::asset_positions::AssetPosition;
+use crate::migrations::{
+    MigrationsDataKey, MigrationsError, CURRENT_SCHEMA_VERSION, LEGACY_SCHEMA_VERSION,
+};
+use crate::testutils::setup_pool;
+use crate::{HelloContract, HelloContractClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, Vec};
+
+/// Contract whose storage reads as a deployment that predates versioning
+fn setup_legacy() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, admin) = setup_pool();
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .remove(&MigrationsDataKey::SchemaVersion);
+    });
+
+    (env, client, admin)
+}
+
+#[test]
+fn test_fresh_deployment_is_current() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(HelloContract, ());
+    let client = HelloContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    assert_eq!(client.schema_version(), CURRENT_SCHEMA_VERSION);
+    assert_eq!(
+        client.try_migrate(&admin, &Vec::new(&env)),
+        Err(Ok(MigrationsError::AlreadyCurrent))
+    );
+    assert_eq!(
+        client.try_complete_migration(&admin),
+        Err(Ok(MigrationsError::AlreadyCurrent))
+    );
+}
+
+#[test]
+fn test_legacy_positions_attributed_to_native_asset() {
+    let (env, client, admin) = setup_legacy();
+    assert_eq!(client.schema_version(), LEGACY_SCHEMA_VERSION);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let idle = Address::generate(&env);
+    client.test_set_position(&alice, &5_000, &2_000, &0);
+    client.test_set_position(&bob, &1_000, &0, &0);
+
+    assert_eq!(
+        client.migrate(&admin, &vec![&env, alice.clone(), bob.clone(), idle]),
+        2
+    );
+    assert_eq!(
+        client.get_asset_position(&alice, &None),
+        AssetPosition {
+            collateral: 5_000,
+            debt: 2_000,
+        }
+    );
+    assert_eq!(client.get_asset_position(&bob, &None).collateral, 1_000);
+
+    // Resubmitting a batch changes nothing
+    assert_eq!(client.migrate(&admin, &vec![&env, alice.clone()]), 0);
+    assert_eq!(client.get_asset_position(&alice, &None).collateral, 5_000);
+
+    assert_eq!(client.complete_migration(&admin), CURRENT_SCHEMA_VERSION);
+    assert_eq!(client.schema_version(), CURRENT_SCHEMA_VERSION);
+}
+
+#[test]
+fn test_migrate_access_and_batch_size() {
+    let (env, client, admin) = setup_legacy();
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_migrate(&stranger, &Vec::new(&env)),
+        Err(Ok(MigrationsError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_complete_migration(&stranger),
+        Err(Ok(MigrationsError::Unauthorized))
+    );
+
+    let mut users = Vec::new(&env);
+    for _ in 0..51 {
+        users.push_back(Address::generate(&env));
+    }
+    assert_eq!(
+        client.try_migrate(&admin, &users),
+        Err(Ok(MigrationsError::BatchTooLarge))
+    );
+}
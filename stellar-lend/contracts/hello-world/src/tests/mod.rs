@@ -62,3 +62,77 @@ pub mod oracle_staleness_fallback_test;
 pub mod recovery_test;
 pub mod fuzz_test;
 // pub mod fees_test;
+pub mod pol_test;
+pub mod emissions_test;
+pub mod gauge_test;
+pub mod fee_discount_test;
+pub mod liquidation_insurance_test;
+pub mod auto_compound_test;
+pub mod risk_engine_test;
+pub mod volatility_haircut_test;
+pub mod claims_test;
+pub mod signed_oracle_test;
+pub mod asset_oracle_config_test;
+pub mod conversion_test;
+pub mod strategy_test;
+pub mod credit_history_test;
+pub mod keeper_test;
+pub mod flash_guard_test;
+pub mod circuit_breaker_test;
+pub mod throttle_test;
+pub mod emergency_repay_test;
+pub mod accounting_test;
+pub mod donation_test;
+pub mod renounce_admin_test;
+pub mod risk_tier_test;
+pub mod pause_state_test;
+pub mod parameter_change_limit_test;
+pub mod user_deposit_cap_test;
+pub mod capitalization_test;
+pub mod testutils_test;
+pub mod hot_config_test;
+pub mod analytics_verbosity_test;
+pub mod revenue_test;
+pub mod pause_downtime_test;
+pub mod liquidation_hysteresis_test;
+pub mod migration_test;
+pub mod savings_test;
+pub mod depeg_test;
+pub mod account_health_test;
+pub mod liquidation_entrypoint_test;
+pub mod asset_positions_test;
+pub mod oracle_valuation_test;
+pub mod asset_rate_model_test;
+pub mod supply_shares_test;
+pub mod position_summary_test;
+pub mod reserve_factor_test;
+pub mod protocol_error_test;
+pub mod insurance_test;
+pub mod emode_test;
+pub mod deposit_and_borrow_test;
+pub mod repay_with_collateral_test;
+pub mod credit_delegation_test;
+pub mod liquidator_whitelist_test;
+pub mod roles_test;
+pub mod upgrade_test;
+pub mod migrations_test;
+pub mod ttl_test;
+pub mod max_amounts_test;
+pub mod withdraw_all_test;
+pub mod dust_test;
+pub mod liquidation_target_test;
+pub mod asset_pause_test;
+pub mod asset_freeze_test;
+pub mod asset_listing_test;
+pub mod asset_delisting_test;
+pub mod param_ramp_test;
+pub mod origination_fee_test;
+pub mod referral_test;
+pub mod liquidity_mining_test;
+pub mod loyalty_test;
+pub mod activity_test;
+pub mod dashboard_test;
+pub mod simulation_test;
+pub mod versioned_events_test;
+pub mod native_asset_test;
+pub mod token_transfer_test;
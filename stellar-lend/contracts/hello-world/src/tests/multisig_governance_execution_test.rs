@@ -907,7 +907,7 @@ fn test_multisig_with_different_proposal_types() {
         let proposal_types = [
             ProposalType::MinCollateralRatio(15_000),
             ProposalType::RiskParams(Some(15_000), Some(12_000), Some(5000), Some(800)),
-            ProposalType::PauseSwitch(Symbol::new(&env, "deposit"), true),
+            ProposalType::PauseSwitch(crate::risk_management::PauseOperation::Deposit, true),
             ProposalType::EmergencyPause(true),
         ];
 
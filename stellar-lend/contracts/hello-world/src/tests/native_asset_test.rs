@@ -0,0 +1,75 @@
+//! # Native Asset Tests
+//!
+//! Covers native XLM deposits and withdrawals moving funds through the native
+//! asset contract, and resolving that contract's address at initialization.
+
+#![cfg(test)]
+
+use crate::errors::ProtocolError;
+use crate::testutils::setup_pool;
+use crate::{HelloContract, HelloContractClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, admin) = setup_pool();
+    let native_asset = env.register_stellar_asset_contract(admin.clone());
+    client.set_native_asset_address(&admin, &native_asset);
+
+    (env, client, native_asset)
+}
+
+#[test]
+fn test_native_deposit_and_withdraw_move_xlm() {
+    let (env, client, native_asset) = setup();
+    let xlm = TokenClient::new(&env, &native_asset);
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &native_asset).mint(&user, &10_000);
+    xlm.approve(&user, &client.address, &10_000, &1_000_000);
+
+    client.deposit_collateral(&user, &None, &6_000);
+    assert_eq!(xlm.balance(&user), 4_000);
+    assert_eq!(xlm.balance(&client.address), 6_000);
+
+    client.withdraw_collateral(&user, &None, &2_500);
+    assert_eq!(xlm.balance(&user), 6_500);
+    assert_eq!(xlm.balance(&client.address), 3_500);
+}
+
+#[test]
+fn test_native_deposit_requires_balance() {
+    let (env, client, native_asset) = setup();
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &native_asset).mint(&user, &1_000);
+
+    assert_eq!(
+        client.try_deposit_collateral(&user, &None, &1_001),
+        Err(Ok(ProtocolError::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_init_resolves_native_asset_contract() {
+    let env = Env::default();
+    let contract_id = env.register(HelloContract, ());
+    env.as_contract(&contract_id, || {
+        assert_eq!(crate::deposit::get_native_asset_address(&env), None);
+
+        crate::deposit::init_native_asset_address(&env);
+        let native = crate::deposit::native_asset_contract_address(&env);
+        assert_eq!(crate::deposit::get_native_asset_address(&env), Some(native));
+    });
+}
+
+#[test]
+fn test_init_keeps_configured_native_asset() {
+    let (env, client, native_asset) = setup();
+    env.as_contract(&client.address, || {
+        crate::deposit::init_native_asset_address(&env);
+        assert_eq!(
+            crate::deposit::get_native_asset_address(&env),
+            Some(native_asset.clone())
+        );
+    });
+}
@@ -106,7 +106,7 @@ fn test_configure_oracle_success() {
 
 /// Test oracle configuration by unauthorized user should fail
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #200)")] // ProtocolError::Unauthorized
 fn test_configure_oracle_unauthorized() {
     let env = create_test_env();
     let (_contract_id, _admin, client) = setup_contract_with_admin(&env);
@@ -140,7 +140,7 @@ fn test_set_primary_oracle_success() {
 
 /// Test setting primary oracle by unauthorized user should fail
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #200)")] // ProtocolError::Unauthorized
 fn test_set_primary_oracle_unauthorized() {
     let env = create_test_env();
     let (_contract_id, _admin, client) = setup_contract_with_admin(&env);
@@ -168,7 +168,7 @@ fn test_set_fallback_oracle_success() {
 
 /// Test setting fallback oracle by unauthorized user should fail
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #200)")] // ProtocolError::Unauthorized
 fn test_set_fallback_oracle_unauthorized() {
     let env = create_test_env();
     let (_contract_id, _admin, client) = setup_contract_with_admin(&env);
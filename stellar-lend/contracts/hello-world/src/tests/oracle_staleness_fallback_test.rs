@@ -123,7 +123,7 @@ fn test_staleness_at_exact_threshold_is_valid() {
 
 /// Price one second past threshold is stale and rejected
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #218)")] // ProtocolError::StalePrice
 fn test_staleness_one_second_past_threshold_is_rejected() {
     let env = create_env();
     let (contract_id, admin, client) = setup(&env);
@@ -141,7 +141,7 @@ fn test_staleness_one_second_past_threshold_is_rejected() {
 
 /// Price with future timestamp (last_updated > current) is treated as stale
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #218)")] // ProtocolError::StalePrice
 fn test_staleness_future_timestamp_rejected() {
     let env = create_env();
     let (contract_id, _admin, client) = setup(&env);
@@ -158,7 +158,7 @@ fn test_staleness_future_timestamp_rejected() {
 
 /// Stale price is rejected even when cache has just expired
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #218)")] // ProtocolError::StalePrice
 fn test_stale_price_after_cache_expires() {
     let env = create_env();
     let (contract_id, admin, client) = setup(&env);
@@ -176,7 +176,7 @@ fn test_stale_price_after_cache_expires() {
 
 /// Custom staleness threshold is respected
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #218)")] // ProtocolError::StalePrice
 fn test_custom_staleness_threshold_respected() {
     let env = create_env();
     let (contract_id, admin, client) = setup(&env);
@@ -294,7 +294,7 @@ fn test_fallback_used_when_primary_stale() {
 
 /// Fallback that is itself stale is also rejected
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #218)")] // ProtocolError::StalePrice
 fn test_stale_fallback_is_also_rejected() {
     let env = create_env();
     let (contract_id, admin, client) = setup(&env);
@@ -320,7 +320,7 @@ fn test_stale_fallback_is_also_rejected() {
 
 /// No fallback configured + stale primary = error
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #218)")] // ProtocolError::StalePrice
 fn test_no_fallback_stale_primary_returns_error() {
     let env = create_env();
     let (contract_id, admin, client) = setup(&env);
@@ -337,7 +337,7 @@ fn test_no_fallback_stale_primary_returns_error() {
 
 /// Fallback from wrong oracle address is rejected
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #221)")] // ProtocolError::PriceNotAvailable
 fn test_fallback_wrong_oracle_rejected() {
     let env = create_env();
     let (contract_id, admin, client) = setup(&env);
@@ -413,7 +413,7 @@ fn test_borrow_succeeds_with_fresh_price() {
 
 /// Getting price for asset with no feed returns error
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #221)")] // ProtocolError::PriceNotAvailable
 fn test_missing_feed_no_fallback_returns_error() {
     let env = create_env();
     let (_contract_id, _admin, client) = setup(&env);
@@ -478,7 +478,7 @@ fn test_multiple_assets_independent_staleness() {
 
 /// Multiple assets — stale asset panics, fresh asset still works
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #218)")] // ProtocolError::StalePrice
 fn test_stale_asset_panics_independently() {
     let env = create_env();
     let (contract_id, admin, client) = setup(&env);
@@ -513,7 +513,7 @@ fn test_freshness_boundary_exactly_at_limit_valid() {
 
 /// Freshness boundary: price updated at t=100, read at t=3701 (age=3601, one past limit)
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #218)")] // ProtocolError::StalePrice
 fn test_freshness_boundary_one_past_limit_rejected() {
     let env = create_env();
     let (contract_id, admin, client) = setup(&env);
@@ -151,7 +151,7 @@ fn test_update_price_feed_by_oracle() {
 
 /// Test zero price rejection
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #217)")] // ProtocolError::InvalidPrice
 fn test_update_price_feed_zero_price() {
     let env = create_test_env();
     let (_contract_id, admin, client) = setup_contract_with_admin(&env);
@@ -163,7 +163,7 @@ fn test_update_price_feed_zero_price() {
 
 /// Test negative price rejection
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #217)")] // ProtocolError::InvalidPrice
 fn test_update_price_feed_negative_price() {
     let env = create_test_env();
     let (_contract_id, admin, client) = setup_contract_with_admin(&env);
@@ -175,7 +175,7 @@ fn test_update_price_feed_negative_price() {
 
 /// Test unauthorized caller rejection
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #200)")] // ProtocolError::Unauthorized
 fn test_update_price_feed_unauthorized() {
     let env = create_test_env();
     let (_contract_id, _admin, client) = setup_contract_with_admin(&env);
@@ -188,7 +188,7 @@ fn test_update_price_feed_unauthorized() {
 
 /// Test malicious update where caller passes themselves as oracle
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #200)")] // ProtocolError::Unauthorized
 fn test_update_price_feed_malicious_caller() {
     let env = create_test_env();
     let (_contract_id, admin, client) = setup_contract_with_admin(&env);
@@ -230,7 +230,7 @@ fn test_price_deviation_within_range() {
 
 /// Test price deviation exceeds maximum (should fail)
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #219)")] // ProtocolError::PriceDeviationExceeded
 fn test_price_deviation_exceeds_maximum() {
     let env = create_test_env();
     let (_contract_id, admin, client) = setup_contract_with_admin(&env);
@@ -347,7 +347,7 @@ fn test_set_fallback_oracle() {
 
 /// Test setting fallback oracle unauthorized
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #200)")] // ProtocolError::Unauthorized
 fn test_set_fallback_oracle_unauthorized() {
     let env = create_test_env();
     let (_contract_id, _admin, client) = setup_contract_with_admin(&env);
@@ -360,7 +360,7 @@ fn test_set_fallback_oracle_unauthorized() {
 
 /// Test fallback oracle cannot be contract itself
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #220)")] // ProtocolError::InvalidOracle
 fn test_set_fallback_oracle_self() {
     let env = create_test_env();
     let (contract_id, admin, client) = setup_contract_with_admin(&env);
@@ -394,7 +394,7 @@ fn test_configure_oracle() {
 
 /// Test configure oracle unauthorized
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #200)")] // ProtocolError::Unauthorized
 fn test_configure_oracle_unauthorized() {
     let env = create_test_env();
     let (_contract_id, _admin, client) = setup_contract_with_admin(&env);
@@ -413,7 +413,7 @@ fn test_configure_oracle_unauthorized() {
 
 /// Test invalid deviation configuration (zero)
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #217)")] // ProtocolError::InvalidPrice
 fn test_configure_oracle_invalid_deviation_zero() {
     let env = create_test_env();
     let (_contract_id, admin, client) = setup_contract_with_admin(&env);
@@ -431,7 +431,7 @@ fn test_configure_oracle_invalid_deviation_zero() {
 
 /// Test invalid deviation configuration (too high)
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #217)")] // ProtocolError::InvalidPrice
 fn test_configure_oracle_invalid_deviation_too_high() {
     let env = create_test_env();
     let (_contract_id, admin, client) = setup_contract_with_admin(&env);
@@ -449,7 +449,7 @@ fn test_configure_oracle_invalid_deviation_too_high() {
 
 /// Test invalid staleness configuration (zero)
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #217)")] // ProtocolError::InvalidPrice
 fn test_configure_oracle_invalid_staleness_zero() {
     let env = create_test_env();
     let (_contract_id, admin, client) = setup_contract_with_admin(&env);
@@ -471,7 +471,7 @@ fn test_configure_oracle_invalid_staleness_zero() {
 
 /// Test oracle updates when paused
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #208)")] // ProtocolError::OperationPaused
 fn test_update_price_feed_when_paused() {
     let env = create_test_env();
     let (contract_id, admin, client) = setup_contract_with_admin(&env);
@@ -509,7 +509,7 @@ fn test_update_price_feed_after_unpause() {
 
 /// Test price retrieval with stale primary feed and no fallback
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #218)")] // ProtocolError::StalePrice
 fn test_get_price_stale_no_fallback() {
     let env = create_test_env();
     let (contract_id, admin, client) = setup_contract_with_admin(&env);
@@ -576,7 +576,7 @@ fn test_get_price_with_successful_fallback() {
 
 /// Test retrieval fails when both primary and fallback are stale
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #218)")] // ProtocolError::StalePrice
 fn test_get_price_both_stale() {
     let env = create_test_env();
     let (_contract_id, admin, client) = setup_contract_with_admin(&env);
@@ -732,7 +732,7 @@ fn test_same_timestamp_update() {
 
 /// Test price retrieval for non-existent asset
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #221)")] // ProtocolError::PriceNotAvailable
 fn test_get_price_nonexistent_asset() {
     let env = create_test_env();
     let (_contract_id, _admin, client) = setup_contract_with_admin(&env);
@@ -771,7 +771,7 @@ fn test_price_bounds_enforcement() {
 
 /// Test price below minimum bound is rejected
 #[test]
-#[should_panic(expected = "Oracle error")]
+#[should_panic(expected = "Error(Contract, #217)")] // ProtocolError::InvalidPrice
 fn test_price_below_minimum_bound() {
     let env = create_test_env();
     let (_contract_id, admin, client) = setup_contract_with_admin(&env);
@@ -0,0 +1,170 @@
+//! # Oracle Valuation Tests
+//!
+//! Covers price-aware ratio math: borrowing one asset against another at
+//! their oracle prices, withdrawals checked by value, health reflecting price
+//! moves, liquidations flagging what is left by value, and stale prices
+//! blocking borrows instead of falling back to 1:1.
+
+#![cfg(test)]
+
+use crate::errors::ProtocolError;
+use crate::oracle::OracleError;
+use crate::risk_params::is_liquidation_flagged;
+use crate::testutils::{setup_pool, supply};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, Vec};
+
+/// 1.0 with 7 price decimals
+const ONE: i128 = 10_000_000;
+
+struct Setup {
+    env: Env,
+    client: HelloContractClient<'static>,
+    admin: Address,
+    user: Address,
+    collateral_asset: Address,
+    debt_asset: Address,
+}
+
+/// User holding 1_000 of a collateral asset priced at 2.0; the debt asset is
+/// priced at 1.0, with 10_000 of it lendable in the pool
+fn setup() -> Setup {
+    let (env, client, admin) = setup_pool();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let collateral_asset = env.register_stellar_asset_contract(admin.clone());
+    let debt_asset = env.register_stellar_asset_contract(admin.clone());
+    supply(&env, &client, &Address::generate(&env), &debt_asset, 10_000);
+    client.update_price_feed(&admin, &collateral_asset, &(2 * ONE), &7, &admin);
+    client.update_price_feed(&admin, &debt_asset, &ONE, &7, &admin);
+
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &collateral_asset).mint(&user, &1_000);
+    TokenClient::new(&env, &collateral_asset).approve(&user, &client.address, &1_000, &1_000);
+    client.deposit_collateral(&user, &Some(collateral_asset.clone()), &1_000);
+
+    Setup {
+        env,
+        client,
+        admin,
+        user,
+        collateral_asset,
+        debt_asset,
+    }
+}
+
+fn health(s: &Setup) -> crate::analytics::AccountHealth {
+    let mut users = Vec::new(&s.env);
+    users.push_back(s.user.clone());
+    s.client.get_account_health_batch(&users).get(0).unwrap()
+}
+
+#[test]
+fn test_borrow_capacity_uses_collateral_price() {
+    let s = setup();
+
+    // 1_000 collateral at 2.0 supports 2_000 / 110% = 1_818 of debt at 1.0
+    s.client
+        .borrow_asset(&s.user, &Some(s.debt_asset.clone()), &1_800);
+    assert_eq!(
+        s.client
+            .try_borrow_asset(&s.user, &Some(s.debt_asset.clone()), &100),
+        Err(Ok(ProtocolError::MaxBorrowExceeded))
+    );
+
+    let health = health(&s);
+    assert_eq!(health.collateral_value, 2_000);
+    assert_eq!(health.debt_value, 1_800);
+}
+
+#[test]
+fn test_withdraw_checked_by_value() {
+    let s = setup();
+    s.client
+        .borrow_asset(&s.user, &Some(s.debt_asset.clone()), &1_800);
+
+    // 1_800 of debt needs 1_980 of collateral value at 110%
+    s.client
+        .withdraw_collateral(&s.user, &Some(s.collateral_asset.clone()), &5);
+    assert_eq!(
+        s.client
+            .try_withdraw_collateral(&s.user, &Some(s.collateral_asset.clone()), &10),
+        Err(Ok(ProtocolError::InsufficientCollateralRatio))
+    );
+}
+
+#[test]
+fn test_collateral_price_drop_makes_position_liquidatable() {
+    let s = setup();
+    s.client
+        .borrow_asset(&s.user, &Some(s.debt_asset.clone()), &1_800);
+
+    s.client
+        .update_price_feed(&s.admin, &s.collateral_asset, &19_100_000, &7, &s.admin);
+    let health = health(&s);
+    assert_eq!(health.collateral_value, 1_910);
+    assert!(!health.is_liquidatable);
+
+    s.client
+        .update_price_feed(&s.admin, &s.collateral_asset, &18_200_000, &7, &s.admin);
+    let health = health(&s);
+    assert_eq!(health.collateral_value, 1_820);
+    assert!(health.is_liquidatable);
+}
+
+#[test]
+fn test_liquidation_flags_remaining_position_by_value() {
+    let s = setup();
+    // A debt asset priced at 4.0, so debt units are worth more than collateral units
+    let pricey_asset = s.env.register_stellar_asset_contract(s.admin.clone());
+    let supplier = Address::generate(&s.env);
+    supply(&s.env, &s.client, &supplier, &pricey_asset, 1_000);
+    s.client
+        .update_price_feed(&s.admin, &pricey_asset, &(4 * ONE), &7, &s.admin);
+    s.client
+        .borrow_asset(&s.user, &Some(pricey_asset.clone()), &450);
+    s.client
+        .update_price_feed(&s.admin, &s.collateral_asset, &19_100_000, &7, &s.admin);
+    s.client
+        .update_price_feed(&s.admin, &s.collateral_asset, &18_200_000, &7, &s.admin);
+
+    let liquidator = Address::generate(&s.env);
+    StellarAssetClient::new(&s.env, &pricey_asset).mint(&liquidator, &25);
+    TokenClient::new(&s.env, &pricey_asset).approve(&liquidator, &s.client.address, &25, &1_000);
+    s.client.liquidate(
+        &liquidator,
+        &s.user,
+        &Some(pricey_asset.clone()),
+        &Some(s.collateral_asset.clone()),
+        &25,
+    );
+
+    // 940 units of collateral outnumber the 425 of debt, but are worth only
+    // 100.5% of it
+    let health = health(&s);
+    assert_eq!(health.collateral_value, 1_710);
+    assert_eq!(health.debt_value, 1_700);
+    let flagged = s.env.as_contract(&s.client.address, || {
+        is_liquidation_flagged(&s.env, &s.user)
+    });
+    assert!(flagged);
+}
+
+#[test]
+fn test_stale_price_blocks_borrow() {
+    let s = setup();
+    assert_eq!(s.client.get_asset_price(&s.debt_asset), ONE);
+
+    s.env.ledger().with_mut(|li| li.timestamp += 3_601);
+    assert_eq!(
+        s.client.try_get_asset_price(&s.debt_asset),
+        Err(Ok(OracleError::StalePrice))
+    );
+    assert_eq!(
+        s.client
+            .try_borrow_asset(&s.user, &Some(s.debt_asset.clone()), &100),
+        Err(Ok(ProtocolError::PriceNotAvailable))
+    );
+}
@@ -0,0 +1,109 @@
+//! # Origination Fee Tests
+//!
+//! Covers the protocol origination fee: the governed rate, its deduction from
+//! the amount received, the insurance/treasury split returned by
+//! `borrow_asset_with_receipt`, and the `fees` section of the protocol report.
+
+#![cfg(test)]
+
+use crate::borrow::BorrowReceipt;
+use crate::errors::GovernanceError;
+use crate::origination_fee::{OriginationFeeError, MAX_ORIGINATION_FEE_BPS};
+use crate::testutils::{execute_governance_proposal, setup_governance, setup_pool};
+use crate::types::ProposalType;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, Env, String};
+
+fn set_fee(env: &Env, client: &HelloContractClient, fee_bps: i128) {
+    env.as_contract(&client.address, || {
+        crate::origination_fee::set_origination_fee_bps(env, fee_bps).unwrap();
+    });
+}
+
+#[test]
+fn test_set_origination_fee_bounds() {
+    let (env, client, _admin) = setup_pool();
+    assert_eq!(client.get_origination_fee_bps(), 0);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            crate::origination_fee::set_origination_fee_bps(&env, MAX_ORIGINATION_FEE_BPS + 1),
+            Err(OriginationFeeError::InvalidFee)
+        );
+        assert_eq!(
+            crate::origination_fee::set_origination_fee_bps(&env, -1),
+            Err(OriginationFeeError::InvalidFee)
+        );
+    });
+
+    set_fee(&env, &client, 50);
+    assert_eq!(client.get_origination_fee_bps(), 50);
+}
+
+#[test]
+fn test_borrow_receipt_splits_fee() {
+    let (env, client, _admin) = setup_pool();
+    let user = Address::generate(&env);
+    set_fee(&env, &client, 100);
+    env.as_contract(&client.address, || {
+        crate::insurance::set_insurance_fee_share(&env, 2_500).unwrap();
+    });
+    client.deposit_collateral(&user, &None, &10_000);
+
+    let receipt = client.borrow_asset_with_receipt(&user, &None, &2_000);
+    assert_eq!(
+        receipt,
+        BorrowReceipt {
+            total_debt: 2_000,
+            amount_received: 1_980,
+            origination_fee: 20,
+            insurance_share: 5,
+            referral_share: 0,
+            treasury_share: 15,
+        }
+    );
+    assert_eq!(client.get_reserve_balance(&None), 15);
+    assert_eq!(client.get_insurance_fund_balance(&None), 5);
+
+    let fees = client.get_protocol_report().fees;
+    assert_eq!(fees.origination_fees, 20);
+    assert_eq!(fees.insurance_share, 5);
+    assert_eq!(fees.treasury_share, 15);
+}
+
+#[test]
+fn test_no_fee_by_default() {
+    let (env, client, _admin) = setup_pool();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+
+    let receipt = client.borrow_asset_with_receipt(&user, &None, &1_000);
+    assert_eq!(receipt.amount_received, 1_000);
+    assert_eq!(receipt.origination_fee, 0);
+    assert_eq!(client.get_protocol_report().fees.origination_fees, 0);
+}
+
+#[test]
+fn test_origination_fee_via_governance() {
+    let (env, client, admin) = setup_pool();
+    let gov_token = setup_governance(&env, &client, &admin);
+    let voter = Address::generate(&env);
+    StellarAssetClient::new(&env, &gov_token).mint(&voter, &1_000);
+    client.gov_stake(&voter, &1_000);
+
+    assert_eq!(
+        client.try_gov_create_proposal(
+            &voter,
+            &ProposalType::OriginationFee(MAX_ORIGINATION_FEE_BPS + 1),
+            &String::from_str(&env, "Fee too high"),
+            &None,
+        ),
+        Err(Ok(GovernanceError::InvalidProposal))
+    );
+
+    execute_governance_proposal(&env, &client, &ProposalType::OriginationFee(30));
+
+    assert_eq!(client.get_origination_fee_bps(), 30);
+}
@@ -0,0 +1,133 @@
+//! # Parameter Ramp Tests
+//!
+//! Covers gradual collateral factor and liquidation threshold changes: linear
+//! interpolation over the governed duration, restarting from the current
+//! value mid-ramp, and immediate changes when no duration is configured.
+
+#![cfg(test)]
+
+use crate::deposit::AssetParams;
+use crate::errors::GovernanceError;
+use crate::param_ramp::{RampedParam, MAX_RAMP_DURATION};
+use crate::testutils::{execute_governance_proposal, setup_governance, setup_pool};
+use crate::types::ProposalType;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, Env, String};
+
+fn set_duration(env: &Env, client: &HelloContractClient, duration: u64) {
+    env.as_contract(&client.address, || {
+        crate::param_ramp::set_ramp_duration(env, duration).unwrap();
+    });
+}
+
+fn params(collateral_factor: i128) -> AssetParams {
+    AssetParams {
+        deposit_enabled: true,
+        collateral_factor,
+        max_deposit: 0,
+        borrow_fee_bps: 0,
+    }
+}
+
+#[test]
+fn test_liquidation_threshold_ramps_linearly() {
+    let (env, client, admin) = setup_pool();
+    set_duration(&env, &client, 1_000);
+    let start = env.ledger().timestamp();
+
+    client.set_risk_params(&admin, &None, &Some(11_000), &None, &None);
+    assert_eq!(client.get_liquidation_threshold(), 10_500);
+    let ramp = client
+        .get_param_ramp(&RampedParam::LiquidationThreshold)
+        .unwrap();
+    assert_eq!((ramp.from, ramp.to, ramp.duration), (10_500, 11_000, 1_000));
+
+    env.ledger().with_mut(|li| li.timestamp = start + 500);
+    assert_eq!(client.get_liquidation_threshold(), 10_750);
+
+    env.ledger().with_mut(|li| li.timestamp = start + 1_000);
+    assert_eq!(client.get_liquidation_threshold(), 11_000);
+    assert_eq!(
+        client.get_param_ramp(&RampedParam::LiquidationThreshold),
+        None
+    );
+}
+
+#[test]
+fn test_change_without_duration_is_immediate() {
+    let (_env, client, admin) = setup_pool();
+    assert_eq!(client.get_param_ramp_duration(), 0);
+
+    client.set_risk_params(&admin, &None, &Some(11_000), &None, &None);
+    assert_eq!(client.get_liquidation_threshold(), 11_000);
+    assert_eq!(
+        client.get_param_ramp(&RampedParam::LiquidationThreshold),
+        None
+    );
+}
+
+#[test]
+fn test_collateral_factor_ramp_restarts_from_current_value() {
+    let (env, client, _admin) = setup_pool();
+    let asset = Address::generate(&env);
+    set_duration(&env, &client, 1_000);
+    let start = env.ledger().timestamp();
+
+    let effective = || {
+        env.as_contract(&client.address, || {
+            let configured = crate::deposit::get_asset_params(&env, asset.clone())
+                .unwrap()
+                .collateral_factor;
+            crate::risk_params::effective_collateral_factor(&env, Some(&asset), configured)
+        })
+    };
+    let set_cf = |cf: i128| {
+        env.as_contract(&client.address, || {
+            crate::deposit::set_asset_params(&env, asset.clone(), params(cf)).unwrap();
+        });
+    };
+
+    // A first listing applies at once
+    set_cf(8_000);
+    assert_eq!(effective(), 8_000);
+
+    set_cf(6_000);
+    env.ledger().with_mut(|li| li.timestamp = start + 500);
+    assert_eq!(effective(), 7_000);
+
+    // Changing again mid-ramp starts from the value in force
+    set_cf(5_000);
+    let ramp = client
+        .get_param_ramp(&RampedParam::CollateralFactor(asset.clone()))
+        .unwrap();
+    assert_eq!((ramp.from, ramp.to), (7_000, 5_000));
+    env.ledger().with_mut(|li| li.timestamp = start + 1_000);
+    assert_eq!(effective(), 6_000);
+    env.ledger().with_mut(|li| li.timestamp = start + 1_500);
+    assert_eq!(effective(), 5_000);
+}
+
+#[test]
+fn test_ramp_duration_via_governance() {
+    let (env, client, admin) = setup_pool();
+    let gov_token = setup_governance(&env, &client, &admin);
+    let voter = Address::generate(&env);
+    StellarAssetClient::new(&env, &gov_token).mint(&voter, &1_000);
+    client.gov_stake(&voter, &1_000);
+
+    assert_eq!(
+        client.try_gov_create_proposal(
+            &voter,
+            &ProposalType::ParameterRampDuration(MAX_RAMP_DURATION + 1),
+            &String::from_str(&env, "Ramp too long"),
+            &None,
+        ),
+        Err(Ok(GovernanceError::InvalidProposal))
+    );
+
+    execute_governance_proposal(&env, &client, &ProposalType::ParameterRampDuration(86_400));
+
+    assert_eq!(client.get_param_ramp_duration(), 86_400);
+}
@@ -0,0 +1,59 @@
+//! # Parameter Change Limit Tests
+//!
+//! Covers the governed per-update change limit on risk parameters and the
+//! governance bypass of that limit.
+
+#![cfg(test)]
+
+use crate::risk_management::RiskManagementError;
+use crate::risk_params::{self, RiskParamsError};
+use crate::testutils::setup_pool;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+#[test]
+fn test_change_limit_is_governed() {
+    let (env, client, admin) = setup_pool();
+    assert_eq!(client.get_max_parameter_change_bps(), 1_000);
+
+    // 50% -> 70% close factor is a 40% step
+    assert_eq!(
+        client.try_set_risk_params(&admin, &None, &None, &Some(7_000), &None),
+        Err(Ok(RiskManagementError::ParameterChangeTooLarge))
+    );
+
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            risk_params::set_max_parameter_change_bps(&env, 99),
+            Err(RiskParamsError::InvalidParameter)
+        );
+        assert_eq!(
+            risk_params::set_max_parameter_change_bps(&env, 5_001),
+            Err(RiskParamsError::InvalidParameter)
+        );
+        risk_params::set_max_parameter_change_bps(&env, 5_000).unwrap();
+    });
+    assert_eq!(client.get_max_parameter_change_bps(), 5_000);
+
+    client.set_risk_params(&admin, &None, &None, &Some(7_000), &None);
+    assert_eq!(client.get_close_factor(), 7_000);
+}
+
+#[test]
+fn test_governed_update_bypasses_change_limit() {
+    let (env, client, _admin) = setup_pool();
+
+    env.as_contract(&client.address, || {
+        risk_params::set_risk_params_governed(&env, Some(20_000), None, Some(10_000), None)
+            .unwrap();
+
+        // Range validation still applies
+        assert_eq!(
+            risk_params::set_risk_params_governed(&env, None, None, Some(10_001), None),
+            Err(RiskParamsError::InvalidCloseFactor)
+        );
+    });
+
+    assert_eq!(client.get_min_collateral_ratio(), 20_000);
+    assert_eq!(client.get_close_factor(), 10_000);
+}
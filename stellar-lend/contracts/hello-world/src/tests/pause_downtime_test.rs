@@ -0,0 +1,99 @@
+//! # Pause Downtime Tests
+//!
+//! Covers the activation/clear timestamps and accumulated paused time kept
+//! for each pause switch and the emergency pause.
+
+#![cfg(test)]
+
+use crate::pause_downtime;
+use crate::risk_management::PauseOperation;
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, admin) = setup_pool();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    (env, client, admin)
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+#[test]
+fn test_switch_downtime_accumulates() {
+    let (env, client, admin) = setup();
+    let fresh = client.get_pause_downtime(&PauseOperation::Borrow);
+    assert_eq!(fresh.paused_since, None);
+    assert_eq!(fresh.total_paused, 0);
+
+    client.set_pause_switch(&admin, &PauseOperation::Borrow, &true);
+    advance(&env, 100);
+
+    // Ongoing pause is included in the view
+    let ongoing = client.get_pause_downtime(&PauseOperation::Borrow);
+    assert_eq!(ongoing.paused_since, Some(1_000));
+    assert_eq!(ongoing.last_paused_at, 1_000);
+    assert_eq!(ongoing.total_paused, 100);
+
+    client.set_pause_switch(&admin, &PauseOperation::Borrow, &false);
+    advance(&env, 50);
+    client.set_pause_switch(&admin, &PauseOperation::Borrow, &true);
+    advance(&env, 30);
+    client.set_pause_switch(&admin, &PauseOperation::Borrow, &false);
+
+    let downtime = client.get_pause_downtime(&PauseOperation::Borrow);
+    assert_eq!(downtime.paused_since, None);
+    assert_eq!(downtime.last_paused_at, 1_150);
+    assert_eq!(downtime.last_cleared_at, 1_180);
+    assert_eq!(downtime.total_paused, 130);
+    assert_eq!(client.get_pause_downtime(&PauseOperation::Deposit).total_paused, 0);
+}
+
+#[test]
+fn test_repeated_pause_keeps_original_start() {
+    let (env, client, admin) = setup();
+    client.set_pause_switch(&admin, &PauseOperation::Deposit, &true);
+    advance(&env, 40);
+    client.set_pause_switch(&admin, &PauseOperation::Deposit, &true);
+    advance(&env, 60);
+    client.set_pause_switch(&admin, &PauseOperation::Deposit, &false);
+
+    let downtime = client.get_pause_downtime(&PauseOperation::Deposit);
+    assert_eq!(downtime.last_paused_at, 1_000);
+    assert_eq!(downtime.total_paused, 100);
+}
+
+#[test]
+fn test_pause_all_records_every_switch() {
+    let (env, client, admin) = setup();
+    client.set_pause_switch(&admin, &PauseOperation::All, &true);
+    advance(&env, 25);
+    client.set_pause_switch(&admin, &PauseOperation::All, &false);
+
+    assert_eq!(client.get_pause_downtime(&PauseOperation::Repay).total_paused, 25);
+    assert_eq!(client.get_pause_downtime(&PauseOperation::Oracle).total_paused, 25);
+}
+
+#[test]
+fn test_emergency_downtime_counts_toward_operations() {
+    let (env, client, admin) = setup();
+    client.set_emergency_pause(&admin, &true);
+    advance(&env, 200);
+    client.set_emergency_pause(&admin, &false);
+    client.set_pause_switch(&admin, &PauseOperation::Withdraw, &true);
+    advance(&env, 10);
+
+    let emergency = client.get_emergency_pause_downtime();
+    assert_eq!(emergency.last_paused_at, 1_000);
+    assert_eq!(emergency.last_cleared_at, 1_200);
+    assert_eq!(emergency.total_paused, 200);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(pause_downtime::total_paused(&env, PauseOperation::Withdraw), 210);
+        assert_eq!(pause_downtime::total_paused(&env, PauseOperation::Borrow), 200);
+    });
+}
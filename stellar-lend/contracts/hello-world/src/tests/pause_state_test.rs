@@ -0,0 +1,70 @@
+//! # Pause State Tests
+//!
+//! Covers typed pause switches and the aggregated `get_pause_state` view.
+
+#![cfg(test)]
+
+use crate::risk_management::{PauseOperation, PauseState, RiskManagementError};
+use crate::testutils::setup_pool;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Map};
+
+#[test]
+fn test_pause_state_reflects_switches() {
+    let (_env, client, admin) = setup_pool();
+    let unpaused = PauseState {
+        deposit: false,
+        withdraw: false,
+        borrow: false,
+        repay: false,
+        liquidate: false,
+        flash_loan: false,
+        oracle: false,
+        emergency: false,
+    };
+    assert_eq!(client.get_pause_state(), unpaused);
+
+    client.set_pause_switch(&admin, &PauseOperation::Borrow, &true);
+    client.set_pause_switch(&admin, &PauseOperation::FlashLoan, &true);
+    client.set_emergency_pause(&admin, &true);
+
+    let state = client.get_pause_state();
+    assert!(state.borrow && state.flash_loan && state.emergency);
+    assert!(!state.deposit && !state.withdraw && !state.oracle);
+    assert!(client.is_operation_paused(&PauseOperation::Borrow));
+    assert!(!client.is_operation_paused(&PauseOperation::All));
+}
+
+#[test]
+fn test_pause_all_sets_every_switch() {
+    let (_env, client, admin) = setup_pool();
+    client.set_pause_switch(&admin, &PauseOperation::All, &true);
+    assert!(client.is_operation_paused(&PauseOperation::All));
+
+    let state = client.get_pause_state();
+    assert!(state.deposit && state.withdraw && state.borrow && state.repay);
+    assert!(state.liquidate && state.flash_loan && state.oracle);
+    assert!(!state.emergency);
+
+    client.set_pause_switch(&admin, &PauseOperation::All, &false);
+    assert!(!client.is_operation_paused(&PauseOperation::Deposit));
+}
+
+#[test]
+fn test_pause_switches_batch_and_auth() {
+    let (env, client, admin) = setup_pool();
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_pause_switch(&stranger, &PauseOperation::Deposit, &true),
+        Err(Ok(RiskManagementError::Unauthorized))
+    );
+
+    env.as_contract(&client.address, || {
+        let mut switches = Map::new(&env);
+        switches.set(PauseOperation::Withdraw, true);
+        switches.set(PauseOperation::Repay, true);
+        crate::risk_management::set_pause_switches(&env, admin.clone(), switches).unwrap();
+    });
+    let state = client.get_pause_state();
+    assert!(state.withdraw && state.repay && !state.deposit);
+}
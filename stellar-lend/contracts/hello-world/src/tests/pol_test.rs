@@ -0,0 +1,120 @@
+//! # Protocol-Owned Liquidity Tests
+//!
+//! Covers seeding accrued reserves into the pool through governance and the
+//! separate POL accounting exposed by analytics.
+
+#![cfg(test)]
+
+use crate::reserve::{
+    get_protocol_owned_liquidity, get_protocol_owned_liquidity_assets,
+    get_protocol_owned_liquidity_value, get_reserve_balance, seed_protocol_liquidity,
+    ReserveDataKey, ReserveError,
+};
+use crate::testutils::{execute_governance_proposal, setup_governance, setup_pool};
+use crate::types::{ProposalStatus, ProposalType};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, Address, HelloContractClient<'static>) {
+    let (env, client, admin) = setup_pool();
+    setup_governance(&env, &client, &admin);
+    (env, admin, client)
+}
+
+fn fund_reserve(env: &Env, contract_id: &Address, asset: Option<Address>, amount: i128) {
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&ReserveDataKey::ReserveBalance(asset), &amount);
+    });
+}
+
+#[test]
+fn test_seed_protocol_liquidity_moves_reserves() {
+    let (env, _admin, client) = setup();
+    let asset = Some(Address::generate(&env));
+    fund_reserve(&env, &client.address, asset.clone(), 10_000);
+
+    let pol = env
+        .as_contract(&client.address, || {
+            seed_protocol_liquidity(&env, asset.clone(), 4_000)
+        })
+        .unwrap();
+    assert_eq!(pol, 4_000);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(get_reserve_balance(&env, asset.clone()), 6_000);
+        assert_eq!(get_protocol_owned_liquidity(&env, asset.clone()), 4_000);
+        assert_eq!(get_protocol_owned_liquidity_value(&env), Ok(4_000));
+    });
+}
+
+#[test]
+fn test_pol_value_prices_each_asset() {
+    let (env, admin, client) = setup();
+    let cheap = Some(Address::generate(&env));
+    let dear = Some(Address::generate(&env));
+    fund_reserve(&env, &client.address, cheap.clone(), 1_000);
+    fund_reserve(&env, &client.address, dear.clone(), 1_000);
+    // 1 unit of `dear` is worth 3 units of the reference asset
+    client.update_price_feed(&admin, dear.as_ref().unwrap(), &30_000_000, &7, &admin);
+
+    env.as_contract(&client.address, || {
+        seed_protocol_liquidity(&env, cheap.clone(), 1_000).unwrap();
+        seed_protocol_liquidity(&env, dear.clone(), 500).unwrap();
+        seed_protocol_liquidity(&env, dear.clone(), 500).unwrap();
+
+        assert_eq!(get_protocol_owned_liquidity_assets(&env).len(), 2);
+        assert_eq!(get_protocol_owned_liquidity_value(&env), Ok(4_000));
+    });
+    assert_eq!(
+        client.get_protocol_analytics().protocol_owned_liquidity,
+        4_000
+    );
+}
+
+#[test]
+fn test_seed_protocol_liquidity_exceeding_reserve_fails() {
+    let (env, _admin, client) = setup();
+    let asset = Some(Address::generate(&env));
+    fund_reserve(&env, &client.address, asset.clone(), 1_000);
+
+    let result = env.as_contract(&client.address, || {
+        seed_protocol_liquidity(&env, asset.clone(), 1_001)
+    });
+    assert_eq!(result, Err(ReserveError::InsufficientReserve));
+}
+
+#[test]
+fn test_seed_protocol_liquidity_zero_amount_fails() {
+    let (env, _admin, client) = setup();
+    let result = env.as_contract(&client.address, || {
+        seed_protocol_liquidity(&env, None, 0)
+    });
+    assert_eq!(result, Err(ReserveError::InvalidAmount));
+}
+
+#[test]
+fn test_seed_via_governance_tracks_pol_separately() {
+    let (env, _admin, client) = setup();
+    let asset = Some(Address::generate(&env));
+    fund_reserve(&env, &client.address, asset.clone(), 5_000);
+
+    let id = execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::SeedProtocolLiquidity(asset.clone(), 2_500),
+    );
+
+    let p = client.gov_get_proposal(&id).unwrap();
+    assert_eq!(p.status, ProposalStatus::Executed);
+    assert_eq!(client.get_protocol_owned_liquidity(&asset), 2_500);
+
+    let metrics = client.get_protocol_analytics();
+    assert_eq!(metrics.protocol_owned_liquidity, 2_500);
+    assert_eq!(
+        metrics.user_value_locked + metrics.protocol_owned_liquidity,
+        metrics.total_value_locked
+    );
+}
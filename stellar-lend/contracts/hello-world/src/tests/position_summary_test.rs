@@ -0,0 +1,83 @@
+//! # Position Summary Tests
+//!
+//! Covers `get_position_summary`: empty accounts, health and headroom that
+//! match what borrow and withdraw actually allow, and accrued interest.
+
+#![cfg(test)]
+
+use crate::analytics::PositionSummary;
+use crate::errors::ProtocolError;
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>) {
+    let (env, client, _admin) = setup_pool();
+    (env, client)
+}
+
+#[test]
+fn test_summary_without_position() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.get_position_summary(&user),
+        PositionSummary {
+            user,
+            collateral_value: 0,
+            debt_value: 0,
+            accrued_interest: 0,
+            health_factor: i128::MAX,
+            max_borrowable: 0,
+            max_withdrawable: 0,
+        }
+    );
+}
+
+#[test]
+fn test_summary_headroom_matches_borrow_and_withdraw() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &11_000);
+    client.borrow_asset(&user, &None, &5_000);
+
+    // 11_000 supports 10_000 of debt at 110%; 5_000 of debt needs 5_500
+    let summary = client.get_position_summary(&user);
+    assert_eq!(summary.collateral_value, 11_000);
+    assert_eq!(summary.debt_value, 5_000);
+    assert_eq!(summary.health_factor, 22_000);
+    assert_eq!(summary.max_borrowable, 5_000);
+    assert_eq!(summary.max_withdrawable, 5_500);
+
+    assert_eq!(
+        client.try_withdraw_collateral(&user, &None, &5_501),
+        Err(Ok(ProtocolError::InsufficientCollateralRatio))
+    );
+    client.withdraw_collateral(&user, &None, &5_500);
+    assert_eq!(client.get_position_summary(&user).max_withdrawable, 0);
+
+    client.deposit_collateral(&user, &None, &5_500);
+    client.borrow_asset(&user, &None, &5_000);
+    assert_eq!(client.get_position_summary(&user).max_borrowable, 0);
+    assert_eq!(
+        client.try_borrow_asset(&user, &None, &1),
+        Err(Ok(ProtocolError::MaxBorrowExceeded))
+    );
+}
+
+#[test]
+fn test_summary_includes_accrued_interest() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    client.borrow_asset(&user, &None, &5_000);
+
+    // 50% utilization: 13.5% for a year
+    env.ledger().with_mut(|li| li.timestamp += 365 * 86_400);
+    let summary = client.get_position_summary(&user);
+    assert_eq!(summary.accrued_interest, 675);
+    assert_eq!(summary.debt_value, 5_675);
+    assert_eq!(summary.health_factor, 10_000 * 10_000 / 5_675);
+}
@@ -0,0 +1,87 @@
+//! # Protocol Error Tests
+//!
+//! Covers the shared `ProtocolError` codes: oracle entrypoints returning them
+//! instead of panicking, position entrypoints returning them in place of
+//! module errors, and module errors converting into them.
+
+#![cfg(test)]
+
+use crate::borrow::BorrowError;
+use crate::errors::ProtocolError;
+use crate::oracle::OracleError;
+use crate::repay::RepayError;
+use crate::testutils::setup_pool;
+use crate::withdraw::WithdrawError;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::Address;
+
+#[test]
+fn test_oracle_entrypoints_return_protocol_errors() {
+    let (env, client, admin) = setup_pool();
+    let asset = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_primary_oracle(&stranger, &asset, &oracle),
+        Err(Ok(ProtocolError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_update_price_feed(&admin, &asset, &0, &8, &oracle),
+        Err(Ok(ProtocolError::InvalidPrice))
+    );
+    assert_eq!(
+        client.try_get_price(&asset),
+        Err(Ok(ProtocolError::PriceNotAvailable))
+    );
+
+    client.update_price_feed(&admin, &asset, &100_000_000, &8, &oracle);
+    env.ledger().with_mut(|li| li.timestamp += 3_601);
+    assert_eq!(
+        client.try_get_price(&asset),
+        Err(Ok(ProtocolError::StalePrice))
+    );
+}
+
+#[test]
+fn test_position_entrypoints_return_protocol_errors() {
+    let (env, client, _admin) = setup_pool();
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_deposit_collateral(&user, &None, &0),
+        Err(Ok(ProtocolError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_withdraw_collateral(&user, &None, &0),
+        Err(Ok(ProtocolError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_borrow_asset(&user, &None, &0),
+        Err(Ok(ProtocolError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_repay_debt(&user, &None, &0),
+        Err(Ok(ProtocolError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_module_errors_convert() {
+    assert_eq!(
+        ProtocolError::from(BorrowError::BorrowPaused),
+        ProtocolError::OperationPaused
+    );
+    assert_eq!(
+        ProtocolError::from(WithdrawError::Undercollateralized),
+        ProtocolError::InsufficientCollateralRatio
+    );
+    assert_eq!(
+        ProtocolError::from(RepayError::InsufficientAllowance),
+        ProtocolError::InsufficientAllowance
+    );
+    assert_eq!(
+        ProtocolError::from(OracleError::FallbackNotConfigured),
+        ProtocolError::PriceNotAvailable
+    );
+}
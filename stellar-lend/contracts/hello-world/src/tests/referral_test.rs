@@ -0,0 +1,163 @@
+//! # Referral Tests
+//!
+//! Covers referrer attribution on deposits and borrows, per-referrer volume,
+//! the referral share of origination fees and reward claims.
+
+#![cfg(test)]
+
+use crate::errors::GovernanceError;
+use crate::referral::{ReferralError, ReferralStats, MAX_REFERRAL_SHARE_BPS};
+use crate::testutils::{execute_governance_proposal, setup_governance, setup_pool};
+use crate::types::ProposalType;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, Env, String};
+
+fn set_fees(env: &Env, client: &HelloContractClient, fee_bps: i128, referral_bps: i128) {
+    env.as_contract(&client.address, || {
+        crate::origination_fee::set_origination_fee_bps(env, fee_bps).unwrap();
+        crate::referral::set_referral_share_bps(env, referral_bps).unwrap();
+    });
+}
+
+#[test]
+fn test_first_referrer_sticks() {
+    let (env, client, _admin) = setup_pool();
+    let user = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.deposit_collateral_with_referrer(&user, &None, &1_000, &Some(referrer.clone()));
+    client.deposit_collateral_with_referrer(&user, &None, &500, &Some(other.clone()));
+
+    assert_eq!(client.get_referrer(&user), Some(referrer.clone()));
+    assert_eq!(
+        client.get_referral_stats(&referrer),
+        ReferralStats {
+            referred_users: 1,
+            deposit_volume: 1_500,
+            borrow_volume: 0,
+        }
+    );
+    assert_eq!(client.get_referral_stats(&other), ReferralStats::default());
+}
+
+#[test]
+fn test_self_referral_ignored() {
+    let (env, client, _admin) = setup_pool();
+    let user = Address::generate(&env);
+
+    client.deposit_collateral_with_referrer(&user, &None, &1_000, &Some(user.clone()));
+
+    assert_eq!(client.get_referrer(&user), None);
+    assert_eq!(client.get_referral_stats(&user), ReferralStats::default());
+}
+
+#[test]
+fn test_volume_tracked_through_plain_entrypoints() {
+    let (env, client, _admin) = setup_pool();
+    let user = Address::generate(&env);
+    let referrer = Address::generate(&env);
+
+    client.deposit_collateral_with_referrer(&user, &None, &10_000, &Some(referrer.clone()));
+    client.deposit_collateral(&user, &None, &2_000);
+    client.borrow_asset(&user, &None, &1_000);
+    client.borrow_asset_with_referrer(&user, &None, &500, &None);
+
+    let stats = client.get_referral_stats(&referrer);
+    assert_eq!(stats.deposit_volume, 12_000);
+    assert_eq!(stats.borrow_volume, 1_500);
+}
+
+#[test]
+fn test_referrer_earns_share_of_treasury_fee() {
+    let (env, client, _admin) = setup_pool();
+    let user = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    set_fees(&env, &client, 100, 2_000);
+    env.as_contract(&client.address, || {
+        crate::insurance::set_insurance_fee_share(&env, 5_000).unwrap();
+    });
+    client.deposit_collateral(&user, &None, &20_000);
+
+    let receipt = client.borrow_asset_with_receipt(&user, &None, &10_000);
+    assert_eq!(receipt.referral_share, 0);
+
+    let user2 = Address::generate(&env);
+    client.deposit_collateral(&user2, &None, &20_000);
+    client.borrow_asset_with_referrer(&user2, &None, &10_000, &Some(referrer.clone()));
+
+    // 100 fee: 50 insurance, 20% of the remaining 50 to the referrer
+    assert_eq!(client.get_referral_rewards(&referrer, &None), 10);
+    assert_eq!(client.get_reserve_balance(&None), 50 + 40);
+    let fees = client.get_protocol_report().fees;
+    assert_eq!(fees.origination_fees, 200);
+    assert_eq!(fees.insurance_share, 100);
+    assert_eq!(fees.referral_share, 10);
+    assert_eq!(fees.treasury_share, 90);
+}
+
+#[test]
+fn test_claim_referral_rewards() {
+    let (env, client, _admin) = setup_pool();
+    let user = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    set_fees(&env, &client, 100, MAX_REFERRAL_SHARE_BPS);
+
+    assert_eq!(
+        client.try_claim_referral_rewards(&referrer, &None),
+        Err(Ok(ReferralError::NoRewards))
+    );
+
+    client.deposit_collateral_with_referrer(&user, &None, &20_000, &Some(referrer.clone()));
+    client.borrow_asset(&user, &None, &10_000);
+    assert_eq!(client.get_referral_rewards(&referrer, &None), 50);
+
+    assert_eq!(client.claim_referral_rewards(&referrer, &None), 50);
+    assert_eq!(client.get_referral_rewards(&referrer, &None), 0);
+    assert_eq!(
+        client.try_claim_referral_rewards(&referrer, &None),
+        Err(Ok(ReferralError::NoRewards))
+    );
+}
+
+#[test]
+fn test_set_referral_share_bounds() {
+    let (env, client, _admin) = setup_pool();
+    assert_eq!(client.get_referral_share_bps(), 0);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            crate::referral::set_referral_share_bps(&env, MAX_REFERRAL_SHARE_BPS + 1),
+            Err(ReferralError::InvalidShare)
+        );
+        assert_eq!(
+            crate::referral::set_referral_share_bps(&env, -1),
+            Err(ReferralError::InvalidShare)
+        );
+    });
+}
+
+#[test]
+fn test_referral_share_via_governance() {
+    let (env, client, admin) = setup_pool();
+    let gov_token = setup_governance(&env, &client, &admin);
+    let voter = Address::generate(&env);
+    StellarAssetClient::new(&env, &gov_token).mint(&voter, &1_000);
+    client.gov_stake(&voter, &1_000);
+
+    assert_eq!(
+        client.try_gov_create_proposal(
+            &voter,
+            &ProposalType::ReferralShare(MAX_REFERRAL_SHARE_BPS + 1),
+            &String::from_str(&env, "Share too high"),
+            &None,
+        ),
+        Err(Ok(GovernanceError::InvalidProposal))
+    );
+
+    execute_governance_proposal(&env, &client, &ProposalType::ReferralShare(1_000));
+
+    assert_eq!(client.get_referral_share_bps(), 1_000);
+}
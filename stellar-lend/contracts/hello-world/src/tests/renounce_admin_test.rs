@@ -0,0 +1,80 @@
+//! # Renounce Admin Tests
+//!
+//! Covers the one-way hand-off of super-admin rights to governance: the old
+//! admin loses access, including governance's admin-only calls, roles it
+//! granted are frozen, the admin can never change again, and admin-only
+//! operations remain reachable through executed proposals.
+
+#![cfg(test)]
+
+use crate::admin::AdminError;
+use crate::errors::GovernanceError;
+use crate::testutils::{execute_governance_proposal, setup_governance, setup_pool};
+use crate::types::ProposalType;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Symbol, Vec};
+
+#[test]
+fn test_renounce_admin_is_one_way() {
+    let (env, client, admin) = setup_pool();
+    assert!(!client.is_admin_renounced());
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_renounce_admin(&stranger),
+        Err(Ok(AdminError::Unauthorized))
+    );
+
+    client.renounce_admin(&admin);
+    assert!(client.is_admin_renounced());
+
+    // The old admin has no powers left
+    assert!(client.try_set_emergency_pause(&admin, &true).is_err());
+    assert_eq!(
+        client.try_transfer_admin(&admin, &stranger),
+        Err(Ok(AdminError::AdminRenounced))
+    );
+    assert_eq!(
+        client.try_transfer_admin(&client.address, &stranger),
+        Err(Ok(AdminError::AdminRenounced))
+    );
+}
+
+#[test]
+fn test_renounce_freezes_roles_and_governance_admin() {
+    let (env, client, admin) = setup_pool();
+    setup_governance(&env, &client, &admin);
+    let guardian = Address::generate(&env);
+    let role = Symbol::new(&env, "pause_guardian");
+    client.grant_role(&admin, &role, &guardian);
+    assert!(client.has_role(&role, &guardian));
+
+    client.renounce_admin(&admin);
+
+    // Roles granted by the old admin no longer count
+    assert!(!client.has_role(&role, &guardian));
+    assert!(client.try_set_emergency_pause(&guardian, &true).is_err());
+
+    // The old admin no longer administers governance
+    let mut signers = Vec::new(&env);
+    signers.push_back(admin.clone());
+    assert_eq!(
+        client.try_gov_set_multisig_config(&admin, &signers, &1),
+        Err(Ok(GovernanceError::Unauthorized))
+    );
+
+    // Governance can grant roles again
+    client.grant_role(&client.address, &role, &guardian);
+    assert!(client.has_role(&role, &guardian));
+}
+
+#[test]
+fn test_admin_actions_pass_through_governance_after_renounce() {
+    let (env, client, admin) = setup_pool();
+    setup_governance(&env, &client, &admin);
+    client.renounce_admin(&admin);
+
+    execute_governance_proposal(&env, &client, &ProposalType::EmergencyPause(true));
+
+    assert!(client.is_emergency_paused());
+}
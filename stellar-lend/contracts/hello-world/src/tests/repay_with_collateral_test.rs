@@ -0,0 +1,78 @@
+//! # Repay-with-Collateral Tests
+//!
+//! Covers closing debt with the borrower's own collateral, capping at the
+//! outstanding debt, missing collateral and refusing liquidatable positions.
+
+#![cfg(test)]
+
+use crate::errors::ProtocolError;
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+/// Borrower with 10_000 native collateral and 2_000 native debt
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, _admin) = setup_pool();
+
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    client.borrow_asset(&user, &None, &2_000);
+
+    (env, client, user)
+}
+
+#[test]
+fn test_partial_repay_with_collateral() {
+    let (_, client, user) = setup();
+
+    assert_eq!(
+        client.repay_with_collateral(&user, &None, &None, &500),
+        (1_500, 500)
+    );
+    let position = client.get_asset_position(&user, &None);
+    assert_eq!(position.collateral, 9_500);
+    assert_eq!(position.debt, 1_500);
+}
+
+#[test]
+fn test_repay_with_collateral_caps_at_debt() {
+    let (_, client, user) = setup();
+
+    assert_eq!(
+        client.repay_with_collateral(&user, &None, &None, &50_000),
+        (0, 2_000)
+    );
+    assert_eq!(client.get_asset_position(&user, &None).collateral, 8_000);
+    assert_eq!(
+        client.try_repay_with_collateral(&user, &None, &None, &1),
+        Err(Ok(ProtocolError::NoDebt))
+    );
+}
+
+#[test]
+fn test_collateral_asset_not_held() {
+    let (env, client, user) = setup();
+    let other = Address::generate(&env);
+
+    assert_eq!(
+        client.try_repay_with_collateral(&user, &None, &Some(other), &500),
+        Err(Ok(ProtocolError::InsufficientCollateral))
+    );
+    assert_eq!(
+        client.try_repay_with_collateral(&user, &None, &None, &0),
+        Err(Ok(ProtocolError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_liquidatable_position_rejected() {
+    let (env, client, _) = setup();
+    let user = Address::generate(&env);
+    client.test_set_position(&user, &1_000, &1_000, &0);
+
+    assert_eq!(
+        client.try_repay_with_collateral(&user, &None, &None, &500),
+        Err(Ok(ProtocolError::InsufficientCollateralRatio))
+    );
+}
@@ -0,0 +1,157 @@
+//! # Reserve Factor and Treasury Tests
+//!
+//! Covers the per-asset share of borrower interest kept as reserves, the
+//! combined reserve view, admin and governance withdrawals, and reserves in
+//! the protocol analytics.
+
+#![cfg(test)]
+
+use crate::deposit::DepositDataKey;
+use crate::reserve::{self, ReserveError};
+use crate::testutils::{setup_pool, supply};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+struct Setup {
+    env: Env,
+    client: HelloContractClient<'static>,
+    admin: Address,
+    asset: Address,
+}
+
+fn setup() -> Setup {
+    let (env, client, admin) = setup_pool();
+
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    Setup {
+        env,
+        client,
+        admin,
+        asset,
+    }
+}
+
+/// Give `user` `amount` of `asset` and approve the pool to pull it
+fn fund(s: &Setup, asset: &Address, user: &Address, amount: i128) {
+    StellarAssetClient::new(&s.env, asset).mint(user, &amount);
+    TokenClient::new(&s.env, asset).approve(user, &s.client.address, &amount, &1_000);
+}
+
+/// Credit `amount` of borrow-fee reserves for `asset`
+fn set_fee_reserve(s: &Setup, amount: i128) {
+    s.env.as_contract(&s.client.address, || {
+        s.env.storage().persistent().set(
+            &DepositDataKey::ProtocolReserve(Some(s.asset.clone())),
+            &amount,
+        );
+    });
+}
+
+#[test]
+fn test_repaid_interest_uses_asset_reserve_factor() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    assert_eq!(s.client.get_reserve_factor(&Some(s.asset.clone())), 1_000);
+
+    s.client
+        .set_reserve_factor(&s.admin, &Some(s.asset.clone()), &2_000);
+    assert_eq!(s.client.get_reserve_factor(&Some(s.asset.clone())), 2_000);
+
+    s.client.test_set_position(&user, &10_000, &1_000, &100);
+    fund(&s, &s.asset, &user, 100);
+    s.client.repay_debt(&user, &Some(s.asset.clone()), &100);
+    assert_eq!(s.client.get_reserves(&Some(s.asset.clone())), 20);
+
+    // Other assets keep the default 10%
+    let other = s.env.register_stellar_asset_contract(s.admin.clone());
+    s.client.test_set_position(&user, &10_000, &1_000, &100);
+    fund(&s, &other, &user, 100);
+    s.client.repay_debt(&user, &Some(other.clone()), &100);
+    assert_eq!(s.client.get_reserves(&Some(other)), 10);
+}
+
+#[test]
+fn test_reserve_factor_bounds_and_auth() {
+    let s = setup();
+    assert_eq!(
+        s.client
+            .try_set_reserve_factor(&s.admin, &Some(s.asset.clone()), &5_001),
+        Err(Ok(ReserveError::InvalidReserveFactor))
+    );
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.client
+            .try_set_reserve_factor(&stranger, &Some(s.asset.clone()), &500),
+        Err(Ok(ReserveError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_withdraw_reserves_draws_fees_first() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    let treasury = Address::generate(&s.env);
+    s.client.test_set_position(&user, &10_000, &1_000, &200);
+    fund(&s, &s.asset, &user, 200);
+    s.client.repay_debt(&user, &Some(s.asset.clone()), &200);
+    set_fee_reserve(&s, 50);
+    assert_eq!(s.client.get_reserves(&Some(s.asset.clone())), 70);
+
+    let remaining = s
+        .client
+        .withdraw_reserves(&s.admin, &treasury, &Some(s.asset.clone()), &60);
+    assert_eq!(remaining, 10);
+    s.env.as_contract(&s.client.address, || {
+        assert_eq!(
+            reserve::get_reserve_balance(&s.env, Some(s.asset.clone())),
+            10
+        );
+    });
+
+    assert_eq!(
+        s.client
+            .try_withdraw_reserves(&s.admin, &treasury, &Some(s.asset.clone()), &11),
+        Err(Ok(ReserveError::InsufficientReserve))
+    );
+    assert_eq!(
+        s.client
+            .try_withdraw_reserves(&user, &user, &Some(s.asset.clone()), &10),
+        Err(Ok(ReserveError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_governance_release_rejects_contract_recipient() {
+    let s = setup();
+    set_fee_reserve(&s, 50);
+
+    let result = s.env.as_contract(&s.client.address, || {
+        reserve::release_reserves(&s.env, s.client.address.clone(), Some(s.asset.clone()), 10)
+    });
+    assert_eq!(result, Err(ReserveError::InvalidTreasury));
+
+    let to = Address::generate(&s.env);
+    let result = s.env.as_contract(&s.client.address, || {
+        reserve::release_reserves(&s.env, to, Some(s.asset.clone()), 50)
+    });
+    assert_eq!(result, Ok(0));
+}
+
+#[test]
+fn test_protocol_analytics_report_reserves() {
+    let s = setup();
+    let user = Address::generate(&s.env);
+    supply(&s.env, &s.client, &user, &s.asset, 10_000);
+    s.client.borrow_asset(&user, &Some(s.asset.clone()), &1_000);
+    s.client
+        .set_reserve_factor(&s.admin, &Some(s.asset.clone()), &2_000);
+
+    // 10% utilization: 3.5% for a year on 1_000, a fifth of it to reserves
+    s.env.ledger().with_mut(|li| li.timestamp += 365 * 86_400);
+    fund(&s, &s.asset, &user, 100);
+    s.client.repay_debt(&user, &Some(s.asset.clone()), &100);
+    assert_eq!(s.client.get_reserves(&Some(s.asset.clone())), 7);
+    assert_eq!(s.client.get_protocol_analytics().total_reserves, 7);
+}
@@ -0,0 +1,119 @@
+//! # Protocol Revenue Tests
+//!
+//! Covers the per-asset revenue counters and the `get_revenue_report` view.
+
+#![cfg(test)]
+
+use crate::deposit::{AssetParams, DepositDataKey};
+use crate::revenue::{self, RevenueSource};
+use crate::testutils::{setup_pool, supply};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, admin) = setup_pool();
+
+    // Borrowable liquidity in the pool
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    supply(&env, &client, &Address::generate(&env), &asset, 10_000);
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DepositDataKey::AssetParams(asset.clone()),
+            &AssetParams {
+                deposit_enabled: true,
+                collateral_factor: 10000,
+                max_deposit: 0,
+                borrow_fee_bps: 200,
+            },
+        );
+    });
+
+    (env, client, admin, asset)
+}
+
+#[test]
+fn test_report_starts_empty() {
+    let (_env, client, _admin, asset) = setup();
+    let report = client.get_revenue_report(&Some(asset));
+    assert_eq!(report.interest_accrued, 0);
+    assert_eq!(report.fees_collected, 0);
+    assert_eq!(report.liquidation_penalties, 0);
+    assert_eq!(report.flash_loan_fees, 0);
+    assert_eq!(report.updated_at, 0);
+}
+
+#[test]
+fn test_borrow_fee_is_counted() {
+    let (env, client, _admin, asset) = setup();
+    let user = Address::generate(&env);
+    client.test_set_position(&user, &10_000, &0, &0);
+
+    client.borrow_asset(&user, &Some(asset.clone()), &1_000);
+    client.borrow_asset(&user, &Some(asset.clone()), &500);
+
+    let report = client.get_revenue_report(&Some(asset));
+    assert_eq!(report.fees_collected, 30);
+    assert_eq!(report.interest_accrued, 0);
+}
+
+#[test]
+fn test_repaid_interest_is_counted() {
+    let (env, client, _admin, asset) = setup();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let user = Address::generate(&env);
+    client.test_set_position(&user, &10_000, &1_000, &50);
+    StellarAssetClient::new(&env, &asset).mint(&user, &300);
+    TokenClient::new(&env, &asset).approve(&user, &client.address, &300, &1_000);
+
+    // Only the reserve factor's cut (10% by default) of the interest portion
+    // of the repayment is revenue
+    client.repay_debt(&user, &Some(asset.clone()), &300);
+
+    let report = client.get_revenue_report(&Some(asset.clone()));
+    assert_eq!(report.interest_accrued, 5);
+    assert_eq!(report.updated_at, 1_000);
+    assert_eq!(client.get_revenue_report(&None).interest_accrued, 0);
+}
+
+#[test]
+fn test_liquidated_interest_counts_reserve_cut() {
+    let (env, client, admin, asset) = setup();
+    let collateral_asset = env.register_stellar_asset_contract(admin.clone());
+    client.update_price_feed(&admin, &asset, &10_000_000, &7, &admin);
+    client.update_price_feed(&admin, &collateral_asset, &10_000_000, &7, &admin);
+    StellarAssetClient::new(&env, &collateral_asset).mint(&client.address, &1_000);
+
+    let borrower = Address::generate(&env);
+    client.test_set_position(&borrower, &1_000, &1_000, &100);
+    let liquidator = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&liquidator, &400);
+    TokenClient::new(&env, &asset).approve(&liquidator, &client.address, &400, &1_000);
+
+    // The liquidation repays the 100 of interest first, 10% of which is the protocol's
+    client.liquidate(
+        &liquidator,
+        &borrower,
+        &Some(asset.clone()),
+        &Some(collateral_asset),
+        &400,
+    );
+    assert_eq!(client.get_revenue_report(&Some(asset)).interest_accrued, 10);
+}
+
+#[test]
+fn test_counters_are_per_source_and_ignore_non_positive() {
+    let (env, client, _admin, asset) = setup();
+    env.as_contract(&client.address, || {
+        revenue::record(&env, Some(asset.clone()), RevenueSource::LiquidationPenalty, 40);
+        revenue::record(&env, Some(asset.clone()), RevenueSource::FlashLoanFee, 9);
+        revenue::record(&env, Some(asset.clone()), RevenueSource::FlashLoanFee, 0);
+        revenue::record(&env, Some(asset.clone()), RevenueSource::Fee, -5);
+    });
+
+    let report = client.get_revenue_report(&Some(asset));
+    assert_eq!(report.liquidation_penalties, 40);
+    assert_eq!(report.flash_loan_fees, 9);
+    assert_eq!(report.fees_collected, 0);
+}
@@ -0,0 +1,181 @@
+//! # Risk Engine Tests
+//!
+//! Covers band-limited base rate and collateral factor adjustments, per-asset
+//! and global cooldowns, oracle volatility tracking, and governance reverts.
+
+#![cfg(test)]
+
+use crate::cross_asset::AssetConfig;
+use crate::deposit::{DepositDataKey, ProtocolAnalytics};
+use crate::risk_engine::{
+    revert_adjustment, set_collateral_band, set_rate_band, set_settings, CollateralBand,
+    RateBand, RiskEngineError,
+};
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env};
+
+/// Enable the engine (1 hour cooldown) with a 100-300 bps base rate band targeting 80%
+fn enable_rate_band(env: &Env, client: &HelloContractClient) {
+    env.as_contract(&client.address, || {
+        set_settings(env, true, 3_600);
+        set_rate_band(
+            env,
+            RateBand {
+                min_base_rate_bps: 100,
+                max_base_rate_bps: 300,
+                step_bps: 50,
+                target_utilization_bps: 8_000,
+            },
+        )
+        .unwrap();
+        env.storage().persistent().set(
+            &DepositDataKey::ProtocolAnalytics,
+            &ProtocolAnalytics {
+                total_deposits: 10_000,
+                total_borrows: 9_000,
+                total_value_locked: 10_000,
+            },
+        );
+    });
+}
+
+fn base_rate(client: &HelloContractClient) -> i128 {
+    client.get_interest_rate_config().unwrap().base_rate_bps
+}
+
+#[test]
+fn test_engine_disabled_by_default() {
+    let (_env, client, _admin) = setup_pool();
+    assert_eq!(
+        client.try_run_risk_engine(&None),
+        Err(Ok(RiskEngineError::NotEnabled))
+    );
+}
+
+#[test]
+fn test_high_utilization_steps_base_rate_within_band() {
+    let (env, client, _admin) = setup_pool();
+    enable_rate_band(&env, &client);
+
+    let ids = client.run_risk_engine(&None);
+    assert_eq!(ids.len(), 1);
+    assert_eq!(base_rate(&client), 150);
+    let adjustment = client.get_risk_adjustment(&ids.get(0).unwrap()).unwrap();
+    assert_eq!(adjustment.old_value, 100);
+    assert_eq!(adjustment.new_value, 150);
+
+    assert_eq!(
+        client.try_run_risk_engine(&None),
+        Err(Ok(RiskEngineError::CooldownActive))
+    );
+
+    // Further runs stop at the top of the band
+    for i in 1..=5 {
+        env.ledger().set_timestamp(3_600 * i);
+        client.run_risk_engine(&None);
+    }
+    assert_eq!(base_rate(&client), 300);
+}
+
+#[test]
+fn test_base_rate_steps_once_per_cooldown_across_assets() {
+    let (env, client, _admin) = setup_pool();
+    enable_rate_band(&env, &client);
+
+    assert_eq!(client.run_risk_engine(&None).len(), 1);
+    // Runs for other, even unregistered, assets do not step the shared rate again
+    for _ in 0..3 {
+        let ids = client.run_risk_engine(&Some(Address::generate(&env)));
+        assert_eq!(ids.len(), 0);
+    }
+    assert_eq!(base_rate(&client), 150);
+
+    env.ledger().set_timestamp(3_600);
+    let ids = client.run_risk_engine(&Some(Address::generate(&env)));
+    assert_eq!(ids.len(), 1);
+    assert_eq!(base_rate(&client), 200);
+}
+
+#[test]
+fn test_revert_restores_previous_value() {
+    let (env, client, _admin) = setup_pool();
+    enable_rate_band(&env, &client);
+    let id = client.run_risk_engine(&None).get(0).unwrap();
+
+    env.as_contract(&client.address, || {
+        revert_adjustment(&env, id).unwrap();
+        assert_eq!(
+            revert_adjustment(&env, id),
+            Err(RiskEngineError::AlreadyReverted)
+        );
+    });
+    assert_eq!(base_rate(&client), 100);
+    assert!(client.get_risk_adjustment(&id).unwrap().reverted);
+}
+
+#[test]
+fn test_volatility_tightens_collateral_factor() {
+    let (env, client, admin) = setup_pool();
+    client.initialize_ca(&admin);
+    let asset = Address::generate(&env);
+    client.initialize_asset(
+        &Some(asset.clone()),
+        &AssetConfig {
+            asset: Some(asset.clone()),
+            collateral_factor: 7_000,
+            liquidation_threshold: 8_000,
+            reserve_factor: 1_000,
+            max_supply: 0,
+            max_borrow: 0,
+            can_collateralize: true,
+            can_borrow: true,
+            price: 10_000_000,
+            price_updated_at: 0,
+        },
+    );
+
+    // A 5% move gives an EWMA volatility of 100 bps
+    client.update_price_feed(&admin, &asset, &10_000_000, &7, &admin);
+    client.update_price_feed(&admin, &asset, &10_500_000, &7, &admin);
+    assert_eq!(client.get_price_volatility(&asset), 100);
+
+    env.as_contract(&client.address, || {
+        set_settings(&env, true, 0);
+        set_collateral_band(
+            &env,
+            Some(asset.clone()),
+            CollateralBand {
+                min_collateral_factor: 6_000,
+                max_collateral_factor: 7_500,
+                step_bps: 500,
+                volatility_threshold_bps: 50,
+            },
+        )
+        .unwrap();
+    });
+
+    client.run_risk_engine(&Some(asset.clone()));
+    assert_eq!(
+        client.get_asset_config(&Some(asset.clone())).collateral_factor,
+        6_500
+    );
+}
+
+#[test]
+fn test_invalid_band_rejected() {
+    let (env, client, _admin) = setup_pool();
+    let result = env.as_contract(&client.address, || {
+        set_rate_band(
+            &env,
+            RateBand {
+                min_base_rate_bps: 300,
+                max_base_rate_bps: 100,
+                step_bps: 50,
+                target_utilization_bps: 8_000,
+            },
+        )
+    });
+    assert_eq!(result, Err(RiskEngineError::InvalidParameter));
+}
@@ -0,0 +1,113 @@
+//! # Risk Tier Tests
+//!
+//! Covers listing assets from tier presets, moving assets between tiers, and
+//! tier parameter changes that re-apply to every asset in the tier.
+
+#![cfg(test)]
+
+use crate::risk_tier::{self, RiskTier, RiskTierError, TierParams};
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, admin) = setup_pool();
+    client.initialize_ca(&admin);
+
+    (env, client, admin)
+}
+
+#[test]
+fn test_list_asset_uses_tier_preset() {
+    let (env, client, _admin) = setup();
+    let usdc = Some(Address::generate(&env));
+
+    client.list_asset_in_tier(&usdc, &RiskTier::Stable, &1_000, &10_000_000);
+
+    let preset = risk_tier::preset_params(RiskTier::Stable);
+    let config = client.get_asset_config(&usdc);
+    assert_eq!(config.collateral_factor, preset.collateral_factor);
+    assert_eq!(config.liquidation_threshold, preset.liquidation_threshold);
+    assert_eq!(client.get_asset_tier(&usdc), Some(RiskTier::Stable));
+    assert_eq!(client.get_tier_assets(&RiskTier::Stable).len(), 1);
+
+    assert_eq!(
+        client.try_list_asset_in_tier(&usdc, &RiskTier::Volatile, &1_000, &10_000_000),
+        Err(Ok(RiskTierError::ListingFailed))
+    );
+}
+
+#[test]
+fn test_tier_update_applies_to_all_members() {
+    let (env, client, _admin) = setup();
+    let a = Some(Address::generate(&env));
+    let b = Some(Address::generate(&env));
+    let other = Some(Address::generate(&env));
+    client.list_asset_in_tier(&a, &RiskTier::Volatile, &1_000, &10_000_000);
+    client.list_asset_in_tier(&b, &RiskTier::Volatile, &1_000, &20_000_000);
+    client.list_asset_in_tier(&other, &RiskTier::BlueChip, &1_000, &10_000_000);
+
+    let params = TierParams {
+        collateral_factor: 4_000,
+        liquidation_threshold: 5_500,
+        max_supply: 1_000_000,
+        max_borrow: 500_000,
+        liquidation_incentive: 1_200,
+    };
+    env.as_contract(&client.address, || {
+        risk_tier::set_tier_params(&env, RiskTier::Volatile, params.clone()).unwrap();
+        assert_eq!(risk_tier::liquidation_incentive_for(&env, &a), Some(1_200));
+    });
+
+    for asset in [&a, &b] {
+        let config = client.get_asset_config(asset);
+        assert_eq!(config.collateral_factor, 4_000);
+        assert_eq!(config.liquidation_threshold, 5_500);
+        assert_eq!(config.max_supply, 1_000_000);
+        assert_eq!(config.max_borrow, 500_000);
+    }
+    let untouched = client.get_asset_config(&other);
+    assert_eq!(
+        untouched.collateral_factor,
+        risk_tier::preset_params(RiskTier::BlueChip).collateral_factor
+    );
+}
+
+#[test]
+fn test_invalid_tier_params_rejected() {
+    let (env, client, _admin) = setup();
+    env.as_contract(&client.address, || {
+        let mut params = risk_tier::preset_params(RiskTier::Stable);
+        params.collateral_factor = params.liquidation_threshold + 1;
+        assert_eq!(
+            risk_tier::set_tier_params(&env, RiskTier::Stable, params),
+            Err(RiskTierError::InvalidParameter)
+        );
+    });
+}
+
+#[test]
+fn test_set_asset_tier_moves_membership() {
+    let (env, client, admin) = setup();
+    let asset = Some(Address::generate(&env));
+    client.list_asset_in_tier(&asset, &RiskTier::Experimental, &1_000, &10_000_000);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_asset_tier(&stranger, &asset, &RiskTier::BlueChip),
+        Err(Ok(RiskTierError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_asset_tier(&admin, &Some(Address::generate(&env)), &RiskTier::BlueChip),
+        Err(Ok(RiskTierError::AssetNotListed))
+    );
+
+    client.set_asset_tier(&admin, &asset, &RiskTier::BlueChip);
+    assert_eq!(client.get_asset_tier(&asset), Some(RiskTier::BlueChip));
+    assert_eq!(client.get_tier_assets(&RiskTier::Experimental).len(), 0);
+    assert_eq!(
+        client.get_asset_config(&asset).collateral_factor,
+        risk_tier::preset_params(RiskTier::BlueChip).collateral_factor
+    );
+}
@@ -0,0 +1,69 @@
+//! # Role-Based Access Control Tests
+//!
+//! Covers delegating admin-gated functions to operational roles, each role
+//! being limited to its own functions, the super admin holding every role,
+//! and renouncing roles.
+
+#![cfg(test)]
+
+use crate::admin::{AdminError, Role};
+use crate::risk_management::{PauseOperation, RiskManagementError};
+use crate::testutils::setup_pool;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, IntoVal, Symbol};
+
+#[test]
+fn test_roles_are_limited_to_their_functions() {
+    let (env, client, admin) = setup_pool();
+    let risk_admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    client.grant_role(&admin, &Role::RiskAdmin.name(&env), &risk_admin);
+    client.grant_role(&admin, &Role::PauseGuardian.name(&env), &guardian);
+
+    client.set_risk_params(&risk_admin, &Some(11_500), &None, &None, &None);
+    assert_eq!(
+        client.try_set_pause_switch(&risk_admin, &PauseOperation::Borrow, &true),
+        Err(Ok(RiskManagementError::Unauthorized))
+    );
+
+    client.set_pause_switch(&guardian, &PauseOperation::Borrow, &true);
+    assert!(client.is_operation_paused(&PauseOperation::Borrow));
+    assert_eq!(
+        client.try_set_risk_params(&guardian, &Some(12_000), &None, &None, &None),
+        Err(Ok(RiskManagementError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_admin_holds_every_role() {
+    let (_, client, admin) = setup_pool();
+
+    client.set_risk_params(&admin, &Some(11_500), &None, &None, &None);
+    client.set_pause_switch(&admin, &PauseOperation::Borrow, &true);
+    client.set_emergency_pause(&admin, &true);
+}
+
+#[test]
+fn test_renounce_role() {
+    let (env, client, admin) = setup_pool();
+    let guardian = Address::generate(&env);
+    let role = Role::PauseGuardian.name(&env);
+    client.grant_role(&admin, &role, &guardian);
+    assert!(client.has_role(&role, &guardian));
+
+    client.renounce_role(&guardian, &role);
+    assert!(!client.has_role(&role, &guardian));
+
+    let event = env.events().all().last().unwrap();
+    let topic: Symbol = event.1.first().unwrap().into_val(&env);
+    assert_eq!(topic, Symbol::new(&env, "role_renounced"));
+
+    assert_eq!(
+        client.try_set_emergency_pause(&guardian, &true),
+        Err(Ok(RiskManagementError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_renounce_role(&guardian, &role),
+        Err(Ok(AdminError::InvalidParameter))
+    );
+}
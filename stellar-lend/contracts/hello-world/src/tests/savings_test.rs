@@ -0,0 +1,154 @@
+//! # Savings Rate Tests
+//!
+//! Covers joining and exiting the savings bucket, accrual through the rate
+//! index, funding from the asset's reserve (and capping growth when the
+//! reserve runs dry) and the governance rate bounds.
+
+#![cfg(test)]
+
+use crate::deposit::{DepositDataKey, Position};
+use crate::savings::{self, SavingsError, MAX_SAVINGS_RATE_BPS};
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+const YEAR: u64 = 365 * 86_400;
+
+/// Pool with `reserve` of `asset` in reserves and a user holding 100_000
+fn setup(reserve: i128) -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, admin) = setup_pool();
+
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&user, &100_000);
+    if reserve > 0 {
+        StellarAssetClient::new(&env, &asset).mint(&client.address, &reserve);
+    }
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DepositDataKey::ProtocolReserve(Some(asset.clone())), &reserve);
+    });
+
+    (env, client, user, asset)
+}
+
+fn set_rate(env: &Env, client: &HelloContractClient, asset: &Address, rate_bps: i128) {
+    env.as_contract(&client.address, || {
+        savings::set_savings_rate(env, asset.clone(), rate_bps).unwrap();
+    });
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+#[test]
+fn test_join_and_exit_without_rate() {
+    let (env, client, user, asset) = setup(0);
+    let token = TokenClient::new(&env, &asset);
+
+    assert_eq!(client.join_savings(&user, &asset, &40_000), 40_000);
+    assert_eq!(token.balance(&user), 60_000);
+    assert_eq!(token.balance(&client.address), 40_000);
+
+    advance(&env, YEAR);
+    assert_eq!(client.get_savings_balance(&user, &asset), 40_000);
+
+    assert_eq!(client.exit_savings(&user, &asset, &40_000), 0);
+    assert_eq!(token.balance(&user), 100_000);
+    assert_eq!(client.get_savings_state(&asset).total_normalized, 0);
+}
+
+#[test]
+fn test_savings_accrue_from_reserve() {
+    let (env, client, user, asset) = setup(1_000_000);
+    set_rate(&env, &client, &asset, 1_000);
+
+    client.join_savings(&user, &asset, &100_000);
+    advance(&env, YEAR);
+
+    // 10% for a year, without touching the reserve until the next drip
+    assert_eq!(client.get_savings_balance(&user, &asset), 110_000);
+    assert_eq!(client.get_reserve_balance(&Some(asset.clone())), 1_000_000);
+
+    assert_eq!(client.exit_savings(&user, &asset, &110_000), 0);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&user), 110_000);
+    assert_eq!(client.get_reserve_balance(&Some(asset.clone())), 990_000);
+}
+
+#[test]
+fn test_savings_growth_capped_by_reserve() {
+    let (env, client, user, asset) = setup(1_000);
+    set_rate(&env, &client, &asset, 1_000);
+
+    client.join_savings(&user, &asset, &100_000);
+    advance(&env, YEAR);
+
+    // Only the 1_000 in reserve can be paid out
+    assert_eq!(client.get_savings_balance(&user, &asset), 101_000);
+    client.exit_savings(&user, &asset, &101_000);
+    assert_eq!(client.get_reserve_balance(&Some(asset.clone())), 0);
+}
+
+#[test]
+fn test_rate_change_drips_at_old_rate() {
+    let (env, client, user, asset) = setup(1_000_000);
+    set_rate(&env, &client, &asset, 1_000);
+
+    client.join_savings(&user, &asset, &100_000);
+    advance(&env, YEAR);
+    set_rate(&env, &client, &asset, 0);
+    advance(&env, YEAR);
+
+    assert_eq!(client.get_savings_balance(&user, &asset), 110_000);
+    assert_eq!(client.get_savings_rate(&asset), 0);
+    assert_eq!(client.get_reserve_balance(&Some(asset.clone())), 990_000);
+}
+
+#[test]
+fn test_exit_more_than_balance_fails() {
+    let (_env, client, user, asset) = setup(0);
+    client.join_savings(&user, &asset, &1_000);
+
+    assert_eq!(
+        client.try_exit_savings(&user, &asset, &1_001),
+        Err(Ok(SavingsError::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_join_savings(&user, &asset, &0),
+        Err(Ok(SavingsError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_savings_are_not_collateral() {
+    let (env, client, user, asset) = setup(0);
+    client.join_savings(&user, &asset, &50_000);
+
+    let position: Option<Position> = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&DepositDataKey::Position(user.clone()))
+    });
+    assert!(position.is_none());
+}
+
+#[test]
+fn test_savings_rate_bounds() {
+    let (env, client, _user, asset) = setup(0);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            savings::set_savings_rate(&env, asset.clone(), MAX_SAVINGS_RATE_BPS + 1),
+            Err(SavingsError::InvalidParameter)
+        );
+        assert_eq!(
+            savings::set_savings_rate(&env, asset.clone(), -1),
+            Err(SavingsError::InvalidParameter)
+        );
+    });
+    assert_eq!(client.get_savings_rate(&asset), 0);
+}
@@ -0,0 +1,134 @@
+//! # Signed Oracle Price Tests
+//!
+//! Covers the rejection paths of ed25519-signed price submissions: reporter
+//! whitelisting, timestamp freshness and ordering, and nonce replay
+//! protection.
+
+#![cfg(test)]
+
+use crate::oracle::{OracleDataKey, OracleError};
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Env};
+
+const NOW: u64 = 100_000;
+
+fn setup() -> (Env, HelloContractClient<'static>, Address, BytesN<32>) {
+    let (env, client, admin) = setup_pool();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+
+    let reporter = BytesN::from_array(&env, &[7u8; 32]);
+
+    (env, client, admin, reporter)
+}
+
+fn submit(
+    env: &Env,
+    client: &HelloContractClient,
+    reporter: &BytesN<32>,
+    asset: &Address,
+    timestamp: u64,
+    nonce: u64,
+) -> Result<
+    Result<i128, soroban_sdk::ConversionError>,
+    Result<OracleError, soroban_sdk::InvokeError>,
+> {
+    let signature = BytesN::from_array(env, &[0u8; 64]);
+    client.try_submit_signed_price(
+        reporter,
+        asset,
+        &10_000_000,
+        &7,
+        &timestamp,
+        &nonce,
+        &signature,
+    )
+}
+
+#[test]
+fn test_unlisted_reporter_rejected() {
+    let (env, client, _admin, reporter) = setup();
+    let asset = Address::generate(&env);
+    assert_eq!(
+        submit(&env, &client, &reporter, &asset, NOW, 1),
+        Err(Ok(OracleError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_set_signed_reporter_requires_admin() {
+    let (env, client, _admin, reporter) = setup();
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_signed_reporter(&stranger, &reporter, &true),
+        Err(Ok(OracleError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_future_and_stale_timestamps_rejected() {
+    let (env, client, admin, reporter) = setup();
+    client.set_signed_reporter(&admin, &reporter, &true);
+    let asset = Address::generate(&env);
+
+    assert_eq!(
+        submit(&env, &client, &reporter, &asset, NOW + 1, 1),
+        Err(Ok(OracleError::InvalidTimestamp))
+    );
+    assert_eq!(
+        submit(&env, &client, &reporter, &asset, 0, 1),
+        Err(Ok(OracleError::StalePrice))
+    );
+}
+
+#[test]
+fn test_timestamp_not_after_feed_update_rejected() {
+    let (env, client, admin, reporter) = setup();
+    client.set_signed_reporter(&admin, &reporter, &true);
+    let asset = Address::generate(&env);
+    // Feed last updated at NOW
+    client.update_price_feed(&admin, &asset, &10_000_000, &7, &admin);
+
+    assert_eq!(
+        submit(&env, &client, &reporter, &asset, NOW, 1),
+        Err(Ok(OracleError::InvalidTimestamp))
+    );
+    assert_eq!(
+        submit(&env, &client, &reporter, &asset, NOW - 10, 2),
+        Err(Ok(OracleError::InvalidTimestamp))
+    );
+}
+
+#[test]
+fn test_replayed_nonce_rejected() {
+    let (env, client, admin, reporter) = setup();
+    client.set_signed_reporter(&admin, &reporter, &true);
+    let asset = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&OracleDataKey::ReporterNonce(reporter.clone()), &5u64);
+    });
+
+    assert_eq!(
+        submit(&env, &client, &reporter, &asset, NOW, 5),
+        Err(Ok(OracleError::InvalidNonce))
+    );
+    assert_eq!(
+        submit(&env, &client, &reporter, &asset, NOW, 4),
+        Err(Ok(OracleError::InvalidNonce))
+    );
+}
+
+#[test]
+fn test_removed_reporter_rejected() {
+    let (env, client, admin, reporter) = setup();
+    client.set_signed_reporter(&admin, &reporter, &true);
+    client.set_signed_reporter(&admin, &reporter, &false);
+    let asset = Address::generate(&env);
+    assert_eq!(
+        submit(&env, &client, &reporter, &asset, NOW, 1),
+        Err(Ok(OracleError::Unauthorized))
+    );
+}
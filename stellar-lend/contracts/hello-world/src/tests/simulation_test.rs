@@ -0,0 +1,104 @@
+//! # Position Simulation Tests
+//!
+//! Covers projected health for each simulated action, revert detection
+//! against the flows' limits and that simulating leaves state untouched.
+
+#![cfg(test)]
+
+use crate::deposit::DepositDataKey;
+use crate::simulation::SimulatedAction;
+use crate::testutils::setup_pool;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+#[test]
+fn test_simulate_deposit_and_borrow() {
+    let (env, client, _admin) = setup_pool();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    client.borrow_asset(&user, &None, &2_000);
+
+    let deposit = client.simulate_action(&user, &SimulatedAction::Deposit, &None, &10_000);
+    assert!(!deposit.would_revert);
+    assert_eq!(deposit.max_amount, i128::MAX);
+    assert_eq!(deposit.collateral_value_before, 10_000);
+    assert_eq!(deposit.collateral_value_after, 20_000);
+    assert_eq!(deposit.health_factor_before, 50_000);
+    assert_eq!(deposit.health_factor_after, 100_000);
+    assert_eq!(deposit.loan_to_value_after, 1_000);
+
+    let borrow = client.simulate_action(&user, &SimulatedAction::Borrow, &None, &2_000);
+    assert!(!borrow.would_revert);
+    assert_eq!(borrow.max_amount, client.get_max_borrowable(&user, &None));
+    assert_eq!(borrow.debt_value_after, 4_000);
+    assert_eq!(borrow.health_factor_after, 25_000);
+
+    // Nothing was executed
+    let health = client.get_account_health(&user);
+    assert_eq!(health.collateral_value, 10_000);
+    assert_eq!(health.debt_value, 2_000);
+}
+
+#[test]
+fn test_simulate_flags_reverts() {
+    let (env, client, _admin) = setup_pool();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+    client.borrow_asset(&user, &None, &2_000);
+
+    let max_borrow = client.get_max_borrowable(&user, &None);
+    let borrow = client.simulate_action(&user, &SimulatedAction::Borrow, &None, &(max_borrow + 1));
+    assert!(borrow.would_revert);
+
+    let withdraw = client.simulate_action(&user, &SimulatedAction::Withdraw, &None, &10_000);
+    assert!(withdraw.would_revert);
+    assert_eq!(withdraw.collateral_value_after, 0);
+    assert_eq!(withdraw.health_factor_after, 0);
+    assert_eq!(withdraw.loan_to_value_after, i128::MAX);
+    assert!(withdraw.is_liquidatable_after);
+
+    let zero = client.simulate_action(&user, &SimulatedAction::Deposit, &None, &0);
+    assert!(zero.would_revert);
+}
+
+#[test]
+fn test_simulate_deposit_respects_cap() {
+    let (env, client, admin) = setup_pool();
+    let user = Address::generate(&env);
+    client.set_user_deposit_cap(&admin, &None, &Some(5_000));
+    client.deposit_collateral(&user, &None, &3_000);
+
+    let within = client.simulate_action(&user, &SimulatedAction::Deposit, &None, &2_000);
+    assert!(!within.would_revert);
+    assert_eq!(within.max_amount, 2_000);
+
+    let above = client.simulate_action(&user, &SimulatedAction::Deposit, &None, &2_001);
+    assert!(above.would_revert);
+}
+
+#[test]
+fn test_simulate_repay() {
+    let (env, client, admin) = setup_pool();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &10_000);
+
+    let no_debt = client.simulate_action(&user, &SimulatedAction::Repay, &None, &100);
+    assert!(no_debt.would_revert);
+
+    client.borrow_asset(&user, &None, &2_000);
+    // Native XLM repayments need the token address configured
+    let unconfigured = client.simulate_action(&user, &SimulatedAction::Repay, &None, &100);
+    assert!(unconfigured.would_revert);
+
+    let native = env.register_stellar_asset_contract(admin.clone());
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DepositDataKey::NativeAssetAddress, &native);
+    });
+    let overpay = client.simulate_action(&user, &SimulatedAction::Repay, &None, &5_000);
+    assert!(!overpay.would_revert);
+    assert_eq!(overpay.debt_value_after, 0);
+    assert_eq!(overpay.health_factor_after, i128::MAX);
+    assert!(!overpay.is_liquidatable_after);
+}
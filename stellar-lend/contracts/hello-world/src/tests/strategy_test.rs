@@ -0,0 +1,203 @@
+//! # Idle-Liquidity Strategy Tests
+//!
+//! Covers governance-controlled allocation of pool liquidity to an external
+//! strategy, the allocation cap, yield harvesting into reserves and automatic
+//! recall of principal before tokens leave the pool.
+
+#![cfg(test)]
+
+use crate::reserve::get_reserve_balance;
+use crate::strategy::{self, StrategyError};
+use crate::testutils::{self, setup_pool};
+use crate::types::ProposalType;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+/// Minimal strategy that holds tokens and reports its full balance
+#[contract]
+pub struct MockStrategy;
+
+#[contractimpl]
+impl MockStrategy {
+    pub fn init(env: Env, token: Address) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "token"), &token);
+    }
+
+    pub fn deposit(_env: Env, _from: Address, _amount: i128) {}
+
+    pub fn withdraw(env: Env, to: Address, amount: i128) {
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "token"))
+            .unwrap();
+        TokenClient::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+    }
+
+    pub fn balance(env: Env, _owner: Address) -> i128 {
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "token"))
+            .unwrap();
+        TokenClient::new(&env, &token).balance(&env.current_contract_address())
+    }
+}
+
+/// Pool holding 10_000 of `asset`, all supplied by `user`, with a strategy deployed
+fn setup() -> (
+    Env,
+    HelloContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let (env, client, admin) = setup_pool();
+
+    let asset = env.register_stellar_asset_contract(admin.clone());
+
+    let strategy_id = env.register(MockStrategy, ());
+    MockStrategyClient::new(&env, &strategy_id).init(&asset);
+
+    let user = Address::generate(&env);
+    testutils::supply(&env, &client, &user, &asset, 10_000);
+    testutils::advance_ledgers(&env, 1);
+    (env, client, admin, asset, strategy_id, user)
+}
+
+#[test]
+fn test_allocate_via_governance() {
+    let (env, client, admin, asset, strategy_id, _user) = setup();
+    testutils::setup_governance(&env, &client, &admin);
+
+    testutils::execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::SetStrategy(asset.clone(), strategy_id.clone(), 5_000),
+    );
+    testutils::execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::StrategyDeposit(asset.clone(), 4_000),
+    );
+
+    let token = TokenClient::new(&env, &asset);
+    assert_eq!(client.get_strategy_allocation(&asset), 4_000);
+    assert_eq!(token.balance(&client.address), 6_000);
+    assert_eq!(token.balance(&strategy_id), 4_000);
+    assert_eq!(client.get_available_liquidity(&asset), 10_000);
+
+    testutils::execute_governance_proposal(
+        &env,
+        &client,
+        &ProposalType::StrategyWithdraw(asset.clone(), 1_000),
+    );
+    assert_eq!(client.get_strategy_allocation(&asset), 3_000);
+    assert_eq!(token.balance(&client.address), 7_000);
+}
+
+#[test]
+fn test_allocation_cap() {
+    let (env, client, _admin, asset, strategy_id, _user) = setup();
+    // Tokens sent straight to the pool are not pool liquidity
+    StellarAssetClient::new(&env, &asset).mint(&client.address, &90_000);
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            strategy::set_strategy(&env, asset.clone(), strategy_id.clone(), 5_001),
+            Err(StrategyError::InvalidParameter)
+        );
+        strategy::set_strategy(&env, asset.clone(), strategy_id.clone(), 3_000).unwrap();
+        strategy::strategy_deposit(&env, asset.clone(), 3_000).unwrap();
+        assert_eq!(
+            strategy::strategy_deposit(&env, asset.clone(), 1),
+            Err(StrategyError::CapExceeded)
+        );
+    });
+}
+
+#[test]
+fn test_cannot_replace_funded_strategy() {
+    let (env, client, _admin, asset, strategy_id, _user) = setup();
+    let other = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        strategy::set_strategy(&env, asset.clone(), strategy_id.clone(), 5_000).unwrap();
+        strategy::strategy_deposit(&env, asset.clone(), 1_000).unwrap();
+        assert_eq!(
+            strategy::set_strategy(&env, asset.clone(), other.clone(), 5_000),
+            Err(StrategyError::StrategyActive)
+        );
+        // Adjusting the cap of the same strategy is allowed
+        strategy::set_strategy(&env, asset.clone(), strategy_id.clone(), 2_000).unwrap();
+    });
+}
+
+#[test]
+fn test_harvest_credits_reserves() {
+    let (env, client, _admin, asset, strategy_id, _user) = setup();
+    env.as_contract(&client.address, || {
+        strategy::set_strategy(&env, asset.clone(), strategy_id.clone(), 5_000).unwrap();
+        strategy::strategy_deposit(&env, asset.clone(), 5_000).unwrap();
+    });
+    assert_eq!(client.harvest_strategy(&asset), 0);
+
+    // Strategy earns 250
+    StellarAssetClient::new(&env, &asset).mint(&strategy_id, &250);
+    assert_eq!(client.harvest_strategy(&asset), 250);
+    assert_eq!(client.get_strategy_allocation(&asset), 5_000);
+    assert_eq!(
+        TokenClient::new(&env, &asset).balance(&client.address),
+        5_250
+    );
+    env.as_contract(&client.address, || {
+        assert_eq!(get_reserve_balance(&env, Some(asset.clone())), 250);
+    });
+}
+
+#[test]
+fn test_withdraw_recalls_from_strategy() {
+    let (env, client, _admin, asset, strategy_id, user) = setup();
+    env.as_contract(&client.address, || {
+        strategy::set_strategy(&env, asset.clone(), strategy_id.clone(), 5_000).unwrap();
+        strategy::strategy_deposit(&env, asset.clone(), 5_000).unwrap();
+    });
+
+    client.withdraw_collateral(&user, &Some(asset.clone()), &8_000);
+
+    let token = TokenClient::new(&env, &asset);
+    assert_eq!(token.balance(&user), 8_000);
+    assert_eq!(client.get_strategy_allocation(&asset), 2_000);
+    assert_eq!(client.get_available_liquidity(&asset), 2_000);
+}
+
+#[test]
+fn test_savings_exit_recalls_from_strategy() {
+    let (env, client, _admin, asset, strategy_id, user) = setup();
+    let saver = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&saver, &4_000);
+    client.join_savings(&saver, &asset, &4_000);
+    env.as_contract(&client.address, || {
+        strategy::set_strategy(&env, asset.clone(), strategy_id.clone(), 5_000).unwrap();
+        strategy::strategy_deposit(&env, asset.clone(), 5_000).unwrap();
+    });
+
+    // Suppliers drain the idle balance, so the saver's exit needs a recall
+    client.withdraw_collateral(&user, &Some(asset.clone()), &9_000);
+    client.exit_savings(&saver, &asset, &4_000);
+
+    assert_eq!(TokenClient::new(&env, &asset).balance(&saver), 4_000);
+    assert_eq!(client.get_strategy_allocation(&asset), 1_000);
+}
+
+#[test]
+fn test_harvest_requires_strategy() {
+    let (_env, client, _admin, asset, _strategy_id, _user) = setup();
+    assert_eq!(
+        client.try_harvest_strategy(&asset),
+        Err(Ok(StrategyError::NotConfigured))
+    );
+}
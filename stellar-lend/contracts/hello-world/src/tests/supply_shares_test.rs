@@ -0,0 +1,111 @@
+//! # Per-Asset Supply Interest Tests
+//!
+//! Covers supply interest settled separately per deposited asset: interest
+//! earned on a token deposit is claimable in that token, other assets'
+//! exchange rates do not pay on it, and auto-compounding grows the asset's
+//! own collateral.
+
+#![cfg(test)]
+
+use crate::supply_interest::SupplyInterestError;
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+const YEAR: u64 = 365 * 86_400;
+
+struct Setup {
+    env: Env,
+    client: HelloContractClient<'static>,
+    supplier: Address,
+    usdc: Address,
+    eurc: Address,
+}
+
+/// Mint `amount` of `asset` to `user` and deposit it as collateral
+fn deposit(env: &Env, client: &HelloContractClient, user: &Address, asset: &Address, amount: i128) {
+    StellarAssetClient::new(env, asset).mint(user, &amount);
+    TokenClient::new(env, asset).approve(user, &client.address, &amount, &1_000);
+    client.deposit_collateral(user, &Some(asset.clone()), &amount);
+}
+
+/// Supplier holding 5_000 USDC in a pool at 40% utilization (900 bps supply
+/// rate); EURC has a running exchange rate but the supplier holds none
+fn setup() -> Setup {
+    let (env, client, admin) = setup_pool();
+
+    let usdc = env.register_stellar_asset_contract(admin.clone());
+    let eurc = env.register_stellar_asset_contract(admin.clone());
+
+    let supplier = Address::generate(&env);
+    deposit(&env, &client, &supplier, &usdc, 5_000);
+    deposit(&env, &client, &Address::generate(&env), &eurc, 1);
+
+    let borrower = Address::generate(&env);
+    client.deposit_collateral(&borrower, &None, &4_999);
+    client.borrow_asset(&borrower, &None, &4_000);
+
+    Setup {
+        env,
+        client,
+        supplier,
+        usdc,
+        eurc,
+    }
+}
+
+#[test]
+fn test_interest_claimed_in_deposited_asset() {
+    let s = setup();
+    s.env.ledger().with_mut(|li| li.timestamp += YEAR);
+
+    s.client
+        .claim_supply_interest(&s.supplier, &Some(s.usdc.clone()));
+    assert_eq!(TokenClient::new(&s.env, &s.usdc).balance(&s.supplier), 450);
+    assert_eq!(
+        s.client
+            .get_claimable_interest_for(&s.supplier, &Some(s.usdc.clone())),
+        0
+    );
+    assert_eq!(s.client.get_claimable_interest(&s.supplier), 0);
+}
+
+#[test]
+fn test_other_asset_rate_does_not_pay_on_deposit() {
+    let s = setup();
+    s.env.ledger().with_mut(|li| li.timestamp += YEAR);
+
+    // EURC's exchange rate grew too, but the supplier holds no EURC
+    assert_eq!(
+        s.client
+            .try_claim_supply_interest(&s.supplier, &Some(s.eurc.clone())),
+        Err(Ok(SupplyInterestError::NothingToClaim))
+    );
+    s.client
+        .claim_supply_interest(&s.supplier, &Some(s.usdc.clone()));
+    assert_eq!(TokenClient::new(&s.env, &s.usdc).balance(&s.supplier), 450);
+}
+
+#[test]
+fn test_auto_compound_grows_asset_collateral() {
+    let s = setup();
+    s.client
+        .set_auto_compound(&s.supplier, &Some(s.usdc.clone()), &true);
+
+    s.env.ledger().with_mut(|li| li.timestamp += YEAR);
+    s.client
+        .set_auto_compound(&s.supplier, &Some(s.usdc.clone()), &false);
+
+    assert_eq!(
+        s.client
+            .get_asset_position(&s.supplier, &Some(s.usdc.clone()))
+            .collateral,
+        5_450
+    );
+    assert_eq!(
+        s.client.get_asset_position(&s.supplier, &None).collateral,
+        0
+    );
+}
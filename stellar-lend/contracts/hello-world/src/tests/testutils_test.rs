@@ -0,0 +1,99 @@
+//! # Test Utility Tests
+//!
+//! Exercises the helpers and cheat entry points exported under the
+//! `testutils` feature the way a downstream integration test would.
+
+#![cfg(test)]
+
+use crate::deposit::{DepositDataKey, Position};
+use crate::errors::GovernanceError;
+use crate::testutils::{
+    advance_ledgers, advance_time, build_position, create_funded_token, set_price,
+    setup_pool, setup_pool_with_native_asset,
+};
+use crate::types::{ProposalStatus, ProposalType, VoteType};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, String};
+
+#[test]
+fn test_native_pool_funded_and_usable() {
+    let (env, client, _admin, native_asset) = setup_pool_with_native_asset(1_000_000);
+    assert_eq!(
+        TokenClient::new(&env, &native_asset).balance(&client.address),
+        1_000_000
+    );
+
+    let user = Address::generate(&env);
+    build_position(&env, &client, &user, 50_000, 10_000);
+    let position = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get::<DepositDataKey, Position>(&DepositDataKey::Position(user.clone()))
+            .unwrap()
+    });
+    assert_eq!(position.collateral, 50_000);
+    assert_eq!(position.debt, 10_000);
+}
+
+#[test]
+fn test_prices_tokens_and_time() {
+    let (env, client, admin, _native_asset) = setup_pool_with_native_asset(0);
+    let holder = Address::generate(&env);
+    let token = create_funded_token(&env, &admin, &[(holder.clone(), 500)]);
+    assert_eq!(TokenClient::new(&env, &token).balance(&holder), 500);
+
+    set_price(&client, &admin, &token, 1_000);
+    assert_eq!(client.get_price(&token), 1_000);
+
+    let (t, seq) = (env.ledger().timestamp(), env.ledger().sequence());
+    advance_time(&env, 60);
+    advance_ledgers(&env, 10);
+    assert_eq!(env.ledger().timestamp(), t + 110);
+    assert_eq!(env.ledger().sequence(), seq + 10);
+}
+
+#[test]
+fn test_warp_proposal_through_lifecycle() {
+    let (env, client, admin) = setup_pool();
+    let token = create_funded_token(&env, &admin, &[(admin.clone(), 1_000_000)]);
+    client.gov_initialize(
+        &admin,
+        &token,
+        &Some(259_200),
+        &Some(86_400),
+        &Some(400),
+        &Some(100),
+        &Some(604_800),
+        &Some(5_000),
+    );
+    // Stake far enough ahead that the creation time, warped back by one
+    // second, still falls after the staking ledger
+    client.gov_stake(&admin, &1_000);
+    advance_time(&env, 2);
+
+    let id = client.gov_create_proposal(
+        &admin,
+        &ProposalType::ResetCircuitBreaker(None),
+        &String::from_str(&env, "Warp"),
+        &None,
+    );
+    client.test_warp_proposal(&id, &1);
+    client.gov_vote(&admin, &id, &VoteType::For);
+
+    // Voting period over without touching the ledger clock
+    client.test_warp_proposal(&id, &259_200);
+    client.gov_queue_proposal(&admin, &id);
+    let p = client.test_warp_proposal(&id, &86_400);
+    assert_eq!(p.execution_time, Some(env.ledger().timestamp()));
+    client.gov_execute_proposal(&admin, &id);
+    assert_eq!(
+        client.gov_get_proposal(&id).unwrap().status,
+        ProposalStatus::Executed
+    );
+
+    assert_eq!(
+        client.try_test_warp_proposal(&(id + 100), &1),
+        Err(Ok(GovernanceError::ProposalNotFound))
+    );
+}
@@ -0,0 +1,103 @@
+//! # Per-User Operation Throttle Tests
+//!
+//! Covers per-user borrow/withdrawal limits, window reset and isolation
+//! between users.
+
+#![cfg(test)]
+
+use crate::errors::ProtocolError;
+use crate::testutils::setup_pool;
+use crate::throttle::{ThrottleConfig, ThrottleError, ThrottledOperation};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+fn config() -> ThrottleConfig {
+    ThrottleConfig {
+        window_seconds: 3_600,
+        max_borrows: 2,
+        max_withdrawals: 3,
+    }
+}
+
+fn funded_user(env: &Env, client: &HelloContractClient<'static>) -> Address {
+    let user = Address::generate(env);
+    client.deposit_collateral(&user, &None, &1_000_000);
+    user
+}
+
+#[test]
+fn test_borrows_limited_per_window() {
+    let (env, client, admin) = setup_pool();
+    client.set_throttle_config(&admin, &config());
+    let user = funded_user(&env, &client);
+
+    client.borrow_asset(&user, &None, &1_000);
+    client.borrow_asset(&user, &None, &1_000);
+    assert_eq!(
+        client.get_operation_count(&user, &ThrottledOperation::Borrow),
+        2
+    );
+    assert_eq!(
+        client.try_borrow_asset(&user, &None, &1_000),
+        Err(Ok(ProtocolError::RateLimited))
+    );
+
+    // New window
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    assert_eq!(
+        client.get_operation_count(&user, &ThrottledOperation::Borrow),
+        0
+    );
+    client.borrow_asset(&user, &None, &1_000);
+}
+
+#[test]
+fn test_withdrawals_limited_independently() {
+    let (env, client, admin) = setup_pool();
+    client.set_throttle_config(&admin, &config());
+    let user = funded_user(&env, &client);
+    let other = funded_user(&env, &client);
+
+    for _ in 0..3 {
+        client.withdraw_collateral(&user, &None, &1_000);
+    }
+    assert_eq!(
+        client.try_withdraw_collateral(&user, &None, &1_000),
+        Err(Ok(ProtocolError::RateLimited))
+    );
+
+    // Borrow limit and other users are unaffected
+    client.borrow_asset(&user, &None, &1_000);
+    client.withdraw_collateral(&other, &None, &1_000);
+}
+
+#[test]
+fn test_zero_limit_is_unthrottled() {
+    let (env, client, admin) = setup_pool();
+    let mut cfg = config();
+    cfg.max_withdrawals = 0;
+    client.set_throttle_config(&admin, &cfg);
+    let user = funded_user(&env, &client);
+
+    for _ in 0..10 {
+        client.withdraw_collateral(&user, &None, &1_000);
+    }
+}
+
+#[test]
+fn test_config_validation_and_auth() {
+    let (env, client, admin) = setup_pool();
+    let mut bad = config();
+    bad.window_seconds = 0;
+    assert_eq!(
+        client.try_set_throttle_config(&admin, &bad),
+        Err(Ok(ThrottleError::InvalidParameter))
+    );
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_throttle_config(&stranger, &config()),
+        Err(Ok(ThrottleError::Unauthorized))
+    );
+}
@@ -0,0 +1,197 @@
+//! # Borrow and Repay Token Transfer Tests
+//!
+//! Covers borrowed tokens leaving the pool for the borrower, repayments
+//! pulled from the borrower, the liquidity, balance and allowance checks in
+//! front of those transfers, and the same flows with native XLM and with a
+//! token contract that is not a Stellar Asset Contract. Pool liquidity is
+//! always supplied through deposits, since only supply not lent out can be
+//! borrowed.
+
+#![cfg(test)]
+
+use crate::errors::ProtocolError;
+use crate::testutils::{setup_pool, supply};
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+/// Minimal token with balances and allowances, standing in for a custom
+/// (non-SAC) token contract
+#[contract]
+pub struct CustomToken;
+
+#[contractimpl]
+impl CustomToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("bal"), to), &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("bal"), id))
+            .unwrap_or(0)
+    }
+
+    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("allow"), from, spender))
+            .unwrap_or(0)
+    }
+
+    pub fn approve(env: Env, from: Address, spender: Address, amount: i128) {
+        from.require_auth();
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("allow"), from, spender), &amount);
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        Self::move_balance(&env, from, to, amount);
+    }
+
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        let allowance = Self::allowance(env.clone(), from.clone(), spender.clone());
+        assert!(allowance >= amount, "insufficient allowance");
+        env.storage().persistent().set(
+            &(symbol_short!("allow"), from.clone(), spender),
+            &(allowance - amount),
+        );
+        Self::move_balance(&env, from, to, amount);
+    }
+}
+
+impl CustomToken {
+    fn move_balance(env: &Env, from: Address, to: Address, amount: i128) {
+        let from_balance = Self::balance(env.clone(), from.clone());
+        assert!(from_balance >= amount, "insufficient balance");
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("bal"), from), &(from_balance - amount));
+        Self::mint(env.clone(), to, amount);
+    }
+}
+
+/// User with 10_000 of collateral supplied in a token of its own and no debt
+fn borrower(env: &Env, client: &HelloContractClient, admin: &Address) -> Address {
+    let user = Address::generate(env);
+    let collateral = env.register_stellar_asset_contract(admin.clone());
+    supply(env, client, &user, &collateral, 10_000);
+    user
+}
+
+#[test]
+fn test_borrow_and_repay_move_sac_tokens() {
+    let (env, client, admin) = setup_pool();
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let token = TokenClient::new(&env, &asset);
+    supply(&env, &client, &Address::generate(&env), &asset, 5_000);
+    let user = borrower(&env, &client, &admin);
+
+    client.borrow_asset(&user, &Some(asset.clone()), &1_000);
+    assert_eq!(token.balance(&user), 1_000);
+    assert_eq!(token.balance(&client.address), 4_000);
+
+    token.approve(&user, &client.address, &400, &1_000);
+    client.repay_debt(&user, &Some(asset.clone()), &400);
+    assert_eq!(token.balance(&user), 600);
+    assert_eq!(token.balance(&client.address), 4_400);
+    assert_eq!(client.get_asset_position(&user, &Some(asset)).debt, 600);
+}
+
+#[test]
+fn test_borrow_rejected_without_liquidity() {
+    let (env, client, admin) = setup_pool();
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    supply(&env, &client, &Address::generate(&env), &asset, 500);
+    let user = borrower(&env, &client, &admin);
+
+    // Tokens sent to the pool without a deposit are not lendable
+    StellarAssetClient::new(&env, &asset).mint(&client.address, &5_000);
+    assert_eq!(client.get_max_borrowable(&user, &Some(asset.clone())), 500);
+    assert_eq!(
+        client.try_borrow_asset(&user, &Some(asset.clone()), &1_000),
+        Err(Ok(ProtocolError::InsufficientLiquidity))
+    );
+    assert_eq!(client.get_asset_position(&user, &Some(asset)).debt, 0);
+}
+
+#[test]
+fn test_repay_checks_balance_and_allowance() {
+    let (env, client, admin) = setup_pool();
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let token = TokenClient::new(&env, &asset);
+    supply(&env, &client, &Address::generate(&env), &asset, 5_000);
+    let user = borrower(&env, &client, &admin);
+    client.borrow_asset(&user, &Some(asset.clone()), &1_000);
+
+    token.transfer(&user, &Address::generate(&env), &900);
+    assert_eq!(
+        client.try_repay_debt(&user, &Some(asset.clone()), &200),
+        Err(Ok(ProtocolError::InsufficientBalance))
+    );
+
+    assert_eq!(
+        client.try_repay_debt(&user, &Some(asset.clone()), &100),
+        Err(Ok(ProtocolError::InsufficientAllowance))
+    );
+
+    token.approve(&user, &client.address, &100, &1_000);
+    client.repay_debt(&user, &Some(asset.clone()), &100);
+    assert_eq!(token.balance(&user), 0);
+    assert_eq!(client.get_asset_position(&user, &Some(asset)).debt, 900);
+}
+
+#[test]
+fn test_borrow_and_repay_move_custom_tokens() {
+    let (env, client, admin) = setup_pool();
+    let asset = env.register(CustomToken, ());
+    let token = CustomTokenClient::new(&env, &asset);
+    let lender = Address::generate(&env);
+    token.mint(&lender, &5_000);
+    token.approve(&lender, &client.address, &5_000);
+    client.deposit_collateral(&lender, &Some(asset.clone()), &5_000);
+    let user = borrower(&env, &client, &admin);
+
+    client.borrow_asset(&user, &Some(asset.clone()), &1_000);
+    assert_eq!(token.balance(&user), 1_000);
+    assert_eq!(token.balance(&client.address), 4_000);
+
+    assert_eq!(
+        client.try_repay_debt(&user, &Some(asset.clone()), &400),
+        Err(Ok(ProtocolError::InsufficientAllowance))
+    );
+    token.approve(&user, &client.address, &400);
+    client.repay_debt(&user, &Some(asset.clone()), &400);
+    assert_eq!(token.balance(&user), 600);
+    assert_eq!(token.allowance(&user, &client.address), 0);
+}
+
+#[test]
+fn test_native_borrow_pays_out_xlm() {
+    let (env, client, admin) = setup_pool();
+    let native_asset = env.register_stellar_asset_contract(admin.clone());
+    client.set_native_asset_address(&admin, &native_asset);
+    let xlm = TokenClient::new(&env, &native_asset);
+    let lender = Address::generate(&env);
+    StellarAssetClient::new(&env, &native_asset).mint(&lender, &5_000);
+    xlm.approve(&lender, &client.address, &5_000, &1_000);
+    client.deposit_collateral(&lender, &None, &5_000);
+    let user = borrower(&env, &client, &admin);
+
+    client.borrow_asset(&user, &None, &2_000);
+    assert_eq!(xlm.balance(&user), 2_000);
+    assert_eq!(xlm.balance(&client.address), 3_000);
+
+    xlm.approve(&user, &client.address, &2_000, &1_000);
+    client.repay_debt(&user, &None, &2_000);
+    assert_eq!(xlm.balance(&user), 0);
+    assert_eq!(xlm.balance(&client.address), 5_000);
+}
@@ -0,0 +1,73 @@
+//! # Storage TTL Tests
+//!
+//! Covers extending position entries on access and through the
+//! permissionless bump entrypoints.
+
+#![cfg(test)]
+
+use crate::deposit::DepositDataKey;
+use crate::risk_management::RiskDataKey;
+use crate::testutils::setup_pool;
+use crate::ttl::BUMP_AMOUNT;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::storage::Persistent as _;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+/// New entries live for `BUMP_AMOUNT` ledgers, so nothing is archived when
+/// the tests skip half of that
+fn setup() -> (Env, HelloContractClient<'static>) {
+    let (env, client, _admin) = setup_pool();
+    env.ledger()
+        .with_mut(|li| li.min_persistent_entry_ttl = BUMP_AMOUNT);
+    // Entries written by `initialize` predate the TTL above
+    client.bump_protocol_state();
+    (env, client)
+}
+
+fn position_ttl(env: &Env, client: &HelloContractClient, user: &Address) -> u32 {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&DepositDataKey::Position(user.clone()))
+    })
+}
+
+fn skip_half_bump(env: &Env) {
+    env.ledger()
+        .with_mut(|li| li.sequence_number += BUMP_AMOUNT / 2 + 1);
+}
+
+#[test]
+fn test_position_extended_on_access() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+
+    client.deposit_collateral(&user, &None, &1_000);
+
+    // Once below the threshold, the next borrow extends it
+    skip_half_bump(&env);
+    client.borrow_asset(&user, &None, &100);
+    assert_eq!(position_ttl(&env, &client, &user), BUMP_AMOUNT);
+}
+
+#[test]
+fn test_permissionless_bumps() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &1_000);
+
+    skip_half_bump(&env);
+    assert!(position_ttl(&env, &client, &user) < BUMP_AMOUNT / 2);
+    client.bump_position(&user);
+    assert_eq!(position_ttl(&env, &client, &user), BUMP_AMOUNT);
+
+    client.bump_protocol_state();
+    let config_ttl = env.as_contract(&client.address, || {
+        env.storage().persistent().get_ttl(&RiskDataKey::RiskConfig)
+    });
+    assert_eq!(config_ttl, BUMP_AMOUNT);
+
+    // Accounts without storage are skipped
+    client.bump_position(&Address::generate(&env));
+}
@@ -0,0 +1,38 @@
+//! # Upgrade Tests
+//!
+//! Covers the initial contract version and the voting bar that applies to
+//! upgrade proposals.
+
+#![cfg(test)]
+
+use crate::types::{ProposalCategory, ProposalType};
+use crate::upgrade::{UpgradeDataKey, INITIAL_VERSION};
+use crate::{HelloContract, HelloContractClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env};
+
+#[test]
+fn test_version_starts_at_initial_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(HelloContract, ());
+    let client = HelloContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
+
+    assert_eq!(client.version(), INITIAL_VERSION);
+
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&UpgradeDataKey::Version, &3u32);
+    });
+    assert_eq!(client.version(), 3);
+}
+
+#[test]
+fn test_upgrade_proposal_uses_generic_category() {
+    let env = Env::default();
+    let proposal = ProposalType::UpgradeContract(BytesN::from_array(&env, &[7; 32]));
+    assert_eq!(proposal.category(), ProposalCategory::Generic);
+    assert_eq!(proposal.treasury_outflow(), None);
+}
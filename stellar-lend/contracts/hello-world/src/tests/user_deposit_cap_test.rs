@@ -0,0 +1,56 @@
+//! # Per-Account Deposit Cap Tests
+//!
+//! Covers the optional per-asset ceiling on a single account's collateral
+//! enforced by `deposit_collateral`.
+
+#![cfg(test)]
+
+use crate::errors::ProtocolError;
+use crate::testutils::setup_pool;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+#[test]
+fn test_deposit_cap_unlimited_by_default() {
+    let (env, client, _admin) = setup_pool();
+    assert_eq!(client.get_user_deposit_cap(&None), None);
+
+    let user = Address::generate(&env);
+    client.deposit_collateral(&user, &None, &1_000_000_000);
+}
+
+#[test]
+fn test_deposit_cap_enforced_per_account() {
+    let (env, client, admin) = setup_pool();
+    client.set_user_deposit_cap(&admin, &None, &Some(10_000));
+    assert_eq!(client.get_user_deposit_cap(&None), Some(10_000));
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.deposit_collateral(&alice, &None, &6_000);
+    assert_eq!(
+        client.try_deposit_collateral(&alice, &None, &4_001),
+        Err(Ok(ProtocolError::CapExceeded))
+    );
+    assert_eq!(client.deposit_collateral(&alice, &None, &4_000), 10_000);
+
+    // Each account has its own headroom
+    client.deposit_collateral(&bob, &None, &10_000);
+
+    client.set_user_deposit_cap(&admin, &None, &None);
+    client.deposit_collateral(&alice, &None, &1);
+}
+
+#[test]
+fn test_deposit_cap_admin_only_and_positive() {
+    let (env, client, admin) = setup_pool();
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_user_deposit_cap(&stranger, &None, &Some(10_000)),
+        Err(Ok(ProtocolError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_user_deposit_cap(&admin, &None, &Some(0)),
+        Err(Ok(ProtocolError::InvalidAmount))
+    );
+}
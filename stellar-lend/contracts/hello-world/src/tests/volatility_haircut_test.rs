@@ -0,0 +1,99 @@
+//! # Volatility Haircut Tests
+//!
+//! Covers the tiered collateral factor haircut driven by oracle price
+//! volatility and its effect on borrow capacity.
+
+#![cfg(test)]
+
+use crate::deposit::{AssetParams, DepositDataKey, Position};
+use crate::risk_management::RiskManagementError;
+use crate::risk_params::VolatilityHaircut;
+use crate::testutils::setup_pool;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, admin) = setup_pool();
+
+    let asset = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DepositDataKey::AssetParams(asset.clone()),
+            &AssetParams {
+                deposit_enabled: true,
+                collateral_factor: 10_000,
+                max_deposit: 0,
+                borrow_fee_bps: 0,
+            },
+        );
+    });
+
+    (env, client, admin, asset)
+}
+
+fn tiers() -> VolatilityHaircut {
+    VolatilityHaircut {
+        threshold_bps: 50,
+        haircut_bps: 2_000,
+        severe_threshold_bps: 300,
+        severe_haircut_bps: 5_000,
+    }
+}
+
+#[test]
+fn test_haircut_follows_volatility_tiers() {
+    let (_env, client, admin, asset) = setup();
+    client.set_volatility_haircut(&admin, &asset, &tiers());
+
+    client.update_price_feed(&admin, &asset, &10_000_000, &7, &admin);
+    assert_eq!(client.get_collateral_haircut(&asset), 0);
+
+    // A 5% move gives an EWMA volatility of 100 bps
+    client.update_price_feed(&admin, &asset, &10_500_000, &7, &admin);
+    assert_eq!(client.get_price_volatility(&asset), 100);
+    assert_eq!(client.get_collateral_haircut(&asset), 2_000);
+}
+
+#[test]
+fn test_haircut_reduces_borrow_capacity() {
+    let (env, client, admin, asset) = setup();
+    let user = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DepositDataKey::Position(user.clone()),
+            &Position {
+                collateral: 10_000,
+                debt: 0,
+                borrow_interest: 0,
+                last_accrual_time: 0,
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&DepositDataKey::CollateralBalance(user.clone()), &10_000i128);
+    });
+    client.update_price_feed(&admin, &asset, &10_000_000, &7, &admin);
+    client.update_price_feed(&admin, &asset, &10_500_000, &7, &admin);
+
+    let calm = client
+        .preview_borrow(&user, &Some(asset.clone()), &100)
+        .max_borrowable;
+    client.set_volatility_haircut(&admin, &asset, &tiers());
+    let volatile = client
+        .preview_borrow(&user, &Some(asset.clone()), &100)
+        .max_borrowable;
+
+    assert_eq!(volatile, calm * 8_000 / 10_000);
+}
+
+#[test]
+fn test_invalid_tiers_rejected() {
+    let (_env, client, admin, asset) = setup();
+    let mut bad = tiers();
+    bad.severe_haircut_bps = 1_000;
+    assert_eq!(
+        client.try_set_volatility_haircut(&admin, &asset, &bad),
+        Err(Ok(RiskManagementError::InvalidParameter))
+    );
+}
@@ -0,0 +1,78 @@
+//! # Withdraw-All and Repay-All Tests
+//!
+//! Covers the `i128::MAX` sentinels of `withdraw_collateral` and `repay_debt`:
+//! they resolve to the exact balance or debt, including interest accrued in
+//! the same call, and leave no dust behind.
+
+#![cfg(test)]
+
+use crate::errors::ProtocolError;
+use crate::repay::REPAY_ALL;
+use crate::testutils::setup_pool;
+use crate::withdraw::WITHDRAW_ALL;
+use crate::HelloContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+fn setup() -> (Env, HelloContractClient<'static>, Address) {
+    let (env, client, admin) = setup_pool();
+    let native_asset = env.register_stellar_asset_contract(admin.clone());
+    client.set_native_asset_address(&admin, &native_asset);
+
+    (env, client, native_asset)
+}
+
+/// A user holding 20_000 native XLM, all approved for the pool to pull, so
+/// there is enough left after a deposit to repay with interest
+fn funded_user(env: &Env, client: &HelloContractClient, native_asset: &Address) -> Address {
+    let user = Address::generate(env);
+    StellarAssetClient::new(env, native_asset).mint(&user, &20_000);
+    TokenClient::new(env, native_asset).approve(&user, &client.address, &20_000, &1_000_000);
+    user
+}
+
+#[test]
+fn test_repay_all_settles_accrued_interest() {
+    let (env, client, native_asset) = setup();
+    let user = funded_user(&env, &client, &native_asset);
+    client.deposit_collateral(&user, &None, &10_000);
+    client.borrow_asset(&user, &None, &5_000);
+
+    // 50% utilization: 13.5% for a year
+    env.ledger().with_mut(|li| li.timestamp += 365 * 86_400);
+    assert_eq!(client.repay_debt(&user, &None, &REPAY_ALL), (0, 675, 5_000));
+
+    assert_eq!(client.get_position_summary(&user).debt_value, 0);
+    assert_eq!(client.get_asset_position(&user, &None).debt, 0);
+}
+
+#[test]
+fn test_withdraw_all_empties_balance() {
+    let (env, client, native_asset) = setup();
+    let user = funded_user(&env, &client, &native_asset);
+    client.deposit_collateral(&user, &None, &10_000);
+
+    assert_eq!(client.withdraw_collateral(&user, &None, &WITHDRAW_ALL), 0);
+    assert_eq!(client.get_max_withdrawable(&user, &None), 0);
+    assert_eq!(
+        client.try_withdraw_collateral(&user, &None, &WITHDRAW_ALL),
+        Err(Ok(ProtocolError::InsufficientCollateral))
+    );
+}
+
+#[test]
+fn test_withdraw_all_rejected_with_debt() {
+    let (env, client, native_asset) = setup();
+    let user = funded_user(&env, &client, &native_asset);
+    client.deposit_collateral(&user, &None, &10_000);
+    client.borrow_asset(&user, &None, &1_000);
+
+    assert_eq!(
+        client.try_withdraw_collateral(&user, &None, &WITHDRAW_ALL),
+        Err(Ok(ProtocolError::InsufficientCollateralRatio))
+    );
+
+    client.repay_debt(&user, &None, &REPAY_ALL);
+    assert_eq!(client.withdraw_collateral(&user, &None, &WITHDRAW_ALL), 0);
+}
@@ -0,0 +1,279 @@
+//! # Test Utilities
+//!
+//! Integration helpers for contracts that build on the lending pool, so their
+//! tests can stand up a realistic pool without copying this crate's test
+//! module. Enabled with the `testutils` cargo feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! hello-world = { path = "../hello-world", features = ["testutils"] }
+//! ```
+//!
+//! - [`setup_pool`] registers and initializes the pool with all auths mocked.
+//! - [`setup_pool_with_native_asset`] also wires up a native asset contract
+//!   and funds the pool with borrowable liquidity.
+//! - [`create_funded_token`] registers a Stellar asset contract and mints
+//!   balances to holders.
+//! - [`set_price`] posts an oracle price as the pool admin.
+//! - [`advance_time`] and [`advance_ledgers`] fast-forward the ledger.
+//! - [`build_position`] writes a position straight into storage.
+//! - [`supply`] mints a Stellar asset to a supplier and deposits it through
+//!   the real entry point, giving the pool recorded lendable liquidity.
+//! - [`setup_governance`] initializes governance with a fresh vote token, and
+//!   [`queue_governance_proposal`] / [`execute_governance_proposal`] carry a
+//!   proposal through a passing vote using `test_warp_proposal`.
+//!
+//! ## Cheat Entry Points
+//! The same feature adds contract entry points that encapsulate state
+//! manipulation tests would otherwise do through raw storage, so they keep
+//! working when storage layouts change:
+//! - `test_set_position` — overwrite a user's position.
+//! - `test_warp_proposal` — move a proposal's clock back as if time had passed
+//!   for it alone.
+//!
+//! ## Security
+//! - Compiled only for this crate's tests and with the `testutils` feature;
+//!   never part of a release build.
+
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contractimpl, Address, Env, String};
+
+use crate::deposit::{DepositDataKey, Position};
+use crate::errors::GovernanceError;
+use crate::storage::GovernanceDataKey;
+use crate::types::{Proposal, ProposalType, VoteType};
+use crate::{HelloContract, HelloContractClient};
+
+/// Decimals used by [`set_price`]
+pub const PRICE_DECIMALS: u32 = 8;
+
+/// Voting period set by [`setup_governance`] (3 days)
+pub const GOVERNANCE_VOTING_PERIOD: u64 = 259_200;
+
+/// Execution delay set by [`setup_governance`] (1 day)
+pub const GOVERNANCE_EXECUTION_DELAY: u64 = 86_400;
+
+/// Vote tokens the voter in [`queue_governance_proposal`] stakes
+pub const GOVERNANCE_VOTER_STAKE: i128 = 1_000_000;
+
+/// Register and initialize the pool with all auths mocked
+///
+/// Returns the environment, a client for the pool and its admin.
+pub fn setup_pool() -> (Env, HelloContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(HelloContract, ());
+    let client = HelloContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    // Leak env to get 'static lifetime for the client
+    let env: &'static Env = Box::leak(Box::new(env));
+    let client = HelloContractClient::new(env, &contract_id);
+    (env.clone(), client, admin)
+}
+
+/// [`setup_pool`] plus a native asset contract with `liquidity` supplied to
+/// the pool by a lender
+///
+/// Deposits, borrows and repays with `asset = None` go through the returned
+/// native asset contract.
+pub fn setup_pool_with_native_asset(
+    liquidity: i128,
+) -> (Env, HelloContractClient<'static>, Address, Address) {
+    let (env, client, admin) = setup_pool();
+    let lender = Address::generate(&env);
+    let native_asset = create_funded_token(&env, &admin, &[(lender.clone(), liquidity)]);
+    client.set_native_asset_address(&admin, &native_asset);
+    if liquidity > 0 {
+        TokenClient::new(&env, &native_asset).approve(
+            &lender,
+            &client.address,
+            &liquidity,
+            &(env.ledger().sequence() + 1_000),
+        );
+        client.deposit_collateral(&lender, &None, &liquidity);
+    }
+    (env, client, admin, native_asset)
+}
+
+/// Register a Stellar asset contract and mint `amount` to each holder
+pub fn create_funded_token(env: &Env, admin: &Address, holders: &[(Address, i128)]) -> Address {
+    let token = env.register_stellar_asset_contract(admin.clone());
+    let sac = StellarAssetClient::new(env, &token);
+    for (holder, amount) in holders {
+        if *amount > 0 {
+            sac.mint(holder, amount);
+        }
+    }
+    token
+}
+
+/// Post an oracle price for `asset` as the pool admin
+///
+/// Subject to the oracle's usual deviation checks, so large jumps should be
+/// made in steps.
+pub fn set_price(client: &HelloContractClient, admin: &Address, asset: &Address, price: i128) {
+    client.update_price_feed(admin, asset, &price, &PRICE_DECIMALS, admin);
+}
+
+/// Move the ledger timestamp forward by `seconds`
+pub fn advance_time(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+/// Move the ledger sequence forward by `ledgers`, at roughly 5 seconds each
+pub fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| {
+        li.sequence_number += ledgers;
+        li.timestamp += ledgers as u64 * 5;
+    });
+}
+
+/// Write a position for `user` directly into the pool's storage
+///
+/// Skips token transfers, analytics and protocol totals; use the real
+/// deposit and borrow entry points when those matter to the test.
+pub fn build_position(
+    env: &Env,
+    client: &HelloContractClient,
+    user: &Address,
+    collateral: i128,
+    debt: i128,
+) {
+    client.test_set_position(user, &collateral, &debt, &0);
+}
+
+/// Mint `amount` of the Stellar asset `asset` to `supplier` and deposit it
+///
+/// Unlike [`build_position`] this goes through the real deposit entry point,
+/// so token balances, protocol totals and the per-asset ledger all record the
+/// supply.
+pub fn supply(
+    env: &Env,
+    client: &HelloContractClient,
+    supplier: &Address,
+    asset: &Address,
+    amount: i128,
+) {
+    StellarAssetClient::new(env, asset).mint(supplier, &amount);
+    TokenClient::new(env, asset).approve(
+        supplier,
+        &client.address,
+        &amount,
+        &(env.ledger().sequence() + 1_000),
+    );
+    client.deposit_collateral(supplier, &Some(asset.clone()), &amount);
+}
+
+/// Initialize governance with a fresh vote token and return the token
+///
+/// Uses [`GOVERNANCE_VOTING_PERIOD`], [`GOVERNANCE_EXECUTION_DELAY`], a 4%
+/// quorum, a 100-token proposal threshold, a 7-day timelock and a 50% voting
+/// threshold.
+pub fn setup_governance(env: &Env, client: &HelloContractClient, admin: &Address) -> Address {
+    let vote_token = create_funded_token(env, admin, &[]);
+    client.gov_initialize(
+        admin,
+        &vote_token,
+        &Some(GOVERNANCE_VOTING_PERIOD),
+        &Some(GOVERNANCE_EXECUTION_DELAY),
+        &Some(400),
+        &Some(100),
+        &Some(604_800),
+        &Some(5_000),
+    );
+    vote_token
+}
+
+/// Propose `proposal`, vote it through and queue it, ready to execute
+///
+/// A fresh voter stakes [`GOVERNANCE_VOTER_STAKE`] vote tokens, proposes and
+/// votes for it. The proposal's own clock is then warped past the voting
+/// period and execution delay with `test_warp_proposal`, so the ledger only
+/// moves the two seconds staking and voting need. Works with any governance
+/// configuration whose vote token is a Stellar asset contract.
+///
+/// Returns the proposal id and the voter.
+pub fn queue_governance_proposal(
+    env: &Env,
+    client: &HelloContractClient,
+    proposal: &ProposalType,
+) -> (u64, Address) {
+    let config = client.gov_get_config().expect("governance not initialized");
+    let voter = Address::generate(env);
+    StellarAssetClient::new(env, &config.vote_token).mint(&voter, &GOVERNANCE_VOTER_STAKE);
+    client.gov_stake(&voter, &GOVERNANCE_VOTER_STAKE);
+    advance_time(env, 1);
+
+    let id =
+        client.gov_create_proposal(&voter, proposal, &String::from_str(env, "Proposal"), &None);
+    advance_time(env, 1);
+    client.gov_vote(&voter, &id, &VoteType::For);
+
+    client.test_warp_proposal(&id, &config.voting_period);
+    client.gov_queue_proposal(&voter, &id);
+    client.test_warp_proposal(&id, &config.execution_delay);
+    (id, voter)
+}
+
+/// [`queue_governance_proposal`], then execute the proposal
+///
+/// Returns the proposal id.
+pub fn execute_governance_proposal(
+    env: &Env,
+    client: &HelloContractClient,
+    proposal: &ProposalType,
+) -> u64 {
+    let (id, voter) = queue_governance_proposal(env, client, proposal);
+    client.gov_execute_proposal(&voter, &id);
+    id
+}
+
+#[contractimpl]
+impl HelloContract {
+    /// Overwrite a user's position and collateral balance (testutils only).
+    pub fn test_set_position(
+        env: Env,
+        user: Address,
+        collateral: i128,
+        debt: i128,
+        borrow_interest: i128,
+    ) {
+        env.storage().persistent().set(
+            &DepositDataKey::Position(user.clone()),
+            &Position {
+                collateral,
+                debt,
+                borrow_interest,
+                last_accrual_time: env.ledger().timestamp(),
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&DepositDataKey::CollateralBalance(user), &collateral);
+    }
+
+    /// Shift a proposal's start, end, creation and execution times back by `seconds` (testutils only).
+    pub fn test_warp_proposal(
+        env: Env,
+        proposal_id: u64,
+        seconds: u64,
+    ) -> Result<Proposal, GovernanceError> {
+        let key = GovernanceDataKey::Proposal(proposal_id);
+        let mut proposal: Proposal = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(GovernanceError::ProposalNotFound)?;
+
+        proposal.start_time = proposal.start_time.saturating_sub(seconds);
+        proposal.end_time = proposal.end_time.saturating_sub(seconds);
+        proposal.created_at = proposal.created_at.saturating_sub(seconds);
+        proposal.execution_time = proposal.execution_time.map(|t| t.saturating_sub(seconds));
+        env.storage().persistent().set(&key, &proposal);
+
+        Ok(proposal)
+    }
+}
@@ -0,0 +1,174 @@
+//! # Operation Throttle Module
+//!
+//! Per-user rate limits on borrows and withdrawals.
+//!
+//! The admin configures a fixed window length and a maximum number of borrows
+//! and withdrawals a single user may make inside one window. Each user has a
+//! timestamped counter per operation; the counter resets when a call arrives
+//! after the window has elapsed.
+//!
+//! Limits are meant to be generous enough that normal users never notice them
+//! while slowing down automated attack loops that need many calls in a short
+//! time.
+//!
+//! ## Security
+//! - Configuration is admin-only and throttling is disabled until configured.
+//! - A limit of `0` leaves that operation unthrottled.
+
+#![allow(unused)]
+use soroban_sdk::{contracterror, contracttype, Address, Env, Symbol};
+
+/// Errors that can occur during throttle operations
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ThrottleError {
+    /// Caller is not authorized
+    Unauthorized = 1,
+    /// A parameter is out of range
+    InvalidParameter = 2,
+    /// User exceeded the operation limit for the current window
+    RateLimited = 3,
+}
+
+/// Operations subject to per-user throttling
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ThrottledOperation {
+    Borrow,
+    Withdraw,
+}
+
+/// Storage keys for throttle data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum ThrottleDataKey {
+    /// Rate limit configuration: ThrottleConfig
+    Config,
+    /// Per-user counter per operation: OperationCounter
+    Counter(Address, ThrottledOperation),
+}
+
+/// Per-user rate limit configuration
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThrottleConfig {
+    /// Window length in seconds
+    pub window_seconds: u64,
+    /// Maximum borrows per user per window (0 = unlimited)
+    pub max_borrows: u32,
+    /// Maximum withdrawals per user per window (0 = unlimited)
+    pub max_withdrawals: u32,
+}
+
+/// A user's operation count in the current window
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OperationCounter {
+    /// Timestamp the current window started at
+    pub window_start: u64,
+    pub count: u32,
+}
+
+/// Set the per-user rate limits (admin only)
+///
+/// # Errors
+/// * `ThrottleError::Unauthorized` - If caller is not admin
+/// * `ThrottleError::InvalidParameter` - If `window_seconds` is zero
+#[allow(deprecated)]
+pub fn set_config(env: &Env, caller: Address, config: ThrottleConfig) -> Result<(), ThrottleError> {
+    crate::admin::require_role(env, &caller, crate::admin::Role::RiskAdmin)
+        .map_err(|_| ThrottleError::Unauthorized)?;
+
+    if config.window_seconds == 0 {
+        return Err(ThrottleError::InvalidParameter);
+    }
+
+    env.storage().persistent().set(&ThrottleDataKey::Config, &config);
+
+    let topics = (Symbol::new(env, "throttle_config_updated"), caller);
+    env.events().publish(
+        topics,
+        (
+            config.window_seconds,
+            config.max_borrows,
+            config.max_withdrawals,
+        ),
+    );
+
+    Ok(())
+}
+
+/// Get the per-user rate limit configuration
+pub fn get_config(env: &Env) -> Option<ThrottleConfig> {
+    env.storage().persistent().get(&ThrottleDataKey::Config)
+}
+
+/// Operations `user` has made in the current window
+pub fn get_operation_count(env: &Env, user: &Address, operation: ThrottledOperation) -> u32 {
+    let config = match get_config(env) {
+        Some(config) => config,
+        None => return 0,
+    };
+    let counter: Option<OperationCounter> = env
+        .storage()
+        .persistent()
+        .get(&ThrottleDataKey::Counter(user.clone(), operation));
+    match counter {
+        Some(counter)
+            if env.ledger().timestamp()
+                < counter.window_start.saturating_add(config.window_seconds) =>
+        {
+            counter.count
+        }
+        _ => 0,
+    }
+}
+
+/// Count an operation by `user`, rejecting it if the window's limit is reached
+///
+/// # Errors
+/// * `ThrottleError::RateLimited` - If `user` already made the maximum number
+///   of `operation` calls in the current window
+pub fn check_and_record(
+    env: &Env,
+    user: &Address,
+    operation: ThrottledOperation,
+) -> Result<(), ThrottleError> {
+    let config = match get_config(env) {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+    let limit = match operation {
+        ThrottledOperation::Borrow => config.max_borrows,
+        ThrottledOperation::Withdraw => config.max_withdrawals,
+    };
+    if limit == 0 {
+        return Ok(());
+    }
+
+    let now = env.ledger().timestamp();
+    let key = ThrottleDataKey::Counter(user.clone(), operation);
+    let mut counter = match env
+        .storage()
+        .persistent()
+        .get::<ThrottleDataKey, OperationCounter>(&key)
+    {
+        Some(counter) if now < counter.window_start.saturating_add(config.window_seconds) => {
+            counter
+        }
+        _ => OperationCounter {
+            window_start: now,
+            count: 0,
+        },
+    };
+
+    if counter.count >= limit {
+        return Err(ThrottleError::RateLimited);
+    }
+    counter.count += 1;
+    env.storage().persistent().set(&key, &counter);
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! # Storage TTL Module
+//!
+//! Keeps persistent entries from being archived.
+//!
+//! Soroban persistent and instance entries carry a time-to-live in ledgers
+//! and are archived once it runs out. Every flow that touches a user's
+//! position calls [`bump_position`], which extends the TTL of the user's
+//! position entries and of the contract instance whenever it has fallen below
+//! [`BUMP_THRESHOLD`]. Users and protocol-wide state that see no traffic can
+//! be kept alive by anyone through the permissionless [`bump_position`] and
+//! [`bump_protocol_state`] entrypoints.
+//!
+//! Entries that do not exist are skipped, so bumping never fails.
+
+#![allow(unused)]
+use soroban_sdk::{Address, Env, IntoVal, Val};
+
+use crate::activity::ActivityDataKey;
+use crate::admin::AdminDataKey;
+use crate::analytics::AnalyticsDataKey;
+use crate::asset_positions::AssetPositionDataKey;
+use crate::deposit::DepositDataKey;
+use crate::emode::EModeDataKey;
+use crate::interest_rate::InterestRateDataKey;
+use crate::migrations::MigrationsDataKey;
+use crate::risk_management::RiskDataKey;
+use crate::risk_params::RiskParamsDataKey;
+use crate::upgrade::UpgradeDataKey;
+
+/// Ledgers in one day at ~5 seconds per ledger
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Remaining TTL below which an entry is extended (30 days)
+pub const BUMP_THRESHOLD: u32 = 30 * DAY_IN_LEDGERS;
+
+/// TTL an entry is extended to (60 days)
+pub const BUMP_AMOUNT: u32 = 60 * DAY_IN_LEDGERS;
+
+/// Extend the contract instance's TTL
+pub fn bump_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(BUMP_THRESHOLD, BUMP_AMOUNT);
+}
+
+/// Extend a persistent entry's TTL if it exists
+pub fn bump_persistent<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    let storage = env.storage().persistent();
+    if storage.has(key) {
+        storage.extend_ttl(key, BUMP_THRESHOLD, BUMP_AMOUNT);
+    }
+}
+
+/// Extend the TTL of `user`'s position, per-asset ledger, borrow index
+/// snapshots, user metrics and latest activity page
+pub fn bump_position(env: &Env, user: &Address) {
+    bump_instance(env);
+
+    bump_persistent(env, &DepositDataKey::Position(user.clone()));
+    bump_persistent(env, &DepositDataKey::CollateralBalance(user.clone()));
+    bump_persistent(env, &DepositDataKey::UserAnalytics(user.clone()));
+    bump_persistent(env, &AnalyticsDataKey::UserMetrics(user.clone()));
+    bump_persistent(env, &AnalyticsDataKey::BorrowerRiskLevel(user.clone()));
+    bump_persistent(env, &EModeDataKey::UserCategory(user.clone()));
+    bump_persistent(env, &ActivityDataKey::Index(user.clone()));
+    let last_page = crate::activity::get_activity_index(env, user).last_page;
+    bump_persistent(env, &ActivityDataKey::Page(user.clone(), last_page));
+
+    bump_persistent(env, &AssetPositionDataKey::UserAssets(user.clone()));
+    bump_persistent(env, &InterestRateDataKey::PositionIndex(user.clone(), None));
+    for asset in crate::asset_positions::get_user_assets(env, user).iter() {
+        bump_persistent(
+            env,
+            &AssetPositionDataKey::Position(user.clone(), asset.clone()),
+        );
+        bump_persistent(
+            env,
+            &InterestRateDataKey::PositionIndex(user.clone(), asset),
+        );
+    }
+}
+
+/// Extend the TTL of the instance and of protocol-wide configuration and
+/// analytics entries
+pub fn bump_protocol_state(env: &Env) {
+    bump_instance(env);
+
+    bump_persistent(env, &AdminDataKey::Admin);
+    bump_persistent(env, &DepositDataKey::ProtocolAnalytics);
+    bump_persistent(env, &RiskDataKey::RiskConfig);
+    bump_persistent(env, &RiskDataKey::EmergencyPause);
+    bump_persistent(env, &RiskParamsDataKey::RiskParamsConfig);
+    bump_persistent(env, &InterestRateDataKey::InterestRateConfig);
+    bump_persistent(env, &AnalyticsDataKey::ProtocolMetrics);
+    bump_persistent(env, &AnalyticsDataKey::ActivityLog);
+    bump_persistent(env, &AnalyticsDataKey::TotalUsers);
+    bump_persistent(env, &AnalyticsDataKey::TotalTransactions);
+    bump_persistent(env, &AnalyticsDataKey::HealthDistribution);
+    bump_persistent(env, &AssetPositionDataKey::SupportedAssets);
+    bump_persistent(env, &MigrationsDataKey::SchemaVersion);
+    bump_persistent(env, &UpgradeDataKey::Version);
+}
@@ -1,4 +1,16 @@
-use soroban_sdk::{contracttype, Address, Bytes, String, Symbol, Val, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Symbol, Val, Vec};
+
+use crate::analytics::AnalyticsVerbosity;
+use crate::asset_registry::AssetListing;
+use crate::credit_history::CreditHistoryConfig;
+use crate::deposit::AssetParams;
+use crate::emode::EModeCategory;
+use crate::interest_rate::AssetRateModel;
+use crate::keeper::KeeperConfig;
+use crate::oracle::AssetOracleConfig;
+use crate::rewards::{EmissionSchedule, RewardMarket};
+use crate::risk_engine::{CollateralBand, RateBand};
+use crate::risk_tier::{RiskTier, TierParams};
 
 // ========================================================================
 // Proposal Types
@@ -44,13 +56,207 @@ pub enum ProposalType {
         Option<bool>,
     ),
     /// Pause/unpause operation
-    PauseSwitch(Symbol, bool),
+    PauseSwitch(crate::risk_management::PauseOperation, bool),
     /// Emergency pause
     EmergencyPause(bool),
+    /// Seed accrued reserves into the pool as protocol-owned liquidity (asset, amount)
+    SeedProtocolLiquidity(Option<Address>, i128),
+    /// Set the share of liquidation incentives routed to the insurance fund (bps)
+    LiquidationInsuranceShare(i128),
+    /// Enable/disable the risk engine and set its per-asset cooldown (enabled, cooldown_seconds)
+    RiskEngineSettings(bool, u64),
+    /// Set the band within which the risk engine may move the base rate
+    RiskEngineRateBand(RateBand),
+    /// Set the band within which the risk engine may move an asset's collateral factor
+    RiskEngineCollateralBand(Option<Address>, CollateralBand),
+    /// Undo an automatic risk engine adjustment by id
+    RevertRiskAdjustment(u64),
+    /// Post a Merkle-root claim campaign (token, merkle_root, total_amount)
+    CreateClaimCampaign(Address, BytesN<32>, i128),
+    /// Set or end the liquidity mining schedule of a market (market, schedule)
+    RewardEmission(RewardMarket, Option<EmissionSchedule>),
+    /// Set an asset's oracle configuration (asset, config)
+    AssetOracleConfig(Address, AssetOracleConfig),
+    /// Set the yield strategy for an asset (asset, strategy, max_allocation_bps)
+    SetStrategy(Address, Address, i128),
+    /// Move idle liquidity into an asset's strategy (asset, amount)
+    StrategyDeposit(Address, i128),
+    /// Recall principal from an asset's strategy (asset, amount)
+    StrategyWithdraw(Address, i128),
+    /// Configure history-based collateral factor adjustments
+    CreditHistoryConfig(CreditHistoryConfig),
+    /// Configure the keeper registry
+    KeeperConfig(KeeperConfig),
+    /// Slash a keeper's bond into reserves (keeper, amount)
+    SlashKeeper(Address, i128),
+    /// Set the maximum risk parameter change per admin update (bps of the old value)
+    ParameterChangeLimit(i128),
+    /// Set the duration over which collateral factor and liquidation threshold changes ramp in (seconds)
+    ParameterRampDuration(u64),
+    /// Change a risk tier's parameters and re-apply them to its assets
+    RiskTierParams(RiskTier, TierParams),
+    /// Set the minimum collateral holding period before borrowing (asset, seconds)
+    MinHoldingPeriod(Option<Address>, u64),
+    /// Clear a tripped volume circuit breaker for an asset
+    ResetCircuitBreaker(Option<Address>),
+    /// Return mistakenly sent non-protocol tokens (asset, amount, recipient)
+    RescueTokens(Address, i128, Address),
+    /// Forgive a borrower's debt, funded from reserves (borrower, asset, amount)
+    ForgiveDebt(Address, Option<Address>, i128),
+    /// Set (or clear with `None`) the per-voter vote weight cap
+    VoteWeightCap(Option<VoteWeightCap>),
+    /// Enable or disable quadratic vote weighting for new proposals
+    QuadraticVoting(bool),
+    /// Set (or clear with `None`) the voting bar for a proposal category
+    CategoryThreshold(ProposalCategory, Option<CategoryThreshold>),
+    /// Configure (or disable with `None`) emergency fast-track proposals
+    EmergencyTrackConfig(Option<EmergencyTrackConfig>),
+    /// Set (or clear with `None`) the multisig's rolling 24h spend limit for an asset
+    MultisigSpendLimit(Option<Address>, Option<i128>),
+    /// Set how much telemetry the flows record
+    AnalyticsVerbosity(AnalyticsVerbosity),
+    /// Set the hysteresis buffer around the liquidation threshold (ratio bps)
+    LiquidationHysteresis(i128),
+    /// Set an asset's savings rate, funded from its reserve (asset, rate_bps)
+    SavingsRate(Address, i128),
+    /// Set (or clear with `None`) an asset's interest rate curve
+    AssetRateModel(Option<Address>, Option<AssetRateModel>),
+    /// Send protocol reserves to an address (to, asset, amount)
+    WithdrawReserves(Address, Option<Address>, i128),
+    /// Set an asset's deposit parameters
+    AssetParams(Address, AssetParams),
+    /// Set an asset's oracles (asset, primary, fallback); `None` keeps the current one
+    OracleAddresses(Address, Option<Address>, Option<Address>),
+    /// Update the pool interest rate curve (base, kink, multiplier, jump, floor, ceiling, spread)
+    InterestRateConfig(
+        Option<i128>,
+        Option<i128>,
+        Option<i128>,
+        Option<i128>,
+        Option<i128>,
+        Option<i128>,
+        Option<i128>,
+    ),
+    /// Set the share of protocol fees routed to the insurance fund (bps)
+    InsuranceFeeShare(i128),
+    /// Set the protocol origination fee charged on borrows (bps)
+    OriginationFee(i128),
+    /// Set the share of the treasury fee paid to referrers (bps)
+    ReferralShare(i128),
+    /// Create or update an e-mode category (id, ratios)
+    EModeCategory(u32, EModeCategory),
+    /// Assign an asset to an e-mode category; `None` removes it
+    EModeAsset(Option<Address>, Option<u32>),
+    /// Turn liquidator whitelist mode on or off
+    LiquidatorWhitelist(bool),
+    /// Add (`true`) or remove (`false`) a whitelisted liquidator
+    Liquidator(Address, bool),
+    /// List a new market with its full configuration (asset, listing)
+    ListAsset(Address, AssetListing),
+    /// Start delisting an asset (asset, collateral factor ramp duration in seconds)
+    DelistAsset(Address, u64),
+    /// Replace the contract code with an uploaded WASM hash
+    UpgradeContract(BytesN<32>),
     /// Generic action for future extensions
     GenericAction(Action),
 }
 
+/// Proposal categories with independently configurable voting bars
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ProposalCategory {
+    /// Risk, asset, oracle and feature parameters
+    Parameter,
+    /// Movements of protocol funds
+    Treasury,
+    /// Pauses and circuit breakers
+    Emergency,
+    /// Governance's own rules
+    Governance,
+    /// Arbitrary contract calls (e.g. upgrades)
+    Generic,
+}
+
+impl ProposalType {
+    /// Category used to look up the proposal's voting bar
+    pub fn category(&self) -> ProposalCategory {
+        match self {
+            ProposalType::MinCollateralRatio(..)
+            | ProposalType::RiskParams(..)
+            | ProposalType::AssetConfigUpdate(..)
+            | ProposalType::LiquidationInsuranceShare(..)
+            | ProposalType::RiskEngineSettings(..)
+            | ProposalType::RiskEngineRateBand(..)
+            | ProposalType::RiskEngineCollateralBand(..)
+            | ProposalType::RevertRiskAdjustment(..)
+            | ProposalType::AssetOracleConfig(..)
+            | ProposalType::SetStrategy(..)
+            | ProposalType::CreditHistoryConfig(..)
+            | ProposalType::KeeperConfig(..)
+            | ProposalType::MinHoldingPeriod(..)
+            | ProposalType::ParameterChangeLimit(..)
+            | ProposalType::ParameterRampDuration(..)
+            | ProposalType::RiskTierParams(..)
+            | ProposalType::AnalyticsVerbosity(..)
+            | ProposalType::LiquidationHysteresis(..)
+            | ProposalType::SavingsRate(..)
+            | ProposalType::AssetRateModel(..)
+            | ProposalType::AssetParams(..)
+            | ProposalType::OracleAddresses(..)
+            | ProposalType::InterestRateConfig(..)
+            | ProposalType::InsuranceFeeShare(..)
+            | ProposalType::OriginationFee(..)
+            | ProposalType::ReferralShare(..)
+            | ProposalType::EModeCategory(..)
+            | ProposalType::EModeAsset(..)
+            | ProposalType::LiquidatorWhitelist(..)
+            | ProposalType::Liquidator(..)
+            | ProposalType::ListAsset(..)
+            | ProposalType::DelistAsset(..) => ProposalCategory::Parameter,
+            ProposalType::SeedProtocolLiquidity(..)
+            | ProposalType::CreateClaimCampaign(..)
+            | ProposalType::RewardEmission(..)
+            | ProposalType::StrategyDeposit(..)
+            | ProposalType::StrategyWithdraw(..)
+            | ProposalType::SlashKeeper(..)
+            | ProposalType::RescueTokens(..)
+            | ProposalType::ForgiveDebt(..)
+            | ProposalType::WithdrawReserves(..) => ProposalCategory::Treasury,
+            ProposalType::PauseSwitch(..)
+            | ProposalType::EmergencyPause(..)
+            | ProposalType::ResetCircuitBreaker(..) => ProposalCategory::Emergency,
+            ProposalType::VoteWeightCap(..)
+            | ProposalType::QuadraticVoting(..)
+            | ProposalType::CategoryThreshold(..)
+            | ProposalType::EmergencyTrackConfig(..)
+            | ProposalType::MultisigSpendLimit(..) => ProposalCategory::Governance,
+            ProposalType::UpgradeContract(..) | ProposalType::GenericAction(..) => {
+                ProposalCategory::Generic
+            }
+        }
+    }
+
+    /// Asset and amount the proposal moves out of protocol holdings, if any
+    ///
+    /// A generic action can call any token the protocol holds, so it counts as
+    /// an unbounded outflow of its target. A reward emission pays out over
+    /// its schedule rather than at execution, so it has no outflow here and
+    /// is left out of the multisig allowlist instead.
+    pub fn treasury_outflow(&self) -> Option<(Option<Address>, i128)> {
+        match self {
+            ProposalType::SeedProtocolLiquidity(asset, amount)
+            | ProposalType::ForgiveDebt(_, asset, amount)
+            | ProposalType::WithdrawReserves(_, asset, amount) => Some((asset.clone(), *amount)),
+            ProposalType::CreateClaimCampaign(token, _, amount)
+            | ProposalType::StrategyDeposit(token, amount)
+            | ProposalType::StrategyWithdraw(token, amount)
+            | ProposalType::RescueTokens(token, amount, _) => Some((Some(token.clone()), *amount)),
+            ProposalType::GenericAction(action) => Some((Some(action.target.clone()), i128::MAX)),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[contracttype]
 pub struct Proposal {
@@ -68,6 +274,18 @@ pub struct Proposal {
     pub abstain_votes: i128,
     pub total_voting_power: i128,
     pub created_at: u64,
+    pub quadratic: bool, // Tally sqrt of voting power (snapshot of the governance setting at creation)
+    pub quorum_bps: u32, // Quorum in basis points (snapshot of the category or default quorum)
+    pub emergency: bool, // Fast-track proposal: short vote, no timelock, guardian co-sign
+    pub turnout: i128, // Stake of everyone who voted, before the weight cap and quadratic weighting
+}
+
+/// Staked vote-token balance from `timestamp` until the next checkpoint
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct VoteCheckpoint {
+    pub timestamp: u64,
+    pub staked: i128,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -118,6 +336,31 @@ pub struct GovernanceConfig {
     pub default_voting_threshold: i128, // Default 50% in basis points
 }
 
+/// Voting bar for a proposal category
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CategoryThreshold {
+    pub voting_threshold: i128, // Minimum for-vote threshold in basis points
+    pub quorum_bps: u32,        // Quorum in basis points
+}
+
+/// Emergency fast-track proposal configuration
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct EmergencyTrackConfig {
+    pub voting_period: u64,      // Shortened voting window in seconds
+    pub voting_threshold: i128,  // Supermajority for-vote threshold in basis points
+    pub quorum_bps: u32,         // Quorum in basis points
+    pub guardian_approvals: u32, // Guardian co-signatures required to execute
+}
+
+/// Anti-whale cap on the voting power a single address contributes to a proposal
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct VoteWeightCap {
+    pub max_vote_bps: i128, // Max share of the proposal's staked total per voter
+}
+
 // ========================================================================
 // Multisig Types
 // ========================================================================
@@ -129,6 +372,14 @@ pub struct MultisigConfig {
     pub threshold: u32,
 }
 
+/// Treasury outflow executed through the multisig
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct MultisigSpend {
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
 // ========================================================================
 // Social Recovery Types
 // ========================================================================
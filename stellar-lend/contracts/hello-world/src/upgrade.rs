@@ -0,0 +1,64 @@
+//! # Upgrade Module
+//!
+//! Replaces the contract's code in place.
+//!
+//! [`upgrade`] swaps the running WASM for a previously uploaded one with
+//! `update_current_contract_wasm`. Storage, balances and the contract address
+//! are kept; only the code changes. Every upgrade bumps the contract version
+//! returned by [`version`], which starts at 1 for the initial deployment.
+//!
+//! ## Security
+//! - Only reachable through an executed governance proposal or an approved
+//!   multisig proposal (`ProposalType::UpgradeContract`), which use the
+//!   `Generic` category's voting bar and approval threshold.
+//! - The new code takes effect after the current invocation finishes.
+
+#![allow(unused)]
+use soroban_sdk::{contracttype, BytesN, Env, Symbol};
+
+/// Version of the initially deployed code
+pub const INITIAL_VERSION: u32 = 1;
+
+/// Storage keys for upgrade data
+#[contracttype]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum UpgradeDataKey {
+    /// Current contract version: Version -> u32
+    Version,
+}
+
+/// Current contract version
+pub fn version(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&UpgradeDataKey::Version)
+        .unwrap_or(INITIAL_VERSION)
+}
+
+/// Replace the contract code with the uploaded WASM `new_wasm_hash`
+///
+/// Only reachable through an executed governance proposal.
+///
+/// # Returns
+/// The new contract version
+#[allow(deprecated)]
+pub fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) -> u32 {
+    let old_version = version(env);
+    let new_version = old_version.saturating_add(1);
+    env.storage()
+        .persistent()
+        .set(&UpgradeDataKey::Version, &new_version);
+
+    env.deployer()
+        .update_current_contract_wasm(new_wasm_hash.clone());
+
+    let topics = (
+        Symbol::new(env, "contract_upgraded"),
+        old_version,
+        new_version,
+    );
+    env.events().publish(topics, new_wasm_hash);
+
+    new_version
+}
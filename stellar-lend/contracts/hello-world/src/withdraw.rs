@@ -7,11 +7,17 @@
 //! 1. **Amount validation** — amount must be strictly positive.
 //! 2. **Authorization** — only the position owner may withdraw their collateral.
 //! 3. **Reentrancy guard** — prevents re-entrant calls via temporary storage lock.
-//! 4. **Pause checks** — both the per-operation pause flag and the global emergency
-//!    pause are consulted; any active pause halts the withdrawal.
+//! 4. **Pause checks** — the per-operation pause flag, the asset's own pause
+//!    flag and the global emergency pause are consulted; any active pause halts
+//!    the withdrawal.
 //! 5. **Asset validation** — the asset address may not be the contract itself.
 //! 6. **Balance check** — the user must hold at least `amount` collateral.
-//! 7. **Post-withdrawal health** — after subtracting `amount`, the position must:
+//!    [`WITHDRAW_ALL`] (`i128::MAX`) resolves to the user's whole balance of
+//!    the asset after supply interest is settled, so nothing is left behind.
+//!    Collateral left in the asset must be zero or reach the asset's minimum
+//!    size (see [`crate::dust`]).
+//! 7. **Post-withdrawal health** — after subtracting the oracle value of `amount`,
+//!    the position must:
 //!    - Maintain a collateral ratio ≥ `min_collateral_ratio` (latest risk params).
 //!    - Remain above the liquidation threshold (i.e. not immediately liquidatable).
 //! 8. **State-before-transfer** — storage is updated *before* any token transfer to
@@ -23,7 +29,8 @@
 //! - Risk parameters are always read from persistent storage at call time — no
 //!   cached or stale values are used.
 //! - Token transfers use the Soroban token interface; the contract never retains
-//!   custody beyond what is recorded in `CollateralBalance`.
+//!   custody beyond what is recorded in `CollateralBalance`. Native XLM is paid
+//!   out through the Stellar Asset Contract stored as the native asset address.
 //!
 //! ## Admin / Guardian Powers
 //! - Admins can pause all withdrawals via `PauseSwitches` or `EmergencyPause`.
@@ -34,7 +41,8 @@
 //! - `CollateralBalance(user)` — updated before token transfer.
 //! - `Position(user)` — collateral field updated in sync.
 //! - `UserAnalytics(user)` / `ProtocolAnalytics` — updated after transfer.
-//! - `ActivityLog` — bounded append (max 1000 entries, FIFO eviction).
+//! - `ActivityLog` — bounded append (max 100 entries, FIFO eviction); the
+//!   user's paged history is appended too (see [`crate::activity`]).
 
 use soroban_sdk::{contracterror, Address, Env, Map, Symbol};
 
@@ -43,7 +51,9 @@ use crate::deposit::{
     emit_user_activity_tracked_event, AssetParams, DepositDataKey, Position, ProtocolAnalytics,
     UserAnalytics,
 };
-use crate::events::{emit_withdrawal, WithdrawalEvent};
+use crate::events::{
+    emit_withdrawal, emit_withdrawal_v1, WithdrawalEvent, WithdrawalEventV1, EVENT_SCHEMA_VERSION,
+};
 
 /// Errors that can occur during withdraw operations.
 ///
@@ -70,8 +80,21 @@ pub enum WithdrawError {
     Undercollateralized = 8,
     /// Caller is not the position owner.
     Unauthorized = 9,
+    /// User deposited in the current ledger (same-ledger guard).
+    SameLedgerDeposit = 10,
+    /// Volume circuit breaker is tripped for the asset.
+    CircuitBreakerTripped = 11,
+    /// User exceeded the per-user withdrawal rate limit.
+    RateLimited = 12,
+    /// A priced asset in the position has no fresh oracle price.
+    PriceNotAvailable = 13,
+    /// Withdrawal would leave collateral in the asset below its minimum size.
+    DustPosition = 14,
 }
 
+/// Amount that withdraws the user's whole balance of the asset
+pub const WITHDRAW_ALL: i128 = i128::MAX;
+
 // ---------------------------------------------------------------------------
 // Internal calculation helpers
 // ---------------------------------------------------------------------------
@@ -118,9 +141,10 @@ fn calculate_collateral_ratio(
 /// # Errors
 /// - `WithdrawError::InsufficientCollateral` — arithmetic underflow (new < 0).
 /// - `WithdrawError::Overflow` — addition overflow on debt fields.
+/// - `WithdrawError::PriceNotAvailable` — a priced asset has no fresh price.
 /// - `WithdrawError::InsufficientCollateralRatio` — would breach minimum ratio.
 /// - `WithdrawError::Undercollateralized` — would become liquidatable.
-fn validate_collateral_ratio_after_withdraw(
+pub(crate) fn validate_collateral_ratio_after_withdraw(
     env: &Env,
     user: &Address,
     withdraw_amount: i128,
@@ -147,10 +171,10 @@ fn validate_collateral_ratio_after_withdraw(
         .get::<DepositDataKey, i128>(&collateral_key)
         .unwrap_or(0);
 
-    // Projected collateral after withdrawal
-    let new_collateral = current_collateral
-        .checked_sub(withdraw_amount)
-        .ok_or(WithdrawError::InsufficientCollateral)?;
+    // Projected collateral after withdrawal must not go negative
+    if current_collateral < withdraw_amount {
+        return Err(WithdrawError::InsufficientCollateral);
+    }
 
     // Per-asset collateral factor (default 100% = 10_000 bps when not configured)
     let collateral_factor: i128 = if let Some(asset_addr) = asset {
@@ -163,6 +187,10 @@ fn validate_collateral_ratio_after_withdraw(
     } else {
         10_000 // Native XLM: full collateral weight
     };
+    let collateral_factor =
+        crate::risk_params::effective_collateral_factor(env, asset, collateral_factor);
+    let collateral_factor =
+        crate::credit_history::adjusted_collateral_factor(env, user, collateral_factor);
 
     // Validate total debt arithmetic is safe
     let _total_debt = position
@@ -170,11 +198,33 @@ fn validate_collateral_ratio_after_withdraw(
         .checked_add(position.borrow_interest)
         .ok_or(WithdrawError::Overflow)?;
 
-    // Compute projected health ratio
-    let new_ratio_opt = calculate_collateral_ratio(
-        new_collateral,
+    // Value the position and the withdrawal at oracle prices
+    let value = crate::asset_positions::position_value(
+        env,
+        user,
+        current_collateral,
         position.debt,
         position.borrow_interest,
+    )
+    .map_err(|_| WithdrawError::PriceNotAvailable)?;
+    // Collateral excluded from valuation does not lower it further
+    let counted_amount =
+        if crate::asset_positions::is_collateral_enabled(env, user, &asset.cloned()) {
+            withdraw_amount
+        } else {
+            let own =
+                crate::asset_positions::get_asset_position(env, user, asset.cloned()).collateral;
+            withdraw_amount.saturating_sub(own).max(0)
+        };
+    let withdraw_value = crate::oracle::ratio_value(env, asset, counted_amount)
+        .map_err(|_| WithdrawError::PriceNotAvailable)?;
+    let new_collateral_value = value.collateral.saturating_sub(withdraw_value).max(0);
+
+    // Compute projected health ratio
+    let new_ratio_opt = calculate_collateral_ratio(
+        new_collateral_value,
+        value.debt,
+        value.interest,
         collateral_factor,
     );
 
@@ -187,7 +237,7 @@ fn validate_collateral_ratio_after_withdraw(
     //
     // Fallback of 15_000 (150%) is intentionally conservative: it applies only
     // when `initialize` was never called, protecting uninitialised deployments.
-    let min_ratio = crate::risk_params::get_min_collateral_ratio(env).unwrap_or(15_000);
+    let min_ratio = crate::emode::min_collateral_ratio_for(env, user, None);
     if new_ratio < min_ratio {
         return Err(WithdrawError::InsufficientCollateralRatio);
     }
@@ -198,8 +248,9 @@ fn validate_collateral_ratio_after_withdraw(
     // enforced at parameter-update time, this check is normally redundant.
     // We keep it explicit so that any future parameter inconsistency cannot
     // silently produce a liquidatable withdrawal.
-    let liq_threshold =
-        crate::risk_params::get_liquidation_threshold(env).unwrap_or(min_ratio);
+    let liq_threshold = crate::emode::liquidation_threshold_for(env, user)
+        .or_else(|| crate::risk_params::get_liquidation_threshold(env).ok())
+        .unwrap_or(min_ratio);
     if new_ratio < liq_threshold {
         return Err(WithdrawError::Undercollateralized);
     }
@@ -222,8 +273,9 @@ fn validate_collateral_ratio_after_withdraw(
 /// # Arguments
 /// * `env` — Soroban execution environment.
 /// * `user` — Account withdrawing collateral; must sign the transaction.
-/// * `asset` — Token contract address, or `None` for native XLM placeholder.
-/// * `amount` — Amount to withdraw (must be > 0).
+/// * `asset` — Token contract address, or `None` for native XLM.
+/// * `amount` — Amount to withdraw (must be > 0), or [`WITHDRAW_ALL`] for
+///   the whole balance of `asset`.
 ///
 /// # Returns
 /// The updated collateral balance after withdrawal.
@@ -234,6 +286,8 @@ fn validate_collateral_ratio_after_withdraw(
 /// * [`WithdrawError::WithdrawPaused`] — withdrawals are paused (per-op or emergency).
 /// * [`WithdrawError::InvalidAsset`] — `asset` is the contract address itself.
 /// * [`WithdrawError::InsufficientCollateral`] — user's balance < `amount`.
+/// * [`WithdrawError::DustPosition`] — withdrawal would leave collateral in
+///   `asset` below its minimum size.
 /// * [`WithdrawError::InsufficientCollateralRatio`] — withdrawal would breach minimum ratio.
 /// * [`WithdrawError::Undercollateralized`] — withdrawal would make position liquidatable.
 /// * [`WithdrawError::Overflow`] — arithmetic overflow during calculation.
@@ -273,8 +327,10 @@ pub fn withdraw_collateral(
     // 4. Pause checks — consult BOTH emergency pause and per-op flag
     // -----------------------------------------------------------------------
 
-    // 4a. Global emergency pause (risk_management module)
-    if crate::risk_management::is_emergency_paused(env) {
+    // 4a. Global emergency pause and withdraw switch (hot configuration cache)
+    if crate::hot_config::load(env)
+        .is_paused(env, crate::risk_management::PauseOperation::Withdraw)
+    {
         return Err(WithdrawError::WithdrawPaused);
     }
 
@@ -293,6 +349,23 @@ pub fn withdraw_collateral(
         }
     }
 
+    // 4c. Per-asset withdraw switch
+    if crate::risk_management::is_asset_paused(
+        env,
+        asset.clone(),
+        crate::risk_management::PauseOperation::Withdraw,
+    ) {
+        return Err(WithdrawError::WithdrawPaused);
+    }
+
+    // 4d. Same-ledger guard — no withdrawing collateral deposited this ledger
+    crate::flash_guard::require_not_same_ledger(env, &user)
+        .map_err(|_| WithdrawError::SameLedgerDeposit)?;
+
+    // 4e. Per-user rate limit
+    crate::throttle::check_and_record(env, &user, crate::throttle::ThrottledOperation::Withdraw)
+        .map_err(|_| WithdrawError::RateLimited)?;
+
     // -----------------------------------------------------------------------
     // 5. Asset validation — contract address is not a valid collateral asset
     // -----------------------------------------------------------------------
@@ -303,8 +376,12 @@ pub fn withdraw_collateral(
     }
 
     // -----------------------------------------------------------------------
-    // 6. Balance check
+    // 6. Settle supply interest, resolve WITHDRAW_ALL, then balance check
     // -----------------------------------------------------------------------
+    crate::interest_rate::accrue_asset(env, asset.clone()).map_err(|_| WithdrawError::Overflow)?;
+    crate::supply_interest::settle_supply_interest(env, &user, asset.clone())
+        .map_err(|_| WithdrawError::Overflow)?;
+
     let collateral_key = DepositDataKey::CollateralBalance(user.clone());
     let current_collateral: i128 = env
         .storage()
@@ -312,10 +389,34 @@ pub fn withdraw_collateral(
         .get::<DepositDataKey, i128>(&collateral_key)
         .unwrap_or(0);
 
+    let amount = if amount == WITHDRAW_ALL {
+        let balance = withdrawable_balance(env, &user, &asset, current_collateral);
+        if balance == 0 {
+            return Err(WithdrawError::InsufficientCollateral);
+        }
+        balance
+    } else {
+        amount
+    };
+
     if current_collateral < amount {
         return Err(WithdrawError::InsufficientCollateral);
     }
 
+    // Leave either nothing or at least the asset's minimum collateral
+    let remaining = withdrawable_balance(env, &user, &asset, current_collateral)
+        .checked_sub(amount)
+        .ok_or(WithdrawError::Overflow)?;
+    let min_collateral = crate::dust::get_min_position_size(env, asset.clone()).min_collateral;
+    if crate::dust::is_dust(remaining, min_collateral) {
+        return Err(WithdrawError::DustPosition);
+    }
+
+    // Volume circuit breaker — track per-ledger volume of the resolved
+    // amount, reject while tripped
+    crate::circuit_breaker::record_volume(env, asset.clone(), amount)
+        .map_err(|_| WithdrawError::CircuitBreakerTripped)?;
+
     // -----------------------------------------------------------------------
     // 7. Post-withdrawal health check (uses latest risk params)
     //    ANY withdrawal that makes the position unsafe MUST fail.
@@ -335,6 +436,14 @@ pub fn withdraw_collateral(
     env.storage()
         .persistent()
         .set(&collateral_key, &new_collateral);
+    crate::asset_positions::record_withdrawal(
+        env,
+        &user,
+        asset.clone(),
+        amount,
+        current_collateral,
+    )
+    .map_err(|_| WithdrawError::InsufficientCollateral)?;
 
     let timestamp = env.ledger().timestamp();
     let position_key = DepositDataKey::Position(user.clone());
@@ -351,22 +460,25 @@ pub fn withdraw_collateral(
         });
 
     position.collateral = new_collateral;
-    position.last_accrual_time = timestamp;
     env.storage().persistent().set(&position_key, &position);
 
     // -----------------------------------------------------------------------
     // 10. Token transfer — state already committed, so reentrancy is safe
     // -----------------------------------------------------------------------
-    if let Some(ref asset_addr) = asset {
-        let token_client = soroban_sdk::token::Client::new(env, asset_addr);
+    // Native XLM is paid out through its Stellar Asset Contract
+    if let Some(token_addr) = asset
+        .clone()
+        .or_else(|| crate::deposit::get_native_asset_address(env))
+    {
+        crate::strategy::ensure_liquidity(env, &token_addr, amount)
+            .map_err(|_| WithdrawError::InsufficientCollateral)?;
+        let token_client = soroban_sdk::token::Client::new(env, &token_addr);
         token_client.transfer(
             &env.current_contract_address(), // from: this contract
             &user,                            // to: the position owner
             &amount,
         );
     }
-    // Native XLM: accounting tracked above; actual XLM handling depends on
-    // Soroban's native-asset support and is a known protocol placeholder.
 
     // -----------------------------------------------------------------------
     // 11. Analytics and event emission
@@ -393,13 +505,222 @@ pub fn withdraw_collateral(
             timestamp,
         },
     );
+    emit_withdrawal_v1(
+        env,
+        WithdrawalEventV1 {
+            schema_version: EVENT_SCHEMA_VERSION,
+            user: user.clone(),
+            asset: asset.clone(),
+            amount,
+            collateral_after: position.collateral,
+            total_debt_after: position.debt.saturating_add(position.borrow_interest),
+            timestamp,
+        },
+    );
     emit_position_updated_event(env, &user, &position);
+    crate::ttl::bump_position(env, &user);
+    crate::analytics::emit_health_factor_changed(env, &user, Symbol::new(env, "withdraw"));
     emit_analytics_updated_event(env, &user, "withdraw", amount, timestamp);
     emit_user_activity_tracked_event(env, &user, Symbol::new(env, "withdraw"), amount, timestamp);
 
     Ok(new_collateral)
 }
 
+// ---------------------------------------------------------------------------
+// Views
+// ---------------------------------------------------------------------------
+
+/// Largest amount of `asset` `user` could withdraw right now.
+///
+/// Applies the same limits as [`withdraw_collateral`]: the user's balance of
+/// `asset` (including unattributed collateral), the minimum collateral ratio
+/// and liquidation threshold against debt with interest accrued up to now,
+/// all at current oracle prices, the asset's minimum collateral, and the
+/// pool's available liquidity. Returns `0` whenever the withdrawal would be
+/// rejected outright: withdrawals paused, the asset behind a tripped circuit
+/// breaker, or a deposit in this same ledger.
+///
+/// # Errors
+/// * [`WithdrawError::PriceNotAvailable`] — a priced asset has no fresh price.
+/// * [`WithdrawError::Overflow`] — arithmetic overflow during calculation.
+pub fn get_max_withdrawable(
+    env: &Env,
+    user: Address,
+    asset: Option<Address>,
+) -> Result<i128, WithdrawError> {
+    if is_withdraw_paused(env, asset.clone())
+        || crate::circuit_breaker::is_tripped(env, asset.clone())
+        || crate::flash_guard::require_not_same_ledger(env, &user).is_err()
+    {
+        return Ok(0);
+    }
+    if asset.as_ref() == Some(&env.current_contract_address()) {
+        return Ok(0);
+    }
+
+    let current_collateral: i128 = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, i128>(&DepositDataKey::CollateralBalance(user.clone()))
+        .unwrap_or(0);
+    let balance = withdrawable_balance(env, &user, &asset, current_collateral);
+    if balance == 0 {
+        return Ok(0);
+    }
+
+    let position = env
+        .storage()
+        .persistent()
+        .get::<DepositDataKey, Position>(&DepositDataKey::Position(user.clone()));
+    let max_withdrawable = match position {
+        Some(position) if position.debt > 0 || position.borrow_interest > 0 => {
+            let pending = crate::borrow::calculate_accrued_interest(
+                env,
+                &user,
+                position.debt,
+                position.last_accrual_time,
+            )
+            .map_err(|_| WithdrawError::Overflow)?;
+            let interest = position
+                .borrow_interest
+                .checked_add(pending)
+                .ok_or(WithdrawError::Overflow)?;
+            let value = crate::asset_positions::position_value(
+                env,
+                &user,
+                current_collateral,
+                position.debt,
+                interest,
+            )
+            .map_err(|_| WithdrawError::PriceNotAvailable)?;
+            let debt_value = value
+                .debt
+                .checked_add(value.interest)
+                .ok_or(WithdrawError::Overflow)?;
+
+            let collateral_factor = crate::risk_params::effective_collateral_factor(
+                env,
+                asset.as_ref(),
+                asset
+                    .as_ref()
+                    .and_then(|asset_addr| {
+                        env.storage()
+                            .persistent()
+                            .get::<DepositDataKey, AssetParams>(&DepositDataKey::AssetParams(
+                                asset_addr.clone(),
+                            ))
+                    })
+                    .map(|p| p.collateral_factor)
+                    .unwrap_or(10_000),
+            );
+            let min_ratio = crate::emode::min_collateral_ratio_for(env, &user, None);
+            let liq_threshold = crate::emode::liquidation_threshold_for(env, &user)
+                .or_else(|| crate::risk_params::get_liquidation_threshold(env).ok())
+                .unwrap_or(min_ratio);
+            let required = if collateral_factor <= 0 {
+                value.collateral
+            } else {
+                required_collateral_value(
+                    debt_value,
+                    min_ratio.max(liq_threshold),
+                    collateral_factor,
+                )
+                .ok_or(WithdrawError::Overflow)?
+            };
+            let excess_value = value.collateral.saturating_sub(required).max(0);
+            let excess = crate::oracle::ratio_amount(env, asset.as_ref(), excess_value)
+                .map_err(|_| WithdrawError::PriceNotAvailable)?;
+
+            // Collateral excluded from valuation can leave without affecting health
+            if crate::asset_positions::is_collateral_enabled(env, &user, &asset) {
+                excess
+            } else {
+                crate::asset_positions::get_asset_position(env, &user, asset.clone())
+                    .collateral
+                    .max(0)
+                    .saturating_add(excess)
+            }
+        }
+        _ => balance,
+    };
+    let max_withdrawable = max_withdrawable.min(balance);
+
+    // Stop at the asset's minimum collateral unless the whole balance can go
+    let min_collateral = crate::dust::get_min_position_size(env, asset.clone()).min_collateral;
+    let max_withdrawable = if crate::dust::is_dust(balance - max_withdrawable, min_collateral) {
+        balance.saturating_sub(min_collateral).max(0)
+    } else {
+        max_withdrawable
+    };
+
+    let max_withdrawable = match asset {
+        Some(ref asset_addr) => {
+            max_withdrawable.min(crate::strategy::get_available_liquidity(env, asset_addr))
+        }
+        None => max_withdrawable,
+    };
+
+    Ok(max_withdrawable.max(0))
+}
+
+/// Balance of `asset` a withdrawal may draw on: the asset's own collateral
+/// plus any collateral not yet attributed to an asset.
+fn withdrawable_balance(
+    env: &Env,
+    user: &Address,
+    asset: &Option<Address>,
+    current_collateral: i128,
+) -> i128 {
+    crate::asset_positions::get_asset_position(env, user, asset.clone())
+        .collateral
+        .saturating_add(crate::asset_positions::unattributed_collateral(
+            env,
+            user,
+            current_collateral,
+        ))
+        .min(current_collateral)
+        .max(0)
+}
+
+/// Smallest collateral value that keeps `debt_value` at `ratio`, matching
+/// the rounding of [`calculate_collateral_ratio`].
+///
+/// `collateral_factor` must be positive. Returns `None` on overflow.
+fn required_collateral_value(
+    debt_value: i128,
+    ratio: i128,
+    collateral_factor: i128,
+) -> Option<i128> {
+    // ratio check: (value * cf / 10_000) * 10_000 / debt >= ratio
+    let weighted = debt_value
+        .checked_mul(ratio)?
+        .checked_add(10_000 - 1)?
+        .checked_div(10_000)?;
+    weighted
+        .checked_mul(10_000)?
+        .checked_add(collateral_factor - 1)?
+        .checked_div(collateral_factor)
+}
+
+/// Whether withdrawals of `asset` are paused by the emergency pause, the
+/// withdraw switch, the asset's own switch or the legacy pause switches.
+fn is_withdraw_paused(env: &Env, asset: Option<Address>) -> bool {
+    if crate::hot_config::load(env).is_paused(env, crate::risk_management::PauseOperation::Withdraw)
+        || crate::risk_management::is_asset_paused(
+            env,
+            asset,
+            crate::risk_management::PauseOperation::Withdraw,
+        )
+    {
+        return true;
+    }
+    env.storage()
+        .persistent()
+        .get::<DepositDataKey, Map<Symbol, bool>>(&DepositDataKey::PauseSwitches)
+        .and_then(|pause_map| pause_map.get(Symbol::new(env, "pause_withdraw")))
+        .unwrap_or(false)
+}
+
 // ---------------------------------------------------------------------------
 // Analytics helpers
 // ---------------------------------------------------------------------------
@@ -411,6 +732,9 @@ fn update_user_analytics_withdraw(
     amount: i128,
     timestamp: u64,
 ) -> Result<(), WithdrawError> {
+    if !crate::analytics::tracks_user_activity(env) {
+        return Ok(());
+    }
     let analytics_key = DepositDataKey::UserAnalytics(user.clone());
     #[allow(clippy::unnecessary_lazy_evaluations)]
     let mut analytics = env
@@ -458,6 +782,7 @@ fn update_user_analytics_withdraw(
 
     analytics.transaction_count = analytics.transaction_count.saturating_add(1);
     analytics.last_activity = timestamp;
+    analytics.loyalty_tier = crate::loyalty::tier_for(env, &analytics, timestamp);
 
     env.storage().persistent().set(&analytics_key, &analytics);
     Ok(())